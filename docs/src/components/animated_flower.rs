@@ -250,6 +250,7 @@ pub fn AnimatedFlower() -> Element {
                 .stiffness(60.0)
                 .damping(8.0)
                 .loop_mode(LoopMode::Infinite)
+                .ambient()
                 .animate_to(PetalTransform::new(PI / 4.0, 1.2, 3.0, 3.0));
 
             center_scale
@@ -257,6 +258,7 @@ pub fn AnimatedFlower() -> Element {
                 .stiffness(100.0)
                 .damping(10.0)
                 .loop_mode(LoopMode::Infinite)
+                .ambient()
                 .animate_to(1.2);
         }
     };
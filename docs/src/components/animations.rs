@@ -63,24 +63,8 @@ fn AnimationStep(title: String, description: String, code: String, children: Ele
 /// }
 /// ```
 fn BasicValueAnimation() -> Element {
-    let mut opacity = use_motion(0.0f32);
     let mut is_visible = use_signal(|| false);
-
-    use_effect(move || {
-        if *is_visible.read() {
-            opacity
-                .tween()
-                .duration(Duration::from_millis(500))
-                .easing(easer::functions::Cubic::ease_in_out)
-                .animate_to(1.0);
-        } else {
-            opacity
-                .tween()
-                .duration(Duration::from_millis(500))
-                .easing(easer::functions::Cubic::ease_in_out)
-                .animate_to(0.0);
-        }
-    });
+    let opacity = use_fade(is_visible);
 
     rsx! {
         div { class: "space-y-4 w-full text-center",
@@ -97,6 +81,24 @@ fn BasicValueAnimation() -> Element {
     }
 }
 
+/// Fades an opacity in or out over 500 milliseconds whenever `is_visible` changes.
+///
+/// The opacity is labeled `"opacity"`, which lets tests find it in a motion test harness.
+fn use_fade(is_visible: Signal<bool>) -> MotionValue<f32> {
+    let opacity = use_motion_labeled("opacity", 0.0f32);
+
+    use_effect(move || {
+        let target = if *is_visible.read() { 1.0 } else { 0.0 };
+        opacity
+            .tween()
+            .duration(Duration::from_millis(500))
+            .easing(easer::functions::Cubic::ease_in_out)
+            .animate_to(target);
+    });
+
+    opacity
+}
+
 #[component]
 /// Animates an element's transform properties with a spring dynamic effect.
 ///
@@ -121,7 +123,7 @@ fn TransformAnimation() -> Element {
                 .spring()
                 .stiffness(100.0)
                 .damping(10.0)
-                .animate_to(Transform::new(100.0, 50.0, 1.2, 1.2, 0.0, 0.0, 45.0));
+                .animate_to(Transform::from_degrees(100.0, 50.0, 1.2, 1.2, 0.0, 0.0, 45.0));
         } else {
             transform
                 .spring()
@@ -454,7 +456,7 @@ fn TransformAnimation() -> Element {
                 .spring()
                 .stiffness(100.0)
                 .damping(10.0)
-                .animate_to(Transform::new(100.0, 50.0, 1.2, 1.2, 0.0, 0.0, 45.0));
+                .animate_to(Transform::from_degrees(100.0, 50.0, 1.2, 1.2, 0.0, 0.0, 45.0));
         } else {
             transform
                 .spring()
@@ -505,7 +507,7 @@ let mut transform = use_motion(Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0)
 
 // Animate with spring for natural motion
 transform.animate_to(
-    Transform::new(100.0, 50.0, 1.2, 1.2, 0.0, 0.0, 45.0),
+    Transform::from_degrees(100.0, 50.0, 1.2, 1.2, 0.0, 0.0, 45.0),
     AnimationConfig::new(AnimationMode::Spring(Spring {
         stiffness: 100.0,
         damping: 10.0,
@@ -677,3 +679,43 @@ pub fn Home() -> Element {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dioxus_motion2::testing::motion_test_harness;
+    use std::cell::Cell;
+
+    thread_local! {
+        static IS_VISIBLE: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+    }
+
+    /// `BasicValueAnimation` with its toggle reachable from the test, as the button
+    /// can't be clicked without a renderer
+    fn basic_value_animation() -> Element {
+        let is_visible = use_signal(|| false);
+        IS_VISIBLE.set(Some(is_visible));
+        let opacity = use_fade(is_visible);
+
+        rsx! {
+            div { style: "opacity: {opacity.get()}" }
+        }
+    }
+
+    #[test]
+    fn toggling_fades_the_square_in() {
+        let mut harness = motion_test_harness(basic_value_animation);
+        let opacity = harness.motion_value("opacity").expect("the fade labels its opacity");
+        assert_eq!(opacity.value::<f32>(), Some(0.0));
+
+        // What clicking "Show" does
+        harness.act(|| IS_VISIBLE.get().expect("the app is mounted").toggle());
+        let opacity = harness.motion_value("opacity").expect("the fade labels its opacity");
+        assert_eq!(opacity.target::<f32>(), Some(1.0));
+
+        harness.tick(Duration::from_millis(500));
+        let opacity = harness.motion_value("opacity").expect("the fade labels its opacity");
+        assert!((opacity.value::<f32>().unwrap_or_default() - 1.0).abs() < 1e-3);
+        assert!(!opacity.is_animating());
+    }
+}
@@ -454,6 +454,7 @@ pub fn SwingingCube() -> Element {
             .stiffness(35.0)
             .damping(5.0)
             .loop_mode(LoopMode::Infinite)
+            .ambient()
             .animate_to(Transform3D::new(
                 PI / 3.0, // X rotation
                 PI / 2.0, // Y rotation
@@ -467,7 +468,8 @@ pub fn SwingingCube() -> Element {
             .spring()
             .stiffness(40.0)
             .damping(4.0)
-            .loop_mode(LoopMode::Infinite)
+            .loop_mode(LoopMode::Alternate)
+            .ambient()
             .animate_to(1.4);
     };
 
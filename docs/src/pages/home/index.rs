@@ -6,12 +6,20 @@ use crate::components::animated_flower::AnimatedFlower;
 use crate::components::cube_animation::SwingingCube;
 use crate::{components::transformation_example::TransformAnimationShowcase, utils::router::Route};
 
+/// How the hero's elements come in on the landing page
+fn hero_entrance() -> Entrance {
+    Entrance::new()
+        .rise(24.0)
+        .fade()
+        .stagger(Duration::from_millis(70))
+        .spring(Spring::snappy())
+}
+
 #[component]
 /// Renders the main landing page of the application.
 ///
-/// This component initializes animated states for opacity, scale, and vertical positioning of key elements.
-/// On mount, it triggers staggered spring and tween animations that animate the hero section, titles, and feature overlays,
-/// creating a dynamic and engaging home page layout.
+/// On mount, the hero's title, tagline and buttons rise and fade in one after another, following
+/// [`hero_entrance`], while the live demo springs up to full size.
 ///
 /// # Examples
 ///
@@ -25,42 +33,19 @@ use crate::{components::transformation_example::TransformAnimationShowcase, util
 /// }
 /// ```
 pub fn Home() -> Element {
-    // Animation values
-    let title_y = use_motion(-50.0);
-    let subtitle_y = use_motion(50.0);
-    let hero_opacity = use_motion(0.0);
+    // The hero's title, tagline and buttons come in one after another
+    let title = use_entrance(&hero_entrance(), 0);
+    let subtitle = use_entrance(&hero_entrance(), 1);
+    let actions = use_entrance(&hero_entrance(), 2);
     let demo_scale = use_motion(0.8);
-    let demo_opacity = use_motion(0.0);
 
     // Animate in on mount
     use_effect(move || {
-        title_y
-            .spring()
-            .stiffness(100.0)
-            .damping(15.0)
-            .animate_to(0.0);
-
-        subtitle_y
-            .spring()
-            .stiffness(100.0)
-            .damping(15.0)
-            .animate_to(0.0);
-
-        hero_opacity
-            .tween()
-            .duration(Duration::from_millis(800))
-            .animate_to(1.0);
-
         demo_scale
             .spring()
             .stiffness(100.0)
             .damping(15.0)
             .animate_to(1.0);
-
-        demo_opacity
-            .tween()
-            .duration(Duration::from_millis(800))
-            .animate_to(1.0);
     });
 
     rsx! {
@@ -77,17 +62,19 @@ pub fn Home() -> Element {
                 div { class: "relative z-10 text-center max-w-4xl mx-auto px-4",
                     h1 {
                         class: "text-4xl md:text-5xl lg:text-6xl font-bold mb-4",
-                        style: "transform: translateY({title_y.get()}px)",
+                        style: "{title.get().to_css_string()}",
                         span { class: "text-gradient-primary", "Dioxus Motion2" }
                     }
                     p {
                         class: "text-lg md:text-xl text-text-secondary mb-8",
-                        style: "transform: translateY({subtitle_y.get()}px)",
+                        style: "{subtitle.get().to_css_string()}",
                         "Create beautiful animations with simple, powerful APIs"
                     }
 
                     // CTA buttons
-                    div { class: "flex flex-col sm:flex-row justify-center gap-4",
+                    div {
+                        class: "flex flex-col sm:flex-row justify-center gap-4",
+                        style: "{actions.get().to_css_string()}",
                         Link {
                             to: Route::DocsLanding {},
                             class: "px-8 py-3 bg-primary/90 backdrop-blur-sm text-dark-50 rounded-xl
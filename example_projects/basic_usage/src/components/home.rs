@@ -310,8 +310,10 @@ fn OrbitalSystem() -> Element {
                     .map(|(i, (name, orbit_radius, size))| {
                         let angle = crate_positions.read()[i].get();
                         let rotation = crate_rotations.read()[i].get();
-                        let x = orbit_radius * angle.cos();
-                        let y = orbit_radius * angle.sin();
+                        let transform = Transform {
+                            rotation,
+                            ..Transform::orbit(0.0, 0.0, *orbit_radius, angle)
+                        };
                         rsx! {
                             // Orbit path
                             div {
@@ -322,7 +324,7 @@ fn OrbitalSystem() -> Element {
                             div {
                                 key: "crate-{i}",
                                 class: "absolute left-1/2 top-1/2 bg-gradient-to-br from-orange-400 to-red-500 rounded-lg flex items-center justify-center shadow-lg",
-                                style: "transform: translate({x}px, {y}px) rotate({rotation}rad); width: {size}px; height: {size}px;",
+                                style: "transform: {transform.to_css_string()}; width: {size}px; height: {size}px;",
                                 span { class: "text-[8px] font-semibold text-white", "{name}" }
                             }
                         }
@@ -0,0 +1,174 @@
+//! Colors: conversions, mixing, color channels and the light or dark scheme
+
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+
+use crate::support::{act, advance, keep, kept, mount, near};
+
+/// Whether every channel of `a` is within `tolerance` of `b`
+fn close(a: Color, b: Color, tolerance: f32) -> bool {
+    near(a.r, b.r, tolerance)
+        && near(a.g, b.g, tolerance)
+        && near(a.b, b.b, tolerance)
+        && near(a.a, b.a, tolerance)
+}
+
+fn tween() -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(100)),
+    ))
+}
+
+#[test]
+fn named_colors_are_constants() {
+    const PALETTE: [Color; 10] = [
+        Color::transparent(),
+        Color::black(),
+        Color::white(),
+        Color::red(),
+        Color::green(),
+        Color::blue(),
+        Color::yellow(),
+        Color::cyan(),
+        Color::magenta(),
+        Color::gray(),
+    ];
+    assert_eq!(PALETTE[0].a, 0.0);
+    assert_eq!(Color::from_rgba(255, 255, 0, 255), Color::yellow());
+    assert_eq!(Color::new(0.0, 1.0, 1.0, 1.0), Color::cyan());
+    assert_eq!(Color::magenta().to_rgba(), (255, 0, 255, 255));
+}
+
+#[test]
+fn colors_convert_to_and_from_text() {
+    assert_eq!(Color::from_hex("#f80"), Color::from_hex("#FF8800"));
+    assert_eq!(
+        Color::from_hex("#ff000080").map(|c| c.to_rgba()),
+        Ok((255, 0, 0, 128))
+    );
+    assert!(Color::from_hex("#12").is_err());
+
+    assert_eq!(Color::gray().to_hex_string(), "#808080");
+    assert_eq!(
+        Color::black().with_alpha(0.5).to_css_string(),
+        "rgba(0, 0, 0, 0.5)"
+    );
+    assert_eq!(Color::black().to_oklch_string(), "oklch(0% 0 0)");
+    assert!(Color::black()
+        .with_alpha(0.5)
+        .to_oklch_string()
+        .ends_with("/ 0.5)"));
+}
+
+#[test]
+fn colors_convert_between_spaces() {
+    let (hue, saturation, lightness) = Color::blue().to_hsl();
+    assert_eq!((hue, saturation, lightness), (240.0, 1.0, 0.5));
+    assert!(close(
+        Color::from_hsl(hue, saturation, lightness, 1.0),
+        Color::blue(),
+        1e-4
+    ));
+
+    let (lightness, chroma, hue) = Color::red().to_oklch();
+    assert!(lightness > 0.6 && chroma > 0.2);
+    assert!(close(
+        Color::from_oklch(lightness, chroma, hue, 1.0),
+        Color::red(),
+        1e-3
+    ));
+}
+
+#[test]
+fn colors_adjust_one_channel() {
+    assert_eq!(Color::red().with_alpha(0.25).a, 0.25);
+    assert!(close(
+        Color::red().with_lightness(0.25),
+        Color::new(0.5, 0.0, 0.0, 1.0),
+        1e-4
+    ));
+    assert!(Color::red().rotate_hue(240.0).is_some_and(|turned| close(
+        turned,
+        Color::blue(),
+        1e-4
+    )));
+    // Grays have no hue to turn
+    assert_eq!(Color::white().rotate_hue(90.0), None);
+}
+
+#[test]
+fn mixes_pick_a_space_and_a_way_round() {
+    // Halfway from red to blue in RGB is a dull purple...
+    let rgb = Color::red().mix(&Color::blue(), 0.5, ColorMix::default());
+    assert!(close(rgb, Color::new(0.5, 0.0, 0.5, 1.0), 1e-4));
+    // ...and a bright magenta the short way round the HSL wheel
+    let hsl = ColorMix {
+        space: ColorSpace::Hsl,
+        hue: HueDirection::Shorter,
+    };
+    assert!(close(
+        Color::red().mix(&Color::blue(), 0.5, hsl),
+        Color::magenta(),
+        1e-4
+    ));
+
+    let oklch = ColorMix {
+        space: ColorSpace::Oklch,
+        hue: HueDirection::Shorter,
+    };
+    let (lightness, _, _) = Color::red().mix(&Color::blue(), 0.5, oklch).to_oklch();
+    assert!(lightness > 0.5);
+
+    assert_eq!(HueDirection::Shorter.interpolate(350.0, 10.0, 0.5), 0.0);
+    assert_eq!(HueDirection::Longer.interpolate(350.0, 10.0, 0.5), 180.0);
+    assert_eq!(HueDirection::Increasing.interpolate(90.0, 0.0, 0.5), 225.0);
+    assert_eq!(HueDirection::Decreasing.interpolate(0.0, 90.0, 0.5), 225.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn color_values_animate_one_channel() {
+    let mut dom = mount(|| {
+        keep(use_motion(Color::red()));
+        rsx! {}
+    });
+    let mut color: MotionValue<Color> = kept();
+    act(&mut dom, || color.animate_alpha_to(0.5, tween()));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(dom.in_runtime(|| color.get()), Color::red().with_alpha(0.5));
+
+    act(&mut dom, || color.animate_lightness_to(0.25, tween()));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert!(near(dom.in_runtime(|| color.get()).to_hsl().2, 0.25, 1e-3));
+
+    act(&mut dom, || color.animate_hue_by(120.0, tween()));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert!(near(dom.in_runtime(|| color.get()).to_hsl().0, 120.0, 0.1));
+
+    // A gray has no hue, so there is nothing to animate
+    act(&mut dom, || color.set(Color::gray()));
+    let token = act(&mut dom, || color.animate_hue_by(120.0, tween()));
+    assert!(!token.is_active());
+}
+
+#[tokio::test(start_paused = true)]
+async fn values_follow_the_color_scheme() {
+    fn app() -> Element {
+        let background = use_scheme_motion(Color::white(), Color::black(), tween());
+        keep((background, use_color_scheme()));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let (background, scheme): (MotionValue<Color>, Signal<ColorScheme>) = kept();
+    assert_eq!(dom.in_runtime(|| *scheme.read()), ColorScheme::Light);
+    assert_eq!(ColorScheme::Dark.pick("light", "dark"), "dark");
+
+    // Normally called from an event handler, inside the app's scopes
+    act(&mut dom, || {
+        ScopeId::ROOT.in_runtime(|| set_color_scheme(ColorScheme::Dark))
+    });
+    advance(&mut dom, Duration::from_millis(50)).await;
+    assert!(dom.in_runtime(|| background.is_animating()));
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(dom.in_runtime(|| background.get()), Color::black());
+}
@@ -0,0 +1,866 @@
+//! Checks that every public item of the library has a recipe
+//!
+//! The library's sources are scanned for public items: free functions, types,
+//! traits and constants at the top level of a file, and the `pub fn`s of their
+//! `impl` blocks as `Type::method`. Each one is listed either in [`CHECKLIST`],
+//! with the module whose recipes use it, or in [`GAPS`], with the reason it
+//! has none. A new item fails [`every_public_item_has_a_recipe`] until it is
+//! listed.
+//!
+//! The scan reads lines, not Rust: items must start their line, at column 0
+//! or, in an `impl` block, at column 4, and a file's `#[cfg(test)]` module
+//! ends its scan.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// The library's sources
+const LIBRARY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../src");
+
+/// The recipes
+const RECIPES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// Public items and the module whose recipes use them
+const CHECKLIST: &[(&str, &str)] = &[
+    ("AmbientPolicy", "motion_values"),
+    ("AmbientPolicy::never", "motion_values"),
+    ("AmbientPolicy::resolve", "motion_values"),
+    ("AmbientReduction", "motion_values"),
+    ("AmbientReduction::and", "motion_values"),
+    ("AmbientReduction::frame_rate", "motion_values"),
+    ("AmbientReduction::jump", "motion_values"),
+    ("AmbientReduction::none", "motion_values"),
+    ("AmbientReduction::passes", "motion_values"),
+    ("AmbientReduction::skip", "motion_values"),
+    ("Animatable", "geometry"),
+    ("AnimatedDialog", "transitions"),
+    ("AnimatedShow", "transitions"),
+    ("AnimatedSwitch", "transitions"),
+    ("Animation", "engine"),
+    ("AnimationConfig", "colors"),
+    ("AnimationConfig::get_duration", "migration"),
+    ("AnimationConfig::new", "colors"),
+    ("AnimationConfig::validate", "motion_values"),
+    ("AnimationConfig::with_delay", "motion_values"),
+    ("AnimationConfig::with_loop", "motion_values"),
+    ("AnimationConfig::with_on_complete", "groups"),
+    ("AnimationEngine", "engine"),
+    ("AnimationEngine::add_completion_callback", "engine"),
+    ("AnimationEngine::add_interrupt_callback", "engine"),
+    ("AnimationEngine::add_rest_callback", "engine"),
+    ("AnimationEngine::apply_custom", "engine"),
+    ("AnimationEngine::apply_keyframes", "engine"),
+    ("AnimationEngine::apply_sequence", "sequences"),
+    ("AnimationEngine::cancel", "engine"),
+    ("AnimationEngine::chase", "engine"),
+    ("AnimationEngine::clear_trace", "engine"),
+    ("AnimationEngine::default_animation", "engine"),
+    ("AnimationEngine::get", "engine"),
+    ("AnimationEngine::has_ready_callbacks", "engine"),
+    ("AnimationEngine::is_active", "engine"),
+    ("AnimationEngine::label", "engine"),
+    ("AnimationEngine::new", "engine"),
+    ("AnimationEngine::progress", "engine"),
+    ("AnimationEngine::queue_to", "engine"),
+    ("AnimationEngine::remaining_time", "engine"),
+    ("AnimationEngine::retune_spring", "engine"),
+    ("AnimationEngine::run_token", "engine"),
+    ("AnimationEngine::set", "engine"),
+    ("AnimationEngine::set_trace_capacity", "engine"),
+    ("AnimationEngine::spring_to", "engine"),
+    ("AnimationEngine::spring_with_noise", "springs"),
+    ("AnimationEngine::stop", "engine"),
+    ("AnimationEngine::stop_at_cycle_end", "engine"),
+    ("AnimationEngine::take_ready_callbacks", "engine"),
+    ("AnimationEngine::target", "engine"),
+    ("AnimationEngine::teardown", "engine"),
+    ("AnimationEngine::total_duration", "engine"),
+    ("AnimationEngine::trace", "engine"),
+    ("AnimationEngine::tween_to", "engine"),
+    ("AnimationEngine::update", "engine"),
+    ("AnimationEngine::velocity", "engine"),
+    ("AnimationKind", "events"),
+    ("AnimationManager", "migration"),
+    ("AnimationMode", "colors"),
+    ("AnimationSequence", "sequences"),
+    ("AnimationSequence::build", "sequences"),
+    ("AnimationSequence::new", "sequences"),
+    ("AnimationSequence::on_complete", "sequences"),
+    ("AnimationSequence::start", "sequences"),
+    ("AnimationSequence::then", "sequences"),
+    ("AnimationSequence::wait", "sequences"),
+    ("AnimationSequence::with_capacity", "migration"),
+    ("AnimationState", "engine"),
+    ("AnimationTiming", "keyframes"),
+    ("AnimationTiming::finish_current_loop", "timing"),
+    ("AnimationTiming::handle_delay", "timing"),
+    ("AnimationTiming::handle_loop_completion", "timing"),
+    ("AnimationTiming::is_reverse", "timing"),
+    ("AnimationTiming::new", "keyframes"),
+    ("AnimationTiming::remaining_delay", "timing"),
+    ("AnimationTiming::remaining_loops", "timing"),
+    ("AnimationTiming::total_loops", "timing"),
+    ("AnimationTiming::with_delay", "timing"),
+    ("AnimationTiming::with_direction", "timing"),
+    ("AnimationTiming::with_loop_mode", "keyframes"),
+    ("AnimationTiming::with_on_complete", "timing"),
+    ("AnimationToken", "engine"),
+    ("AnimationToken::finished", "engine"),
+    ("AnimationToken::id", "engine"),
+    ("AnimationToken::is_active", "engine"),
+    ("AnimationToken::outcome", "engine"),
+    ("BounceBounds", "springs"),
+    ("BounceBounds::new", "springs"),
+    ("Channel", "keyframes"),
+    ("Clip", "keyframes"),
+    ("Clip::channel", "keyframes"),
+    ("Clip::duration", "keyframes"),
+    ("Clip::loop_mode", "keyframes"),
+    ("Clip::new", "keyframes"),
+    ("Clip::validate", "keyframes"),
+    ("ClipError", "keyframes"),
+    ("ClipKey", "keyframes"),
+    ("ClipKey::easing", "keyframes"),
+    ("ClipKey::new", "keyframes"),
+    ("ClipPlayer", "keyframes"),
+    ("ClipPlayer::bind", "keyframes"),
+    ("ClipPlayer::clip", "keyframes"),
+    ("ClipPlayer::is_playing", "keyframes"),
+    ("ClipPlayer::new", "keyframes"),
+    ("ClipPlayer::pause", "keyframes"),
+    ("ClipPlayer::play", "keyframes"),
+    ("ClipPlayer::seek", "keyframes"),
+    ("ClipPlayer::time", "keyframes"),
+    ("ClipValue", "keyframes"),
+    ("Color", "colors"),
+    ("Color::black", "colors"),
+    ("Color::blue", "colors"),
+    ("Color::cyan", "colors"),
+    ("Color::from_hex", "colors"),
+    ("Color::from_hsl", "colors"),
+    ("Color::from_oklch", "colors"),
+    ("Color::from_rgba", "colors"),
+    ("Color::gray", "colors"),
+    ("Color::green", "colors"),
+    ("Color::magenta", "colors"),
+    ("Color::mix", "colors"),
+    ("Color::new", "colors"),
+    ("Color::red", "colors"),
+    ("Color::rotate_hue", "colors"),
+    ("Color::to_css_string", "colors"),
+    ("Color::to_hex_string", "colors"),
+    ("Color::to_hsl", "colors"),
+    ("Color::to_oklch", "colors"),
+    ("Color::to_oklch_string", "colors"),
+    ("Color::to_rgba", "colors"),
+    ("Color::transparent", "colors"),
+    ("Color::white", "colors"),
+    ("Color::with_alpha", "colors"),
+    ("Color::with_lightness", "colors"),
+    ("Color::yellow", "colors"),
+    ("ColorMix", "colors"),
+    ("ColorScheme", "colors"),
+    ("ColorScheme::pick", "colors"),
+    ("ColorSpace", "colors"),
+    ("ConfigError", "keyframes"),
+    ("Crossfade", "groups"),
+    ("Crossfade::current", "groups"),
+    ("Crossfade::current_opacity", "groups"),
+    ("Crossfade::is_animating", "groups"),
+    ("Crossfade::previous", "groups"),
+    ("Crossfade::previous_opacity", "groups"),
+    ("Crossfade::progress", "groups"),
+    ("CubicBezier", "keyframes"),
+    ("CubicBezier::ease", "keyframes"),
+    ("CubicBezier::new", "keyframes"),
+    ("CubicBezier::points", "keyframes"),
+    ("DEFAULT_TRACE_CAPACITY", "engine"),
+    ("EasingFunction", "tweens"),
+    ("Entrance", "groups"),
+    ("Entrance::delay_for", "groups"),
+    ("Entrance::fade", "groups"),
+    ("Entrance::hidden", "groups"),
+    ("Entrance::new", "groups"),
+    ("Entrance::rise", "groups"),
+    ("Entrance::shown", "groups"),
+    ("Entrance::spring", "groups"),
+    ("Entrance::stagger", "groups"),
+    ("FIXED_DT_VAR", "motion_values"),
+    ("Filter", "geometry"),
+    ("Filter::blur", "geometry"),
+    ("Filter::brightness", "geometry"),
+    ("Filter::grayscale", "geometry"),
+    ("Filter::is_none", "geometry"),
+    ("Filter::none", "geometry"),
+    ("Filter::saturate", "geometry"),
+    ("Filter::to_css_string", "geometry"),
+    ("Filter::with_blur", "geometry"),
+    ("Filter::with_brightness", "geometry"),
+    ("Filter::with_grayscale", "geometry"),
+    ("Filter::with_saturate", "geometry"),
+    ("Finished", "engine"),
+    ("GroupProgress", "groups"),
+    ("HoverIntent", "interaction"),
+    ("HoverIntent::delay", "interaction"),
+    ("HoverIntent::enter", "interaction"),
+    ("HoverIntent::is_active", "interaction"),
+    ("HoverIntent::leave", "interaction"),
+    ("HueDirection", "colors"),
+    ("HueDirection::interpolate", "colors"),
+    ("InterpMode", "keyframes"),
+    ("Keyframe", "keyframes"),
+    ("Keyframe::new", "keyframes"),
+    ("Keyframe::with_easing", "keyframes"),
+    ("KeyframeAnimation", "engine"),
+    ("KeyframeAnimation::at", "engine"),
+    ("KeyframeAnimation::at_with_bezier", "keyframes"),
+    ("KeyframeAnimation::at_with_easing", "keyframes"),
+    ("KeyframeAnimation::at_with_interp", "keyframes"),
+    ("KeyframeAnimation::at_with_spring", "keyframes"),
+    ("KeyframeAnimation::color_space", "keyframes"),
+    ("KeyframeAnimation::default_easing", "keyframes"),
+    ("KeyframeAnimation::default_interp", "keyframes"),
+    ("KeyframeAnimation::delay", "interaction"),
+    ("KeyframeAnimation::direction", "keyframes"),
+    ("KeyframeAnimation::duration", "interaction"),
+    ("KeyframeAnimation::ease_between", "keyframes"),
+    ("KeyframeAnimation::for_duration", "keyframes"),
+    ("KeyframeAnimation::hue_direction", "keyframes"),
+    ("KeyframeAnimation::looping", "engine"),
+    ("KeyframeAnimation::new", "engine"),
+    ("KeyframeAnimation::on_complete", "engine"),
+    ("KeyframeAnimation::sample", "keyframes"),
+    ("KeyframeAnimation::start", "keyframes"),
+    ("KeyframeAnimation::timing", "keyframes"),
+    ("KeyframeAnimation::try_start", "keyframes"),
+    ("KeyframeAnimation::validate", "keyframes"),
+    ("KeyframeTrack", "keyframes"),
+    ("KeyframeTrack::at", "keyframes"),
+    ("KeyframeTrack::at_with_easing", "keyframes"),
+    ("KeyframeTrack::create_animation", "keyframes"),
+    ("KeyframeTrack::duration", "keyframes"),
+    ("KeyframeTrack::new", "keyframes"),
+    ("KeyframeTrack::validate", "keyframes"),
+    ("Length", "geometry"),
+    ("Length::new", "geometry"),
+    ("Length::percent", "geometry"),
+    ("Length::px", "geometry"),
+    ("Length::rem", "geometry"),
+    ("Length::to_css_string", "geometry"),
+    ("Length::vw", "geometry"),
+    ("LengthUnit", "geometry"),
+    ("LengthUnit::as_str", "geometry"),
+    ("LoopMode", "engine"),
+    ("LoopMode::alternates", "timing"),
+    ("MagneticSnap", "interaction"),
+    ("MagneticSnap::biased", "interaction"),
+    ("MagneticSnap::detents", "interaction"),
+    ("MagneticSnap::nearest", "interaction"),
+    ("MagneticSnap::new", "interaction"),
+    ("MagneticSnap::on_snap", "interaction"),
+    ("MagneticSnap::release", "interaction"),
+    ("MagneticSnap::spring", "interaction"),
+    ("MagneticSnap::track", "interaction"),
+    ("MaybeSend", "engine"),
+    ("MotionBundle", "groups"),
+    ("MotionBundle::animate_to", "groups"),
+    ("MotionBundle::animate_to_with_config", "groups"),
+    ("MotionBundle::get", "groups"),
+    ("MotionBundle::get_index", "groups"),
+    ("MotionBundle::index", "groups"),
+    ("MotionBundle::is_animating", "groups"),
+    ("MotionBundle::names", "groups"),
+    ("MotionBundle::new", "groups"),
+    ("MotionBundle::progress", "groups"),
+    ("MotionBundle::progress_by", "groups"),
+    ("MotionBundle::stop", "groups"),
+    ("MotionBundle::values", "groups"),
+    ("MotionConfig", "motion_values"),
+    ("MotionConfig::deterministic_mode", "motion_values"),
+    ("MotionConfig::new", "motion_values"),
+    ("MotionConfig::with_ambient_policy", "motion_values"),
+    ("MotionConfig::with_default_animation", "motion_values"),
+    ("MotionEvent", "events"),
+    ("MotionEvent::label", "events"),
+    ("MotionEvents", "events"),
+    ("MotionEvents::drain", "events"),
+    ("MotionEvents::dropped", "events"),
+    ("MotionEvents::recv", "events"),
+    ("MotionEvents::try_next", "events"),
+    ("MotionEvents::with_unlabeled", "events"),
+    ("MotionHarness", "motion_values"),
+    ("MotionHarness::act", "motion_values"),
+    ("MotionHarness::dom", "motion_values"),
+    ("MotionHarness::motion_value", "motion_values"),
+    ("MotionHarness::motion_values", "motion_values"),
+    ("MotionHarness::tick", "motion_values"),
+    ("MotionSnapshot", "motion_values"),
+    ("MotionSnapshot::is_animating", "motion_values"),
+    ("MotionSnapshot::label", "motion_values"),
+    ("MotionSnapshot::progress", "motion_values"),
+    ("MotionSnapshot::target", "motion_values"),
+    ("MotionSnapshot::value", "motion_values"),
+    ("MotionStats", "motion_values"),
+    ("MotionStore", "groups"),
+    ("MotionStore::get", "groups"),
+    ("MotionStore::insert", "groups"),
+    ("MotionStore::new", "groups"),
+    ("MotionStore::remove", "groups"),
+    ("MotionStyle", "geometry"),
+    ("MotionStyle::disable_pointer_while_animating", "geometry"),
+    ("MotionStyle::disables_pointer_while_animating", "geometry"),
+    ("MotionStyle::filter", "geometry"),
+    ("MotionStyle::hidden", "geometry"),
+    ("MotionStyle::new", "geometry"),
+    ("MotionStyle::opacity", "geometry"),
+    ("MotionStyle::raw_opacity", "geometry"),
+    ("MotionStyle::to_css_string", "geometry"),
+    ("MotionStyle::to_css_string_animating", "geometry"),
+    ("MotionStyle::visible", "geometry"),
+    ("MotionStyle::with_filter", "geometry"),
+    ("MotionTime", "motion_values"),
+    ("MotionTimeScale", "motion_values"),
+    ("MotionValue", "colors"),
+    ("MotionValue::animate_alpha_to", "colors"),
+    ("MotionValue::animate_by", "motion_values"),
+    ("MotionValue::animate_custom", "motion_values"),
+    ("MotionValue::animate_from_to", "motion_values"),
+    ("MotionValue::animate_hue_by", "colors"),
+    ("MotionValue::animate_keyframes", "events"),
+    ("MotionValue::animate_lightness_to", "colors"),
+    ("MotionValue::animate_to", "groups"),
+    ("MotionValue::animate_to_if_changed", "motion_values"),
+    ("MotionValue::animate_to_with_config", "events"),
+    ("MotionValue::blur_to", "geometry"),
+    ("MotionValue::brightness_to", "geometry"),
+    ("MotionValue::cancel", "motion_values"),
+    ("MotionValue::chain", "groups"),
+    ("MotionValue::follow", "interaction"),
+    ("MotionValue::get", "colors"),
+    ("MotionValue::grayscale_to", "geometry"),
+    ("MotionValue::interrupt_to", "interaction"),
+    ("MotionValue::is_animating", "colors"),
+    ("MotionValue::keyframes", "keyframes"),
+    ("MotionValue::new", "colors"),
+    ("MotionValue::peek", "motion_values"),
+    ("MotionValue::progress", "events"),
+    ("MotionValue::queue", "motion_values"),
+    ("MotionValue::remaining_time", "motion_values"),
+    ("MotionValue::rotation", "geometry"),
+    ("MotionValue::saturate_to", "geometry"),
+    ("MotionValue::scale_x", "geometry"),
+    ("MotionValue::scale_y", "geometry"),
+    ("MotionValue::sequence", "sequences"),
+    ("MotionValue::set", "colors"),
+    ("MotionValue::set_priority", "motion_values"),
+    ("MotionValue::set_trace_capacity", "motion_values"),
+    ("MotionValue::skew_x", "geometry"),
+    ("MotionValue::skew_y", "geometry"),
+    ("MotionValue::spring", "interaction"),
+    ("MotionValue::stagger", "groups"),
+    ("MotionValue::stop", "events"),
+    ("MotionValue::stop_at_cycle_end", "motion_values"),
+    ("MotionValue::to_css_string", "colors"),
+    ("MotionValue::token", "colors"),
+    ("MotionValue::total_duration", "groups"),
+    ("MotionValue::trace", "motion_values"),
+    ("MotionValue::tween", "colors"),
+    ("MotionValue::velocity", "motion_values"),
+    ("MotionValue::x", "geometry"),
+    ("MotionValue::y", "geometry"),
+    ("NextEvent", "events"),
+    ("Noise", "springs"),
+    ("Noise::frequency", "springs"),
+    ("Noise::new", "springs"),
+    ("Noise::offset", "springs"),
+    ("PlaybackDirection", "keyframes"),
+    ("PowerPreferences", "motion_values"),
+    ("Presence", "transitions"),
+    ("Presence::hide", "transitions"),
+    ("Presence::is_animating", "transitions"),
+    ("Presence::is_mounted", "transitions"),
+    ("Presence::new", "transitions"),
+    ("Presence::settle", "transitions"),
+    ("Presence::show", "transitions"),
+    ("Priority", "motion_values"),
+    ("Rotation3D", "geometry"),
+    ("Rotation3D::axis_angle", "geometry"),
+    ("Rotation3D::from_degrees", "geometry"),
+    ("Rotation3D::identity", "geometry"),
+    ("Rotation3D::new", "geometry"),
+    ("Rotation3D::rotation_mode", "geometry"),
+    ("Rotation3D::to_css_string", "geometry"),
+    ("RotationMode", "geometry"),
+    ("RotationWinding", "geometry"),
+    ("RotationWinding::delta", "geometry"),
+    ("RunOutcome", "engine"),
+    ("ScrollMetrics", "interaction"),
+    ("ScrollRange", "interaction"),
+    ("ScrollRange::progress", "interaction"),
+    ("ScrubHandle", "interaction"),
+    ("ScrubHandle::progress", "interaction"),
+    ("ScrubHandle::range", "interaction"),
+    ("ScrubHandle::update", "interaction"),
+    ("SegmentCurve", "keyframes"),
+    ("SequenceBuilder", "sequences"),
+    ("SequenceBuilder::on_complete", "sequences"),
+    ("SequenceBuilder::start", "sequences"),
+    ("SequenceBuilder::then", "sequences"),
+    ("SequenceBuilder::wait", "sequences"),
+    ("Shimmer", "transitions"),
+    ("SimpleMotion", "motion_values"),
+    ("SimpleMotion::animate_to", "motion_values"),
+    ("SimpleMotion::get", "motion_values"),
+    ("SimpleMotion::is_animating", "motion_values"),
+    ("SimpleMotion::peek", "motion_values"),
+    ("SimpleMotion::set", "motion_values"),
+    ("SimpleMotion::spring_to", "motion_values"),
+    ("SimpleMotion::stop", "motion_values"),
+    ("SimpleMotion::tween_to", "motion_values"),
+    ("Spring", "engine"),
+    ("Spring::create_animation", "sequences"),
+    ("Spring::damping", "interaction"),
+    ("Spring::damping_ratio", "springs"),
+    ("Spring::evaluate", "springs"),
+    ("Spring::frequency", "springs"),
+    ("Spring::initial_velocity", "springs"),
+    ("Spring::mass", "interaction"),
+    ("Spring::max_excursion", "springs"),
+    ("Spring::new", "engine"),
+    ("Spring::sample", "springs"),
+    ("Spring::settle_time", "springs"),
+    ("Spring::snappy", "engine"),
+    ("Spring::stiffness", "interaction"),
+    ("Spring::validate", "motion_values"),
+    ("Spring::weight", "springs"),
+    ("SpringAnimation", "springs"),
+    ("SpringAnimation::new", "springs"),
+    ("SpringAnimation::with_bounds", "springs"),
+    ("SpringAnimation::with_noise", "springs"),
+    ("SpringBuilder", "interaction"),
+    ("SpringBuilder::ambient", "motion_values"),
+    ("SpringBuilder::animate_to", "interaction"),
+    ("SpringBuilder::bounce_bounds", "springs"),
+    ("SpringBuilder::build", "springs"),
+    ("SpringBuilder::by", "motion_values"),
+    ("SpringBuilder::damping", "interaction"),
+    ("SpringBuilder::delay", "springs"),
+    ("SpringBuilder::loop_mode", "springs"),
+    ("SpringBuilder::mass", "interaction"),
+    ("SpringBuilder::noise", "springs"),
+    ("SpringBuilder::on_complete", "springs"),
+    ("SpringBuilder::on_direction_change", "springs"),
+    ("SpringBuilder::on_interrupt", "springs"),
+    ("SpringBuilder::on_rest", "springs"),
+    ("SpringBuilder::preserve_velocity", "springs"),
+    ("SpringBuilder::rest_when", "springs"),
+    ("SpringBuilder::spring_tunable", "interaction"),
+    ("SpringBuilder::stiffness", "interaction"),
+    ("SpringBuilder::to", "springs"),
+    ("SpringBuilder::velocity", "springs"),
+    ("SpringBuilder::weight", "springs"),
+    ("SpringParams", "interaction"),
+    ("SpringParams::apply", "interaction"),
+    ("SpringParams::of", "interaction"),
+    ("StaggerAnimation", "groups"),
+    ("StaggerAnimation::new", "groups"),
+    ("StaggerAnimation::total_duration", "groups"),
+    ("StaggerAnimation::with_direction", "groups"),
+    ("StaggerBuilder", "groups"),
+    ("StaggerBuilder::animate_to", "groups"),
+    ("StaggerBuilder::build", "groups"),
+    ("StaggerBuilder::direction", "groups"),
+    ("StaggerBuilder::duration", "groups"),
+    ("StaggerBuilder::each", "groups"),
+    ("StaggerBuilder::easing", "groups"),
+    ("StaggerBuilder::exit_reverse", "groups"),
+    ("StaggerBuilder::on_complete", "groups"),
+    ("StaggerDirection", "groups"),
+    ("SwitchState", "transitions"),
+    ("SwitchState::current", "transitions"),
+    ("SwitchState::leaving", "transitions"),
+    ("SwitchState::new", "transitions"),
+    ("SwitchState::settle", "transitions"),
+    ("SwitchState::switch", "transitions"),
+    ("TimeProvider", "motion_values"),
+    ("TraceSample", "engine"),
+    ("Transform", "geometry"),
+    ("Transform::combine", "geometry"),
+    ("Transform::from_degrees", "geometry"),
+    ("Transform::identity", "geometry"),
+    ("Transform::new", "geometry"),
+    ("Transform::orbit", "geometry"),
+    ("Transform::rotate", "geometry"),
+    ("Transform::rotate_around", "geometry"),
+    ("Transform::rotate_degrees", "geometry"),
+    ("Transform::scale", "geometry"),
+    ("Transform::scale_uniform", "geometry"),
+    ("Transform::skew", "geometry"),
+    ("Transform::skew_degrees", "geometry"),
+    ("Transform::to_css_string", "geometry"),
+    ("Transform::translate", "geometry"),
+    ("TransitionConfig", "transitions"),
+    ("TransitionPhase", "transitions"),
+    ("TransitionVariant", "transitions"),
+    ("TransitionVariant::default_timing", "transitions"),
+    ("TransitionVariant::get_config", "transitions"),
+    ("TransitionVariant::resolve_timing", "transitions"),
+    ("TunableSpring", "interaction"),
+    ("TunableSpring::name", "interaction"),
+    ("TunableSpring::params", "interaction"),
+    ("TunableSpring::reset", "interaction"),
+    ("TunableSpring::retargets", "interaction"),
+    ("TunableSpring::set_damping", "interaction"),
+    ("TunableSpring::set_mass", "interaction"),
+    ("TunableSpring::set_params", "interaction"),
+    ("TunableSpring::set_retarget", "interaction"),
+    ("TunableSpring::set_stiffness", "interaction"),
+    ("TunableSpring::spring", "interaction"),
+    ("TuningPanel", "interaction"),
+    ("Tween", "colors"),
+    ("Tween::create_animation", "sequences"),
+    ("Tween::duration", "colors"),
+    ("Tween::easing", "groups"),
+    ("Tween::new", "colors"),
+    ("Tween::validate", "motion_values"),
+    ("Tween::winding", "tweens"),
+    ("Tween::with_easing", "migration"),
+    ("TweenAnimation", "tweens"),
+    ("TweenAnimation::new", "tweens"),
+    ("TweenBuilder", "tweens"),
+    ("TweenBuilder::ambient", "motion_values"),
+    ("TweenBuilder::animate_to", "tweens"),
+    ("TweenBuilder::build", "tweens"),
+    ("TweenBuilder::by", "motion_values"),
+    ("TweenBuilder::duration", "tweens"),
+    ("TweenBuilder::easing", "tweens"),
+    ("TweenBuilder::into_sequence", "tweens"),
+    ("TweenBuilder::loop_mode", "timing"),
+    ("TweenBuilder::on_complete", "tweens"),
+    ("TweenBuilder::to", "tweens"),
+    ("TweenBuilder::winding", "tweens"),
+    ("Weight", "springs"),
+    ("Weight::mass", "springs"),
+    ("easing_named", "keyframes"),
+    ("fade_in", "motion_values"),
+    ("fade_out", "motion_values"),
+    ("is_paused", "motion_values"),
+    ("join_complete", "groups"),
+    ("motion_stats", "motion_values"),
+    ("motion_test_harness", "motion_values"),
+    ("pause_all", "motion_values"),
+    ("play_transition", "transitions"),
+    ("pop", "motion_values"),
+    ("pulse", "motion_values"),
+    ("resume_all", "motion_values"),
+    ("sequence", "sequences"),
+    ("set_animation_cap", "motion_values"),
+    ("set_color_scheme", "colors"),
+    ("set_completion_budget", "motion_values"),
+    ("set_frame_budget", "motion_values"),
+    ("set_power_preferences", "motion_values"),
+    ("shake", "events"),
+    ("slide_up", "motion_values"),
+    ("split_easing", "tweens"),
+    ("subscribe_events", "events"),
+    ("transition_transform", "transitions"),
+    ("tunables", "interaction"),
+    ("use_clip_player", "keyframes"),
+    ("use_color_scheme", "colors"),
+    ("use_crossfade", "groups"),
+    ("use_entrance", "groups"),
+    ("use_entrance_on", "groups"),
+    ("use_hover_intent", "interaction"),
+    ("use_hover_motion", "interaction"),
+    ("use_motion", "colors"),
+    ("use_motion_bundle", "groups"),
+    ("use_motion_config", "motion_values"),
+    ("use_motion_labeled", "events"),
+    ("use_motion_styles", "groups"),
+    ("use_motion_with", "motion_values"),
+    ("use_persistent_motion", "groups"),
+    ("use_power_preferences", "motion_values"),
+    ("use_retuning", "interaction"),
+    ("use_scheme_motion", "colors"),
+    ("use_scroll_timeline", "interaction"),
+    ("use_scrub", "interaction"),
+    ("use_simple_motion", "motion_values"),
+    ("use_tracked_motion", "interaction"),
+    ("use_tunable_spring", "interaction"),
+];
+
+/// Public items without a recipe, and why
+const GAPS: &[(&str, &str)] = &[
+    (
+        "AnimatableRoute",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "AnimatedOutlet",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "AnimatedRouterContext",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "AnimatedRouterContext::set_target_route",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "AnimatedRouterContext::settle",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "AnimatedRouterContext::target_route",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "AnimationEngine::run_animation_loop",
+        "never returns; the driver runs it",
+    ),
+    (
+        "AnimationStep",
+        "has no public constructor; sequences build their steps",
+    ),
+    (
+        "BundleAnimation",
+        "has no public constructor; bundles drive it",
+    ),
+    (
+        "MotionValue::bind_motion_style",
+        "needs a mounted element in a browser",
+    ),
+    (
+        "MotionValue::bind_style",
+        "needs a mounted element in a browser",
+    ),
+    (
+        "MotionValue::unbind_style",
+        "needs a mounted element in a browser",
+    ),
+    (
+        "RouteTransitions",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "RouteTransitions::resolve",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "RouteTransitions::transition",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "ScrubHandle::attach",
+        "needs a mounted element in a browser",
+    ),
+    (
+        "TransitionMap",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionOutlet",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionsBuilder",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionsBuilder::build",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionsBuilder::default",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionsBuilder::layout_depth",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionsBuilder::new",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionsBuilder::on",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionsBuilder::on_match",
+        "needs the `transitions` feature and a router",
+    ),
+    (
+        "TransitionsBuilder::on_prefix",
+        "needs the `transitions` feature and a router",
+    ),
+    ("request_animation_frame", "lives in a private module"),
+    (
+        "use_animated_router",
+        "needs the `transitions` feature and a router",
+    ),
+];
+
+/// Every public item of the library
+fn public_items() -> BTreeSet<String> {
+    let mut items = BTreeSet::new();
+    scan_dir(Path::new(LIBRARY), &mut items);
+    items
+}
+
+fn scan_dir(dir: &Path, items: &mut BTreeSet<String>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        unreachable!("the library sources are at {}", dir.display());
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            scan_dir(&path, items);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            scan_file(&read(&path), items);
+        }
+    }
+}
+
+fn scan_file(source: &str, items: &mut BTreeSet<String>) {
+    const FNS: &[&str] = &["pub fn ", "pub const fn ", "pub async fn "];
+    const METHODS: &[&str] = &["    pub fn ", "    pub const fn ", "    pub async fn "];
+    const TYPES: &[&str] = &[
+        "pub struct ",
+        "pub enum ",
+        "pub trait ",
+        "pub type ",
+        "pub const ",
+        "pub static ",
+    ];
+
+    let mut owner = None;
+    for line in source.lines() {
+        if line.starts_with("#[cfg(test)]") {
+            break;
+        }
+        if line.starts_with("impl") {
+            owner = impl_target(line);
+        } else if line.starts_with('}') {
+            owner = None;
+        } else if let Some(name) = item_name(line, FNS) {
+            items.insert(name.to_string());
+        } else if let Some(name) = item_name(line, METHODS) {
+            if let Some(owner) = owner {
+                items.insert(format!("{owner}::{name}"));
+            }
+        } else if let Some(name) = item_name(line, TYPES) {
+            items.insert(name.to_string());
+        }
+    }
+}
+
+/// The type an `impl` line adds to, e.g. `Foo` for `impl<T> Trait for Foo<T> {`
+fn impl_target(line: &str) -> Option<&str> {
+    let mut rest = &line["impl".len()..];
+    if rest.starts_with('<') {
+        let mut depth = 0;
+        let end = rest.find(|c| {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => {}
+            }
+            depth == 0
+        })?;
+        rest = &rest[end + 1..];
+    }
+    if let Some((_, target)) = rest.rsplit_once(" for ") {
+        rest = target;
+    }
+    let name = leading_ident(rest.trim_start());
+    (!name.is_empty()).then_some(name)
+}
+
+/// The name declared by `line` if it starts with one of `prefixes`
+fn item_name<'a>(line: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix))
+        .map(leading_ident)
+        .filter(|name| !name.is_empty())
+}
+
+fn leading_ident(text: &str) -> &str {
+    let end = text.find(|c: char| !is_ident(c)).unwrap_or(text.len());
+    &text[..end]
+}
+
+fn is_ident(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Whether `source` contains `word` with no identifier characters around it
+fn mentions(source: &str, word: &str) -> bool {
+    source.match_indices(word).any(|(start, _)| {
+        let before = source[..start].chars().next_back();
+        let after = source[start + word.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+fn read(path: &Path) -> String {
+    let Ok(source) = fs::read_to_string(path) else {
+        unreachable!("{} is readable", path.display());
+    };
+    source
+}
+
+fn listed() -> impl Iterator<Item = &'static str> {
+    CHECKLIST.iter().chain(GAPS).map(|(item, _)| *item)
+}
+
+#[test]
+fn every_public_item_has_a_recipe() {
+    let listed: BTreeSet<_> = listed().collect();
+    let missing: Vec<_> = public_items()
+        .into_iter()
+        .filter(|item| !listed.contains(item.as_str()))
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "add a recipe, then list these in CHECKLIST: {missing:#?}"
+    );
+}
+
+#[test]
+fn listed_items_exist_once() {
+    let items = public_items();
+    let mut seen = BTreeSet::new();
+    for item in listed() {
+        assert!(items.contains(item), "{item} is no longer public");
+        assert!(seen.insert(item), "{item} is listed twice");
+    }
+}
+
+#[test]
+fn recipes_mention_their_items() {
+    for (item, module) in CHECKLIST {
+        let source = read(&Path::new(RECIPES).join(format!("{module}.rs")));
+        // A method counts where both its type and its name appear
+        for part in item.split("::") {
+            assert!(mentions(&source, part), "{module} does not use {item}");
+        }
+    }
+}
+
+#[test]
+fn mentions_are_whole_words() {
+    assert!(mentions("motion.get()", "get"));
+    assert!(!mentions("motion.get_index(1)", "get"));
+    assert!(!mentions("forget", "get"));
+    assert_eq!(
+        impl_target("impl<T: Animatable> MotionValue<T> {"),
+        Some("MotionValue")
+    );
+    assert_eq!(
+        impl_target("impl<const N: usize> From<[f32; N]> for Arr<N> {"),
+        Some("Arr")
+    );
+}
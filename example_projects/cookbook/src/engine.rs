@@ -0,0 +1,223 @@
+//! The animation engine: runs, callbacks, tokens and custom animations
+
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::trace::DEFAULT_TRACE_CAPACITY;
+use dioxus_motion2::{Animation, AnimationState, Finished, MaybeSend, TraceSample};
+
+use crate::support::{near, run, run_to_rest, Calls, FRAME};
+
+/// Moves at a constant speed until it reaches its target
+struct Ramp {
+    value: f32,
+    target: f32,
+    speed: f32,
+}
+
+impl Animation for Ramp {
+    type Value = f32;
+
+    fn update(&mut self, dt: f32) -> (AnimationState, f32, f32) {
+        let step = self.speed * dt;
+        if (self.target - self.value).abs() <= step {
+            self.value = self.target;
+            return (AnimationState::Completed, self.value, 0.0);
+        }
+        self.value += step.copysign(self.target - self.value);
+        (AnimationState::Active, self.value, self.speed)
+    }
+
+    fn value(&self) -> f32 {
+        self.value
+    }
+
+    fn velocity(&self) -> f32 {
+        self.speed
+    }
+
+    fn reset(&mut self) {}
+
+    fn is_active(&self) -> bool {
+        self.value != self.target
+    }
+}
+
+/// Register `callback`, which must be sendable on native targets
+fn on_complete(engine: &mut AnimationEngine<f32>, callback: impl FnOnce() + MaybeSend + 'static) {
+    engine.add_completion_callback(callback);
+}
+
+#[test]
+fn engine_runs_springs_and_tweens() {
+    let mut engine = AnimationEngine::new(0.0f32);
+    assert_eq!(engine.label(), None);
+    assert!(matches!(
+        engine.default_animation(),
+        AnimationMode::Spring(_)
+    ));
+
+    engine.spring_to(10.0, Spring::snappy());
+    assert_eq!(engine.target(), Some(10.0));
+    assert!(engine.remaining_time().is_some());
+    assert!(engine.update(FRAME));
+    assert!(engine.velocity() > 0.0);
+    run_to_rest(&mut engine);
+    assert_eq!(engine.get(), 10.0);
+    assert!(!engine.is_active());
+    assert_eq!(engine.velocity(), 0.0);
+
+    engine.tween_to(0.0, Tween::new());
+    assert_eq!(engine.total_duration(), Some(Duration::from_millis(300)));
+    run(&mut engine, 9);
+    assert!(engine
+        .progress()
+        .is_some_and(|progress| near(progress, 0.5, 0.01)));
+
+    engine.set(5.0);
+    assert!(!engine.is_active());
+    assert_eq!(engine.get(), 5.0);
+}
+
+#[test]
+fn queued_calls_start_on_the_next_update() {
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.queue_to(10.0, AnimationMode::Tween(Tween::new()));
+    engine.queue_to(20.0, AnimationMode::Spring(Spring::snappy()));
+    // Nothing moves until the next update, and the last call wins
+    assert_eq!(engine.progress(), Some(0.0));
+    assert_eq!(engine.target(), Some(20.0));
+    run_to_rest(&mut engine);
+    assert_eq!(engine.get(), 20.0);
+}
+
+#[test]
+fn chase_and_retune_keep_the_run() {
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.chase(100.0, &Spring::default());
+    run(&mut engine, 5);
+    let token = engine.run_token();
+    engine.chase(200.0, &Spring::default());
+    assert_eq!(engine.run_token(), token);
+    assert!(engine.retune_spring(&Spring::snappy()));
+    run_to_rest(&mut engine);
+    assert_eq!(engine.get(), 200.0);
+    assert!(!engine.retune_spring(&Spring::default()));
+}
+
+#[test]
+fn callbacks_fire_by_how_the_run_ends() {
+    let (completed, rested, interrupted) = (Calls::default(), Calls::default(), Calls::default());
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.tween_to(1.0, Tween::new());
+    on_complete(&mut engine, completed.record());
+    engine.add_rest_callback(rested.record());
+    engine.add_interrupt_callback(interrupted.record());
+    run(&mut engine, 30);
+
+    // Callbacks wait for the caller to run them, outside the engine borrow
+    assert!(engine.has_ready_callbacks());
+    for callback in engine.take_ready_callbacks() {
+        callback();
+    }
+    assert_eq!(
+        (completed.count(), rested.count(), interrupted.count()),
+        (1, 1, 0)
+    );
+
+    engine.tween_to(0.0, Tween::new());
+    engine.add_interrupt_callback(interrupted.record());
+    engine.stop();
+    run_to_rest(&mut engine);
+    assert_eq!(interrupted.count(), 1);
+}
+
+#[test]
+fn tokens_cancel_only_their_own_run() {
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.tween_to(1.0, Tween::new());
+    let Some(first): Option<AnimationToken> = engine.run_token() else {
+        unreachable!("the tween is running");
+    };
+    assert!(first.is_active());
+    engine.tween_to(2.0, Tween::new());
+    assert_eq!(first.outcome(), Some(RunOutcome::Replaced));
+    assert!(!engine.cancel(&first));
+
+    let Some(second) = engine.run_token() else {
+        unreachable!("the tween is running");
+    };
+    assert_ne!(first.id(), second.id());
+    assert!(engine.cancel(&second));
+    assert_eq!(second.outcome(), Some(RunOutcome::Cancelled));
+}
+
+#[tokio::test]
+async fn tokens_can_be_awaited() {
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.tween_to(1.0, Tween::new());
+    let Some(token) = engine.run_token() else {
+        unreachable!("the tween is running");
+    };
+    let finished: Finished = token.finished();
+    run_to_rest(&mut engine);
+    assert_eq!(finished.await, RunOutcome::Completed);
+}
+
+#[test]
+fn looping_runs_stop_at_the_end_of_a_pass() {
+    let mut engine = AnimationEngine::new(1.0f32);
+    engine.apply_keyframes(
+        KeyframeAnimation::new()
+            .at(0.0, 1.0)
+            .at(0.5, 1.5)
+            .at(1.0, 1.0)
+            .looping(LoopMode::Infinite),
+    );
+    run(&mut engine, 40);
+    engine.stop_at_cycle_end();
+    run_to_rest(&mut engine);
+    assert_eq!(engine.get(), 1.0);
+}
+
+#[test]
+fn custom_animations_run_like_built_in_ones() {
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.apply_custom(Ramp {
+        value: 0.0,
+        target: 1.0,
+        speed: 2.0,
+    });
+    run(&mut engine, 15);
+    assert!(near(engine.get(), 0.5, 0.01));
+    // A custom animation has no spring or tween target
+    assert_eq!(engine.target(), None);
+    let frames = run_to_rest(&mut engine);
+    assert!(frames <= 15);
+    assert_eq!(engine.get(), 1.0);
+}
+
+#[test]
+fn teardown_drops_callbacks_unrun() {
+    let completed = Calls::default();
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.tween_to(1.0, Tween::new());
+    engine.add_completion_callback(completed.record());
+    engine.teardown();
+    assert!(!engine.is_active());
+    assert!(!engine.has_ready_callbacks());
+    assert_eq!(completed.count(), 0);
+}
+
+#[test]
+fn trace_records_recent_values() {
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.tween_to(1.0, Tween::new());
+    run(&mut engine, 30);
+    let trace: Vec<TraceSample<f32>> = engine.trace();
+    assert!(trace.len() >= 18 && trace.len() <= DEFAULT_TRACE_CAPACITY);
+    assert!(trace.windows(2).all(|pair| pair[0].time < pair[1].time));
+
+    engine.set_trace_capacity(4);
+    assert_eq!(engine.trace().len(), 4);
+    engine.clear_trace();
+    assert!(engine.trace().is_empty());
+}
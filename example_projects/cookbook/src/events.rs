@@ -0,0 +1,100 @@
+//! Events: watching animations start, complete and get interrupted
+//!
+//! Subscriptions see the motion values of every thread, and tests run side by
+//! side, so each recipe only looks at the events of its own labels.
+
+use dioxus::prelude::*;
+use dioxus_motion2::events::NextEvent;
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::{AnimationKind, MotionEvents};
+
+use crate::support::{act, advance, keep, kept, mount, run_to_rest};
+
+fn tween(millis: u64) -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(millis)),
+    ))
+}
+
+/// The buffered events about the value labeled `label`
+fn about(events: &mut MotionEvents, label: &str) -> Vec<MotionEvent> {
+    events
+        .drain()
+        .into_iter()
+        .filter(|event| event.label() == Some(label))
+        .collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn labeled_values_report_their_runs() {
+    let mut events = subscribe_events(64);
+    let mut dom = mount(|| {
+        keep(use_motion_labeled("events-card", 0.0f32));
+        rsx! {}
+    });
+    let mut card: MotionValue<f32> = kept();
+
+    act(&mut dom, || card.animate_to_with_config(1.0, tween(100)));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    act(&mut dom, || card.animate_to_with_config(0.0, tween(100)));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    act(&mut dom, || card.stop());
+
+    let seen = about(&mut events, "events-card");
+    assert!(matches!(
+        seen.as_slice(),
+        [
+            MotionEvent::Started {
+                kind: AnimationKind::Tween,
+                ..
+            },
+            MotionEvent::Completed { .. },
+            MotionEvent::Started { .. },
+            MotionEvent::Interrupted {
+                progress: Some(_),
+                ..
+            },
+        ]
+    ));
+    if let MotionEvent::Completed { duration, .. } = &seen[1] {
+        assert!(*duration >= Duration::from_millis(100));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn subscriptions_can_be_awaited() {
+    let mut events = subscribe_events(64);
+    let mut dom = mount(|| {
+        keep(use_motion_labeled("events-awaited", 0.0f32));
+        rsx! {}
+    });
+    let mut value: MotionValue<f32> = kept();
+    act(&mut dom, || value.animate_keyframes(presets::shake(4.0)));
+
+    // Other tests' events may come first
+    loop {
+        let next: NextEvent<'_> = events.recv();
+        let event = next.await;
+        if event.label() == Some("events-awaited") {
+            assert!(matches!(
+                event,
+                MotionEvent::Started {
+                    kind: AnimationKind::Keyframes,
+                    ..
+                }
+            ));
+            break;
+        }
+    }
+}
+
+#[test]
+fn full_buffers_drop_the_oldest_events() {
+    let mut events = subscribe_events(1).with_unlabeled();
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.spring_to(1.0, Spring::snappy());
+    run_to_rest(&mut engine);
+    // Started was pushed out by Completed
+    assert!(events.dropped() >= 1);
+    assert!(events.try_next().is_some());
+}
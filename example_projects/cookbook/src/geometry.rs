@@ -0,0 +1,282 @@
+//! Geometry: lengths, transforms, 3D rotations and styles
+
+use std::cell::Cell;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+
+use crate::support::{act, advance, keep, kept, mount, near};
+
+fn tween() -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(100)),
+    ))
+}
+
+#[test]
+fn lengths_keep_their_unit() {
+    assert_eq!(Length::px(12.0).to_css_string(), "12px");
+    assert_eq!(Length::rem(1.5).to_css_string(), "1.5rem");
+    assert_eq!(Length::percent(50.0).to_css_string(), "50%");
+    assert_eq!(Length::vw(100.0).to_css_string(), "100vw");
+    assert_eq!(Length::new(2.0, LengthUnit::Rem), Length::rem(2.0));
+    assert_eq!(LengthUnit::default().as_str(), "px");
+
+    let halfway = Length::rem(1.0).interpolate(&Length::rem(2.0), 0.5);
+    assert_eq!(halfway, Length::rem(1.5));
+}
+
+#[test]
+fn transforms_compose_poses() {
+    const LIFTED: Transform = Transform::translate(0.0, -8.0);
+    assert_eq!(Transform::identity().to_css_string(), "none");
+    assert_eq!(LIFTED.to_css_string(), "translate(0px, -8px)");
+    assert_eq!(Transform::scale_uniform(2.0).to_css_string(), "scale(2)");
+    assert_eq!(Transform::scale(2.0, 1.0).to_css_string(), "scale(2, 1)");
+    assert_eq!(
+        Transform::rotate_degrees(90.0),
+        Transform::rotate(FRAC_PI_2)
+    );
+    assert_eq!(
+        Transform::skew_degrees(45.0, 0.0),
+        Transform::skew(PI / 4.0, 0.0)
+    );
+    assert_eq!(
+        Transform::from_degrees(1.0, 2.0, 1.0, 1.0, 180.0, 0.0, 0.0),
+        Transform::new(1.0, 2.0, 1.0, 1.0, PI, 0.0, 0.0)
+    );
+
+    let both = LIFTED.combine(&Transform::scale_uniform(2.0));
+    assert_eq!((both.y, both.scale_x), (-8.0, 2.0));
+}
+
+#[test]
+fn transforms_move_around_a_point() {
+    let below = Transform::orbit(100.0, 50.0, 20.0, FRAC_PI_2);
+    assert!(near(below.x, 100.0, 1e-4) && near(below.y, 70.0, 1e-4));
+    // Orbiting keeps the element upright; rotating around a pivot turns it
+    assert_eq!(below.rotation, 0.0);
+
+    let arm = Transform::translate(10.0, 0.0).rotate_around(0.0, 0.0, FRAC_PI_2);
+    assert!(near(arm.x, 0.0, 1e-4) && near(arm.y, 10.0, 1e-4));
+    assert_eq!(arm.rotation, FRAC_PI_2);
+}
+
+#[test]
+fn winding_picks_the_way_round() {
+    let (from, to) = (0.0, 1.5 * PI);
+    assert!(near(
+        RotationWinding::Shortest.delta(from, to),
+        -FRAC_PI_2,
+        1e-4
+    ));
+    assert!(near(
+        RotationWinding::Clockwise.delta(from, to),
+        1.5 * PI,
+        1e-4
+    ));
+    assert!(near(
+        RotationWinding::CounterClockwise.delta(from, to),
+        -FRAC_PI_2,
+        1e-4
+    ));
+    assert!(near(
+        RotationWinding::Turns(1).delta(from, to),
+        1.5 * PI,
+        1e-4
+    ));
+}
+
+#[test]
+fn rotations_travel_the_shortest_arc() {
+    assert_eq!(Rotation3D::identity(), Rotation3D::new(0.0, 0.0, 0.0));
+    let ([x, y, z], angle) = Rotation3D::from_degrees(90.0, 0.0, 0.0).axis_angle();
+    assert!(near(x, 1.0, 1e-4) && near(y, 0.0, 1e-4) && near(z, 0.0, 1e-4));
+    assert!(near(angle, FRAC_PI_2, 1e-4));
+    assert_eq!(RotationMode::default(), RotationMode::Euler);
+
+    let to = Rotation3D::from_degrees(170.0, 170.0, 0.0);
+    let euler = Rotation3D::identity().interpolate(&to, 0.5);
+    let arc = Rotation3D::identity().interpolate(&to.rotation_mode(RotationMode::ShortestArc), 0.5);
+    // The arc turns steadily toward the end; the angles on their own overshoot
+    assert!(arc.axis_angle().1 < euler.axis_angle().1);
+    assert!(arc.to_css_string().starts_with("rotate3d("));
+}
+
+#[test]
+fn styles_clamp_the_opacity() {
+    let style = MotionStyle::new(Transform::translate(10.0, 0.0), 1.2);
+    assert_eq!(style.opacity(), 1.0);
+    assert_eq!(style.raw_opacity(), 1.2);
+    assert_eq!(
+        style.to_css_string(),
+        "transform: translate(10px, 0px); opacity: 1;"
+    );
+    assert_eq!(MotionStyle::default(), MotionStyle::visible());
+    assert_eq!(MotionStyle::hidden().opacity(), 0.0);
+
+    let toast = MotionStyle::hidden().disable_pointer_while_animating();
+    assert!(toast.disables_pointer_while_animating());
+    assert!(toast
+        .to_css_string_animating(true)
+        .ends_with("pointer-events: none;"));
+    assert_eq!(toast.to_css_string_animating(false), toast.to_css_string());
+}
+
+#[tokio::test(start_paused = true)]
+async fn styles_ignore_the_pointer_while_animating() {
+    let mut dom = mount(|| {
+        keep(use_motion(MotionStyle::hidden()));
+        rsx! {}
+    });
+    let mut style: MotionValue<MotionStyle> = kept();
+    act(&mut dom, || {
+        style.animate_to_with_config(
+            MotionStyle::visible().disable_pointer_while_animating(),
+            tween(),
+        )
+    });
+    advance(&mut dom, Duration::from_millis(50)).await;
+    assert!(dom
+        .in_runtime(|| style.to_css_string())
+        .contains("pointer-events: none;"));
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(
+        dom.in_runtime(|| style.to_css_string()),
+        "transform: none; opacity: 1;"
+    );
+}
+
+#[test]
+fn filters_write_only_their_functions() {
+    const GLASS: Filter = Filter::none().with_blur(12.0).with_saturate(1.8);
+    assert!(Filter::none().is_none());
+    assert_eq!(GLASS.to_css_string(), "blur(12px) saturate(1.8)");
+    assert_eq!((GLASS.blur(), GLASS.brightness()), (Some(12.0), None));
+
+    // A function missing on one side animates from its identity
+    let dimmed = Filter::none().with_brightness(0.5).with_grayscale(1.0);
+    let halfway = GLASS.interpolate(&dimmed, 0.5);
+    assert_eq!(halfway.saturate(), Some(1.4));
+    assert_eq!(halfway.grayscale(), Some(0.5));
+    assert_eq!(
+        halfway.to_css_string(),
+        "blur(6px) saturate(1.4) brightness(0.75) grayscale(0.5)"
+    );
+
+    let panel = MotionStyle::visible().with_filter(GLASS);
+    assert_eq!(panel.filter(), GLASS);
+    assert!(panel
+        .to_css_string()
+        .ends_with("filter: blur(12px) saturate(1.8);"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn filter_values_animate_one_function() {
+    let mut dom = mount(|| {
+        keep(use_motion(Filter::none()));
+        rsx! {}
+    });
+    let mut backdrop: MotionValue<Filter> = kept();
+    act(&mut dom, || backdrop.blur_to(8.0, tween()));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    let blurring = dom.in_runtime(|| backdrop.get()).blur().unwrap_or_default();
+    assert!(blurring > 0.0 && blurring < 8.0);
+    advance(&mut dom, Duration::from_millis(100)).await;
+
+    // Each call keeps the functions already there
+    act(&mut dom, || backdrop.saturate_to(1.8, tween()));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    act(&mut dom, || backdrop.brightness_to(1.2, tween()));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    act(&mut dom, || backdrop.grayscale_to(0.5, tween()));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(
+        dom.in_runtime(|| backdrop.get()).to_css_string(),
+        "blur(8px) saturate(1.8) brightness(1.2) grayscale(0.5)"
+    );
+}
+
+/// A card's pose and tint, animated as one value
+#[derive(Animatable, Debug, Clone, Copy, PartialEq)]
+struct Card {
+    lift: f32,
+    tint: Color,
+}
+
+#[tokio::test(start_paused = true)]
+async fn derived_values_animate_every_field() {
+    let rest = Card {
+        lift: 0.0,
+        tint: Color::white(),
+    };
+    let raised = Card {
+        lift: -8.0,
+        tint: Color::gray(),
+    };
+    let halfway = rest.interpolate(&raised, 0.5);
+    assert_eq!(halfway.lift, -4.0);
+    assert_eq!(
+        halfway.tint,
+        Color::white().interpolate(&Color::gray(), 0.5)
+    );
+
+    let mut dom = mount(|| {
+        keep(use_motion(Card {
+            lift: 0.0,
+            tint: Color::white(),
+        }));
+        rsx! {}
+    });
+    let mut card: MotionValue<Card> = kept();
+    act(&mut dom, || card.animate_to_with_config(raised, tween()));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(dom.in_runtime(|| card.get()), raised);
+}
+
+thread_local! {
+    static X_RENDERS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[tokio::test(start_paused = true)]
+async fn components_subscribe_to_one_part() {
+    /// Renders the x translation only
+    #[component]
+    fn XReadout() -> Element {
+        let transform = use_context::<MotionValue<Transform>>();
+        X_RENDERS.set(X_RENDERS.get() + 1);
+        rsx! { "{transform.x()}" }
+    }
+
+    let mut dom = mount(|| {
+        let transform = use_motion(Transform::identity());
+        use_context_provider(|| transform);
+        keep(transform);
+        rsx! {
+            XReadout {}
+        }
+    });
+    let mut transform: MotionValue<Transform> = kept();
+    let renders = X_RENDERS.get();
+
+    // Moving down leaves the x readout alone...
+    act(&mut dom, || {
+        transform.animate_to_with_config(Transform::translate(0.0, 100.0), tween())
+    });
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(X_RENDERS.get(), renders);
+    dom.in_runtime(|| {
+        assert_eq!((transform.x(), transform.y()), (0.0, 100.0));
+        assert_eq!((transform.scale_x(), transform.scale_y()), (1.0, 1.0));
+        assert_eq!(transform.rotation(), 0.0);
+        assert_eq!((transform.skew_x(), transform.skew_y()), (0.0, 0.0));
+    });
+
+    // ...while moving across re-renders it as it goes
+    act(&mut dom, || {
+        transform.animate_to_with_config(Transform::translate(100.0, 100.0), tween())
+    });
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert!(X_RENDERS.get() > renders + 3);
+}
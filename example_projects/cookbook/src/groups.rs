@@ -0,0 +1,390 @@
+//! Groups: bundles, staggers, entrances, list styles, chains, barriers, cross-fades and persistence
+
+use dioxus::prelude::*;
+use dioxus_motion2::animations::stagger::{StaggerAnimation, StaggerBuilder, StaggerDirection};
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::Animation;
+use easer::functions::{Cubic, Easing};
+
+use crate::support::{act, advance, keep, kept, mount, near, Calls};
+
+fn tween(millis: u64) -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(millis)),
+    ))
+}
+
+#[tokio::test(start_paused = true)]
+async fn bundles_animate_fields_independently() {
+    let mut dom = mount(|| {
+        keep(use_motion_bundle([
+            ("x", 0.0),
+            ("opacity", 1.0),
+            ("scale", 1.0),
+        ]));
+        rsx! {}
+    });
+    let mut row: MotionBundle<3> = kept();
+    assert_eq!(row.names(), &["x", "opacity", "scale"]);
+    assert_eq!(row.index("scale"), Some(2));
+
+    let completed = Calls::default();
+    act(&mut dom, || {
+        row.animate_to_with_config("x", 16.0, tween(100));
+        row.animate_to_with_config(
+            "opacity",
+            0.5,
+            tween(300).with_on_complete(completed.record()),
+        );
+    });
+    advance(&mut dom, Duration::from_millis(150)).await;
+    dom.in_runtime(|| {
+        assert_eq!(row.get("x"), 16.0);
+        assert!(row
+            .get_index(1)
+            .is_some_and(|opacity| opacity < 1.0 && opacity > 0.5));
+        // The slowest field is half way, the fields on average further
+        let slowest = row.progress().unwrap_or_default();
+        let mean = row.progress_by(GroupProgress::Mean).unwrap_or_default();
+        assert!(near(slowest, 0.5, 0.06));
+        assert!(mean > slowest);
+        assert_eq!(GroupProgress::default(), GroupProgress::Slowest);
+    });
+
+    // The callback waits for every field
+    assert_eq!(completed.count(), 0);
+    act(&mut dom, || row.animate_to("scale", 1.02));
+    advance(&mut dom, Duration::from_secs(2)).await;
+    assert_eq!(completed.count(), 1);
+    dom.in_runtime(|| {
+        assert!(!row.is_animating());
+        assert_eq!(row.values(), [16.0, 0.5, 1.02]);
+        assert_eq!(row.progress(), None);
+    });
+}
+
+#[tokio::test(start_paused = true)]
+async fn bundles_wrap_existing_values() {
+    let mut dom = mount(|| {
+        let size = use_motion([100.0f32, 50.0]);
+        keep(use_hook(|| MotionBundle::new(size, ["width", "height"])));
+        rsx! {}
+    });
+    let mut size: MotionBundle<2> = kept();
+    act(&mut dom, || {
+        size.animate_to_with_config("height", 80.0, tween(100))
+    });
+    advance(&mut dom, Duration::from_millis(50)).await;
+    act(&mut dom, || size.stop());
+    let height = dom.in_runtime(|| size.get("height"));
+    assert!(height > 50.0 && height < 80.0);
+    assert_eq!(dom.in_runtime(|| size.get("width")), 100.0);
+}
+
+#[test]
+fn stagger_animations_cascade() {
+    let mut reveal = StaggerAnimation::new(
+        [0.0; 3],
+        [1.0; 3],
+        Tween::new().duration(Duration::from_millis(100)),
+        Duration::from_millis(50),
+    );
+    assert_eq!(reveal.total_duration(), Duration::from_millis(200));
+    let (_, values, _) = reveal.update(0.1);
+    assert_eq!(values, [1.0, 0.5, 0.0]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn stagger_builder_reveals_a_list() {
+    let mut dom = mount(|| {
+        keep(use_motion([0.0f32; 4]));
+        rsx! {}
+    });
+    let reveal: MotionValue<[f32; 4]> = kept();
+    let built = dom.in_runtime(|| {
+        let builder: StaggerBuilder<4> = reveal.stagger();
+        builder.each(Duration::from_millis(100)).build([1.0; 4])
+    });
+    assert_eq!(built.total_duration(), Duration::from_millis(600));
+
+    let completed = Calls::default();
+    act(&mut dom, || {
+        reveal
+            .stagger()
+            .each(Duration::from_millis(60))
+            .duration(Duration::from_millis(250))
+            .easing(Cubic::ease_out)
+            .on_complete(completed.record())
+            .animate_to([1.0; 4])
+    });
+    advance(&mut dom, Duration::from_millis(200)).await;
+    let values = dom.in_runtime(|| reveal.get());
+    assert!(values.windows(2).all(|pair| pair[0] > pair[1]));
+    advance(&mut dom, Duration::from_millis(300)).await;
+    assert_eq!(dom.in_runtime(|| reveal.get()), [1.0; 4]);
+    assert_eq!(completed.count(), 1);
+}
+
+fn hero() -> Entrance {
+    Entrance::new()
+        .rise(24.0)
+        .fade()
+        .stagger(Duration::from_millis(70))
+        .spring(Spring::snappy())
+}
+
+#[tokio::test(start_paused = true)]
+async fn entrances_stagger_by_index() {
+    fn app() -> Element {
+        // Each card calls `use_entrance(&hero(), index)` in its own component
+        let cards = [0, 1, 2].map(|index| use_entrance(&hero(), index));
+        keep(cards);
+        rsx! {
+            for (i, card) in cards.iter().enumerate() {
+                div { key: "{i}", style: "{card.get().to_css_string()}" }
+            }
+        }
+    }
+
+    let mut dom = mount(app);
+    let cards: [MotionValue<MotionStyle>; 3] = kept();
+    assert_eq!(hero().delay_for(2), Duration::from_millis(140));
+    assert_eq!(dom.in_runtime(|| cards[2].get()), hero().hidden());
+
+    // 100ms in: the first two have started, the third waits until 140ms
+    advance(&mut dom, Duration::from_millis(100)).await;
+    let opacities = dom.in_runtime(|| cards.map(|card| card.get().opacity()));
+    assert!(opacities[0] > opacities[1] && opacities[1] > 0.0);
+    assert_eq!(opacities[2], 0.0);
+
+    advance(&mut dom, Duration::from_secs(1)).await;
+    assert!(dom.in_runtime(|| cards.iter().all(|card| card.get() == hero().shown())));
+}
+
+#[tokio::test(start_paused = true)]
+async fn entrances_wait_for_a_shared_trigger() {
+    fn app() -> Element {
+        let shown = use_signal(|| false);
+        let cards = [0, 1].map(|index| use_entrance_on(&hero(), index, shown()));
+        keep((shown, cards));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let (mut shown, cards): (Signal<bool>, [MotionValue<MotionStyle>; 2]) = kept();
+    advance(&mut dom, Duration::from_secs(1)).await;
+    assert_eq!(dom.in_runtime(|| cards[0].get()), hero().hidden());
+
+    // e.g. once the section scrolls into view
+    act(&mut dom, || shown.set(true));
+    advance(&mut dom, Duration::from_secs(1)).await;
+    assert!(dom.in_runtime(|| cards.iter().all(|card| card.get() == hero().shown())));
+
+    // Turned off, the group is hidden again, ready to replay
+    act(&mut dom, || shown.set(false));
+    advance(&mut dom, Duration::from_millis(20)).await;
+    assert_eq!(dom.in_runtime(|| cards[1].get()), hero().hidden());
+}
+
+#[tokio::test(start_paused = true)]
+async fn list_styles_render_in_one_memo() {
+    fn app() -> Element {
+        // A fixed number of rows, so the hooks run in the same order each render
+        let rows: Vec<_> = (0..20).map(|_| use_motion(0.0f32)).collect();
+        let styles = use_motion_styles(&rows, |x| format!("transform: translateX({x}px);"));
+        keep((rows, styles));
+        rsx! {
+            for (i, style) in styles.read().iter().enumerate() {
+                div { key: "{i}", style: "{style}" }
+            }
+        }
+    }
+
+    let mut dom = mount(app);
+    let (rows, styles): (Vec<MotionValue<f32>>, Memo<Vec<String>>) = kept();
+    act(&mut dom, || {
+        for (i, mut row) in rows.iter().copied().enumerate() {
+            row.animate_to(i as f32 * 10.0);
+        }
+    });
+    advance(&mut dom, Duration::from_secs(3)).await;
+    dom.in_runtime(|| {
+        let styles = styles.read();
+        assert_eq!(styles.len(), 20);
+        assert_eq!(styles[3], "transform: translateX(30px);");
+    });
+}
+
+#[tokio::test(start_paused = true)]
+async fn stagger_exits_leave_last_in_first_out() {
+    let mut dom = mount(|| {
+        keep(use_motion([0.0f32; 5]));
+        rsx! {}
+    });
+    let reveal: MotionValue<[f32; 5]> = kept();
+    let stagger = || {
+        reveal
+            .stagger()
+            .each(Duration::from_millis(100))
+            .duration(Duration::from_millis(80))
+    };
+
+    // Dismissed while the third item has just appeared...
+    act(&mut dom, || stagger().animate_to([1.0; 5]));
+    advance(&mut dom, Duration::from_millis(290)).await;
+    assert_eq!(dom.in_runtime(|| reveal.get()), [1.0, 1.0, 1.0, 0.0, 0.0]);
+    act(&mut dom, || stagger().exit_reverse().animate_to([0.0; 5]));
+
+    // ...it leaves first, and the items still to come never show
+    advance(&mut dom, Duration::from_millis(60)).await;
+    let values = dom.in_runtime(|| reveal.get());
+    assert!(values[2] < 1.0 && values[..2] == [1.0, 1.0]);
+    assert_eq!(values[3..], [0.0, 0.0]);
+    advance(&mut dom, Duration::from_millis(100)).await;
+    let values = dom.in_runtime(|| reveal.get());
+    assert!(values[1] < 1.0 && values[0] == 1.0);
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(dom.in_runtime(|| reveal.get()), [0.0; 5]);
+
+    // The same order, set explicitly on an animation of its own
+    let exit = StaggerAnimation::new(
+        [1.0; 3],
+        [0.0; 3],
+        Tween::new().duration(Duration::from_millis(100)),
+        Duration::from_millis(50),
+    )
+    .with_direction(StaggerDirection::Reverse);
+    let built = dom.in_runtime(|| {
+        reveal
+            .stagger()
+            .direction(StaggerDirection::Forward)
+            .build([1.0; 5])
+    });
+    assert_eq!(exit.total_duration(), Duration::from_millis(200));
+    assert_eq!(built.total_duration(), Duration::from_millis(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn chained_values_map_their_source() {
+    let mut dom = mount(|| {
+        let mut fill = use_motion(0.0f32);
+        let percent = use_motion(0i32);
+        use_hook(move || fill.chain(percent, |fill| (fill * 100.0).round() as i32));
+        keep((fill, percent));
+        rsx! {}
+    });
+    let (mut fill, percent): (MotionValue<f32>, MotionValue<i32>) = kept();
+    act(&mut dom, || fill.animate_to_with_config(1.0, tween(200)));
+    advance(&mut dom, Duration::from_millis(100)).await;
+    let (now, shown) = dom.in_runtime(|| (fill.get(), percent.get()));
+    assert_eq!(shown, (now * 100.0).round() as i32);
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(dom.in_runtime(|| percent.get()), 100);
+
+    act(&mut dom, || fill.set(0.25));
+    assert_eq!(dom.in_runtime(|| percent.get()), 25);
+}
+
+#[tokio::test(start_paused = true)]
+async fn join_complete_waits_for_every_value() {
+    let mut dom = mount(|| {
+        keep([use_motion(0.0f32), use_motion(0.0f32), use_motion(0.0f32)]);
+        rsx! {}
+    });
+    let mut cards: [MotionValue<f32>; 3] = kept();
+    let settled = Calls::default();
+    act(&mut dom, || {
+        for (i, card) in cards.iter_mut().enumerate() {
+            card.animate_to_with_config(1.0, tween(100 * (i as u64 + 1)));
+        }
+        let cards: Vec<_> = cards.iter().collect();
+        join_complete(&cards, settled.record());
+    });
+    advance(&mut dom, Duration::from_millis(250)).await;
+    assert_eq!(settled.count(), 0);
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(settled.count(), 1);
+
+    // With nothing to wait for, it runs right away
+    let now = Calls::default();
+    join_complete::<f32, _>(&[], now.record());
+    assert_eq!(now.count(), 1);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Icon {
+    Play,
+    Pause,
+}
+
+#[tokio::test(start_paused = true)]
+async fn crossfades_keep_the_previous_state() {
+    let mut dom = mount(|| {
+        let icon = use_signal(|| Icon::Play);
+        let fade = use_crossfade(icon(), tween(200));
+        keep((icon, fade));
+        rsx! {}
+    });
+    let (mut icon, fade): (Signal<Icon>, Crossfade<Icon>) = kept();
+    dom.in_runtime(|| {
+        assert_eq!((fade.current(), fade.previous()), (Icon::Play, None));
+        assert_eq!(fade.current_opacity(), 1.0);
+    });
+
+    act(&mut dom, || icon.set(Icon::Pause));
+    advance(&mut dom, Duration::from_millis(100)).await;
+    dom.in_runtime(|| {
+        assert!(fade.is_animating());
+        assert_eq!(
+            (fade.current(), fade.previous()),
+            (Icon::Pause, Some(Icon::Play))
+        );
+        assert!(near(fade.progress(), 0.5, 0.1));
+        assert!(near(
+            fade.current_opacity() + fade.previous_opacity(),
+            1.0,
+            1e-6
+        ));
+    });
+
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(dom.in_runtime(|| fade.previous()), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn persistent_values_survive_a_remount() {
+    #[component]
+    fn Sidebar() -> Element {
+        keep((
+            use_persistent_motion("sidebar-width", 240.0f32),
+            use_context::<MotionStore>(),
+            use_context::<Signal<bool>>(),
+        ));
+        rsx! {}
+    }
+
+    let mut dom = mount(|| {
+        let shown = use_context_provider(|| Signal::new(true));
+        use_context_provider(MotionStore::new);
+        rsx! {
+            if shown() {
+                Sidebar {}
+            }
+        }
+    });
+    let (mut width, store, mut shown): (MotionValue<f32>, MotionStore, Signal<bool>) = kept();
+    act(&mut dom, || width.set(64.0));
+
+    // Unmounting saves the value in the store provided above...
+    act(&mut dom, || shown.set(false));
+    assert_eq!(store.get::<f32>("sidebar-width"), Some(64.0));
+    // ...and the next sidebar to mount starts from it
+    act(&mut dom, || shown.set(true));
+    let (width, _, _): (MotionValue<f32>, MotionStore, Signal<bool>) = kept();
+    assert_eq!(dom.in_runtime(|| width.get()), 64.0);
+
+    store.insert("sidebar-width", 120.0f32);
+    assert_eq!(store.get::<f32>("sidebar-width"), Some(120.0));
+    store.remove("sidebar-width");
+    assert_eq!(store.get::<f32>("sidebar-width"), None);
+}
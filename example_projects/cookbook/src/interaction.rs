@@ -0,0 +1,224 @@
+//! Interaction: following, hovering, snapping, scrolling and live tuning
+
+use dioxus::prelude::*;
+use dioxus_motion2::animations::spring::SpringBuilder;
+use dioxus_motion2::animations::tuning::{tunables, SpringParams};
+use dioxus_motion2::prelude::*;
+
+use crate::support::{act, advance, keep, kept, mount, near, Calls};
+
+fn tween(millis: u64) -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(millis)),
+    ))
+}
+
+#[tokio::test(start_paused = true)]
+async fn followers_spring_after_their_leader() {
+    let mut dom = mount(|| {
+        let leader = use_motion(0.0f32);
+        let mut follower = use_motion(0.0f32);
+        use_hook(move || follower.follow(leader, Spring::snappy()));
+        keep((leader, follower));
+        rsx! {}
+    });
+    let (mut leader, follower): (MotionValue<f32>, MotionValue<f32>) = kept();
+    act(&mut dom, || leader.set(100.0));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    let lagging = dom.in_runtime(|| follower.get());
+    assert!(lagging > 0.0 && lagging < 100.0);
+    advance(&mut dom, Duration::from_secs(2)).await;
+    assert!(near(dom.in_runtime(|| follower.get()), 100.0, 0.01));
+}
+
+#[tokio::test(start_paused = true)]
+async fn values_track_a_signal() {
+    let mut dom = mount(|| {
+        let target = use_signal(|| 0.0f32);
+        let motion = use_tracked_motion(target, tween(100));
+        keep((target, motion));
+        rsx! {}
+    });
+    let (mut target, motion): (Signal<f32>, MotionValue<f32>) = kept();
+    act(&mut dom, || target.set(40.0));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    assert!(dom.in_runtime(|| motion.is_animating()));
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 40.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn interrupt_to_blends_a_redirection() {
+    let mut dom = mount(|| {
+        keep([use_motion(0.0f32), use_motion(0.0f32)]);
+        rsx! {}
+    });
+    let [mut snapped, mut blended]: [MotionValue<f32>; 2] = kept();
+    act(&mut dom, || {
+        snapped.animate_to_with_config(100.0, tween(400));
+        blended.animate_to_with_config(100.0, tween(400));
+    });
+    advance(&mut dom, Duration::from_millis(200)).await;
+
+    // Turning back: a restart reverses at once, a blend first carries on
+    let before = dom.in_runtime(|| blended.get());
+    act(&mut dom, || {
+        snapped.animate_to_with_config(0.0, tween(400));
+        blended.interrupt_to(0.0, tween(400), Duration::from_millis(150));
+    });
+    advance(&mut dom, Duration::from_millis(50)).await;
+    let (snapped_now, blended_now) = dom.in_runtime(|| (snapped.get(), blended.get()));
+    assert!(snapped_now < before);
+    assert!(blended_now > snapped_now);
+
+    advance(&mut dom, Duration::from_millis(500)).await;
+    assert_eq!(dom.in_runtime(|| blended.get()), 0.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn hover_intent_waits_for_the_pointer_to_linger() {
+    let mut dom = mount(|| {
+        let intent = use_hover_intent(Duration::from_millis(100));
+        let lift = use_motion(0.0f32);
+        use_hover_motion(lift, intent, 0.0, -8.0, tween(100));
+        keep((intent, lift));
+        rsx! {}
+    });
+    let (mut intent, lift): (HoverIntent, MotionValue<f32>) = kept();
+    assert_eq!(intent.delay(), Duration::from_millis(100));
+
+    // Passing over briefly does nothing
+    act(&mut dom, || ScopeId::ROOT.in_runtime(|| intent.enter()));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    act(&mut dom, || intent.leave());
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert!(!dom.in_runtime(|| intent.is_active()));
+    assert_eq!(dom.in_runtime(|| lift.get()), 0.0);
+
+    // Staying lifts the element
+    act(&mut dom, || ScopeId::ROOT.in_runtime(|| intent.enter()));
+    advance(&mut dom, Duration::from_millis(250)).await;
+    assert!(dom.in_runtime(|| intent.is_active()));
+    assert_eq!(dom.in_runtime(|| lift.get()), -8.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn magnetic_snap_catches_a_dragged_value() {
+    let mut dom = mount(|| {
+        keep(use_motion(0.0f32));
+        rsx! {}
+    });
+    let mut volume: MotionValue<f32> = kept();
+    let snapped = Calls::default();
+    let record = snapped.record();
+    let snap = MagneticSnap::new(vec![0.0, 50.0, 100.0], 0.5)
+        .spring(Spring::snappy())
+        .on_snap(move |_| record());
+    assert_eq!(snap.detents(), &[0.0, 50.0, 100.0]);
+    assert_eq!(snap.nearest(30.0), Some(1));
+    assert_eq!(snap.biased(37.5), 40.625);
+
+    act(&mut dom, || snap.track(&mut volume, 37.5));
+    assert_eq!(dom.in_runtime(|| volume.get()), 40.625);
+    assert_eq!(act(&mut dom, || snap.release(&mut volume)), Some(1));
+    advance(&mut dom, Duration::from_secs(2)).await;
+    assert_eq!(dom.in_runtime(|| volume.get()), 50.0);
+    assert_eq!(snapped.count(), 1);
+}
+
+#[test]
+fn scroll_ranges_map_positions_to_progress() {
+    let at = |element_top| ScrollMetrics {
+        element_top,
+        element_height: 200.0,
+        viewport_height: 800.0,
+    };
+    assert_eq!(ScrollRange::Cover.progress(at(800.0)), 0.0);
+    assert_eq!(ScrollRange::Cover.progress(at(300.0)), 0.5);
+    assert_eq!(ScrollRange::Entry.progress(at(700.0)), 0.5);
+    assert_eq!(ScrollRange::Exit.progress(at(-100.0)), 0.5);
+    assert_eq!(ScrollRange::Distance(120.0).progress(at(-60.0)), 0.5);
+}
+
+#[tokio::test(start_paused = true)]
+async fn scroll_timelines_scrub_keyframes() {
+    let mut dom = mount(|| {
+        let timeline = use_scroll_timeline(ScrollRange::Distance(120.0));
+        let height = use_motion(96.0f32);
+        use_scrub(
+            height,
+            timeline,
+            KeyframeAnimation::new().at(0.0, 96.0).at(1.0, 56.0),
+        );
+        keep((timeline, height));
+        rsx! {}
+    });
+    let (mut timeline, height): (ScrubHandle, MotionValue<f32>) = kept();
+    assert_eq!(timeline.range(), ScrollRange::Distance(120.0));
+
+    // Scroll events only exist on the web; elsewhere feed the positions in
+    act(&mut dom, || {
+        timeline.update(ScrollMetrics {
+            element_top: -60.0,
+            element_height: 96.0,
+            viewport_height: 800.0,
+        })
+    });
+    assert_eq!(dom.in_runtime(|| timeline.progress()), 0.5);
+    assert_eq!(dom.in_runtime(|| height.get()), 76.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn tunable_springs_change_while_running() {
+    let mut dom = mount(|| {
+        let tunable = use_tunable_spring("card", Spring::default());
+        let y = use_motion(0.0f32);
+        use_retuning(y, tunable);
+        keep((tunable, y));
+        rsx! {
+            TuningPanel {}
+        }
+    });
+    let (mut tunable, y): (TunableSpring, MotionValue<f32>) = kept();
+    assert_eq!(tunable.name(), "card");
+    assert_eq!(tunables().len(), 1);
+    assert_eq!(
+        dom.in_runtime(|| tunable.params()),
+        SpringParams::of(&Spring::default())
+    );
+
+    act(&mut dom, || {
+        tunable.set_stiffness(400.0);
+        tunable.set_damping(30.0);
+        tunable.set_mass(1.0);
+    });
+    let stiff = SpringParams {
+        stiffness: 400.0,
+        damping: 30.0,
+        mass: 1.0,
+    };
+    assert_eq!(dom.in_runtime(|| tunable.params()), stiff);
+    assert_eq!(stiff.apply(&Spring::default()).stiffness, 400.0);
+
+    act(&mut dom, || {
+        let builder: SpringBuilder<f32> = y.spring();
+        builder.spring_tunable(&tunable).animate_to(100.0)
+    });
+    // Retargeting running springs is opt-in
+    act(&mut dom, || {
+        tunable.set_retarget(true);
+        tunable.set_params(SpringParams {
+            stiffness: 100.0,
+            ..stiff
+        });
+    });
+    assert!(dom.in_runtime(|| tunable.retargets()));
+    advance(&mut dom, Duration::from_secs(3)).await;
+    assert!(near(dom.in_runtime(|| y.get()), 100.0, 0.01));
+
+    act(&mut dom, || tunable.reset());
+    assert_eq!(
+        dom.in_runtime(|| tunable.spring()).stiffness,
+        Spring::default().stiffness
+    );
+}
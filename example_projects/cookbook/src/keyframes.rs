@@ -0,0 +1,369 @@
+//! Keyframe tracks: positions, easing, interpolation and playback; clips of
+//! keyframes loaded as data
+
+use dioxus::prelude::*;
+use dioxus_motion2::animations::clip::ClipValue;
+use dioxus_motion2::animations::keyframe::Keyframe;
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::{Animation, AnimationTiming};
+use easer::functions::{Cubic, Easing};
+
+use crate::support::{act, advance, keep, kept, mount, near, run, run_to_rest, Calls};
+
+#[test]
+fn keyframes_hold_values_and_easing() {
+    let plain = Keyframe::new(1.0f32);
+    assert_eq!(plain.value, 1.0);
+    assert!(plain.easing.is_none() && plain.interp.is_none());
+    assert!(Keyframe::with_easing(2.0f32, Cubic::ease_in)
+        .easing
+        .is_some());
+}
+
+#[test]
+fn track_samples_between_keyframes() {
+    let track = KeyframeAnimation::new()
+        .at(0.0, 0.0f32)
+        .at_with_easing(0.5, 100.0, Cubic::ease_in)
+        .at_with_interp(0.75, 50.0, InterpMode::Step)
+        .at(1.0, 0.0)
+        .duration(Duration::from_secs(1));
+    assert_eq!(track.validate(), Ok(()));
+
+    assert!(near(track.sample(0.25), 50.0, 0.01));
+    // The segment from 0.5 eases in: slow to leave 100
+    assert!(track.sample(0.6) > 80.0);
+    // The stepped segment holds until the last keyframe
+    assert_eq!(track.sample(0.9), 50.0);
+    assert_eq!(track.sample(1.0), 0.0);
+}
+
+#[test]
+fn track_wide_easing_and_interpolation() {
+    let eased = KeyframeAnimation::new()
+        .at(0.0, 0.0f32)
+        .at(0.5, 50.0)
+        .at(1.0, 100.0)
+        .default_easing(Cubic::ease_out)
+        .ease_between(0.5, 1.0, Cubic::ease_in);
+    assert!(eased.sample(0.25) > 25.0);
+
+    let stepped = KeyframeAnimation::new()
+        .at(0.0, 0.0f32)
+        .at(1.0, 10.0)
+        .default_interp(InterpMode::Step);
+    assert_eq!(stepped.sample(0.99), 0.0);
+
+    // A spline passes through every keyframe without kinks
+    let smooth = KeyframeAnimation::new()
+        .at(0.0, 0.0f32)
+        .at(0.5, 100.0)
+        .at(1.0, 0.0)
+        .default_interp(InterpMode::Spline);
+    assert!(near(smooth.sample(0.5), 100.0, 0.01));
+    assert!(
+        smooth.sample(0.25)
+            > KeyframeAnimation::new()
+                .at(0.0, 0.0f32)
+                .at(0.5, 100.0)
+                .sample(0.25)
+    );
+    assert_eq!(InterpMode::default(), InterpMode::Linear);
+}
+
+#[test]
+fn segments_can_follow_beziers_and_springs() {
+    // A drop that bounces on landing, then a CSS `ease-out` slide
+    let bouncy = Spring {
+        stiffness: 300.0,
+        damping: 8.0,
+        ..Spring::default()
+    };
+    let track = KeyframeAnimation::new()
+        .at_with_spring(0.0, 0.0f32, bouncy)
+        .at_with_bezier(0.6, 100.0, 0.0, 0.0, 0.58, 1.0)
+        .at(1.0, 50.0);
+    assert!((0..60).any(|i| track.sample(i as f32 / 100.0) > 100.0));
+    assert_eq!(track.sample(0.6), 100.0);
+    assert!(track.sample(0.8) < 75.0);
+
+    // The same curve on its own
+    let ease_out = CubicBezier::new(0.0, 0.0, 0.58, 1.0);
+    assert!(ease_out.ease(0.5) > 0.5);
+    assert_eq!(ease_out.points(), (0.0, 0.0, 0.58, 1.0));
+
+    // Hand-built keyframes carry it as their segment's curve
+    let mut keyframe = Keyframe::new(0.0f32);
+    keyframe.curve = Some(SegmentCurve::CubicBezier(ease_out));
+    assert!(keyframe.curve.is_some());
+}
+
+#[test]
+fn malformed_tracks_fail_validation() {
+    assert_eq!(
+        KeyframeAnimation::<f32>::new().validate(),
+        Err(ConfigError::EmptyKeyframes)
+    );
+    assert_eq!(
+        KeyframeAnimation::new().at(1.5, 0.0f32).validate(),
+        Err(ConfigError::InvalidKeyframePosition { position: 1.5 })
+    );
+    assert_eq!(
+        KeyframeAnimation::new()
+            .at(0.0, 0.0f32)
+            .at(1.0, 1.0)
+            .for_duration(Duration::ZERO)
+            .validate(),
+        Err(ConfigError::ZeroDuration {
+            parameter: "duration"
+        })
+    );
+}
+
+#[test]
+fn color_tracks_mix_in_a_color_space() {
+    let track = KeyframeAnimation::new()
+        .at(0.0, Color::blue())
+        .at(1.0, Color::red())
+        .color_space(ColorSpace::Hsl)
+        .hue_direction(HueDirection::Longer);
+    // The long way from blue to red passes through green
+    assert_eq!(track.sample(0.5), Color::green());
+}
+
+#[test]
+fn looping_reversed_track_plays_on_an_engine() {
+    let completed = Calls::default();
+    let track = KeyframeAnimation::new()
+        .at(0.0, 0.0f32)
+        .at(1.0, 10.0)
+        .for_duration(Duration::from_millis(500))
+        .looping(LoopMode::Count(2))
+        .direction(PlaybackDirection::Reverse)
+        .delay(Duration::from_millis(100))
+        .on_complete(completed.record());
+    assert_eq!(track.total_duration(), Some(Duration::from_secs(1)));
+
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.apply_keyframes(track);
+    let frames = run_to_rest(&mut engine);
+    assert!((64..=68).contains(&frames), "took {frames} frames");
+    // Played backwards, it ends on the first keyframe
+    assert_eq!(engine.get(), 0.0);
+    assert_eq!(completed.count(), 1);
+}
+
+#[test]
+fn timing_replaces_the_playback_settings() {
+    let timing = AnimationTiming::new().with_loop_mode(LoopMode::Infinite);
+    let track = KeyframeAnimation::new()
+        .at(0.0, 0.0f32)
+        .at(1.0, 1.0)
+        .timing(timing);
+    assert_eq!(track.total_duration(), None);
+
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.apply_keyframes(track);
+    run(&mut engine, 600);
+    assert!(engine.is_active());
+    assert_eq!(engine.progress(), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn motion_values_play_keyframes() {
+    fn app() -> Element {
+        keep(use_motion(0.0f32));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    act(&mut dom, || {
+        motion
+            .keyframes()
+            .at(0.0, 0.0)
+            .at(0.5, 100.0)
+            .at(1.0, 20.0)
+            .duration(Duration::from_millis(400))
+            .start(&mut motion)
+    });
+    // Halfway through, give or take a frame
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert!(near(dom.in_runtime(|| motion.get()), 100.0, 10.0));
+    advance(&mut dom, Duration::from_millis(300)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 20.0);
+
+    // try_start refuses a broken track and leaves the value alone
+    let refused = act(&mut dom, || {
+        KeyframeAnimation::<f32>::new()
+            .try_start(&mut motion)
+            .is_err()
+    });
+    assert!(refused);
+    assert!(!dom.in_runtime(|| motion.is_animating()));
+
+    let token = act(&mut dom, || {
+        motion.animate_keyframes(
+            KeyframeAnimation::new()
+                .at(0.0, 20.0)
+                .at(1.0, 0.0)
+                .duration(Duration::from_millis(100)),
+        )
+    });
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(token.outcome(), Some(RunOutcome::Completed));
+    assert_eq!(dom.in_runtime(|| motion.get()), 0.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn keyframe_configs_work_wherever_configs_do() {
+    // Keyframes as shares of the move: a pop past the target and back
+    let pop =
+        KeyframeTrack::new(Duration::from_millis(300)).at_with_easing(0.6, 1.2, Cubic::ease_out);
+    let config = AnimationConfig::new(AnimationMode::Keyframes(pop.clone()));
+    assert_eq!(config.validate(), Ok(()));
+    assert_eq!(config.to_string(), "Keyframes(300ms, 1 stop)");
+    assert_eq!(pop.duration(), Duration::from_millis(300));
+
+    // A sequence step built from the track, between two absolute values
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.apply_sequence(
+        sequence::sequence()
+            .then(pop.create_animation(0.0f32, 50.0))
+            .then(Tween::new().create_animation(50.0, 0.0))
+            .start(),
+    );
+    run(&mut engine, 11);
+    assert!(engine.get() > 50.0);
+    run_to_rest(&mut engine);
+    assert_eq!(engine.get(), 0.0);
+
+    // Or queued on a motion value after another config
+    fn app() -> Element {
+        keep(use_motion(0.0f32));
+        rsx! {}
+    }
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    act(&mut dom, || {
+        motion.animate_to_with_config(
+            10.0,
+            AnimationConfig::new(AnimationMode::Tween(Tween::new())),
+        );
+        motion.queue(100.0, config)
+    });
+    advance(&mut dom, Duration::from_secs(1)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 100.0);
+}
+
+const CARD_CLIP: &str = r#"{
+    "channels": {
+        "card.y": {
+            "kind": "f32",
+            "keyframes": [
+                { "time_ms": 0, "value": 20 },
+                { "time_ms": 300, "value": 0, "easing": "cubic_out" }
+            ]
+        },
+        "card.opacity": {
+            "kind": "f32",
+            "keyframes": [
+                { "time_ms": 0, "value": 0 },
+                { "time_ms": 200, "value": 1 }
+            ]
+        }
+    }
+}"#;
+
+#[test]
+fn clips_are_authored_as_data() {
+    let Ok(clip) = serde_json::from_str::<Clip>(CARD_CLIP) else {
+        unreachable!("the card clip parses");
+    };
+    assert_eq!(clip.validate(), Ok(()));
+    assert_eq!(clip.duration(), Duration::from_millis(300));
+    assert_eq!(clip.loop_mode, LoopMode::None);
+
+    // The same clip built in code
+    let built = Clip::new()
+        .channel(
+            "card.y",
+            Channel::F32(vec![
+                ClipKey::new(0.0, 20.0),
+                ClipKey::new(300.0, 0.0).easing("cubic_out"),
+            ]),
+        )
+        .channel(
+            "card.opacity",
+            Channel::F32(vec![ClipKey::new(0.0, 0.0), ClipKey::new(200.0, 1.0)]),
+        );
+    assert_eq!(built, clip);
+
+    // Each channel holds one kind of value
+    let Some(y) = clip.channels.get("card.y") else {
+        unreachable!("the clip has a card.y channel");
+    };
+    assert_eq!(f32::keys(y).map(<[_]>::len), Some(2));
+    assert!(Color::keys(y).is_none());
+
+    // Easings go by name, and unknown ones fail validation
+    assert!(easing_named("cubic_out").is_some());
+    assert!(easing_named("wobble").is_none());
+    let wobbly = Clip::new().channel(
+        "dot.x",
+        Channel::F32(vec![ClipKey::new(0.0, 0.0).easing("wobble")]),
+    );
+    let Err(error) = wobbly.validate() else {
+        unreachable!("wobble is no easing");
+    };
+    assert_eq!(
+        error,
+        ClipError::UnknownEasing {
+            channel: "dot.x".into(),
+            easing: "wobble".into()
+        }
+    );
+    assert_eq!(error.to_string(), "unknown easing `wobble` in `dot.x`");
+}
+
+#[tokio::test(start_paused = true)]
+async fn clip_players_drive_bound_values_together() {
+    fn app() -> Element {
+        let y = use_motion(0.0f32);
+        let opacity = use_motion(1.0f32);
+        let player = use_clip_player(|| serde_json::from_str(CARD_CLIP).unwrap_or_default());
+        use_hook(|| {
+            player
+                .bind("card.y", &y)
+                .bind("card.opacity", &opacity)
+                // Channels the clip lacks are ignored
+                .bind("card.scale", &opacity);
+        });
+        keep((player, y, opacity));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let (player, y, opacity): (ClipPlayer, MotionValue<f32>, MotionValue<f32>) = kept();
+    let values = |dom: &mut VirtualDom| dom.in_runtime(|| (y.get(), opacity.get()));
+    // Bound values start at the clip's first keyframes
+    assert_eq!(values(&mut dom), (20.0, 0.0));
+
+    act(&mut dom, || player.play());
+    advance(&mut dom, Duration::from_millis(100)).await;
+    let (moved, shown) = values(&mut dom);
+    assert!(moved < 20.0 && near(shown, 0.5, 0.1));
+
+    // Paused, the values hold; seeking moves all of them at once
+    act(&mut dom, || player.pause());
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(values(&mut dom), (moved, shown));
+    act(&mut dom, || player.seek(Duration::from_millis(200)));
+    assert_eq!(values(&mut dom).1, 1.0);
+    assert!(!dom.in_runtime(|| player.is_playing()));
+
+    act(&mut dom, || player.play());
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(values(&mut dom), (0.0, 1.0));
+    assert_eq!(dom.in_runtime(|| player.time()), Duration::from_millis(300));
+}
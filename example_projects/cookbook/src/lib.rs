@@ -0,0 +1,43 @@
+//! Recipes for every public item of dioxus-motion2
+//!
+//! Each module groups the recipes for one area as tests, and each test uses an
+//! item the way an app would and asserts what it does. Plain animations are
+//! stepped by hand with fixed time steps; hooks and components run in a
+//! headless [`VirtualDom`](dioxus::prelude::VirtualDom) whose shared driver is
+//! in deterministic mode on a paused clock, see [`support`].
+//!
+//! [`coverage`] maps every public item to the module using it and fails when
+//! a new item has no recipe.
+
+#[cfg(test)]
+mod colors;
+#[cfg(test)]
+mod coverage;
+#[cfg(test)]
+mod engine;
+#[cfg(test)]
+mod events;
+#[cfg(test)]
+mod geometry;
+#[cfg(test)]
+mod groups;
+#[cfg(test)]
+mod interaction;
+#[cfg(test)]
+mod keyframes;
+#[cfg(test)]
+mod migration;
+#[cfg(test)]
+mod motion_values;
+#[cfg(test)]
+mod sequences;
+#[cfg(test)]
+mod springs;
+#[cfg(test)]
+mod support;
+#[cfg(test)]
+mod timing;
+#[cfg(test)]
+mod transitions;
+#[cfg(test)]
+mod tweens;
@@ -0,0 +1,81 @@
+//! Migration: v1 code on the deprecated shims of the `compat` feature
+
+// Porting code calls the shims until it is done
+#![allow(deprecated)]
+
+use dioxus::prelude::*;
+use dioxus_motion2::compat::{self as v1, AnimationManager};
+use dioxus_motion2::prelude::*;
+use easer::functions::{Cubic, Easing};
+
+use crate::support::{act, advance, keep, kept, mount, Calls};
+
+#[test]
+fn v1_configs_convert_to_their_new_counterparts() {
+    let tween = v1::Tween::new(Duration::from_millis(400)).with_easing(Cubic::ease_in_out);
+    let old = v1::AnimationConfig::new(v1::AnimationMode::Tween(tween))
+        .with_loop(v1::LoopMode::Times(2))
+        .with_delay(Duration::from_millis(100));
+    assert_eq!(old.get_duration(), Some(Duration::from_millis(400)));
+
+    let new = AnimationConfig::new(AnimationMode::Tween(
+        Tween::new()
+            .duration(Duration::from_millis(400))
+            .easing(Cubic::ease_in_out),
+    ))
+    .with_loop(LoopMode::Count(2))
+    .with_delay(Duration::from_millis(100));
+    assert_eq!(AnimationConfig::from(old), new);
+
+    // The v1 `velocity` is the initial velocity
+    let spring: Spring = v1::Spring {
+        stiffness: 300.0,
+        velocity: 5.0,
+        ..v1::Spring::default()
+    }
+    .into();
+    assert_eq!(spring.initial_velocity, Some(5.0));
+}
+
+#[tokio::test(start_paused = true)]
+async fn v1_code_runs_on_the_compat_hook() {
+    fn app() -> Element {
+        let mut x = v1::use_motion(0.0f32);
+        // Ported code reaches the new API through the handle
+        keep(x.motion());
+        use_hook(move || {
+            x.animate_to(
+                100.0,
+                v1::AnimationConfig::new(v1::AnimationMode::Spring(v1::Spring::default())),
+            );
+        });
+        rsx! {
+            div { "{x.get_value()}" }
+        }
+    }
+
+    let mut dom = mount(app);
+    let mut x: MotionValue<f32> = kept();
+    assert!(dom.in_runtime(|| x.is_running()));
+    advance(&mut dom, Duration::from_secs(3)).await;
+    assert_eq!(dom.in_runtime(|| x.get_value()), 100.0);
+
+    // Steps play one after another, the last one ending the sequence
+    let done = Calls::default();
+    let tween = || v1::AnimationConfig::new(v1::AnimationMode::Tween(v1::Tween::default()));
+    act(&mut dom, || {
+        x.animate_sequence(
+            v1::AnimationSequence::with_capacity(2)
+                .then(0.0, tween())
+                .then(50.0, tween())
+                .on_complete(done.record()),
+        )
+    });
+    advance(&mut dom, Duration::from_millis(200)).await;
+    let first = dom.in_runtime(|| x.get_value());
+    assert!(first > 0.0 && first < 100.0);
+    assert_eq!(done.count(), 0);
+    advance(&mut dom, Duration::from_millis(500)).await;
+    assert_eq!(dom.in_runtime(|| x.get_value()), 50.0);
+    assert_eq!(done.count(), 1);
+}
@@ -0,0 +1,719 @@
+//! Motion values: the hook, its animate calls, app-wide settings and presets
+
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::testing::{motion_test_harness, MotionHarness, MotionSnapshot};
+use dioxus_motion2::{
+    is_paused, motion_stats, set_animation_cap, set_completion_budget, set_frame_budget, Animation,
+    AnimationState, MotionStats, MotionTime, TimeProvider, FIXED_DT_VAR,
+};
+
+use crate::support::{act, advance, keep, kept, mount, near, run_to_rest, Calls, FRAME};
+
+fn tween(millis: u64) -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(millis)),
+    ))
+}
+
+fn app() -> Element {
+    keep(use_motion(0.0f32));
+    rsx! {}
+}
+
+/// Every value `config` passes through from `from` to `to`, at 60fps
+fn frames(config: AnimationConfig, from: f32, to: f32) -> Vec<f32> {
+    let mut engine = AnimationEngine::new(from);
+    match config.mode {
+        AnimationMode::Spring(spring) => engine.spring_to(to, spring),
+        AnimationMode::Tween(tween) => engine.tween_to(to, tween),
+        AnimationMode::Keyframes(track) => engine.apply_keyframes(track.create_animation(from, to)),
+    }
+    let mut values = Vec::new();
+    while engine.update(FRAME) {
+        values.push(engine.get());
+        assert!(values.len() < 600);
+    }
+    values.push(engine.get());
+    values
+}
+
+#[tokio::test(start_paused = true)]
+async fn animate_to_reports_its_progress() {
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    let completed = Calls::default();
+    let token: AnimationToken = act(&mut dom, || {
+        motion.animate_to_with_config(100.0, tween(200).with_on_complete(completed.record()))
+    });
+    advance(&mut dom, Duration::from_millis(100)).await;
+    dom.in_runtime(|| {
+        assert!(motion.is_animating());
+        assert_eq!(motion.token(), Some(token.clone()));
+        assert_eq!(motion.total_duration(), Some(Duration::from_millis(200)));
+        assert!(motion
+            .progress()
+            .is_some_and(|progress| near(progress, 0.5, 0.1)));
+        assert!(motion
+            .remaining_time()
+            .is_some_and(|left| left <= Duration::from_millis(120)));
+        // `peek` reads without subscribing the caller
+        assert_eq!(motion.peek(), motion.get());
+    });
+
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 100.0);
+    assert_eq!(token.outcome(), Some(RunOutcome::Completed));
+    assert_eq!(completed.count(), 1);
+    assert_eq!(dom.in_runtime(|| motion.token()), None);
+
+    // The trace holds the frames just played
+    let trace = dom.in_runtime(|| {
+        motion.set_trace_capacity(8);
+        motion.trace()
+    });
+    assert_eq!(trace.len(), 8);
+    assert!(trace.last().is_some_and(|sample| sample.value == 100.0));
+}
+
+#[tokio::test(start_paused = true)]
+async fn lazy_initial_values_are_computed_once() {
+    thread_local! {
+        static COMPUTED: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+    fn app() -> Element {
+        let renders = use_signal(|| 0);
+        let motion = use_motion_with(|| {
+            COMPUTED.set(COMPUTED.get() + 1);
+            (1..=4).map(|i| i as f32).sum::<f32>()
+        });
+        keep((motion, renders));
+        rsx! { "{renders}" }
+    }
+
+    let mut dom = mount(app);
+    let (motion, mut renders): (MotionValue<f32>, Signal<i32>) = kept();
+    assert_eq!(dom.in_runtime(|| motion.get()), 10.0);
+    // Re-rendering reuses the value instead of computing it again
+    for _ in 0..3 {
+        act(&mut dom, || renders += 1);
+    }
+    assert_eq!(dom.in_runtime(|| renders.cloned()), 3);
+    assert_eq!(COMPUTED.get(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn plain_animate_to_uses_the_type_default() {
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    act(&mut dom, || motion.animate_to(1.0));
+    advance(&mut dom, Duration::from_secs(3)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 1.0);
+
+    act(&mut dom, || motion.set(0.5));
+    assert_eq!(dom.in_runtime(|| motion.get()), 0.5);
+    assert!(!dom.in_runtime(|| motion.is_animating()));
+}
+
+#[tokio::test(start_paused = true)]
+async fn config_loops_and_delays() {
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    act(&mut dom, || {
+        motion.animate_to_with_config(
+            10.0,
+            tween(100)
+                .with_delay(Duration::from_millis(100))
+                .with_loop(LoopMode::Count(2)),
+        )
+    });
+    advance(&mut dom, Duration::from_millis(80)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 0.0);
+    // Two passes after the delay take until 300ms
+    advance(&mut dom, Duration::from_millis(170)).await;
+    assert!(dom.in_runtime(|| motion.is_animating()));
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert!(!dom.in_runtime(|| motion.is_animating()));
+    assert_eq!(dom.in_runtime(|| motion.get()), 10.0);
+}
+
+#[test]
+fn configs_validate_and_compare() {
+    assert_eq!(tween(100).validate(), Ok(()));
+    assert_eq!(
+        tween(100).with_loop(LoopMode::Count(0)).validate(),
+        Err(ConfigError::ZeroLoopCount)
+    );
+    let limp = Spring {
+        stiffness: 0.0,
+        ..Spring::new()
+    };
+    assert_eq!(
+        AnimationConfig::new(AnimationMode::Spring(limp)).validate(),
+        Err(ConfigError::NotPositive {
+            parameter: "stiffness",
+            value: 0.0
+        })
+    );
+
+    // Clones and comparisons leave the completion callback out
+    let config = tween(100).with_on_complete(|| {});
+    let copy = config.clone();
+    assert!(copy.on_complete.is_none());
+    assert_eq!(copy, config);
+    assert_ne!(
+        AnimationMode::Tween(Tween::new()),
+        AnimationMode::Spring(Spring::new())
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn stop_cancel_and_restart() {
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    let first = act(&mut dom, || {
+        motion.animate_to_with_config(100.0, tween(200))
+    });
+    advance(&mut dom, Duration::from_millis(100)).await;
+    act(&mut dom, || motion.stop());
+    let stopped_at = dom.in_runtime(|| motion.get());
+    assert!(stopped_at > 0.0 && stopped_at < 100.0);
+    assert!(!dom.in_runtime(|| motion.is_animating()));
+    assert!(!act(&mut dom, || motion.cancel(&first)));
+    // Stopped values stay where they froze
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), stopped_at);
+    assert_eq!(first.outcome(), Some(RunOutcome::Cancelled));
+
+    let second = act(&mut dom, || motion.animate_from_to(0.0, 50.0, tween(200)));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    assert!(act(&mut dom, || motion.cancel(&second)));
+    assert_eq!(second.outcome(), Some(RunOutcome::Cancelled));
+
+    // Already heading to 50: the running animation carries on untouched
+    let third = act(&mut dom, || motion.animate_to_with_config(50.0, tween(200)));
+    let same = act(&mut dom, || motion.animate_to_if_changed(50.0, tween(1000)));
+    assert_eq!(same, third);
+    advance(&mut dom, Duration::from_millis(250)).await;
+    assert_eq!(third.outcome(), Some(RunOutcome::Completed));
+}
+
+#[tokio::test(start_paused = true)]
+async fn queue_plays_moves_one_after_another() {
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    // Three quick clicks: each move waits for the one before it
+    let moves: Vec<_> = act(&mut dom, || {
+        [100.0, 200.0, 300.0]
+            .into_iter()
+            .map(|target| motion.queue(target, tween(200)))
+            .collect()
+    });
+    advance(&mut dom, Duration::from_millis(250)).await;
+    assert_eq!(moves[0].outcome(), Some(RunOutcome::Completed));
+    assert!(moves[1].is_active());
+    let second = dom.in_runtime(|| motion.get());
+    assert!(second > 100.0 && second < 200.0);
+
+    advance(&mut dom, Duration::from_millis(400)).await;
+    assert_eq!(moves[2].outcome(), Some(RunOutcome::Completed));
+    assert_eq!(dom.in_runtime(|| motion.get()), 300.0);
+
+    // A plain animate call replaces whatever still waits
+    let waiting = act(&mut dom, || {
+        motion.queue(0.0, tween(200));
+        motion.queue(100.0, tween(200))
+    });
+    act(&mut dom, || motion.animate_to_with_config(50.0, tween(200)));
+    assert_eq!(waiting.outcome(), Some(RunOutcome::Replaced));
+}
+
+#[tokio::test(start_paused = true)]
+async fn animate_by_nudges_from_where_the_value_is() {
+    let mut dom = mount(app);
+    let mut x: MotionValue<f32> = kept();
+
+    // A "move right by 50px" button, clicked twice in quick succession
+    act(&mut dom, || x.animate_by(50.0));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    let mid = dom.in_runtime(|| x.get());
+    assert!(mid > 0.0 && mid < 50.0);
+    act(&mut dom, || x.spring().by(50.0));
+    advance(&mut dom, Duration::from_secs(3)).await;
+    // The second nudge counts from where the first had got to
+    assert!(near(dom.in_runtime(|| x.get()), mid + 50.0, 0.01));
+
+    act(&mut dom, || {
+        x.tween().duration(Duration::from_millis(100)).by(-10.0)
+    });
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert!(near(dom.in_runtime(|| x.get()), mid + 40.0, 0.01));
+}
+
+#[tokio::test(start_paused = true)]
+async fn looping_values_stop_at_the_end_of_a_pass() {
+    let mut dom = mount(|| {
+        keep(use_motion(1.0f32));
+        rsx! {}
+    });
+    let mut motion: MotionValue<f32> = kept();
+    act(&mut dom, || presets::pulse(1.2).start(&mut motion));
+    advance(&mut dom, Duration::from_millis(1500)).await;
+    assert!(dom.in_runtime(|| motion.get()) > 1.0);
+    act(&mut dom, || motion.stop_at_cycle_end());
+    advance(&mut dom, Duration::from_millis(1200)).await;
+    assert!(!dom.in_runtime(|| motion.is_animating()));
+    assert_eq!(dom.in_runtime(|| motion.get()), 1.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn custom_animations_run_on_motion_values() {
+    /// Jumps to its target after a set number of frames
+    struct Hold {
+        frames: u32,
+        target: f32,
+    }
+
+    impl Animation for Hold {
+        type Value = f32;
+
+        fn update(&mut self, _dt: f32) -> (AnimationState, f32, f32) {
+            self.frames = self.frames.saturating_sub(1);
+            if self.frames == 0 {
+                (AnimationState::Completed, self.target, 0.0)
+            } else {
+                (AnimationState::Active, 0.0, 0.0)
+            }
+        }
+
+        fn value(&self) -> f32 {
+            if self.frames == 0 {
+                self.target
+            } else {
+                0.0
+            }
+        }
+
+        fn velocity(&self) -> f32 {
+            0.0
+        }
+
+        fn reset(&mut self) {}
+
+        fn is_active(&self) -> bool {
+            self.frames > 0
+        }
+    }
+
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    let token = act(&mut dom, || {
+        motion.animate_custom(Hold {
+            frames: 6,
+            target: 7.0,
+        })
+    });
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(token.outcome(), Some(RunOutcome::Completed));
+    assert_eq!(dom.in_runtime(|| motion.get()), 7.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn motion_values_wrap_any_engine_signal() {
+    let dom = mount(|| {
+        keep(MotionValue::new(use_signal(|| {
+            AnimationEngine::new(3.0f32)
+        })));
+        rsx! {}
+    });
+    let mut motion: MotionValue<f32> = kept();
+    dom.in_runtime(|| {
+        assert_eq!(motion.get(), 3.0);
+        motion.set(4.0);
+        assert_eq!(motion.get(), 4.0);
+    });
+}
+
+#[tokio::test(start_paused = true)]
+async fn config_sets_the_default_animation() {
+    let mut dom = VirtualDom::new(|| {
+        use_motion_config(|| {
+            MotionConfig::new()
+                .deterministic_mode(FRAME)
+                .with_default_animation(AnimationMode::Tween(
+                    Tween::new().duration(Duration::from_millis(100)),
+                ))
+        });
+        keep(use_motion(0.0f32));
+        rsx! {}
+    });
+    dom.rebuild_in_place();
+    let mut motion: MotionValue<f32> = kept();
+    act(&mut dom, || motion.animate_to(1.0));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(
+        dom.in_runtime(|| motion.total_duration()),
+        Some(Duration::from_millis(100))
+    );
+
+    let config = MotionConfig::new();
+    assert_eq!(config, MotionConfig::default());
+    assert_eq!((config.default_animation, config.fixed_dt), (None, None));
+    // The variable deterministic mode reads when the config is provided
+    assert_eq!(FIXED_DT_VAR, "DIOXUS_MOTION_FIXED_DT");
+}
+
+#[test]
+fn ambient_animations_save_power() {
+    // Each preference the user turns on cuts back ambient motion, the
+    // stricter reduction winning where they overlap
+    let policy = AmbientPolicy::default();
+    let everything = PowerPreferences {
+        save_data: true,
+        reduced_motion: true,
+        battery_saver: true,
+    };
+    assert_eq!(
+        policy.resolve(PowerPreferences::default()),
+        AmbientReduction::none()
+    );
+    assert_eq!(policy.reduced_motion, AmbientReduction::skip());
+    assert_eq!(
+        policy.resolve(everything),
+        AmbientReduction {
+            skip: true,
+            jump: false,
+            frame_rate: Some(20.0),
+            passes: Some(3),
+        }
+    );
+    assert_eq!(
+        AmbientReduction::frame_rate(30.0).and(AmbientReduction::frame_rate(10.0)),
+        AmbientReduction::frame_rate(10.0)
+    );
+    assert_eq!(
+        AmbientPolicy::never().resolve(everything),
+        AmbientReduction::none()
+    );
+
+    fn app() -> Element {
+        // Loop only once to save data instead of three times
+        use_motion_config(|| {
+            MotionConfig::new().with_ambient_policy(AmbientPolicy {
+                save_data: AmbientReduction::passes(1),
+                ..AmbientPolicy::default()
+            })
+        });
+        let preferences = use_power_preferences();
+        let glow = use_motion_labeled("glow", 1.0f32);
+        let drift = use_motion_labeled("drift", 0.0f32);
+        use_effect(move || {
+            // Restart the decoration whenever the preferences change
+            if preferences().reduced_motion {
+                return;
+            }
+            // Marked ambient, the SpringBuilder cuts back on its own
+            glow.spring()
+                .loop_mode(LoopMode::Infinite)
+                .ambient()
+                .animate_to(1.4);
+            // A TweenBuilder takes the same mark
+            drift
+                .tween()
+                .duration(Duration::from_secs(2))
+                .ambient()
+                .animate_to(100.0);
+        });
+        rsx! {}
+    }
+
+    let glowing = |harness: &MotionHarness| {
+        harness
+            .motion_value("glow")
+            .is_some_and(|glow| glow.is_animating())
+    };
+    let mut harness = motion_test_harness(app);
+    harness.tick(Duration::from_secs(5));
+    assert!(glowing(&harness));
+
+    // An app's own data saver switch restarts it for a single pass
+    harness.act(|| {
+        ScopeId::ROOT.in_runtime(|| {
+            set_power_preferences(PowerPreferences {
+                save_data: true,
+                ..PowerPreferences::default()
+            })
+        })
+    });
+    harness.tick(Duration::from_secs(5));
+    assert!(!glowing(&harness));
+    let drift = harness
+        .motion_value("drift")
+        .and_then(|drift| drift.value::<f32>());
+    assert_eq!(drift, Some(100.0));
+}
+
+#[test]
+fn animation_caps_protect_low_end_devices() {
+    // Past the cap, new ambient animations land on their target at once
+    // instead of piling up; a driver warns once, naming the busiest labels
+    const CAP: usize = 8;
+    const ORBS: usize = CAP + 10;
+    set_animation_cap(Some(CAP), AmbientReduction::jump());
+
+    fn app() -> Element {
+        let orbs: Vec<_> = (0..ORBS)
+            .map(|_| use_motion_labeled("orb", 0.0f32))
+            .collect();
+        use_effect(move || {
+            for orb in &orbs {
+                orb.tween()
+                    .duration(Duration::from_secs(2))
+                    .loop_mode(LoopMode::Alternate)
+                    .ambient()
+                    .animate_to(100.0);
+            }
+        });
+        rsx! {}
+    }
+
+    let mut harness = motion_test_harness(app);
+    harness.tick(Duration::from_secs(1));
+    let orbs = harness.motion_values();
+    let animating = orbs.iter().filter(|orb| orb.is_animating()).count();
+    let jumped = orbs
+        .iter()
+        .filter(|orb| !orb.is_animating() && orb.value::<f32>() == Some(100.0))
+        .count();
+    assert_eq!((animating, jumped), (CAP, 10));
+
+    // MotionStats tells how busy the driver is
+    assert_eq!(
+        motion_stats(),
+        MotionStats {
+            registered: ORBS,
+            active: CAP,
+            cap: Some(CAP),
+            cap_warnings: 1,
+        }
+    );
+
+    // Lifting the cap lets everything animate again
+    set_animation_cap(None, AmbientReduction::none());
+    assert_eq!(motion_stats().cap, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn pause_all_freezes_the_thread() {
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    act(&mut dom, || {
+        pause_all();
+        motion.animate_to_with_config(1.0, tween(100))
+    });
+    assert!(is_paused());
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 0.0);
+
+    act(&mut dom, resume_all);
+    assert!(!is_paused());
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 1.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn priorities_only_matter_over_budget() {
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    assert_eq!(Priority::default(), Priority::Normal);
+    act(&mut dom, || {
+        motion.set_priority(Priority::Low);
+        // Deterministic mode never defers, however tight the budget
+        set_frame_budget(Some(Duration::ZERO));
+        motion.animate_to_with_config(1.0, tween(100))
+    });
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 1.0);
+    act(&mut dom, || {
+        motion.set_priority(Priority::High);
+        set_frame_budget(None);
+    });
+}
+
+#[tokio::test(start_paused = true)]
+async fn motion_values_report_their_velocity() {
+    let mut dom = mount(app);
+    let mut motion: MotionValue<f32> = kept();
+    assert_eq!(dom.in_runtime(|| motion.velocity()), 0.0);
+    act(&mut dom, || {
+        motion.animate_to_with_config(100.0, tween(1000))
+    });
+
+    // A linear tween moves 100 units a second, e.g. to blur it while fast
+    advance(&mut dom, Duration::from_millis(500)).await;
+    assert!(near(dom.in_runtime(|| motion.velocity()), 100.0, 1.0));
+    advance(&mut dom, Duration::from_secs(1)).await;
+    assert_eq!(dom.in_runtime(|| motion.velocity()), 0.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn completion_callbacks_of_a_burst_are_spread() {
+    const ROWS: usize = 200;
+    let mut dom = mount(|| {
+        keep((0..ROWS).map(|_| use_motion(0.0f32)).collect::<Vec<_>>());
+        rsx! {}
+    });
+    let rows: Vec<MotionValue<f32>> = kept();
+    let completed = Calls::default();
+    act(&mut dom, || {
+        // Ten callbacks a frame, the rest over the next four frames
+        set_completion_budget(Some(10), 4);
+        for mut row in rows.iter().copied() {
+            row.animate_to_with_config(1.0, tween(100).with_on_complete(completed.record()));
+        }
+    });
+
+    // Every value lands with its run; most callbacks wait a little
+    advance(&mut dom, Duration::from_millis(110)).await;
+    assert!(dom.in_runtime(|| rows.iter().all(|row| row.get() == 1.0)));
+    assert!(completed.count() < ROWS);
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(completed.count(), ROWS);
+    act(&mut dom, || set_completion_budget(Some(64), 4));
+}
+
+#[tokio::test(start_paused = true)]
+async fn time_scale_slows_the_values_below_it() {
+    #[component]
+    fn Slow() -> Element {
+        let normal = use_context::<MotionValue<f32>>();
+        keep((normal, use_motion(0.0f32)));
+        rsx! {}
+    }
+
+    let mut dom = mount(|| {
+        let normal = use_motion(0.0f32);
+        use_context_provider(|| normal);
+        rsx! {
+            MotionTimeScale { factor: 0.5, Slow {} }
+        }
+    });
+    let (mut normal, mut slow): (MotionValue<f32>, MotionValue<f32>) = kept();
+    act(&mut dom, || {
+        normal.animate_to_with_config(100.0, tween(200));
+        slow.animate_to_with_config(100.0, tween(200));
+    });
+    advance(&mut dom, Duration::from_millis(250)).await;
+    assert_eq!(dom.in_runtime(|| normal.get()), 100.0);
+    assert!(near(dom.in_runtime(|| slow.get()), 62.5, 10.0));
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(dom.in_runtime(|| slow.get()), 100.0);
+}
+
+#[tokio::test]
+async fn motion_time_sleeps_on_the_runtime() {
+    let start = MotionTime::now();
+    MotionTime::delay(Duration::from_millis(5)).await;
+    assert!(MotionTime::now() - start >= Duration::from_millis(5));
+}
+
+#[tokio::test(start_paused = true)]
+async fn simple_motions_animate_leaf_values() {
+    // A chart with many bars, each a cheap animated f32
+    let mut dom = mount(|| {
+        keep([use_simple_motion(0.0), use_simple_motion(0.0)]);
+        rsx! {}
+    });
+    let [mut tall, mut short]: [SimpleMotion; 2] = kept();
+    act(&mut dom, || {
+        tall.animate_to(120.0);
+        short.tween_to(40.0, Tween::new().duration(Duration::from_millis(200)));
+    });
+    advance(&mut dom, Duration::from_millis(100)).await;
+    dom.in_runtime(|| {
+        assert!(tall.is_animating() && short.is_animating());
+        assert!(short.peek() > 0.0 && short.get() < 40.0);
+    });
+    advance(&mut dom, Duration::from_secs(2)).await;
+    assert!(near(dom.in_runtime(|| tall.get()), 120.0, 0.01));
+    assert_eq!(dom.in_runtime(|| short.get()), 40.0);
+
+    // Springs keep their speed when retargeted; set and stop end the run
+    act(&mut dom, || tall.spring_to(0.0, Spring::default()));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    act(&mut dom, || tall.stop());
+    assert!(!dom.in_runtime(|| tall.is_animating()));
+    act(&mut dom, || short.set(10.0));
+    assert_eq!(dom.in_runtime(|| short.get()), 10.0);
+}
+
+#[test]
+fn harnesses_step_components_frame_by_frame() {
+    fn card() -> Element {
+        let lifted = use_signal(|| false);
+        let y = use_motion_labeled("y", 0.0f32);
+        let _shadow = use_motion(0.0f32);
+        use_effect(move || {
+            let target = if lifted() { -8.0 } else { 0.0 };
+            y.tween()
+                .duration(Duration::from_millis(200))
+                .animate_to(target);
+        });
+        keep(lifted);
+        rsx! {}
+    }
+
+    // No renderer and no clock: time passes when the test ticks
+    let mut harness: MotionHarness = motion_test_harness(card);
+    assert_eq!(harness.motion_values().len(), 2);
+    let mut lifted: Signal<bool> = kept();
+    harness.act(|| lifted.set(true));
+
+    let Some(y): Option<MotionSnapshot> = harness.motion_value("y") else {
+        unreachable!("the card labels its offset");
+    };
+    assert_eq!(y.label(), Some("y"));
+    assert_eq!(
+        (y.value::<f32>(), y.target::<f32>()),
+        (Some(0.0), Some(-8.0))
+    );
+    assert!(y.is_animating());
+    assert_eq!(y.progress(), Some(0.0));
+
+    harness.tick(Duration::from_millis(100));
+    let y = harness.motion_value("y").and_then(|y| y.value::<f32>());
+    assert!(y.is_some_and(|y| y < 0.0 && y > -8.0));
+    harness.tick(Duration::from_millis(100));
+    let y = harness.motion_value("y").and_then(|y| y.value::<f32>());
+    assert_eq!(y, Some(-8.0));
+    // The dom is there for anything else a test needs
+    assert!(harness.dom().base_scope().try_root_node().is_some());
+}
+
+#[test]
+fn presets_have_their_feel() {
+    // Tweens that appear gently and leave faster
+    let fade_in = frames(presets::fade_in(), 0.0, 1.0);
+    let fade_out = frames(presets::fade_out(), 1.0, 0.0);
+    assert!(fade_out.len() < fade_in.len());
+    assert!(fade_in[fade_in.len() / 2] > 0.5);
+
+    // A slide never passes its target, a pop overshoots it
+    assert!(frames(presets::slide_up(), 16.0, 0.0)
+        .iter()
+        .all(|value| *value >= -0.01));
+    let pop = frames(presets::pop(), 0.0, 1.0);
+    assert!(pop.iter().copied().fold(0.0, f32::max) > 1.1);
+
+    let shake = presets::shake(8.0);
+    assert_eq!(shake.sample(0.15), -8.0);
+    assert_eq!(shake.sample(1.0), 0.0);
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.apply_keyframes(shake);
+    run_to_rest(&mut engine);
+    assert_eq!(engine.get(), 0.0);
+
+    let pulse = presets::pulse(1.2);
+    assert!(near(pulse.sample(0.5), 1.2, 1e-4));
+    assert_eq!(pulse.total_duration(), None);
+}
@@ -0,0 +1,80 @@
+//! Sequences: steps played one after another
+
+use dioxus::prelude::*;
+use dioxus_motion2::animations::sequence::{sequence, AnimationSequence, SequenceBuilder};
+use dioxus_motion2::prelude::*;
+
+use crate::support::{act, advance, keep, kept, mount, near, run, run_to_rest, Calls};
+
+fn tween(millis: u64) -> Tween {
+    Tween::new().duration(Duration::from_millis(millis))
+}
+
+#[test]
+fn sequence_plays_steps_in_order() {
+    let completed = Calls::default();
+    let steps = AnimationSequence::new()
+        .then(tween(200).create_animation(0.0f32, 10.0))
+        .wait(Duration::from_millis(200))
+        .then(tween(200).create_animation(10.0, 0.0))
+        .on_complete(completed.record())
+        .start();
+
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.apply_sequence(steps);
+    run(&mut engine, 12);
+    assert_eq!(engine.get(), 10.0);
+    // Held through the wait, a third of the way in
+    run(&mut engine, 6);
+    assert_eq!(engine.get(), 10.0);
+    assert!(engine
+        .progress()
+        .is_some_and(|progress| near(progress, 0.5, 0.05)));
+
+    run_to_rest(&mut engine);
+    assert_eq!(engine.get(), 0.0);
+    assert_eq!(completed.count(), 1);
+}
+
+#[test]
+fn sequence_function_builds_a_boxed_animation() {
+    let mut boxed = sequence()
+        .then(tween(100).create_animation(0.0f32, 1.0))
+        .then(Spring::snappy().create_animation(1.0, 2.0, 0.0))
+        .start()
+        .build();
+    let mut frames = 0;
+    while boxed.is_active() {
+        boxed.update(1.0 / 60.0);
+        frames += 1;
+        assert!(frames < 600);
+    }
+    assert_eq!(boxed.value(), 2.0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn motion_values_play_sequences() {
+    fn app() -> Element {
+        keep(use_motion(0.0f32));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let motion: MotionValue<f32> = kept();
+    let completed = Calls::default();
+    act(&mut dom, || {
+        let builder: SequenceBuilder<f32> = motion.sequence();
+        builder
+            .then(tween(100).create_animation(0.0, 50.0))
+            .wait(Duration::from_millis(100))
+            .then(tween(100).create_animation(50.0, 100.0))
+            .on_complete(completed.record())
+            .start()
+    });
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 50.0);
+    advance(&mut dom, Duration::from_millis(250)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 100.0);
+    assert_eq!(completed.count(), 1);
+    assert!(!dom.in_runtime(|| motion.is_animating()));
+}
@@ -0,0 +1,354 @@
+//! Springs: physics, builders and noise
+
+use dioxus::prelude::*;
+use dioxus_motion2::animations::bounce::BounceBounds;
+use dioxus_motion2::animations::spring::{SpringAnimation, SpringBuilder};
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::{Animation, AnimationState, AnimationTiming};
+
+use crate::support::{advance, keep, kept, mount, near, run, run_to_rest, Calls, FRAME};
+
+#[test]
+fn spring_parameters_shape_the_motion() {
+    let spring = Spring::new()
+        .stiffness(300.0)
+        .damping(8.0)
+        .mass(1.0)
+        .initial_velocity(0.0);
+    assert_eq!(spring.validate(), Ok(()));
+
+    // Underdamped: overshoots before settling
+    let peak = spring.max_excursion(0.0f32, 100.0);
+    assert!(peak > 100.0 && peak < 200.0);
+    let Some(settle) = spring.settle_time(100.0, 0.0, 0.01) else {
+        unreachable!("a damped spring settles");
+    };
+    assert!(settle.as_secs_f32() > 0.5);
+
+    // Critically damped: no overshoot, and quicker to rest
+    let snappy = Spring::snappy();
+    assert_eq!(snappy.max_excursion(0.0f32, 100.0), 100.0);
+    assert!(snappy.settle_time(100.0, 0.0, 0.01) < Some(settle));
+
+    // Builders clamp, so only hand-written fields can be invalid
+    let broken = Spring {
+        mass: f32::NAN,
+        ..Spring::default()
+    };
+    assert!(broken.validate().is_err());
+    assert_eq!(Spring::new().stiffness(-5.0).stiffness, 0.1);
+}
+
+#[test]
+fn springs_can_be_sampled_ahead_of_time() {
+    // The closed-form position, no animation or frame loop involved: just
+    // past its peak, on the way back
+    let bouncy = Spring::new().stiffness(300.0).damping(8.0);
+    let (position, velocity) = bouncy.evaluate(0.0, 100.0, 0.0, 0.2);
+    assert!(position > 100.0 && velocity < 0.0);
+
+    // One position per 1/30s until it settles, e.g. for CSS @keyframes stops
+    let frames = bouncy.sample(0.0, 100.0, 0.0, 30.0, Duration::from_secs(5));
+    let stops: Vec<String> = frames
+        .iter()
+        .enumerate()
+        .map(|(i, y)| {
+            let percent = i as f32 * 100.0 / (frames.len() - 1) as f32;
+            format!("{percent:.1}% {{ transform: translateY({y:.2}px) }}")
+        })
+        .collect();
+    assert_eq!(stops[0], "0.0% { transform: translateY(0.00px) }");
+    assert_eq!(
+        stops.last().map(String::as_str),
+        Some("100.0% { transform: translateY(100.00px) }")
+    );
+}
+
+#[test]
+fn configs_print_a_readable_summary() {
+    // Below a damping ratio of 1 a spring overshoots; the frequency is how
+    // fast it would swing without damping
+    let spring = Spring::new().stiffness(400.0).damping(16.0);
+    assert!(near(spring.damping_ratio(), 0.4, 1e-6));
+    assert!(near(spring.frequency(), 3.18, 0.01));
+    assert_eq!(spring.to_string(), "Spring(ζ=0.4, f=3.18Hz)");
+
+    // Logged configs read as what they play, not as a struct dump
+    let config = AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(250)),
+    ))
+    .with_loop(LoopMode::Infinite)
+    .with_delay(Duration::from_millis(100));
+    assert_eq!(format!("{config}"), "Tween(250ms) looping after 100ms");
+    assert_eq!(
+        AnimationMode::Spring(spring).to_string(),
+        "Spring(ζ=0.4, f=3.18Hz)"
+    );
+}
+
+#[test]
+fn weight_picks_the_mass() {
+    assert_eq!(Weight::Light.mass(), 0.5);
+    assert_eq!(Weight::default().mass(), 1.0);
+    assert_eq!(
+        Spring::new().weight(Weight::Heavy).mass,
+        Weight::Heavy.mass()
+    );
+
+    // A heavy spring takes longer to rest
+    let rest = |weight| {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_to(100.0, Spring::new().weight(weight));
+        run_to_rest(&mut engine)
+    };
+    assert!(rest(Weight::Heavy) > rest(Weight::Light));
+}
+
+#[test]
+fn spring_animation_runs_on_its_own() {
+    let mut animation = Spring::snappy().create_animation(0.0f32, 10.0, 0.0);
+    let (state, value, velocity) = animation.update(FRAME);
+    assert_eq!(state, AnimationState::Active);
+    assert!(value > 0.0 && velocity > 0.0);
+    assert!(animation.remaining_time().is_some());
+
+    let mut manual = SpringAnimation::new(0.0f32, 10.0, Spring::snappy(), AnimationTiming::new());
+    let mut frames = 0;
+    while manual.update(FRAME).0 == AnimationState::Active {
+        frames += 1;
+    }
+    assert!(frames < 120);
+    assert_eq!(manual.value(), 10.0);
+
+    // With noise it drifts around the target for good
+    let mut drifting = SpringAnimation::new(0.0f32, 0.0, Spring::default(), AnimationTiming::new())
+        .with_noise(Noise::new(4.0, 7));
+    for _ in 0..600 {
+        assert_eq!(drifting.update(FRAME).0, AnimationState::Active);
+    }
+    assert!(drifting.value().abs() < 8.0);
+}
+
+#[test]
+fn noise_replays_the_same_drift_for_a_seed() {
+    let noise = Noise::new(10.0f32, 42).frequency(2.0);
+    assert_eq!(noise.frequency, 2.0);
+    assert_eq!(
+        noise.offset(1.25),
+        Noise::new(10.0f32, 42).frequency(2.0).offset(1.25)
+    );
+    assert!((0..100).all(|step| noise.offset(step as f32 * 0.1).abs() <= 10.0));
+
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.spring_with_noise(0.0, Spring::default(), noise);
+    run(&mut engine, 120);
+    assert!(engine.is_active());
+    assert_eq!(engine.progress(), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn spring_builder_builds_without_starting() {
+    fn app() -> Element {
+        let motion = use_motion(20.0f32);
+        keep(motion);
+        rsx! {}
+    }
+
+    let dom = mount(app);
+    let mut animation = dom.in_runtime(|| {
+        let builder: SpringBuilder<f32> = kept::<MotionValue<f32>>().spring();
+        builder.stiffness(400.0).damping(40.0).to(0.0).build()
+    });
+    assert!(!dom.in_runtime(|| kept::<MotionValue<f32>>().is_animating()));
+
+    // The animation starts from the value at build time
+    let (_, value, _) = animation.update(FRAME);
+    assert!(value < 20.0 && value > 0.0);
+    let noisy = dom.in_runtime(|| {
+        kept::<MotionValue<f32>>()
+            .spring()
+            .noise(Noise::new(1.0, 3))
+            .to(20.0)
+            .build()
+    });
+    assert_eq!(noisy.progress(), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn spring_builder_animates_with_callbacks() {
+    fn app() -> Element {
+        let motion = use_motion(0.0f32);
+        keep(motion);
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let (completed, rested, interrupted) = (Calls::default(), Calls::default(), Calls::default());
+    let token = dom.in_runtime(|| {
+        kept::<MotionValue<f32>>()
+            .spring()
+            .stiffness(200.0)
+            .damping(20.0)
+            .mass(1.0)
+            .weight(Weight::Medium)
+            .velocity(50.0)
+            .on_complete(completed.record())
+            .on_rest(rested.record())
+            .on_interrupt(interrupted.record())
+            .animate_to(100.0)
+    });
+    assert!(token.is_active());
+
+    advance(&mut dom, Duration::from_secs(3)).await;
+    assert_eq!(token.outcome(), Some(RunOutcome::Completed));
+    assert_eq!(dom.in_runtime(|| kept::<MotionValue<f32>>().get()), 100.0);
+    assert_eq!(
+        (completed.count(), rested.count(), interrupted.count()),
+        (1, 1, 0)
+    );
+
+    // A looping spring keeps going until stopped at the end of a pass
+    let looping = dom.in_runtime(|| {
+        kept::<MotionValue<f32>>()
+            .spring()
+            .loop_mode(LoopMode::Infinite)
+            .animate_to(0.0)
+    });
+    advance(&mut dom, Duration::from_secs(5)).await;
+    assert!(looping.is_active());
+    dom.in_runtime(|| {
+        kept::<MotionValue<f32>>().stop_at_cycle_end();
+    });
+    advance(&mut dom, Duration::from_secs(5)).await;
+    assert_eq!(looping.outcome(), Some(RunOutcome::Completed));
+    assert!(near(
+        dom.in_runtime(|| kept::<MotionValue<f32>>().get()),
+        0.0,
+        0.01
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn spring_builder_cues_each_bounce_and_the_look_of_rest() {
+    fn app() -> Element {
+        let motion = use_motion(0.0f32);
+        keep(motion);
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let (bounced, rested, completed) = (Calls::default(), Calls::default(), Calls::default());
+    dom.in_runtime(|| {
+        kept::<MotionValue<f32>>()
+            .spring()
+            .stiffness(100.0)
+            .damping(4.0)
+            // e.g. play a tick per swing, and a thud once it looks settled
+            .on_direction_change(bounced.record())
+            .rest_when(50.0, Duration::from_millis(200))
+            .on_rest(rested.record())
+            .on_complete(completed.record())
+            .animate_to(100.0)
+    });
+
+    advance(&mut dom, Duration::from_millis(500)).await;
+    assert_eq!(bounced.count(), 1);
+    advance(&mut dom, Duration::from_millis(1500)).await;
+    assert!(bounced.count() > 3);
+    assert_eq!((rested.count(), completed.count()), (1, 0));
+    advance(&mut dom, Duration::from_secs(3)).await;
+    assert_eq!((rested.count(), completed.count()), (1, 1));
+}
+
+#[tokio::test(start_paused = true)]
+async fn retargeted_springs_keep_their_momentum() {
+    fn app() -> Element {
+        keep((use_motion(0.0f32), use_motion(0.0f32)));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let (carried, restarted): (MotionValue<f32>, MotionValue<f32>) = kept();
+    dom.in_runtime(|| {
+        carried.spring().animate_to(200.0);
+        restarted.spring().animate_to(200.0);
+    });
+    advance(&mut dom, Duration::from_millis(100)).await;
+    let before = dom.in_runtime(|| (carried.get(), restarted.get()));
+    assert_eq!(before.0, before.1);
+
+    // Turned back mid-flight, the spring first coasts on toward 200 without a
+    // hitch; opted out, it heads back at once
+    dom.in_runtime(|| {
+        carried.spring().animate_to(0.0);
+        restarted.spring().preserve_velocity(false).animate_to(0.0);
+    });
+    advance(&mut dom, Duration::from_millis(34)).await;
+    assert!(dom.in_runtime(|| carried.get()) > before.0);
+    assert!(dom.in_runtime(|| restarted.get()) < before.1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn springs_bounce_off_their_bounds() {
+    fn app() -> Element {
+        keep(use_motion(0.0f32));
+        rsx! {}
+    }
+
+    // A ball dropped onto the floor of a 300px box
+    let mut dom = mount(app);
+    let ball: MotionValue<f32> = kept();
+    dom.in_runtime(|| {
+        ball.spring()
+            .stiffness(100.0)
+            .damping(1.0)
+            .bounce_bounds(0.0, 300.0, 0.6)
+            .animate_to(300.0)
+    });
+    let mut heights = Vec::new();
+    for _ in 0..120 {
+        advance(&mut dom, Duration::from_millis(25)).await;
+        let y = dom.in_runtime(|| ball.get());
+        assert!((0.0..=300.0).contains(&y));
+        heights.push(y);
+    }
+    // It came back up off the floor, then settled on it
+    assert!(heights
+        .iter()
+        .skip_while(|y| **y < 250.0)
+        .any(|y| *y < 200.0));
+    advance(&mut dom, Duration::from_secs(10)).await;
+    assert_eq!(dom.in_runtime(|| ball.get()), 300.0);
+
+    // The same walls on a hand-built animation, here per channel of a point
+    let mut point = Spring::default()
+        .create_animation([0.0f32, 0.0], [100.0, 100.0], [0.0, 0.0])
+        .with_bounds(BounceBounds::new(0.0, 100.0, 0.5));
+    while point.update(FRAME).0 == AnimationState::Active {
+        assert!(point.value().iter().all(|x| *x <= 100.0));
+    }
+    assert_eq!(point.value(), [100.0, 100.0]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn spring_builder_waits_out_its_delay() {
+    fn app() -> Element {
+        keep(use_motion(0.0f32));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let position: MotionValue<f32> = kept();
+    dom.in_runtime(|| {
+        position
+            .spring()
+            .stiffness(180.0)
+            .damping(12.0)
+            .delay(Duration::from_millis(250))
+            .animate_to(100.0)
+    });
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(dom.in_runtime(|| position.get()), 0.0);
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert!(dom.in_runtime(|| position.get()) > 0.0);
+}
@@ -0,0 +1,129 @@
+//! Shared scaffolding for the recipes
+//!
+//! Plain engines are stepped with [`run`] and [`run_to_rest`]. Hooks and
+//! components run in a [`VirtualDom`] built by [`mount`], whose root turns on
+//! deterministic mode, and time passes with [`advance`] on tokio's paused
+//! clock: the driver then steps every animation by exactly [`FRAME`] per frame
+//! and no test waits in real time.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+
+/// Time step of every frame, in seconds
+pub const FRAME: f32 = 1.0 / 60.0;
+
+/// Counts how often the callbacks it hands out are called
+#[derive(Clone, Default)]
+pub struct Calls(Arc<AtomicUsize>);
+
+impl Calls {
+    /// A callback adding one to the count
+    pub fn record(&self) -> impl Fn() + Send + Sync + 'static {
+        let count = self.0.clone();
+        move || {
+            count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// How often the callbacks were called
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Step `engine` by `frames` frames
+pub fn run<T: Animatable>(engine: &mut AnimationEngine<T>, frames: usize) {
+    for _ in 0..frames {
+        engine.update(FRAME);
+    }
+}
+
+/// Step `engine` until its animation ends, returning the number of frames
+///
+/// Runs the callbacks of ended runs on the way, as the driver does.
+pub fn run_to_rest<T: Animatable>(engine: &mut AnimationEngine<T>) -> usize {
+    let mut frames = 0;
+    while engine.update(FRAME) {
+        frames += 1;
+        assert!(frames < 10_000, "the animation never came to rest");
+    }
+    for callback in engine.take_ready_callbacks() {
+        callback();
+    }
+    frames
+}
+
+thread_local! {
+    static KEPT: RefCell<Option<Box<dyn Any>>> = const { RefCell::new(None) };
+}
+
+/// Keep `value`, e.g. a hook's handle, for the test to [`kept`] after rendering
+pub fn keep<T: Clone + 'static>(value: T) {
+    KEPT.set(Some(Box::new(value)));
+}
+
+/// The value last passed to [`keep`]
+pub fn kept<T: Clone + 'static>() -> T {
+    KEPT.with_borrow(|kept| {
+        let Some(value) = kept.as_ref().and_then(|value| value.downcast_ref::<T>()) else {
+            unreachable!("the app keeps a value of this type");
+        };
+        value.clone()
+    })
+}
+
+/// Render `app` below a root putting the driver in deterministic mode
+///
+/// Each test runs on a thread of its own, and so with a driver of its own;
+/// mount at most one app per test.
+pub fn mount(app: fn() -> Element) -> VirtualDom {
+    fn root(app: fn() -> Element) -> Element {
+        use_motion_config(|| MotionConfig::new().deterministic_mode(FRAME));
+        app()
+    }
+
+    let mut dom = VirtualDom::new_with_props(root, app);
+    dom.rebuild_in_place();
+    settle(&mut dom);
+    dom
+}
+
+/// Run pending effects and re-render dirty components
+pub fn settle(dom: &mut VirtualDom) {
+    dom.process_events();
+    dom.render_immediate(&mut NoOpMutations);
+}
+
+/// Let `duration` of paused tokio time pass, rendering as the app asks to
+///
+/// Needs a test on a paused clock, `#[tokio::test(start_paused = true)]`.
+pub async fn advance(dom: &mut VirtualDom, duration: Duration) {
+    let deadline = tokio::time::sleep(duration);
+    tokio::pin!(deadline);
+    loop {
+        tokio::select! {
+            biased;
+            () = &mut deadline => break,
+            () = dom.wait_for_work() => dom.render_immediate(&mut NoOpMutations),
+        }
+    }
+    settle(dom);
+}
+
+/// Run `action` in the app's runtime, then let the app react to it
+pub fn act<R>(dom: &mut VirtualDom, action: impl FnOnce() -> R) -> R {
+    let result = dom.in_runtime(action);
+    settle(dom);
+    result
+}
+
+/// Whether `a` and `b` are within `tolerance` of each other
+pub fn near(a: f32, b: f32, tolerance: f32) -> bool {
+    (a - b).abs() <= tolerance
+}
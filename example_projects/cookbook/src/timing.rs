@@ -0,0 +1,124 @@
+//! Timing: delays, loops and playback direction
+
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::{AnimationState, AnimationTiming};
+
+use crate::support::{act, advance, keep, kept, mount, Calls};
+
+#[test]
+fn delay_counts_down_with_the_frames() {
+    let mut timing = AnimationTiming::new().with_delay(Duration::from_millis(100));
+    assert_eq!(timing.remaining_delay(), Duration::from_millis(100));
+    assert!(!timing.handle_delay(0.06));
+    assert!(timing.remaining_delay() < Duration::from_millis(41));
+    assert!(timing.handle_delay(0.06));
+    assert_eq!(timing.remaining_delay(), Duration::ZERO);
+}
+
+#[test]
+fn loop_modes_count_passes() {
+    let once = AnimationTiming::new();
+    assert_eq!(once.loop_mode, LoopMode::default());
+    assert_eq!(once.total_loops(), Some(1));
+
+    let forever = AnimationTiming::new().with_loop_mode(LoopMode::Infinite);
+    assert_eq!(forever.total_loops(), None);
+    assert_eq!(forever.remaining_loops(), None);
+
+    let completed = Calls::default();
+    let mut thrice = AnimationTiming::new()
+        .with_loop_mode(LoopMode::Count(3))
+        .with_on_complete(completed.record());
+    assert_eq!(thrice.remaining_loops(), Some(2));
+    assert!(thrice.handle_loop_completion());
+    assert_eq!(thrice.remaining_loops(), Some(1));
+
+    // Finishing the current pass makes it the last one
+    thrice.finish_current_loop();
+    assert!(!thrice.handle_loop_completion());
+    assert_eq!(completed.count(), 1);
+
+    let mut single = AnimationTiming::new().with_loop_mode(LoopMode::None);
+    assert!(!single.handle_loop_completion());
+}
+
+#[test]
+fn playback_direction_picks_the_reversed_passes() {
+    let forward = AnimationTiming::new().with_direction(PlaybackDirection::Forward);
+    assert!(!forward.is_reverse());
+    assert_eq!(PlaybackDirection::default(), PlaybackDirection::Forward);
+    let reverse = AnimationTiming::new().with_direction(PlaybackDirection::Reverse);
+    assert!(reverse.is_reverse());
+
+    let passes = |direction| {
+        let mut timing = AnimationTiming::new()
+            .with_loop_mode(LoopMode::Infinite)
+            .with_direction(direction);
+        (0..4)
+            .map(|_| {
+                let reversed = timing.is_reverse();
+                timing.handle_loop_completion();
+                reversed
+            })
+            .collect::<Vec<_>>()
+    };
+    let alternate = passes(PlaybackDirection::Alternate);
+    let alternate_reverse = passes(PlaybackDirection::AlternateReverse);
+    assert!(!alternate[0]);
+    assert!(alternate_reverse[0]);
+    // Each pass runs the other way round from the one before
+    assert!(alternate.windows(2).all(|pair| pair[0] != pair[1]));
+    assert!(alternate_reverse.windows(2).all(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn animation_state_tells_whether_to_keep_going() {
+    let mut animation = Tween::new().create_animation(0.0f32, 1.0);
+    use dioxus_motion2::Animation;
+    assert_eq!(animation.update(0.1).0, AnimationState::Active);
+    assert_eq!(animation.update(1.0).0, AnimationState::Completed);
+}
+
+#[tokio::test(start_paused = true)]
+async fn alternating_loops_play_back_and_forth() {
+    fn app() -> Element {
+        keep(use_motion(0.0f32));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let motion: MotionValue<f32> = kept();
+    let completed = Calls::default();
+    // `TweenBuilder::loop_mode` plays there, back and there again; only the
+    // last pass completes the run
+    act(&mut dom, || {
+        motion
+            .tween()
+            .duration(Duration::from_millis(100))
+            .loop_mode(LoopMode::AlternateTimes(3))
+            .on_complete(completed.record())
+            .animate_to(1.0)
+    });
+    advance(&mut dom, Duration::from_millis(150)).await;
+    let on_the_way_back = dom.in_runtime(|| motion.get());
+    assert!(on_the_way_back > 0.0 && on_the_way_back < 1.0);
+    assert!(dom.in_runtime(|| motion.velocity()) < 0.0);
+    assert_eq!(completed.count(), 0);
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(dom.in_runtime(|| motion.get()), 1.0);
+    assert_eq!(completed.count(), 1);
+
+    // Forever, it never completes
+    assert!(LoopMode::Alternate.alternates() && !LoopMode::Infinite.alternates());
+    act(&mut dom, || {
+        motion
+            .spring()
+            .loop_mode(LoopMode::Alternate)
+            .on_complete(completed.record())
+            .animate_to(0.0)
+    });
+    advance(&mut dom, Duration::from_secs(5)).await;
+    assert!(dom.in_runtime(|| motion.is_animating()));
+    assert_eq!(completed.count(), 1);
+}
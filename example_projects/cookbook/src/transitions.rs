@@ -0,0 +1,319 @@
+//! Transitions: enter and exit choreography, presence, switches, dialogs and
+//! skeletons
+
+use std::cell::Cell;
+
+use dioxus::dioxus_core::{AttributeValue, Mutation, Mutations};
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::transitions::presence::{Presence, SwitchState};
+
+use crate::support::{act, advance, keep, kept, mount, near, FRAME};
+
+fn tween(millis: u64) -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(millis)),
+    ))
+}
+
+/// The duration of a tween timing
+fn duration_of(timing: &AnimationConfig) -> Option<Duration> {
+    match &timing.mode {
+        AnimationMode::Tween(tween) => Some(tween.duration),
+        _ => None,
+    }
+}
+
+thread_local! {
+    static LIVE: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Content counting how many copies of it are mounted
+#[component]
+fn Panel(label: String) -> Element {
+    use_hook(|| LIVE.set(LIVE.get() + 1));
+    use_drop(|| LIVE.set(LIVE.get() - 1));
+    rsx! { "{label}" }
+}
+
+#[test]
+fn variants_suggest_a_timing() {
+    assert_eq!(
+        duration_of(&TransitionVariant::Fade.default_timing()),
+        Some(Duration::from_millis(180))
+    );
+    assert_eq!(
+        duration_of(&TransitionVariant::SlideLeft.default_timing()),
+        Some(Duration::from_millis(350))
+    );
+
+    // A route's own timing wins over the outlet's, which wins over the default
+    let slide = TransitionVariant::SlideLeft;
+    let (route, outlet) = (tween(500), tween(250));
+    assert_eq!(
+        slide.resolve_timing(Some(route.clone()), Some(outlet.clone())),
+        route
+    );
+    assert_eq!(slide.resolve_timing(None, Some(outlet.clone())), outlet);
+    assert_eq!(slide.resolve_timing(None, None), slide.default_timing());
+}
+
+#[test]
+fn configs_move_in_percent_of_the_element() {
+    let TransitionConfig {
+        exit_start,
+        exit_end,
+        enter_start,
+        enter_end,
+        timing,
+    } = TransitionVariant::SlideLeft.get_config();
+    assert_eq!(timing, TransitionVariant::SlideLeft.default_timing());
+    assert_eq!(
+        (exit_start, enter_end),
+        (Transform::identity(), Transform::identity())
+    );
+    assert_eq!((exit_end.x, enter_start.x), (-100.0, 100.0));
+    assert_eq!(
+        transition_transform(&enter_start),
+        "translate3d(100%, 0%, 0) scale(1, 1)"
+    );
+    assert_eq!(
+        transition_transform(&Transform::rotate(0.5)),
+        "translate3d(0%, 0%, 0) rotate(0.5rad) scale(1, 1)"
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn bouncy_variants_spring_past_their_place() {
+    let mut dom = mount(|| {
+        keep((use_motion(Transform::identity()), use_motion(0.0f32)));
+        rsx! {}
+    });
+    let (mut transform, mut opacity): (MotionValue<Transform>, MotionValue<f32>) = kept();
+
+    // Fades tween, bounces spring
+    let fade = TransitionVariant::Fade.get_config();
+    let bounce = TransitionVariant::BounceIn.get_config();
+    assert!(matches!(fade.timing.mode, AnimationMode::Tween(_)));
+    assert!(matches!(bounce.timing.mode, AnimationMode::Spring(_)));
+
+    act(&mut dom, || {
+        transform.set(bounce.enter_start);
+        play_transition(
+            &mut transform,
+            &mut opacity,
+            &bounce,
+            TransitionPhase::Enter,
+            bounce.timing.clone(),
+        );
+    });
+    // Rising from below, the content overshoots its place before settling
+    let mut highest = 0.0f32;
+    for _ in 0..60 {
+        advance(&mut dom, Duration::from_millis(16)).await;
+        highest = highest.min(dom.in_runtime(|| transform.get().y));
+    }
+    assert!(highest < -5.0);
+    advance(&mut dom, Duration::from_secs(1)).await;
+    assert!(near(dom.in_runtime(|| transform.get().y), 0.0, 0.01));
+}
+
+#[tokio::test(start_paused = true)]
+async fn hand_rolled_drawers_play_either_half() {
+    let mut dom = mount(|| {
+        keep((use_motion(Transform::identity()), use_motion(0.0f32)));
+        rsx! {}
+    });
+    let (mut transform, mut opacity): (MotionValue<Transform>, MotionValue<f32>) = kept();
+    let drawer = TransitionVariant::SlideLeft.get_config();
+
+    // Entering content starts from the entering pose...
+    act(&mut dom, || {
+        transform.set(drawer.enter_start);
+        play_transition(
+            &mut transform,
+            &mut opacity,
+            &drawer,
+            TransitionPhase::Enter,
+            tween(200),
+        );
+    });
+    advance(&mut dom, Duration::from_millis(100)).await;
+    let (x, faded) = dom.in_runtime(|| (transform.get().x, opacity.get()));
+    assert!(x > 0.0 && x < 100.0);
+    assert!(near(faded, 0.5, 0.1));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(dom.in_runtime(|| transform.get()), drawer.enter_end);
+    assert_eq!(dom.in_runtime(|| opacity.get()), 1.0);
+
+    // ...and leaving content moves on from wherever it is
+    act(&mut dom, || {
+        play_transition(
+            &mut transform,
+            &mut opacity,
+            &drawer,
+            TransitionPhase::Exit,
+            tween(200),
+        )
+    });
+    advance(&mut dom, Duration::from_millis(250)).await;
+    assert_eq!(dom.in_runtime(|| transform.get()), drawer.exit_end);
+    assert_eq!(dom.in_runtime(|| opacity.get()), 0.0);
+}
+
+#[test]
+fn presence_tracks_the_lifecycle() {
+    let mut presence = Presence::new(false);
+    assert!(!presence.is_mounted());
+    assert!(presence.show());
+    assert_eq!(presence, Presence::Entering);
+    // Showing again while entering changes nothing
+    assert!(!presence.show());
+    presence.settle();
+    assert_eq!(presence, Presence::Visible);
+
+    // Hidden content stays mounted until its exit animation settles
+    assert!(presence.hide());
+    assert!(presence.is_mounted() && presence.is_animating());
+    presence.settle();
+    assert_eq!(presence, Presence::Hidden);
+}
+
+#[test]
+fn switches_keep_the_leaving_content() {
+    let mut tabs = SwitchState::new("home");
+    assert_eq!(tabs.current(), (&"home", 0));
+    assert!(!tabs.switch("home"));
+    assert!(tabs.switch("search"));
+    assert_eq!(tabs.current(), (&"search", 1));
+    assert_eq!(tabs.leaving(), Some((&"home", 0)));
+
+    // Switching again mid-transition sends the half-entered tab out instead
+    assert!(tabs.switch("profile"));
+    assert_eq!(tabs.leaving(), Some((&"search", 1)));
+    tabs.settle();
+    assert_eq!(tabs.leaving(), None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn animated_show_unmounts_after_the_exit() {
+    let mut dom = mount(|| {
+        let shown = use_signal(|| false);
+        keep(shown);
+        rsx! {
+            AnimatedShow { when: shown(), enter: TransitionVariant::SlideUpFade, timing: tween(100),
+                Panel { label: "toast" }
+            }
+        }
+    });
+    let mut shown: Signal<bool> = kept();
+    assert_eq!(LIVE.get(), 0);
+
+    act(&mut dom, || shown.set(true));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(LIVE.get(), 1);
+
+    act(&mut dom, || shown.set(false));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(LIVE.get(), 1);
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(LIVE.get(), 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn animated_switch_overlaps_old_and_new_content() {
+    let mut dom = mount(|| {
+        let tab = use_signal(|| 0usize);
+        keep(tab);
+        rsx! {
+            AnimatedSwitch {
+                value: tab(),
+                variant: TransitionVariant::SlideLeft,
+                render: move |tab: usize| rsx! {
+                    Panel { label: "tab {tab}" }
+                },
+            }
+        }
+    });
+    let mut tab: Signal<usize> = kept();
+    assert_eq!(LIVE.get(), 1);
+
+    act(&mut dom, || tab.set(1));
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(LIVE.get(), 2);
+    // SlideLeft takes 350ms unless told otherwise
+    advance(&mut dom, Duration::from_millis(300)).await;
+    assert_eq!(LIVE.get(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn dialogs_close_through_their_owner() {
+    let mut dom = mount(|| {
+        let mut open = use_signal(|| false);
+        keep(open);
+        rsx! {
+            AnimatedDialog {
+                open: open(),
+                on_close: move |_| open.set(false),
+                timing: tween(100),
+                close_on_backdrop: false,
+                Panel { label: "settings" }
+            }
+        }
+    });
+    let mut open: Signal<bool> = kept();
+    assert_eq!(LIVE.get(), 0);
+
+    act(&mut dom, || open.set(true));
+    advance(&mut dom, Duration::from_millis(150)).await;
+    assert_eq!(LIVE.get(), 1);
+
+    // The panel stays while it animates away
+    act(&mut dom, || open.set(false));
+    advance(&mut dom, Duration::from_millis(50)).await;
+    assert_eq!(LIVE.get(), 1);
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert_eq!(LIVE.get(), 0);
+}
+
+/// The inline styles set by `mutations`
+fn styles(mutations: Mutations) -> Vec<String> {
+    mutations
+        .edits
+        .into_iter()
+        .filter_map(|edit| match edit {
+            Mutation::SetAttribute {
+                name: "style",
+                value: AttributeValue::Text(style),
+                ..
+            } => Some(style),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test(start_paused = true)]
+async fn shimmers_sweep_while_content_loads() {
+    fn app() -> Element {
+        use_motion_config(|| MotionConfig::new().deterministic_mode(FRAME));
+        rsx! {
+            for index in 0..2 {
+                Shimmer { key: "{index}", index, style: "height: 1rem" }
+            }
+        }
+    }
+
+    // Watch the styles written instead of rendering them nowhere
+    let mut dom = VirtualDom::new(app);
+    let first = styles(dom.rebuild_to_vec());
+    assert_eq!(first.len(), 2);
+    assert!(first[0].contains("linear-gradient(100deg") && first[0].ends_with("height: 1rem"));
+    // The second line runs a little behind the first
+    assert_ne!(first[0], first[1]);
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    dom.wait_for_work().await;
+    let later = styles(dom.render_immediate_to_vec());
+    assert!(!later.is_empty());
+    assert_ne!(later[0], first[0]);
+}
@@ -0,0 +1,125 @@
+//! Tweens: durations, easing and the tween builder
+
+use dioxus::prelude::*;
+use dioxus_motion2::animations::tween::{EasingFunction, TweenAnimation, TweenBuilder};
+use dioxus_motion2::prelude::*;
+use dioxus_motion2::{Animation, AnimationState, AnimationTiming};
+use easer::functions::{Cubic, Easing, Linear};
+
+use crate::support::{act, advance, keep, kept, mount, near, run_to_rest, Calls, FRAME};
+
+/// Value of a 1s tween from 0 to 100 after `seconds`
+fn sample(easing: EasingFunction, seconds: f32) -> f32 {
+    let tween = Tween::new().duration(Duration::from_secs(1)).easing(easing);
+    let mut animation = tween.create_animation(0.0f32, 100.0);
+    animation.update(seconds).1
+}
+
+#[test]
+fn easing_bends_the_curve() {
+    let linear = sample(Linear::ease_in_out, 0.5);
+    assert!(near(linear, 50.0, 0.01));
+    assert!(sample(Cubic::ease_in, 0.5) < linear);
+    assert!(sample(Cubic::ease_out, 0.5) > linear);
+}
+
+#[test]
+fn tween_validates_and_reports_time() {
+    assert!(Tween::new().validate().is_ok());
+    assert_eq!(
+        Tween::new().duration(Duration::ZERO).validate(),
+        Err(ConfigError::ZeroDuration {
+            parameter: "duration"
+        })
+    );
+
+    let mut engine = AnimationEngine::new(0.0f32);
+    engine.tween_to(1.0, Tween::new().duration(Duration::from_millis(500)));
+    assert_eq!(engine.total_duration(), Some(Duration::from_millis(500)));
+    let frames = run_to_rest(&mut engine);
+    assert!((29..=31).contains(&frames), "took {frames} frames");
+    assert_eq!(engine.get(), 1.0);
+}
+
+#[test]
+fn tween_animation_plays_with_timing() {
+    let timing = AnimationTiming::new().with_delay(Duration::from_millis(100));
+    let mut animation = TweenAnimation::new(0.0f32, 10.0, Tween::new(), timing);
+    assert_eq!(animation.update(0.05), (AnimationState::Active, 0.0, 0.0));
+
+    let mut state = AnimationState::Active;
+    for _ in 0..60 {
+        state = animation.update(FRAME).0;
+    }
+    assert_eq!(state, AnimationState::Completed);
+    assert_eq!(animation.value(), 10.0);
+}
+
+#[test]
+fn split_easing_joins_two_curves() {
+    let eased = split_easing(0.5, Cubic::ease_in, Linear::ease_in_out);
+    assert_eq!(eased(0.0, 0.0, 1.0, 1.0), 0.0);
+    assert!(near(eased(0.5, 0.0, 1.0, 1.0), 0.5, 1e-4));
+    assert!(eased(0.25, 0.0, 1.0, 1.0) < 0.25);
+    assert!(near(eased(0.75, 0.0, 1.0, 1.0), 0.75, 1e-4));
+
+    // Tweens take function pointers, so wrap it in a closure capturing nothing
+    let tween = Tween::new()
+        .easing(|t, b, c, d| split_easing(0.5, Cubic::ease_in, Linear::ease_in_out)(t, b, c, d));
+    let mut animation = tween.create_animation(0.0f32, 1.0);
+    assert!(animation.update(0.075).1 < 0.25);
+}
+
+#[tokio::test(start_paused = true)]
+async fn tween_builder_animates_with_callbacks() {
+    fn app() -> Element {
+        keep(use_motion(0.0f32));
+        rsx! {}
+    }
+
+    let mut dom = mount(app);
+    let motion: MotionValue<f32> = kept();
+    let completed = Calls::default();
+    let token = act(&mut dom, || {
+        let builder: TweenBuilder<f32> = motion.tween();
+        builder
+            .duration(Duration::from_millis(200))
+            .easing(Cubic::ease_out)
+            .winding(RotationWinding::Shortest)
+            .on_complete(completed.record())
+            .animate_to(50.0)
+    });
+
+    advance(&mut dom, Duration::from_millis(100)).await;
+    assert!(dom.in_runtime(|| motion.get()) > 25.0);
+    advance(&mut dom, Duration::from_millis(200)).await;
+    assert_eq!(token.outcome(), Some(RunOutcome::Completed));
+    assert_eq!(dom.in_runtime(|| motion.get()), 50.0);
+    assert_eq!(completed.count(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn tween_builder_builds_steps_for_sequences() {
+    fn app() -> Element {
+        keep(use_motion(10.0f32));
+        rsx! {}
+    }
+
+    let dom = mount(app);
+    let motion: MotionValue<f32> = kept();
+    let (mut built, mut step) = dom.in_runtime(|| {
+        let built = motion.tween().to(20.0).build();
+        let step = motion
+            .tween()
+            .duration(Duration::from_millis(100))
+            .to(0.0)
+            .into_sequence();
+        (built, step)
+    });
+    assert_eq!(built.total_duration(), Some(Duration::from_millis(300)));
+    assert_eq!(step.total_duration(), Some(Duration::from_millis(100)));
+
+    // Both start from the value at build time
+    assert!(built.update(0.15).1 > 10.0);
+    assert!(step.update(0.05).1 < 10.0);
+}
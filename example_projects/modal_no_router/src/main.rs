@@ -0,0 +1,83 @@
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+
+fn main() {
+    dioxus::launch(App);
+}
+
+#[component]
+fn App() -> Element {
+    let mut open = use_signal(|| false);
+
+    rsx! {
+        div {
+            style: "font-family: sans-serif; padding: 2rem;",
+            button { onclick: move |_| open.set(true), "Open modal" }
+            Modal { open: open(), on_close: move |_| open.set(false) }
+        }
+    }
+}
+
+/// A dialog that scales in and fades out, driven by `play_transition`
+#[component]
+fn Modal(open: bool, on_close: EventHandler<()>) -> Element {
+    let variant = TransitionVariant::ScaleUpFade;
+    let config = variant.get_config();
+    let timing = variant.default_timing();
+
+    let mut transform = use_motion(config.enter_start);
+    let mut opacity = use_motion(0.0f32);
+    // Stays true while the exit animation plays
+    let mut mounted = use_signal(|| open);
+
+    use_effect(use_reactive((&open,), move |(open,)| {
+        if open {
+            if !*mounted.peek() {
+                transform.set(config.enter_start);
+                opacity.set(0.0);
+                mounted.set(true);
+            }
+            play_transition(
+                &mut transform,
+                &mut opacity,
+                &config,
+                TransitionPhase::Enter,
+                timing.clone(),
+            );
+        } else if *mounted.peek() {
+            play_transition(
+                &mut transform,
+                &mut opacity,
+                &config,
+                TransitionPhase::Exit,
+                timing.clone(),
+            );
+        }
+    }));
+
+    // Unmount once the exit animation has come to rest
+    use_effect(use_reactive((&open,), move |(open,)| {
+        let animating = transform.is_animating() || opacity.is_animating();
+        if !open && !animating && mounted() {
+            mounted.set(false);
+        }
+    }));
+
+    if !mounted() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            style: "position: fixed; inset: 0; background: rgba(0, 0, 0, {opacity.get() * 0.4}); display: flex; align-items: center; justify-content: center;",
+            onclick: move |_| on_close.call(()),
+            div {
+                style: "background: white; border-radius: 12px; padding: 2rem; transform: {transition_transform(&transform.get())}; opacity: {opacity.get()};",
+                onclick: move |event| event.stop_propagation(),
+                h2 { "Hello from a modal" }
+                p { "No router involved: just transition-core." }
+                button { onclick: move |_| on_close.call(()), "Close" }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+
+fn main() {
+    dioxus::launch(App);
+}
+
+#[component]
+fn App() -> Element {
+    let mut open = use_signal(|| false);
+    let mut notifications = use_signal(|| true);
+    let mut compact = use_signal(|| false);
+
+    rsx! {
+        div {
+            style: "font-family: sans-serif; padding: 2rem;",
+            button { onclick: move |_| open.set(true), "Settings" }
+            p { "Notifications: {notifications()}, compact layout: {compact()}" }
+
+            AnimatedDialog {
+                open: open(),
+                on_close: move |_| open.set(false),
+                enter: TransitionVariant::SlideUpFade.get_config(),
+                style: "background: white; border-radius: 12px; padding: 1.5rem 2rem; min-width: 280px;",
+                h2 { "Settings" }
+                label { style: "display: block; margin: 0.5rem 0;",
+                    input {
+                        r#type: "checkbox",
+                        checked: notifications(),
+                        onchange: move |_| notifications.toggle(),
+                    }
+                    " Notifications"
+                }
+                label { style: "display: block; margin: 0.5rem 0;",
+                    input {
+                        r#type: "checkbox",
+                        checked: compact(),
+                        onchange: move |_| compact.toggle(),
+                    }
+                    " Compact layout"
+                }
+                div { style: "display: flex; gap: 0.5rem; justify-content: flex-end; margin-top: 1rem;",
+                    button { onclick: move |_| open.set(false), "Done" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+
+mod tokens;
+
+use crate::tokens::*;
+
+fn main() {
+    dioxus::launch(App);
+}
+
+#[component]
+fn App() -> Element {
+    let scheme = use_color_scheme();
+    let fade = AnimationConfig::new(AnimationMode::Tween(
+        Tween::new().duration(Duration::from_millis(400)),
+    ));
+
+    let background = use_scheme_motion(SURFACE_LIGHT, SURFACE_DARK, fade.clone());
+    let text = use_scheme_motion(TEXT_LIGHT, TEXT_DARK, fade.clone());
+    let accent = use_scheme_motion(ACCENT_LIGHT, ACCENT_DARK, fade);
+    let swatch = if accent.is_animating() {
+        SWATCH_LIFTED
+    } else {
+        SWATCH_REST
+    };
+
+    rsx! {
+        div {
+            style: "font-family: sans-serif; min-height: 100vh; padding: 2rem; background: {background.get().to_css_string()}; color: {text.get().to_css_string()};",
+            h2 { "Theme: {scheme():?}" }
+            p { "Switch your system between light and dark, or use the buttons." }
+            button { onclick: move |_| set_color_scheme(ColorScheme::Light), "Light" }
+            button { onclick: move |_| set_color_scheme(ColorScheme::Dark), "Dark" }
+            div {
+                style: "margin-top: 1rem; width: 120px; height: 40px; border-radius: 8px; background: {accent.get().to_css_string()}; transform: {swatch.to_css_string()}; transition: transform 150ms;",
+            }
+        }
+    }
+}
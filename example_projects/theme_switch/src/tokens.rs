@@ -0,0 +1,23 @@
+//! Design tokens, declared once as constants
+
+use dioxus_motion2::prelude::*;
+
+/// Page background in the light theme
+pub const SURFACE_LIGHT: Color = Color::from_rgba(250, 250, 250, 255);
+/// Page background in the dark theme
+pub const SURFACE_DARK: Color = Color::from_rgba(24, 24, 27, 255);
+
+/// Body text in the light theme
+pub const TEXT_LIGHT: Color = Color::from_rgba(24, 24, 27, 255);
+/// Body text in the dark theme
+pub const TEXT_DARK: Color = Color::from_rgba(228, 228, 231, 255);
+
+/// Accent swatch in the light theme
+pub const ACCENT_LIGHT: Color = Color::from_rgba(37, 99, 235, 255);
+/// Accent swatch in the dark theme
+pub const ACCENT_DARK: Color = Color::from_rgba(147, 197, 253, 255);
+
+/// The accent swatch at rest
+pub const SWATCH_REST: Transform = Transform::identity();
+/// The accent swatch nudged up and enlarged while the theme changes
+pub const SWATCH_LIFTED: Transform = Transform::new(0.0, -4.0, 1.05, 1.05, 0.0, 0.0, 0.0);
@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+
+fn main() {
+    dioxus::launch(App);
+}
+
+#[component]
+fn App() -> Element {
+    let mut next_id = use_signal(|| 0u32);
+    let mut toasts = use_signal(Vec::<u32>::new);
+    let mut clicks = use_signal(|| 0u32);
+
+    rsx! {
+        div {
+            style: "font-family: sans-serif; padding: 2rem;",
+            button {
+                onclick: move |_| {
+                    toasts.write().push(next_id());
+                    next_id += 1;
+                },
+                "Show toast"
+            }
+            p { "Dismiss a toast, then quickly click where it was." }
+            button {
+                style: "position: fixed; right: 2rem; bottom: 2rem; padding: 1rem 2rem;",
+                onclick: move |_| clicks += 1,
+                "Clicked {clicks} times"
+            }
+            div {
+                style: "position: fixed; right: 1rem; bottom: 1rem; display: flex; flex-direction: column-reverse; gap: 0.5rem;",
+                for id in toasts() {
+                    Toast { key: "{id}", id }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Toast(id: u32) -> Element {
+    let mut shown = use_signal(|| true);
+
+    rsx! {
+        AnimatedShow {
+            when: shown(),
+            enter: TransitionVariant::SlideLeftFade,
+            // Clicks go to the counter underneath as soon as the toast starts leaving
+            disable_pointer_during_exit: true,
+            div {
+                style: "background: #222; color: white; border-radius: 8px; padding: 0.75rem 1rem; min-width: 220px; cursor: pointer;",
+                onclick: move |_| shown.set(false),
+                "Toast #{id}, click to dismiss"
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use dioxus::prelude::*;
+use dioxus_motion2::prelude::*;
+
+const TRACK_WIDTH: f32 = 300.0;
+const LEVELS: [&str; 5] = ["Mute", "Low", "Medium", "High", "Max"];
+
+fn main() {
+    dioxus::launch(App);
+}
+
+#[component]
+fn App() -> Element {
+    rsx! {
+        div {
+            style: "font-family: sans-serif; padding: 2rem;",
+            h2 { "Volume" }
+            VolumeSlider {}
+        }
+    }
+}
+
+#[component]
+fn VolumeSlider() -> Element {
+    let mut volume = use_motion(50.0f32);
+    let mut dragging = use_signal(|| false);
+    let mut level = use_signal(|| 2usize);
+    let snap = use_hook(|| {
+        MagneticSnap::new(vec![0.0, 25.0, 50.0, 75.0, 100.0], 0.4).spring(Spring::snappy())
+    });
+    let (drag, release) = (snap.clone(), snap.clone());
+
+    let track = use_callback(move |x: f64| {
+        let pointer = (x as f32 / TRACK_WIDTH * 100.0).clamp(0.0, 100.0);
+        drag.track(&mut volume, pointer);
+    });
+    let drop_thumb = use_callback(move |()| {
+        if dragging() {
+            dragging.set(false);
+            if let Some(index) = release.release(&mut volume) {
+                level.set(index);
+            }
+        }
+    });
+
+    rsx! {
+        div {
+            style: "position: relative; width: {TRACK_WIDTH}px; height: 32px; cursor: pointer; user-select: none;",
+            onmousedown: move |event| {
+                dragging.set(true);
+                volume.set_priority(Priority::High);
+                track.call(event.element_coordinates().x);
+            },
+            onmousemove: move |event| {
+                if dragging() {
+                    track.call(event.element_coordinates().x);
+                }
+            },
+            onmouseup: move |_| drop_thumb.call(()),
+            onmouseleave: move |_| drop_thumb.call(()),
+            div { style: "position: absolute; top: 14px; left: 0; right: 0; height: 4px; background: #ccc; border-radius: 2px; pointer-events: none;" }
+            for detent in snap.detents().iter().copied() {
+                div { style: "position: absolute; top: 10px; left: calc({detent}% - 1px); width: 2px; height: 12px; background: #999; pointer-events: none;" }
+            }
+            div { style: "position: absolute; top: 4px; left: calc({volume.get()}% - 12px); width: 24px; height: 24px; border-radius: 50%; background: #36f; pointer-events: none;" }
+        }
+        p { "{volume.get().round()}% ({LEVELS[level()]})" }
+    }
+}
@@ -1,6 +1,8 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Attribute, Data, DataEnum, DeriveInput, Fields, Meta};
+use syn::{
+    parse_macro_input, Attribute, Data, DataEnum, DataStruct, DeriveInput, Fields, Index, Meta,
+};
 
 fn get_transition_from_attrs(attrs: &[Attribute]) -> Option<String> {
     attrs
@@ -16,28 +18,22 @@ fn get_transition_from_attrs(attrs: &[Attribute]) -> Option<String> {
 }
 
 // Helper to extract layout nesting information from enum variants
+//
+// Layout attributes apply in source order, before the variant they sit on: every
+// `#[layout]` opens one more layout around it and every `#[end_layout]` closes
+// the innermost open one. A variant can carry several of each, e.g. the first
+// route after two nested layouts end, or a route opening a second layout.
 fn get_layout_depth(variants: &[&syn::Variant]) -> Vec<(syn::Ident, usize)> {
     let mut layout_depth = Vec::new();
-    let mut current_depth = 0;
+    let mut current_depth: usize = 0;
 
     for variant in variants {
-        // Check if this variant has a layout attribute
-        if variant
-            .attrs
-            .iter()
-            .any(|attr| attr.path().is_ident("layout"))
-        {
-            current_depth += 1;
-        }
-
-        // Check if this variant ends a layout
-        if variant
-            .attrs
-            .iter()
-            .any(|attr| attr.path().is_ident("end_layout"))
-            && current_depth > 0
-        {
-            current_depth -= 1;
+        for attr in &variant.attrs {
+            if attr.path().is_ident("layout") {
+                current_depth += 1;
+            } else if attr.path().is_ident("end_layout") {
+                current_depth = current_depth.saturating_sub(1);
+            }
         }
 
         // Associate current depth with this variant
@@ -47,10 +43,22 @@ fn get_layout_depth(variants: &[&syn::Variant]) -> Vec<(syn::Ident, usize)> {
     layout_depth
 }
 
-#[proc_macro_derive(MotionTransitions, attributes(transition, layout, end_layout))]
+#[proc_macro_derive(
+    MotionTransitions,
+    attributes(transition, transition_map, layout, end_layout)
+)]
 pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(expand_route_transitions(input))
+}
+
+fn expand_route_transitions(input: DeriveInput) -> proc_macro2::TokenStream {
     let name = &input.ident;
+    // `#[transition_map]` on the enum hands every transition to its `TransitionMap` impl
+    let uses_transition_map = input
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("transition_map"));
     let variants = match input.data {
         Data::Enum(DataEnum { variants, .. }) => variants,
         _ => panic!("MotionTransitions can only be derived for enums"),
@@ -106,13 +114,10 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
     // Generate layout depth match arms
     let layout_depths = get_layout_depth(&variants.iter().collect::<Vec<_>>());
     let layout_depth_match_arms =
-        layout_depths.iter().map(|(variant_ident, depth)| {
-            match &variants
-                .iter()
-                .find(|v| &v.ident == variant_ident)
-                .unwrap()
-                .fields
-            {
+        variants
+            .iter()
+            .zip(&layout_depths)
+            .map(|(variant, (variant_ident, depth))| match &variant.fields {
                 Fields::Named(fields) => {
                     let field_patterns = fields.named.iter().map(|f| {
                         let name = &f.ident;
@@ -128,10 +133,19 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
                 Fields::Unit => {
                     quote! { Self::#variant_ident {} => #depth }
                 }
+            });
+
+    let transition_between = if uses_transition_map {
+        quote! {
+            fn transition_between(from: &Self, to: &Self) -> TransitionVariant {
+                <Self as TransitionMap>::transition(from, to)
             }
-        });
+        }
+    } else {
+        quote! {}
+    };
 
-    let expanded = quote! {
+    quote! {
         impl AnimatableRoute for  #name {
             fn get_transition(&self) -> TransitionVariant {
                 match self {
@@ -153,8 +167,357 @@ pub fn derive_route_transitions(input: TokenStream) -> TokenStream {
                     _ => 0,
                 }
             }
+
+            #transition_between
+        }
+    }
+}
+
+/// Derives `Animatable` for a struct whose fields are all animatable
+///
+/// Every operation is applied field by field, so fields of different types
+/// (`f32`, `Color`, `Transform`, other derived structs) animate together as one
+/// value. The struct has settled once every field has settled by its own type's
+/// rules.
+#[proc_macro_derive(Animatable)]
+pub fn derive_animatable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(DataStruct { fields, .. }) => fields,
+        _ => {
+            return syn::Error::new_spanned(name, "Animatable can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    // How to access each field, and how to build the struct back from per-field values
+    let members: Vec<_> = match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|field| {
+                let ident = &field.ident;
+                quote! { #ident }
+            })
+            .collect(),
+        Fields::Unnamed(unnamed) => (0..unnamed.unnamed.len())
+            .map(|index| {
+                let index = Index::from(index);
+                quote! { #index }
+            })
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let build = |values: Vec<proc_macro2::TokenStream>| {
+        quote! { Self { #(#members: #values,)* } }
+    };
+
+    let animatable = quote! { ::dioxus_motion2::Animatable };
+    let zero = build(
+        types
+            .iter()
+            .map(|ty| quote! { <#ty as #animatable>::zero() })
+            .collect(),
+    );
+    let scale = build(
+        members
+            .iter()
+            .map(|m| quote! { #animatable::scale(&self.#m, factor) })
+            .collect(),
+    );
+    let add = build(
+        members
+            .iter()
+            .map(|m| quote! { #animatable::add(&self.#m, &other.#m) })
+            .collect(),
+    );
+    let sub = build(
+        members
+            .iter()
+            .map(|m| quote! { #animatable::sub(&self.#m, &other.#m) })
+            .collect(),
+    );
+    let interpolate = build(
+        members
+            .iter()
+            .map(|m| quote! { #animatable::interpolate(&self.#m, &target.#m, t) })
+            .collect(),
+    );
+
+    let expanded = quote! {
+        impl #impl_generics #animatable for #name #ty_generics #where_clause {
+            fn zero() -> Self {
+                #zero
+            }
+
+            fn epsilon() -> f32 {
+                // The finest tolerance of any field
+                let mut epsilon = f32::MAX;
+                #(epsilon = epsilon.min(<#types as #animatable>::epsilon());)*
+                epsilon
+            }
+
+            fn magnitude(&self) -> f32 {
+                let mut sum = 0.0f32;
+                #(sum += #animatable::magnitude(&self.#members).powi(2);)*
+                sum.sqrt()
+            }
+
+            fn scale(&self, factor: f32) -> Self {
+                #scale
+            }
+
+            fn add(&self, other: &Self) -> Self {
+                #add
+            }
+
+            fn sub(&self, other: &Self) -> Self {
+                #sub
+            }
+
+            fn interpolate(&self, target: &Self, t: f32) -> Self {
+                #interpolate
+            }
+
+            fn is_settled(&self, target: &Self, velocity: &Self) -> bool {
+                true #(&& #animatable::is_settled(&self.#members, &target.#members, &velocity.#members))*
+            }
         }
     };
 
     TokenStream::from(expanded)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn depths(input: DeriveInput) -> Vec<(String, usize)> {
+        let Data::Enum(data) = input.data else {
+            unreachable!("test routes are enums");
+        };
+        get_layout_depth(&data.variants.iter().collect::<Vec<_>>())
+            .into_iter()
+            .map(|(ident, depth)| (ident.to_string(), depth))
+            .collect()
+    }
+
+    /// The expansion with all whitespace removed, for substring checks
+    fn expand(input: DeriveInput) -> String {
+        expand_route_transitions(input)
+            .to_string()
+            .split_whitespace()
+            .collect()
+    }
+
+    fn contains(expanded: &str, snippet: &str) -> bool {
+        let snippet: String = snippet.split_whitespace().collect();
+        expanded.contains(&snippet)
+    }
+
+    fn depth_pairs(pairs: &[(&str, usize)]) -> Vec<(String, usize)> {
+        pairs
+            .iter()
+            .map(|(name, depth)| (name.to_string(), *depth))
+            .collect()
+    }
+
+    #[test]
+    fn test_flat_routes_have_no_depth() {
+        let input: DeriveInput = parse_quote! {
+            enum Route {
+                #[route("/")]
+                Home {},
+                #[route("/about")]
+                About {},
+            }
+        };
+        assert_eq!(depths(input), depth_pairs(&[("Home", 0), ("About", 0)]));
+    }
+
+    #[test]
+    fn test_one_layout_then_end_layout() {
+        let input: DeriveInput = parse_quote! {
+            enum Route {
+                #[layout(NavBar)]
+                    #[route("/")]
+                    Home {},
+                    #[route("/blog")]
+                    Blog {},
+                #[end_layout]
+                #[route("/:..segments")]
+                PageNotFound { segments: Vec<String> },
+            }
+        };
+        assert_eq!(
+            depths(input),
+            depth_pairs(&[("Home", 1), ("Blog", 1), ("PageNotFound", 0)])
+        );
+    }
+
+    #[test]
+    fn test_child_under_two_layouts() {
+        let input: DeriveInput = parse_quote! {
+            enum Route {
+                #[layout(NavBar)]
+                #[nest("/docs")]
+                #[layout(Docs)]
+                    #[route("/")]
+                    DocsLanding {},
+                    #[route("/animations")]
+                    Animations {},
+            }
+        };
+        assert_eq!(
+            depths(input),
+            depth_pairs(&[("DocsLanding", 2), ("Animations", 2)])
+        );
+    }
+
+    #[test]
+    fn test_nested_layouts_closed_on_one_variant() {
+        let input: DeriveInput = parse_quote! {
+            enum Route {
+                #[layout(NavBar)]
+                    #[route("/")]
+                    Home {},
+                    #[nest("/docs")]
+                    #[layout(Docs)]
+                        #[route("/")]
+                        DocsLanding {},
+                    #[end_layout]
+                    #[end_nest]
+                    #[route("/blog")]
+                    Blog {},
+                #[end_layout]
+                #[route("/:..segments")]
+                PageNotFound { segments: Vec<String> },
+            }
+        };
+        assert_eq!(
+            depths(input),
+            depth_pairs(&[
+                ("Home", 1),
+                ("DocsLanding", 2),
+                ("Blog", 1),
+                ("PageNotFound", 0)
+            ])
+        );
+
+        let input: DeriveInput = parse_quote! {
+            enum Route {
+                #[layout(NavBar)]
+                #[layout(Docs)]
+                    #[route("/docs")]
+                    Docs {},
+                #[end_layout]
+                #[end_layout]
+                #[route("/")]
+                Home {},
+            }
+        };
+        assert_eq!(depths(input), depth_pairs(&[("Docs", 2), ("Home", 0)]));
+    }
+
+    #[test]
+    fn test_end_layout_and_layout_on_the_same_variant() {
+        let input: DeriveInput = parse_quote! {
+            enum Route {
+                #[layout(NavBar)]
+                    #[route("/")]
+                    Home {},
+                #[end_layout]
+                #[layout(Fullscreen)]
+                    #[route("/player")]
+                    Player {},
+                    #[route("/player/settings")]
+                    Settings {},
+            }
+        };
+        assert_eq!(
+            depths(input),
+            depth_pairs(&[("Home", 1), ("Player", 1), ("Settings", 1)])
+        );
+    }
+
+    #[test]
+    fn test_stray_end_layout_does_not_underflow() {
+        let input: DeriveInput = parse_quote! {
+            enum Route {
+                #[end_layout]
+                #[route("/")]
+                Home {},
+                #[layout(NavBar)]
+                #[route("/blog")]
+                Blog {},
+            }
+        };
+        assert_eq!(depths(input), depth_pairs(&[("Home", 0), ("Blog", 1)]));
+    }
+
+    #[test]
+    fn test_generated_arms() {
+        let expanded = expand(parse_quote! {
+            enum Route {
+                #[layout(NavBar)]
+                    #[route("/")]
+                    Home {},
+                    #[route("/blog/:id")]
+                    #[transition(SlideLeft)]
+                    BlogPost { id: usize, slug: String },
+                #[end_layout]
+                #[route("/:..segments")]
+                PageNotFound { segments: Vec<String> },
+            }
+        });
+
+        // Transitions default to Fade and ignore the fields
+        assert!(contains(
+            &expanded,
+            "Self::Home {} => TransitionVariant::Fade"
+        ));
+        assert!(contains(
+            &expanded,
+            "Self::BlogPost { id: _, slug: _, } => TransitionVariant::SlideLeft"
+        ));
+        // Components receive every named field
+        assert!(contains(
+            &expanded,
+            "Self::BlogPost { ref id, ref slug, } => { rsx! { BlogPost { id: id.clone(), slug: slug.clone(), } } }"
+        ));
+        assert!(contains(&expanded, "Self::Home {} => { rsx! { Home {} } }"));
+        // Depths in order
+        assert!(contains(&expanded, "Self::Home {} => 1usize"));
+        assert!(contains(
+            &expanded,
+            "Self::BlogPost { id: _, slug: _, } => 1usize"
+        ));
+        assert!(contains(
+            &expanded,
+            "Self::PageNotFound { segments: _, } => 0usize"
+        ));
+        // No transition map unless asked for
+        assert!(!expanded.contains("transition_between"));
+    }
+
+    #[test]
+    fn test_transition_map_generates_transition_between() {
+        let expanded = expand(parse_quote! {
+            #[transition_map]
+            enum Route {
+                #[route("/")]
+                Home {},
+            }
+        });
+        assert!(contains(
+            &expanded,
+            "fn transition_between(from: &Self, to: &Self) -> TransitionVariant { <Self as TransitionMap>::transition(from, to) }"
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+//! Storage for animation callbacks
+//!
+//! Callbacks run on the thread that drives the animations. On wasm that is the
+//! only thread, so callbacks there need not be `Send` and may capture `Rc`
+//! state, and shared callbacks live in an `Rc<RefCell<..>>`. Natively they must
+//! be `Send`, and shared callbacks sit in a `Mutex` behind an `Arc`, only ever
+//! tried: whoever finds it locked skips the callback, as a running callback
+//! firing its own owners finds the `RefCell` borrowed, so firing never waits.
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::{Arc, Mutex};
+#[cfg(target_arch = "wasm32")]
+use std::{cell::RefCell, rc::Rc};
+
+/// `Send` natively; implemented by every type on wasm
+///
+/// Bounds callbacks such as `on_complete`, so closures capturing `Rc` state can
+/// be passed in the browser.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// `Send` natively; implemented by every type on wasm
+///
+/// Bounds callbacks such as `on_complete`, so closures capturing `Rc` state can
+/// be passed in the browser.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: ?Sized + Send> MaybeSend for T {}
+
+/// A boxed callback, run at most once
+#[cfg(target_arch = "wasm32")]
+pub(crate) type Callback = Box<dyn FnOnce()>;
+
+/// A boxed callback, run at most once
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) type Callback = Box<dyn FnOnce() + Send>;
+
+/// Box `callback`
+pub(crate) fn boxed<F: FnOnce() + MaybeSend + 'static>(callback: F) -> Callback {
+    Box::new(callback)
+}
+
+/// A callback shared by several owners, run by whichever fires it first
+#[derive(Clone)]
+pub(crate) struct SharedCallback {
+    #[cfg(target_arch = "wasm32")]
+    callback: Rc<RefCell<Option<Callback>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    callback: Arc<Mutex<Option<Callback>>>,
+}
+
+impl SharedCallback {
+    pub(crate) fn new<F: FnOnce() + MaybeSend + 'static>(callback: F) -> Self {
+        Self {
+            #[cfg(target_arch = "wasm32")]
+            callback: Rc::new(RefCell::new(Some(boxed(callback)))),
+            #[cfg(not(target_arch = "wasm32"))]
+            callback: Arc::new(Mutex::new(Some(boxed(callback)))),
+        }
+    }
+
+    /// Take the callback, leaving nothing for the other owners
+    #[cfg(target_arch = "wasm32")]
+    fn take(&self) -> Option<Callback> {
+        self.callback.borrow_mut().take()
+    }
+
+    /// Take the callback, leaving nothing for the other owners
+    #[cfg(not(target_arch = "wasm32"))]
+    fn take(&self) -> Option<Callback> {
+        // Locked only for the take: an owner finding it locked is too late
+        self.callback.try_lock().ok()?.take()
+    }
+
+    /// Run the callback unless an owner already did; returns whether it ran
+    pub(crate) fn fire(&self) -> bool {
+        // Taken before running, so the callback may fire its own owners
+        let Some(callback) = self.take() else {
+            return false;
+        };
+        callback();
+        true
+    }
+}
+
+/// A callback run each time its event happens, e.g. each time a spring turns
+///
+/// Queued as one [`Callback`] per run, so it fires outside the engine borrow
+/// like the others.
+#[derive(Clone)]
+pub(crate) struct RepeatingCallback {
+    #[cfg(target_arch = "wasm32")]
+    callback: Rc<RefCell<Box<dyn FnMut()>>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    callback: Arc<Mutex<Box<dyn FnMut() + Send>>>,
+}
+
+impl RepeatingCallback {
+    pub(crate) fn new<F: FnMut() + MaybeSend + 'static>(callback: F) -> Self {
+        Self {
+            #[cfg(target_arch = "wasm32")]
+            callback: Rc::new(RefCell::new(Box::new(callback))),
+            #[cfg(not(target_arch = "wasm32"))]
+            callback: Arc::new(Mutex::new(Box::new(callback))),
+        }
+    }
+
+    /// One run of the callback, to queue with the callbacks of ended runs
+    pub(crate) fn once(&self) -> Callback {
+        let shared = self.clone();
+        boxed(move || shared.fire())
+    }
+
+    /// Run the callback, unless it is already running
+    #[cfg(target_arch = "wasm32")]
+    fn fire(&self) {
+        if let Ok(mut callback) = self.callback.try_borrow_mut() {
+            callback();
+        }
+    }
+
+    /// Run the callback, unless it is already running
+    #[cfg(not(target_arch = "wasm32"))]
+    fn fire(&self) {
+        if let Ok(mut callback) = self.callback.try_lock() {
+            callback();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_shared_callback_fires_once() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let callback = SharedCallback::new({
+            let count = count.clone();
+            move || {
+                count.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let other = callback.clone();
+        assert!(callback.fire());
+        assert!(!other.fire());
+        assert!(!callback.fire());
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_repeating_callback_runs_once_per_queued_run() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let callback = RepeatingCallback::new({
+            let count = count.clone();
+            move || {
+                count.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let queued = [callback.once(), callback.once(), callback.once()];
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        for run in queued {
+            run();
+        }
+        assert_eq!(count.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_callback_may_fire_its_owners() {
+        let callback = SharedCallback::new(|| {});
+        let inner = callback.clone();
+        let outer = SharedCallback::new(move || {
+            inner.fire();
+        });
+        assert!(outer.fire());
+        assert!(!callback.fire());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_shared_callback_fires_once_across_threads() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let callback = SharedCallback::new({
+            let count = count.clone();
+            move || {
+                count.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        let fired: usize = std::thread::scope(|scope| {
+            let threads: Vec<_> = (0..8)
+                .map(|_| scope.spawn(|| callback.clone().fire()))
+                .collect();
+            threads
+                .into_iter()
+                .map(|thread| usize::from(thread.join().unwrap_or(false)))
+                .sum()
+        });
+        assert_eq!(fired, 1);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_unfired_callbacks_are_dropped_with_their_owners() {
+        let captured = Arc::new(());
+        let callback = SharedCallback::new({
+            let captured = captured.clone();
+            move || drop(captured)
+        });
+        let repeating = RepeatingCallback::new({
+            let captured = captured.clone();
+            move || {
+                let _ = &captured;
+            }
+        });
+        repeating.once()();
+        assert_eq!(Arc::strong_count(&captured), 3);
+        drop((callback, repeating));
+        assert_eq!(Arc::strong_count(&captured), 1);
+    }
+
+    /// Play a keyframe track inside a sequence to the end, with `keyframes`
+    /// and `sequence` as their completion callbacks
+    fn complete_keyframes_in_sequence(
+        keyframes: impl FnOnce() + MaybeSend + 'static,
+        sequence: impl FnOnce() + MaybeSend + 'static,
+    ) {
+        use crate::animation::{Animation, AnimationState};
+        use crate::animations::keyframe::KeyframeAnimation;
+        use crate::animations::sequence::AnimationSequence;
+
+        let track = KeyframeAnimation::new()
+            .at(0.0, 0.0f32)
+            .at(1.0, 1.0)
+            .for_duration(crate::Duration::from_millis(100))
+            .on_complete(keyframes)
+            .prepared();
+        let mut played = AnimationSequence::new()
+            .then(track)
+            .on_complete(sequence)
+            .start();
+        for _ in 0..100 {
+            if played.update(0.05).0 != AnimationState::Active {
+                break;
+            }
+        }
+    }
+
+    /// Natively, callbacks capturing `Send` values are accepted
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_send_captures_accepted() {
+        let shared = Arc::new(AtomicUsize::new(0));
+        let count = || {
+            let shared = shared.clone();
+            move || {
+                shared.fetch_add(1, Ordering::SeqCst);
+            }
+        };
+        let config = crate::animation::AnimationConfig::default().with_on_complete(count());
+        assert!(config.on_complete.is_some());
+
+        complete_keyframes_in_sequence(count(), count());
+        assert_eq!(shared.load(Ordering::SeqCst), 2);
+    }
+
+    /// On wasm, callbacks may capture `Rc` state
+    #[cfg(target_arch = "wasm32")]
+    #[test]
+    fn test_non_send_captures_accepted() {
+        let shared = std::rc::Rc::new(std::cell::Cell::new(0));
+        let count = || {
+            let shared = shared.clone();
+            move || shared.set(shared.get() + 1)
+        };
+        let config = crate::animation::AnimationConfig::default().with_on_complete(count());
+        if let Some(callback) = config.on_complete {
+            callback();
+        }
+        assert_eq!(shared.get(), 1);
+
+        complete_keyframes_in_sequence(count(), count());
+        assert_eq!(shared.get(), 3);
+    }
+}
@@ -1,5 +1,12 @@
-use crate::animation::timing::LoopMode;
-use crate::animations::{spring::Spring, tween::Tween};
+use std::fmt;
+
+use dioxus::prelude::*;
+
+use crate::animation::timing::{LoopMode, fmt_repeat};
+use crate::animation::{Callback, MaybeSend, boxed};
+use crate::animations::{
+    keyframe::KeyframeTrack, power::AmbientPolicy, spring::Spring, tween::Tween,
+};
 use instant::Duration;
 
 /// Configuration for animations
@@ -11,7 +18,7 @@ pub struct AnimationConfig {
     /// Delay before animation starts
     pub delay: Option<Duration>,
     /// Callback to run on completion
-    pub on_complete: Option<Box<dyn FnOnce() + Send>>,
+    pub on_complete: Option<Callback>,
 }
 
 impl Clone for AnimationConfig {
@@ -25,6 +32,37 @@ impl Clone for AnimationConfig {
     }
 }
 
+impl fmt::Debug for AnimationConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnimationConfig")
+            .field("mode", &self.mode)
+            .field("loop_mode", &self.loop_mode)
+            .field("delay", &self.delay)
+            .field("on_complete", &self.on_complete.is_some())
+            .finish()
+    }
+}
+
+/// A short summary of the mode, loops and delay, e.g.
+/// `Spring(ζ=0.5, f=1.59Hz) ×3 after 200ms`
+impl fmt::Display for AnimationConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mode)?;
+        fmt_repeat(
+            f,
+            self.loop_mode.unwrap_or_default(),
+            self.delay.unwrap_or_default(),
+        )
+    }
+}
+
+impl PartialEq for AnimationConfig {
+    /// Compares the animation parameters; completion callbacks are ignored
+    fn eq(&self, other: &Self) -> bool {
+        self.mode == other.mode && self.loop_mode == other.loop_mode && self.delay == other.delay
+    }
+}
+
 impl Default for AnimationConfig {
     fn default() -> Self {
         Self {
@@ -60,17 +98,442 @@ impl AnimationConfig {
     }
 
     /// Set the completion callback
-    pub fn with_on_complete<F: FnOnce() + Send + 'static>(mut self, callback: F) -> Self {
-        self.on_complete = Some(Box::new(callback));
+    ///
+    /// It runs once the value rests on its target and the animation has been
+    /// cleared. An animate call made from it, e.g. to chain the next step,
+    /// starts at the next frame even if another call is made in between, see
+    /// [`AnimationEngine::queue_to`](crate::AnimationEngine::queue_to).
+    pub fn with_on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.on_complete = Some(boxed(callback));
+        self
+    }
+
+    /// Check that the configuration describes a playable animation
+    ///
+    /// Useful for configs coming from a tuning panel or deserialization, where
+    /// a NaN stiffness or zero-length tween would otherwise only show up as a
+    /// broken animation at runtime.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        match &self.mode {
+            AnimationMode::Spring(spring) => spring.validate()?,
+            AnimationMode::Tween(tween) => tween.validate()?,
+            AnimationMode::Keyframes(track) => track.validate()?,
+        }
+
+        if let Some(LoopMode::Count(0) | LoopMode::AlternateTimes(0)) = self.loop_mode {
+            return Err(ConfigError::ZeroLoopCount);
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned when an animation configuration is malformed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    /// A parameter is NaN or infinite
+    NonFinite {
+        /// Name of the offending parameter
+        parameter: &'static str,
+        /// The rejected value
+        value: f32,
+    },
+    /// A parameter that must be strictly positive is zero or negative
+    NotPositive {
+        /// Name of the offending parameter
+        parameter: &'static str,
+        /// The rejected value
+        value: f32,
+    },
+    /// A parameter that must not be negative is below zero
+    Negative {
+        /// Name of the offending parameter
+        parameter: &'static str,
+        /// The rejected value
+        value: f32,
+    },
+    /// A duration that must be non-zero is zero
+    ZeroDuration {
+        /// Name of the offending duration
+        parameter: &'static str,
+    },
+    /// `LoopMode::Count(0)` or `LoopMode::AlternateTimes(0)` would never play
+    ZeroLoopCount,
+    /// A keyframe track has no keyframes
+    EmptyKeyframes,
+    /// A keyframe position is outside 0.0..=1.0 or not a number
+    InvalidKeyframePosition {
+        /// The rejected position
+        position: f32,
+    },
+    /// Two `ease_between` declarations cover the same segment
+    OverlappingEasingRanges {
+        /// The earlier declared range
+        first: (f32, f32),
+        /// The range overlapping it
+        second: (f32, f32),
+    },
+    /// An `ease_between` range does not fully contain any keyframe segment
+    EasingRangeMatchesNoSegment {
+        /// The declared range
+        range: (f32, f32),
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFinite { parameter, value } => {
+                write!(f, "`{parameter}` must be finite, got {value}")
+            }
+            Self::NotPositive { parameter, value } => {
+                write!(f, "`{parameter}` must be greater than zero, got {value}")
+            }
+            Self::Negative { parameter, value } => {
+                write!(f, "`{parameter}` must not be negative, got {value}")
+            }
+            Self::ZeroDuration { parameter } => write!(f, "`{parameter}` must not be zero"),
+            Self::ZeroLoopCount => write!(f, "a loop count of 0 never plays"),
+            Self::EmptyKeyframes => write!(f, "keyframe animation has no keyframes"),
+            Self::InvalidKeyframePosition { position } => {
+                write!(
+                    f,
+                    "keyframe position must be within 0.0..=1.0, got {position}"
+                )
+            }
+            Self::OverlappingEasingRanges { first, second } => write!(
+                f,
+                "easing ranges {}..{} and {}..{} overlap",
+                first.0, first.1, second.0, second.1
+            ),
+            Self::EasingRangeMatchesNoSegment { range } => write!(
+                f,
+                "easing range {}..{} does not contain any keyframe segment",
+                range.0, range.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Validate that `value` is finite and strictly positive
+pub(crate) fn check_positive(parameter: &'static str, value: f32) -> Result<(), ConfigError> {
+    if !value.is_finite() {
+        Err(ConfigError::NonFinite { parameter, value })
+    } else if value <= 0.0 {
+        Err(ConfigError::NotPositive { parameter, value })
+    } else {
+        Ok(())
+    }
+}
+
+/// Validate that `value` is finite and not negative
+pub(crate) fn check_non_negative(parameter: &'static str, value: f32) -> Result<(), ConfigError> {
+    if !value.is_finite() {
+        Err(ConfigError::NonFinite { parameter, value })
+    } else if value < 0.0 {
+        Err(ConfigError::Negative { parameter, value })
+    } else {
+        Ok(())
+    }
+}
+
+/// Environment variable switching on [`MotionConfig::deterministic_mode`],
+/// holding the time step in seconds
+pub const FIXED_DT_VAR: &str = "DIOXUS_MOTION_FIXED_DT";
+
+/// App-wide animation settings, provided as a context
+///
+/// Motion values created below the provider pick it up when they are created.
+/// Provide it with [`use_motion_config`]. More settings may come, so build it
+/// with [`MotionConfig::new`] and the builder methods rather than a literal.
+///
+/// ```ignore
+/// // Make every plain `animate_to` in the app a 250ms tween
+/// use_motion_config(|| {
+///     MotionConfig::new().with_default_animation(AnimationMode::Tween(
+///         Tween::new().duration(Duration::from_millis(250)),
+///     ))
+/// });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct MotionConfig {
+    /// Animation for plain `animate_to` calls, replacing each type's
+    /// [`Animatable::preferred_animation`](crate::Animatable::preferred_animation)
+    pub default_animation: Option<AnimationMode>,
+    /// Time step of every frame in deterministic mode, `None` for the adaptive loop
+    pub fixed_dt: Option<f32>,
+    /// How animations marked `.ambient()` cut back on the user's power preferences
+    pub ambient_policy: AmbientPolicy,
+}
+
+impl MotionConfig {
+    /// Create a configuration that keeps every default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `mode` for every plain `animate_to`, whatever the value's type
+    pub fn with_default_animation(mut self, mode: AnimationMode) -> Self {
+        self.default_animation = Some(mode);
+        self
+    }
+
+    /// Cut back animations marked `.ambient()` as `policy` says
+    ///
+    /// See [`AmbientPolicy`] for the defaults.
+    pub fn with_ambient_policy(mut self, policy: AmbientPolicy) -> Self {
+        self.ambient_policy = policy;
+        self
+    }
+
+    /// Advance every animation by exactly `fixed_dt` seconds per frame
+    ///
+    /// For reproducible profiling: the driver ignores the real frame time,
+    /// never slows down while idle and never defers low priority engines, so
+    /// every run does the same work. Applies to the whole thread while the
+    /// [`use_motion_config`] provider is mounted.
+    pub fn deterministic_mode(mut self, fixed_dt: f32) -> Self {
+        self.fixed_dt = Some(fixed_dt);
+        self
+    }
+
+    /// Apply the [`FIXED_DT_VAR`] environment variable, if set to a positive
+    /// number of seconds
+    fn with_env_fixed_dt(mut self, value: Option<String>) -> Self {
+        let fixed_dt = value
+            .and_then(|value| value.trim().parse::<f32>().ok())
+            .filter(|dt| dt.is_finite() && *dt > 0.0);
+        if fixed_dt.is_some() {
+            self.fixed_dt = fixed_dt;
+        }
         self
     }
 }
 
-/// Mode of animation (spring or tween)
+/// Provide app-wide animation settings to every motion value below
+///
+/// `init` runs once. At that point the [`FIXED_DT_VAR`] environment variable is
+/// read, and when it holds a time step, it turns on
+/// [`MotionConfig::deterministic_mode`] with that step, e.g. for a CI profiling
+/// run. Deterministic mode ends, and the adaptive loop takes over again, when
+/// the providing component unmounts.
+pub fn use_motion_config(init: impl FnOnce() -> MotionConfig) -> MotionConfig {
+    let config = use_hook(|| {
+        let config = init().with_env_fixed_dt(std::env::var(FIXED_DT_VAR).ok());
+        crate::driver::set_fixed_dt(config.fixed_dt);
+        provide_context(config)
+    });
+    let deterministic = config.fixed_dt.is_some();
+    use_drop(move || {
+        if deterministic {
+            crate::driver::set_fixed_dt(None);
+        }
+    });
+    config
+}
+
+/// Mode of animation (spring, tween or keyframes)
 #[derive(Debug, Clone)]
 pub enum AnimationMode {
     /// Spring-based physics animation
     Spring(Spring),
     /// Time-based tween animation
     Tween(Tween),
+    /// Keyframes placed relative to the start value and the target
+    Keyframes(KeyframeTrack),
+}
+
+impl fmt::Display for AnimationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Spring(spring) => spring.fmt(f),
+            Self::Tween(tween) => tween.fmt(f),
+            Self::Keyframes(track) => track.fmt(f),
+        }
+    }
+}
+
+impl PartialEq for AnimationMode {
+    /// Compares every parameter of the mode; completion callbacks in a
+    /// spring's timing are ignored
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Spring(a), Self::Spring(b)) => {
+                a.stiffness == b.stiffness
+                    && a.damping == b.damping
+                    && a.mass == b.mass
+                    && a.initial_velocity == b.initial_velocity
+                    && a.timing == b.timing
+            }
+            (Self::Tween(a), Self::Tween(b)) => {
+                a.duration == b.duration
+                    && std::ptr::fn_addr_eq(a.easing, b.easing)
+                    && a.winding == b.winding
+            }
+            (Self::Keyframes(a), Self::Keyframes(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_valid() {
+        assert_eq!(AnimationConfig::default().validate(), Ok(()));
+        let tween = AnimationConfig::new(AnimationMode::Tween(Tween::default()));
+        assert_eq!(tween.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_spring_parameters() {
+        let spring = Spring {
+            stiffness: f32::NAN,
+            ..Spring::default()
+        };
+        let error = AnimationConfig::new(AnimationMode::Spring(spring))
+            .validate()
+            .expect_err("NaN stiffness must be rejected");
+        assert!(matches!(
+            error,
+            ConfigError::NonFinite {
+                parameter: "stiffness",
+                ..
+            }
+        ));
+
+        let spring = Spring {
+            mass: 0.0,
+            ..Spring::default()
+        };
+        assert_eq!(
+            AnimationConfig::new(AnimationMode::Spring(spring)).validate(),
+            Err(ConfigError::NotPositive {
+                parameter: "mass",
+                value: 0.0
+            })
+        );
+
+        let spring = Spring {
+            damping: -1.0,
+            ..Spring::default()
+        };
+        assert_eq!(
+            AnimationConfig::new(AnimationMode::Spring(spring)).validate(),
+            Err(ConfigError::Negative {
+                parameter: "damping",
+                value: -1.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_tween_and_loop() {
+        let tween = Tween::new().duration(Duration::ZERO);
+        assert_eq!(
+            AnimationConfig::new(AnimationMode::Tween(tween)).validate(),
+            Err(ConfigError::ZeroDuration {
+                parameter: "duration"
+            })
+        );
+
+        let config = AnimationConfig::default().with_loop(LoopMode::Count(0));
+        assert_eq!(config.validate(), Err(ConfigError::ZeroLoopCount));
+    }
+
+    #[test]
+    fn test_error_messages_are_descriptive() {
+        let error = ConfigError::NotPositive {
+            parameter: "stiffness",
+            value: -5.0,
+        };
+        assert_eq!(
+            error.to_string(),
+            "`stiffness` must be greater than zero, got -5"
+        );
+    }
+
+    #[test]
+    fn test_env_fixed_dt_overrides_config() {
+        let env = |value: &str| MotionConfig::new().with_env_fixed_dt(Some(value.to_string()));
+        assert_eq!(env("0.008").fixed_dt, Some(0.008));
+        assert_eq!(env(" 0.02\n").fixed_dt, Some(0.02));
+
+        // Unusable values leave the config as it was
+        for value in ["", "fast", "0", "-0.01", "inf", "NaN"] {
+            assert_eq!(env(value).fixed_dt, None, "{value:?}");
+        }
+        let programmatic = MotionConfig::new().deterministic_mode(0.01);
+        assert_eq!(
+            programmatic.clone().with_env_fixed_dt(None).fixed_dt,
+            Some(0.01)
+        );
+        assert_eq!(
+            programmatic
+                .with_env_fixed_dt(Some("0.005".into()))
+                .fixed_dt,
+            Some(0.005)
+        );
+    }
+
+    #[test]
+    fn test_configs_display_a_short_summary() {
+        use crate::properties::transform::RotationWinding;
+
+        assert_eq!(Spring::default().to_string(), "Spring(ζ=0.5, f=1.59Hz)");
+        assert_eq!(Spring::snappy().to_string(), "Spring(ζ=1, f=3.18Hz)");
+        let pushed = Spring::default().initial_velocity(-40.0);
+        assert_eq!(pushed.to_string(), "Spring(ζ=0.5, f=1.59Hz, v=-40)");
+        assert_eq!(Tween::default().to_string(), "Tween(300ms)");
+        let spinner = Tween::new()
+            .duration(Duration::from_millis(1500))
+            .winding(RotationWinding::Turns(2));
+        assert_eq!(spinner.to_string(), "Tween(1.5s, Turns(2))");
+
+        let config = AnimationConfig::default()
+            .with_loop(LoopMode::Count(3))
+            .with_delay(Duration::from_millis(200));
+        assert_eq!(config.to_string(), "Spring(ζ=0.5, f=1.59Hz) ×3 after 200ms");
+        let forever = AnimationConfig::new(AnimationMode::Tween(Tween::default()))
+            .with_loop(LoopMode::Infinite);
+        assert_eq!(forever.to_string(), "Tween(300ms) looping");
+        // Debug still shows every parameter
+        assert!(format!("{config:?}").contains("stiffness: 100.0"));
+    }
+
+    #[test]
+    fn test_modes_differing_in_winding_or_timing_are_unequal() {
+        use crate::animation::timing::AnimationTiming;
+        use crate::properties::transform::RotationWinding;
+
+        let tween = Tween::default();
+        let spinner = tween.winding(RotationWinding::Turns(2));
+        assert_eq!(AnimationMode::Tween(tween), AnimationMode::Tween(tween));
+        assert_ne!(AnimationMode::Tween(tween), AnimationMode::Tween(spinner));
+
+        let spring = Spring::default();
+        let delayed = Spring {
+            timing: AnimationTiming::new().with_delay(Duration::from_millis(200)),
+            ..spring.clone()
+        };
+        assert_ne!(
+            AnimationMode::Spring(spring.clone()),
+            AnimationMode::Spring(delayed)
+        );
+
+        // A completion callback alone doesn't make a difference
+        let notifying = Spring {
+            timing: AnimationTiming::new().with_on_complete(|| {}),
+            ..spring.clone()
+        };
+        assert_eq!(
+            AnimationMode::Spring(spring),
+            AnimationMode::Spring(notifying)
+        );
+    }
 }
@@ -1,9 +1,19 @@
+mod callback;
 mod config;
+pub mod presets;
 mod state;
 pub mod timing;
+mod token;
 mod traits;
 
-pub use config::{AnimationConfig, AnimationMode};
+pub use callback::MaybeSend;
+pub(crate) use callback::{Callback, RepeatingCallback, SharedCallback, boxed};
+pub use config::{
+    AnimationConfig, AnimationMode, ConfigError, FIXED_DT_VAR, MotionConfig, use_motion_config,
+};
+pub(crate) use config::{check_non_negative, check_positive};
 pub use state::AnimationState;
 pub use timing::{AnimationTiming, LoopMode, PlaybackDirection};
+pub(crate) use token::Run;
+pub use token::{AnimationToken, Finished, RunOutcome};
 pub use traits::Animation;
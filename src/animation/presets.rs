@@ -0,0 +1,193 @@
+//! Ready-made animations with a recognizable feel
+//!
+//! Each preset is tuned for a common UI gesture, so a first animation looks
+//! right without picking stiffness or easing by hand. Presets that move to a
+//! target return an [`AnimationConfig`] for
+//! [`MotionValue::animate_to_with_config`](crate::MotionValue::animate_to_with_config);
+//! presets that move and come back, like a shake, return a keyframe track to
+//! [`start`](KeyframeAnimation::start) on a motion value.
+//!
+//! | Preset | Kind | Feel |
+//! |---|---|---|
+//! | [`fade_in`] | 200ms tween, cubic ease-out | Quick to appear, gentle landing |
+//! | [`fade_out`] | 150ms tween, cubic ease-in | Leaves faster than it came |
+//! | [`slide_up`] | Critically damped spring | Glides into place without bouncing |
+//! | [`pop`] | Underdamped spring | Overshoots by about 15%, then settles |
+//! | [`shake`] | 450ms offset track | Side to side, dying down, back to rest |
+//! | [`pulse`] | Looping scale track | Breathes in and out around 1 |
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Badge() -> Element {
+//!     let mut scale = use_motion(0.0f32);
+//!     let mut offset = use_motion(0.0f32);
+//!     use_effect(move || {
+//!         scale.animate_to_with_config(1.0, presets::pop());
+//!     });
+//!
+//!     rsx! {
+//!         button {
+//!             style: "transform: translateX({offset.get()}px) scale({scale.get()})",
+//!             onclick: move |_| {
+//!                 presets::shake(8.0).start(&mut offset);
+//!             },
+//!             "New"
+//!         }
+//!     }
+//! }
+//! ```
+
+use easer::functions::{Cubic, Easing, Sine};
+
+use crate::Duration;
+use crate::animation::timing::LoopMode;
+use crate::animation::{AnimationConfig, AnimationMode};
+use crate::animations::keyframe::KeyframeAnimation;
+use crate::animations::{spring::Spring, tween::Tween};
+
+/// Fade something in, e.g. an opacity to `1.0`
+pub fn fade_in() -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new()
+            .duration(Duration::from_millis(200))
+            .easing(Cubic::ease_out),
+    ))
+}
+
+/// Fade something out, e.g. an opacity to `0.0`
+pub fn fade_out() -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Tween(
+        Tween::new()
+            .duration(Duration::from_millis(150))
+            .easing(Cubic::ease_in),
+    ))
+}
+
+/// Slide content into place, e.g. a translation from `16.0` to `0.0`
+///
+/// Critically damped, so it never passes its target.
+pub fn slide_up() -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Spring(
+        Spring::new().stiffness(300.0).damping(2.0 * 300f32.sqrt()),
+    ))
+}
+
+/// Pop something into view, e.g. a scale from `0.0` to `1.0`
+///
+/// A lively spring overshooting its target by about 15% before settling.
+pub fn pop() -> AnimationConfig {
+    AnimationConfig::new(AnimationMode::Spring(
+        Spring::new().stiffness(500.0).damping(22.0),
+    ))
+}
+
+/// Shake an offset side to side by up to `distance`, ending where it started
+///
+/// Meant for a translation resting at `0.0`, e.g. a form rejecting its input.
+pub fn shake(distance: f32) -> KeyframeAnimation<f32> {
+    KeyframeAnimation::new()
+        .at(0.0, 0.0)
+        .at(0.15, -distance)
+        .at(0.3, distance)
+        .at(0.45, -0.6 * distance)
+        .at(0.6, 0.6 * distance)
+        .at(0.75, -0.3 * distance)
+        .at(0.9, 0.3 * distance)
+        .at(1.0, 0.0)
+        .for_duration(Duration::from_millis(450))
+}
+
+/// Grow a scale to `peak` and back to `1.0`, forever
+///
+/// Meant for a scale resting at `1.0`, e.g. drawing attention to a badge. Stop
+/// it with [`MotionValue::stop_at_cycle_end`](crate::MotionValue::stop_at_cycle_end)
+/// to let it ease back to `1.0` rather than freeze mid-pulse.
+pub fn pulse(peak: f32) -> KeyframeAnimation<f32> {
+    KeyframeAnimation::new()
+        .at(0.0, 1.0)
+        .at(0.5, peak)
+        .at(1.0, 1.0)
+        .default_easing(Sine::ease_in_out)
+        .for_duration(Duration::from_millis(1200))
+        .looping(LoopMode::Infinite)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+
+    /// Play `config` from `from` to `to` at 60fps, returning every frame's value
+    fn frames(config: AnimationConfig, from: f32, to: f32) -> Vec<f32> {
+        let mut engine = AnimationEngine::new(from);
+        match config.mode {
+            AnimationMode::Spring(spring) => engine.spring_to(to, spring),
+            AnimationMode::Tween(tween) => engine.tween_to(to, tween),
+            AnimationMode::Keyframes(track) => {
+                engine.apply_keyframes(track.create_animation(from, to))
+            }
+        }
+        let mut values = Vec::new();
+        while engine.update(1.0 / 60.0) {
+            values.push(engine.get());
+            assert!(values.len() < 600, "the preset should come to rest");
+        }
+        values.push(engine.get());
+        values
+    }
+
+    #[test]
+    fn test_pop_overshoots_then_settles() {
+        let values = frames(pop(), 0.0, 1.0);
+        let peak = values.iter().copied().fold(f32::MIN, f32::max);
+        assert!(peak > 1.08 && peak < 1.25, "peak {peak}");
+        assert!(
+            values.len() < 60,
+            "settles within a second, took {}",
+            values.len()
+        );
+        assert!((values[values.len() - 1] - 1.0).abs() < 0.01);
+
+        assert_eq!(pop().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_slide_up_never_passes_its_target() {
+        let values = frames(slide_up(), 16.0, 0.0);
+        assert!(values.iter().all(|value| *value >= -0.01));
+        assert!(values[values.len() - 1].abs() < 0.01);
+    }
+
+    #[test]
+    fn test_fades_are_short_tweens() {
+        let appear = frames(fade_in(), 0.0, 1.0);
+        let leave = frames(fade_out(), 1.0, 0.0);
+        assert!(leave.len() < appear.len());
+        assert!(appear.len() <= 14);
+        // Fading in starts fast, fading out starts slow
+        assert!(appear[0] > 0.1);
+        assert!(leave[0] > 0.99);
+    }
+
+    #[test]
+    fn test_shake_returns_to_rest() {
+        let shake = shake(8.0);
+        assert_eq!(shake.validate(), Ok(()));
+        assert_eq!(shake.sample(0.0), 0.0);
+        assert_eq!(shake.sample(0.15), -8.0);
+        assert_eq!(shake.sample(0.3), 8.0);
+        assert_eq!(shake.sample(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_pulse_peaks_halfway() {
+        let pulse = pulse(1.05);
+        assert_eq!(pulse.validate(), Ok(()));
+        assert_eq!(pulse.sample(0.0), 1.0);
+        assert!((pulse.sample(0.5) - 1.05).abs() < 1e-6);
+        assert_eq!(pulse.sample(1.0), 1.0);
+    }
+}
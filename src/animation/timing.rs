@@ -1,8 +1,18 @@
 use instant::Duration;
-use std::sync::{Arc, Mutex};
+use std::fmt;
+
+use crate::animation::{MaybeSend, SharedCallback};
 
 /// Animation loop mode
+///
+/// New modes may be added, so matches on it need a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "clips",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[non_exhaustive]
 pub enum LoopMode {
     /// No looping - animation plays once
     None,
@@ -10,6 +20,23 @@ pub enum LoopMode {
     Infinite,
     /// Animation repeats a specific number of times
     Count(u32),
+    /// Animation plays to the target and back again, reversing at each end
+    /// instead of snapping back to the start, indefinitely
+    ///
+    /// With [`PlaybackDirection::Alternate`] or
+    /// [`AlternateReverse`](PlaybackDirection::AlternateReverse) the passes
+    /// still alternate once rather than the two cancelling out.
+    Alternate,
+    /// Like [`Alternate`](Self::Alternate) for a specific number of passes,
+    /// each way counting as one, e.g. `AlternateTimes(2)` there and back
+    AlternateTimes(u32),
+}
+
+impl LoopMode {
+    /// Whether every other pass plays backwards
+    pub fn alternates(self) -> bool {
+        matches!(self, Self::Alternate | Self::AlternateTimes(_))
+    }
 }
 
 impl Default for LoopMode {
@@ -18,7 +45,48 @@ impl Default for LoopMode {
     }
 }
 
+/// Write how a run repeats and waits, e.g. ` ×3 after 200ms`, for the
+/// summaries of springs, tweens and configs; nothing for a single run
+pub(crate) fn fmt_repeat(
+    f: &mut fmt::Formatter<'_>,
+    loop_mode: LoopMode,
+    delay: Duration,
+) -> fmt::Result {
+    match loop_mode {
+        LoopMode::None => {}
+        LoopMode::Infinite => f.write_str(" looping")?,
+        LoopMode::Count(count) => write!(f, " ×{count}")?,
+        LoopMode::Alternate => f.write_str(" alternating")?,
+        LoopMode::AlternateTimes(count) => write!(f, " ×{count} alternating")?,
+    }
+    if !delay.is_zero() {
+        write!(f, " after {delay:?}")?;
+    }
+    Ok(())
+}
+
+/// A number with at most two decimals and no trailing zeros
+pub(crate) struct Rounded(pub(crate) f32);
+
+impl fmt::Display for Rounded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = format!("{:.2}", self.0);
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        f.write_str(if text == "-0" { "0" } else { text })
+    }
+}
+
 /// Animation playback direction
+///
+/// ```
+/// use dioxus_motion2::prelude::*;
+/// use dioxus_motion2::{AnimationTiming, PlaybackDirection};
+///
+/// let timing = AnimationTiming::new()
+///     .with_loop_mode(LoopMode::Count(2))
+///     .with_direction(PlaybackDirection::Reverse);
+/// assert!(timing.is_reverse());
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlaybackDirection {
     /// Forward playback (normal)
@@ -50,8 +118,9 @@ pub struct AnimationTiming {
     pub current_loop: u32,
     /// Whether delay has elapsed
     pub delay_elapsed: bool,
-    /// Completion callback
-    pub on_complete: Option<Arc<Mutex<dyn FnMut() + Send>>>,
+    /// Completion callback, shared by the copies of this timing and run by
+    /// the first of them to complete
+    pub(crate) on_complete: Option<SharedCallback>,
 }
 
 impl Default for AnimationTiming {
@@ -80,6 +149,18 @@ impl std::fmt::Debug for AnimationTiming {
     }
 }
 
+impl PartialEq for AnimationTiming {
+    /// Compares the timing parameters and playback state; completion callbacks
+    /// are ignored
+    fn eq(&self, other: &Self) -> bool {
+        self.loop_mode == other.loop_mode
+            && self.direction == other.direction
+            && self.delay == other.delay
+            && self.current_loop == other.current_loop
+            && self.delay_elapsed == other.delay_elapsed
+    }
+}
+
 impl AnimationTiming {
     /// Create a new animation timing configuration
     pub fn new() -> Self {
@@ -104,13 +185,12 @@ impl AnimationTiming {
         self
     }
 
-    pub fn with_on_complete<F>(mut self, f: F) -> Self
-    where
-        F: FnMut() + Send + 'static,
-    {
-        self.on_complete = Some(Arc::new(Mutex::new(f)));
+    /// Run `callback` once the animation completes its last pass
+    pub fn with_on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.on_complete = Some(SharedCallback::new(callback));
         self
     }
+
     /// Handle the delay
     pub fn handle_delay(&mut self, dt: f32) -> bool {
         if self.delay_elapsed {
@@ -139,60 +219,150 @@ impl AnimationTiming {
             LoopMode::None => {
                 // Execute completion callback if provided
                 if let Some(on_complete) = &self.on_complete {
-                    if let Ok(mut callback) = on_complete.lock() {
-                        callback();
-                    }
+                    on_complete.fire();
                 }
                 false
             }
-            LoopMode::Infinite => {
+            LoopMode::Infinite | LoopMode::Alternate => {
+                // Alternating directions follow the pass count, see `is_reverse`
                 self.current_loop += 1;
-                // Toggle direction if alternating
-                if self.direction == PlaybackDirection::Alternate
-                    || self.direction == PlaybackDirection::AlternateReverse
-                {
-                    self.direction = match self.direction {
-                        PlaybackDirection::Alternate => PlaybackDirection::AlternateReverse,
-                        PlaybackDirection::AlternateReverse => PlaybackDirection::Alternate,
-                        _ => self.direction,
-                    };
-                }
                 true
             }
-            LoopMode::Count(count) => {
+            LoopMode::Count(count) | LoopMode::AlternateTimes(count) => {
                 self.current_loop += 1;
                 if self.current_loop >= count {
                     // Execute completion callback if provided
                     if let Some(on_complete) = &self.on_complete {
-                        if let Ok(mut callback) = on_complete.lock() {
-                            callback();
-                        }
+                        on_complete.fire();
                     }
                     false
                 } else {
-                    // Toggle direction if alternating
-                    if self.direction == PlaybackDirection::Alternate
-                        || self.direction == PlaybackDirection::AlternateReverse
-                    {
-                        self.direction = match self.direction {
-                            PlaybackDirection::Alternate => PlaybackDirection::AlternateReverse,
-                            PlaybackDirection::AlternateReverse => PlaybackDirection::Alternate,
-                            _ => self.direction,
-                        };
-                    }
                     true
                 }
             }
         }
     }
 
+    /// Time left before playback starts
+    pub fn remaining_delay(&self) -> Duration {
+        if self.delay_elapsed {
+            Duration::ZERO
+        } else {
+            self.delay
+        }
+    }
+
+    /// Number of passes to play in total, `None` when looping forever
+    pub fn total_loops(&self) -> Option<u32> {
+        match self.loop_mode {
+            LoopMode::None => Some(1),
+            LoopMode::Infinite | LoopMode::Alternate => None,
+            LoopMode::Count(count) | LoopMode::AlternateTimes(count) => Some(count.max(1)),
+        }
+    }
+
+    /// Make the current pass the last one, so looping stops at its boundary
+    ///
+    /// Does nothing to an animation that plays once.
+    pub fn finish_current_loop(&mut self) {
+        let passes = self.current_loop + 1;
+        self.loop_mode = match self.loop_mode {
+            LoopMode::None => LoopMode::None,
+            LoopMode::Infinite | LoopMode::Count(_) => LoopMode::Count(passes),
+            LoopMode::Alternate | LoopMode::AlternateTimes(_) => LoopMode::AlternateTimes(passes),
+        };
+    }
+
+    /// Number of passes still to play after the current one, `None` when looping forever
+    pub fn remaining_loops(&self) -> Option<u32> {
+        self.total_loops()
+            .map(|total| total.saturating_sub(self.current_loop + 1))
+    }
+
     /// Get whether animation should play in reverse for current loop
+    ///
+    /// An alternating [`LoopMode`] flips the direction on every other pass.
+    /// An alternating direction already does, so the two together alternate
+    /// once rather than playing every pass the same way.
     pub fn is_reverse(&self) -> bool {
+        let odd = self.current_loop % 2 == 1;
         match self.direction {
-            PlaybackDirection::Forward => false,
-            PlaybackDirection::Reverse => true,
-            PlaybackDirection::Alternate => self.current_loop % 2 == 1,
-            PlaybackDirection::AlternateReverse => self.current_loop % 2 == 0,
+            PlaybackDirection::Forward => self.loop_mode.alternates() && odd,
+            PlaybackDirection::Reverse => !(self.loop_mode.alternates() && odd),
+            PlaybackDirection::Alternate => odd,
+            PlaybackDirection::AlternateReverse => !odd,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alternating_passes_switch_direction() {
+        let mut timing = AnimationTiming::new()
+            .with_loop_mode(LoopMode::Count(4))
+            .with_direction(PlaybackDirection::Alternate);
+        let mut reversed = Vec::new();
+        loop {
+            reversed.push(timing.is_reverse());
+            if !timing.handle_loop_completion() {
+                break;
+            }
+        }
+        assert_eq!(reversed, [false, true, false, true]);
+    }
+
+    #[test]
+    fn test_alternating_loop_modes_reverse_every_other_pass() {
+        let mut timing = AnimationTiming::new().with_loop_mode(LoopMode::AlternateTimes(3));
+        assert_eq!(timing.total_loops(), Some(3));
+        let mut reversed = Vec::new();
+        loop {
+            reversed.push(timing.is_reverse());
+            if !timing.handle_loop_completion() {
+                break;
+            }
         }
+        assert_eq!(reversed, [false, true, false]);
+
+        // Forever, and stopping at a boundary keeps the alternation
+        let mut timing = AnimationTiming::new().with_loop_mode(LoopMode::Alternate);
+        assert_eq!(timing.total_loops(), None);
+        assert!(timing.handle_loop_completion());
+        timing.finish_current_loop();
+        assert_eq!(timing.loop_mode, LoopMode::AlternateTimes(2));
+        assert!(timing.is_reverse());
+        assert!(!timing.handle_loop_completion());
+    }
+
+    #[test]
+    fn test_alternating_loop_and_direction_alternate_once() {
+        let passes = |direction| {
+            let mut timing = AnimationTiming::new()
+                .with_loop_mode(LoopMode::AlternateTimes(4))
+                .with_direction(direction);
+            let mut reversed = Vec::new();
+            loop {
+                reversed.push(timing.is_reverse());
+                if !timing.handle_loop_completion() {
+                    break;
+                }
+            }
+            reversed
+        };
+        assert_eq!(
+            passes(PlaybackDirection::Alternate),
+            [false, true, false, true]
+        );
+        assert_eq!(
+            passes(PlaybackDirection::AlternateReverse),
+            [true, false, true, false]
+        );
+        assert_eq!(
+            passes(PlaybackDirection::Reverse),
+            [true, false, true, false]
+        );
     }
 }
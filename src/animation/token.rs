@@ -0,0 +1,175 @@
+//! Handles to individual animation runs
+//!
+//! Every animation started on a motion value is a run. Starting one returns an
+//! [`AnimationToken`] for it, which can check whether the run is still going,
+//! cancel exactly that run, or be awaited until it ends.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Source of run ids, unique across all motion values
+static NEXT_RUN_ID: AtomicU64 = AtomicU64::new(1);
+
+/// How an animation run ended
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RunOutcome {
+    /// The animation settled on its own
+    Completed,
+    /// The animation was stopped, overwritten by a `set` or its owner unmounted
+    Cancelled,
+    /// A new animation was started on the same value
+    Replaced,
+}
+
+#[derive(Default)]
+struct RunState {
+    outcome: Option<RunOutcome>,
+    wakers: Vec<Waker>,
+}
+
+/// The engine's side of a run, ended exactly once
+pub(crate) struct Run {
+    id: u64,
+    state: Arc<Mutex<RunState>>,
+}
+
+impl Run {
+    /// Begin a new run with a fresh id
+    pub(crate) fn new() -> Self {
+        Self {
+            id: NEXT_RUN_ID.fetch_add(1, Ordering::Relaxed),
+            state: Arc::default(),
+        }
+    }
+
+    pub(crate) fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// A token for this run
+    pub(crate) fn token(&self) -> AnimationToken {
+        AnimationToken {
+            id: self.id,
+            state: self.state.clone(),
+        }
+    }
+
+    /// Record the outcome, unless already ended, and wake every task awaiting it
+    pub(crate) fn end(&self, outcome: RunOutcome) {
+        let wakers = match self.state.lock() {
+            Ok(mut state) if state.outcome.is_none() => {
+                state.outcome = Some(outcome);
+                std::mem::take(&mut state.wakers)
+            }
+            _ => return,
+        };
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for Run {
+    /// A run dropped with its engine counts as cancelled, so no task waits forever
+    fn drop(&mut self) {
+        self.end(RunOutcome::Cancelled);
+    }
+}
+
+/// Handle to one animation run
+///
+/// Returned by `animate_to` and the builders. Cheap to clone; it doesn't keep
+/// the motion value alive.
+///
+/// # Example
+/// ```ignore
+/// let token = motion.animate_to(100.0);
+/// // Later: only stops the animation if nothing replaced it meanwhile
+/// motion.cancel(&token);
+///
+/// // Or, in an async task
+/// if token.finished().await == RunOutcome::Completed {
+///     next_step();
+/// }
+/// ```
+#[derive(Clone)]
+pub struct AnimationToken {
+    id: u64,
+    state: Arc<Mutex<RunState>>,
+}
+
+impl AnimationToken {
+    /// A token for a run that already ended
+    pub(crate) fn ended() -> Self {
+        Run::new().token()
+    }
+
+    /// Unique id of the run
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Whether the run is still going
+    pub fn is_active(&self) -> bool {
+        self.outcome().is_none()
+    }
+
+    /// How the run ended, or `None` while it is still going
+    pub fn outcome(&self) -> Option<RunOutcome> {
+        self.state
+            .lock()
+            .map_or(Some(RunOutcome::Cancelled), |state| state.outcome)
+    }
+
+    /// Wait for the run to end
+    ///
+    /// The future relies only on its waker, so it runs on any executor, in the
+    /// browser as well as natively.
+    pub fn finished(&self) -> Finished {
+        Finished {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl std::fmt::Debug for AnimationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnimationToken")
+            .field("id", &self.id)
+            .field("outcome", &self.outcome())
+            .finish()
+    }
+}
+
+impl PartialEq for AnimationToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for AnimationToken {}
+
+/// Future returned by [`AnimationToken::finished`], resolving to the run's outcome
+pub struct Finished {
+    state: Arc<Mutex<RunState>>,
+}
+
+impl Future for Finished {
+    type Output = RunOutcome;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<RunOutcome> {
+        let Ok(mut state) = self.state.lock() else {
+            return Poll::Ready(RunOutcome::Cancelled);
+        };
+        if let Some(outcome) = state.outcome {
+            return Poll::Ready(outcome);
+        }
+        if !state.wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            state.wakers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
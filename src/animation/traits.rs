@@ -1,10 +1,114 @@
-use super::AnimationState;
+use instant::Duration;
+
+use super::{AnimationState, MaybeSend};
 use crate::Animatable;
+use crate::animations::spring::Spring;
 
 /// Core trait for all animation types
 ///
 /// This trait defines the interface that all animation implementations must provide.
-pub trait Animation: Send + 'static {
+/// Springs, tweens, keyframes and sequences all implement it, and it is a stable
+/// extension point for third-party animations: start one on a
+/// [`MotionValue`](crate::MotionValue) with `animate_custom` and it is driven by the
+/// same engine, loop and completion callbacks as the built-in animations.
+///
+/// # Contract
+///
+/// - [`update`](Animation::update) advances the animation by `dt` seconds and returns
+///   `(state, value, velocity)`. The engine stores `value` and `velocity` as the
+///   motion value's current state. Return [`AnimationState::Completed`] once, together
+///   with the final resting value; the engine then drops the animation and runs the
+///   completion callbacks.
+/// - [`value`](Animation::value) and [`velocity`](Animation::velocity) report the state
+///   after the latest `update` without advancing time. Velocity is in units per second;
+///   return `Self::Value::zero()` if the animation does not track it.
+/// - [`reset`](Animation::reset) rewinds to the initial state and makes the animation
+///   active again, e.g. when it is replayed as a sequence step.
+/// - [`is_active`](Animation::is_active) is `false` once the animation has completed.
+///
+/// # Example
+///
+/// A ball dropped onto a floor, losing energy with each bounce:
+///
+/// ```
+/// use dioxus_motion2::{Animation, AnimationEngine, AnimationState};
+///
+/// struct BouncingBall {
+///     start: f32,
+///     height: f32,
+///     velocity: f32,
+///     restitution: f32,
+///     active: bool,
+/// }
+///
+/// impl BouncingBall {
+///     const GRAVITY: f32 = -980.0;
+///
+///     fn new(height: f32, restitution: f32) -> Self {
+///         Self { start: height, height, velocity: 0.0, restitution, active: true }
+///     }
+/// }
+///
+/// impl Animation for BouncingBall {
+///     type Value = f32;
+///
+///     fn update(&mut self, dt: f32) -> (AnimationState, f32, f32) {
+///         if !self.active {
+///             return (AnimationState::Completed, self.height, 0.0);
+///         }
+///         self.velocity += Self::GRAVITY * dt;
+///         self.height += self.velocity * dt;
+///         if self.height <= 0.0 {
+///             // Hit the floor: reflect and damp the velocity
+///             self.height = 0.0;
+///             self.velocity = -self.velocity * self.restitution;
+///             if self.velocity < 20.0 {
+///                 self.velocity = 0.0;
+///                 self.active = false;
+///                 return (AnimationState::Completed, 0.0, 0.0);
+///             }
+///         }
+///         (AnimationState::Active, self.height, self.velocity)
+///     }
+///
+///     fn value(&self) -> f32 {
+///         self.height
+///     }
+///
+///     fn velocity(&self) -> f32 {
+///         self.velocity
+///     }
+///
+///     fn reset(&mut self) {
+///         self.height = self.start;
+///         self.velocity = 0.0;
+///         self.active = true;
+///     }
+///
+///     fn is_active(&self) -> bool {
+///         self.active
+///     }
+/// }
+///
+/// // In a component: `height.animate_custom(BouncingBall::new(100.0, 0.6));`
+/// let mut engine = AnimationEngine::new(100.0f32);
+/// engine.apply_custom(BouncingBall::new(100.0, 0.6));
+///
+/// let mut trajectory = vec![engine.get()];
+/// while engine.update(1.0 / 120.0) {
+///     trajectory.push(engine.get());
+/// }
+///
+/// // The ball never falls through the floor and comes to rest on it
+/// assert!(trajectory.iter().all(|height| *height >= 0.0));
+/// assert_eq!(engine.get(), 0.0);
+///
+/// // Each bounce peaks lower than the drop height
+/// let first_floor_hit = trajectory.iter().position(|h| *h == 0.0).unwrap();
+/// let rebound_peak = trajectory[first_floor_hit..].iter().cloned().fold(0.0, f32::max);
+/// assert!(rebound_peak > 10.0 && rebound_peak < 100.0 * 0.6 * 0.6 + 5.0);
+/// ```
+pub trait Animation: MaybeSend + 'static {
     /// The type of value being animated
     type Value: Animatable;
 
@@ -13,7 +117,7 @@ pub trait Animation: Send + 'static {
     /// Returns:
     /// - The current animation state (active or completed)
     /// - The current value
-    /// - The current velocity
+    /// - The current velocity in units per second
     fn update(&mut self, dt: f32) -> (AnimationState, Self::Value, Self::Value);
 
     /// Get the current value
@@ -27,4 +131,65 @@ pub trait Animation: Send + 'static {
 
     /// Is the animation in progress
     fn is_active(&self) -> bool;
+
+    /// Time left until the animation completes
+    ///
+    /// Exact for duration-based animations and estimated for physics-based ones;
+    /// `None` when unknown, e.g. when looping forever. Defaults to `None`.
+    fn remaining_time(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Total play time including loops, excluding any start delay
+    ///
+    /// Only duration-based animations know it up front. Defaults to `None`.
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+
+    /// How far the animation has come, from `0.0` at the start to `1.0` once
+    /// complete, counting every loop
+    ///
+    /// Defaults to the share of [`total_duration`](Animation::total_duration)
+    /// already played, so duration-based animations get it for free; `None`
+    /// when the total is unknown, e.g. when looping forever.
+    fn progress(&self) -> Option<f32> {
+        if !self.is_active() {
+            return Some(1.0);
+        }
+        let total = self.total_duration()?.as_secs_f32();
+        let remaining = self.remaining_time()?.as_secs_f32();
+        Some(if total > 0.0 {
+            (1.0 - remaining / total).clamp(0.0, 1.0)
+        } else {
+            1.0
+        })
+    }
+
+    /// Switch to new spring physics mid-flight, keeping the current value and velocity
+    ///
+    /// Returns whether the animation is driven by a spring and took the new
+    /// physics. Defaults to `false`.
+    fn retune_spring(&mut self, spring: &Spring) -> bool {
+        let _ = spring;
+        false
+    }
+
+    /// Stop looping once the current pass reaches its end
+    ///
+    /// Returns whether the animation now completes on its own at a pass
+    /// boundary. Defaults to `false`, which makes
+    /// [`stop_at_cycle_end`](crate::MotionValue::stop_at_cycle_end) stop it
+    /// right away instead.
+    fn finish_loop(&mut self) -> bool {
+        false
+    }
+
+    /// Move the target mid-flight, keeping the current value and velocity
+    ///
+    /// Returns whether the animation took the new target. Defaults to `false`.
+    fn retarget(&mut self, target: Self::Value) -> bool {
+        let _ = target;
+        false
+    }
 }
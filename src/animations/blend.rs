@@ -0,0 +1,144 @@
+//! Blended hand-over from one animation to the next
+//!
+//! [`Blend`] plays an interrupted animation and its replacement side by side
+//! for a short window, cross-fading from the first's output to the second's, so
+//! redirecting a value doesn't snap its velocity even when the new animation
+//! starts at rest, like a tween does. Used by
+//! [`MotionValue::interrupt_to`](crate::MotionValue::interrupt_to).
+
+use instant::Duration;
+
+use crate::Animatable;
+use crate::animation::{Animation, AnimationState};
+use crate::animations::spring::Spring;
+
+/// An animation taking over from an interrupted one over `window` seconds
+pub(crate) struct Blend<T: Animatable> {
+    /// The interrupted animation, played on until the window is over
+    ///
+    /// Dropped after the window, so values retargeted every frame nest at most
+    /// a window's worth of blends.
+    outgoing: Option<Box<dyn Animation<Value = T>>>,
+    /// Where the outgoing animation stopped, if it ended within the window
+    rest: T,
+    /// The animation taking over
+    incoming: Box<dyn Animation<Value = T>>,
+    /// Length of the cross-fade, in seconds
+    window: f32,
+    elapsed: f32,
+    value: T,
+    velocity: T,
+}
+
+impl<T: Animatable> Blend<T> {
+    pub(crate) fn new(
+        outgoing: Box<dyn Animation<Value = T>>,
+        incoming: Box<dyn Animation<Value = T>>,
+        window: Duration,
+    ) -> Self {
+        Self {
+            value: outgoing.value(),
+            velocity: outgoing.velocity(),
+            rest: outgoing.value(),
+            outgoing: Some(outgoing),
+            incoming,
+            window: window.as_secs_f32(),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Whether the outgoing animation still shows
+    fn is_blending(&self) -> bool {
+        self.outgoing.is_some()
+    }
+}
+
+impl<T: Animatable> Animation for Blend<T> {
+    type Value = T;
+
+    fn update(&mut self, dt: f32) -> (AnimationState, T, T) {
+        let (from, from_velocity) = match &mut self.outgoing {
+            Some(outgoing) if outgoing.is_active() => {
+                let (_, value, velocity) = outgoing.update(dt);
+                self.rest = value;
+                (value, velocity)
+            }
+            // Ended before the window: it rests where it stopped
+            _ => (self.rest, T::zero()),
+        };
+        let (state, to, to_velocity) = if self.incoming.is_active() {
+            self.incoming.update(dt)
+        } else {
+            (AnimationState::Completed, self.incoming.value(), T::zero())
+        };
+        self.elapsed += dt;
+        if self.elapsed >= self.window {
+            self.outgoing = None;
+        }
+
+        if !self.is_blending() {
+            (self.value, self.velocity) = (to, to_velocity);
+            return (state, to, to_velocity);
+        }
+
+        // Smoothstep weight, with no jump in slope at either end of the window
+        let t = self.elapsed / self.window;
+        let weight = t * t * (3.0 - 2.0 * t);
+        let weight_rate = 6.0 * t * (1.0 - t) / self.window;
+        self.value = from.interpolate(&to, weight);
+        self.velocity = from_velocity
+            .scale(1.0 - weight)
+            .add(&to_velocity.scale(weight))
+            .add(&to.sub(&from).scale(weight_rate));
+        (AnimationState::Active, self.value, self.velocity)
+    }
+
+    fn value(&self) -> T {
+        self.value
+    }
+
+    fn velocity(&self) -> T {
+        self.velocity
+    }
+
+    /// Restarts the incoming animation alone; the interrupted one is not
+    /// replayed
+    fn reset(&mut self) {
+        self.incoming.reset();
+        self.outgoing = None;
+        self.value = self.incoming.value();
+        self.velocity = self.incoming.velocity();
+    }
+
+    fn is_active(&self) -> bool {
+        self.incoming.is_active() || self.is_blending()
+    }
+
+    fn remaining_time(&self) -> Option<Duration> {
+        let remaining = self.incoming.remaining_time()?;
+        if !self.is_blending() {
+            return Some(remaining);
+        }
+        Some(remaining.max(Duration::from_secs_f32(self.window - self.elapsed)))
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.incoming.total_duration()
+    }
+
+    fn progress(&self) -> Option<f32> {
+        self.incoming.progress()
+    }
+
+    fn retune_spring(&mut self, spring: &Spring) -> bool {
+        self.incoming.retune_spring(spring)
+    }
+
+    fn finish_loop(&mut self) -> bool {
+        self.incoming.finish_loop()
+    }
+
+    fn retarget(&mut self, target: T) -> bool {
+        self.incoming.retarget(target)
+    }
+}
@@ -0,0 +1,140 @@
+//! Walls a spring bounces off instead of passing through
+//!
+//! A value with [`BounceBounds`] that crosses `min` or `max` is reflected back
+//! inside and its velocity reversed, scaled by the restitution, so each hit
+//! loses energy like a ball against the side of a box. Set up with
+//! [`SpringBuilder::bounce_bounds`].
+//!
+//! Scalars bounce as a whole and `[f32; N]` values bounce channel by channel,
+//! e.g. a ball in a square box as `[x, y]`. Other types pass through the walls;
+//! see [`Animatable::bounce`].
+//!
+//! # Example
+//! ```
+//! use dioxus_motion2::animations::bounce::BounceBounds;
+//! use dioxus_motion2::prelude::*;
+//! use dioxus_motion2::{Animation, AnimationState};
+//!
+//! // Dropped onto the floor at 300, it bounces lower each time and comes to rest
+//! let mut ball = Spring::default()
+//!     .create_animation(0.0f32, 300.0, 0.0)
+//!     .with_bounds(BounceBounds::new(0.0, 300.0, 0.6));
+//! while ball.update(1.0 / 60.0).0 == AnimationState::Active {
+//!     assert!(ball.value() <= 300.0);
+//! }
+//! assert_eq!(ball.value(), 300.0);
+//! ```
+//!
+//! [`SpringBuilder::bounce_bounds`]: crate::animations::spring::SpringBuilder::bounce_bounds
+//! [`Animatable::bounce`]: crate::Animatable::bounce
+
+/// Walls at `min` and `max`, and the share of speed kept bouncing off them
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BounceBounds {
+    /// Lower wall
+    pub min: f32,
+    /// Upper wall
+    pub max: f32,
+    /// Share of the speed kept on each bounce, from 0 (stops dead) to 1 (elastic)
+    pub restitution: f32,
+}
+
+impl BounceBounds {
+    /// Walls at `min` and `max`, given in either order, keeping `restitution`
+    /// of the speed on each bounce, clamped to `0.0..=1.0`
+    pub fn new(min: f32, max: f32, restitution: f32) -> Self {
+        Self {
+            min: min.min(max),
+            max: min.max(max),
+            restitution: restitution.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Reflect a scalar that crossed a wall back inside, reversing its velocity
+    pub(crate) fn reflect(&self, position: &mut f32, velocity: &mut f32) {
+        let wall = if *position > self.max {
+            self.max
+        } else if *position < self.min {
+            self.min
+        } else {
+            return;
+        };
+        // Mirror the overshoot, losing speed like the velocity does
+        *position = (wall - (*position - wall) * self.restitution).clamp(self.min, self.max);
+        *velocity = -*velocity * self.restitution;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Animatable;
+    use crate::animation::{Animation, AnimationState};
+    use crate::animations::spring::Spring;
+
+    const FRAME: f32 = 1.0 / 60.0;
+
+    #[test]
+    fn test_reflection_mirrors_and_slows() {
+        let bounds = BounceBounds::new(100.0, 0.0, 1.5);
+        assert_eq!(bounds, BounceBounds::new(0.0, 100.0, 1.0));
+
+        let bounds = BounceBounds::new(0.0, 100.0, 0.5);
+        let (mut position, mut velocity) = (110.0f32, 400.0f32);
+        bounds.reflect(&mut position, &mut velocity);
+        assert_eq!((position, velocity), (95.0, -200.0));
+
+        // Inside the walls nothing changes
+        let (mut position, mut velocity) = (50.0f32, -30.0f32);
+        bounds.reflect(&mut position, &mut velocity);
+        assert_eq!((position, velocity), (50.0, -30.0));
+    }
+
+    #[test]
+    fn test_dropped_value_bounces_lower_each_time_until_it_rests() {
+        let mut ball = Spring {
+            stiffness: 100.0,
+            damping: 1.0,
+            ..Spring::default()
+        }
+        .create_animation(0.0f32, 300.0, 0.0)
+        .with_bounds(BounceBounds::new(0.0, 300.0, 0.6));
+
+        // Highest point reached after each bounce off the floor, where the
+        // velocity turns back down
+        let mut peaks = Vec::new();
+        let (mut last, mut frames) = (0.0f32, 0);
+        loop {
+            let (state, value, velocity) = ball.update(FRAME);
+            assert!((0.0..=300.0).contains(&value));
+            if velocity.signum() != last.signum() && velocity > 0.0 {
+                peaks.push(value);
+            }
+            last = velocity;
+            frames += 1;
+            if state == AnimationState::Completed {
+                break;
+            }
+            assert!(frames < 6000, "the ball comes to rest");
+        }
+        assert_eq!(ball.value(), 300.0);
+
+        // Each bounce loses energy, so the next peak is closer to the floor
+        assert!(peaks.len() > 3);
+        assert!(peaks.windows(2).all(|pair| pair[1] > pair[0]));
+    }
+
+    #[test]
+    fn test_arrays_bounce_channel_by_channel() {
+        let bounds = BounceBounds::new(0.0, 100.0, 1.0);
+        let (mut position, mut velocity) = ([120.0f32, 50.0], [300.0f32, 10.0]);
+        position.bounce(&mut velocity, &bounds);
+        assert_eq!(position, [80.0, 50.0]);
+        assert_eq!(velocity, [-300.0, 10.0]);
+
+        // Types without walls pass through them
+        let (mut position, mut velocity) = (120, 300);
+        position.bounce(&mut velocity, &bounds);
+        assert_eq!((position, velocity), (120, 300));
+    }
+}
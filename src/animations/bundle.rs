@@ -0,0 +1,557 @@
+//! Several named properties animated by one engine
+//!
+//! A list row animating `x`, `opacity` and `scale` would otherwise need three
+//! motion values, i.e. three signals and three engines per row. A
+//! [`MotionBundle`] packs the properties into one `[f32; N]` motion value
+//! instead. Each property still animates independently: starting an animation
+//! on one field leaves the others running undisturbed, and every field runs its
+//! own spring or tween.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Row(selected: bool) -> Element {
+//!     let mut row = use_motion_bundle([("x", 0.0), ("opacity", 1.0), ("scale", 1.0)]);
+//!
+//!     use_effect(use_reactive((&selected,), move |(selected,)| {
+//!         row.animate_to("x", if selected { 16.0 } else { 0.0 });
+//!         row.animate_to("scale", if selected { 1.02 } else { 1.0 });
+//!     }));
+//!
+//!     rsx! {
+//!         div {
+//!             style: "transform: translateX({row.get(\"x\")}px) scale({row.get(\"scale\")}); opacity: {row.get(\"opacity\")};",
+//!             "Row"
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! # Cost
+//!
+//! Packing pays off once many rows animate together. The bench
+//! `cargo test --release --lib -- --ignored bench_packed_vs_unpacked` mounts
+//! 100 rows animating all three fields and compares the average frame, driver
+//! tick and re-render included, of one bundle per row against one motion value
+//! per field; it fails with both times when the bundles are not the faster.
+
+use std::sync::{Arc, Mutex};
+
+use dioxus::prelude::*;
+use tracing::warn;
+
+use crate::animation::{Animation, AnimationConfig, AnimationMode, AnimationState};
+use crate::{AnimationEngine, MotionValue, use_motion};
+
+/// The animation of a field in the current run, if any, kept once it completes
+/// so it still counts toward the bundle's progress
+type Channel = Option<Box<dyn Animation<Value = f32>>>;
+
+/// Field animations, shared between a bundle and the animation driving it
+type Channels<const N: usize> = Arc<Mutex<[Channel; N]>>;
+
+/// Drives every field of a bundle from its own animation
+pub struct BundleAnimation<const N: usize> {
+    channels: Channels<N>,
+    current: [f32; N],
+    velocity: [f32; N],
+}
+
+impl<const N: usize> BundleAnimation<N> {
+    fn new(channels: Channels<N>, current: [f32; N]) -> Self {
+        Self {
+            channels,
+            current,
+            velocity: [0.0; N],
+        }
+    }
+}
+
+impl<const N: usize> Animation for BundleAnimation<N> {
+    type Value = [f32; N];
+
+    fn update(&mut self, dt: f32) -> (AnimationState, Self::Value, Self::Value) {
+        let Ok(mut channels) = self.channels.lock() else {
+            return (AnimationState::Completed, self.current, [0.0; N]);
+        };
+
+        for (index, channel) in channels.iter_mut().enumerate() {
+            let Some(animation) = channel.as_mut().filter(|animation| animation.is_active()) else {
+                self.velocity[index] = 0.0;
+                continue;
+            };
+            let (state, value, velocity) = animation.update(dt);
+            self.current[index] = value;
+            self.velocity[index] = if state == AnimationState::Completed {
+                0.0
+            } else {
+                velocity
+            };
+        }
+
+        if !channels
+            .iter()
+            .flatten()
+            .any(|animation| animation.is_active())
+        {
+            (AnimationState::Completed, self.current, [0.0; N])
+        } else {
+            (AnimationState::Active, self.current, self.velocity)
+        }
+    }
+
+    fn value(&self) -> Self::Value {
+        self.current
+    }
+
+    fn velocity(&self) -> Self::Value {
+        self.velocity
+    }
+
+    fn reset(&mut self) {
+        if let Ok(mut channels) = self.channels.lock() {
+            for animation in channels.iter_mut().flatten() {
+                animation.reset();
+            }
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.channels.lock().is_ok_and(|channels| {
+            channels
+                .iter()
+                .flatten()
+                .any(|animation| animation.is_active())
+        })
+    }
+
+    /// Progress of the slowest field, see [`GroupProgress::Slowest`]
+    fn progress(&self) -> Option<f32> {
+        group_progress(&self.channels, GroupProgress::Slowest)
+    }
+}
+
+/// How a group's progress is summarized from its members'
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupProgress {
+    /// The least progressed member, so the group reads done only once every
+    /// member is
+    #[default]
+    Slowest,
+    /// The average of the members
+    Mean,
+}
+
+/// Progress of the fields animated in the current run
+///
+/// Fields that complete keep counting as done; `None` if nothing was animated
+/// or a field's animation can't tell its progress.
+fn group_progress<const N: usize>(channels: &Channels<N>, summary: GroupProgress) -> Option<f32> {
+    let channels = channels.lock().ok()?;
+    let members = channels
+        .iter()
+        .flatten()
+        .map(|animation| animation.progress())
+        .collect::<Option<Vec<f32>>>()?;
+    if members.is_empty() {
+        return None;
+    }
+    Some(match summary {
+        GroupProgress::Slowest => members.iter().copied().fold(1.0, f32::min),
+        GroupProgress::Mean => members.iter().sum::<f32>() / members.len() as f32,
+    })
+}
+
+/// Start animating field `index` of `engine` towards `target`
+///
+/// Other fields keep their running animations. If the engine is idle, a new
+/// bundle animation is started carrying only this field.
+fn start_field<const N: usize>(
+    engine: &mut AnimationEngine<[f32; N]>,
+    channels: &Channels<N>,
+    index: usize,
+    target: f32,
+    mode: AnimationMode,
+) {
+    let Ok(mut fields) = channels.lock() else {
+        return;
+    };
+    let idle = !engine.is_active();
+    if idle {
+        // Anything left over was stopped from outside and must not resume
+        fields.iter_mut().for_each(|channel| *channel = None);
+    }
+
+    let current = engine.current()[index];
+    // Carry a running spring's momentum into the new animation
+    let velocity = fields[index]
+        .as_ref()
+        .filter(|animation| animation.is_active())
+        .map_or(0.0, |animation| animation.velocity());
+    fields[index] = Some(match mode {
+        AnimationMode::Spring(spring) => {
+            Box::new(spring.create_animation(current, target, velocity))
+        }
+        AnimationMode::Tween(tween) => Box::new(tween.create_animation(current, target)),
+        AnimationMode::Keyframes(track) => Box::new(track.create_animation(current, target)),
+    });
+    drop(fields);
+
+    if idle {
+        engine.apply_custom(BundleAnimation::new(channels.clone(), engine.get()));
+    }
+}
+
+/// Named `f32` properties animated together by one engine
+///
+/// Create with [`use_motion_bundle`].
+pub struct MotionBundle<const N: usize> {
+    motion: MotionValue<[f32; N]>,
+    names: [&'static str; N],
+    channels: Channels<N>,
+}
+
+impl<const N: usize> Clone for MotionBundle<N> {
+    fn clone(&self) -> Self {
+        Self {
+            motion: self.motion,
+            names: self.names,
+            channels: self.channels.clone(),
+        }
+    }
+}
+
+impl<const N: usize> PartialEq for MotionBundle<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.motion.engine == other.motion.engine
+    }
+}
+
+impl<const N: usize> MotionBundle<N> {
+    /// Bundle the fields of an existing motion value under `names`
+    pub fn new(motion: MotionValue<[f32; N]>, names: [&'static str; N]) -> Self {
+        Self {
+            motion,
+            names,
+            channels: Arc::new(Mutex::new(std::array::from_fn(|_| None))),
+        }
+    }
+
+    /// The field names, in storage order
+    pub fn names(&self) -> &[&'static str; N] {
+        &self.names
+    }
+
+    /// Position of the field called `name`
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|field| *field == name)
+    }
+
+    /// Current value of the field called `name`, `0.0` if there is none
+    ///
+    /// Reading it subscribes the caller to changes of the bundle.
+    pub fn get(&self, name: &str) -> f32 {
+        self.index(name)
+            .and_then(|index| self.get_index(index))
+            .unwrap_or_else(|| {
+                warn!("MotionBundle has no field named {name:?}");
+                0.0
+            })
+    }
+
+    /// Current value of the field at `index`, `None` past the last field
+    pub fn get_index(&self, index: usize) -> Option<f32> {
+        self.motion.engine.read().current().get(index).copied()
+    }
+
+    /// Current values of every field
+    pub fn values(&self) -> [f32; N] {
+        self.motion.get()
+    }
+
+    /// Whether any field is animating
+    pub fn is_animating(&self) -> bool {
+        self.motion.is_animating()
+    }
+
+    /// How far the fields animated in the current run have come, from `0.0`
+    /// to `1.0`, going by the slowest field
+    ///
+    /// Reading it subscribes the caller. `None` when idle.
+    pub fn progress(&self) -> Option<f32> {
+        self.progress_by(GroupProgress::Slowest)
+    }
+
+    /// Like [`progress`](MotionBundle::progress), summarizing the fields as `summary` says
+    pub fn progress_by(&self, summary: GroupProgress) -> Option<f32> {
+        if !self.motion.is_animating() {
+            return None;
+        }
+        group_progress(&self.channels, summary)
+    }
+
+    /// Animate one field with its preferred animation, leaving the others running
+    pub fn animate_to(&mut self, name: &str, target: f32) -> &Self {
+        let mode = self.motion.engine.peek().default_animation().clone();
+        self.animate_to_with_config(name, target, AnimationConfig::new(mode))
+    }
+
+    /// Animate one field with a custom configuration, leaving the others running
+    ///
+    /// The config's completion callback runs once every field has come to rest.
+    pub fn animate_to_with_config(
+        &mut self,
+        name: &str,
+        target: f32,
+        config: AnimationConfig,
+    ) -> &Self {
+        let Some(index) = self.index(name) else {
+            warn!("MotionBundle has no field named {name:?}");
+            return self;
+        };
+
+        {
+            let mut engine = self.motion.engine.write();
+            start_field(&mut engine, &self.channels, index, target, config.mode);
+            if let Some(callback) = config.on_complete {
+                engine.add_completion_callback(callback);
+            }
+        }
+        self.motion.run_ready_callbacks();
+        self
+    }
+
+    /// Stop every field where it is
+    pub fn stop(&mut self) -> &Self {
+        self.motion.stop();
+        self
+    }
+}
+
+/// Create a bundle of named properties animated by a single engine
+///
+/// `fields` gives each property's name and initial value. Like other hooks,
+/// call it unconditionally from the component body.
+pub fn use_motion_bundle<const N: usize>(fields: [(&'static str, f32); N]) -> MotionBundle<N> {
+    let motion = use_motion(fields.map(|(_, value)| value));
+    use_hook(|| MotionBundle::new(motion, fields.map(|(name, _)| name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animations::{spring::Spring, tween::Tween};
+    use instant::Duration;
+    use std::cell::Cell;
+
+    fn channels<const N: usize>() -> Channels<N> {
+        Arc::new(Mutex::new(std::array::from_fn(|_| None)))
+    }
+
+    fn spring() -> AnimationMode {
+        AnimationMode::Spring(Spring::default())
+    }
+
+    #[test]
+    fn test_fields_animate_independently() {
+        let shared = channels::<3>();
+        let mut packed = AnimationEngine::new([0.0, 1.0, 1.0]);
+        let mut x = AnimationEngine::new(0.0f32);
+
+        start_field(&mut packed, &shared, 0, 100.0, spring());
+        x.spring_to(100.0, Spring::default());
+        for _ in 0..10 {
+            packed.update(1.0 / 60.0);
+            x.update(1.0 / 60.0);
+        }
+
+        // Starting another field doesn't disturb the running one
+        let fade = AnimationMode::Tween(Tween::new().duration(Duration::from_millis(100)));
+        start_field(&mut packed, &shared, 1, 0.0, fade);
+        for _ in 0..20 {
+            packed.update(1.0 / 60.0);
+            x.update(1.0 / 60.0);
+            assert_eq!(packed.get()[0], x.get());
+            // The untouched field holds still
+            assert_eq!(packed.get()[2], 1.0);
+        }
+        assert_eq!(packed.get()[1], 0.0);
+
+        while packed.update(1.0 / 60.0) {}
+        assert_eq!(packed.get(), [100.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_retargeting_a_field_keeps_its_momentum() {
+        let shared = channels::<2>();
+        let mut packed = AnimationEngine::new([0.0, 0.0]);
+        start_field(&mut packed, &shared, 0, 100.0, spring());
+        for _ in 0..10 {
+            packed.update(1.0 / 60.0);
+        }
+        let before = packed.get()[0];
+
+        start_field(&mut packed, &shared, 0, 0.0, spring());
+        packed.update(1.0 / 60.0);
+        // Still moving towards the old target for a moment
+        assert!(packed.get()[0] > before);
+    }
+
+    #[test]
+    fn test_restart_after_stop_drops_stale_fields() {
+        let shared = channels::<2>();
+        let mut packed = AnimationEngine::new([0.0, 0.0]);
+        start_field(&mut packed, &shared, 0, 100.0, spring());
+        packed.update(1.0 / 60.0);
+        packed.stop();
+        let stopped_at = packed.get()[0];
+
+        start_field(&mut packed, &shared, 1, 50.0, spring());
+        while packed.update(1.0 / 60.0) {}
+        assert_eq!(packed.get(), [stopped_at, 50.0]);
+    }
+
+    #[test]
+    fn test_group_progress_summarizes_fields() {
+        let shared = channels::<3>();
+        let mut packed = AnimationEngine::new([0.0, 0.0, 0.0]);
+        let tween =
+            |millis| AnimationMode::Tween(Tween::new().duration(Duration::from_millis(millis)));
+        start_field(&mut packed, &shared, 0, 1.0, tween(100));
+        start_field(&mut packed, &shared, 1, 1.0, tween(300));
+        assert_eq!(packed.progress(), Some(0.0));
+
+        // The short field is done and still counts, the untouched one doesn't
+        for _ in 0..15 {
+            packed.update(0.01);
+        }
+        let Some(slowest) = group_progress(&shared, GroupProgress::Slowest) else {
+            unreachable!("both fields report progress");
+        };
+        let Some(mean) = group_progress(&shared, GroupProgress::Mean) else {
+            unreachable!("both fields report progress");
+        };
+        assert!((slowest - 0.5).abs() < 0.04, "{slowest}");
+        assert!((mean - 0.75).abs() < 0.04, "{mean}");
+        assert_eq!(packed.progress(), Some(slowest));
+
+        while packed.update(0.01) {}
+        assert_eq!(packed.progress(), None);
+        assert_eq!(packed.get(), [1.0, 1.0, 0.0]);
+    }
+
+    /// 100 rows × 3 fields: one engine per row against one engine per field
+    fn rows_packed_and_unpacked() -> (Vec<AnimationEngine<[f32; 3]>>, Vec<AnimationEngine<f32>>) {
+        let mut packed = Vec::new();
+        let mut unpacked = Vec::new();
+        for row in 0..100 {
+            let shared = channels::<3>();
+            let mut engine = AnimationEngine::new([0.0, 1.0, 1.0]);
+            let targets = [row as f32, 0.5, 1.1];
+            for (field, target) in targets.into_iter().enumerate() {
+                start_field(&mut engine, &shared, field, target, spring());
+                let mut single = AnimationEngine::new(engine.get()[field]);
+                single.spring_to(target, Spring::default());
+                unpacked.push(single);
+            }
+            packed.push(engine);
+        }
+        (packed, unpacked)
+    }
+
+    #[test]
+    fn test_packed_rows_match_unpacked() {
+        let (mut packed, mut unpacked) = rows_packed_and_unpacked();
+        for _ in 0..60 {
+            packed.iter_mut().for_each(|engine| {
+                engine.update(1.0 / 60.0);
+            });
+            unpacked.iter_mut().for_each(|engine| {
+                engine.update(1.0 / 60.0);
+            });
+        }
+
+        let packed: Vec<f32> = packed.iter().flat_map(|engine| engine.get()).collect();
+        let unpacked: Vec<f32> = unpacked.iter().map(|engine| engine.get()).collect();
+        assert_eq!(packed, unpacked);
+    }
+
+    /// Time per frame of 100 mounted rows × 3 fields, through the driver from
+    /// compute to re-render: one bundle per row, or one motion value per field
+    fn mounted_rows(packed: bool) -> Duration {
+        const ROWS: usize = 100;
+        const FRAMES: u32 = 60;
+        thread_local! {
+            static PACKED: Cell<bool> = const { Cell::new(false) };
+        }
+
+        #[component]
+        fn PackedRow(index: usize) -> Element {
+            let mut row = use_motion_bundle([("x", 0.0), ("opacity", 1.0), ("scale", 1.0)]);
+            use_hook(|| {
+                row.animate_to("x", index as f32);
+                row.animate_to("opacity", 0.5);
+                row.animate_to("scale", 1.1);
+            });
+            rsx! {
+                div { style: "transform: translateX({row.get(\"x\")}px) scale({row.get(\"scale\")}); opacity: {row.get(\"opacity\")};" }
+            }
+        }
+
+        #[component]
+        fn UnpackedRow(index: usize) -> Element {
+            let x = use_motion(0.0f32);
+            let opacity = use_motion(1.0f32);
+            let scale = use_motion(1.0f32);
+            use_hook(move || {
+                for (mut motion, target) in [(x, index as f32), (opacity, 0.5), (scale, 1.1)] {
+                    motion.animate_to(target);
+                }
+            });
+            rsx! {
+                div { style: "transform: translateX({x.get()}px) scale({scale.get()}); opacity: {opacity.get()};" }
+            }
+        }
+
+        fn rows() -> Element {
+            rsx! {
+                for index in 0..ROWS {
+                    if PACKED.get() {
+                        PackedRow { key: "{index}", index }
+                    } else {
+                        UnpackedRow { key: "{index}", index }
+                    }
+                }
+            }
+        }
+
+        PACKED.set(packed);
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(rows);
+        dom.rebuild_in_place();
+        let start = std::time::Instant::now();
+        for _ in 0..FRAMES {
+            dom.in_runtime(|| crate::driver::step(1.0 / 60.0));
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+        start.elapsed() / FRAMES
+    }
+
+    /// Run with `cargo test --release --lib -- --ignored bench_packed`, see the
+    /// module docs
+    #[test]
+    #[ignore]
+    fn bench_packed_vs_unpacked() {
+        let packed = mounted_rows(true);
+        let unpacked = mounted_rows(false);
+        assert!(
+            packed < unpacked,
+            "packed {packed:?}, unpacked {unpacked:?}"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+//! Values derived from other motion values
+//!
+//! [`MotionValue::chain`] keeps one motion value mapped from another's current
+//! value, updating it on every frame the source moves. Where
+//! [`follow`](MotionValue::follow) springs after the leader with its own
+//! velocity, a chained value is always exactly the mapping of the source, e.g. a
+//! label counting up to 100 while a bar fills.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Upload() -> Element {
+//!     let mut fill = use_motion(0.0f32);
+//!     let percent = use_motion(0i32);
+//!
+//!     use_hook(move || fill.chain(percent, |fill| (fill * 100.0).round() as i32));
+//!
+//!     rsx! {
+//!         button { onclick: move |_| { fill.tween().animate_to(1.0); }, "Upload" }
+//!         div { class: "bar", style: "width: {fill.get() * 100.0}%" }
+//!         span { "{percent.get()}%" }
+//!     }
+//! }
+//! ```
+
+use dioxus::prelude::*;
+use dioxus::signals::BorrowMutError;
+
+use crate::{Animatable, MotionValue};
+
+impl<T: Animatable> MotionValue<T> {
+    /// Keep `next` set to `mapping` of this value, updating it whenever this
+    /// value changes
+    ///
+    /// `next` takes the mapped value right away and then on every frame of this
+    /// value's animations and on every [`set`](MotionValue::set). Chaining lasts
+    /// as long as both values exist; call it once, e.g. from `use_hook`, rather
+    /// than on every render. Animating `next` directly still works, until this
+    /// value changes again and interrupts it.
+    pub fn chain<U: Animatable>(
+        &mut self,
+        next: MotionValue<U>,
+        mapping: impl Fn(T) -> U + 'static,
+    ) {
+        let mut next = next;
+        next.engine.write().set(mapping(self.peek()));
+        self.engine
+            .write()
+            .add_follower(move |&value| match next.engine.try_write() {
+                Ok(mut engine) => {
+                    engine.set(mapping(value));
+                    true
+                }
+                // The chained value unmounted
+                Err(BorrowMutError::Dropped(_)) => false,
+                Err(_) => true,
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use dioxus::prelude::*;
+
+    use crate::MotionValue;
+
+    fn percent(fill: f32) -> i32 {
+        (fill * 100.0).round() as i32
+    }
+
+    #[test]
+    fn test_counter_label_tracks_bar_fill() {
+        thread_local! {
+            static VALUES: Cell<Option<(MotionValue<f32>, MotionValue<i32>)>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let mut bar = crate::use_motion(0.0f32);
+            let label = crate::use_motion(-1);
+            use_hook(move || bar.chain(label, percent));
+            VALUES.set(Some((bar, label)));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some((bar, label)) = VALUES.get() else {
+            unreachable!("the app stores its values");
+        };
+        assert_eq!(dom.in_runtime(|| label.peek()), 0);
+
+        dom.in_runtime(|| bar.tween().animate_to(1.0));
+        let mut counted = Vec::new();
+        while dom.in_runtime(|| crate::driver::step(1.0 / 60.0)) {
+            let (fill, count) = dom.in_runtime(|| (bar.peek(), label.peek()));
+            assert_eq!(count, percent(fill));
+            counted.push(count);
+        }
+
+        assert_eq!(dom.in_runtime(|| label.peek()), 100);
+        assert!(counted.len() > 2, "the label should count through the fill");
+        assert!(counted.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(!dom.in_runtime(|| label.is_animating()));
+    }
+
+    #[test]
+    fn test_chained_value_follows_sets_and_interrupts_its_own_runs() {
+        thread_local! {
+            static VALUES: Cell<Option<(MotionValue<f32>, MotionValue<f32>)>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let mut source = crate::use_motion(1.0f32);
+            let doubled = crate::use_motion(0.0f32);
+            use_hook(move || source.chain(doubled, |value| value * 2.0));
+            VALUES.set(Some((source, doubled)));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some((mut source, doubled)) = VALUES.get() else {
+            unreachable!("the app stores its values");
+        };
+        assert_eq!(dom.in_runtime(|| doubled.peek()), 2.0);
+
+        dom.in_runtime(|| doubled.spring().animate_to(50.0));
+        dom.in_runtime(|| crate::driver::step(1.0 / 60.0));
+        assert!(dom.in_runtime(|| doubled.is_animating()));
+        dom.in_runtime(|| source.set(4.0));
+        assert_eq!(dom.in_runtime(|| doubled.peek()), 8.0);
+        assert!(!dom.in_runtime(|| doubled.is_animating()));
+    }
+}
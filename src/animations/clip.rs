@@ -0,0 +1,821 @@
+//! Animation clips authored as data and loaded at runtime
+//!
+//! A [`Clip`] holds keyframes for named channels, e.g. `"card.y"` and
+//! `"card.opacity"`, and how the whole clip loops. Clips deserialize with
+//! serde, so motion designers can author them in a JSON file the app loads
+//! without recompiling. A [`ClipPlayer`] binds channel names to motion values
+//! and plays, pauses and seeks all of them together.
+//!
+//! Each keyframe has a time in milliseconds from the start of the clip, a
+//! value, and optionally the name of the easing into it from the keyframe
+//! before, as listed by [`easing_named`]; without one it moves linearly.
+//! Channels hold `f32`, [`Color`] or [`Transform`] values, the kind given as
+//! `"kind"`. Transforms may leave out fields, which default to the identity.
+//! `"loop"` is a [`LoopMode`] such as `"infinite"`, `{ "count": 2 }` or
+//! `"alternate"`, which plays the clip backwards on every other pass.
+//!
+//! ```json
+//! {
+//!   "loop": { "count": 2 },
+//!   "channels": {
+//!     "card.y": {
+//!       "kind": "f32",
+//!       "keyframes": [
+//!         { "time_ms": 0, "value": 20 },
+//!         { "time_ms": 300, "value": 0, "easing": "cubic_out" }
+//!       ]
+//!     },
+//!     "card.tint": {
+//!       "kind": "color",
+//!       "keyframes": [
+//!         { "time_ms": 0, "value": { "r": 1, "g": 1, "b": 1, "a": 1 } },
+//!         { "time_ms": 300, "value": { "r": 0.2, "g": 0.4, "b": 1, "a": 1 } }
+//!       ]
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Card(clip: String) -> Element {
+//!     let y = use_motion(0.0f32);
+//!     let opacity = use_motion(1.0f32);
+//!     let player = use_clip_player(|| serde_json::from_str(&clip).unwrap_or_default());
+//!     use_hook(|| {
+//!         player.bind("card.y", &y).bind("card.opacity", &opacity);
+//!         player.play();
+//!     });
+//!
+//!     rsx! {
+//!         div {
+//!             style: "transform: translateY({y.get()}px); opacity: {opacity.get()}",
+//!             onclick: move |_| player.pause(),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Requires the `clips` feature.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus::signals::BorrowMutError;
+use easer::functions::{Easing, Linear};
+use instant::Duration;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::animation::{Animation, AnimationState, AnimationTiming, LoopMode};
+use crate::animations::easing::easing_named;
+use crate::properties::{color::Color, transform::Transform};
+use crate::{Animatable, MotionValue, use_motion};
+
+/// Keyframes for named channels, played together
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Clip {
+    /// How the whole clip repeats
+    #[serde(default, rename = "loop")]
+    pub loop_mode: LoopMode,
+    /// Keyframes of each channel, by name
+    pub channels: BTreeMap<String, Channel>,
+}
+
+/// The keyframes of one channel, by the kind of value they hold
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "keyframes", rename_all = "snake_case")]
+pub enum Channel {
+    /// Plain numbers, e.g. a position or an opacity
+    F32(Vec<ClipKey<f32>>),
+    /// Colors
+    Color(Vec<ClipKey<Color>>),
+    /// Transforms
+    Transform(Vec<ClipKey<Transform>>),
+}
+
+/// A value a channel passes through at a time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClipKey<T> {
+    /// Milliseconds from the start of the clip
+    pub time_ms: f32,
+    /// Value at that time
+    pub value: T,
+    /// Name of the easing from the keyframe before; linear when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub easing: Option<String>,
+}
+
+impl<T> ClipKey<T> {
+    /// A keyframe at `time_ms`, reached linearly
+    pub fn new(time_ms: f32, value: T) -> Self {
+        Self {
+            time_ms,
+            value,
+            easing: None,
+        }
+    }
+
+    /// Reach this keyframe with the easing called `easing`
+    pub fn easing(mut self, easing: impl Into<String>) -> Self {
+        self.easing = Some(easing.into());
+        self
+    }
+}
+
+/// Error returned when a clip is malformed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipError {
+    /// A channel has no keyframes
+    EmptyChannel {
+        /// Name of the channel
+        channel: String,
+    },
+    /// A keyframe time is negative or not a number
+    InvalidTime {
+        /// Name of the channel
+        channel: String,
+        /// The rejected time
+        time_ms: f32,
+    },
+    /// A keyframe comes earlier than the one before it
+    TimeGoesBack {
+        /// Name of the channel
+        channel: String,
+        /// Index of the keyframe
+        index: usize,
+    },
+    /// An easing name is not one [`easing_named`] knows
+    UnknownEasing {
+        /// Name of the channel
+        channel: String,
+        /// The unknown name
+        easing: String,
+    },
+    /// `LoopMode::Count(0)` or `LoopMode::AlternateTimes(0)` would never play
+    ZeroLoopCount,
+}
+
+impl fmt::Display for ClipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyChannel { channel } => write!(f, "channel `{channel}` has no keyframes"),
+            Self::InvalidTime { channel, time_ms } => write!(
+                f,
+                "keyframe time in `{channel}` must not be negative, got {time_ms}ms"
+            ),
+            Self::TimeGoesBack { channel, index } => write!(
+                f,
+                "keyframe {index} of `{channel}` comes before the one listed ahead of it"
+            ),
+            Self::UnknownEasing { channel, easing } => {
+                write!(f, "unknown easing `{easing}` in `{channel}`")
+            }
+            Self::ZeroLoopCount => write!(f, "a loop count of 0 never plays"),
+        }
+    }
+}
+
+impl std::error::Error for ClipError {}
+
+impl Clip {
+    /// An empty clip, played once
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace the channel `name`
+    pub fn channel(mut self, name: impl Into<String>, channel: Channel) -> Self {
+        self.channels.insert(name.into(), channel);
+        self
+    }
+
+    /// Set how the whole clip repeats
+    pub fn loop_mode(mut self, loop_mode: LoopMode) -> Self {
+        self.loop_mode = loop_mode;
+        self
+    }
+
+    /// Length of one pass, up to the last keyframe of any channel
+    pub fn duration(&self) -> Duration {
+        let end = self
+            .channels
+            .values()
+            .map(Channel::end_ms)
+            .fold(0.0, f32::max);
+        Duration::from_secs_f64(f64::from(end) / 1000.0)
+    }
+
+    /// Check that every channel has keyframes in time order with known easings
+    pub fn validate(&self) -> Result<(), ClipError> {
+        if matches!(
+            self.loop_mode,
+            LoopMode::Count(0) | LoopMode::AlternateTimes(0)
+        ) {
+            return Err(ClipError::ZeroLoopCount);
+        }
+        for (name, channel) in &self.channels {
+            let keys: Vec<_> = match channel {
+                Channel::F32(keys) => keys.iter().map(ClipKey::timing).collect(),
+                Channel::Color(keys) => keys.iter().map(ClipKey::timing).collect(),
+                Channel::Transform(keys) => keys.iter().map(ClipKey::timing).collect(),
+            };
+            validate_channel(name, &keys)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> ClipKey<T> {
+    fn timing(&self) -> (f32, Option<&str>) {
+        (self.time_ms, self.easing.as_deref())
+    }
+}
+
+/// Check the times and easings of one channel's keyframes
+fn validate_channel(name: &str, keys: &[(f32, Option<&str>)]) -> Result<(), ClipError> {
+    let channel = || name.to_string();
+    if keys.is_empty() {
+        return Err(ClipError::EmptyChannel { channel: channel() });
+    }
+    let mut last = 0.0;
+    for (index, (time_ms, easing)) in keys.iter().enumerate() {
+        if !time_ms.is_finite() || *time_ms < 0.0 {
+            return Err(ClipError::InvalidTime {
+                channel: channel(),
+                time_ms: *time_ms,
+            });
+        }
+        // Equal times are allowed, jumping straight to the later value
+        if *time_ms < last {
+            return Err(ClipError::TimeGoesBack {
+                channel: channel(),
+                index,
+            });
+        }
+        last = *time_ms;
+        if let Some(easing) = easing
+            && easing_named(easing).is_none()
+        {
+            return Err(ClipError::UnknownEasing {
+                channel: channel(),
+                easing: (*easing).to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+impl Channel {
+    /// Time of the last keyframe, in milliseconds
+    fn end_ms(&self) -> f32 {
+        let last = match self {
+            Self::F32(keys) => keys.last().map(|key| key.time_ms),
+            Self::Color(keys) => keys.last().map(|key| key.time_ms),
+            Self::Transform(keys) => keys.last().map(|key| key.time_ms),
+        };
+        last.unwrap_or(0.0)
+    }
+
+    /// Name of the kind of value the channel holds
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::F32(_) => "f32",
+            Self::Color(_) => "color",
+            Self::Transform(_) => "transform",
+        }
+    }
+}
+
+/// Value of `keys` at `time_ms`, holding the first and last values outside them
+fn sample<T: Animatable>(keys: &[ClipKey<T>], time_ms: f32) -> Option<T> {
+    let next = keys.iter().position(|key| key.time_ms > time_ms);
+    let (from, to) = match next {
+        Some(0) => return keys.first().map(|key| key.value),
+        Some(index) => (&keys[index - 1], &keys[index]),
+        None => return keys.last().map(|key| key.value),
+    };
+    // `to` is later than `time_ms`, which is at or after `from`
+    let progress = (time_ms - from.time_ms) / (to.time_ms - from.time_ms);
+    let easing = to
+        .easing
+        .as_deref()
+        .and_then(easing_named)
+        .unwrap_or(Linear::ease_in_out);
+    Some(
+        from.value
+            .interpolate(&to.value, easing(progress, 0.0, 1.0, 1.0)),
+    )
+}
+
+/// Types of values a [`Channel`] holds, which a [`ClipPlayer`] can bind
+pub trait ClipValue: Animatable {
+    /// The keyframes of `channel` if it holds values of this type
+    fn keys(channel: &Channel) -> Option<&[ClipKey<Self>]>;
+}
+
+impl ClipValue for f32 {
+    fn keys(channel: &Channel) -> Option<&[ClipKey<Self>]> {
+        match channel {
+            Channel::F32(keys) => Some(keys),
+            _ => None,
+        }
+    }
+}
+
+impl ClipValue for Color {
+    fn keys(channel: &Channel) -> Option<&[ClipKey<Self>]> {
+        match channel {
+            Channel::Color(keys) => Some(keys),
+            _ => None,
+        }
+    }
+}
+
+impl ClipValue for Transform {
+    fn keys(channel: &Channel) -> Option<&[ClipKey<Self>]> {
+        match channel {
+            Channel::Transform(keys) => Some(keys),
+            _ => None,
+        }
+    }
+}
+
+/// Plays a [`Clip`] on the motion values bound to its channels
+///
+/// The player keeps the clip time, in milliseconds, in a motion value of its
+/// own; every bound value is set to its channel's value whenever that time
+/// moves, so the whole clip plays, pauses and seeks as one. Create it with
+/// [`use_clip_player`], or with [`ClipPlayer::new`] around a clock value.
+#[derive(Clone)]
+pub struct ClipPlayer {
+    clip: Rc<Clip>,
+    clock: MotionValue<f32>,
+}
+
+impl ClipPlayer {
+    /// A player of `clip`, keeping its time in `clock`
+    pub fn new(clip: Clip, clock: MotionValue<f32>) -> Self {
+        Self {
+            clip: Rc::new(clip),
+            clock,
+        }
+    }
+
+    /// The clip being played
+    pub fn clip(&self) -> &Clip {
+        &self.clip
+    }
+
+    /// Drive `motion` with the channel `name`
+    ///
+    /// The value jumps to the channel's value at the current time, then
+    /// follows the clip for as long as both exist. Bind once, e.g. from
+    /// `use_hook`. A name the clip has no channel for, or one holding another
+    /// kind of value, is logged at debug level and ignored.
+    pub fn bind<T: ClipValue>(&self, name: &str, motion: &MotionValue<T>) -> &Self {
+        let Some(channel) = self.clip.channels.get(name) else {
+            debug!("Clip has no channel `{name}`; ignoring its binding");
+            return self;
+        };
+        let Some(keys) = T::keys(channel) else {
+            debug!(
+                "Clip channel `{name}` holds {} values; ignoring its binding",
+                channel.kind()
+            );
+            return self;
+        };
+        let keys = keys.to_vec();
+        let mut bound = *motion;
+        if let Some(value) = sample(&keys, self.clock.peek()) {
+            bound.engine.write().set(value);
+        }
+        let mut clock = self.clock;
+        clock.engine.write().add_follower(move |&time| {
+            let Some(value) = sample(&keys, time) else {
+                return true;
+            };
+            match bound.engine.try_write() {
+                Ok(mut engine) => {
+                    engine.set(value);
+                    true
+                }
+                // The bound value unmounted
+                Err(BorrowMutError::Dropped(_)) => false,
+                Err(_) => true,
+            }
+        });
+        self
+    }
+
+    /// Play from the current time, or from the start once the clip has ended
+    pub fn play(&self) {
+        let duration = self.duration_ms();
+        let time = self.clock.peek();
+        let start = if time >= duration { 0.0 } else { time };
+        let mut clock = self.clock;
+        clock.animate_custom(ClipClock::new(start, duration, self.clip.loop_mode));
+    }
+
+    /// Stop at the current time; [`ClipPlayer::play`] continues from there
+    pub fn pause(&self) {
+        let mut clock = self.clock;
+        clock.stop();
+    }
+
+    /// Jump to `time` from the start, clamped to the clip, carrying on playing
+    /// from there if the clip is playing
+    ///
+    /// A looping clip starts its passes over from the one seeked into.
+    pub fn seek(&self, time: Duration) {
+        let time = (time.as_secs_f32() * 1000.0).min(self.duration_ms());
+        let playing = self.is_playing();
+        let mut clock = self.clock;
+        clock.set(time);
+        if playing {
+            self.play();
+        }
+    }
+
+    /// Whether the clip is playing
+    pub fn is_playing(&self) -> bool {
+        self.clock.engine.peek().is_active()
+    }
+
+    /// Time played into the current pass
+    pub fn time(&self) -> Duration {
+        Duration::from_secs_f64(f64::from(self.clock.peek().max(0.0)) / 1000.0)
+    }
+
+    fn duration_ms(&self) -> f32 {
+        self.clip.duration().as_secs_f32() * 1000.0
+    }
+}
+
+/// Create a player for the clip `clip` builds, paused at its start
+///
+/// Like other hooks, call it unconditionally from the component body; `clip`
+/// only runs on the first render.
+pub fn use_clip_player(clip: impl FnOnce() -> Clip) -> ClipPlayer {
+    let clock = use_motion(0.0f32);
+    use_hook(|| ClipPlayer::new(clip(), clock))
+}
+
+/// Clip time in milliseconds, advancing in real time and looping as the
+/// clip says
+struct ClipClock {
+    /// Clip time last reached
+    time: f32,
+    /// Time into the current pass, whichever way it plays
+    elapsed: f32,
+    duration: f32,
+    timing: AnimationTiming,
+    active: bool,
+}
+
+impl ClipClock {
+    fn new(time: f32, duration: f32, loop_mode: LoopMode) -> Self {
+        Self {
+            time,
+            elapsed: time,
+            duration,
+            timing: AnimationTiming::new().with_loop_mode(loop_mode),
+            active: true,
+        }
+    }
+
+    /// Clip time `elapsed` into the current pass, running back on reversed
+    /// passes
+    fn pass_time(&self) -> f32 {
+        if self.timing.is_reverse() {
+            self.duration - self.elapsed
+        } else {
+            self.elapsed
+        }
+    }
+}
+
+impl Animation for ClipClock {
+    type Value = f32;
+
+    fn update(&mut self, dt: f32) -> (AnimationState, f32, f32) {
+        if !self.active {
+            return (AnimationState::Completed, self.time, 0.0);
+        }
+        self.elapsed += dt * 1000.0;
+        if self.elapsed >= self.duration {
+            // The end of the pass that just played, before completion counts it
+            let over = self.elapsed - self.duration;
+            self.elapsed = self.duration;
+            self.time = self.pass_time();
+            if self.duration > 0.0 && self.timing.handle_loop_completion() {
+                self.elapsed = over.rem_euclid(self.duration);
+            } else {
+                self.active = false;
+                return (AnimationState::Completed, self.time, 0.0);
+            }
+        }
+        self.time = self.pass_time();
+        (AnimationState::Active, self.time, self.velocity())
+    }
+
+    fn value(&self) -> f32 {
+        self.time
+    }
+
+    fn velocity(&self) -> f32 {
+        match (self.active, self.timing.is_reverse()) {
+            (false, _) => 0.0,
+            (true, false) => 1000.0,
+            (true, true) => -1000.0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.time = 0.0;
+        self.elapsed = 0.0;
+        self.timing.current_loop = 0;
+        self.active = true;
+    }
+
+    fn is_active(&self) -> bool {
+        self.active
+    }
+
+    fn remaining_time(&self) -> Option<Duration> {
+        let loops = self.timing.remaining_loops()? as f32;
+        let left = (loops + 1.0) * self.duration - self.elapsed;
+        Some(Duration::from_secs_f64(f64::from(left.max(0.0)) / 1000.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AnimationEngine;
+    use std::cell::Cell;
+
+    const CARD: &str = r#"{
+        "loop": "none",
+        "channels": {
+            "card.y": {
+                "kind": "f32",
+                "keyframes": [
+                    { "time_ms": 0, "value": 20 },
+                    { "time_ms": 300, "value": 0 }
+                ]
+            },
+            "card.opacity": {
+                "kind": "f32",
+                "keyframes": [
+                    { "time_ms": 0, "value": 0 },
+                    { "time_ms": 200, "value": 1 },
+                    { "time_ms": 400, "value": 0.5, "easing": "quad_in" }
+                ]
+            },
+            "card.tint": {
+                "kind": "color",
+                "keyframes": [
+                    { "time_ms": 0, "value": { "r": 1, "g": 1, "b": 1, "a": 1 } }
+                ]
+            },
+            "card.pose": {
+                "kind": "transform",
+                "keyframes": [
+                    { "time_ms": 0, "value": { "x": 10, "rotation": 0.5 } }
+                ]
+            }
+        }
+    }"#;
+
+    fn card() -> Clip {
+        let Ok(clip) = serde_json::from_str::<Clip>(CARD) else {
+            unreachable!("the card clip parses");
+        };
+        clip
+    }
+
+    #[test]
+    fn test_clips_round_trip_through_json() {
+        let clip = card();
+        assert_eq!(clip.validate(), Ok(()));
+        assert_eq!(clip.duration(), Duration::from_millis(400));
+        // Left out transform fields are the identity's
+        let Some(Channel::Transform(pose)) = clip.channels.get("card.pose") else {
+            unreachable!("the pose is a transform channel");
+        };
+        assert_eq!(
+            pose[0].value,
+            Transform {
+                x: 10.0,
+                rotation: 0.5,
+                ..Transform::identity()
+            }
+        );
+
+        let Ok(json) = serde_json::to_string(&clip) else {
+            unreachable!("clips serialize");
+        };
+        assert_eq!(serde_json::from_str::<Clip>(&json).ok(), Some(clip));
+
+        // Built in code, a clip serializes the same way
+        let built = Clip::new()
+            .loop_mode(LoopMode::Count(2))
+            .channel(
+                "dot.x",
+                Channel::F32(vec![
+                    ClipKey::new(0.0, 0.0),
+                    ClipKey::new(250.0, 40.0).easing("cubic_out"),
+                ]),
+            )
+            .channel(
+                "dot.color",
+                Channel::Color(vec![ClipKey::new(0.0, Color::new(1.0, 0.0, 0.0, 1.0))]),
+            );
+        let Ok(json) = serde_json::to_string(&built) else {
+            unreachable!("clips serialize");
+        };
+        assert!(json.contains(r#""loop":{"count":2}"#));
+        assert!(json.contains(r#""kind":"f32""#));
+        assert_eq!(serde_json::from_str::<Clip>(&json).ok(), Some(built));
+    }
+
+    #[test]
+    fn test_malformed_clips_are_rejected() {
+        let with = |keys: Vec<ClipKey<f32>>| Clip::new().channel("x", Channel::F32(keys));
+        let channel = "x".to_string();
+
+        assert_eq!(
+            with(Vec::new()).validate(),
+            Err(ClipError::EmptyChannel {
+                channel: channel.clone()
+            })
+        );
+        assert_eq!(
+            with(vec![ClipKey::new(100.0, 0.0), ClipKey::new(50.0, 1.0)]).validate(),
+            Err(ClipError::TimeGoesBack {
+                channel: channel.clone(),
+                index: 1
+            })
+        );
+        assert_eq!(
+            with(vec![ClipKey::new(-1.0, 0.0)]).validate(),
+            Err(ClipError::InvalidTime {
+                channel: channel.clone(),
+                time_ms: -1.0
+            })
+        );
+        let wobble = with(vec![ClipKey::new(0.0, 0.0).easing("wobble")]);
+        assert_eq!(
+            wobble.validate(),
+            Err(ClipError::UnknownEasing {
+                channel,
+                easing: "wobble".into()
+            })
+        );
+        assert_eq!(
+            card().loop_mode(LoopMode::Count(0)).validate(),
+            Err(ClipError::ZeroLoopCount)
+        );
+
+        // Equal times jump to the later value
+        let jump = with(vec![ClipKey::new(100.0, 0.0), ClipKey::new(100.0, 1.0)]);
+        assert_eq!(jump.validate(), Ok(()));
+        let Some(Channel::F32(keys)) = jump.channels.get("x") else {
+            unreachable!("x is an f32 channel");
+        };
+        assert_eq!(sample(keys, 99.0), Some(0.0));
+        assert_eq!(sample(keys, 100.0), Some(1.0));
+    }
+
+    struct Engines {
+        clock: Signal<AnimationEngine<f32>>,
+        y: Signal<AnimationEngine<f32>>,
+        opacity: Signal<AnimationEngine<f32>>,
+        tint: Signal<AnimationEngine<Color>>,
+        /// Bound to a channel the clip lacks
+        stray: Signal<AnimationEngine<f32>>,
+        /// Bound to a channel of another kind
+        pose: Signal<AnimationEngine<f32>>,
+    }
+
+    thread_local! {
+        static ENGINES: Cell<Option<Engines>> = const { Cell::new(None) };
+    }
+
+    #[test]
+    fn test_player_plays_pauses_and_seeks_the_whole_clip() {
+        fn app() -> Element {
+            ENGINES.set(Some(Engines {
+                clock: use_signal(|| AnimationEngine::new(0.0f32)),
+                y: use_signal(|| AnimationEngine::new(0.0f32)),
+                opacity: use_signal(|| AnimationEngine::new(0.0f32)),
+                tint: use_signal(|| AnimationEngine::new(Color::new(0.0, 0.0, 0.0, 1.0))),
+                stray: use_signal(|| AnimationEngine::new(7.0f32)),
+                pose: use_signal(|| AnimationEngine::new(3.0f32)),
+            }));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(Engines {
+            mut clock,
+            y,
+            opacity,
+            tint,
+            stray,
+            pose,
+        }) = ENGINES.take()
+        else {
+            unreachable!("the app stores its engines");
+        };
+        let near = |engine: Signal<AnimationEngine<f32>>, expected: f32| {
+            let value = engine.peek().get();
+            assert!((value - expected).abs() < 1e-3, "{value} != {expected}");
+        };
+
+        dom.in_runtime(|| {
+            let player = ClipPlayer::new(card(), MotionValue::new(clock));
+            player
+                .bind("card.y", &MotionValue::new(y))
+                .bind("card.opacity", &MotionValue::new(opacity))
+                .bind("card.tint", &MotionValue::new(tint));
+            // Bound values start at the clip's start
+            near(y, 20.0);
+            near(opacity, 0.0);
+            assert_eq!(tint.peek().get(), Color::new(1.0, 1.0, 1.0, 1.0));
+
+            // Unknown channels and other kinds of values are ignored
+            player
+                .bind("card.missing", &MotionValue::new(stray))
+                .bind("card.pose", &MotionValue::new(pose));
+
+            player.play();
+            assert!(player.is_playing());
+            clock.write().update(0.1);
+            // 100ms: y a third of the way down, opacity halfway up
+            near(y, 20.0 - 20.0 / 3.0);
+            near(opacity, 0.5);
+
+            clock.write().update(0.2);
+            // 300ms: y done, opacity halfway down a quad_in, at 0.25 of the way
+            near(y, 0.0);
+            near(opacity, 1.0 - 0.5 * 0.25);
+
+            player.pause();
+            assert!(!player.is_playing());
+            clock.write().update(0.05);
+            near(opacity, 0.875);
+
+            // Seeking while paused moves every value there and stays put
+            player.seek(Duration::from_millis(250));
+            near(y, 20.0 - 20.0 * 250.0 / 300.0);
+            near(opacity, 1.0 - 0.5 * 0.25 * 0.25);
+            assert!(!player.is_playing());
+
+            // Playing carries on from there to the end
+            player.play();
+            clock.write().update(0.1);
+            near(opacity, 1.0 - 0.5 * 0.75 * 0.75);
+            clock.write().update(0.1);
+            near(y, 0.0);
+            near(opacity, 0.5);
+            assert!(!player.is_playing());
+            assert_eq!(player.time(), Duration::from_millis(400));
+
+            assert_eq!(stray.peek().get(), 7.0);
+            assert_eq!(pose.peek().get(), 3.0);
+        });
+    }
+
+    #[test]
+    fn test_looping_clips_start_each_pass_over() {
+        let mut clock = ClipClock::new(0.0, 400.0, LoopMode::Count(2));
+        assert_eq!(clock.update(0.3), (AnimationState::Active, 300.0, 1000.0));
+        // Into the second pass
+        let (state, time, _) = clock.update(0.2);
+        assert_eq!(state, AnimationState::Active);
+        assert!((time - 100.0).abs() < 1e-3);
+        assert_eq!(clock.remaining_time(), Some(Duration::from_millis(300)));
+        // Ending on the last keyframe
+        assert_eq!(clock.update(0.5), (AnimationState::Completed, 400.0, 0.0));
+
+        // Alternating, the second pass plays the clip backwards to its start
+        let mut clock = ClipClock::new(0.0, 400.0, LoopMode::AlternateTimes(2));
+        clock.update(0.5);
+        assert!((clock.value() - 300.0).abs() < 1e-3);
+        assert!(clock.velocity() < 0.0);
+        assert_eq!(clock.update(0.4), (AnimationState::Completed, 0.0, 0.0));
+    }
+}
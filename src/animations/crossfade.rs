@@ -0,0 +1,207 @@
+//! Cross-fades between discrete states
+//!
+//! [`use_crossfade`] ties a 0 to 1 progress value to a state such as an enum:
+//! whenever the state changes, progress restarts from 0 and animates to 1 while
+//! the previous state is kept around, so both renderings can be drawn with
+//! opacities interpolated from the progress.
+//!
+//! # Example
+//!
+//! A play/pause icon:
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[derive(Clone, Copy, PartialEq)]
+//! enum Icon {
+//!     Play,
+//!     Pause,
+//! }
+//!
+//! fn glyph(icon: Icon) -> &'static str {
+//!     match icon {
+//!         Icon::Play => "▶",
+//!         Icon::Pause => "⏸",
+//!     }
+//! }
+//!
+//! #[component]
+//! fn PlayButton(icon: Icon) -> Element {
+//!     let fade = use_crossfade(icon, AnimationConfig::default());
+//!
+//!     rsx! {
+//!         span {
+//!             style: "position: relative; display: inline-block;",
+//!             if let Some(previous) = fade.previous() {
+//!                 span {
+//!                     style: "position: absolute; opacity: {fade.previous_opacity()};",
+//!                     {glyph(previous)}
+//!                 }
+//!             }
+//!             span { style: "opacity: {fade.current_opacity()};", {glyph(fade.current())} }
+//!         }
+//!     }
+//! }
+//! ```
+
+use dioxus::prelude::*;
+
+use crate::animation::AnimationConfig;
+use crate::{MotionValue, use_motion};
+
+/// The states taking part in a cross-fade
+#[derive(Debug, Clone, PartialEq)]
+struct CrossfadeState<S> {
+    current: S,
+    previous: Option<S>,
+}
+
+impl<S: Clone + PartialEq> CrossfadeState<S> {
+    /// Start settled on `state`
+    fn new(state: S) -> Self {
+        Self {
+            current: state,
+            previous: None,
+        }
+    }
+
+    /// Switch to `state` at `progress`; returns the progress to fade in from
+    ///
+    /// Switching back to the state that is still fading out reverses the fade
+    /// from where it is. Switching to a third state mid-fade drops the state
+    /// that was fading out.
+    fn switch(&mut self, state: S, progress: f32) -> Option<f32> {
+        if state == self.current {
+            return None;
+        }
+        let reversed = self.previous.as_ref() == Some(&state);
+        let previous = std::mem::replace(&mut self.current, state);
+        self.previous = Some(previous);
+        Some(if reversed { 1.0 - progress } else { 0.0 })
+    }
+
+    /// Finish the fade, dropping the previous state
+    fn settle(&mut self) {
+        self.previous = None;
+    }
+}
+
+/// Handle to a cross-fade created with [`use_crossfade`]
+pub struct Crossfade<S: 'static> {
+    progress: MotionValue<f32>,
+    states: Signal<CrossfadeState<S>>,
+}
+
+impl<S: 'static> Clone for Crossfade<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: 'static> Copy for Crossfade<S> {}
+
+impl<S: Clone + PartialEq + 'static> Crossfade<S> {
+    /// How far the fade to the current state is, from 0 to 1
+    pub fn progress(&self) -> f32 {
+        self.progress.get()
+    }
+
+    /// The state being faded to
+    pub fn current(&self) -> S {
+        self.states.read().current.clone()
+    }
+
+    /// The state being faded from, `None` once the fade has settled
+    pub fn previous(&self) -> Option<S> {
+        self.states.read().previous.clone()
+    }
+
+    /// Opacity for rendering the current state
+    pub fn current_opacity(&self) -> f32 {
+        self.progress().clamp(0.0, 1.0)
+    }
+
+    /// Opacity for rendering the previous state
+    pub fn previous_opacity(&self) -> f32 {
+        1.0 - self.current_opacity()
+    }
+
+    /// Whether a fade is running
+    pub fn is_animating(&self) -> bool {
+        self.progress.is_animating()
+    }
+}
+
+/// Cross-fade between renderings of `state`, animating with `config` on every change
+///
+/// Starts settled on the initial state. Like other hooks, call it
+/// unconditionally from the component body.
+pub fn use_crossfade<S: Clone + PartialEq + 'static>(
+    state: S,
+    config: AnimationConfig,
+) -> Crossfade<S> {
+    let mut progress = use_motion(1.0f32);
+    let mut states = use_signal(|| CrossfadeState::new(state.clone()));
+
+    use_effect(use_reactive((&state,), move |(state,)| {
+        let start = states.write().switch(state, progress.peek());
+        if let Some(start) = start {
+            progress.set(start);
+            progress.animate_to_with_config(1.0, config.clone());
+        }
+    }));
+
+    // Drop the previous state once the fade comes to rest
+    use_effect(move || {
+        if !progress.is_animating() && states.peek().previous.is_some() {
+            states.write().settle();
+        }
+    });
+
+    Crossfade { progress, states }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Icon {
+        Play,
+        Pause,
+        Stop,
+    }
+
+    #[test]
+    fn test_switch_fades_in_from_zero() {
+        let mut state = CrossfadeState::new(Icon::Play);
+        assert_eq!(state.switch(Icon::Play, 1.0), None);
+
+        assert_eq!(state.switch(Icon::Pause, 1.0), Some(0.0));
+        assert_eq!(state.current, Icon::Pause);
+        assert_eq!(state.previous, Some(Icon::Play));
+
+        state.settle();
+        assert_eq!(state.previous, None);
+    }
+
+    #[test]
+    fn test_switching_back_reverses_mid_fade() {
+        let mut state = CrossfadeState::new(Icon::Play);
+        state.switch(Icon::Pause, 1.0);
+        // 30% into the fade, Play is still 70% visible
+        assert_eq!(state.switch(Icon::Play, 0.3), Some(0.7));
+        assert_eq!(state.current, Icon::Play);
+        assert_eq!(state.previous, Some(Icon::Pause));
+    }
+
+    #[test]
+    fn test_third_state_replaces_fading_out_state() {
+        let mut state = CrossfadeState::new(Icon::Play);
+        state.switch(Icon::Pause, 1.0);
+        assert_eq!(state.switch(Icon::Stop, 0.5), Some(0.0));
+        assert_eq!(state.current, Icon::Stop);
+        assert_eq!(state.previous, Some(Icon::Pause));
+    }
+}
@@ -0,0 +1,250 @@
+//! Velocity cues of a running animation, e.g. for sound design
+//!
+//! [`Cues`] watch the velocity of one run frame by frame: each time the value
+//! turns around, as a bouncy spring does at every swing, and once it has come
+//! to rest to the eye, which is usually well before the spring completes.
+//! Set up with [`SpringBuilder::on_direction_change`] and
+//! [`SpringBuilder::rest_when`].
+//!
+//! A turn is where the velocity, projected onto the line from where the run
+//! started to its target, changes sign. For values with several channels,
+//! such as transforms, a reversal along that line counts and a swing sideways
+//! doesn't. Only turns after moving faster than a dead band count, so the
+//! numerical jitter of a value all but at rest never fires them.
+//!
+//! [`SpringBuilder::on_direction_change`]: crate::animations::spring::SpringBuilder::on_direction_change
+//! [`SpringBuilder::rest_when`]: crate::animations::spring::SpringBuilder::rest_when
+
+use instant::Duration;
+
+use crate::Animatable;
+use crate::animation::{Callback, RepeatingCallback};
+
+/// Share of the distance to the target per second below which a turn doesn't
+/// count, when no rest speed is given
+const DEAD_BAND: f32 = 0.01;
+
+/// Callbacks fired from the velocity of one run, and what they have seen of it
+pub(crate) struct Cues<T: Animatable> {
+    on_direction_change: Option<RepeatingCallback>,
+    /// Speed and how long it must stay below it, and the callback to fire then
+    rest: Option<(f32, Duration, Callback)>,
+    /// Direction from the start of the run to its target
+    axis: T,
+    /// Speeds at or below this don't arm a turn
+    dead_band: f32,
+    /// Projected velocity of the last frame
+    last: f32,
+    /// Whether the value moved faster than the dead band since the last turn
+    armed: bool,
+    /// Whether the value moved faster than the rest speed yet
+    moved: bool,
+    /// Seconds the speed has stayed below the rest speed
+    still: f32,
+}
+
+impl<T: Animatable> Cues<T> {
+    pub(crate) fn new(
+        on_direction_change: Option<RepeatingCallback>,
+        rest: Option<(f32, Duration, Callback)>,
+    ) -> Self {
+        Self {
+            on_direction_change,
+            rest,
+            axis: T::zero(),
+            dead_band: 0.0,
+            last: 0.0,
+            armed: false,
+            moved: false,
+            still: 0.0,
+        }
+    }
+
+    /// Start watching a run heading from `from` to `to`
+    pub(crate) fn begin(&mut self, from: T, to: T) {
+        let travel = to.sub(&from);
+        let distance = travel.magnitude();
+        self.axis = if distance > T::epsilon() {
+            travel.scale(1.0 / distance)
+        } else {
+            T::zero()
+        };
+        self.dead_band = self.rest.as_ref().map_or_else(
+            || (distance * DEAD_BAND).max(T::epsilon()),
+            |(speed, _, _)| *speed,
+        );
+        self.last = 0.0;
+        self.armed = false;
+        self.moved = false;
+        self.still = 0.0;
+    }
+
+    /// Look at a frame `dt` seconds long ending at `velocity`, queueing the
+    /// callbacks it fires into `ready`
+    pub(crate) fn observe(&mut self, velocity: T, dt: f32, ready: &mut Vec<Callback>) {
+        let along = dot(&velocity, &self.axis);
+        if along * self.last < 0.0 && self.armed {
+            if let Some(callback) = &self.on_direction_change {
+                ready.push(callback.once());
+            }
+            self.armed = false;
+        }
+        if along != 0.0 {
+            self.last = along;
+        }
+        self.armed |= along.abs() > self.dead_band;
+
+        let Some((speed, hold, _)) = &self.rest else {
+            return;
+        };
+        if velocity.magnitude() > *speed {
+            self.moved = true;
+            self.still = 0.0;
+        } else if self.moved {
+            self.still += dt;
+            if self.still >= hold.as_secs_f32() {
+                self.fire_rest(ready);
+            }
+        }
+    }
+
+    /// The run settled on its own: fire the rest callback if it hasn't yet
+    pub(crate) fn finish(mut self, ready: &mut Vec<Callback>) {
+        self.fire_rest(ready);
+    }
+
+    fn fire_rest(&mut self, ready: &mut Vec<Callback>) {
+        if let Some((_, _, callback)) = self.rest.take() {
+            ready.push(callback);
+        }
+    }
+}
+
+/// Dot product of two values, from their magnitudes
+fn dot<T: Animatable>(a: &T, b: &T) -> f32 {
+    let sum = a.add(b).magnitude();
+    let difference = a.sub(b).magnitude();
+    (sum * sum - difference * difference) / 4.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::{Animation, AnimationState, boxed};
+    use crate::animations::spring::Spring;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const FRAME: f32 = 1.0 / 60.0;
+
+    fn counter() -> (Arc<AtomicUsize>, impl FnMut() + Send + 'static) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let counted = count.clone();
+        (count, move || {
+            counted.fetch_add(1, Ordering::SeqCst);
+        })
+    }
+
+    /// Play a spring from 0 to 100 to its end, returning the frame each
+    /// callback fired on
+    fn play(spring: Spring, mut cues: Cues<f32>) -> (usize, Vec<usize>) {
+        let mut animation = spring.create_animation(0.0f32, 100.0, 0.0);
+        cues.begin(0.0, 100.0);
+        let mut fired = Vec::new();
+        let mut frame = 0;
+        loop {
+            frame += 1;
+            let (state, _, velocity) = animation.update(FRAME);
+            let mut ready = Vec::new();
+            let done = state == AnimationState::Completed || !animation.is_active();
+            if !done {
+                cues.observe(velocity, FRAME, &mut ready);
+            }
+            for callback in ready.drain(..) {
+                callback();
+                fired.push(frame);
+            }
+            if done {
+                cues.finish(&mut ready);
+                for callback in ready {
+                    callback();
+                    fired.push(frame);
+                }
+                return (frame, fired);
+            }
+            assert!(frame < 6000, "the spring settles");
+        }
+    }
+
+    #[test]
+    fn test_underdamped_springs_turn_once_per_swing() {
+        // ω = 10, ζ = 0.2: each swing peaks at about 0.53 of the last, from
+        // about 755/s on the way out; with a dead band of 10/s the swings
+        // down to ~16/s count and the next, ~8.5/s, doesn't
+        let spring = Spring {
+            stiffness: 100.0,
+            damping: 4.0,
+            ..Spring::default()
+        };
+        let (count, on_turn) = counter();
+        let cues = Cues::new(
+            Some(RepeatingCallback::new(on_turn)),
+            Some((10.0, Duration::from_secs(10), boxed(|| {}))),
+        );
+        let (frames, fired) = play(spring, cues);
+        assert_eq!(count.load(Ordering::SeqCst), 7);
+
+        // A turn every half period of π/ωd ≈ 0.32s, the first one after the
+        // first overshoot
+        let turns = &fired[..7];
+        let half_period = std::f32::consts::PI / (10.0 * (1.0f32 - 0.04).sqrt()) / FRAME;
+        for (index, frame) in turns.iter().enumerate() {
+            let expected = half_period * (index + 1) as f32;
+            assert!((*frame as f32 - expected).abs() <= 2.0);
+        }
+        // The rest callback waited for the spring to complete
+        assert_eq!(fired.last(), Some(&frames));
+    }
+
+    #[test]
+    fn test_critically_damped_springs_never_turn() {
+        let spring = Spring {
+            stiffness: 100.0,
+            damping: 20.0,
+            ..Spring::default()
+        };
+        let (count, on_turn) = counter();
+        play(
+            spring,
+            Cues::new(Some(RepeatingCallback::new(on_turn)), None),
+        );
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_rest_fires_once_the_speed_stays_low() {
+        let spring = Spring {
+            stiffness: 100.0,
+            damping: 4.0,
+            ..Spring::default()
+        };
+        let (count, on_rest) = counter();
+        let cues = Cues::new(
+            None,
+            Some((50.0, Duration::from_millis(200), boxed(on_rest))),
+        );
+        let (frames, fired) = play(spring, cues);
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        // Once, well before the spring completes
+        assert_eq!(fired.len(), 1);
+        assert!(fired[0] + 30 < frames);
+    }
+
+    #[test]
+    fn test_sideways_swings_are_not_turns() {
+        let mut cues = Cues::<[f32; 2]>::new(None, None);
+        cues.begin([0.0, 0.0], [100.0, 0.0]);
+        assert!((dot(&[3.0, -4.0], &cues.axis) - 3.0).abs() < 1e-4);
+        assert!(dot(&[0.0, 7.0], &cues.axis).abs() < 1e-4);
+    }
+}
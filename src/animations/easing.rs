@@ -0,0 +1,265 @@
+//! Easing combinators and curves
+//!
+//! Builds compound curves out of the easer functions, e.g. easing in over the
+//! first part of an animation and moving linearly after that, and provides
+//! [`CubicBezier`] for CSS-style `cubic-bezier()` timing curves. Easings
+//! written down as data, e.g. in a [`Clip`](crate::animations::clip::Clip),
+//! are looked up by name with [`easing_named`].
+//!
+//! Tweens and keyframes store easings as plain function pointers, so a curve
+//! built here is handed over through a closure that captures nothing:
+//!
+//! ```ignore
+//! use dioxus_motion2::prelude::*;
+//! use easer::functions::{Cubic, Easing, Linear};
+//!
+//! let tween = Tween::new().easing(|t, b, c, d| {
+//!     split_easing(0.3, Cubic::ease_in, Linear::ease_in_out)(t, b, c, d)
+//! });
+//! ```
+
+use easer::functions::{
+    Back, Bounce, Circ, Cubic, Easing, Elastic, Expo, Linear, Quad, Quart, Quint, Sine,
+};
+
+use crate::animations::tween::EasingFunction;
+
+/// The easer function called `name`, e.g. `"linear"` or `"cubic_out"`
+///
+/// Names are a family, one of `quad`, `cubic`, `quart`, `quint`, `sine`,
+/// `expo`, `circ`, `back`, `elastic` and `bounce`, followed by `_in`, `_out`
+/// or `_in_out`. Returns `None` for any other name.
+pub fn easing_named(name: &str) -> Option<EasingFunction> {
+    fn ends<E: Easing<f32>>(end: &str) -> Option<EasingFunction> {
+        match end {
+            "in" => Some(E::ease_in),
+            "out" => Some(E::ease_out),
+            "in_out" => Some(E::ease_in_out),
+            _ => None,
+        }
+    }
+
+    if name == "linear" {
+        return Some(Linear::ease_in_out);
+    }
+    let (family, end) = name.split_once('_')?;
+    match family {
+        "quad" => ends::<Quad>(end),
+        "cubic" => ends::<Cubic>(end),
+        "quart" => ends::<Quart>(end),
+        "quint" => ends::<Quint>(end),
+        "sine" => ends::<Sine>(end),
+        "expo" => ends::<Expo>(end),
+        "circ" => ends::<Circ>(end),
+        "back" => ends::<Back>(end),
+        "elastic" => ends::<Elastic>(end),
+        "bounce" => ends::<Bounce>(end),
+        _ => None,
+    }
+}
+
+/// Join two easings at `at`, a fraction of the animation between 0 and 1
+///
+/// `first` covers progress up to `at` and `second` the rest, each scaled to its
+/// share of both time and distance, so the curve reaches `at` exactly at the
+/// split and has no jump there. An `at` outside `0.0..=1.0` is clamped, leaving
+/// a single easing for the whole animation.
+///
+/// The result takes the same `(t, b, c, d)` arguments as the easer functions.
+pub fn split_easing(
+    at: f32,
+    first: EasingFunction,
+    second: EasingFunction,
+) -> impl Fn(f32, f32, f32, f32) -> f32 + Copy {
+    let at = at.clamp(0.0, 1.0);
+    move |t, b, c, d| {
+        let progress = if d == 0.0 { 1.0 } else { t / d };
+        let eased = if progress < at {
+            first(progress / at, 0.0, at, 1.0)
+        } else if at < 1.0 {
+            second((progress - at) / (1.0 - at), at, 1.0 - at, 1.0)
+        } else {
+            first(1.0, 0.0, 1.0, 1.0)
+        };
+        b + c * eased
+    }
+}
+
+/// A CSS `cubic-bezier(x1, y1, x2, y2)` timing curve
+///
+/// The curve runs from `(0, 0)` to `(1, 1)` with control points `(x1, y1)`
+/// and `(x2, y2)`; `x` is the share of time elapsed and `y` the share of the
+/// distance covered, which may leave `0.0..=1.0` to anticipate or overshoot.
+/// As in CSS, `x1` and `x2` are clamped into `0.0..=1.0` so each time has one
+/// progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+impl CubicBezier {
+    /// The curve with control points `(x1, y1)` and `(x2, y2)`
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: x1.clamp(0.0, 1.0),
+            y1,
+            x2: x2.clamp(0.0, 1.0),
+            y2,
+        }
+    }
+
+    /// The control points, `(x1, y1, x2, y2)`
+    pub fn points(&self) -> (f32, f32, f32, f32) {
+        (self.x1, self.y1, self.x2, self.y2)
+    }
+
+    /// Progress along the curve `progress` of the way through time
+    pub fn ease(&self, progress: f32) -> f32 {
+        if progress <= 0.0 {
+            return 0.0;
+        }
+        if progress >= 1.0 {
+            return 1.0;
+        }
+        let t = self.solve_x(progress);
+        bezier(t, self.y1, self.y2)
+    }
+
+    /// The curve parameter whose `x` is `x`: Newton's method, falling back to
+    /// bisection where the slope is too flat
+    fn solve_x(&self, x: f32) -> f32 {
+        let mut t = x;
+        for _ in 0..8 {
+            let error = bezier(t, self.x1, self.x2) - x;
+            if error.abs() < 1e-6 {
+                return t;
+            }
+            let slope = bezier_slope(t, self.x1, self.x2);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            t -= error / slope;
+        }
+
+        let (mut low, mut high) = (0.0, 1.0);
+        t = x;
+        for _ in 0..32 {
+            let value = bezier(t, self.x1, self.x2);
+            if (value - x).abs() < 1e-6 {
+                break;
+            }
+            if value < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) / 2.0;
+        }
+        t
+    }
+}
+
+/// One coordinate of the curve from 0 to 1 through control values `p1` and `p2`
+fn bezier(t: f32, p1: f32, p2: f32) -> f32 {
+    let u = 1.0 - t;
+    3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+}
+
+/// Slope of [`bezier`] at `t`
+fn bezier_slope(t: f32, p1: f32, p2: f32) -> f32 {
+    let u = 1.0 - t;
+    3.0 * u * u * p1 + 6.0 * u * t * (p2 - p1) + 3.0 * t * t * (1.0 - p2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(easing: impl Fn(f32, f32, f32, f32) -> f32, progress: f32) -> f32 {
+        easing(progress, 0.0, 1.0, 1.0)
+    }
+
+    #[test]
+    fn test_eases_in_then_goes_linear() {
+        let easing = split_easing(0.3, Cubic::ease_in, Linear::ease_in_out);
+
+        assert_eq!(sample(easing, 0.0), 0.0);
+        // Halfway through the ease-in: 0.3 * 0.5³
+        assert!((sample(easing, 0.15) - 0.0375).abs() < 1e-6);
+        assert!(sample(easing, 0.15) < 0.15);
+        assert!((sample(easing, 0.3) - 0.3).abs() < 1e-6);
+        for progress in [0.4, 0.65, 0.9] {
+            assert!((sample(easing, progress) - progress).abs() < 1e-6);
+        }
+        assert!((sample(easing, 1.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_respects_start_change_and_duration() {
+        let easing = split_easing(0.3, Cubic::ease_in, Linear::ease_in_out);
+        // 650ms into a 1s move from 100 to 300
+        assert!((easing(650.0, 100.0, 200.0, 1000.0) - 230.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_split_at_the_ends_uses_one_easing() {
+        let all_first = split_easing(1.5, Cubic::ease_in, Linear::ease_in_out);
+        assert!((sample(all_first, 0.5) - 0.125).abs() < 1e-6);
+        assert!((sample(all_first, 1.0) - 1.0).abs() < 1e-6);
+
+        let all_second = split_easing(-1.0, Cubic::ease_in, Linear::ease_in_out);
+        assert!((sample(all_second, 0.5) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cubic_bezier_matches_css_curves() {
+        // CSS `ease`, known to reach ~0.8024 halfway through
+        let ease = CubicBezier::new(0.25, 0.1, 0.25, 1.0);
+        assert!((ease.ease(0.5) - 0.8024).abs() < 1e-3);
+        assert_eq!(ease.ease(0.0), 0.0);
+        assert_eq!(ease.ease(1.0), 1.0);
+
+        // Straight control points give a straight line
+        let linear = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+        for progress in [0.1, 0.37, 0.8] {
+            assert!((linear.ease(progress) - progress).abs() < 1e-4);
+        }
+
+        // Overshoots past the end, and x is kept within time
+        let back = CubicBezier::new(0.3, 0.0, 1.4, 1.6);
+        assert_eq!(back.points(), (0.3, 0.0, 1.0, 1.6));
+        assert!(
+            [0.6, 0.7, 0.8, 0.9]
+                .into_iter()
+                .any(|progress| back.ease(progress) > 1.0)
+        );
+    }
+
+    #[test]
+    fn test_usable_as_tween_easing() {
+        let tween = crate::animations::tween::Tween::new().easing(|t, b, c, d| {
+            split_easing(0.3, Cubic::ease_in, Linear::ease_in_out)(t, b, c, d)
+        });
+        assert!(((tween.easing)(0.65, 0.0, 1.0, 1.0) - 0.65).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_easings_are_found_by_name() {
+        let Some(cubic_out) = easing_named("cubic_out") else {
+            unreachable!("cubic_out is a known easing");
+        };
+        assert_eq!(sample(cubic_out, 0.5), sample(Cubic::ease_out, 0.5));
+        assert_eq!(
+            easing_named("linear").map(|linear| sample(linear, 0.3)),
+            Some(0.3)
+        );
+        assert!(easing_named("bounce_in_out").is_some());
+
+        for unknown in ["", "cubic", "cubic_sideways", "wobble_in", "linear_in"] {
+            assert!(easing_named(unknown).is_none(), "{unknown}");
+        }
+    }
+}
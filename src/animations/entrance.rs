@@ -0,0 +1,263 @@
+//! Declarative entrance choreography
+//!
+//! Landing pages tend to bring their elements in the same way: each one rises a
+//! little while fading in, slightly after the one before it. An [`Entrance`]
+//! describes that once, for the whole page, and every element plays it with
+//! [`use_entrance`] given its place in the order, so the timing stays
+//! consistent and lives in one spot.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! fn hero() -> Entrance {
+//!     Entrance::new()
+//!         .rise(24.0)
+//!         .fade()
+//!         .stagger(Duration::from_millis(70))
+//!         .spring(Spring::snappy())
+//! }
+//!
+//! #[component]
+//! fn Card(index: usize, title: String) -> Element {
+//!     let style = use_entrance(&hero(), index);
+//!     rsx! {
+//!         div { style: "{style.get().to_css_string()}", "{title}" }
+//!     }
+//! }
+//! ```
+//!
+//! [`use_entrance_on`] waits for a trigger shared by the group instead of
+//! playing on mount, e.g. for a section scrolled into view.
+
+use dioxus::prelude::*;
+use instant::Duration;
+
+use crate::animation::{AnimationConfig, AnimationMode};
+use crate::animations::spring::Spring;
+use crate::properties::{style::MotionStyle, transform::Transform};
+use crate::{MotionValue, use_motion};
+
+/// How a group of elements comes in, each one after the one before it
+///
+/// A factory for entrance animations: it holds the design only, and each
+/// element plays it through [`use_entrance`] or [`use_entrance_on`].
+#[derive(Debug, Clone)]
+pub struct Entrance {
+    /// Distance in pixels the elements rise from
+    rise: f32,
+    /// Whether the elements fade in from transparent
+    fade: bool,
+    /// Delay between the start of one element and the next
+    stagger: Duration,
+    /// Spring bringing each element in
+    spring: Spring,
+}
+
+impl Default for Entrance {
+    fn default() -> Self {
+        Self {
+            rise: 0.0,
+            fade: false,
+            stagger: Duration::ZERO,
+            spring: Spring::snappy(),
+        }
+    }
+}
+
+impl Entrance {
+    /// An entrance that plays a [`Spring::snappy`] and moves nothing yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rise into place from `distance` pixels below
+    pub fn rise(mut self, distance: f32) -> Self {
+        self.rise = distance;
+        self
+    }
+
+    /// Fade in from transparent
+    pub fn fade(mut self) -> Self {
+        self.fade = true;
+        self
+    }
+
+    /// Start each element `delay` after the one before it
+    pub fn stagger(mut self, delay: Duration) -> Self {
+        self.stagger = delay;
+        self
+    }
+
+    /// Bring each element in with `spring`
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.spring = spring;
+        self
+    }
+
+    /// The style elements start from, before they come in
+    pub fn hidden(&self) -> MotionStyle {
+        let opacity = if self.fade { 0.0 } else { 1.0 };
+        MotionStyle::new(Transform::translate(0.0, self.rise), opacity)
+    }
+
+    /// The style elements come to rest at
+    pub fn shown(&self) -> MotionStyle {
+        MotionStyle::visible()
+    }
+
+    /// How long the element at `index` in the order waits before coming in
+    pub fn delay_for(&self, index: usize) -> Duration {
+        self.stagger
+            .saturating_mul(u32::try_from(index).unwrap_or(u32::MAX))
+    }
+
+    /// Animation bringing in the element at `index`
+    fn config(&self, index: usize) -> AnimationConfig {
+        AnimationConfig::new(AnimationMode::Spring(self.spring.clone()))
+            .with_delay(self.delay_for(index))
+    }
+}
+
+/// Play `entrance` for the element at `index` in the order, on mount
+///
+/// Returns the element's style, starting hidden. Like other hooks, call it
+/// unconditionally from the component body; `entrance` and `index` are read
+/// on the first render only.
+pub fn use_entrance(entrance: &Entrance, index: usize) -> MotionValue<MotionStyle> {
+    use_entrance_on(entrance, index, true)
+}
+
+/// Play `entrance` for the element at `index` once `shown` turns true
+///
+/// Share `shown` across the group, e.g. a signal set when the section scrolls
+/// into view, and the elements come in staggered from that moment. When it
+/// turns false again they go back to hidden at once, ready to play again.
+/// `entrance` and `index` are read on the first render only.
+pub fn use_entrance_on(entrance: &Entrance, index: usize, shown: bool) -> MotionValue<MotionStyle> {
+    let mut style = use_motion(entrance.hidden());
+    let entrance = use_hook(|| entrance.clone());
+
+    use_effect(use_reactive((&shown,), move |(shown,)| {
+        if shown {
+            style.animate_to_with_config(entrance.shown(), entrance.config(index));
+        } else {
+            style.set(entrance.hidden());
+        }
+    }));
+
+    style
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entrance_styles_and_delays() {
+        let entrance = Entrance::new()
+            .rise(24.0)
+            .fade()
+            .stagger(Duration::from_millis(70));
+        assert_eq!(
+            entrance.hidden(),
+            MotionStyle::new(Transform::translate(0.0, 24.0), 0.0)
+        );
+        assert_eq!(entrance.shown(), MotionStyle::visible());
+
+        // Each element waits one stagger longer than the one before it
+        assert_eq!(entrance.delay_for(0), Duration::ZERO);
+        assert_eq!(entrance.delay_for(3), Duration::from_millis(210));
+        assert_eq!(
+            entrance.config(2),
+            AnimationConfig::new(AnimationMode::Spring(Spring::snappy()))
+                .with_delay(Duration::from_millis(140))
+        );
+
+        // Without a fade, elements only move
+        assert_eq!(Entrance::new().rise(10.0).hidden().opacity(), 1.0);
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_group_comes_in_staggered_once_shown() {
+        use std::cell::Cell;
+
+        use crate::testing::{MotionHarness, motion_test_harness};
+
+        thread_local! {
+            static SHOWN: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+        }
+
+        fn entrance() -> Entrance {
+            Entrance::new()
+                .rise(24.0)
+                .fade()
+                .stagger(Duration::from_millis(70))
+        }
+
+        #[component]
+        fn Card(index: usize, shown: bool) -> Element {
+            let style = use_entrance_on(&entrance(), index, shown);
+            rsx! {
+                div { style: "{style.get().to_css_string()}" }
+            }
+        }
+
+        fn app() -> Element {
+            let shown = use_signal(|| false);
+            SHOWN.set(Some(shown));
+            rsx! {
+                for index in 0..3 {
+                    Card { key: "{index}", index, shown: shown() }
+                }
+            }
+        }
+
+        let styles = |harness: &MotionHarness| -> Vec<MotionStyle> {
+            harness
+                .motion_values()
+                .iter()
+                .filter_map(|snapshot| snapshot.value())
+                .collect()
+        };
+        let mut harness = motion_test_harness(app);
+        let Some(mut shown) = SHOWN.get() else {
+            unreachable!("the app stores its trigger");
+        };
+        assert_eq!(styles(&harness), vec![entrance().hidden(); 3]);
+        harness.tick(Duration::from_millis(500));
+        assert_eq!(styles(&harness), vec![entrance().hidden(); 3]);
+
+        // A card's effect can hold the next one's back to the following pass,
+        // so give them a frame to all start
+        harness.act(|| shown.set(true));
+        harness.tick(Duration::from_millis(20));
+        for snapshot in harness.motion_values() {
+            assert_eq!(snapshot.target(), Some(entrance().shown()));
+        }
+
+        // Two stagger steps in, the last card is still waiting its turn
+        harness.tick(Duration::from_millis(100));
+        let opacities: Vec<f32> = styles(&harness).iter().map(MotionStyle::opacity).collect();
+        assert!(opacities[0] > opacities[1], "{opacities:?}");
+        assert!(opacities[1] > 0.0, "{opacities:?}");
+        assert_eq!(opacities[2], 0.0);
+
+        harness.tick(Duration::from_secs(2));
+        assert_eq!(styles(&harness), vec![entrance().shown(); 3]);
+
+        // Hiding is immediate, ready to play again
+        harness.act(|| shown.set(false));
+        harness.tick(Duration::from_millis(20));
+        assert_eq!(styles(&harness), vec![entrance().hidden(); 3]);
+        assert!(
+            harness
+                .motion_values()
+                .iter()
+                .all(|value| !value.is_animating())
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Motion values chasing other motion values and signals
+//!
+//! [`MotionValue::follow`] makes one motion value spring toward another one's
+//! current value, retargeting every frame as the leader moves. Each follower
+//! keeps its own velocity, so a chain of followers, each following the one
+//! before it, trails behind the leader like a whip.
+//!
+//! [`use_tracked_motion`] creates a value animating toward a signal's value
+//! whenever it changes, e.g. a slider's position held in component state.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Trail() -> Element {
+//!     let mut head = use_motion(0.0f32);
+//!     let links: Vec<_> = (0..5).map(|_| use_motion(0.0f32)).collect();
+//!
+//!     use_hook({
+//!         let links = links.clone();
+//!         move || {
+//!             let mut leader = head;
+//!             for mut link in links {
+//!                 link.follow(leader, Spring::new().stiffness(300.0).damping(20.0));
+//!                 leader = link;
+//!             }
+//!         }
+//!     });
+//!
+//!     rsx! {
+//!         div {
+//!             onmousemove: move |event| {
+//!                 head.spring().animate_to(event.client_coordinates().x as f32);
+//!             },
+//!             for (i, link) in links.iter().enumerate() {
+//!                 div { key: "{i}", class: "dot", style: "transform: translateX({link.get()}px)" }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use dioxus::prelude::*;
+use dioxus::signals::BorrowMutError;
+
+use crate::animation::AnimationConfig;
+use crate::animations::spring::Spring;
+use crate::{Animatable, MotionValue, use_motion};
+
+impl<T: Animatable> MotionValue<T> {
+    /// Spring toward `leader`'s value, retargeting whenever it changes
+    ///
+    /// The follower starts chasing the leader's current value right away and
+    /// keeps its velocity when the leader moves on. Following lasts as long as
+    /// both values exist; call it once, e.g. from `use_hook`, rather than on
+    /// every render. Animating the follower directly still works, until the
+    /// leader moves again.
+    pub fn follow(&mut self, mut leader: MotionValue<T>, spring: Spring) {
+        let mut follower = *self;
+        follower.engine.write().chase(leader.peek(), &spring);
+        leader
+            .engine
+            .write()
+            .add_follower(move |&value| match follower.engine.try_write() {
+                Ok(mut engine) => {
+                    engine.chase(value, &spring);
+                    true
+                }
+                // The follower unmounted
+                Err(BorrowMutError::Dropped(_)) => false,
+                Err(_) => true,
+            });
+    }
+}
+
+/// A motion value animating toward `signal`'s value with `config` whenever it
+/// changes
+///
+/// Starts at the signal's current value, then retargets on every change,
+/// keeping its velocity for a spring. A re-run with an unchanged value leaves
+/// the running animation alone, as with
+/// [`animate_to_if_changed`](MotionValue::animate_to_if_changed). Like other
+/// hooks, call it unconditionally from the component body.
+pub fn use_tracked_motion<T: Animatable>(
+    signal: impl Readable<Target = T> + 'static,
+    config: AnimationConfig,
+) -> MotionValue<T> {
+    let mut motion = use_motion(*signal.peek());
+    use_effect(move || {
+        let target = *signal.read();
+        motion.animate_to_if_changed(target, config.clone());
+    });
+    motion
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use dioxus::prelude::*;
+
+    use super::use_tracked_motion;
+    use crate::animation::{AnimationConfig, AnimationMode};
+    use crate::animations::spring::Spring;
+    use crate::{AnimationEngine, MotionValue};
+
+    #[test]
+    fn test_chain_trails_behind_leader() {
+        thread_local! {
+            static LINKS: RefCell<Vec<MotionValue<f32>>> = const { RefCell::new(Vec::new()) };
+        }
+
+        fn trail() -> Element {
+            let links: Vec<_> = (0..4).map(|_| crate::use_motion(0.0f32)).collect();
+            use_hook({
+                let links = links.clone();
+                move || {
+                    for pair in links.windows(2) {
+                        let mut link = pair[1];
+                        link.follow(pair[0], Spring::new().stiffness(300.0).damping(20.0));
+                    }
+                }
+            });
+            LINKS.set(links);
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(trail);
+        dom.rebuild_in_place();
+        let links = LINKS.take();
+        let values = |dom: &VirtualDom| -> Vec<f32> {
+            dom.in_runtime(|| links.iter().map(MotionValue::peek).collect())
+        };
+
+        dom.in_runtime(|| links[0].spring().animate_to(100.0));
+        let mut frames = 0;
+        let mut trailing = true;
+        while dom.in_runtime(|| crate::driver::step(1.0 / 60.0)) && frames < 600 {
+            frames += 1;
+            if frames == 10 {
+                // Each link lags behind the one it follows
+                trailing = values(&dom)
+                    .windows(2)
+                    .all(|pair| pair[0] > pair[1] && pair[1] > 0.0);
+            }
+        }
+
+        assert!(trailing, "links should trail the leader");
+        assert!(frames < 600, "the chain should come to rest");
+        for value in values(&dom) {
+            assert!((value - 100.0).abs() < 0.01);
+        }
+    }
+
+    #[test]
+    fn test_retargeting_keeps_the_run_and_velocity() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.chase(100.0, &Spring::default());
+        for _ in 0..5 {
+            engine.update(1.0 / 60.0);
+        }
+        let token = engine.run_token();
+        let value = engine.get();
+
+        engine.chase(200.0, &Spring::default());
+        assert_eq!(engine.run_token(), token);
+        engine.update(1.0 / 60.0);
+        assert!(engine.get() > value);
+    }
+
+    #[test]
+    fn test_settled_follower_ignores_unchanged_target() {
+        let mut engine = AnimationEngine::new(50.0f32);
+        engine.chase(50.0, &Spring::default());
+        assert!(!engine.is_active());
+    }
+
+    #[test]
+    fn test_tracked_motion_retargets_on_every_change() {
+        thread_local! {
+            static STATE: Cell<Option<(Signal<f32>, MotionValue<f32>)>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let level = use_signal(|| 10.0f32);
+            let motion = use_tracked_motion(
+                level,
+                AnimationConfig::new(AnimationMode::Spring(Spring::default())),
+            );
+            STATE.set(Some((level, motion)));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some((mut level, motion)) = STATE.get() else {
+            unreachable!("the app stores its signals");
+        };
+        let settle = |dom: &mut VirtualDom| {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        };
+        settle(&mut dom);
+        // Already at the signal's value: nothing to animate
+        assert_eq!(dom.in_runtime(|| motion.peek()), 10.0);
+        assert!(!dom.in_runtime(|| motion.is_animating()));
+
+        for target in [40.0, 75.0] {
+            dom.in_runtime(|| level.set(target));
+            settle(&mut dom);
+            assert_eq!(
+                dom.in_runtime(|| motion.engine.peek().target()),
+                Some(target)
+            );
+        }
+    }
+}
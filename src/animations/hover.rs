@@ -0,0 +1,302 @@
+//! Hover intent for debounced hover animations
+//!
+//! Starting a hover animation on every `mouseenter` makes elements flash when the
+//! pointer merely brushes over them. A hover intent only activates once the
+//! pointer has stayed on the element for a delay, and a quick exit cancels the
+//! pending activation so no animation starts at all.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Card() -> Element {
+//!     let mut intent = use_hover_intent(Duration::from_millis(120));
+//!     let scale = use_motion(1.0f32);
+//!     use_hover_motion(scale, intent, 1.0, 1.05, AnimationConfig::default());
+//!
+//!     rsx! {
+//!         div {
+//!             style: "transform: scale({scale.get()});",
+//!             onmouseenter: move |_| intent.enter(),
+//!             onmouseleave: move |_| intent.leave(),
+//!             "Hover me"
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use instant::Duration;
+
+use crate::animation::AnimationConfig;
+use crate::{Animatable, MotionTime, MotionValue, TimeProvider};
+
+/// Bookkeeping for pending and active hovers
+///
+/// Every enter or leave bumps the generation, so a pending activation from an
+/// earlier enter can tell that it has been superseded.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct HoverState {
+    generation: u64,
+    active: bool,
+}
+
+impl HoverState {
+    /// Register a pointer entering; returns the token of the pending activation
+    fn enter(&mut self) -> u64 {
+        self.generation += 1;
+        self.generation
+    }
+
+    /// Activate if no enter or leave happened since `token` was issued
+    fn activate(&mut self, token: u64) -> bool {
+        if token != self.generation || self.active {
+            return false;
+        }
+        self.active = true;
+        true
+    }
+
+    /// Register a pointer leaving, cancelling any pending activation
+    fn leave(&mut self) {
+        self.generation += 1;
+        self.active = false;
+    }
+}
+
+/// Handle to a hover intent created with [`use_hover_intent`]
+#[derive(Clone, Copy)]
+pub struct HoverIntent {
+    delay: Duration,
+    state: Signal<HoverState>,
+}
+
+impl HoverIntent {
+    /// Whether the pointer has lingered long enough to count as hovering
+    ///
+    /// Reading it subscribes the caller to changes.
+    pub fn is_active(&self) -> bool {
+        self.state.read().active
+    }
+
+    /// The delay the pointer must stay before the hover activates
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Call from `onmouseenter`
+    pub fn enter(&mut self) {
+        let token = self.state.write().enter();
+        if self.delay.is_zero() {
+            self.state.write().activate(token);
+            return;
+        }
+
+        let mut state = self.state;
+        let delay = self.delay;
+        spawn(async move {
+            MotionTime::delay(delay).await;
+            // Only write when activating, so stale timers don't notify subscribers
+            let pending = {
+                let current = *state.peek();
+                current.generation == token && !current.active
+            };
+            if pending {
+                state.write().activate(token);
+            }
+        });
+    }
+
+    /// Call from `onmouseleave`
+    pub fn leave(&mut self) {
+        self.state.write().leave();
+    }
+}
+
+/// Create a hover intent that activates after the pointer stays for `delay`
+pub fn use_hover_intent(delay: Duration) -> HoverIntent {
+    let state = use_signal(HoverState::default);
+    HoverIntent { delay, state }
+}
+
+/// Animate `motion` between `rest` and `hovered` as a hover intent activates
+/// and ends
+///
+/// A resting element doesn't animate on mount. A render passing other values
+/// animates to the new one for the current state, and the latest `config` is
+/// used from then on. Like other hooks, call it unconditionally from the
+/// component body.
+pub fn use_hover_motion<T: Animatable + PartialEq>(
+    mut motion: MotionValue<T>,
+    intent: HoverIntent,
+    rest: T,
+    hovered: T,
+    config: AnimationConfig,
+) {
+    let last = use_hook(|| Rc::new(Cell::new(None)));
+
+    use_effect(use_reactive(
+        (&rest, &hovered, &config),
+        move |(rest, hovered, config)| {
+            let target = if intent.is_active() { hovered } else { rest };
+            // The first run finds the element resting there already
+            if last
+                .replace(Some(target))
+                .is_some_and(|last| last != target)
+            {
+                motion.animate_to_with_config(target, config);
+            }
+        },
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lingering_activates() {
+        let mut state = HoverState::default();
+        let token = state.enter();
+        assert!(state.activate(token));
+        assert!(state.active);
+    }
+
+    #[test]
+    fn test_quick_exit_cancels_pending_activation() {
+        let mut state = HoverState::default();
+        let token = state.enter();
+        state.leave();
+        assert!(!state.activate(token));
+        assert!(!state.active);
+    }
+
+    #[test]
+    fn test_only_latest_enter_activates() {
+        let mut state = HoverState::default();
+        let first = state.enter();
+        state.leave();
+        let second = state.enter();
+
+        assert!(!state.activate(first));
+        assert!(state.activate(second));
+        // A duplicate timer for the same enter doesn't re-trigger
+        assert!(!state.activate(second));
+    }
+
+    type Hovered = (HoverIntent, MotionValue<f32>, Signal<u32>);
+
+    #[test]
+    fn test_hover_returns_to_rest_after_a_re_render() {
+        use crate::animation::AnimationMode;
+        use crate::animations::tween::Tween;
+
+        thread_local! {
+            static STATE: Cell<Option<Hovered>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let renders = use_signal(|| 0u32);
+            let intent = use_hover_intent(Duration::from_millis(100));
+            let scale = crate::use_motion(1.0f32);
+            use_hover_motion(
+                scale,
+                intent,
+                1.0,
+                1.1,
+                AnimationConfig::new(AnimationMode::Tween(Tween::default())),
+            );
+            STATE.set(Some((intent, scale, renders)));
+            rsx! { "{renders}" }
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some((intent, scale, mut renders)) = STATE.get() else {
+            unreachable!("the app stores its state");
+        };
+        let mut state = intent.state;
+        let settle = |dom: &mut VirtualDom| {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        };
+        let target = |dom: &VirtualDom| dom.in_runtime(|| scale.engine.peek().target());
+        settle(&mut dom);
+        assert_eq!(target(&dom), None);
+
+        dom.in_runtime(|| state.write().active = true);
+        settle(&mut dom);
+        assert_eq!(target(&dom), Some(1.1));
+
+        dom.in_runtime(|| renders += 1);
+        settle(&mut dom);
+        dom.in_runtime(|| state.write().active = false);
+        settle(&mut dom);
+        assert_eq!(target(&dom), Some(1.0));
+    }
+
+    type Lifted = (HoverIntent, MotionValue<f32>, Signal<f32>);
+
+    #[test]
+    fn test_hover_follows_values_of_later_renders() {
+        use crate::animation::AnimationMode;
+        use crate::animations::tween::Tween;
+
+        thread_local! {
+            static STATE: Cell<Option<Lifted>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let lift = use_signal(|| -8.0f32);
+            let intent = use_hover_intent(Duration::from_millis(100));
+            let y = crate::use_motion(0.0f32);
+            use_hover_motion(
+                y,
+                intent,
+                0.0,
+                lift(),
+                AnimationConfig::new(AnimationMode::Tween(Tween::default())),
+            );
+            STATE.set(Some((intent, y, lift)));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some((intent, y, mut lift)) = STATE.get() else {
+            unreachable!("the app stores its state");
+        };
+        let mut state = intent.state;
+        let settle = |dom: &mut VirtualDom| {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        };
+        let target = |dom: &VirtualDom| dom.in_runtime(|| y.engine.peek().target());
+        settle(&mut dom);
+
+        dom.in_runtime(|| state.write().active = true);
+        settle(&mut dom);
+        assert_eq!(target(&dom), Some(-8.0));
+
+        // A new lift passed while hovering is animated to as well
+        dom.in_runtime(|| lift.set(-12.0));
+        // One pass re-renders with the new value, the next runs the effect
+        settle(&mut dom);
+        settle(&mut dom);
+        assert_eq!(target(&dom), Some(-12.0));
+
+        dom.in_runtime(|| state.write().active = false);
+        settle(&mut dom);
+        assert_eq!(target(&dom), Some(0.0));
+    }
+}
@@ -0,0 +1,198 @@
+//! Completion barrier over several motion values
+//!
+//! Runs a callback once every listed motion value has come to rest, e.g. to
+//! enable interaction after all cards of a layout animation have settled,
+//! without counting completions by hand.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Deck() -> Element {
+//!     let cards: Vec<_> = (0..5).map(|_| use_motion(0.0f32)).collect();
+//!     // Natively, completion callbacks must be `Send`, hence a sync signal
+//!     let mut ready = use_signal_sync(|| false);
+//!
+//!     use_effect(move || {
+//!         for (i, mut card) in cards.iter().copied().enumerate() {
+//!             card.animate_to(i as f32 * 40.0);
+//!         }
+//!         let cards: Vec<_> = cards.iter().collect();
+//!         join_complete(&cards, move || ready.set(true));
+//!     });
+//!
+//!     rsx! { /* ... */ }
+//! }
+//! ```
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dioxus::signals::Writable;
+
+use crate::animation::{MaybeSend, SharedCallback};
+use crate::{Animatable, AnimationEngine, MotionValue};
+
+/// Counts down arrivals and runs the callback on the last one
+#[derive(Clone)]
+struct Barrier {
+    remaining: Arc<AtomicUsize>,
+    callback: SharedCallback,
+}
+
+impl Barrier {
+    fn new<F: FnOnce() + MaybeSend + 'static>(count: usize, callback: F) -> Self {
+        Self {
+            remaining: Arc::new(AtomicUsize::new(count)),
+            callback: SharedCallback::new(callback),
+        }
+    }
+
+    /// Register one arrival, running the callback if it was the last
+    fn arrive(&self) {
+        if self.remaining.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return;
+        }
+        self.callback.fire();
+    }
+}
+
+/// Make `engine` arrive at `barrier` once its current run completes
+fn join_engine<T: Animatable>(engine: &mut AnimationEngine<T>, barrier: &Barrier) {
+    if engine.is_active() {
+        let barrier = barrier.clone();
+        engine.add_completion_callback(move || barrier.arrive());
+    } else {
+        barrier.arrive();
+    }
+}
+
+/// Run `callback` once, after every value in `values` has come to rest
+///
+/// Values that are already at rest count as settled. A value retargeted in
+/// the meantime is waited for until its new animation settles; one that is
+/// stopped or set before settling counts once its next animation completes.
+/// With no values, `callback` runs immediately.
+pub fn join_complete<T: Animatable, F: FnOnce() + MaybeSend + 'static>(
+    values: &[&MotionValue<T>],
+    callback: F,
+) {
+    let barrier = Barrier::new(values.len() + 1, callback);
+    for motion in values {
+        let mut engine = motion.engine;
+        join_engine(&mut engine.write(), &barrier);
+    }
+    // Released last, so values at rest can't fire the callback before all joined
+    barrier.arrive();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use dioxus::prelude::*;
+    use instant::Duration;
+
+    use super::*;
+
+    thread_local! {
+        static CARDS: RefCell<Vec<MotionValue<f32>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn deck() -> Element {
+        let cards: Vec<_> = (0..3).map(|_| crate::use_motion(0.0f32)).collect();
+        CARDS.set(cards);
+        rsx! {}
+    }
+
+    /// Mount three motion values at rest
+    fn mounted() -> (VirtualDom, Vec<MotionValue<f32>>) {
+        let mut dom = VirtualDom::new(deck);
+        dom.rebuild_in_place();
+        (dom, CARDS.take())
+    }
+
+    fn frame(dom: &mut VirtualDom) {
+        dom.in_runtime(|| crate::driver::step(1.0 / 60.0));
+    }
+
+    /// Join `cards`, counting how often the callback runs
+    fn join(dom: &VirtualDom, cards: &[MotionValue<f32>]) -> Arc<AtomicUsize> {
+        let fired = Arc::new(AtomicUsize::new(0));
+        let counter = fired.clone();
+        dom.in_runtime(|| {
+            let cards: Vec<_> = cards.iter().collect();
+            join_complete(&cards, move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        });
+        fired
+    }
+
+    fn tween_to(dom: &VirtualDom, card: MotionValue<f32>, target: f32, millis: u64) {
+        dom.in_runtime(|| {
+            card.tween()
+                .duration(Duration::from_millis(millis))
+                .animate_to(target);
+        });
+    }
+
+    #[test]
+    fn test_join_fires_once_after_last_value_rests() {
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let (mut dom, cards) = mounted();
+        for (i, card) in cards.iter().enumerate() {
+            tween_to(&dom, *card, 1.0, 100 * (i as u64 + 1));
+        }
+        let fired = join(&dom, &cards);
+
+        while dom.in_runtime(|| cards[2].is_animating()) {
+            assert_eq!(fired.load(Ordering::SeqCst), 0);
+            frame(&mut dom);
+        }
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+        // Later runs don't fire it again
+        tween_to(&dom, cards[0], 0.0, 50);
+        for _ in 0..10 {
+            frame(&mut dom);
+        }
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_join_waits_for_retargeted_value() {
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let (mut dom, cards) = mounted();
+        tween_to(&dom, cards[0], 1.0, 50);
+        tween_to(&dom, cards[1], 1.0, 50);
+        let fired = join(&dom, &cards[..2]);
+
+        frame(&mut dom);
+        tween_to(&dom, cards[1], 2.0, 200);
+        for _ in 0..6 {
+            frame(&mut dom);
+        }
+        assert!(!dom.in_runtime(|| cards[0].is_animating()));
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+        while dom.in_runtime(|| cards[1].is_animating()) {
+            frame(&mut dom);
+        }
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_join_of_values_at_rest_fires_immediately() {
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let (dom, cards) = mounted();
+        assert_eq!(join(&dom, &cards).load(Ordering::SeqCst), 1);
+        assert_eq!(join(&dom, &[]).load(Ordering::SeqCst), 1);
+    }
+}
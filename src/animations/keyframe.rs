@@ -8,15 +8,92 @@ use easer::functions::{Easing, Linear};
 use instant::Duration;
 use ordered_float::OrderedFloat;
 use std::collections::BTreeMap;
-use std::sync::{Arc, Mutex};
+use std::fmt;
+use std::ops::Bound;
+use tracing::warn;
 
-use crate::animation::{Animation, AnimationState, AnimationTiming, LoopMode, PlaybackDirection};
-use crate::{Animatable, MotionValue};
+use crate::animation::{
+    Animation, AnimationState, AnimationTiming, ConfigError, LoopMode, MaybeSend, PlaybackDirection,
+};
+use crate::animations::easing::CubicBezier;
+use crate::animations::spring::Spring;
+use crate::properties::color::{ColorMix, ColorSpace, HueDirection};
+use crate::{Animatable, Color, MotionValue};
 
 /// Type alias for easing functions from the easer package
 // In timing.rs
 pub type EasingFunction = fn(f32, f32, f32, f32) -> f32;
 
+/// How a keyframe segment gets from its start value to its end value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InterpMode {
+    /// A straight line between the two values
+    #[default]
+    Linear,
+    /// Hold the start value until the segment ends, then jump
+    Step,
+    /// A Catmull-Rom curve through the neighbouring keyframes as well, so
+    /// consecutive spline segments join without a kink
+    ///
+    /// Relies on the value's arithmetic, so it suits plain numbers and
+    /// transforms better than colors, whose components are clamped.
+    Spline,
+}
+
+/// A timing curve with parameters of its own, for a keyframe segment
+///
+/// Used in place of the segment's easing function, which can't carry any.
+#[derive(Debug, Clone)]
+pub enum SegmentCurve {
+    /// A CSS-style `cubic-bezier()` curve
+    ///
+    /// Like a spring, a curve overshooting past either value carries a
+    /// straight segment past it too, through the value's arithmetic.
+    CubicBezier(CubicBezier),
+    /// Spring dynamics from the start value to the end value
+    ///
+    /// The spring starts at rest and its motion is stretched or squeezed to
+    /// settle as the segment ends, so its damping ratio shapes the segment:
+    /// the lower, the more it overshoots and bounces around the end value.
+    /// An undamped spring plays in real time instead and jumps to the end
+    /// value when the segment is over.
+    Spring(Spring),
+}
+
+impl SegmentCurve {
+    /// Progress through a segment lasting `seconds`, `position` of the way
+    /// through it
+    fn progress(&self, position: f32, seconds: f32) -> f32 {
+        if position >= 1.0 {
+            return 1.0;
+        }
+        match self {
+            Self::CubicBezier(curve) => curve.ease(position),
+            Self::Spring(spring) => {
+                let span = spring
+                    .settle_time(1.0, 0.0, 1e-3)
+                    .map_or(seconds, |settle| settle.as_secs_f32());
+                spring.evaluate(0.0, 1.0, 0.0, position * span).0
+            }
+        }
+    }
+
+    /// Check the curve's parameters
+    fn validate(&self) -> Result<(), ConfigError> {
+        match self {
+            Self::CubicBezier(curve) => {
+                let (x1, y1, x2, y2) = curve.points();
+                let points = [("x1", x1), ("y1", y1), ("x2", x2), ("y2", y2)];
+                match points.into_iter().find(|(_, value)| !value.is_finite()) {
+                    Some((parameter, value)) => Err(ConfigError::NonFinite { parameter, value }),
+                    None => Ok(()),
+                }
+            }
+            Self::Spring(spring) => spring.validate(),
+        }
+    }
+}
+
 /// A keyframe with value and optional easing function
 #[derive(Clone)]
 pub struct Keyframe<T: Animatable> {
@@ -24,8 +101,24 @@ pub struct Keyframe<T: Animatable> {
     pub value: T,
     /// Optional easing function for interpolation from this keyframe to the next
     pub easing: Option<EasingFunction>,
+    /// Optional interpolation from this keyframe to the next
+    pub interp: Option<InterpMode>,
+    /// Optional timing curve from this keyframe to the next, used instead of
+    /// any easing
+    pub curve: Option<SegmentCurve>,
+}
+
+/// Easing declared for every segment lying within a position range
+#[derive(Clone, Copy)]
+struct EasedRange {
+    from: f32,
+    to: f32,
+    easing: EasingFunction,
 }
 
+/// Interpolates a segment in a color mix, for value types that support one
+type Mixer<T> = fn(&T, &T, f32, ColorMix) -> T;
+
 type KeyframeRef<'a, T> = (&'a OrderedFloat<f32>, &'a Keyframe<T>);
 type KeyframePair<'a, T> = (Option<KeyframeRef<'a, T>>, Option<KeyframeRef<'a, T>>);
 
@@ -35,6 +128,8 @@ impl<T: Animatable> Keyframe<T> {
         Self {
             value,
             easing: None,
+            interp: None,
+            curve: None,
         }
     }
 
@@ -43,6 +138,8 @@ impl<T: Animatable> Keyframe<T> {
         Self {
             value,
             easing: Some(easing),
+            interp: None,
+            curve: None,
         }
     }
 }
@@ -67,6 +164,18 @@ pub struct KeyframeAnimation<T: Animatable> {
     prev_value: T,
     /// Whether the animation is active
     is_active: bool,
+    /// Easing declared per span via `ease_between`, resolved on start
+    eased_ranges: Vec<EasedRange>,
+    /// Easing for segments without one of their own
+    default_easing: Option<EasingFunction>,
+    /// Interpolation for segments without one of their own
+    default_interp: InterpMode,
+    /// First keyframe position given outside 0.0..=1.0, reported by `validate`
+    out_of_range: Option<f32>,
+    /// Color space and hue direction of color keyframes
+    color_mix: ColorMix,
+    /// Interpolation honoring `color_mix`; plain interpolation when unset
+    mixer: Option<Mixer<T>>,
 }
 
 impl<T: Animatable> Default for KeyframeAnimation<T> {
@@ -81,6 +190,12 @@ impl<T: Animatable> Default for KeyframeAnimation<T> {
             prev_time: Duration::ZERO,
             prev_value: T::zero(),
             is_active: false,
+            eased_ranges: Vec::new(),
+            default_easing: None,
+            default_interp: InterpMode::Linear,
+            out_of_range: None,
+            color_mix: ColorMix::default(),
+            mixer: None,
         }
     }
 }
@@ -93,11 +208,22 @@ impl<T: Animatable> KeyframeAnimation<T> {
 
     /// Add a keyframe at position (0.0 to 1.0)
     pub fn at(mut self, position: f32, value: T) -> Self {
-        self.keyframes
-            .insert(OrderedFloat(position.clamp(0.0, 1.0)), Keyframe::new(value));
+        self.insert(position, Keyframe::new(value));
         self
     }
 
+    /// Insert `keyframe`, clamping its position into 0.0..=1.0
+    ///
+    /// The first position that needed clamping is kept for
+    /// [`KeyframeAnimation::validate`].
+    fn insert(&mut self, position: f32, keyframe: Keyframe<T>) {
+        if !(0.0..=1.0).contains(&position) && !position.is_nan() {
+            self.out_of_range.get_or_insert(position);
+        }
+        self.keyframes
+            .insert(OrderedFloat(position.clamp(0.0, 1.0)), keyframe);
+    }
+
     pub fn duration(mut self, duration: Duration) -> Self {
         self.duration = duration;
         self
@@ -105,10 +231,86 @@ impl<T: Animatable> KeyframeAnimation<T> {
 
     /// Add a keyframe with easing
     pub fn at_with_easing(mut self, position: f32, value: T, easing: EasingFunction) -> Self {
-        self.keyframes.insert(
-            OrderedFloat(position.clamp(0.0, 1.0)),
-            Keyframe::with_easing(value, easing),
-        );
+        self.insert(position, Keyframe::with_easing(value, easing));
+        self
+    }
+
+    /// Add a keyframe whose segment to the next keyframe follows the CSS curve
+    /// `cubic-bezier(x1, y1, x2, y2)`
+    ///
+    /// Unlike an easing function, the curve's control points can come from
+    /// anywhere, e.g. a design tool. See [`CubicBezier`].
+    pub fn at_with_bezier(
+        mut self,
+        position: f32,
+        value: T,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+    ) -> Self {
+        let mut keyframe = Keyframe::new(value);
+        keyframe.curve = Some(SegmentCurve::CubicBezier(CubicBezier::new(x1, y1, x2, y2)));
+        self.insert(position, keyframe);
+        self
+    }
+
+    /// Add a keyframe whose segment to the next keyframe springs there,
+    /// bouncing around the next value as `spring` is damped
+    ///
+    /// See [`SegmentCurve::Spring`] for how the spring fits the segment.
+    pub fn at_with_spring(mut self, position: f32, value: T, spring: Spring) -> Self {
+        let mut keyframe = Keyframe::new(value);
+        keyframe.curve = Some(SegmentCurve::Spring(spring));
+        self.insert(position, keyframe);
+        self
+    }
+
+    /// Add a keyframe whose segment to the next keyframe uses `mode`
+    ///
+    /// Lets one track mix stepped holds, straight moves and smooth arcs.
+    pub fn at_with_interp(mut self, position: f32, value: T, mode: InterpMode) -> Self {
+        let mut keyframe = Keyframe::new(value);
+        keyframe.interp = Some(mode);
+        self.insert(position, keyframe);
+        self
+    }
+
+    /// Ease every segment lying within `from..=to`
+    ///
+    /// An alternative to [`KeyframeAnimation::at_with_easing`], which attaches the
+    /// easing to the keyframe a segment starts from. Here the easing is declared for
+    /// the span itself: `ease_between(0.3, 0.7, Bounce::ease_out)` eases the segment
+    /// from the keyframe at 0.3 to the one at 0.7, or every segment fully inside the
+    /// range if more keyframes sit between them. Ranges override per-keyframe easing
+    /// and are resolved when the animation starts; [`KeyframeAnimation::validate`]
+    /// reports overlapping ranges and ranges that contain no segment.
+    pub fn ease_between(mut self, from: f32, to: f32, easing: EasingFunction) -> Self {
+        let (from, to) = (from.clamp(0.0, 1.0), to.clamp(0.0, 1.0));
+        self.eased_ranges.push(EasedRange {
+            from: from.min(to),
+            to: from.max(to),
+            easing,
+        });
+        self
+    }
+
+    /// Ease every segment that has no easing of its own
+    ///
+    /// Easing from [`KeyframeAnimation::at_with_easing`] or
+    /// [`KeyframeAnimation::ease_between`] still takes precedence; without any,
+    /// segments are linear.
+    pub fn default_easing(mut self, easing: EasingFunction) -> Self {
+        self.default_easing = Some(easing);
+        self
+    }
+
+    /// Interpolate every segment that has no mode of its own with `mode`
+    ///
+    /// E.g. `default_interp(InterpMode::Spline)` smooths the whole track, while
+    /// [`KeyframeAnimation::at_with_interp`] still picks per segment.
+    pub fn default_interp(mut self, mode: InterpMode) -> Self {
+        self.default_interp = mode;
         self
     }
 
@@ -142,21 +344,214 @@ impl<T: Animatable> KeyframeAnimation<T> {
     }
 
     /// Set completion callback
-    pub fn on_complete<F>(mut self, f: F) -> Self
-    where
-        F: FnMut() + Send + 'static,
-    {
-        self.timing.on_complete = Some(Arc::new(Mutex::new(f)));
+    pub fn on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.timing = self.timing.with_on_complete(callback);
         self
     }
 
+    /// Check that the keyframe track is playable
+    ///
+    /// Positions are kept sorted by the track itself, so this checks for an
+    /// empty track, positions that are NaN or were clamped into 0.0..=1.0, a
+    /// zero duration, the parameters of segment curves and the `ease_between`
+    /// ranges.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.keyframes.is_empty() {
+            return Err(ConfigError::EmptyKeyframes);
+        }
+        if let Some(position) = self.keyframes.keys().find(|pos| pos.is_nan()) {
+            return Err(ConfigError::InvalidKeyframePosition {
+                position: position.0,
+            });
+        }
+        if let Some(position) = self.out_of_range {
+            return Err(ConfigError::InvalidKeyframePosition { position });
+        }
+        if self.duration.is_zero() {
+            return Err(ConfigError::ZeroDuration {
+                parameter: "duration",
+            });
+        }
+        for curve in self.keyframes.values().filter_map(|kf| kf.curve.as_ref()) {
+            curve.validate()?;
+        }
+        self.resolve_eased_ranges().map(|_| ())
+    }
+
+    /// Map each `ease_between` range onto the keyframes starting the segments it covers
+    fn resolve_eased_ranges(
+        &self,
+    ) -> Result<Vec<(OrderedFloat<f32>, EasingFunction)>, ConfigError> {
+        let mut ranges = self.eased_ranges.clone();
+        ranges.sort_by(|a, b| a.from.total_cmp(&b.from));
+        for pair in ranges.windows(2) {
+            if pair[1].from < pair[0].to {
+                return Err(ConfigError::OverlappingEasingRanges {
+                    first: (pair[0].from, pair[0].to),
+                    second: (pair[1].from, pair[1].to),
+                });
+            }
+        }
+
+        let positions: Vec<_> = self.keyframes.keys().copied().collect();
+        let mut resolved = Vec::new();
+        for range in &ranges {
+            let start = resolved.len();
+            for segment in positions.windows(2) {
+                if *segment[0] >= range.from - f32::EPSILON
+                    && *segment[1] <= range.to + f32::EPSILON
+                {
+                    resolved.push((segment[0], range.easing));
+                }
+            }
+            if resolved.len() == start {
+                return Err(ConfigError::EasingRangeMatchesNoSegment {
+                    range: (range.from, range.to),
+                });
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Apply `ease_between` ranges to the keyframes, skipping them if they are invalid
+    pub(crate) fn apply_eased_ranges(&mut self) {
+        if self.eased_ranges.is_empty() {
+            return;
+        }
+        match self.resolve_eased_ranges() {
+            Ok(resolved) => {
+                for (position, easing) in resolved {
+                    if let Some(keyframe) = self.keyframes.get_mut(&position) {
+                        keyframe.easing = Some(easing);
+                    }
+                }
+            }
+            Err(error) => warn!("Ignoring keyframe easing ranges: {}", error),
+        }
+    }
+
     /// Start the animation
-    pub fn start(mut self, motion: &mut MotionValue<T>) -> MotionValue<T> {
-        self.is_active = true;
+    ///
+    /// Malformed tracks are played as well as they can be: positions are
+    /// clamped, invalid easing ranges skipped and an empty track leaves the
+    /// value where it is. Use [`KeyframeAnimation::try_start`] to be told instead.
+    pub fn start(self, motion: &mut MotionValue<T>) -> MotionValue<T> {
         motion.engine.write().apply_keyframes(self);
+        motion.complete_without_frames();
+        motion.run_ready_callbacks();
         *motion
     }
 
+    /// Resolve the `ease_between` ranges and make the track ready to play
+    pub(crate) fn prepared(mut self) -> Self {
+        self.apply_eased_ranges();
+        self.is_active = true;
+        self
+    }
+
+    /// Start the animation if the track is valid
+    ///
+    /// Returns the [`KeyframeAnimation::validate`] error and leaves `motion`
+    /// untouched when the track is empty or malformed.
+    pub fn try_start(self, motion: &mut MotionValue<T>) -> Result<MotionValue<T>, ConfigError> {
+        self.validate()?;
+        Ok(self.start(motion))
+    }
+
+    /// Evaluate the track at `position` (0.0 to 1.0) without advancing time
+    ///
+    /// Used to seek or scrub the animation, e.g. from scroll progress. Loop and
+    /// direction settings are ignored; positions outside the keyframes hold the
+    /// nearest keyframe value.
+    pub fn sample(&self, position: f32) -> T {
+        let position = position.clamp(0.0, 1.0);
+
+        // Find surrounding keyframes
+        match self.find_surrounding_keyframes(position) {
+            (Some((prev_pos, prev_kf)), Some((next_pos, next_kf))) => {
+                let segment_length = next_pos - prev_pos;
+                let segment_position = if segment_length > ordered_float::OrderedFloat(0.0) {
+                    (position - **prev_pos) / *segment_length
+                } else {
+                    0.0
+                };
+
+                // Apply the curve or easing if specified
+                let eased_position = prev_kf.curve.as_ref().map_or_else(
+                    || {
+                        prev_kf.easing.or(self.default_easing).map_or_else(
+                            || Linear::ease_in_out(segment_position, 0.0, 1.0, 1.0),
+                            |easing| easing(segment_position, 0.0, 1.0, 1.0),
+                        )
+                    },
+                    |curve| {
+                        let seconds = *segment_length * self.duration.as_secs_f32();
+                        curve.progress(segment_position, seconds)
+                    },
+                );
+
+                // Curves may overshoot, which interpolation would clamp away
+                let overshoots = !(0.0..=1.0).contains(&eased_position);
+                match prev_kf.interp.unwrap_or(self.default_interp) {
+                    InterpMode::Linear if prev_kf.curve.is_some() && overshoots => prev_kf
+                        .value
+                        .add(&next_kf.value.sub(&prev_kf.value).scale(eased_position)),
+                    InterpMode::Linear => self.mixer.map_or_else(
+                        || prev_kf.value.interpolate(&next_kf.value, eased_position),
+                        |mix| {
+                            mix(
+                                &prev_kf.value,
+                                &next_kf.value,
+                                eased_position,
+                                self.color_mix,
+                            )
+                        },
+                    ),
+                    InterpMode::Step => prev_kf.value,
+                    InterpMode::Spline => self.spline(*prev_pos, *next_pos, eased_position),
+                }
+            }
+            // We're past the last keyframe or before the first one
+            (Some((_, kf)), None) | (None, Some((_, kf))) => kf.value,
+            (None, None) => self.current,
+        }
+    }
+
+    /// Point at `t` on the Catmull-Rom curve of the segment from `start` to `end`
+    ///
+    /// The curve's tangents come from the keyframes before `start` and after
+    /// `end`, scaled by how far apart they are, so the value keeps its speed
+    /// through a keyframe between segments of different lengths. At either end
+    /// of the track the segment's own end value stands in, one segment away.
+    fn spline(&self, start: OrderedFloat<f32>, end: OrderedFloat<f32>, t: f32) -> T {
+        let value_at = |position| self.keyframes.get(&position).map(|kf| kf.value);
+        let (Some(p1), Some(p2)) = (value_at(start), value_at(end)) else {
+            return self.current;
+        };
+        let span = end.0 - start.0;
+        let (t0, p0) = self
+            .keyframes
+            .range(..start)
+            .next_back()
+            .map_or((start.0 - span, p1), |(position, kf)| {
+                (position.0, kf.value)
+            });
+        let (t3, p3) = self
+            .keyframes
+            .range((Bound::Excluded(end), Bound::Unbounded))
+            .next()
+            .map_or((end.0 + span, p2), |(position, kf)| (position.0, kf.value));
+
+        // Cubic Hermite basis with Catmull-Rom tangents, in units of this segment
+        let m1 = p2.sub(&p0).scale(span / (end.0 - t0));
+        let m2 = p3.sub(&p1).scale(span / (t3 - start.0));
+        let (t2, t3) = (t * t, t * t * t);
+        p1.scale(2.0 * t3 - 3.0 * t2 + 1.0)
+            .add(&m1.scale(t3 - 2.0 * t2 + t))
+            .add(&p2.scale(-2.0 * t3 + 3.0 * t2))
+            .add(&m2.scale(t3 - t2))
+    }
+
     /// Find the surrounding keyframes for a given position
     fn find_surrounding_keyframes(&self, position: f32) -> KeyframePair<T> {
         let mut prev = None;
@@ -177,6 +572,153 @@ impl<T: Animatable> KeyframeAnimation<T> {
     }
 }
 
+impl KeyframeAnimation<Color> {
+    /// Set the color space every segment is interpolated in
+    ///
+    /// Mixing in [`ColorSpace::Hsl`] or [`ColorSpace::Oklch`] keeps a palette
+    /// such as blue → purple → pink saturated instead of passing through the
+    /// muddy RGB midpoints.
+    pub fn color_space(mut self, space: ColorSpace) -> Self {
+        self.color_mix.space = space;
+        self.mixer = Some(Color::mix);
+        self
+    }
+
+    /// Set which way around the hue wheel every segment travels
+    ///
+    /// Only applies in the polar color spaces, see [`KeyframeAnimation::color_space`].
+    pub fn hue_direction(mut self, direction: HueDirection) -> Self {
+        self.color_mix.hue = direction;
+        self.mixer = Some(Color::mix);
+        self
+    }
+}
+
+/// A keyframe track for any type of value, its keyframes given as progress
+/// from where the animation starts to its target
+///
+/// What [`AnimationMode::Keyframes`] plays: a config doesn't know the type of
+/// the value it will animate, so each keyframe is the share of the way from
+/// the start value to the target, 0.0 being the start, 1.0 the target and
+/// 1.2 a fifth of the distance past it. Without keyframes at positions 0.0 and
+/// 1.0, the track starts at the start value and ends on the target.
+///
+/// # Example
+/// ```
+/// use dioxus_motion2::prelude::*;
+///
+/// // Overshoot, then settle back onto the target
+/// let track = KeyframeTrack::new(Duration::from_millis(400)).at(0.6, 1.15);
+/// let config = AnimationConfig::new(AnimationMode::Keyframes(track.clone()));
+/// assert_eq!(config.validate(), Ok(()));
+/// assert_eq!(track.create_animation(0.0f32, 100.0).sample(0.6), 115.0);
+/// ```
+///
+/// [`AnimationMode::Keyframes`]: crate::animation::AnimationMode::Keyframes
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyframeTrack {
+    /// Length of one pass
+    duration: Duration,
+    /// Keyframes in the order they were added
+    stops: Vec<TrackStop>,
+}
+
+/// A keyframe of a [`KeyframeTrack`]
+#[derive(Debug, Clone, Copy)]
+struct TrackStop {
+    position: f32,
+    /// Share of the way from the start value to the target
+    progress: f32,
+    easing: Option<EasingFunction>,
+}
+
+impl PartialEq for TrackStop {
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position
+            && self.progress == other.progress
+            && match (self.easing, other.easing) {
+                (Some(a), Some(b)) => std::ptr::fn_addr_eq(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+    }
+}
+
+impl fmt::Display for KeyframeTrack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = self.stops.len();
+        let plural = if count == 1 { "" } else { "s" };
+        write!(f, "Keyframes({:?}, {count} stop{plural})", self.duration)
+    }
+}
+
+impl KeyframeTrack {
+    /// Create an empty track lasting `duration`, going straight to the target
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            stops: Vec::new(),
+        }
+    }
+
+    /// Add a keyframe `progress` of the way to the target at `position` (0.0 to 1.0)
+    pub fn at(mut self, position: f32, progress: f32) -> Self {
+        self.stops.push(TrackStop {
+            position,
+            progress,
+            easing: None,
+        });
+        self
+    }
+
+    /// Add a keyframe whose segment to the next keyframe uses `easing`
+    pub fn at_with_easing(mut self, position: f32, progress: f32, easing: EasingFunction) -> Self {
+        self.stops.push(TrackStop {
+            position,
+            progress,
+            easing: Some(easing),
+        });
+        self
+    }
+
+    /// Length of one pass
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Check that the track is playable
+    ///
+    /// Reports progress that isn't finite, and whatever
+    /// [`KeyframeAnimation::validate`] reports for the track.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(stop) = self.stops.iter().find(|stop| !stop.progress.is_finite()) {
+            return Err(ConfigError::NonFinite {
+                parameter: "progress",
+                value: stop.progress,
+            });
+        }
+        self.create_animation(0.0f32, 1.0).validate()
+    }
+
+    /// Create the keyframe animation playing this track from `initial` to `target`
+    pub fn create_animation<T: Animatable>(&self, initial: T, target: T) -> KeyframeAnimation<T> {
+        let distance = target.sub(&initial);
+        let value = |progress: f32| initial.add(&distance.scale(progress));
+        let mut animation = KeyframeAnimation::new()
+            .duration(self.duration)
+            .at(0.0, initial)
+            .at(1.0, target);
+        for stop in &self.stops {
+            animation = match stop.easing {
+                Some(easing) => {
+                    animation.at_with_easing(stop.position, value(stop.progress), easing)
+                }
+                None => animation.at(stop.position, value(stop.progress)),
+            };
+        }
+        animation.prepared()
+    }
+}
+
 impl<T: Animatable> MotionValue<T> {
     /// Start a new keyframe animation
     pub fn keyframes(&self) -> KeyframeAnimation<T> {
@@ -206,6 +748,8 @@ impl<T: Animatable> Animation for KeyframeAnimation<T> {
         let is_completed = self.current_time >= self.duration;
 
         if is_completed {
+            // The pass that just ended, before completion counts it
+            let ended_reversed = self.timing.is_reverse();
             // Handle completion
             if self.timing.handle_loop_completion() {
                 // Reset for next loop
@@ -229,7 +773,7 @@ impl<T: Animatable> Animation for KeyframeAnimation<T> {
                 self.is_active = false;
 
                 // Set to final keyframe
-                if self.timing.is_reverse() {
+                if ended_reversed {
                     if let Some((_, keyframe)) = self.keyframes.iter().next() {
                         self.current = keyframe.value;
                     }
@@ -250,37 +794,13 @@ impl<T: Animatable> Animation for KeyframeAnimation<T> {
             position = 1.0 - position;
         }
 
-        // Find surrounding keyframes
-        let (prev_keyframe, next_keyframe) = self.find_surrounding_keyframes(position);
-
-        // Interpolate between keyframes
-        if let (Some((prev_pos, prev_kf)), Some((next_pos, next_kf))) =
-            (prev_keyframe, next_keyframe)
-        {
-            let segment_length = next_pos - prev_pos;
-            let segment_position = if segment_length > ordered_float::OrderedFloat(0.0) {
-                (position - **prev_pos) / *segment_length
-            } else {
-                0.0
-            };
-
-            // Apply easing if specified
-            let eased_position = prev_kf.easing.map_or_else(
-                || Linear::ease_in_out(segment_position, 0.0, 1.0, 1.0),
-                |easing| easing(segment_position, 0.0, 1.0, 1.0),
-            );
-
-            // Interpolate value
-            self.current = prev_kf.value.interpolate(&next_kf.value, eased_position);
-        } else if let Some((_, kf)) = prev_keyframe {
-            // We're past the last keyframe or before the first one
-            self.current = kf.value;
-        }
+        // Interpolate between the surrounding keyframes
+        self.current = self.sample(position);
 
         // Calculate velocity
         let dt_duration = Duration::from_secs_f32(dt);
         if dt_duration > Duration::ZERO {
-            self.velocity = self.prev_value.sub(&self.current).scale(dt);
+            self.velocity = self.current.sub(&self.prev_value).scale(1.0 / dt);
         }
 
         (AnimationState::Active, self.current, self.velocity)
@@ -313,4 +833,301 @@ impl<T: Animatable> Animation for KeyframeAnimation<T> {
     fn is_active(&self) -> bool {
         self.is_active
     }
+
+    fn remaining_time(&self) -> Option<Duration> {
+        if !self.is_active {
+            return Some(Duration::ZERO);
+        }
+        let loops = self.timing.remaining_loops()?;
+        Some(
+            self.timing.remaining_delay()
+                + self.duration.saturating_sub(self.current_time)
+                + self.duration * loops,
+        )
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(self.duration * self.timing.total_loops()?)
+    }
+
+    fn finish_loop(&mut self) -> bool {
+        self.timing.finish_current_loop();
+        self.is_active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jump(_t: f32, b: f32, c: f32, _d: f32) -> f32 {
+        b + c
+    }
+
+    fn eased_at(animation: &KeyframeAnimation<f32>, position: f32) -> Option<f32> {
+        animation
+            .keyframes
+            .get(&OrderedFloat(position))
+            .and_then(|keyframe| keyframe.easing)
+            .map(|easing| easing(0.5, 0.0, 1.0, 1.0))
+    }
+
+    fn track() -> KeyframeAnimation<f32> {
+        KeyframeAnimation::new()
+            .at(0.0, 0.0)
+            .at(0.3, 10.0)
+            .at(0.5, 20.0)
+            .at(0.7, 30.0)
+            .at(1.0, 40.0)
+    }
+
+    #[test]
+    fn test_ease_between_covers_inner_segments() {
+        let mut animation = track().ease_between(0.3, 0.7, jump);
+        assert_eq!(animation.validate(), Ok(()));
+        animation.apply_eased_ranges();
+
+        assert_eq!(eased_at(&animation, 0.0), None);
+        assert_eq!(eased_at(&animation, 0.3), Some(1.0));
+        assert_eq!(eased_at(&animation, 0.5), Some(1.0));
+        assert_eq!(eased_at(&animation, 0.7), None);
+    }
+
+    #[test]
+    fn test_ease_between_rejects_overlaps() {
+        let animation = track()
+            .ease_between(0.5, 1.0, jump)
+            .ease_between(0.0, 0.7, jump);
+        assert_eq!(
+            animation.validate(),
+            Err(ConfigError::OverlappingEasingRanges {
+                first: (0.0, 0.7),
+                second: (0.5, 1.0),
+            })
+        );
+
+        // Ranges that only touch at a keyframe do not overlap
+        let animation = track()
+            .ease_between(0.0, 0.5, jump)
+            .ease_between(0.5, 1.0, jump);
+        assert_eq!(animation.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_ease_between_rejects_unaligned_ranges() {
+        let mut animation = track().ease_between(0.35, 0.65, jump);
+        assert_eq!(
+            animation.validate(),
+            Err(ConfigError::EasingRangeMatchesNoSegment {
+                range: (0.35, 0.65),
+            })
+        );
+
+        // Invalid ranges are skipped rather than partially applied
+        animation.apply_eased_ranges();
+        assert_eq!(eased_at(&animation, 0.3), None);
+        assert_eq!(eased_at(&animation, 0.5), None);
+    }
+
+    #[test]
+    fn test_validate_reports_malformed_tracks() {
+        assert_eq!(
+            KeyframeAnimation::<f32>::new().validate(),
+            Err(ConfigError::EmptyKeyframes)
+        );
+        assert!(matches!(
+            track().at(f32::NAN, 5.0).validate(),
+            Err(ConfigError::InvalidKeyframePosition { position }) if position.is_nan()
+        ));
+        // Out-of-range offsets are clamped for playback but still reported
+        let clamped = track()
+            .at(1.5, 50.0)
+            .at_with_interp(-0.2, 0.0, InterpMode::Step);
+        assert_eq!(
+            clamped.validate(),
+            Err(ConfigError::InvalidKeyframePosition { position: 1.5 })
+        );
+        assert_eq!(clamped.sample(1.0), 50.0);
+        assert_eq!(
+            track().duration(Duration::ZERO).validate(),
+            Err(ConfigError::ZeroDuration {
+                parameter: "duration"
+            })
+        );
+        assert_eq!(track().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_try_start_leaves_value_alone_on_error() {
+        use dioxus::prelude::*;
+
+        thread_local! {
+            static OUTCOMES: std::cell::RefCell<Vec<(bool, f32, bool)>> = const { std::cell::RefCell::new(Vec::new()) };
+        }
+
+        fn app() -> Element {
+            let engine = use_signal(|| crate::AnimationEngine::new(5.0f32));
+            use_hook(move || {
+                let mut motion = MotionValue::new(engine);
+                let record = move |started: Result<MotionValue<f32>, ConfigError>| {
+                    OUTCOMES.with_borrow_mut(|outcomes| {
+                        outcomes.push((started.is_ok(), motion.peek(), motion.is_animating()))
+                    });
+                };
+
+                record(KeyframeAnimation::new().try_start(&mut motion));
+                record(
+                    track()
+                        .ease_between(0.35, 0.65, jump)
+                        .try_start(&mut motion),
+                );
+                record(track().try_start(&mut motion));
+            });
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+
+        let outcomes = OUTCOMES.with_borrow(|outcomes| outcomes.clone());
+        assert_eq!(
+            outcomes,
+            vec![(false, 5.0, false), (false, 5.0, false), (true, 5.0, true)]
+        );
+    }
+
+    #[test]
+    fn test_default_easing_fills_unset_segments() {
+        let animation = track()
+            .at_with_easing(0.5, 20.0, |_, b, _, _| b)
+            .default_easing(jump);
+
+        // Segments without easing jump straight to their end value
+        assert_eq!(animation.sample(0.15), 10.0);
+        assert_eq!(animation.sample(0.4), 20.0);
+        assert_eq!(animation.sample(0.85), 40.0);
+        // An explicit easing still wins
+        assert_eq!(animation.sample(0.6), 20.0);
+
+        // Without a default, segments stay linear
+        assert_eq!(track().sample(0.15), 5.0);
+    }
+
+    #[test]
+    fn test_color_palette_follows_hue_direction() {
+        let palette = |direction| {
+            KeyframeAnimation::new()
+                .at(0.0, Color::blue())
+                .at(1.0, Color::red())
+                .color_space(ColorSpace::Hsl)
+                .hue_direction(direction)
+        };
+
+        assert_eq!(palette(HueDirection::Shorter).sample(0.5), Color::magenta());
+        assert_eq!(palette(HueDirection::Longer).sample(0.5), Color::green());
+        // Without a color space the segment mixes in RGB
+        let rgb = KeyframeAnimation::new()
+            .at(0.0, Color::blue())
+            .at(1.0, Color::red());
+        assert_eq!(rgb.sample(0.5), Color::new(0.5, 0.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn test_segments_mix_step_linear_and_spline() {
+        let animation = KeyframeAnimation::new()
+            .at_with_interp(0.0, 0.0f32, InterpMode::Step)
+            .at(0.3, 10.0)
+            .at_with_interp(0.6, 20.0, InterpMode::Spline)
+            .at(1.0, 40.0);
+
+        // Stepped hold, then a jump at the end of the segment
+        assert_eq!(animation.sample(0.15), 0.0);
+        assert_eq!(animation.sample(0.29), 0.0);
+        assert_eq!(animation.sample(0.3), 10.0);
+        // Straight move
+        assert!((animation.sample(0.45) - 15.0).abs() < 1e-4);
+        // Arc bending above the straight line, still meeting both ends
+        assert!((animation.sample(0.8) - 30.892_857).abs() < 1e-4);
+        assert_eq!(animation.sample(0.6), 20.0);
+        assert_eq!(animation.sample(1.0), 40.0);
+    }
+
+    #[test]
+    fn test_spline_keeps_its_speed_through_uneven_keyframes() {
+        let animation = KeyframeAnimation::new()
+            .at(0.0, 0.0f32)
+            .at(0.2, 10.0)
+            .at(1.0, 20.0)
+            .default_interp(InterpMode::Spline);
+
+        // Slopes just before and just after the keyframe at 0.2 agree
+        let step = 1e-3;
+        let at_key = animation.sample(0.2);
+        let before = (at_key - animation.sample(0.2 - step)) / step;
+        let after = (animation.sample(0.2 + step) - at_key) / step;
+        assert!(
+            (before - after).abs() < 1.0,
+            "{before} before, {after} after"
+        );
+        // Evenly spaced keyframes keep the uniform curve
+        let even = KeyframeAnimation::new()
+            .at(0.0, 0.0f32)
+            .at(0.5, 10.0)
+            .at(1.0, 40.0)
+            .default_interp(InterpMode::Spline);
+        assert!((even.sample(0.25) - 3.125).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_default_interp_fills_unset_segments() {
+        let animation = KeyframeAnimation::new()
+            .at(0.0, 0.0f32)
+            .at_with_interp(0.5, 10.0, InterpMode::Linear)
+            .at(1.0, 20.0)
+            .default_interp(InterpMode::Step);
+
+        assert_eq!(animation.sample(0.25), 0.0);
+        assert!((animation.sample(0.75) - 15.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_spring_segments_bounce_into_the_next_value() {
+        let bouncy = Spring {
+            stiffness: 200.0,
+            damping: 6.0,
+            ..Spring::default()
+        };
+        let animation = KeyframeAnimation::new()
+            .at_with_spring(0.0, 0.0f32, bouncy)
+            .at_with_bezier(0.5, 100.0, 0.25, 0.1, 0.25, 1.0)
+            .at(1.0, 200.0)
+            .duration(Duration::from_secs(1));
+        assert_eq!(animation.validate(), Ok(()));
+
+        // Past the next value and back, landing on it as the segment ends
+        let spring_half: Vec<f32> = (0..50)
+            .map(|i| animation.sample(i as f32 / 100.0))
+            .collect();
+        assert!(spring_half.iter().any(|value| *value > 105.0));
+        assert!(spring_half.windows(2).any(|pair| pair[1] < pair[0]));
+        assert!((animation.sample(0.499) - 100.0).abs() < 0.5);
+        assert_eq!(animation.sample(0.5), 100.0);
+
+        // The bezier half follows CSS `ease`
+        assert!((animation.sample(0.75) - 180.24).abs() < 0.1);
+
+        let broken = KeyframeAnimation::new()
+            .at_with_bezier(0.0, 0.0f32, 0.5, f32::NAN, 0.5, 1.0)
+            .at(1.0, 1.0);
+        assert!(matches!(
+            broken.validate(),
+            Err(ConfigError::NonFinite {
+                parameter: "y1",
+                ..
+            })
+        ));
+    }
 }
@@ -1,4 +1,28 @@
+pub(crate) mod blend;
+pub mod bounce;
+pub mod bundle;
+pub mod chain;
+#[cfg(feature = "clips")]
+pub mod clip;
+pub mod crossfade;
+pub(crate) mod cues;
+pub mod easing;
+pub mod entrance;
+pub mod follow;
+pub mod hover;
+pub mod join;
 pub mod keyframe;
+pub mod noise;
+pub mod persist;
+pub mod power;
+pub mod scheme;
+pub mod scroll;
 pub mod sequence;
+pub mod simple;
+pub mod snap;
 pub mod spring;
+pub mod stagger;
+pub mod styles;
+pub mod time_scale;
+pub mod tuning;
 pub mod tween;
@@ -0,0 +1,159 @@
+//! Seeded noise for idle motion
+//!
+//! Provides a smooth pseudo-random signal that a spring can layer on its target,
+//! so an element at rest keeps drifting gently, e.g. a floating card. The signal
+//! depends only on the seed and the time played, so the same seed replays the
+//! same drift, frame for frame.
+
+use crate::Animatable;
+
+/// Low-amplitude drift layered on a spring
+///
+/// The spring chases a target that wanders by up to `amplitude` around the
+/// real one; the spring's own lag smooths the wander into organic motion.
+/// Since the drift never ends, a spring with noise doesn't settle: it keeps
+/// running until it is replaced or stopped.
+///
+/// All channels of `amplitude` move together. To drift freely in two
+/// dimensions, animate x and y as separate values with different seeds.
+///
+/// # Example
+/// ```
+/// use dioxus_motion2::prelude::*;
+///
+/// let mut engine = AnimationEngine::new(0.0f32);
+/// engine.spring_with_noise(0.0, Spring::default(), Noise::new(6.0, 42));
+/// for _ in 0..120 {
+///     engine.update(1.0 / 60.0);
+/// }
+/// assert!(engine.is_active());
+/// assert!(engine.get().abs() < 12.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Noise<T: Animatable> {
+    /// Largest offset of the wandering target
+    pub amplitude: T,
+    /// Roughly how many times per second the drift changes direction
+    pub frequency: f32,
+    /// Seed picking the drift path
+    pub seed: u64,
+}
+
+impl<T: Animatable> Noise<T> {
+    /// Create noise of up to `amplitude`, changing direction about every two seconds
+    pub fn new(amplitude: T, seed: u64) -> Self {
+        Self {
+            amplitude,
+            frequency: 0.5,
+            seed,
+        }
+    }
+
+    /// Set how many times per second the drift changes direction
+    pub fn frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency.max(0.0);
+        self
+    }
+
+    /// The offset from the target after `elapsed` seconds
+    pub fn offset(&self, elapsed: f32) -> T {
+        self.amplitude
+            .scale(signal(self.seed, elapsed * self.frequency))
+    }
+}
+
+/// Two octaves of value noise, within `-1.0..=1.0`
+fn signal(seed: u64, position: f32) -> f32 {
+    let coarse = value_noise(seed, position);
+    let fine = value_noise(seed ^ 0xA5A5_A5A5_A5A5_A5A5, position * 2.0 + 17.0);
+    (coarse * 2.0 + fine) / 3.0
+}
+
+/// Random values at whole positions, smoothly interpolated in between
+fn value_noise(seed: u64, position: f32) -> f32 {
+    let whole = position.floor();
+    let fraction = position - whole;
+    let eased = fraction * fraction * (3.0 - 2.0 * fraction);
+    let index = whole as i64;
+    let start = lattice(seed, index);
+    let end = lattice(seed, index.wrapping_add(1));
+    start + (end - start) * eased
+}
+
+/// A value in `-1.0..=1.0` hashed from the seed and a whole position
+fn lattice(seed: u64, index: i64) -> f32 {
+    // SplitMix64 finalizer
+    let mut hash = seed ^ (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^= hash >> 31;
+    // The top 24 bits fit an f32 mantissa exactly
+    (hash >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+    use crate::animations::spring::Spring;
+
+    fn drift(seed: u64) -> Vec<f32> {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_with_noise(0.0, Spring::default(), Noise::new(6.0, seed));
+        (0..600)
+            .map(|_| {
+                assert!(engine.update(1.0 / 60.0));
+                engine.get()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_spring_drifts_reproducibly_without_settling() {
+        let path = drift(42);
+        assert_eq!(path, drift(42));
+        assert_ne!(path, drift(43));
+
+        let reach = path
+            .iter()
+            .fold(0.0f32, |reach, value| reach.max(value.abs()));
+        assert!(reach > 0.5 && reach < 8.0, "drifted up to {reach}");
+    }
+
+    #[test]
+    fn test_drifting_spring_reports_no_progress_and_stops_on_request() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_with_noise(0.0, Spring::default(), Noise::new(6.0, 1));
+        engine.update(1.0 / 60.0);
+        assert_eq!(engine.progress(), None);
+
+        engine.stop_at_cycle_end();
+        assert!(!engine.is_active());
+    }
+
+    #[test]
+    fn test_signal_is_bounded_smooth_and_seeded() {
+        let samples = |seed| {
+            (0..2000)
+                .map(|step| signal(seed, step as f32 * 0.01))
+                .collect::<Vec<_>>()
+        };
+        let first = samples(7);
+
+        assert_eq!(first, samples(7));
+        assert_ne!(first, samples(8));
+        assert!(first.iter().all(|value| (-1.0..=1.0).contains(value)));
+        assert!(
+            first
+                .windows(2)
+                .all(|pair| (pair[1] - pair[0]).abs() < 0.05)
+        );
+    }
+
+    #[test]
+    fn test_zero_amplitude_or_frequency_stays_put() {
+        assert_eq!(Noise::new(0.0f32, 3).offset(12.5), 0.0);
+        let frozen = Noise::new(5.0f32, 3).frequency(0.0);
+        assert_eq!(frozen.offset(0.0), frozen.offset(30.0));
+    }
+}
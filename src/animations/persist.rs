@@ -0,0 +1,139 @@
+//! Motion values that survive remounts
+//!
+//! A component's motion values are dropped when it unmounts, e.g. when the user
+//! navigates to another route, and start over from their initial value when it
+//! mounts again. [`use_persistent_motion`] keeps the value under a key in a
+//! [`MotionStore`] instead, so a remounted component picks up where it left off.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Sidebar() -> Element {
+//!     // Still collapsed after visiting another page and coming back
+//!     let mut width = use_persistent_motion("sidebar-width", 240.0f32);
+//!
+//!     rsx! {
+//!         nav {
+//!             style: "width: {width.get()}px",
+//!             onclick: move |_| { width.animate_to(64.0); },
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use tracing::debug;
+
+use crate::{Animatable, MotionValue, use_motion};
+
+/// Values of persistent motion values, keyed by name
+///
+/// [`use_persistent_motion`] uses the nearest store in context, or one shared
+/// by the whole app if none is provided. Provide one with
+/// `use_context_provider(MotionStore::new)` to scope the keys to part of the
+/// tree; the values live as long as the component providing it.
+#[derive(Clone, Default)]
+pub struct MotionStore {
+    values: Rc<RefCell<HashMap<String, Box<dyn Any>>>>,
+}
+
+impl MotionStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value kept under `key`, if one of type `T` is
+    pub fn get<T: Animatable>(&self, key: &str) -> Option<T> {
+        let values = self.values.borrow();
+        let value = values.get(key)?;
+        let value = value.downcast_ref::<T>().copied();
+        if value.is_none() {
+            debug!("Persisted motion value {key:?} has another type; starting over");
+        }
+        value
+    }
+
+    /// Keep `value` under `key`, replacing whatever was there
+    pub fn insert<T: Animatable>(&self, key: impl Into<String>, value: T) {
+        self.values.borrow_mut().insert(key.into(), Box::new(value));
+    }
+
+    /// Forget the value kept under `key`
+    pub fn remove(&self, key: &str) {
+        self.values.borrow_mut().remove(key);
+    }
+}
+
+/// Create a motion value whose value outlives the component, kept under `key`
+///
+/// On the first mount the value starts at `initial`. When the component
+/// unmounts its current value is saved, and the next component to mount with
+/// the same key starts from there; running animations are not resumed. The key
+/// is read on the first render only.
+pub fn use_persistent_motion<T: Animatable>(key: impl Into<String>, initial: T) -> MotionValue<T> {
+    let (store, key) = use_hook(|| {
+        let store = try_consume_context::<MotionStore>()
+            .unwrap_or_else(|| provide_root_context(MotionStore::new()));
+        (store, key.into())
+    });
+    let start = use_hook({
+        let (store, key) = (store.clone(), key.clone());
+        move || store.get(&key).unwrap_or(initial)
+    });
+    let motion = use_motion(start);
+
+    use_drop(move || {
+        if let Ok(engine) = motion.engine.try_peek() {
+            store.insert(key, engine.get());
+        }
+    });
+
+    motion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Transform;
+
+    #[test]
+    fn test_store_round_trips_values() {
+        let store = MotionStore::new();
+        assert_eq!(store.get::<f32>("offset"), None);
+
+        store.insert("offset", 42.0f32);
+        store.insert("card", Transform::translate(10.0, 20.0));
+        assert_eq!(store.get::<f32>("offset"), Some(42.0));
+        assert_eq!(
+            store.get::<Transform>("card"),
+            Some(Transform::translate(10.0, 20.0))
+        );
+
+        store.remove("offset");
+        assert_eq!(store.get::<f32>("offset"), None);
+    }
+
+    #[test]
+    fn test_type_mismatch_starts_over() {
+        let store = MotionStore::new();
+        store.insert("offset", 42.0f32);
+        assert_eq!(store.get::<Transform>("offset"), None);
+    }
+
+    #[test]
+    fn test_clones_share_values() {
+        let store = MotionStore::new();
+        store.clone().insert("offset", 7.0f32);
+        assert_eq!(store.get::<f32>("offset"), Some(7.0));
+    }
+}
@@ -0,0 +1,748 @@
+//! Saving power and data on ambient motion
+//!
+//! Ambient animations, e.g. an orbiting system or drifting background blobs,
+//! loop for as long as the page is open. [`use_power_preferences`] tells
+//! whether the user asked to save data, prefers reduced motion or runs low on
+//! battery, and spring and tween builders marked [`ambient`] cut back on their
+//! own when they do: they don't start, step at a lower frame rate or stop
+//! looping after a few passes, as the [`AmbientPolicy`] of the enclosing
+//! [`MotionConfig`] says.
+//!
+//! On the web the preferences follow `prefers-reduced-motion`,
+//! `prefers-reduced-data`, `navigator.connection.saveData` and, where the
+//! browser has the battery API, an unplugged battery at 20% or less. Elsewhere
+//! they keep their defaults, saving nothing, unless set with
+//! [`set_power_preferences`].
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Orbit() -> Element {
+//!     let angle = use_motion(0.0f32);
+//!     use_effect(move || {
+//!         angle
+//!             .tween()
+//!             .duration(Duration::from_secs(20))
+//!             .ambient()
+//!             .animate_to(360.0);
+//!     });
+//!
+//!     rsx! {
+//!         div { style: "transform: rotate({angle.get()}deg)" }
+//!     }
+//! }
+//! ```
+//!
+//! [`ambient`]: crate::animations::spring::SpringBuilder::ambient
+//! [`MotionConfig`]: crate::MotionConfig
+
+use std::cell::Cell;
+#[cfg(feature = "web")]
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use instant::Duration;
+
+use crate::Animatable;
+use crate::animation::{Animation, AnimationState, LoopMode, MotionConfig};
+use crate::animations::spring::Spring;
+use crate::core::Playback;
+use crate::driver;
+
+/// What the user asked of the device's power and data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PowerPreferences {
+    /// The user turned on a data saver
+    pub save_data: bool,
+    /// The user prefers reduced motion
+    pub reduced_motion: bool,
+    /// The battery is low and not charging
+    pub battery_saver: bool,
+}
+
+impl PowerPreferences {
+    /// The system's preferences, the defaults where they can't be queried
+    ///
+    /// The battery is only known once the browser answers, see [`PowerSource::attach`].
+    fn system() -> Self {
+        // Outside a browser web-sys panics rather than finding no window
+        #[cfg(feature = "web")]
+        if cfg!(target_arch = "wasm32") {
+            let matches = |query: &str| {
+                web_sys::window()
+                    .and_then(|window| window.match_media(query).ok().flatten())
+                    .is_some_and(|query| query.matches())
+            };
+            return Self {
+                save_data: matches("(prefers-reduced-data: reduce)") || save_data(),
+                reduced_motion: matches("(prefers-reduced-motion: reduce)"),
+                battery_saver: false,
+            };
+        }
+        Self::default()
+    }
+}
+
+/// How an ambient animation cuts back, from [`AmbientPolicy`]
+///
+/// Reductions combine with [`and`](Self::and), the stricter of each part
+/// winning.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AmbientReduction {
+    /// Don't start the animation; the value stays where it is
+    pub skip: bool,
+    /// Land on the target at once instead of animating there
+    pub jump: bool,
+    /// Step only this many times a second, holding the value in between
+    pub frame_rate: Option<f32>,
+    /// Stop looping after this many passes
+    pub passes: Option<u32>,
+}
+
+impl AmbientReduction {
+    /// Play as asked
+    pub const fn none() -> Self {
+        Self {
+            skip: false,
+            jump: false,
+            frame_rate: None,
+            passes: None,
+        }
+    }
+
+    /// Don't start at all
+    pub const fn skip() -> Self {
+        Self {
+            skip: true,
+            ..Self::none()
+        }
+    }
+
+    /// Land on the target at once
+    pub const fn jump() -> Self {
+        Self {
+            jump: true,
+            ..Self::none()
+        }
+    }
+
+    /// Step `frame_rate` times a second
+    pub const fn frame_rate(frame_rate: f32) -> Self {
+        Self {
+            frame_rate: Some(frame_rate),
+            ..Self::none()
+        }
+    }
+
+    /// Stop looping after `passes` passes
+    pub const fn passes(passes: u32) -> Self {
+        Self {
+            passes: Some(passes),
+            ..Self::none()
+        }
+    }
+
+    /// Both reductions at once: skipped if either skips, jumping if either
+    /// jumps, at the lower frame rate and the fewer passes
+    pub fn and(self, other: Self) -> Self {
+        let stricter = |a: Option<f32>, b: Option<f32>| match (a, b) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            _ => a.or(b),
+        };
+        Self {
+            skip: self.skip || other.skip,
+            jump: self.jump || other.jump,
+            frame_rate: stricter(self.frame_rate, other.frame_rate),
+            passes: match (self.passes, other.passes) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+        }
+    }
+
+    /// How the engine plays an animation cut back this way
+    pub(crate) fn playback(&self) -> Playback {
+        Playback {
+            frame_rate: self.frame_rate,
+            ..Playback::default()
+        }
+    }
+
+    /// Limit `loop_mode` to the allowed passes
+    pub(crate) fn limit(&self, loop_mode: LoopMode) -> LoopMode {
+        match (self.passes, loop_mode) {
+            (Some(passes), LoopMode::Infinite) => LoopMode::Count(passes),
+            (Some(passes), LoopMode::Count(count)) => LoopMode::Count(count.min(passes)),
+            (Some(passes), LoopMode::Alternate) => LoopMode::AlternateTimes(passes),
+            (Some(passes), LoopMode::AlternateTimes(count)) => {
+                LoopMode::AlternateTimes(count.min(passes))
+            }
+            (_, loop_mode) => loop_mode,
+        }
+    }
+}
+
+/// How ambient animations cut back for each preference, set with
+/// [`MotionConfig::with_ambient_policy`]
+///
+/// By default they don't start for reduced motion, step at 20 frames a second
+/// on a low battery and stop after three passes to save data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmbientPolicy {
+    /// When the user turned on a data saver
+    pub save_data: AmbientReduction,
+    /// When the user prefers reduced motion
+    pub reduced_motion: AmbientReduction,
+    /// When the battery is low and not charging
+    pub battery_saver: AmbientReduction,
+}
+
+impl Default for AmbientPolicy {
+    fn default() -> Self {
+        Self {
+            save_data: AmbientReduction::passes(3),
+            reduced_motion: AmbientReduction::skip(),
+            battery_saver: AmbientReduction::frame_rate(20.0),
+        }
+    }
+}
+
+impl AmbientPolicy {
+    /// A policy playing ambient animations as asked, whatever the preferences
+    pub const fn never() -> Self {
+        Self {
+            save_data: AmbientReduction::none(),
+            reduced_motion: AmbientReduction::none(),
+            battery_saver: AmbientReduction::none(),
+        }
+    }
+
+    /// The reduction for `preferences`, every matching one combined
+    pub fn resolve(&self, preferences: PowerPreferences) -> AmbientReduction {
+        [
+            (preferences.save_data, self.save_data),
+            (preferences.reduced_motion, self.reduced_motion),
+            (preferences.battery_saver, self.battery_saver),
+        ]
+        .into_iter()
+        .filter(|(applies, _)| *applies)
+        .fold(AmbientReduction::none(), |all, (_, reduction)| {
+            all.and(reduction)
+        })
+    }
+
+    /// The reduction for animations started now in the current scope, cut
+    /// back further while the driver is at its animation cap
+    pub(crate) fn current() -> AmbientReduction {
+        let policy = try_consume_context::<MotionConfig>()
+            .map(|config| config.ambient_policy)
+            .unwrap_or_default();
+        let preferences = try_consume_context::<PowerSource>()
+            .map_or_else(PowerPreferences::system, |source| {
+                *source.preferences.peek()
+            });
+        policy.resolve(preferences).and(driver::cap_reduction())
+    }
+}
+
+/// An animation stepped at a lower frame rate, holding its value in between
+pub(crate) struct Throttled<T: Animatable> {
+    inner: Box<dyn Animation<Value = T>>,
+    /// Seconds between two steps
+    interval: f32,
+    /// Time since the last step
+    held: f32,
+}
+
+impl<T: Animatable> Throttled<T> {
+    pub(crate) fn new(inner: Box<dyn Animation<Value = T>>, frame_rate: f32) -> Self {
+        Self {
+            inner,
+            interval: 1.0 / frame_rate.max(1.0),
+            held: 0.0,
+        }
+    }
+}
+
+impl<T: Animatable> Animation for Throttled<T> {
+    type Value = T;
+
+    fn update(&mut self, dt: f32) -> (AnimationState, T, T) {
+        self.held += dt;
+        // Frame times summing up to the interval may fall short by rounding
+        if self.held < self.interval - 1e-4 && self.inner.is_active() {
+            return (
+                AnimationState::Active,
+                self.inner.value(),
+                self.inner.velocity(),
+            );
+        }
+        // The skipped frames' time passes in one step
+        self.inner.update(std::mem::take(&mut self.held))
+    }
+
+    fn value(&self) -> T {
+        self.inner.value()
+    }
+
+    fn velocity(&self) -> T {
+        self.inner.velocity()
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.held = 0.0;
+    }
+
+    fn is_active(&self) -> bool {
+        self.inner.is_active()
+    }
+
+    fn remaining_time(&self) -> Option<Duration> {
+        self.inner.remaining_time()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+
+    fn progress(&self) -> Option<f32> {
+        self.inner.progress()
+    }
+
+    fn retune_spring(&mut self, spring: &Spring) -> bool {
+        self.inner.retune_spring(spring)
+    }
+
+    fn finish_loop(&mut self) -> bool {
+        self.inner.finish_loop()
+    }
+
+    fn retarget(&mut self, target: T) -> bool {
+        self.inner.retarget(target)
+    }
+}
+
+#[cfg(feature = "web")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    /// `navigator.connection`, with the `saveData` flag web-sys doesn't bind
+    type Connection;
+
+    #[wasm_bindgen(method, getter, js_name = saveData)]
+    fn save_data(this: &Connection) -> Option<bool>;
+
+    /// `navigator`, with the `getBattery` web-sys doesn't bind
+    type BatteryNavigator;
+
+    /// Throws where the browser has no battery API
+    #[wasm_bindgen(method, catch, js_name = getBattery)]
+    fn get_battery(
+        this: &BatteryNavigator,
+    ) -> Result<web_sys::js_sys::Promise, wasm_bindgen::JsValue>;
+}
+
+/// `navigator.connection`, where the browser has it
+#[cfg(feature = "web")]
+fn connection() -> Option<web_sys::NetworkInformation> {
+    web_sys::window()?.navigator().connection().ok()
+}
+
+/// Whether the data saver is on, as far as the browser tells
+#[cfg(feature = "web")]
+fn save_data() -> bool {
+    use wasm_bindgen::JsCast;
+
+    connection().is_some_and(|connection| {
+        connection
+            .unchecked_into::<Connection>()
+            .save_data()
+            .unwrap_or(false)
+    })
+}
+
+/// An event target and the event it is listened to for
+#[cfg(feature = "web")]
+type Listened = (web_sys::EventTarget, &'static str);
+
+/// A browser callback, kept alive for as long as it is listened with
+#[cfg(feature = "web")]
+type Held<F> = Rc<RefCell<Option<wasm_bindgen::closure::Closure<F>>>>;
+
+/// The app's power preferences, shared by every [`use_power_preferences`]
+/// through the root context
+#[derive(Clone)]
+struct PowerSource {
+    preferences: Signal<PowerPreferences>,
+    /// Mounted components using the preferences
+    users: Rc<Cell<usize>>,
+    /// Re-reads the preferences on any change
+    #[cfg(feature = "web")]
+    refresh: Held<dyn FnMut()>,
+    #[cfg(feature = "web")]
+    listened: Rc<RefCell<Vec<Listened>>>,
+    /// Battery, once the browser handed it over
+    #[cfg(feature = "web")]
+    battery: Rc<RefCell<Option<web_sys::BatteryManager>>>,
+    /// Receives the battery from the browser
+    #[cfg(feature = "web")]
+    on_battery: Held<dyn FnMut(wasm_bindgen::JsValue)>,
+}
+
+impl PowerSource {
+    /// The app's source, created on first use
+    fn get() -> Self {
+        try_consume_context::<Self>().unwrap_or_else(|| {
+            provide_root_context(Self {
+                preferences: Signal::new_in_scope(PowerPreferences::system(), ScopeId::ROOT),
+                users: Rc::default(),
+                #[cfg(feature = "web")]
+                refresh: Rc::default(),
+                #[cfg(feature = "web")]
+                listened: Rc::default(),
+                #[cfg(feature = "web")]
+                battery: Rc::default(),
+                #[cfg(feature = "web")]
+                on_battery: Rc::default(),
+            })
+        })
+    }
+
+    /// Count a component in, listening for changes for the first one
+    fn acquire(&self) {
+        self.users.set(self.users.get() + 1);
+        if self.users.get() == 1 {
+            self.attach();
+        }
+    }
+
+    /// Count a component out, removing the listeners after the last one
+    fn release(&self) {
+        let users = self.users.get().saturating_sub(1);
+        self.users.set(users);
+        if users == 0 {
+            self.detach();
+        }
+    }
+
+    /// The current preferences, the battery as last reported
+    #[cfg(feature = "web")]
+    fn read(battery: &RefCell<Option<web_sys::BatteryManager>>) -> PowerPreferences {
+        let battery_saver = battery
+            .borrow()
+            .as_ref()
+            .is_some_and(|battery| !battery.charging() && battery.level() <= 0.2);
+        PowerPreferences {
+            battery_saver,
+            ..PowerPreferences::system()
+        }
+    }
+
+    /// Follow changes of the media queries, the connection and the battery
+    #[cfg(feature = "web")]
+    fn attach(&self) {
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen::closure::Closure;
+
+        if !cfg!(target_arch = "wasm32") {
+            return;
+        }
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let preferences = self.preferences;
+        let battery = self.battery.clone();
+        let refresh = Closure::<dyn FnMut()>::new(move || {
+            let mut preferences = preferences;
+            let latest = Self::read(&battery);
+            if *preferences.peek() != latest {
+                preferences.set(latest);
+            }
+        });
+        let callback = refresh
+            .as_ref()
+            .unchecked_ref::<web_sys::js_sys::Function>()
+            .clone();
+        *self.refresh.borrow_mut() = Some(refresh);
+
+        let listen = {
+            let listened = self.listened.clone();
+            move |target: web_sys::EventTarget,
+                  event: &'static str,
+                  callback: &web_sys::js_sys::Function| {
+                if target
+                    .add_event_listener_with_callback(event, callback)
+                    .is_ok()
+                {
+                    listened.borrow_mut().push((target, event));
+                }
+            }
+        };
+        for query in [
+            "(prefers-reduced-motion: reduce)",
+            "(prefers-reduced-data: reduce)",
+        ] {
+            if let Ok(Some(query)) = window.match_media(query) {
+                listen(query.into(), "change", &callback);
+            }
+        }
+        if let Some(connection) = connection() {
+            listen(connection.into(), "change", &callback);
+        }
+
+        // The battery arrives later, if the browser has the API at all
+        let navigator = window.navigator().unchecked_into::<BatteryNavigator>();
+        let Ok(promise) = navigator.get_battery() else {
+            return;
+        };
+        let source = self.clone();
+        let on_battery = Closure::<dyn FnMut(wasm_bindgen::JsValue)>::new(
+            move |value: wasm_bindgen::JsValue| {
+                if source.users.get() == 0 {
+                    return;
+                }
+                let manager: web_sys::BatteryManager = value.unchecked_into();
+                for event in ["levelchange", "chargingchange"] {
+                    listen(manager.clone().into(), event, &callback);
+                }
+                *source.battery.borrow_mut() = Some(manager);
+                let latest = Self::read(&source.battery);
+                let mut preferences = source.preferences;
+                if *preferences.peek() != latest {
+                    preferences.set(latest);
+                }
+            },
+        );
+        let _ = promise.then(&on_battery);
+        *self.on_battery.borrow_mut() = Some(on_battery);
+    }
+
+    #[cfg(not(feature = "web"))]
+    fn attach(&self) {}
+
+    /// Stop following changes
+    #[cfg(feature = "web")]
+    fn detach(&self) {
+        use wasm_bindgen::JsCast;
+
+        if let Some(refresh) = self.refresh.borrow_mut().take() {
+            for (target, event) in self.listened.borrow_mut().drain(..) {
+                let _ = target
+                    .remove_event_listener_with_callback(event, refresh.as_ref().unchecked_ref());
+            }
+        }
+        self.battery.borrow_mut().take();
+        // Drops the clone of this source the callback holds
+        self.on_battery.borrow_mut().take();
+    }
+
+    #[cfg(not(feature = "web"))]
+    fn detach(&self) {}
+}
+
+/// The user's power and data preferences
+///
+/// Starts from the system settings on the web, and follows their changes while
+/// mounted; elsewhere every preference is off unless set with
+/// [`set_power_preferences`]. Reading the signal subscribes the caller to
+/// changes.
+pub fn use_power_preferences() -> Signal<PowerPreferences> {
+    let source = use_hook(|| {
+        let source = PowerSource::get();
+        source.acquire();
+        source
+    });
+    let preferences = source.preferences;
+    use_drop(move || source.release());
+    preferences
+}
+
+/// Set the power preferences, e.g. from an app's own "save battery" switch
+///
+/// On the web a later change of a system setting reads them all again.
+pub fn set_power_preferences(preferences: PowerPreferences) {
+    let mut current = PowerSource::get().preferences;
+    if *current.peek() != preferences {
+        current.set(preferences);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animations::tween::Tween;
+
+    #[test]
+    fn test_policy_resolution_matrix() {
+        let policy = AmbientPolicy::default();
+        let preferences = |save_data, reduced_motion, battery_saver| PowerPreferences {
+            save_data,
+            reduced_motion,
+            battery_saver,
+        };
+
+        // Every combination of the three preferences
+        for save_data in [false, true] {
+            for reduced_motion in [false, true] {
+                for battery_saver in [false, true] {
+                    let reduction =
+                        policy.resolve(preferences(save_data, reduced_motion, battery_saver));
+                    assert_eq!(reduction.skip, reduced_motion);
+                    assert_eq!(reduction.frame_rate, battery_saver.then_some(20.0));
+                    assert_eq!(reduction.passes, save_data.then_some(3));
+                }
+            }
+        }
+        assert_eq!(
+            policy.resolve(PowerPreferences::default()),
+            AmbientReduction::none()
+        );
+        let all = preferences(true, true, true);
+        assert_eq!(
+            AmbientPolicy::never().resolve(all),
+            AmbientReduction::none()
+        );
+
+        // Overlapping reductions keep the stricter part
+        let policy = AmbientPolicy {
+            save_data: AmbientReduction::frame_rate(30.0).and(AmbientReduction::passes(5)),
+            reduced_motion: AmbientReduction::passes(1),
+            battery_saver: AmbientReduction::frame_rate(10.0),
+        };
+        assert_eq!(
+            policy.resolve(all),
+            AmbientReduction {
+                skip: false,
+                jump: false,
+                frame_rate: Some(10.0),
+                passes: Some(1),
+            }
+        );
+    }
+
+    #[test]
+    fn test_passes_only_shorten_loops() {
+        let three = AmbientReduction::passes(3);
+        assert_eq!(three.limit(LoopMode::Infinite), LoopMode::Count(3));
+        assert_eq!(three.limit(LoopMode::Count(5)), LoopMode::Count(3));
+        assert_eq!(three.limit(LoopMode::Count(2)), LoopMode::Count(2));
+        assert_eq!(three.limit(LoopMode::None), LoopMode::None);
+        assert_eq!(
+            three.limit(LoopMode::Alternate),
+            LoopMode::AlternateTimes(3)
+        );
+        assert_eq!(
+            AmbientReduction::none().limit(LoopMode::Infinite),
+            LoopMode::Infinite
+        );
+    }
+
+    #[test]
+    fn test_system_preferences_are_the_defaults_outside_a_browser() {
+        assert_eq!(PowerPreferences::system(), PowerPreferences::default());
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_ambient_springs_follow_the_preferences() {
+        use crate::testing::{MotionHarness, motion_test_harness};
+        use crate::{use_motion_config, use_motion_labeled};
+
+        thread_local! {
+            static PREFERENCES: Cell<PowerPreferences> = Cell::default();
+        }
+
+        fn app() -> Element {
+            use_motion_config(MotionConfig::new);
+            let x = use_motion_labeled("x", 0.0f32);
+            use_hook(move || {
+                set_power_preferences(PREFERENCES.get());
+                x.spring()
+                    .loop_mode(LoopMode::Infinite)
+                    .ambient()
+                    .animate_to(10.0)
+            });
+            rsx! {}
+        }
+
+        let harness = |preferences: PowerPreferences| {
+            PREFERENCES.set(preferences);
+            motion_test_harness(app)
+        };
+        let x = |harness: &MotionHarness| {
+            let Some(x) = harness.motion_value("x") else {
+                unreachable!("the app labels its value");
+            };
+            (x.value::<f32>().unwrap_or_default(), x.is_animating())
+        };
+
+        // Nothing to save: it loops on and on
+        let mut playing = harness(PowerPreferences::default());
+        playing.tick(Duration::from_secs(10));
+        assert!(x(&playing).1);
+        drop(playing);
+
+        // Reduced motion: it never starts
+        let mut still = harness(PowerPreferences {
+            reduced_motion: true,
+            ..PowerPreferences::default()
+        });
+        assert_eq!(x(&still), (0.0, false));
+        still.tick(Duration::from_secs(1));
+        assert_eq!(x(&still), (0.0, false));
+        drop(still);
+
+        // Saving data: it stops after three passes
+        let mut passes = harness(PowerPreferences {
+            save_data: true,
+            ..PowerPreferences::default()
+        });
+        passes.tick(Duration::from_secs(10));
+        assert!(!x(&passes).1);
+        drop(passes);
+
+        // Low battery: it moves on every third frame only
+        let mut throttled = harness(PowerPreferences {
+            battery_saver: true,
+            ..PowerPreferences::default()
+        });
+        let mut values = Vec::new();
+        for _ in 0..30 {
+            throttled.tick(Duration::from_nanos(16_666_667));
+            values.push(x(&throttled).0);
+        }
+        values.dedup();
+        assert!((9..=11).contains(&values.len()), "{values:?}");
+    }
+
+    #[test]
+    fn test_throttled_animations_hold_between_steps() {
+        let tween = Tween::new().duration(Duration::from_millis(500));
+        let mut smooth = tween.create_animation(0.0f32, 100.0);
+        let mut throttled = Throttled::new(Box::new(tween.create_animation(0.0f32, 100.0)), 15.0);
+
+        let (mut steps, mut last) = (0, 0.0);
+        let mut frames = 0;
+        loop {
+            let (_, expected, _) = smooth.update(1.0 / 60.0);
+            let (state, value, _) = throttled.update(1.0 / 60.0);
+            frames += 1;
+            if value != last {
+                steps += 1;
+                // Whenever it steps it catches up with the smooth one
+                assert!((value - expected).abs() < 1e-3);
+                last = value;
+            }
+            if state == AnimationState::Completed {
+                break;
+            }
+        }
+        assert_eq!(last, 100.0);
+        // It ends at the first step after the smooth one, a few frames late
+        assert!((30..=34).contains(&frames), "ended after {frames} frames");
+        assert_eq!(steps, 8);
+    }
+}
@@ -0,0 +1,350 @@
+//! Following the light or dark color scheme
+//!
+//! [`use_color_scheme`] tracks whether the app shows its light or dark theme.
+//! On the web it follows the system setting through a single
+//! `prefers-color-scheme` listener shared by every component using it, removed
+//! once the last of them unmounts. Elsewhere, and to let users pick a theme on
+//! the web too, set it with [`set_color_scheme`].
+//!
+//! [`use_scheme_motion`] creates a value that animates between a light and a
+//! dark palette whenever the scheme changes, without each component wiring its
+//! own listener:
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Page() -> Element {
+//!     let background = use_scheme_motion(
+//!         Color::white(),
+//!         Color::from_rgba(24, 24, 27, 255),
+//!         AnimationConfig::new(AnimationMode::Tween(Tween::default())),
+//!     );
+//!
+//!     rsx! {
+//!         main { style: "background: {background.get().to_css_string()}" }
+//!     }
+//! }
+//! ```
+
+use std::cell::Cell;
+#[cfg(feature = "web")]
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+#[cfg(feature = "web")]
+use crate::Duration;
+use crate::animation::AnimationConfig;
+use crate::{Animatable, MotionValue};
+
+/// How long a system scheme change must hold before it is applied, so rapid
+/// toggles only animate to where they end up
+#[cfg(feature = "web")]
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Light or dark theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorScheme {
+    /// Light backgrounds with dark text
+    #[default]
+    Light,
+    /// Dark backgrounds with light text
+    Dark,
+}
+
+impl ColorScheme {
+    /// `light` or `dark`, whichever this scheme calls for
+    pub fn pick<T>(self, light: T, dark: T) -> T {
+        match self {
+            Self::Light => light,
+            Self::Dark => dark,
+        }
+    }
+
+    /// The system scheme, or light where it can't be queried
+    fn system() -> Self {
+        #[cfg(feature = "web")]
+        if let Some(query) = dark_query() {
+            return Self::dark_if(query.matches());
+        }
+        Self::Light
+    }
+
+    #[cfg(feature = "web")]
+    fn dark_if(dark: bool) -> Self {
+        if dark { Self::Dark } else { Self::Light }
+    }
+}
+
+/// The `(prefers-color-scheme: dark)` media query, outside a browser none
+#[cfg(feature = "web")]
+fn dark_query() -> Option<web_sys::MediaQueryList> {
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    web_sys::window()?
+        .match_media("(prefers-color-scheme: dark)")
+        .ok()
+        .flatten()
+}
+
+/// Hands out tickets for delayed work; only the latest one is still wanted
+#[cfg(any(feature = "web", test))]
+#[derive(Debug, Default)]
+struct Debounce {
+    latest: Cell<u64>,
+}
+
+#[cfg(any(feature = "web", test))]
+impl Debounce {
+    /// Start waiting, superseding every earlier ticket
+    fn start(&self) -> u64 {
+        let ticket = self.latest.get() + 1;
+        self.latest.set(ticket);
+        ticket
+    }
+
+    /// Whether `ticket` was not superseded while waiting
+    fn is_latest(&self, ticket: u64) -> bool {
+        self.latest.get() == ticket
+    }
+}
+
+/// A media query and the change listener registered on it
+#[cfg(feature = "web")]
+type Listener = (
+    web_sys::MediaQueryList,
+    wasm_bindgen::closure::Closure<dyn FnMut()>,
+);
+
+/// The app's color scheme, shared by every [`use_color_scheme`] through the
+/// root context
+#[derive(Clone)]
+struct SchemeSource {
+    scheme: Signal<ColorScheme>,
+    /// Mounted components using the scheme
+    users: Rc<Cell<usize>>,
+    #[cfg(feature = "web")]
+    listener: Rc<RefCell<Option<Listener>>>,
+}
+
+impl SchemeSource {
+    /// The app's source, created on first use
+    fn get() -> Self {
+        try_consume_context::<Self>().unwrap_or_else(|| {
+            provide_root_context(Self {
+                scheme: Signal::new_in_scope(ColorScheme::system(), ScopeId::ROOT),
+                users: Rc::default(),
+                #[cfg(feature = "web")]
+                listener: Rc::default(),
+            })
+        })
+    }
+
+    /// Count a component in, listening for system changes for the first one
+    fn acquire(&self) {
+        self.users.set(self.users.get() + 1);
+        if self.users.get() == 1 {
+            self.attach();
+        }
+    }
+
+    /// Count a component out, removing the listener after the last one
+    fn release(&self) {
+        let users = self.users.get().saturating_sub(1);
+        self.users.set(users);
+        if users == 0 {
+            self.detach();
+        }
+    }
+
+    /// Follow system scheme changes, applying each once it held for [`DEBOUNCE`]
+    #[cfg(feature = "web")]
+    fn attach(&self) {
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen::closure::Closure;
+
+        let Some(query) = dark_query() else {
+            return;
+        };
+        let scheme = self.scheme;
+        let debounce = Rc::new(Debounce::default());
+        let listener = Closure::<dyn FnMut()>::new({
+            let query = query.clone();
+            move || {
+                let latest = ColorScheme::dark_if(query.matches());
+                let ticket = debounce.start();
+                let debounce = debounce.clone();
+                let apply = Closure::once_into_js(move || {
+                    let mut scheme = scheme;
+                    if debounce.is_latest(ticket) && *scheme.peek() != latest {
+                        scheme.set(latest);
+                    }
+                });
+                if let Some(window) = web_sys::window() {
+                    let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(
+                        apply.unchecked_ref(),
+                        DEBOUNCE.as_millis() as i32,
+                    );
+                }
+            }
+        });
+        if query
+            .add_event_listener_with_callback("change", listener.as_ref().unchecked_ref())
+            .is_ok()
+        {
+            *self.listener.borrow_mut() = Some((query, listener));
+        }
+    }
+
+    #[cfg(not(feature = "web"))]
+    fn attach(&self) {}
+
+    /// Stop following system scheme changes
+    #[cfg(feature = "web")]
+    fn detach(&self) {
+        use wasm_bindgen::JsCast;
+
+        if let Some((query, listener)) = self.listener.borrow_mut().take() {
+            let _ = query
+                .remove_event_listener_with_callback("change", listener.as_ref().unchecked_ref());
+        }
+    }
+
+    #[cfg(not(feature = "web"))]
+    fn detach(&self) {}
+}
+
+/// The app's current color scheme
+///
+/// Starts from the system setting on the web and from light elsewhere; reading
+/// the signal subscribes the caller to changes.
+pub fn use_color_scheme() -> Signal<ColorScheme> {
+    let source = use_hook(|| {
+        let source = SchemeSource::get();
+        source.acquire();
+        source
+    });
+    let scheme = source.scheme;
+    use_drop(move || source.release());
+    scheme
+}
+
+/// Switch the app to `scheme`, e.g. from a theme toggle
+///
+/// On the web a later change of the system setting switches it again.
+pub fn set_color_scheme(scheme: ColorScheme) {
+    let mut current = SchemeSource::get().scheme;
+    if *current.peek() != scheme {
+        current.set(scheme);
+    }
+}
+
+/// A motion value at `light` or `dark`, animating to the other whenever the
+/// color scheme changes
+///
+/// Starts at the current scheme's value, then animates with `config` on every
+/// change of [`use_color_scheme`], and to the new value whenever a render
+/// passes other palettes. Like other hooks, call it unconditionally from the
+/// component body; `config` is read on the first render only.
+pub fn use_scheme_motion<T: Animatable + PartialEq>(
+    light: T,
+    dark: T,
+    config: AnimationConfig,
+) -> MotionValue<T> {
+    let scheme = use_color_scheme();
+    let mut motion = crate::use_motion(scheme.peek().pick(light, dark));
+    let last = use_hook(|| Rc::new(Cell::new(None)));
+
+    use_effect(use_reactive((&light, &dark), move |(light, dark)| {
+        let target = scheme().pick(light, dark);
+        // The first run finds the value there already
+        if last
+            .replace(Some(target))
+            .is_some_and(|last| last != target)
+        {
+            motion.animate_to_with_config(target, config.clone());
+        }
+    }));
+    motion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheme_motion_follows_scheme_and_palettes() {
+        use crate::animation::AnimationMode;
+        use crate::animations::tween::Tween;
+
+        thread_local! {
+            static STATE: Cell<Option<(Signal<f32>, MotionValue<f32>)>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let light = use_signal(|| 0.0f32);
+            let motion = use_scheme_motion(
+                light(),
+                1.0,
+                AnimationConfig::new(AnimationMode::Tween(Tween::default())),
+            );
+            STATE.set(Some((light, motion)));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some((mut light, motion)) = STATE.get() else {
+            unreachable!("the app stores its state");
+        };
+        let settle = |dom: &mut VirtualDom| {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        };
+        let target = |dom: &VirtualDom| dom.in_runtime(|| motion.engine.peek().target());
+        settle(&mut dom);
+        // Starts on the light palette without animating
+        assert_eq!(dom.in_runtime(|| motion.peek()), 0.0);
+        assert_eq!(target(&dom), None);
+
+        dom.in_runtime(|| ScopeId::ROOT.in_runtime(|| set_color_scheme(ColorScheme::Dark)));
+        settle(&mut dom);
+        assert_eq!(target(&dom), Some(1.0));
+
+        dom.in_runtime(|| ScopeId::ROOT.in_runtime(|| set_color_scheme(ColorScheme::Light)));
+        settle(&mut dom);
+        assert_eq!(target(&dom), Some(0.0));
+
+        // A new palette passed on a later render is animated to as well
+        dom.in_runtime(|| light.set(0.25));
+        // One pass re-renders with the new palette, the next runs the effect
+        settle(&mut dom);
+        settle(&mut dom);
+        assert_eq!(target(&dom), Some(0.25));
+    }
+
+    #[test]
+    fn test_pick_follows_scheme() {
+        assert_eq!(ColorScheme::Light.pick(1.0, 0.0), 1.0);
+        assert_eq!(ColorScheme::Dark.pick(1.0, 0.0), 0.0);
+        assert_eq!(ColorScheme::default(), ColorScheme::Light);
+    }
+
+    #[test]
+    fn test_rapid_toggles_apply_only_the_last() {
+        let debounce = Debounce::default();
+        let to_dark = debounce.start();
+        let to_light = debounce.start();
+        let to_dark_again = debounce.start();
+
+        assert!(!debounce.is_latest(to_dark));
+        assert!(!debounce.is_latest(to_light));
+        assert!(debounce.is_latest(to_dark_again));
+    }
+}
@@ -0,0 +1,380 @@
+//! Scroll-driven timelines
+//!
+//! Binds keyframe animations to an element's scroll position instead of time.
+//! As the element moves through the viewport, a normalized progress (0.0 to 1.0)
+//! is computed and the keyframe track is sampled at that progress, so the
+//! animation scrubs forwards and backwards with the scroll.
+//!
+//! # Example
+//!
+//! A header that shrinks over the first 120px of scrolling:
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn StickyHeader() -> Element {
+//!     let mut timeline = use_scroll_timeline(ScrollRange::Distance(120.0));
+//!     let height = use_motion(96.0f32);
+//!     use_scrub(height, timeline, KeyframeAnimation::new().at(0.0, 96.0).at(1.0, 56.0));
+//!
+//!     rsx! {
+//!         header {
+//!             style: "position: sticky; top: 0; height: {height.get()}px;",
+//!             onmounted: move |event| timeline.attach(&event.data()),
+//!             "Dioxus Motion"
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::animations::keyframe::KeyframeAnimation;
+use crate::{Animatable, MotionValue};
+
+/// Position of an element relative to the viewport, as reported by
+/// `getBoundingClientRect`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollMetrics {
+    /// Distance from the viewport top to the element's top edge (px)
+    pub element_top: f32,
+    /// Height of the element (px)
+    pub element_height: f32,
+    /// Height of the viewport (px)
+    pub viewport_height: f32,
+}
+
+/// Portion of an element's journey through the viewport mapped to progress 0.0 to 1.0
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollRange {
+    /// From the element's top edge entering at the bottom of the viewport until
+    /// its bottom edge leaves at the top
+    Cover,
+    /// From the element's top edge entering at the bottom of the viewport until
+    /// it is fully visible
+    Entry,
+    /// From the element's top edge reaching the top of the viewport until its
+    /// bottom edge leaves it
+    Exit,
+    /// The first `distance` pixels scrolled after the element's top edge
+    /// reaches the top of the viewport, e.g. for sticky headers
+    Distance(f32),
+}
+
+impl ScrollRange {
+    /// Compute the clamped progress (0.0 to 1.0) for an element position
+    pub fn progress(&self, metrics: ScrollMetrics) -> f32 {
+        let ScrollMetrics {
+            element_top,
+            element_height,
+            viewport_height,
+        } = metrics;
+
+        let (start, end) = match *self {
+            // Progress is measured as the distance the element's top has travelled
+            // from where the range starts
+            Self::Cover => (viewport_height, -element_height),
+            Self::Entry => (viewport_height, viewport_height - element_height),
+            Self::Exit => (0.0, -element_height),
+            Self::Distance(distance) => (0.0, -distance),
+        };
+
+        let length = start - end;
+        if length <= 0.0 || !length.is_finite() {
+            // Degenerate range: jump straight between the ends
+            return if element_top <= end { 1.0 } else { 0.0 };
+        }
+
+        ((start - element_top) / length).clamp(0.0, 1.0)
+    }
+}
+
+/// Handle to a scroll timeline created with [`use_scroll_timeline`]
+///
+/// Progress updates whenever the attached element moves in the viewport. On
+/// platforms without scroll events the handle can be driven with
+/// [`ScrubHandle::update`].
+#[derive(Clone, Copy)]
+pub struct ScrubHandle {
+    range: ScrollRange,
+    progress: Signal<f32>,
+    #[cfg(feature = "web")]
+    listener: Signal<Option<wasm_bindgen::closure::Closure<dyn FnMut()>>>,
+}
+
+impl ScrubHandle {
+    /// Current progress through the scroll range (0.0 to 1.0)
+    ///
+    /// Reading it subscribes the caller to progress changes.
+    pub fn progress(&self) -> f32 {
+        (self.progress)()
+    }
+
+    /// The range this timeline maps to progress
+    pub fn range(&self) -> ScrollRange {
+        self.range
+    }
+
+    /// Recompute progress from an element position
+    pub fn update(&mut self, metrics: ScrollMetrics) {
+        let progress = self.range.progress(metrics);
+        if (progress - *self.progress.peek()).abs() > f32::EPSILON {
+            self.progress.set(progress);
+        }
+    }
+
+    /// Track a mounted element, updating progress on every window scroll event
+    ///
+    /// Call from the element's `onmounted` handler. Attaching again replaces the
+    /// previously tracked element.
+    #[cfg(feature = "web")]
+    pub fn attach(&mut self, mounted: &MountedData) {
+        use wasm_bindgen::JsCast;
+        use wasm_bindgen::closure::Closure;
+
+        let Some(element) = mounted.downcast::<web_sys::Element>().cloned() else {
+            return;
+        };
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        self.detach();
+
+        let mut handle = *self;
+        let mut measure = move || {
+            let Some(window) = web_sys::window() else {
+                return;
+            };
+            let rect = element.get_bounding_client_rect();
+            let viewport_height = window
+                .inner_height()
+                .ok()
+                .and_then(|height| height.as_f64())
+                .unwrap_or_default();
+            handle.update(ScrollMetrics {
+                element_top: rect.top() as f32,
+                element_height: rect.height() as f32,
+                viewport_height: viewport_height as f32,
+            });
+        };
+        measure();
+
+        let listener = Closure::<dyn FnMut()>::new(measure);
+        if window
+            .add_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+            .is_ok()
+        {
+            self.listener.set(Some(listener));
+        }
+    }
+
+    /// Track a mounted element
+    ///
+    /// Scroll events are only available on the web; elsewhere drive the timeline
+    /// with [`ScrubHandle::update`].
+    #[cfg(not(feature = "web"))]
+    pub fn attach(&mut self, _mounted: &MountedData) {}
+
+    /// Stop listening to scroll events
+    #[cfg(feature = "web")]
+    fn detach(&mut self) {
+        use wasm_bindgen::JsCast;
+
+        // The signal may already be gone if the whole scope is being dropped
+        let Some(listener) = self
+            .listener
+            .try_write()
+            .ok()
+            .and_then(|mut listener| listener.take())
+        else {
+            return;
+        };
+        // Outside a browser web-sys panics rather than finding no window
+        if !cfg!(target_arch = "wasm32") {
+            return;
+        }
+        if let Some(window) = web_sys::window() {
+            let _ = window
+                .remove_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref());
+        }
+    }
+}
+
+/// Create a scroll timeline for an element
+///
+/// Attach the element with [`ScrubHandle::attach`] from its `onmounted` handler
+/// and bind animations to it with [`use_scrub`]. The scroll
+/// listener is removed when the component unmounts.
+pub fn use_scroll_timeline(range: ScrollRange) -> ScrubHandle {
+    let progress = use_signal(|| 0.0f32);
+    #[cfg(feature = "web")]
+    let listener = use_signal(|| None);
+
+    let handle = ScrubHandle {
+        range,
+        progress,
+        #[cfg(feature = "web")]
+        listener,
+    };
+
+    #[cfg(feature = "web")]
+    {
+        let mut handle = handle;
+        use_drop(move || handle.detach());
+    }
+
+    handle
+}
+
+/// Drive `motion` from a scroll timeline
+///
+/// The keyframe track is sampled at the timeline's progress whenever it
+/// changes, and again after a render passing other keyframes; no time-based
+/// playback happens. Like other hooks, call it unconditionally from the
+/// component body.
+pub fn use_scrub<T: Animatable>(
+    mut motion: MotionValue<T>,
+    handle: ScrubHandle,
+    keyframes: KeyframeAnimation<T>,
+) {
+    let mut track = keyframes;
+    track.apply_eased_ranges();
+
+    // Keyframes have no equality to react to, so every render samples the
+    // track it passed
+    let renders = use_hook(|| Rc::new(Cell::new(0u64)));
+    renders.set(renders.get() + 1);
+
+    use_effect(use_reactive((&renders.get(),), move |_| {
+        let value = track.sample(handle.progress());
+        let current = motion.peek();
+        // Both ways round, as types such as `Color` clamp differences at zero
+        if value.sub(&current).magnitude() > T::epsilon()
+            || current.sub(&value).magnitude() > T::epsilon()
+        {
+            motion.set(value);
+        }
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(element_top: f32) -> ScrollMetrics {
+        ScrollMetrics {
+            element_top,
+            element_height: 200.0,
+            viewport_height: 800.0,
+        }
+    }
+
+    #[test]
+    fn test_cover_range() {
+        let range = ScrollRange::Cover;
+        assert_eq!(range.progress(metrics(800.0)), 0.0);
+        assert_eq!(range.progress(metrics(300.0)), 0.5);
+        assert_eq!(range.progress(metrics(-200.0)), 1.0);
+    }
+
+    #[test]
+    fn test_entry_and_exit_ranges() {
+        assert_eq!(ScrollRange::Entry.progress(metrics(700.0)), 0.5);
+        assert_eq!(ScrollRange::Entry.progress(metrics(600.0)), 1.0);
+        assert_eq!(ScrollRange::Exit.progress(metrics(0.0)), 0.0);
+        assert_eq!(ScrollRange::Exit.progress(metrics(-100.0)), 0.5);
+    }
+
+    #[test]
+    fn test_distance_range() {
+        let range = ScrollRange::Distance(120.0);
+        assert_eq!(range.progress(metrics(0.0)), 0.0);
+        assert_eq!(range.progress(metrics(-60.0)), 0.5);
+        assert_eq!(range.progress(metrics(-120.0)), 1.0);
+    }
+
+    #[test]
+    fn test_progress_is_clamped_outside_range() {
+        let range = ScrollRange::Cover;
+        assert_eq!(range.progress(metrics(5000.0)), 0.0);
+        assert_eq!(range.progress(metrics(-5000.0)), 1.0);
+
+        // A zero-length range jumps between its ends
+        let range = ScrollRange::Distance(0.0);
+        assert_eq!(range.progress(metrics(10.0)), 0.0);
+        assert_eq!(range.progress(metrics(-10.0)), 1.0);
+    }
+
+    #[test]
+    fn test_timeline_unmounts_without_a_browser() {
+        fn app() -> Element {
+            let mut timeline = use_scroll_timeline(ScrollRange::Cover);
+            use_hook(move || timeline.update(metrics(300.0)));
+            rsx! { "{timeline.progress()}" }
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        // Unmounting detaches the scroll listener
+        drop(dom);
+    }
+
+    #[test]
+    fn test_scrub_honors_keyframes_of_later_renders() {
+        type State = (ScrubHandle, Signal<f32>, MotionValue<f32>);
+        thread_local! {
+            static STATE: Cell<Option<State>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let timeline = use_scroll_timeline(ScrollRange::Distance(120.0));
+            let end = use_signal(|| 56.0f32);
+            let height = crate::use_motion(96.0f32);
+            use_scrub(
+                height,
+                timeline,
+                KeyframeAnimation::new().at(0.0, 96.0).at(1.0, end()),
+            );
+            STATE.set(Some((timeline, end, height)));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some((mut timeline, mut end, height)) = STATE.get() else {
+            unreachable!("the app stores its state");
+        };
+        let settle = |dom: &mut VirtualDom| {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        };
+        settle(&mut dom);
+        assert_eq!(dom.in_runtime(|| height.peek()), 96.0);
+
+        dom.in_runtime(|| timeline.update(metrics(-60.0)));
+        settle(&mut dom);
+        assert_eq!(dom.in_runtime(|| height.peek()), 76.0);
+
+        // Other keyframes passed on a later render apply without another scroll
+        dom.in_runtime(|| end.set(16.0));
+        // One pass re-renders with the new keyframes, the next runs the effect
+        settle(&mut dom);
+        settle(&mut dom);
+        assert_eq!(dom.in_runtime(|| height.peek()), 56.0);
+    }
+
+    #[test]
+    fn test_keyframes_sampled_at_progress() {
+        let track = KeyframeAnimation::new().at(0.0, 96.0f32).at(1.0, 56.0);
+        let progress = ScrollRange::Distance(120.0).progress(metrics(-60.0));
+        assert_eq!(track.sample(progress), 76.0);
+    }
+}
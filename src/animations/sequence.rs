@@ -3,24 +3,102 @@
 //! Provides support for running a series of animations in sequence,
 //! where each animation starts when the previous one completes.
 
-use std::sync::{Arc, Mutex};
+use instant::Duration;
 
 use dioxus::signals::Writable;
 use tracing::{debug, warn};
 
-use crate::animation::{Animation, AnimationState};
+use crate::animation::{Animation, AnimationState, Callback, MaybeSend, boxed};
 use crate::{Animatable, MotionValue};
 
+/// What a sequence step does
+enum StepKind<T: Animatable> {
+    /// Run an animation
+    Animate(Box<dyn Animation<Value = T>>),
+    /// Hold the current value for a duration (seconds)
+    Wait { duration: f32, elapsed: f32 },
+}
+
 /// A step in an animation sequence
 pub struct AnimationStep<T: Animatable> {
-    /// The animation for this step
-    animation: Box<dyn Animation<Value = T>>,
+    /// What this step does
+    kind: StepKind<T>,
     /// Whether this step has started
     started: bool,
     /// Whether this step has completed
     completed: bool,
 }
 
+impl<T: Animatable> AnimationStep<T> {
+    fn new(kind: StepKind<T>) -> Self {
+        Self {
+            kind,
+            started: false,
+            completed: false,
+        }
+    }
+
+    /// Advance the step; `current` is the value the sequence is holding
+    fn update(&mut self, dt: f32, current: T) -> (AnimationState, T, T) {
+        match &mut self.kind {
+            StepKind::Animate(animation) => animation.update(dt),
+            StepKind::Wait { duration, elapsed } => {
+                *elapsed += dt;
+                let state = if *elapsed >= *duration {
+                    AnimationState::Completed
+                } else {
+                    AnimationState::Active
+                };
+                (state, current, T::zero())
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        match &mut self.kind {
+            StepKind::Animate(animation) => animation.reset(),
+            StepKind::Wait { elapsed, .. } => *elapsed = 0.0,
+        }
+    }
+
+    fn remaining_time(&self) -> Option<Duration> {
+        match &self.kind {
+            StepKind::Animate(animation) => animation.remaining_time(),
+            StepKind::Wait { duration, elapsed } => {
+                Some(Duration::from_secs_f32((duration - elapsed).max(0.0)))
+            }
+        }
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        match &self.kind {
+            StepKind::Animate(animation) => animation.total_duration(),
+            StepKind::Wait { duration, .. } => Some(Duration::from_secs_f32(*duration)),
+        }
+    }
+
+    /// Expected play time of a step that hasn't started yet
+    ///
+    /// Exact for duration-based steps; a spring's is its settle estimate.
+    fn expected_duration(&self) -> Option<Duration> {
+        self.total_duration().or_else(|| self.remaining_time())
+    }
+
+    /// How far the step has come, from `0.0` to `1.0`
+    fn progress(&self) -> f32 {
+        if self.completed {
+            return 1.0;
+        }
+        match &self.kind {
+            StepKind::Animate(animation) => animation.progress().unwrap_or(0.0),
+            StepKind::Wait { duration, elapsed } if *duration > 0.0 => {
+                (elapsed / duration).min(1.0)
+            }
+            StepKind::Wait { .. } => 1.0,
+        }
+    }
+}
+
 /// A sequence of animations that run one after another
 pub struct AnimationSequence<T: Animatable> {
     /// Steps in the sequence
@@ -33,8 +111,10 @@ pub struct AnimationSequence<T: Animatable> {
     velocity: T,
     /// Whether the sequence is active
     is_active: bool,
+    /// Each step's share of the sequence's play time, measured on start
+    weights: Vec<f32>,
     /// Completion callback
-    pub on_complete: Option<Arc<Mutex<dyn FnMut() + Send>>>,
+    pub on_complete: Option<Callback>,
 }
 
 impl<T: Animatable> Default for AnimationSequence<T> {
@@ -45,6 +125,7 @@ impl<T: Animatable> Default for AnimationSequence<T> {
             current: T::zero(),
             velocity: T::zero(),
             is_active: false,
+            weights: Vec::new(),
             on_complete: None,
         }
     }
@@ -57,18 +138,29 @@ impl<T: Animatable> AnimationSequence<T> {
     }
 
     /// Add an animation to the sequence
-    pub fn then<A: Animation<Value = T> + Send + 'static>(mut self, animation: A) -> Self {
-        self.steps.push(AnimationStep {
-            animation: Box::new(animation),
-            started: false,
-            completed: false,
-        });
+    pub fn then<A: Animation<Value = T>>(mut self, animation: A) -> Self {
+        self.steps
+            .push(AnimationStep::new(StepKind::Animate(Box::new(animation))));
+        self
+    }
+
+    /// Hold the current value for a duration before the next step
+    pub fn wait(mut self, duration: Duration) -> Self {
+        self.steps.push(AnimationStep::new(StepKind::Wait {
+            duration: duration.as_secs_f32(),
+            elapsed: 0.0,
+        }));
         self
     }
 
+    /// Start holding from `value`, so a leading wait doesn't jump to zero
+    pub(crate) fn seed(&mut self, value: T) {
+        self.current = value;
+    }
+
     /// Set a completion callback
-    pub fn on_complete<F: FnMut() + Send + 'static>(mut self, callback: F) -> Self {
-        self.on_complete = Some(Arc::new(Mutex::new(callback)));
+    pub fn on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.on_complete = Some(boxed(callback));
         self
     }
 
@@ -81,14 +173,40 @@ impl<T: Animatable> AnimationSequence<T> {
             for step in &mut self.steps {
                 step.completed = false;
             }
+            self.measure_steps();
         } else {
             warn!("Attempting to start empty animation sequence");
         }
         self
     }
 
+    /// Weigh every step by its expected play time
+    ///
+    /// Steps that can't tell, such as custom animations, weigh as much as the
+    /// average step that can; if none can, every step weighs the same.
+    fn measure_steps(&mut self) {
+        let expected: Vec<Option<f32>> = self
+            .steps
+            .iter()
+            .map(|step| {
+                step.expected_duration()
+                    .map(|duration| duration.as_secs_f32())
+            })
+            .collect();
+        let known: Vec<f32> = expected.iter().flatten().copied().collect();
+        let average = known.iter().sum::<f32>() / known.len().max(1) as f32;
+        let fallback = if average > 0.0 { average } else { 1.0 };
+        self.weights = expected
+            .into_iter()
+            .map(|weight| weight.unwrap_or(fallback))
+            .collect();
+        if self.weights.iter().sum::<f32>() <= 0.0 {
+            self.weights.fill(1.0);
+        }
+    }
+
     /// Build an animation for use with a MotionValue
-    pub fn build(self) -> Box<dyn Animation<Value = T> + Send + 'static> {
+    pub fn build(self) -> Box<dyn Animation<Value = T>> {
         Box::new(self)
     }
 }
@@ -119,7 +237,7 @@ impl<T: Animatable> Animation for AnimationSequence<T> {
 
         debug!("Updating step {} with dt: {}", self.current_step, dt);
 
-        let (state, value, velocity) = current_step.animation.update(dt);
+        let (state, value, velocity) = current_step.update(dt, self.current);
 
         self.current = value;
         self.velocity = velocity;
@@ -144,11 +262,9 @@ impl<T: Animatable> Animation for AnimationSequence<T> {
                 self.is_active = false;
 
                 // Execute completion callback
-                if let Some(on_complete) = &self.on_complete {
+                if let Some(on_complete) = self.on_complete.take() {
                     debug!("Executing completion callback");
-                    if let Ok(mut callback) = on_complete.lock() {
-                        callback();
-                    }
+                    on_complete();
                 }
                 return (AnimationState::Completed, self.current, T::zero());
             }
@@ -170,12 +286,13 @@ impl<T: Animatable> Animation for AnimationSequence<T> {
     fn reset(&mut self) {
         // Reset all steps
         for step in &mut self.steps {
-            step.animation.reset();
+            step.reset();
             step.started = false;
             step.completed = false;
         }
 
         self.current_step = 0;
+        self.measure_steps();
 
         // Start the first step if there is one
         if !self.steps.is_empty() {
@@ -188,6 +305,39 @@ impl<T: Animatable> Animation for AnimationSequence<T> {
     fn is_active(&self) -> bool {
         self.is_active
     }
+
+    fn remaining_time(&self) -> Option<Duration> {
+        if !self.is_active {
+            return Some(Duration::ZERO);
+        }
+        let mut steps = self.steps.iter().skip(self.current_step);
+        let current = steps
+            .next()
+            .map_or(Some(Duration::ZERO), |step| step.remaining_time())?;
+        steps.try_fold(current, |total, step| Some(total + step.total_duration()?))
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.steps.iter().try_fold(Duration::ZERO, |total, step| {
+            Some(total + step.total_duration()?)
+        })
+    }
+
+    /// Completed steps plus the current step's progress, each weighted by its
+    /// expected play time
+    fn progress(&self) -> Option<f32> {
+        if !self.is_active {
+            return Some(1.0);
+        }
+        if self.weights.len() != self.steps.len() {
+            return None;
+        }
+        let step = self.steps.get(self.current_step)?;
+        let done: f32 = self.weights[..self.current_step].iter().sum();
+        let current = self.weights[self.current_step] * step.progress();
+        let total: f32 = self.weights.iter().sum();
+        Some(((done + current) / total).clamp(0.0, 1.0))
+    }
 }
 
 /// Helper function to create a new animation sequence
@@ -199,7 +349,7 @@ pub fn sequence<T: Animatable>() -> AnimationSequence<T> {
 pub struct SequenceBuilder<T: Animatable> {
     motion: MotionValue<T>,
     sequence: AnimationSequence<T>,
-    completion_callback: Arc<Mutex<Vec<Box<dyn FnOnce() + Send>>>>,
+    completion_callbacks: Vec<Callback>,
 }
 
 impl<T: Animatable> SequenceBuilder<T> {
@@ -208,51 +358,163 @@ impl<T: Animatable> SequenceBuilder<T> {
         Self {
             motion,
             sequence: AnimationSequence::new(),
-            completion_callback: Arc::new(Mutex::new(Vec::new())),
+            completion_callbacks: Vec::new(),
         }
     }
 
     /// Add an animation to the sequence
-    pub fn then<A: Animation<Value = T> + Send + 'static>(mut self, animation: A) -> Self {
+    pub fn then<A: Animation<Value = T>>(mut self, animation: A) -> Self {
         self.sequence = self.sequence.then(animation);
         self
     }
 
+    /// Pause for a duration, holding the current value, before the next step
+    pub fn wait(mut self, duration: Duration) -> Self {
+        self.sequence = self.sequence.wait(duration);
+        self
+    }
+
     /// Add completion callback
-    pub fn on_complete<F: FnOnce() + Send + 'static>(self, callback: F) -> Self {
-        self.completion_callback
-            .lock()
-            .expect("Failed to lock completion callback mutex")
-            .push(Box::new(callback));
+    pub fn on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.completion_callbacks.push(boxed(callback));
         self
     }
 
     /// Start the sequence animation
     pub fn start(mut self) -> MotionValue<T> {
-        // Apply the completion callback if provided
-        if !self
-            .completion_callback
-            .lock()
-            .expect("Failed to lock completion callback mutex")
-            .is_empty()
         {
-            let callback_arc = Arc::new(Mutex::new(move || {
-                for callback in self
-                    .completion_callback
-                    .lock()
-                    .expect("Failed to lock completion callback mutex")
-                    .drain(..)
-                {
-                    callback();
-                }
-            }));
-            self.sequence.on_complete = Some(callback_arc);
+            let mut engine = self.motion.engine.write();
+            engine.apply_sequence(self.sequence.start());
+            // Like the sequence's own callback, these only fire if it completes
+            for callback in self.completion_callbacks {
+                engine.add_rest_callback(callback);
+            }
         }
-
-        self.motion
-            .engine
-            .write()
-            .apply_sequence(self.sequence.start());
+        self.motion.complete_without_frames();
+        self.motion.run_ready_callbacks();
         self.motion
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+    use crate::animations::spring::Spring;
+    use crate::animations::tween::Tween;
+
+    /// Progress of `engine` after each of `frames` 10ms frames
+    fn progress_over(engine: &mut AnimationEngine<f32>, frames: usize) -> Vec<f32> {
+        (0..frames)
+            .map(|_| {
+                engine.update(0.01);
+                engine.progress().unwrap_or(1.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wait_holds_value_between_steps() {
+        let tween = Tween::new().duration(Duration::from_millis(100));
+        let sequence = sequence()
+            .then(tween.create_animation(0.0f32, 100.0))
+            .wait(Duration::from_millis(300))
+            .then(tween.create_animation(100.0, 0.0));
+
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.apply_sequence(sequence.start());
+
+        let mut values = Vec::new();
+        while engine.update(0.01) {
+            values.push(engine.get());
+        }
+
+        // About 300ms spent holding at the end of the first step
+        let held = values.iter().filter(|value| **value == 100.0).count();
+        assert!((29..=32).contains(&held), "held for {held} frames");
+        assert_eq!(engine.get(), 0.0);
+    }
+
+    #[test]
+    fn test_leading_wait_holds_starting_value() {
+        let sequence = sequence()
+            .wait(Duration::from_millis(50))
+            .then(Tween::new().create_animation(40.0f32, 80.0));
+
+        let mut engine = AnimationEngine::new(40.0f32);
+        engine.apply_sequence(sequence.start());
+        engine.update(0.01);
+        assert_eq!(engine.get(), 40.0);
+    }
+
+    #[test]
+    fn test_progress_weighs_steps_by_duration() {
+        // 100ms, then 200ms, then 100ms: 400ms in all
+        let tween = Tween::new().duration(Duration::from_millis(100));
+        let sequence = sequence()
+            .then(tween.create_animation(0.0f32, 100.0))
+            .wait(Duration::from_millis(200))
+            .then(tween.create_animation(100.0, 0.0));
+
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.apply_sequence(sequence.start());
+        assert_eq!(engine.progress(), Some(0.0));
+
+        let progress = progress_over(&mut engine, 45);
+        // Halfway through the first step, the wait and the last step
+        for (frame, expected) in [(4, 0.125), (19, 0.5), (35, 0.875)] {
+            let actual = progress[frame];
+            assert!(
+                (actual - expected).abs() < 0.04,
+                "{actual} after {}ms",
+                (frame + 1) * 10
+            );
+        }
+        assert!(progress.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(progress.last(), Some(&1.0));
+        assert_eq!(engine.progress(), None);
+    }
+
+    #[test]
+    fn test_progress_estimates_spring_steps() {
+        let spring = Spring::default();
+        let settle = spring.settle_time(100.0, 0.0, f32::epsilon() * 1000.0);
+        let Some(settle) = settle else {
+            unreachable!("a damped spring settles");
+        };
+        let tween = Tween::new().duration(settle);
+        let sequence = sequence()
+            .then(tween.create_animation(0.0f32, 100.0))
+            .then(spring.create_animation(100.0, 0.0, 0.0));
+
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.apply_sequence(sequence.start());
+
+        // The spring is expected to take as long as the tween
+        let frames = (settle.as_secs_f32() / 0.01).round() as usize;
+        let progress = progress_over(&mut engine, frames + 2);
+        let at_handover = progress[frames + 1];
+        assert!((at_handover - 0.5).abs() < 0.05, "{at_handover}");
+
+        let mut previous = at_handover;
+        let mut midway = None;
+        for frame in 0.. {
+            engine.update(0.01);
+            let Some(progress) = engine.progress() else {
+                break;
+            };
+            assert!(progress >= previous, "{progress} after {previous}");
+            previous = progress;
+            if frame == frames / 2 {
+                midway = Some(progress);
+            }
+        }
+        let Some(midway) = midway else {
+            unreachable!("the spring should run past its midpoint");
+        };
+        assert!(
+            (midway - 0.75).abs() < 0.1,
+            "{midway} halfway through the spring"
+        );
+    }
+}
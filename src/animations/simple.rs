@@ -0,0 +1,353 @@
+//! Lightweight scalar motion for leaf components
+//!
+//! [`use_simple_motion`] animates a single `f32` for components that render
+//! hundreds of small animated things, e.g. the bars of a chart or the dots of a
+//! loader. It shares the driver with every [`MotionValue`] but leaves out what
+//! a full engine carries: no callbacks, events, tokens, sequences or style
+//! binding. The animation state lives outside the reactive system, and the
+//! value's signal is written only on frames where the number changes, so a
+//! settled value costs nothing but a check per frame.
+//!
+//! Like a [`MotionValue`], it lands on its target right away where no frame
+//! will come, such as server-side rendering.
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Bar(height: f32) -> Element {
+//!     let mut bar = use_simple_motion(0.0);
+//!     use_effect(use_reactive!(|height| bar.animate_to(height)));
+//!
+//!     rsx! {
+//!         div { style: "height: {bar.get()}px" }
+//!     }
+//! }
+//! ```
+//!
+//! # Cost
+//!
+//! `cargo test --release --lib -- --ignored bench_simple_vs_full` times one
+//! frame of 500 mounted bars springing to their heights, from the driver tick
+//! through the re-render, once built from `use_simple_motion` and once from
+//! `use_motion`. It shows the relative cost of the two: the bench fails,
+//! printing both times, unless the simple bars are cheaper.
+//!
+//! [`MotionValue`]: crate::MotionValue
+
+use dioxus::prelude::*;
+
+use crate::animation::{Animation, AnimationState};
+use crate::animations::spring::{Spring, SpringAnimation};
+use crate::animations::time_scale::TimeScale;
+use crate::animations::tween::{Tween, TweenAnimation};
+use crate::driver;
+
+/// The animation a [`SimpleMotion`] is running
+enum Run {
+    Spring(SpringAnimation<f32>),
+    Tween(TweenAnimation<f32>),
+}
+
+impl Run {
+    fn update(&mut self, dt: f32) -> (AnimationState, f32, f32) {
+        match self {
+            Self::Spring(spring) => spring.update(dt),
+            Self::Tween(tween) => tween.update(dt),
+        }
+    }
+}
+
+/// Non-reactive state of a [`SimpleMotion`]
+#[derive(Default)]
+struct SimpleState {
+    run: Option<Run>,
+    /// Velocity of the latest frame, carried into the next spring
+    velocity: f32,
+}
+
+/// A lightweight animated `f32`, created by [`use_simple_motion`]
+///
+/// Reading the value with [`get`](Self::get) subscribes the component like any
+/// signal; whether it is animating is not reactive.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SimpleMotion {
+    value: Signal<f32>,
+    state: CopyValue<SimpleState>,
+}
+
+impl SimpleMotion {
+    /// The current value, subscribing the component to its changes
+    pub fn get(&self) -> f32 {
+        *self.value.read()
+    }
+
+    /// The current value without subscribing
+    pub fn peek(&self) -> f32 {
+        *self.value.peek()
+    }
+
+    /// Jump to `value`, stopping any animation
+    pub fn set(&mut self, value: f32) {
+        self.stop();
+        self.value.set(value);
+    }
+
+    /// Animate to `target` with the snappy spring `f32` values prefer
+    pub fn animate_to(&mut self, target: f32) {
+        self.spring_to(target, Spring::snappy());
+    }
+
+    /// Spring to `target`, keeping the current velocity
+    pub fn spring_to(&mut self, target: f32, spring: Spring) {
+        let velocity = self.state.peek().velocity;
+        let animation = spring.create_animation(self.peek(), target, velocity);
+        self.start(Run::Spring(animation), target);
+    }
+
+    /// Tween to `target` from the current value
+    pub fn tween_to(&mut self, target: f32, tween: Tween) {
+        let animation = tween.create_animation(self.peek(), target);
+        self.start(Run::Tween(animation), target);
+        self.state.write().velocity = 0.0;
+    }
+
+    /// Run `run` toward `target`, or land on `target` right away when no frame
+    /// will come, e.g. while rendering on the server
+    fn start(&mut self, run: Run, target: f32) {
+        if driver::has_frames() {
+            self.state.write().run = Some(run);
+        } else {
+            self.set(target);
+        }
+    }
+
+    /// Stop any animation, leaving the value where it is
+    pub fn stop(&mut self) {
+        let mut state = self.state.write();
+        state.run = None;
+        state.velocity = 0.0;
+    }
+
+    /// Whether an animation is running
+    pub fn is_animating(&self) -> bool {
+        self.state.read().run.is_some()
+    }
+
+    /// Advance the running animation by `dt` seconds; returns whether it is
+    /// still running
+    fn advance(mut self, dt: f32) -> bool {
+        let Ok(mut state) = self.state.try_write() else {
+            // The component is being torn down
+            return false;
+        };
+        let Some(run) = state.run.as_mut() else {
+            return false;
+        };
+        let (status, value, velocity) = run.update(dt);
+        let is_active = status == AnimationState::Active;
+        if is_active {
+            state.velocity = velocity;
+        } else {
+            state.run = None;
+            state.velocity = 0.0;
+        }
+        drop(state);
+
+        if self.value.try_peek().is_ok_and(|current| *current != value) {
+            self.value.set(value);
+        }
+        is_active
+    }
+}
+
+/// Create a lightweight animated `f32`
+///
+/// A cheaper alternative to [`use_motion`](crate::use_motion) for scalar values
+/// that only need springs and tweens: see the [module docs](self) for what it
+/// leaves out and what that saves. It follows the enclosing
+/// [`MotionTimeScale`] like any other motion value.
+///
+/// [`MotionTimeScale`]: crate::animations::time_scale::MotionTimeScale
+pub fn use_simple_motion(initial: f32) -> SimpleMotion {
+    let id = use_hook(driver::next_id);
+    let time_scale = try_use_context::<TimeScale>();
+    let value = use_signal(|| initial);
+    let state = use_hook(|| CopyValue::new(SimpleState::default()));
+    let motion = SimpleMotion { value, state };
+
+    use_hook(move || {
+        driver::register(
+            id,
+            None,
+            Box::new(move |dt| {
+                let dt = time_scale.as_ref().map_or(dt, |scale| scale.scale(dt));
+                motion.advance(dt)
+            }),
+        )
+    });
+    use_drop(move || driver::unregister(id));
+
+    motion
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+    use crate::Duration;
+    use std::cell::{Cell, RefCell};
+
+    #[test]
+    fn test_state_is_smaller_than_an_engine() {
+        assert!(size_of::<SimpleState>() * 2 < size_of::<AnimationEngine<f32>>());
+    }
+
+    #[test]
+    fn test_settled_values_stop_rerendering() {
+        thread_local! {
+            static MOTION: RefCell<Option<SimpleMotion>> = const { RefCell::new(None) };
+            static RENDERS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        fn app() -> Element {
+            let motion = use_simple_motion(0.0);
+            RENDERS.set(RENDERS.get() + 1);
+            MOTION.set(Some(motion));
+            rsx! { "{motion.get()}" }
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(mut motion) = MOTION.take() else {
+            unreachable!("the app stores its motion");
+        };
+        let frame = move |dom: &mut VirtualDom| {
+            let is_active = dom.in_runtime(|| motion.advance(1.0 / 60.0));
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+            is_active
+        };
+
+        dom.in_runtime(|| motion.tween_to(10.0, Tween::new().duration(Duration::from_millis(100))));
+        let mut frames = 0;
+        while frame(&mut dom) {
+            frames += 1;
+            assert!(frames < 60, "the tween never completed");
+        }
+        assert_eq!(dom.in_runtime(|| motion.peek()), 10.0);
+        assert!(!dom.in_runtime(|| motion.is_animating()));
+
+        // Idle frames leave the component alone
+        let renders = RENDERS.get();
+        for _ in 0..10 {
+            assert!(!frame(&mut dom));
+        }
+        assert_eq!(RENDERS.get(), renders);
+
+        // A spring started mid-flight keeps the velocity
+        dom.in_runtime(|| motion.animate_to(0.0));
+        frame(&mut dom);
+        frame(&mut dom);
+        dom.in_runtime(|| motion.spring_to(20.0, Spring::default()));
+        let velocity = dom.in_runtime(|| motion.state.read().velocity);
+        assert!(velocity < 0.0);
+        dom.in_runtime(|| motion.set(5.0));
+        assert!(!dom.in_runtime(|| motion.is_animating()));
+        assert_eq!(dom.in_runtime(|| motion.peek()), 5.0);
+    }
+
+    #[test]
+    fn test_lands_on_the_target_without_frames() {
+        thread_local! {
+            static MOTION: Cell<Option<SimpleMotion>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let motion = use_simple_motion(0.0);
+            MOTION.set(Some(motion));
+            rsx! {}
+        }
+
+        // No runtime entered: nothing will advance the driver
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(mut motion) = MOTION.get() else {
+            unreachable!("the app stores its motion");
+        };
+
+        dom.in_runtime(|| motion.animate_to(10.0));
+        assert_eq!(dom.in_runtime(|| motion.peek()), 10.0);
+        assert!(!dom.in_runtime(|| motion.is_animating()));
+
+        dom.in_runtime(|| motion.tween_to(20.0, Tween::new()));
+        assert_eq!(dom.in_runtime(|| motion.peek()), 20.0);
+        assert!(!dom.in_runtime(|| motion.is_animating()));
+    }
+
+    /// Time per frame of 500 mounted bars springing to their heights, through
+    /// the driver from compute to re-render: simple or full motion values
+    fn mounted_bars(simple: bool) -> std::time::Duration {
+        const BARS: usize = 500;
+        const FRAMES: u32 = 60;
+        thread_local! {
+            static SIMPLE: Cell<bool> = const { Cell::new(false) };
+        }
+
+        #[component]
+        fn SimpleBar(index: usize) -> Element {
+            let mut bar = use_simple_motion(0.0);
+            use_hook(|| bar.animate_to(index as f32));
+            rsx! {
+                div { style: "height: {bar.get()}px" }
+            }
+        }
+
+        #[component]
+        fn FullBar(index: usize) -> Element {
+            let mut bar = crate::use_motion(0.0f32);
+            use_hook(|| bar.animate_to(index as f32));
+            rsx! {
+                div { style: "height: {bar.get()}px" }
+            }
+        }
+
+        fn bars() -> Element {
+            rsx! {
+                for index in 0..BARS {
+                    if SIMPLE.get() {
+                        SimpleBar { key: "{index}", index }
+                    } else {
+                        FullBar { key: "{index}", index }
+                    }
+                }
+            }
+        }
+
+        SIMPLE.set(simple);
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(bars);
+        dom.rebuild_in_place();
+        let start = std::time::Instant::now();
+        for _ in 0..FRAMES {
+            dom.in_runtime(|| crate::driver::step(1.0 / 60.0));
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+        start.elapsed() / FRAMES
+    }
+
+    /// Run with `cargo test --release --lib -- --ignored bench_simple`, see the
+    /// module docs
+    #[test]
+    #[ignore]
+    fn bench_simple_vs_full() {
+        let simple = mounted_bars(true);
+        let full = mounted_bars(false);
+        assert!(simple < full, "simple {simple:?}, full {full:?}");
+    }
+}
@@ -0,0 +1,213 @@
+//! Magnetic detents for sliders and knobs
+//!
+//! While dragging, a [`MagneticSnap`] pulls the pointer-following value towards
+//! the nearest detent, so the thumb feels drawn to it without getting stuck.
+//! On release it springs fully onto the nearest detent and reports which one
+//! it settled on.
+//!
+//! # Example
+//!
+//! A volume slider with five detents:
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Volume() -> Element {
+//!     let mut volume = use_motion(50.0f32);
+//!     let mut dragging = use_signal(|| false);
+//!     let snap = use_hook(|| {
+//!         MagneticSnap::new(vec![0.0, 25.0, 50.0, 75.0, 100.0], 0.4)
+//!             .on_snap(|index| println!("Volume level {index}"))
+//!     });
+//!     let (track, release) = (snap.clone(), snap.clone());
+//!
+//!     rsx! {
+//!         div {
+//!             style: "width: 300px; height: 24px; background: #ddd;",
+//!             onmousedown: move |_| dragging.set(true),
+//!             onmousemove: move |event| {
+//!                 if dragging() {
+//!                     let pointer = event.element_coordinates().x as f32 / 3.0;
+//!                     track.track(&mut volume, pointer.clamp(0.0, 100.0));
+//!                 }
+//!             },
+//!             onmouseup: move |_| {
+//!                 dragging.set(false);
+//!                 release.release(&mut volume);
+//!             },
+//!             div { style: "width: {volume.get()}%; height: 100%; background: #36f;" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::sync::Arc;
+
+use crate::MotionValue;
+use crate::animation::{AnimationConfig, AnimationMode};
+use crate::animations::spring::Spring;
+
+/// Detents that attract a dragged value and catch it on release
+#[derive(Clone)]
+pub struct MagneticSnap {
+    detents: Vec<f32>,
+    attraction: f32,
+    spring: Spring,
+    on_snap: Option<Arc<dyn Fn(usize) + Send + Sync>>,
+}
+
+impl MagneticSnap {
+    /// Create detents at `detents` with the given `attraction`
+    ///
+    /// `attraction` is the fraction of the distance to the nearest detent the
+    /// value is pulled while dragging, right next to it: `0.0` follows the
+    /// pointer exactly and `1.0` sticks to the detent. It is clamped to that
+    /// range.
+    pub fn new(detents: Vec<f32>, attraction: f32) -> Self {
+        Self {
+            detents,
+            attraction: attraction.clamp(0.0, 1.0),
+            spring: Spring::default(),
+            on_snap: None,
+        }
+    }
+
+    /// Set the spring used to snap onto a detent on release
+    pub fn spring(mut self, spring: Spring) -> Self {
+        self.spring = spring;
+        self
+    }
+
+    /// Run `callback` with the detent's index once a release lands on it
+    pub fn on_snap<F: Fn(usize) + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.on_snap = Some(Arc::new(callback));
+        self
+    }
+
+    /// The detent positions
+    pub fn detents(&self) -> &[f32] {
+        &self.detents
+    }
+
+    /// Index of the detent nearest to `position`, the first one on a tie
+    pub fn nearest(&self, position: f32) -> Option<usize> {
+        self.detents
+            .iter()
+            .enumerate()
+            .filter(|(_, detent)| !detent.is_nan())
+            .min_by(|(_, a), (_, b)| (*a - position).abs().total_cmp(&(*b - position).abs()))
+            .map(|(index, _)| index)
+    }
+
+    /// Where a value dragged to `pointer` is drawn to
+    ///
+    /// The pointer position moved towards the nearest detent by the attraction.
+    /// The pull fades out towards the midpoint to the next detent, so the value
+    /// passes from one detent's reach to the other's without a jump.
+    pub fn biased(&self, pointer: f32) -> f32 {
+        self.nearest(pointer).map_or(pointer, |index| {
+            let detent = self.detents[index];
+            let offset = pointer - detent;
+            let fade = self
+                .detents
+                .iter()
+                .map(|other| other - detent)
+                .filter(|gap| gap * offset > 0.0)
+                .map(f32::abs)
+                .min_by(f32::total_cmp)
+                .map_or(1.0, |gap| (1.0 - offset.abs() / (gap / 2.0)).max(0.0));
+            pointer - offset * self.attraction * fade
+        })
+    }
+
+    /// Follow the pointer while dragging, biased towards the nearest detent
+    ///
+    /// Call on every pointer move; the value jumps there without animation.
+    pub fn track(&self, motion: &mut MotionValue<f32>, pointer: f32) {
+        motion.set(self.biased(pointer));
+    }
+
+    /// Spring onto the detent nearest to the current value
+    ///
+    /// Returns the winning detent's index, or `None` without detents. The
+    /// `on_snap` callback runs once the value comes to rest on it.
+    pub fn release(&self, motion: &mut MotionValue<f32>) -> Option<usize> {
+        let index = self.nearest(motion.peek())?;
+        let mut config = AnimationConfig::new(AnimationMode::Spring(self.spring.clone()));
+        if let Some(on_snap) = self.on_snap.clone() {
+            config = config.with_on_complete(move || on_snap(index));
+        }
+        motion.animate_to_with_config(self.detents[index], config);
+        Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn volume() -> MagneticSnap {
+        MagneticSnap::new(vec![0.0, 25.0, 50.0, 75.0, 100.0], 0.4)
+    }
+
+    #[test]
+    fn test_nearest_detent() {
+        let snap = volume();
+        assert_eq!(snap.nearest(-10.0), Some(0));
+        assert_eq!(snap.nearest(30.0), Some(1));
+        assert_eq!(snap.nearest(63.0), Some(3));
+        assert_eq!(snap.nearest(500.0), Some(4));
+        // Halfway between two detents picks the first
+        assert_eq!(snap.nearest(37.5), Some(1));
+        assert_eq!(MagneticSnap::new(Vec::new(), 0.4).nearest(10.0), None);
+    }
+
+    #[test]
+    fn test_nearest_ignores_detent_order() {
+        let snap = MagneticSnap::new(vec![100.0, 0.0, 50.0], 0.4);
+        assert_eq!(snap.nearest(40.0), Some(2));
+        assert_eq!(snap.nearest(10.0), Some(1));
+    }
+
+    #[test]
+    fn test_pointer_is_biased_towards_nearest_detent() {
+        let snap = volume();
+        // 5 past 25 keeps 60% of the pull, 40% × 60% of the way back
+        assert!((snap.biased(30.0) - 28.8).abs() < 1e-5);
+        // Down towards 50 from above, up towards 75 from below
+        assert!((snap.biased(60.0) - 59.2).abs() < 1e-5);
+        assert!((snap.biased(70.0) - 71.2).abs() < 1e-5);
+        // Past the outer detents the pull never fades
+        assert!((snap.biased(110.0) - 106.0).abs() < 1e-5);
+        // On a detent nothing moves
+        assert_eq!(snap.biased(50.0), 50.0);
+    }
+
+    #[test]
+    fn test_attraction_bounds() {
+        let free = MagneticSnap::new(vec![0.0, 10.0], 0.0);
+        assert_eq!(free.biased(3.0), 3.0);
+
+        let sticky = MagneticSnap::new(vec![0.0, 10.0], 2.0);
+        assert!((sticky.biased(0.5) - 0.05).abs() < 1e-5);
+        assert_eq!(sticky.biased(-3.0), 0.0);
+
+        let none = MagneticSnap::new(Vec::new(), 0.5);
+        assert_eq!(none.biased(3.0), 3.0);
+    }
+
+    #[test]
+    fn test_biased_is_continuous_across_the_midpoint() {
+        let snap = volume();
+        assert_eq!(snap.biased(37.5), 37.5);
+        let mut previous = snap.biased(36.5);
+        for step in 1..=200 {
+            let value = snap.biased(36.5 + step as f32 * 0.01);
+            assert!(value >= previous, "moved backwards at step {step}");
+            assert!(value - previous < 0.02, "jumped at step {step}");
+            previous = value;
+        }
+    }
+}
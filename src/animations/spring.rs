@@ -3,9 +3,21 @@
 //! Provides a physical spring model for smooth, natural-looking animations.
 //! Based on Hooke's law with damping for realistic motion.
 
-use dioxus::signals::Writable;
+use std::fmt;
 
-use crate::animation::{Animation, AnimationState, AnimationTiming, LoopMode};
+use dioxus::signals::Writable;
+use instant::Duration;
+
+use crate::animation::timing::{Rounded, fmt_repeat};
+use crate::animation::{
+    Animation, AnimationState, AnimationTiming, AnimationToken, Callback, ConfigError, LoopMode,
+    MaybeSend, RepeatingCallback, boxed, check_non_negative, check_positive,
+};
+use crate::animations::bounce::BounceBounds;
+use crate::animations::cues::Cues;
+use crate::animations::noise::Noise;
+use crate::animations::power::{AmbientPolicy, AmbientReduction};
+use crate::core::{Approach, Playback};
 use crate::{Animatable, MotionValue};
 
 /// Spring animation with configurable physics
@@ -33,6 +45,29 @@ pub struct Spring {
     pub timing: AnimationTiming,
 }
 
+/// How heavy an animated object feels, as an alternative to picking a mass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Weight {
+    /// Quick to start and stop, mass 0.5
+    Light,
+    /// The default mass of 1.0
+    #[default]
+    Medium,
+    /// Slow to get going and to settle, mass 3.0
+    Heavy,
+}
+
+impl Weight {
+    /// The spring mass this weight stands for
+    pub fn mass(&self) -> f32 {
+        match self {
+            Self::Light => 0.5,
+            Self::Medium => 1.0,
+            Self::Heavy => 3.0,
+        }
+    }
+}
+
 impl Default for Spring {
     fn default() -> Self {
         Self {
@@ -45,12 +80,41 @@ impl Default for Spring {
     }
 }
 
+/// A short summary such as `Spring(ζ=0.5, f=1.59Hz)`, with the initial
+/// velocity, loops and delay when set
+impl fmt::Display for Spring {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Spring(ζ={}, f={}Hz",
+            Rounded(self.damping_ratio()),
+            Rounded(self.frequency())
+        )?;
+        if let Some(velocity) = self.initial_velocity {
+            write!(f, ", v={}", Rounded(velocity))?;
+        }
+        f.write_str(")")?;
+        fmt_repeat(f, self.timing.loop_mode, self.timing.delay)
+    }
+}
+
 impl Spring {
     /// Create a new spring with default parameters
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// A stiff, critically damped spring
+    ///
+    /// Settles quickly without overshooting, so values like opacity stay in range.
+    pub fn snappy() -> Self {
+        Self {
+            stiffness: 400.0,
+            damping: 40.0,
+            ..Self::default()
+        }
+    }
+
     /// Set the spring stiffness
     pub fn stiffness(mut self, stiffness: f32) -> Self {
         self.stiffness = stiffness.max(0.1);
@@ -69,12 +133,267 @@ impl Spring {
         self
     }
 
+    /// Set the mass from a perceptual weight
+    pub fn weight(self, weight: Weight) -> Self {
+        self.mass(weight.mass())
+    }
+
     /// Set the initial velocity
     pub fn initial_velocity(mut self, velocity: f32) -> Self {
         self.initial_velocity = Some(velocity);
         self
     }
 
+    /// The damping ratio ζ: below 1 the spring overshoots and oscillates, at 1
+    /// or above it settles without passing the target
+    pub fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// The natural frequency in Hz, how many times a second the spring would
+    /// swing without damping
+    pub fn frequency(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt() / std::f32::consts::TAU
+    }
+
+    /// Check that the spring parameters are finite and physically meaningful
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_positive("stiffness", self.stiffness)?;
+        check_non_negative("damping", self.damping)?;
+        check_positive("mass", self.mass)?;
+        match self.initial_velocity {
+            Some(value) if !value.is_finite() => Err(ConfigError::NonFinite {
+                parameter: "initial_velocity",
+                value,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Estimate how long the spring takes to settle
+    ///
+    /// Uses the analytic solution of the damped harmonic oscillator: the time for
+    /// the motion's envelope, starting `displacement` away from the target and
+    /// moving at `velocity`, to decay below `tolerance`. Returns `None` for an
+    /// undamped spring, which never settles.
+    pub fn settle_time(
+        &self,
+        displacement: f32,
+        velocity: f32,
+        tolerance: f32,
+    ) -> Option<Duration> {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping_ratio();
+        let solvable = omega.is_finite() && zeta > 0.0 && tolerance > 0.0;
+        if !solvable {
+            return None;
+        }
+
+        let (displacement, velocity) = (displacement.abs(), velocity.abs());
+        let (decay, amplitude) = if zeta < 1.0 {
+            // Underdamped: oscillates inside an exponentially decaying envelope
+            let decay = zeta * omega;
+            let damped = omega * (1.0 - zeta * zeta).sqrt();
+            let amplitude = displacement.hypot((velocity + decay * displacement) / damped);
+            (decay, amplitude)
+        } else {
+            // Critically or overdamped: the slower exponential dominates
+            let decay = omega * (zeta - (zeta * zeta - 1.0).sqrt());
+            (decay, displacement + velocity / decay)
+        };
+
+        if amplitude <= tolerance {
+            return Some(Duration::ZERO);
+        }
+        Some(Duration::from_secs_f32(
+            (amplitude / tolerance).ln() / decay,
+        ))
+    }
+
+    /// The furthest point past `target` a spring from `start` reaches
+    ///
+    /// Uses the same analytic solution as [`settle_time`](Self::settle_time),
+    /// including the initial velocity, so a container can reserve room for the
+    /// bounce instead of clipping it. Returns `target` when the spring doesn't
+    /// overshoot.
+    ///
+    /// ```
+    /// use dioxus_motion2::prelude::*;
+    ///
+    /// let bouncy = Spring::new().stiffness(300.0).damping(8.0);
+    /// let peak = bouncy.max_excursion(0.0f32, 100.0);
+    /// assert!(peak > 100.0 && peak < 200.0);
+    ///
+    /// let stiff = Spring::new().stiffness(100.0).damping(20.0);
+    /// assert_eq!(stiff.max_excursion(0.0f32, 100.0), 100.0);
+    /// ```
+    pub fn max_excursion<T: Animatable>(&self, start: T, target: T) -> T {
+        let offset = start.sub(&target);
+        let distance = offset.magnitude();
+        if distance <= T::epsilon() {
+            return target;
+        }
+        let velocity = self.initial_velocity.unwrap_or(0.0);
+        let lowest = self.lowest_offset(-velocity / distance);
+        target.add(&offset.scale(lowest))
+    }
+
+    /// Position and velocity at `t` seconds of a spring from `from` to `to`
+    /// starting at `initial_velocity`
+    ///
+    /// A pure function of the damped harmonic oscillator's closed-form
+    /// solution, for the underdamped, critically damped and overdamped cases
+    /// alike: nothing is stepped and no animation is created, so trajectories
+    /// can be sampled ahead of time, e.g. on a server. The spring's own initial
+    /// velocity and timing are ignored; an undamped spring swings forever.
+    ///
+    /// ```
+    /// use dioxus_motion2::prelude::*;
+    ///
+    /// let spring = Spring::new().stiffness(300.0).damping(8.0);
+    /// let (position, _) = spring.evaluate(0.0, 100.0, 0.0, 0.15);
+    /// assert!(position > 100.0);
+    /// ```
+    pub fn evaluate(&self, from: f32, to: f32, initial_velocity: f32, t: f32) -> (f32, f32) {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping_ratio();
+        if t <= 0.0 || !omega.is_finite() || omega <= 0.0 || !zeta.is_finite() {
+            return (from, initial_velocity);
+        }
+
+        // The offset from the target, starting at x0 and moving at v0
+        let (x0, v0) = (from - to, initial_velocity);
+        let (offset, velocity) = if (zeta - 1.0).abs() < 1e-4 {
+            // Critically damped: (x0 + (v0 + w·x0)·t)·e^(-wt)
+            let rate = v0 + omega * x0;
+            let decay = (-omega * t).exp();
+            ((x0 + rate * t) * decay, (v0 - omega * rate * t) * decay)
+        } else if zeta < 1.0 {
+            // Underdamped: e^(-at)·(x0·cos(wt) + b·sin(wt))
+            let decay = zeta * omega;
+            let damped = omega * (1.0 - zeta * zeta).sqrt();
+            let b = (v0 + decay * x0) / damped;
+            let (sin, cos) = (damped * t).sin_cos();
+            let envelope = (-decay * t).exp();
+            (
+                envelope * (x0 * cos + b * sin),
+                envelope * (v0 * cos - (decay * b + damped * x0) * sin),
+            )
+        } else {
+            // Overdamped: c1·e^(r1·t) + c2·e^(r2·t)
+            let root = (zeta * zeta - 1.0).sqrt();
+            let (slow, fast) = (-omega * (zeta - root), -omega * (zeta + root));
+            let c_fast = (v0 - slow * x0) / (fast - slow);
+            let c_slow = x0 - c_fast;
+            let (e_slow, e_fast) = ((slow * t).exp(), (fast * t).exp());
+            (
+                c_slow * e_slow + c_fast * e_fast,
+                c_slow * slow * e_slow + c_fast * fast * e_fast,
+            )
+        };
+        (to + offset, velocity)
+    }
+
+    /// Positions of a spring from `from` to `to`, `sample_rate` times a second
+    ///
+    /// Samples [`evaluate`](Self::evaluate) from the start until the spring
+    /// settles within 0.1% of the distance, ending on `to`, or until
+    /// `max_duration` has passed: enough to export the motion as CSS
+    /// `@keyframes` with one stop per sample.
+    pub fn sample(
+        &self,
+        from: f32,
+        to: f32,
+        initial_velocity: f32,
+        sample_rate: f32,
+        max_duration: Duration,
+    ) -> Vec<f32> {
+        if !(sample_rate.is_finite() && sample_rate > 0.0) {
+            return vec![from];
+        }
+        let tolerance = ((from - to).abs() * 1e-3).max(f32::EPSILON);
+        let settled = self
+            .settle_time(from - to, initial_velocity, tolerance)
+            .filter(|settle| *settle < max_duration);
+        let end = settled.unwrap_or(max_duration).as_secs_f32();
+
+        let count = (end * sample_rate).ceil() as usize;
+        let mut samples: Vec<f32> = (0..=count)
+            .map(|i| {
+                let t = (i as f32 / sample_rate).min(end);
+                self.evaluate(from, to, initial_velocity, t).0
+            })
+            .collect();
+        if let (Some(_), Some(last)) = (settled, samples.last_mut()) {
+            *last = to;
+        }
+        samples
+    }
+
+    /// Lowest point of the offset from the target, as a fraction of the start
+    ///
+    /// The offset starts at 1 changing at `rate` per second, and is clamped to at
+    /// most 0: only the side past the target is of interest.
+    fn lowest_offset(&self, rate: f32) -> f32 {
+        let omega = (self.stiffness / self.mass).sqrt();
+        let zeta = self.damping_ratio();
+        if !omega.is_finite() || omega <= 0.0 || !zeta.is_finite() {
+            return 0.0;
+        }
+
+        let lowest = if zeta < 1.0 {
+            // Underdamped: e^(-at)·(cos(wt) + b·sin(wt)); the lowest point is one
+            // of the first two turning points, as the envelope only shrinks
+            let decay = zeta * omega;
+            let damped = omega * (1.0 - zeta * zeta).sqrt();
+            let b = (rate + decay) / damped;
+            let offset =
+                |t: f32| (-decay * t).exp() * ((damped * t).cos() + b * (damped * t).sin());
+            let sine = -damped - decay * b;
+            let mut first = (-rate).atan2(sine);
+            if first <= 0.0 {
+                first += std::f32::consts::PI;
+            }
+            let second = first + std::f32::consts::PI;
+            offset(first / damped).min(offset(second / damped))
+        } else if zeta == 1.0 {
+            // Critically damped: (1 + (rate + w)·t)·e^(-wt), turning at most once
+            let turn = rate / (omega * (rate + omega));
+            if turn > 0.0 {
+                (1.0 + (rate + omega) * turn) * (-omega * turn).exp()
+            } else {
+                0.0
+            }
+        } else {
+            // Overdamped: c1·e^(r1·t) + c2·e^(r2·t), turning at most once
+            let root = (zeta * zeta - 1.0).sqrt();
+            let (slow, fast) = (-omega * (zeta - root), -omega * (zeta + root));
+            let c_fast = (rate - slow) / (fast - slow);
+            let c_slow = 1.0 - c_fast;
+            let ratio = -(c_fast * fast) / (c_slow * slow);
+            let turn = ratio.ln() / (slow - fast);
+            if ratio > 0.0 && turn > 0.0 {
+                c_slow * (slow * turn).exp() + c_fast * (fast * turn).exp()
+            } else {
+                0.0
+            }
+        };
+        lowest.min(0.0)
+    }
+
+    /// The initial velocity as a value, pointing from `from` toward `to`
+    pub(crate) fn directed_velocity<T: Animatable>(&self, from: T, to: T) -> T {
+        self.initial_velocity.map_or_else(T::zero, |v| {
+            let direction = to.sub(&from);
+            let magnitude = direction.magnitude();
+            if magnitude > T::epsilon() {
+                direction.scale(v / magnitude)
+            } else {
+                T::zero()
+            }
+        })
+    }
+
     /// Create a spring animation with the current configuration
     pub fn create_animation<T: Animatable>(
         &self,
@@ -88,8 +407,12 @@ impl Spring {
             target,
             velocity: initial_velocity,
             spring: self.clone(),
-            timing: AnimationTiming::default(),
+            timing: self.timing.clone(),
             is_active: true,
+            pass_progress: 0.0,
+            noise: None,
+            noise_elapsed: 0.0,
+            bounds: None,
         }
     }
 }
@@ -110,21 +433,21 @@ pub struct SpringAnimation<T: Animatable> {
     timing: AnimationTiming,
     /// Whether the animation is active
     is_active: bool,
+    /// Estimated share of the current pass played, never moving backwards
+    pass_progress: f32,
+    /// Drift layered on the target, keeping the spring from settling
+    noise: Option<Noise<T>>,
+    /// Seconds of drift played
+    noise_elapsed: f32,
+    /// Walls the value bounces off
+    bounds: Option<BounceBounds>,
 }
 
 impl<T: Animatable> SpringAnimation<T> {
     /// Create a new spring animation
     pub fn new(initial: T, target: T, spring: Spring, timing: AnimationTiming) -> Self {
-        let velocity = spring.initial_velocity.map_or_else(T::zero, |v| {
-            // Create initial velocity in the direction of the target
-            let direction = target.sub(&initial);
-            let magnitude = direction.magnitude();
-            if magnitude > T::epsilon() {
-                direction.scale(v / magnitude)
-            } else {
-                T::zero()
-            }
-        });
+        // Create initial velocity in the direction of the target
+        let velocity = spring.directed_velocity(initial, target);
 
         Self {
             initial,
@@ -134,16 +457,72 @@ impl<T: Animatable> SpringAnimation<T> {
             spring,
             timing,
             is_active: true,
+            pass_progress: 0.0,
+            noise: None,
+            noise_elapsed: 0.0,
+            bounds: None,
         }
     }
 
+    /// Layer seeded drift on the target, so the spring keeps moving at rest
+    ///
+    /// See [`Noise`]; the spring then runs until it is replaced or stopped.
+    pub fn with_noise(mut self, noise: Noise<T>) -> Self {
+        self.noise = Some(noise);
+        self
+    }
+
+    /// Bounce off the walls of `bounds` instead of passing through them
+    ///
+    /// Keep the target between the walls, or the spring pins the value
+    /// against one and never settles. See [`BounceBounds`].
+    pub fn with_bounds(mut self, bounds: BounceBounds) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Estimated share of the current pass played, from how long the spring
+    /// takes to settle from here compared to from its start
+    fn estimate_pass_progress(&self) -> Option<f32> {
+        let tolerance = T::epsilon() * 1000.0;
+        let pass = self
+            .spring
+            .settle_time(self.target.sub(&self.initial).magnitude(), 0.0, tolerance)?
+            .as_secs_f32();
+        let left = self
+            .spring
+            .settle_time(
+                self.target.sub(&self.current).magnitude(),
+                self.velocity.magnitude(),
+                tolerance,
+            )?
+            .as_secs_f32();
+        Some(if pass > 0.0 {
+            (1.0 - left / pass).clamp(0.0, 1.0)
+        } else {
+            1.0
+        })
+    }
+
+    /// Whether an alternating spring is heading back to where it started,
+    /// with its ends swapped
+    fn on_return_pass(&self) -> bool {
+        self.timing.loop_mode.alternates() && self.timing.current_loop % 2 == 1
+    }
+
     /// Update the spring physics
     fn update_physics(&mut self, dt: f32) -> bool {
         // Cap dt to avoid numerical instability
         let dt = dt.min(0.064);
 
+        // Chase the target as moved by the drift, if any
+        let target = self.noise.map_or(self.target, |noise| {
+            self.noise_elapsed += dt;
+            self.target.add(&noise.offset(self.noise_elapsed))
+        });
+
         // Calculate spring force
-        let displacement = self.target.sub(&self.current);
+        let displacement = target.sub(&self.current);
         let spring_force = displacement.scale(self.spring.stiffness);
 
         // Calculate damping force
@@ -159,20 +538,13 @@ impl<T: Animatable> SpringAnimation<T> {
 
         // Update position
         self.current = self.current.add(&self.velocity.scale(dt));
+        if let Some(bounds) = &self.bounds {
+            self.current.bounce(&mut self.velocity, bounds);
+        }
 
-        // Check for completion with more lenient thresholds
-        let velocity_magnitude = self.velocity.magnitude();
-        let displacement_magnitude = displacement.magnitude();
-
-        // Use a much larger epsilon for completion check
-        let completion_epsilon = T::epsilon() * 1000.0;
-
-        println!(
-            "Spring physics update - Velocity: {}, Displacement: {}, Epsilon: {}",
-            velocity_magnitude, displacement_magnitude, completion_epsilon
-        );
-
-        if velocity_magnitude < completion_epsilon && displacement_magnitude < completion_epsilon {
+        // Check for completion, channel by channel for composite types; a
+        // drifting spring never settles
+        if self.noise.is_none() && self.current.is_settled(&self.target, &self.velocity) {
             println!("Spring animation completed - velocity and displacement below threshold");
             // Snap to target for precision
             self.current = self.target;
@@ -198,6 +570,13 @@ impl<T: Animatable> Animation for SpringAnimation<T> {
 
         // Update spring physics
         let still_active = self.update_physics(dt);
+        // The discrete spring drifts around the analytic estimate; keep the
+        // progress from moving backwards
+        if self.noise.is_none()
+            && let Some(estimate) = self.estimate_pass_progress()
+        {
+            self.pass_progress = self.pass_progress.max(estimate);
+        }
 
         if still_active {
             (AnimationState::Active, self.current, self.velocity)
@@ -205,11 +584,15 @@ impl<T: Animatable> Animation for SpringAnimation<T> {
             // Handle loop completion
             if self.timing.handle_loop_completion() {
                 println!("Spring animation loop completed, resetting for next loop");
-                // Reset for next loop but maintain target
-                let target = self.target;
-                self.current = self.initial;
-                self.target = target;
+                if self.timing.loop_mode.alternates() {
+                    // Head back from where this pass came to rest
+                    std::mem::swap(&mut self.initial, &mut self.target);
+                } else {
+                    // Reset for next loop but maintain target
+                    self.current = self.initial;
+                }
                 self.velocity = T::zero();
+                self.pass_progress = 0.0;
                 self.is_active = true; // Keep animation active for next loop
                 println!("Spring animation reset for next loop");
                 (AnimationState::Active, self.current, self.velocity)
@@ -230,8 +613,13 @@ impl<T: Animatable> Animation for SpringAnimation<T> {
     }
 
     fn reset(&mut self) {
+        if self.on_return_pass() {
+            std::mem::swap(&mut self.initial, &mut self.target);
+        }
         self.current = self.initial;
         self.velocity = T::zero();
+        self.pass_progress = 0.0;
+        self.noise_elapsed = 0.0;
         self.timing.current_loop = 0;
         self.timing.delay_elapsed = false;
         self.is_active = true;
@@ -240,6 +628,62 @@ impl<T: Animatable> Animation for SpringAnimation<T> {
     fn is_active(&self) -> bool {
         self.is_active
     }
+
+    /// Estimated from how long the spring takes to settle, as for
+    /// [`remaining_time`](Animation::remaining_time)
+    fn progress(&self) -> Option<f32> {
+        if !self.is_active {
+            return Some(1.0);
+        }
+        if self.noise.is_some() {
+            return None;
+        }
+        let loops = self.timing.total_loops()? as f32;
+        Some((self.timing.current_loop as f32 + self.pass_progress) / loops)
+    }
+
+    fn finish_loop(&mut self) -> bool {
+        self.timing.finish_current_loop();
+        self.is_active && self.noise.is_none()
+    }
+
+    fn retune_spring(&mut self, spring: &Spring) -> bool {
+        self.spring.stiffness = spring.stiffness;
+        self.spring.damping = spring.damping;
+        self.spring.mass = spring.mass;
+        self.is_active
+    }
+
+    fn retarget(&mut self, target: T) -> bool {
+        // On the way back the target is where the next pass heads
+        if self.on_return_pass() {
+            self.initial = target;
+        } else {
+            self.target = target;
+        }
+        self.is_active
+    }
+
+    /// Estimated with [`Spring::settle_time`]
+    fn remaining_time(&self) -> Option<Duration> {
+        if !self.is_active {
+            return Some(Duration::ZERO);
+        }
+        if self.noise.is_some() {
+            return None;
+        }
+        let tolerance = T::epsilon() * 1000.0;
+        let current = self.spring.settle_time(
+            self.target.sub(&self.current).magnitude(),
+            self.velocity.magnitude(),
+            tolerance,
+        )?;
+        let pass =
+            self.spring
+                .settle_time(self.target.sub(&self.initial).magnitude(), 0.0, tolerance)?;
+        let loops = self.timing.remaining_loops()?;
+        Some(self.timing.remaining_delay() + current + pass * loops)
+    }
 }
 
 /// Builder for spring animations
@@ -247,7 +691,15 @@ pub struct SpringBuilder<T: Animatable> {
     motion: MotionValue<T>,
     spring: Spring,
     target: Option<T>,
-    completion_callback: Option<Box<dyn FnOnce() + Send>>,
+    completion_callback: Option<Callback>,
+    rest_callback: Option<Callback>,
+    interrupt_callback: Option<Callback>,
+    direction_callback: Option<RepeatingCallback>,
+    rest_when: Option<(f32, Duration)>,
+    noise: Option<Noise<T>>,
+    ambient: bool,
+    preserve_velocity: bool,
+    bounds: Option<BounceBounds>,
 }
 
 impl<T: Animatable> SpringBuilder<T> {
@@ -257,7 +709,15 @@ impl<T: Animatable> SpringBuilder<T> {
             motion,
             spring: Spring::default(),
             completion_callback: None,
+            rest_callback: None,
+            interrupt_callback: None,
+            direction_callback: None,
+            rest_when: None,
             target: None,
+            noise: None,
+            ambient: false,
+            preserve_velocity: true,
+            bounds: None,
         }
     }
 
@@ -279,15 +739,104 @@ impl<T: Animatable> SpringBuilder<T> {
         self
     }
 
+    /// Set spring mass from a perceptual weight
+    ///
+    /// `spring().weight(Weight::Heavy)` lags and swings more than `Weight::Light`.
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.spring.mass = weight.mass();
+        self
+    }
+
     /// Set initial velocity
     pub fn velocity(mut self, velocity: T) -> Self {
         self.spring.initial_velocity = Some(velocity.magnitude());
         self
     }
 
+    /// Whether to carry over the velocity of an interrupted animation
+    ///
+    /// By default a spring started mid-flight keeps moving as fast as the
+    /// value already was, so retargeting never hitches. With `false` it
+    /// starts from rest, or at [`SpringBuilder::velocity`] when set.
+    pub fn preserve_velocity(mut self, preserve: bool) -> Self {
+        self.preserve_velocity = preserve;
+        self
+    }
+
     /// Add completion callback
-    pub fn on_complete<F: FnOnce() + Send + 'static>(mut self, callback: F) -> Self {
-        self.completion_callback = Some(Box::new(callback));
+    pub fn on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.completion_callback = Some(boxed(callback));
+        self
+    }
+
+    /// Add a callback fired when this spring settles at its target on its own
+    ///
+    /// Unlike [`SpringBuilder::on_complete`], it is dropped if the spring is
+    /// retargeted or stopped first, so it never fires alongside `on_interrupt`.
+    pub fn on_rest<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.rest_callback = Some(boxed(callback));
+        self
+    }
+
+    /// Add a callback fired when this spring is retargeted, stopped or set before it settles
+    pub fn on_interrupt<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.interrupt_callback = Some(boxed(callback));
+        self
+    }
+
+    /// Add a callback fired each time this spring turns around
+    ///
+    /// A turn is where the velocity along the line from the start to the
+    /// target changes sign, so a bouncy spring fires once per swing and a
+    /// critically damped one never does. Composite values count reversals
+    /// along that line, not sideways wobble, and a turn only counts after
+    /// moving faster than the [`SpringBuilder::rest_when`] speed, or 1% of
+    /// the distance per second without one, so jitter near rest never fires it.
+    pub fn on_direction_change<F: FnMut() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.direction_callback = Some(RepeatingCallback::new(callback));
+        self
+    }
+
+    /// Fire [`SpringBuilder::on_rest`] once the spring looks at rest
+    ///
+    /// Instead of waiting for the spring to settle, it fires once the speed
+    /// has stayed at or below `speed` units per second for `hold`, after
+    /// first moving faster, or when the spring settles if that comes first.
+    pub fn rest_when(mut self, speed: f32, hold: Duration) -> Self {
+        self.rest_when = Some((speed, hold));
+        self
+    }
+
+    /// Layer seeded drift on the target for idle motion
+    ///
+    /// The spring keeps drifting around the target instead of settling, so
+    /// completion and rest callbacks only run once it is replaced or stopped.
+    /// The same [`Noise`] seed replays the same drift.
+    pub fn noise(mut self, noise: Noise<T>) -> Self {
+        self.noise = Some(noise);
+        self
+    }
+
+    /// Bounce off walls at `min` and `max` instead of passing through them
+    ///
+    /// Crossing a wall reverses the velocity, keeping `restitution` of the
+    /// speed, from 0 to 1, so each hit loses energy until the value rests, e.g.
+    /// a ball dropped onto the floor of a box. Keep the target between the
+    /// walls. Scalars and each channel of `[f32; N]` values bounce; see
+    /// [`BounceBounds`].
+    pub fn bounce_bounds(mut self, min: f32, max: f32, restitution: f32) -> Self {
+        self.bounds = Some(BounceBounds::new(min, max, restitution));
+        self
+    }
+
+    /// Mark this spring as ambient decoration, cut back to save power and data
+    ///
+    /// Following the [`AmbientPolicy`] of the enclosing
+    /// [`MotionConfig`](crate::MotionConfig) and the user's
+    /// [`PowerPreferences`](crate::animations::power::PowerPreferences), it may not start at all, leaving the value and its
+    /// callbacks alone, step at a lower frame rate or loop only a few times.
+    pub fn ambient(mut self) -> Self {
+        self.ambient = true;
         self
     }
 
@@ -299,9 +848,17 @@ impl<T: Animatable> SpringBuilder<T> {
 
     /// Set the loop mode for the animation
     pub fn loop_mode(mut self, mode: LoopMode) -> Self {
-        let mut timing = AnimationTiming::default();
-        timing.loop_mode = mode;
-        self.spring.timing = timing;
+        self.spring.timing.loop_mode = mode;
+        self
+    }
+
+    /// Hold the current value for `delay` before the spring starts
+    ///
+    /// The delay counts the time the value is advanced by, frame by frame,
+    /// not the wall clock. Another animate call during the delay replaces the
+    /// spring, starting its own delay afresh.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.spring.timing.delay = delay;
         self
     }
 
@@ -311,18 +868,76 @@ impl<T: Animatable> SpringBuilder<T> {
             .expect("Target value must be set before building");
 
         // Create the spring animation directly
-        self.spring
-            .create_animation(self.motion.get(), target, T::zero())
+        let animation = self
+            .spring
+            .create_animation(self.motion.get(), target, T::zero());
+        let animation = match self.bounds {
+            Some(bounds) => animation.with_bounds(bounds),
+            None => animation,
+        };
+        match self.noise {
+            Some(noise) => animation.with_noise(noise),
+            None => animation,
+        }
+    }
+
+    /// Start animation to the current value moved by `delta`
+    ///
+    /// Like [`MotionValue::animate_by`], the delta applies to the value at call
+    /// time, even mid-flight.
+    pub fn by(self, delta: T) -> AnimationToken {
+        let target = self.motion.peek().add(&delta);
+        self.animate_to(target)
     }
 
     /// Start animation to target value
-    pub fn animate_to(mut self, target: T) -> MotionValue<T> {
-        // Apply the completion callback if provided
-        if let Some(callback) = self.completion_callback {
-            self.motion.engine.write().add_completion_callback(callback);
+    ///
+    /// Starts at the next frame, like
+    /// [`MotionValue::animate_to_with_config`]; the last animate call before
+    /// it wins.
+    pub fn animate_to(mut self, target: T) -> AnimationToken {
+        let reduction = if self.ambient {
+            AmbientPolicy::current()
+        } else {
+            AmbientReduction::none()
+        };
+        if reduction.skip {
+            return AnimationToken::ended();
         }
-
-        self.motion.engine.write().spring_to(target, self.spring);
-        self.motion
+        self.spring.timing.loop_mode = reduction.limit(self.spring.timing.loop_mode);
+        {
+            let mut engine = self.motion.engine.write();
+            let playback = Playback {
+                from_rest: !self.preserve_velocity,
+                ..reduction.playback()
+            };
+            engine.queue_with(
+                target,
+                Approach::Spring(self.spring, self.noise, self.bounds),
+                playback,
+            );
+            if let Some(callback) = self.completion_callback {
+                engine.add_completion_callback(callback);
+            }
+            let rest_callback = match self.rest_when {
+                Some((speed, hold)) => self.rest_callback.map(|callback| (speed, hold, callback)),
+                None => {
+                    if let Some(callback) = self.rest_callback {
+                        engine.add_rest_callback(callback);
+                    }
+                    None
+                }
+            };
+            if let Some(callback) = self.interrupt_callback {
+                engine.add_interrupt_callback(callback);
+            }
+            if self.direction_callback.is_some() || rest_callback.is_some() {
+                engine.add_cues(Cues::new(self.direction_callback, rest_callback));
+            }
+            if reduction.jump {
+                engine.complete_now();
+            }
+        }
+        self.motion.started()
     }
 }
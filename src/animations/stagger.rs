@@ -0,0 +1,417 @@
+//! Staggered animations for composite values
+//!
+//! Animates every component of a `[f32; N]` motion value with the same tween,
+//! starting each component a fixed delay after the previous one. One motion
+//! value can then drive a cascading reveal of a whole list of items.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn RevealList() -> Element {
+//!     let mut reveal = use_motion([0.0f32; 8]);
+//!
+//!     use_effect(move || {
+//!         reveal
+//!             .stagger()
+//!             .each(Duration::from_millis(60))
+//!             .duration(Duration::from_millis(250))
+//!             .animate_to([1.0; 8]);
+//!     });
+//!
+//!     rsx! {
+//!         for (i, opacity) in reveal.get().into_iter().enumerate() {
+//!             div { key: "{i}", style: "opacity: {opacity};", "Item {i}" }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Dismissing the list with [`exit_reverse`](StaggerBuilder::exit_reverse)
+//! takes the items out last in, first out. Started in the middle of the reveal,
+//! the exit only cascades over the items that have appeared: those that never
+//! started stay hidden and take no slot.
+//!
+//! ```ignore
+//! reveal
+//!     .stagger()
+//!     .each(Duration::from_millis(60))
+//!     .duration(Duration::from_millis(250))
+//!     .exit_reverse()
+//!     .animate_to([0.0; 8]);
+//! ```
+
+use dioxus::signals::Writable;
+use instant::Duration;
+
+use crate::animation::{Animation, AnimationState, AnimationToken, Callback, MaybeSend, boxed};
+use crate::animations::tween::{EasingFunction, Tween};
+use crate::{Animatable, MotionValue};
+
+/// Order in which the components of a stagger start
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StaggerDirection {
+    /// First component first, each one `each` after the previous
+    #[default]
+    Forward,
+    /// Last component first, skipping components already at their target
+    ///
+    /// The order of exits, mirroring a forward enter: dismissed mid-enter, the
+    /// components that have not started yet have nothing to undo and take no
+    /// slot in the cascade.
+    Reverse,
+}
+
+/// Tween applied to each component of an array, with a cascading start delay
+///
+/// The value is every component at once, never an aggregate of them: component
+/// `i` holds its initial value for `i * each`, then plays the tween to its
+/// target and holds that. The velocity is per component as well, measured over
+/// the last update.
+pub struct StaggerAnimation<const N: usize> {
+    /// Initial value
+    initial: [f32; N],
+    /// Current value
+    current: [f32; N],
+    /// Target value
+    target: [f32; N],
+    /// Velocity over the last update
+    velocity: [f32; N],
+    /// Tween applied to every component
+    tween: Tween,
+    /// Delay between the starts of consecutive components
+    each: Duration,
+    /// Start delay of each component
+    delays: [Duration; N],
+    /// Elapsed time in seconds
+    elapsed: f32,
+    /// Whether the animation is active
+    is_active: bool,
+}
+
+impl<const N: usize> StaggerAnimation<N> {
+    /// Create a new staggered animation
+    pub fn new(initial: [f32; N], target: [f32; N], tween: Tween, each: Duration) -> Self {
+        Self {
+            initial,
+            current: initial,
+            target,
+            velocity: [0.0; N],
+            tween,
+            each,
+            delays: std::array::from_fn(|i| each * i as u32),
+            elapsed: 0.0,
+            is_active: true,
+        }
+    }
+
+    /// Start the components in `direction`'s order
+    pub fn with_direction(mut self, direction: StaggerDirection) -> Self {
+        let each = self.each;
+        self.delays = match direction {
+            StaggerDirection::Forward => std::array::from_fn(|i| each * i as u32),
+            StaggerDirection::Reverse => {
+                let mut delays = [Duration::ZERO; N];
+                let mut slot = 0;
+                for i in (0..N).rev() {
+                    if self.initial[i] != self.target[i] {
+                        delays[i] = each * slot;
+                        slot += 1;
+                    }
+                }
+                delays
+            }
+        };
+        self
+    }
+
+    /// Total time until the last component settles
+    pub fn total_duration(&self) -> Duration {
+        let last = self.delays.iter().max().copied().unwrap_or_default();
+        last + self.tween.duration
+    }
+
+    /// Value of every component at the given elapsed time
+    fn value_at(&self, elapsed: f32) -> [f32; N] {
+        let duration = self.tween.duration.as_secs_f32();
+
+        std::array::from_fn(|i| {
+            let local = elapsed - self.delays[i].as_secs_f32();
+            let progress = if duration > 0.0 {
+                (local / duration).clamp(0.0, 1.0)
+            } else if local >= 0.0 {
+                1.0
+            } else {
+                0.0
+            };
+            let eased = (self.tween.easing)(progress, 0.0, 1.0, 1.0);
+            self.initial[i].interpolate(&self.target[i], eased)
+        })
+    }
+}
+
+impl<const N: usize> Animation for StaggerAnimation<N> {
+    type Value = [f32; N];
+
+    fn update(&mut self, dt: f32) -> (AnimationState, Self::Value, Self::Value) {
+        if !self.is_active {
+            return (AnimationState::Completed, self.current, [0.0; N]);
+        }
+
+        let previous = self.current;
+        self.elapsed += dt;
+
+        if self.elapsed >= self.total_duration().as_secs_f32() {
+            self.current = self.target;
+            self.velocity = [0.0; N];
+            self.is_active = false;
+            return (AnimationState::Completed, self.current, [0.0; N]);
+        }
+
+        self.current = self.value_at(self.elapsed);
+        if dt > 0.0 {
+            self.velocity = self.current.sub(&previous).scale(1.0 / dt);
+        }
+
+        (AnimationState::Active, self.current, self.velocity)
+    }
+
+    fn value(&self) -> Self::Value {
+        self.current
+    }
+
+    fn velocity(&self) -> Self::Value {
+        self.velocity
+    }
+
+    fn reset(&mut self) {
+        self.current = self.initial;
+        self.velocity = [0.0; N];
+        self.elapsed = 0.0;
+        self.is_active = true;
+    }
+
+    fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    fn remaining_time(&self) -> Option<Duration> {
+        if !self.is_active {
+            return Some(Duration::ZERO);
+        }
+        let elapsed = Duration::from_secs_f32(self.elapsed.max(0.0));
+        Some(self.total_duration().saturating_sub(elapsed))
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(StaggerAnimation::total_duration(self))
+    }
+}
+
+/// Builder for staggered animations
+pub struct StaggerBuilder<const N: usize> {
+    motion: MotionValue<[f32; N]>,
+    tween: Tween,
+    each: Duration,
+    direction: StaggerDirection,
+    completion_callback: Option<Callback>,
+}
+
+impl<const N: usize> StaggerBuilder<N> {
+    /// Create a new stagger builder
+    pub(crate) fn new(motion: MotionValue<[f32; N]>) -> Self {
+        Self {
+            motion,
+            tween: Tween::default(),
+            each: Duration::from_millis(50),
+            direction: StaggerDirection::Forward,
+            completion_callback: None,
+        }
+    }
+
+    /// Set the delay between the starts of consecutive components
+    pub fn each(mut self, each: Duration) -> Self {
+        self.each = each;
+        self
+    }
+
+    /// Set the tween duration of each component
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.tween.duration = duration;
+        self
+    }
+
+    /// Set the easing function of each component
+    pub fn easing(mut self, easing: EasingFunction) -> Self {
+        self.tween.easing = easing;
+        self
+    }
+
+    /// Set the order in which the components start
+    pub fn direction(mut self, direction: StaggerDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Start the last component first, as exits mirroring an enter do
+    ///
+    /// Shorthand for [`direction`](Self::direction) with
+    /// [`StaggerDirection::Reverse`]: components already at the target, e.g.
+    /// the ones an interrupted enter never reached, are skipped.
+    pub fn exit_reverse(self) -> Self {
+        self.direction(StaggerDirection::Reverse)
+    }
+
+    /// Add completion callback, run once the last component settles
+    pub fn on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.completion_callback = Some(boxed(callback));
+        self
+    }
+
+    /// Build the animation for use in sequences or groups
+    pub fn build(self, target: [f32; N]) -> StaggerAnimation<N> {
+        StaggerAnimation::new(self.motion.get(), target, self.tween, self.each)
+            .with_direction(self.direction)
+    }
+
+    /// Start the staggered animation to the target value
+    ///
+    /// Starts from where each component is, so an exit interrupting an enter
+    /// takes every component back from its current state.
+    pub fn animate_to(mut self, target: [f32; N]) -> AnimationToken {
+        let animation = StaggerAnimation::new(self.motion.get(), target, self.tween, self.each)
+            .with_direction(self.direction);
+
+        if let Some(callback) = self.completion_callback.take() {
+            self.motion.engine.write().add_completion_callback(callback);
+        }
+
+        self.motion.animate_custom(animation)
+    }
+}
+
+impl<const N: usize> MotionValue<[f32; N]> {
+    /// Create a staggered animation builder
+    ///
+    /// Each component starts animating a fixed delay after the previous one.
+    pub fn stagger(&self) -> StaggerBuilder<N> {
+        StaggerBuilder::new(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+
+    fn reveal() -> StaggerAnimation<8> {
+        let tween = Tween::new().duration(Duration::from_millis(200));
+        StaggerAnimation::new([0.0; 8], [1.0; 8], tween, Duration::from_millis(50))
+    }
+
+    #[test]
+    fn test_array_animatable() {
+        let a = [0.0f32, 2.0];
+        let b = [3.0f32, 6.0];
+        assert_eq!(a.interpolate(&b, 0.5), [1.5, 4.0]);
+        assert_eq!(b.sub(&a).magnitude(), 5.0);
+        assert_eq!(a.add(&b).scale(2.0), [6.0, 16.0]);
+    }
+
+    #[test]
+    fn test_components_start_in_cascade() {
+        let mut animation = reveal();
+        assert_eq!(animation.total_duration(), Duration::from_millis(550));
+
+        // 120ms in: the first three components have started, the rest wait
+        let (state, value, _) = animation.update(0.12);
+        assert_eq!(state, AnimationState::Active);
+        assert!(value[0] > value[1] && value[1] > value[2] && value[2] > 0.0);
+        assert!(value[3..].iter().all(|component| *component == 0.0));
+    }
+
+    #[test]
+    fn test_waiting_components_report_their_own_state() {
+        let mut animation = reveal();
+        let (_, value, velocity) = animation.update(0.06);
+        let (_, later, _) = animation.update(0.02);
+
+        // The second component started at 50ms and moves; the third still waits
+        assert!(later[1] > value[1] && velocity[1] > 0.0);
+        assert_eq!((later[2], animation.velocity()[2]), (0.0, 0.0));
+        assert_eq!(animation.value(), later);
+        assert!(animation.velocity()[0] > 0.0);
+    }
+
+    #[test]
+    fn test_stagger_completes_at_target() {
+        let mut engine = AnimationEngine::new([0.0f32; 8]);
+        engine.apply_custom(reveal());
+
+        let mut frames = 0;
+        while engine.update(1.0 / 60.0) {
+            frames += 1;
+        }
+
+        assert_eq!(engine.get(), [1.0; 8]);
+        // 550ms at 60fps
+        assert!((32..=34).contains(&frames));
+    }
+
+    #[test]
+    fn test_reverse_exit_mirrors_the_enter() {
+        let tween = Tween::new().duration(Duration::from_millis(200));
+        let mut exit = StaggerAnimation::new([1.0; 8], [0.0; 8], tween, Duration::from_millis(50))
+            .with_direction(StaggerDirection::Reverse);
+        assert_eq!(exit.total_duration(), reveal().total_duration());
+
+        // 120ms in: the last three components have started, the rest wait
+        let (_, value, _) = exit.update(0.12);
+        assert!(value[7] < value[6] && value[6] < value[5] && value[5] < 1.0);
+        assert!(value[..5].iter().all(|component| *component == 1.0));
+    }
+
+    #[test]
+    fn test_exit_mid_enter_skips_items_never_shown() {
+        let tween = Tween::new().duration(Duration::from_millis(80));
+        let each = Duration::from_millis(100);
+        let mut engine = AnimationEngine::new([0.0f32; 5]);
+        engine.apply_custom(StaggerAnimation::new([0.0; 5], [1.0; 5], tween, each));
+
+        // Item 2 has entered at 280ms, item 3 starts at 300ms
+        for _ in 0..29 {
+            engine.update(0.01);
+        }
+        assert_eq!(engine.get(), [1.0, 1.0, 1.0, 0.0, 0.0]);
+
+        let exit = StaggerAnimation::new(engine.get(), [0.0; 5], tween, each)
+            .with_direction(StaggerDirection::Reverse);
+        // Three items leave, a slot each
+        assert_eq!(exit.total_duration(), Duration::from_millis(280));
+        engine.apply_custom(exit);
+
+        // When each shown item starts leaving, in 10ms frames
+        let mut started = [None; 3];
+        let mut frame = 0;
+        while engine.update(0.01) {
+            frame += 1;
+            let value = engine.get();
+            assert_eq!(&value[3..], &[0.0, 0.0]);
+            for (item, component) in value[..3].iter().enumerate() {
+                if *component < 1.0 && started[item].is_none() {
+                    started[item] = Some(frame);
+                }
+            }
+        }
+        assert_eq!(engine.get(), [0.0; 5]);
+        let [Some(first), Some(second), Some(third)] = started else {
+            unreachable!("every shown item leaves: {started:?}");
+        };
+        // Last in, first out, a slot apart give or take a frame of rounding
+        assert_eq!(third, 1);
+        assert!((9..=11).contains(&(second - third)) && (9..=11).contains(&(first - second)));
+    }
+}
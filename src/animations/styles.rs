@@ -0,0 +1,127 @@
+//! Style strings for a whole list of motion values in one pass
+//!
+//! In a big animated list, a memo per row rendering its own style means as
+//! many memos as rows, each one subscribed to by the list. [`use_motion_styles`]
+//! renders every row's style in a single memo instead: all values advance in
+//! the same driver frame, so the memo reruns once per frame, reading each
+//! value once, and the component depends on that one memo.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Rows() -> Element {
+//!     let rows: Vec<_> = (0..100).map(|_| use_motion(0.0f32)).collect();
+//!     let styles = use_motion_styles(&rows, |x| format!("transform: translateX({x}px);"));
+//!
+//!     rsx! {
+//!         for (i, style) in styles.read().iter().enumerate() {
+//!             div { key: "{i}", style: "{style}", "Row {i}" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use dioxus::prelude::*;
+
+use crate::{Animatable, MotionValue};
+
+/// Render the style of every value in `motions` in a single memo
+///
+/// The memo holds one string per value, in order, rendered by `render`, and
+/// is rebuilt once per frame in which any of the values moved. Like other
+/// hooks, call it unconditionally from the component body; `motions` and
+/// `render` are read on the first render only, so key the component by the
+/// list when its length changes.
+pub fn use_motion_styles<T: Animatable>(
+    motions: &[MotionValue<T>],
+    render: impl Fn(&T) -> String + 'static,
+) -> Memo<Vec<String>> {
+    let motions = use_hook(|| motions.to_vec());
+    use_memo(move || motions.iter().map(|motion| render(&motion.get())).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::AnimationEngine;
+    use crate::prelude::Tween;
+    use instant::Duration;
+    use std::cell::Cell;
+
+    const ROWS: usize = 100;
+
+    type Engines = Signal<Vec<Signal<AnimationEngine<f32>>>>;
+
+    thread_local! {
+        static ENGINES: Cell<Option<Engines>> = const { Cell::new(None) };
+        static RENDERS: Cell<usize> = const { Cell::new(0) };
+        static STYLED: Cell<usize> = const { Cell::new(0) };
+        static LAST: Cell<Option<Memo<Vec<String>>>> = const { Cell::new(None) };
+    }
+
+    #[test]
+    fn test_a_list_is_styled_in_one_pass_per_frame() {
+        fn app() -> Element {
+            let engines = use_signal(|| {
+                (0..ROWS)
+                    .map(|_| Signal::new(AnimationEngine::new(0.0f32)))
+                    .collect::<Vec<_>>()
+            });
+            ENGINES.set(Some(engines));
+            let rows: Vec<_> = engines
+                .peek()
+                .iter()
+                .copied()
+                .map(MotionValue::new)
+                .collect();
+            let styles = use_motion_styles(&rows, |x| {
+                STYLED.set(STYLED.get() + 1);
+                format!("transform: translateX({x}px);")
+            });
+            LAST.set(Some(styles));
+            RENDERS.set(RENDERS.get() + 1);
+            let count = styles.read().len();
+            rsx! { "{count}" }
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let (Some(engines), Some(styles)) = (ENGINES.get(), LAST.get()) else {
+            unreachable!("the app stores its values");
+        };
+        assert_eq!(STYLED.get(), ROWS);
+
+        let tween = Tween::new().duration(Duration::from_millis(100));
+        let (renders, styled) = (RENDERS.get(), STYLED.get());
+        for frame in 1..=3 {
+            // Every row moves in the same frame, as under the driver
+            dom.in_runtime(|| {
+                for mut engine in engines.peek().iter().copied() {
+                    let mut engine = engine.write();
+                    if frame == 1 {
+                        engine.tween_to(100.0, tween);
+                    }
+                    engine.update(1.0 / 60.0);
+                }
+            });
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+
+            // One re-render and one style per row, not one pass per row
+            assert_eq!(RENDERS.get(), renders + frame);
+            assert_eq!(STYLED.get(), styled + frame * ROWS);
+        }
+        dom.in_runtime(|| {
+            let styles = styles.read();
+            assert_eq!(styles.len(), ROWS);
+            assert!(styles.iter().all(|style| style == &styles[0]));
+            assert_ne!(styles[0], "transform: translateX(0px);");
+        });
+    }
+}
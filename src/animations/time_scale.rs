@@ -0,0 +1,144 @@
+//! Slow motion for part of the app
+//!
+//! [`MotionTimeScale`] runs the motion values below it at a fraction of normal
+//! speed, e.g. the stage of a feature demo being recorded at quarter speed, while
+//! the rest of the app keeps its pace. Scales nest: a `0.5` scale inside another
+//! `0.5` scale plays at a quarter of the speed.
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Demo() -> Element {
+//!     let mut slow = use_signal(|| false);
+//!
+//!     rsx! {
+//!         button { onclick: move |_| slow.toggle(), "Slow motion" }
+//!         MotionTimeScale { factor: if slow() { 0.25 } else { 1.0 },
+//!             Stage {}
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+/// A time scale scope, provided as a context by [`MotionTimeScale`]
+#[derive(Clone)]
+pub(crate) struct TimeScale {
+    factor: Rc<Cell<f32>>,
+    parent: Option<Rc<TimeScale>>,
+}
+
+impl TimeScale {
+    fn new(factor: f32, parent: Option<TimeScale>) -> Self {
+        let scale = Self {
+            factor: Rc::new(Cell::new(1.0)),
+            parent: parent.map(Rc::new),
+        };
+        scale.set(factor);
+        scale
+    }
+
+    /// Change this scope's own factor; negative factors freeze time
+    fn set(&self, factor: f32) {
+        self.factor.set(factor.max(0.0));
+    }
+
+    /// Factor applied to the frame time, including the enclosing scopes
+    pub(crate) fn factor(&self) -> f32 {
+        let parent = self.parent.as_ref().map_or(1.0, |parent| parent.factor());
+        self.factor.get() * parent
+    }
+
+    /// Scale the frame time `dt` of a value in this scope
+    pub(crate) fn scale(&self, dt: f32) -> f32 {
+        dt * self.factor()
+    }
+}
+
+/// Run every motion value created below at `factor` times normal speed
+///
+/// Applies to values created by components inside it, on top of any enclosing
+/// time scale. Changing `factor` takes effect on the next frame and running
+/// animations carry on from where they are, only faster or slower; `0.0`
+/// freezes them.
+#[component]
+pub fn MotionTimeScale(factor: f32, children: Element) -> Element {
+    let scale = use_hook(|| {
+        let parent = try_consume_context::<TimeScale>();
+        provide_context(TimeScale::new(factor, parent))
+    });
+    scale.set(factor);
+
+    rsx! {
+        {children}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    thread_local! {
+        static SEEN: RefCell<Vec<(&'static str, f32)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Records the time scale factor its motion values would get
+    #[component]
+    fn Probe(name: &'static str) -> Element {
+        let factor = try_use_context::<TimeScale>().map_or(1.0, |scale| scale.factor());
+        SEEN.with_borrow_mut(|seen| seen.push((name, factor)));
+        rsx! {}
+    }
+
+    #[test]
+    fn test_nested_scopes_multiply_and_siblings_are_unaffected() {
+        fn app() -> Element {
+            rsx! {
+                MotionTimeScale { factor: 0.5,
+                    Probe { name: "outer" }
+                    MotionTimeScale { factor: 0.5,
+                        Probe { name: "nested" }
+                    }
+                }
+                Probe { name: "sibling" }
+                MotionTimeScale { factor: 2.0,
+                    Probe { name: "fast" }
+                }
+            }
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+
+        let mut seen = SEEN.with_borrow(|seen| seen.clone());
+        seen.sort_by(|a, b| a.0.cmp(b.0));
+        assert_eq!(
+            seen,
+            vec![
+                ("fast", 2.0),
+                ("nested", 0.25),
+                ("outer", 0.5),
+                ("sibling", 1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changing_a_factor_applies_to_nested_scopes() {
+        let outer = TimeScale::new(1.0, None);
+        let inner = TimeScale::new(0.5, Some(outer.clone()));
+        assert_eq!(inner.scale(0.016), 0.008);
+
+        outer.set(0.5);
+        assert_eq!(inner.factor(), 0.25);
+        outer.set(-1.0);
+        assert_eq!(inner.scale(0.016), 0.0);
+    }
+}
@@ -0,0 +1,522 @@
+//! Live-tunable springs for design iteration
+//!
+//! A tunable spring is a named spring whose physics can be changed while the app
+//! runs, e.g. from a [`TuningPanel`] next to the animation being designed. Every
+//! animation started from it picks up the latest values, and motion values
+//! following it with [`use_retuning`] can switch mid-flight.
+//!
+//! With the `persist-tuning` feature, tuned values are saved to `localStorage`
+//! on the web so they survive a refresh. Once the feel is right, copy the values
+//! into the code and remove the tunable.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Card() -> Element {
+//!     let tunable = use_tunable_spring("card_spring", Spring::default());
+//!     let mut y = use_motion(0.0f32);
+//!     use_retuning(y, tunable);
+//!
+//!     rsx! {
+//!         div {
+//!             style: "transform: translateY({y.get()}px);",
+//!             onclick: move |_| {
+//!                 y.spring().spring_tunable(&tunable).animate_to(100.0);
+//!             },
+//!             "Open"
+//!         }
+//!         TuningPanel {}
+//!     }
+//! }
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::animations::spring::{Spring, SpringBuilder};
+use crate::{Animatable, MotionValue};
+
+/// Spring physics that a tuning session can change
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringParams {
+    /// Spring stiffness
+    pub stiffness: f32,
+    /// Damping coefficient
+    pub damping: f32,
+    /// Mass
+    pub mass: f32,
+}
+
+impl SpringParams {
+    /// The physics of `spring`
+    pub fn of(spring: &Spring) -> Self {
+        Self {
+            stiffness: spring.stiffness,
+            damping: spring.damping,
+            mass: spring.mass,
+        }
+    }
+
+    /// A copy of `spring` with these physics, keeping its timing and initial velocity
+    pub fn apply(&self, spring: &Spring) -> Spring {
+        Spring {
+            stiffness: self.stiffness,
+            damping: self.damping,
+            mass: self.mass,
+            ..spring.clone()
+        }
+    }
+
+    /// Serialize for storage
+    fn encode(&self) -> String {
+        format!("{},{},{}", self.stiffness, self.damping, self.mass)
+    }
+
+    /// Parse stored params, rejecting anything that isn't a valid spring
+    fn decode(stored: &str) -> Option<Self> {
+        let mut values = stored.split(',').map(|value| value.trim().parse::<f32>());
+        let params = Self {
+            stiffness: values.next()?.ok()?,
+            damping: values.next()?.ok()?,
+            mass: values.next()?.ok()?,
+        };
+        let valid = values.next().is_none() && params.apply(&Spring::default()).validate().is_ok();
+        valid.then_some(params)
+    }
+}
+
+/// The spring a tunable starts with: the stored tuning if any, else the default
+fn resolve(default: &Spring, stored: Option<&str>) -> Spring {
+    stored
+        .and_then(SpringParams::decode)
+        .map_or_else(|| default.clone(), |params| params.apply(default))
+}
+
+#[derive(Clone)]
+struct TunableState {
+    spring: Spring,
+    default: Spring,
+    retarget: bool,
+}
+
+/// Handle to a named spring created with [`use_tunable_spring`]
+#[derive(Clone, Copy, PartialEq)]
+pub struct TunableSpring {
+    name: &'static str,
+    state: Signal<TunableState>,
+}
+
+impl TunableSpring {
+    /// The name the spring was registered under
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The current spring; reading it subscribes the caller to changes
+    pub fn spring(&self) -> Spring {
+        self.state.read().spring.clone()
+    }
+
+    /// The current physics; reading them subscribes the caller to changes
+    pub fn params(&self) -> SpringParams {
+        SpringParams::of(&self.state.read().spring)
+    }
+
+    /// Whether running animations switch to new values mid-flight
+    pub fn retargets(&self) -> bool {
+        self.state.read().retarget
+    }
+
+    /// Replace the physics, saving them if persistence is enabled
+    pub fn set_params(&mut self, params: SpringParams) {
+        let mut state = self.state.write();
+        state.spring = params.apply(&state.spring);
+        store(self.name, &params.encode());
+    }
+
+    /// Set the stiffness
+    pub fn set_stiffness(&mut self, stiffness: f32) {
+        let params = SpringParams::of(&self.state.peek().spring);
+        self.set_params(SpringParams {
+            stiffness,
+            ..params
+        });
+    }
+
+    /// Set the damping
+    pub fn set_damping(&mut self, damping: f32) {
+        let params = SpringParams::of(&self.state.peek().spring);
+        self.set_params(SpringParams { damping, ..params });
+    }
+
+    /// Set the mass
+    pub fn set_mass(&mut self, mass: f32) {
+        let params = SpringParams::of(&self.state.peek().spring);
+        self.set_params(SpringParams { mass, ..params });
+    }
+
+    /// Choose whether running animations switch to new values mid-flight
+    ///
+    /// Only motion values following the spring with [`use_retuning`]
+    /// are affected; otherwise new values apply from the next animation.
+    pub fn set_retarget(&mut self, retarget: bool) {
+        self.state.write().retarget = retarget;
+    }
+
+    /// Go back to the spring given in code, forgetting any saved tuning
+    pub fn reset(&mut self) {
+        let mut state = self.state.write();
+        state.spring = state.default.clone();
+        remove(self.name);
+    }
+}
+
+thread_local! {
+    static TUNABLES: RefCell<Vec<TunableSpring>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Every tunable spring registered so far, in registration order
+pub fn tunables() -> Vec<TunableSpring> {
+    TUNABLES.with_borrow(|tunables| tunables.clone())
+}
+
+/// Look up the tunable registered under `name`, or register one
+fn register(name: &'static str, default: Spring) -> TunableSpring {
+    TUNABLES.with_borrow_mut(|tunables| {
+        // Drop handles whose signals went away with a previous app
+        tunables.retain(|tunable| tunable.state.try_peek().is_ok());
+        if let Some(tunable) = tunables.iter().find(|tunable| tunable.name == name) {
+            return *tunable;
+        }
+
+        let state = TunableState {
+            spring: resolve(&default, load(name).as_deref()),
+            default,
+            retarget: false,
+        };
+        // Owned by the root scope so the spring outlives the component registering it
+        let tunable = TunableSpring {
+            name,
+            state: Signal::new_in_scope(state, ScopeId::ROOT),
+        };
+        tunables.push(tunable);
+        tunable
+    })
+}
+
+/// Create or look up the tunable spring named `name`
+///
+/// Components using the same name share one spring, so tuning it in one place
+/// tunes every animation using it. `default` is the spring given in code; a
+/// saved tuning takes precedence over it.
+pub fn use_tunable_spring(name: &'static str, default: Spring) -> TunableSpring {
+    use_hook(|| register(name, default))
+}
+
+impl<T: Animatable> SpringBuilder<T> {
+    /// Use the current physics of a tunable spring
+    pub fn spring_tunable(self, tunable: &TunableSpring) -> Self {
+        let params = tunable.params();
+        self.stiffness(params.stiffness)
+            .damping(params.damping)
+            .mass(params.mass)
+    }
+}
+
+/// Switch `motion`'s running spring to `tunable`'s new values as soon as they
+/// change
+///
+/// Only applies while [`TunableSpring::retargets`] is on. Like other hooks,
+/// call it unconditionally from the component body.
+pub fn use_retuning<T: Animatable>(mut motion: MotionValue<T>, tunable: TunableSpring) {
+    let last = use_hook(|| Rc::new(Cell::new(None)));
+
+    use_effect(move || {
+        let spring = tunable.spring();
+        let params = SpringParams::of(&spring);
+        // Skip the initial run and changes that leave the physics untouched
+        let changed = last
+            .replace(Some(params))
+            .is_some_and(|last| last != params);
+        if changed && tunable.retargets() {
+            motion.engine.write().retune_spring(&spring);
+        }
+    });
+}
+
+/// Debug panel with sliders for every registered tunable spring
+#[component]
+pub fn TuningPanel() -> Element {
+    rsx! {
+        div {
+            class: "motion-tuning-panel",
+            style: "position: fixed; bottom: 1rem; right: 1rem; z-index: 9999; padding: 0.75rem; background: rgba(0, 0, 0, 0.8); color: white; font: 12px monospace; border-radius: 6px;",
+            for tunable in tunables() {
+                TunableRow { key: "{tunable.name()}", tunable }
+            }
+        }
+    }
+}
+
+/// Sliders for one tunable spring
+#[component]
+fn TunableRow(tunable: TunableSpring) -> Element {
+    let params = tunable.params();
+
+    rsx! {
+        fieldset {
+            legend { "{tunable.name()}" }
+            label {
+                "stiffness {params.stiffness:.0}"
+                input {
+                    r#type: "range",
+                    min: "1",
+                    max: "1000",
+                    step: "1",
+                    value: "{params.stiffness}",
+                    oninput: move |event| {
+                        if let Ok(value) = event.value().parse() {
+                            tunable.set_stiffness(value);
+                        }
+                    },
+                }
+            }
+            label {
+                "damping {params.damping:.1}"
+                input {
+                    r#type: "range",
+                    min: "0",
+                    max: "100",
+                    step: "0.5",
+                    value: "{params.damping}",
+                    oninput: move |event| {
+                        if let Ok(value) = event.value().parse() {
+                            tunable.set_damping(value);
+                        }
+                    },
+                }
+            }
+            label {
+                "mass {params.mass:.1}"
+                input {
+                    r#type: "range",
+                    min: "0.1",
+                    max: "10",
+                    step: "0.1",
+                    value: "{params.mass}",
+                    oninput: move |event| {
+                        if let Ok(value) = event.value().parse() {
+                            tunable.set_mass(value);
+                        }
+                    },
+                }
+            }
+            label {
+                input {
+                    r#type: "checkbox",
+                    checked: tunable.retargets(),
+                    onchange: move |event| tunable.set_retarget(event.checked()),
+                }
+                "retarget running"
+            }
+            button { onclick: move |_| tunable.reset(), "reset" }
+        }
+    }
+}
+
+/// Storage key of a tunable's saved tuning
+#[cfg(feature = "persist-tuning")]
+fn storage_key(name: &str) -> String {
+    format!("dioxus-motion/tuning/{name}")
+}
+
+#[cfg(feature = "persist-tuning")]
+fn local_storage() -> Option<web_sys::Storage> {
+    // Outside a browser web-sys panics rather than finding no window
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Load a saved tuning
+#[cfg(feature = "persist-tuning")]
+fn load(name: &str) -> Option<String> {
+    local_storage()?.get_item(&storage_key(name)).ok()?
+}
+
+/// Save a tuning
+#[cfg(feature = "persist-tuning")]
+fn store(name: &str, value: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(&storage_key(name), value);
+    }
+}
+
+/// Forget a saved tuning
+#[cfg(feature = "persist-tuning")]
+fn remove(name: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(&storage_key(name));
+    }
+}
+
+#[cfg(not(feature = "persist-tuning"))]
+fn load(_name: &str) -> Option<String> {
+    None
+}
+
+#[cfg(not(feature = "persist-tuning"))]
+fn store(_name: &str, _value: &str) {}
+
+#[cfg(not(feature = "persist-tuning"))]
+fn remove(_name: &str) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+    use crate::animation::LoopMode;
+
+    #[test]
+    fn test_params_round_trip() {
+        let params = SpringParams {
+            stiffness: 320.0,
+            damping: 18.5,
+            mass: 1.2,
+        };
+        assert_eq!(SpringParams::decode(&params.encode()), Some(params));
+    }
+
+    #[test]
+    fn test_without_stored_tuning_uses_default() {
+        let default = Spring::new().stiffness(250.0);
+        assert_eq!(
+            SpringParams::of(&resolve(&default, None)),
+            SpringParams::of(&default)
+        );
+    }
+
+    #[test]
+    fn test_stored_tuning_overrides_physics_only() {
+        let mut default = Spring::new().stiffness(250.0);
+        default.timing.loop_mode = LoopMode::Count(2);
+
+        let spring = resolve(&default, Some("400, 30, 2"));
+        assert_eq!(
+            SpringParams::of(&spring),
+            SpringParams {
+                stiffness: 400.0,
+                damping: 30.0,
+                mass: 2.0,
+            }
+        );
+        assert_eq!(spring.timing.loop_mode, LoopMode::Count(2));
+    }
+
+    #[test]
+    fn test_invalid_stored_tuning_is_ignored() {
+        let default = Spring::default();
+        for stored in [
+            "",
+            "garbage",
+            "100,10",
+            "100,10,1,5",
+            "-100,10,1",
+            "100,NaN,1",
+        ] {
+            assert_eq!(
+                SpringParams::of(&resolve(&default, Some(stored))),
+                SpringParams::of(&default),
+                "{stored:?} should be ignored"
+            );
+        }
+    }
+
+    #[test]
+    fn test_retune_keeps_position_and_velocity() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_to(100.0, Spring::default());
+        for _ in 0..10 {
+            engine.update(1.0 / 60.0);
+        }
+        let before = engine.get();
+
+        let stiff = SpringParams {
+            stiffness: 800.0,
+            damping: 60.0,
+            mass: 1.0,
+        };
+        assert!(engine.retune_spring(&stiff.apply(&Spring::default())));
+        assert_eq!(engine.get(), before);
+
+        let mut frames = 0;
+        while engine.update(1.0 / 60.0) {
+            frames += 1;
+        }
+        assert_eq!(engine.get(), 100.0);
+        // Critically damped and stiff: settles well before the default spring would
+        assert!(frames < 60);
+
+        // Nothing to retune once idle
+        assert!(!engine.retune_spring(&Spring::default()));
+    }
+
+    type Retuned = (TunableSpring, MotionValue<f32>, Signal<u32>);
+
+    #[test]
+    fn test_retuning_survives_re_renders() {
+        thread_local! {
+            static STATE: Cell<Option<Retuned>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let renders = use_signal(|| 0u32);
+            let tunable = use_tunable_spring("retuned", Spring::default());
+            let y = crate::use_motion(0.0f32);
+            use_retuning(y, tunable);
+            STATE.set(Some((tunable, y, renders)));
+            rsx! { "{renders}" }
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some((mut tunable, y, mut renders)) = STATE.get() else {
+            unreachable!("the app stores its state");
+        };
+        let settle = |dom: &mut VirtualDom| {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        };
+        dom.in_runtime(|| {
+            tunable.set_retarget(true);
+            y.spring().animate_to(100.0);
+        });
+        settle(&mut dom);
+        dom.in_runtime(|| crate::driver::step(1.0 / 60.0));
+
+        // A re-render in between doesn't make the hook miss the next change
+        dom.in_runtime(|| renders += 1);
+        settle(&mut dom);
+        dom.in_runtime(|| {
+            tunable.set_stiffness(800.0);
+            tunable.set_damping(60.0);
+        });
+        settle(&mut dom);
+
+        let mut frames = 0;
+        while dom.in_runtime(|| crate::driver::step(1.0 / 60.0)) {
+            frames += 1;
+        }
+        assert_eq!(dom.in_runtime(|| y.peek()), 100.0);
+        assert!(frames < 60, "settled after {frames} frames");
+    }
+}
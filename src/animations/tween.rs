@@ -3,11 +3,19 @@
 //! Provides time-based animation with customizable easing functions.
 //! Supports duration and interpolation control for smooth animations.
 
+use std::fmt;
+
 use dioxus::signals::Writable;
 use easer::functions::{Easing, Linear};
 use instant::Duration;
 
-use crate::animation::{Animation, AnimationState, AnimationTiming};
+use crate::animation::{
+    Animation, AnimationState, AnimationTiming, AnimationToken, Callback, ConfigError, LoopMode,
+    MaybeSend, boxed,
+};
+use crate::animations::power::{AmbientPolicy, AmbientReduction};
+use crate::core::Approach;
+use crate::properties::transform::RotationWinding;
 use crate::{Animatable, MotionValue};
 
 /// Type alias for easing functions from the easer package
@@ -21,6 +29,8 @@ pub struct Tween {
     pub duration: Duration,
     /// Easing function for interpolation
     pub easing: EasingFunction,
+    /// Which way rotations turn, for values with a rotation
+    pub winding: RotationWinding,
 }
 
 impl Default for Tween {
@@ -28,7 +38,22 @@ impl Default for Tween {
         Self {
             duration: Duration::from_millis(300),
             easing: Linear::ease_in_out,
+            winding: RotationWinding::Shortest,
+        }
+    }
+}
+
+/// A short summary such as `Tween(300ms)`, with the winding when not the
+/// shortest way
+///
+/// The easing is a plain function and can't be named.
+impl fmt::Display for Tween {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tween({:?}", self.duration)?;
+        if self.winding != RotationWinding::Shortest {
+            write!(f, ", {:?}", self.winding)?;
         }
+        f.write_str(")")
     }
 }
 
@@ -50,6 +75,23 @@ impl Tween {
         self
     }
 
+    /// Set which way rotations turn, e.g. to spin a [`Transform`](crate::Transform)
+    /// by whole turns; ignored by values without a rotation
+    pub fn winding(mut self, winding: RotationWinding) -> Self {
+        self.winding = winding;
+        self
+    }
+
+    /// Check that the tween has a non-zero duration
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.duration.is_zero() {
+            return Err(ConfigError::ZeroDuration {
+                parameter: "duration",
+            });
+        }
+        Ok(())
+    }
+
     /// Create a tween animation with the current configuration
     pub fn create_animation<T: Animatable>(&self, initial: T, target: T) -> TweenAnimation<T> {
         TweenAnimation::new(initial, target, *self, AnimationTiming::default())
@@ -122,20 +164,27 @@ impl<T: Animatable> Animation for TweenAnimation<T> {
         let eased_progress = (self.tween.easing)(progress, 0.0, 1.0, 1.0);
 
         // Update current value
-        self.current = self.initial.interpolate(&self.target, eased_progress);
+        self.current =
+            self.initial
+                .interpolate_winding(&self.target, eased_progress, self.tween.winding);
 
         // Calculate velocity (approximation)
         let velocity = if dt > 0.0 {
-            let prev_progress = if duration > 0.0 {
+            let mut prev_progress = if duration > 0.0 {
                 ((self.elapsed.as_secs_f32() - dt) / duration).clamp(0.0, 1.0)
             } else {
                 1.0
             };
+            if self.timing.is_reverse() {
+                prev_progress = 1.0 - prev_progress;
+            }
 
             let prev_eased = (self.tween.easing)(prev_progress, 0.0, 1.0, 1.0);
-            let prev_value = self.initial.interpolate(&self.target, prev_eased);
+            let prev_value =
+                self.initial
+                    .interpolate_winding(&self.target, prev_eased, self.tween.winding);
 
-            prev_value.sub(&self.current).scale(1.0 / dt)
+            self.current.sub(&prev_value).scale(1.0 / dt)
         } else {
             T::zero()
         };
@@ -189,14 +238,34 @@ impl<T: Animatable> Animation for TweenAnimation<T> {
     fn is_active(&self) -> bool {
         self.is_active
     }
+
+    fn remaining_time(&self) -> Option<Duration> {
+        if !self.is_active {
+            return Some(Duration::ZERO);
+        }
+        let pass = self.tween.duration;
+        let loops = self.timing.remaining_loops()?;
+        Some(self.timing.remaining_delay() + pass.saturating_sub(self.elapsed) + pass * loops)
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(self.tween.duration * self.timing.total_loops()?)
+    }
+
+    fn finish_loop(&mut self) -> bool {
+        self.timing.finish_current_loop();
+        self.is_active
+    }
 }
 
 /// Builder for tween animations
 pub struct TweenBuilder<T: Animatable> {
     motion: MotionValue<T>,
     tween: Tween,
+    timing: AnimationTiming,
     target: Option<T>,
-    completion_callback: Option<Box<dyn FnOnce() + Send>>,
+    completion_callback: Option<Callback>,
+    ambient: bool,
 }
 
 impl<T: Animatable> TweenBuilder<T> {
@@ -205,8 +274,10 @@ impl<T: Animatable> TweenBuilder<T> {
         Self {
             motion,
             tween: Tween::default(),
+            timing: AnimationTiming::default(),
             completion_callback: None,
             target: None,
+            ambient: false,
         }
     }
 
@@ -222,9 +293,28 @@ impl<T: Animatable> TweenBuilder<T> {
         self
     }
 
+    /// Set which way rotations turn; see [`Tween::winding`]
+    pub fn winding(mut self, winding: RotationWinding) -> Self {
+        self.tween.winding = winding;
+        self
+    }
+
+    /// Set the loop mode for the animation
+    pub fn loop_mode(mut self, mode: LoopMode) -> Self {
+        self.timing.loop_mode = mode;
+        self
+    }
+
     /// Add completion callback
-    pub fn on_complete<F: FnOnce() + Send + 'static>(mut self, callback: F) -> Self {
-        self.completion_callback = Some(Box::new(callback));
+    pub fn on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.completion_callback = Some(boxed(callback));
+        self
+    }
+
+    /// Mark this tween as ambient decoration, cut back to save power and data;
+    /// see [`SpringBuilder::ambient`](crate::animations::spring::SpringBuilder::ambient)
+    pub fn ambient(mut self) -> Self {
+        self.ambient = true;
         self
     }
 
@@ -235,25 +325,59 @@ impl<T: Animatable> TweenBuilder<T> {
     }
 
     /// Build the animation for use in sequences or groups
-    pub fn build(self) -> Box<dyn Animation<Value = T> + Send> {
+    pub fn build(self) -> Box<dyn Animation<Value = T>> {
         let target = self.target.unwrap_or_else(|| self.motion.get());
-        Box::new(self.tween.create_animation(self.motion.get(), target))
+        Box::new(TweenAnimation::new(
+            self.motion.get(),
+            target,
+            self.tween,
+            self.timing,
+        ))
+    }
+
+    /// Start animation to the current value moved by `delta`
+    ///
+    /// Like [`MotionValue::animate_by`], the delta applies to the value at call
+    /// time, even mid-flight.
+    pub fn by(self, delta: T) -> AnimationToken {
+        let target = self.motion.peek().add(&delta);
+        self.animate_to(target)
     }
 
     /// Start animation to target value
-    pub fn animate_to(mut self, target: T) -> MotionValue<T> {
-        // Apply the completion callback if provided
-        if let Some(callback) = self.completion_callback {
-            self.motion.engine.write().add_completion_callback(callback);
+    ///
+    /// Starts at the next frame, like
+    /// [`MotionValue::animate_to_with_config`]; the last animate call before
+    /// it wins.
+    pub fn animate_to(mut self, target: T) -> AnimationToken {
+        let reduction = if self.ambient {
+            AmbientPolicy::current()
+        } else {
+            AmbientReduction::none()
+        };
+        if reduction.skip {
+            return AnimationToken::ended();
         }
-
-        self.motion.engine.write().tween_to(target, self.tween);
-        self.motion
+        self.timing.loop_mode = reduction.limit(self.timing.loop_mode);
+        {
+            let mut engine = self.motion.engine.write();
+            engine.queue_with(
+                target,
+                Approach::Tween(self.tween, self.timing),
+                reduction.playback(),
+            );
+            if let Some(callback) = self.completion_callback {
+                engine.add_completion_callback(callback);
+            }
+            if reduction.jump {
+                engine.complete_now();
+            }
+        }
+        self.motion.started()
     }
 
     /// Create a sequence-compatible tween animation
-    pub fn into_sequence(self) -> Box<dyn Animation<Value = T> + Send> {
-        let target = self.target.unwrap_or_else(|| self.motion.get());
-        Box::new(self.tween.create_animation(self.motion.get(), target))
+    pub fn into_sequence(self) -> Box<dyn Animation<Value = T>> {
+        self.build()
     }
 }
@@ -0,0 +1,475 @@
+//! Shims of the dioxus-motion (v1) API, for migrating a step at a time
+//!
+//! Swap `use dioxus_motion::prelude::*` for `use dioxus_motion2::compat::*`
+//! and v1 code builds again: [`use_motion`] returns a handle with the old
+//! [`AnimationManager`] methods, and [`AnimationConfig`], [`Spring`],
+//! [`Tween`] and [`AnimationSequence`] keep their v1 fields and constructors.
+//! Everything here is deprecated, so the compiler lists what is left to port;
+//! each item converts into its new counterpart with `into()`, and
+//! [`AnimationManager::motion`] hands out the [`MotionValue`] underneath, so
+//! old and new calls can be mixed while porting.
+//!
+//! | v1 | dioxus-motion2 |
+//! |----|----------------|
+//! | `use_motion(x)` | [`crate::use_motion`] |
+//! | `value.animate_to(x, config)` | [`MotionValue::animate_to_with_config`] |
+//! | `value.animate_sequence(sequence)` | [`MotionValue::animate_to_with_config`], then [`MotionValue::queue`] per step |
+//! | `value.animate_keyframes(keyframes)` | [`MotionValue::animate_keyframes`] |
+//! | `value.get_value()` | [`MotionValue::get`] |
+//! | `value.is_running()` | [`MotionValue::is_animating`] |
+//! | `value.stop()` | [`MotionValue::stop`] |
+//! | `Spring { stiffness, damping, mass, velocity }` | [`Spring`](v2::Spring), `velocity` as `initial_velocity` |
+//! | `Tween::new(duration).with_easing(f)` | `Tween::new().duration(duration).easing(f)` |
+//! | `LoopMode::Times(n)` | [`LoopMode::Count`](v2::LoopMode::Count) |
+//! | `AnimationConfig { delay, .. }` | [`AnimationConfig::with_delay`](v2::AnimationConfig::with_delay) |
+//!
+//! Not shimmed: `reset`, `delay` and `update` on the handle, and
+//! `with_epsilon` on configs; springs settle on the type's own
+//! [`epsilon`](crate::Animatable::epsilon).
+
+// The shims are deprecated for their users, not for their own implementation
+#![allow(deprecated)]
+
+use instant::Duration;
+
+use crate::animation::{Callback, MaybeSend, boxed};
+use crate::animations::keyframe::KeyframeAnimation;
+use crate::prelude as v2;
+use crate::{Animatable, MotionValue};
+
+/// The v1 spring, with its initial velocity as `velocity`
+#[deprecated(note = "use `dioxus_motion2::prelude::Spring`")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// Spring stiffness coefficient (default: 100.0)
+    pub stiffness: f32,
+    /// Damping coefficient (default: 10.0)
+    pub damping: f32,
+    /// Mass of the object (default: 1.0)
+    pub mass: f32,
+    /// Initial velocity, none when zero (default: 0.0)
+    pub velocity: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self {
+            stiffness: 100.0,
+            damping: 10.0,
+            mass: 1.0,
+            velocity: 0.0,
+        }
+    }
+}
+
+impl From<Spring> for v2::Spring {
+    fn from(spring: Spring) -> Self {
+        Self {
+            stiffness: spring.stiffness,
+            damping: spring.damping,
+            mass: spring.mass,
+            initial_velocity: (spring.velocity != 0.0).then_some(spring.velocity),
+            ..Self::default()
+        }
+    }
+}
+
+/// The v1 tween, built from its duration
+#[deprecated(note = "use `dioxus_motion2::prelude::Tween`")]
+#[derive(Debug, Clone, Copy)]
+pub struct Tween {
+    /// Duration of the animation
+    pub duration: Duration,
+    /// Easing function for interpolation
+    pub easing: fn(f32, f32, f32, f32) -> f32,
+}
+
+impl Default for Tween {
+    fn default() -> Self {
+        let tween = v2::Tween::default();
+        Self {
+            duration: tween.duration,
+            easing: tween.easing,
+        }
+    }
+}
+
+impl Tween {
+    /// A linear tween lasting `duration`
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            ..Self::default()
+        }
+    }
+
+    /// Ease with `easing`, e.g. one of `easer::functions`
+    pub fn with_easing(mut self, easing: fn(f32, f32, f32, f32) -> f32) -> Self {
+        self.easing = easing;
+        self
+    }
+}
+
+impl From<Tween> for v2::Tween {
+    fn from(tween: Tween) -> Self {
+        Self::new().duration(tween.duration).easing(tween.easing)
+    }
+}
+
+/// The v1 animation mode, holding the v1 spring and tween
+#[deprecated(note = "use `dioxus_motion2::prelude::AnimationMode`")]
+#[derive(Debug, Clone, Copy)]
+pub enum AnimationMode {
+    Spring(Spring),
+    Tween(Tween),
+}
+
+impl From<AnimationMode> for v2::AnimationMode {
+    fn from(mode: AnimationMode) -> Self {
+        match mode {
+            AnimationMode::Spring(spring) => Self::Spring(spring.into()),
+            AnimationMode::Tween(tween) => Self::Tween(tween.into()),
+        }
+    }
+}
+
+/// The v1 loop mode, counting passes in a `u8`
+#[deprecated(note = "use `dioxus_motion2::prelude::LoopMode`")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LoopMode {
+    #[default]
+    None,
+    Infinite,
+    Times(u8),
+}
+
+impl From<LoopMode> for v2::LoopMode {
+    fn from(mode: LoopMode) -> Self {
+        match mode {
+            LoopMode::None => Self::None,
+            LoopMode::Infinite => Self::Infinite,
+            LoopMode::Times(times) => Self::Count(times.into()),
+        }
+    }
+}
+
+/// The v1 animation config, with a plain `delay`
+#[deprecated(note = "use `dioxus_motion2::prelude::AnimationConfig`")]
+pub struct AnimationConfig {
+    /// The animation mode (spring or tween)
+    pub mode: AnimationMode,
+    /// Loop configuration
+    pub loop_mode: Option<LoopMode>,
+    /// Delay before the animation starts, none when zero
+    pub delay: Duration,
+    /// Callback to run on completion
+    pub on_complete: Option<Callback>,
+}
+
+impl AnimationConfig {
+    /// Animate as `mode` says, once and without delay
+    pub fn new(mode: AnimationMode) -> Self {
+        Self {
+            mode,
+            loop_mode: None,
+            delay: Duration::ZERO,
+            on_complete: None,
+        }
+    }
+
+    /// Set the loop mode
+    pub fn with_loop(mut self, loop_mode: LoopMode) -> Self {
+        self.loop_mode = Some(loop_mode);
+        self
+    }
+
+    /// Set the delay before the animation starts
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Set the completion callback
+    pub fn with_on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.on_complete = Some(boxed(callback));
+        self
+    }
+
+    /// Length of one pass of a tween, `None` for springs
+    pub fn get_duration(&self) -> Option<Duration> {
+        match self.mode {
+            AnimationMode::Spring(_) => None,
+            AnimationMode::Tween(tween) => Some(tween.duration),
+        }
+    }
+}
+
+impl From<AnimationConfig> for v2::AnimationConfig {
+    fn from(config: AnimationConfig) -> Self {
+        Self {
+            mode: config.mode.into(),
+            loop_mode: config.loop_mode.map(Into::into),
+            delay: (!config.delay.is_zero()).then_some(config.delay),
+            on_complete: config.on_complete,
+        }
+    }
+}
+
+/// The v1 sequence: targets played one after another, each with its config
+#[deprecated(note = "animate the first step, then `MotionValue::queue` the rest")]
+pub struct AnimationSequence<T: Animatable> {
+    steps: Vec<(T, AnimationConfig)>,
+    on_complete: Option<Callback>,
+}
+
+impl<T: Animatable> Default for AnimationSequence<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Animatable> AnimationSequence<T> {
+    /// An empty sequence
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// An empty sequence with room for `capacity` steps
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            steps: Vec::with_capacity(capacity),
+            on_complete: None,
+        }
+    }
+
+    /// Animate to `target` as `config` says once the steps before have settled
+    pub fn then(mut self, target: T, config: AnimationConfig) -> Self {
+        self.steps.push((target, config));
+        self
+    }
+
+    /// Run `callback` once the last step settles
+    pub fn on_complete<F: FnOnce() + MaybeSend + 'static>(mut self, callback: F) -> Self {
+        self.on_complete = Some(boxed(callback));
+        self
+    }
+}
+
+/// The methods of the v1 motion handle, returned by [`use_motion`]
+#[deprecated(note = "use the methods of `dioxus_motion2::MotionValue`")]
+pub trait AnimationManager<T: Animatable>: Clone + Copy {
+    /// Animate to `target` as `config` says, replacing the running animation
+    fn animate_to(&mut self, target: T, config: AnimationConfig);
+
+    /// Play the steps of `sequence` one after another
+    fn animate_sequence(&mut self, sequence: AnimationSequence<T>);
+
+    /// Play `keyframes`
+    fn animate_keyframes(&mut self, keyframes: KeyframeAnimation<T>);
+
+    /// The current value
+    fn get_value(&self) -> T;
+
+    /// Whether an animation is running or about to start
+    fn is_running(&self) -> bool;
+
+    /// Stop the running animation where it is
+    fn stop(&mut self);
+
+    /// The motion value underneath, for the calls already ported
+    fn motion(&self) -> MotionValue<T>;
+}
+
+impl<T: Animatable> AnimationManager<T> for MotionValue<T> {
+    fn animate_to(&mut self, target: T, config: AnimationConfig) {
+        self.animate_to_with_config(target, config.into());
+    }
+
+    fn animate_sequence(&mut self, sequence: AnimationSequence<T>) {
+        let AnimationSequence { steps, on_complete } = sequence;
+        let last = steps.len().checked_sub(1);
+        let mut on_complete = on_complete;
+        for (index, (target, config)) in steps.into_iter().enumerate() {
+            let mut config = v2::AnimationConfig::from(config);
+            if Some(index) == last
+                && let Some(sequence_done) = on_complete.take()
+            {
+                config.on_complete = Some(match config.on_complete.take() {
+                    Some(step_done) => boxed(move || {
+                        step_done();
+                        sequence_done();
+                    }),
+                    None => sequence_done,
+                });
+            }
+            if index == 0 {
+                self.animate_to_with_config(target, config);
+            } else {
+                self.queue(target, config);
+            }
+        }
+        // An empty sequence is over at once
+        if let Some(sequence_done) = on_complete {
+            sequence_done();
+        }
+    }
+
+    fn animate_keyframes(&mut self, keyframes: KeyframeAnimation<T>) {
+        MotionValue::animate_keyframes(self, keyframes);
+    }
+
+    fn get_value(&self) -> T {
+        self.get()
+    }
+
+    fn is_running(&self) -> bool {
+        self.is_animating()
+    }
+
+    fn stop(&mut self) {
+        MotionValue::stop(self);
+    }
+
+    fn motion(&self) -> MotionValue<T> {
+        *self
+    }
+}
+
+/// The v1 hook, a [`crate::use_motion`] value with the v1 methods
+///
+/// Only the [`AnimationManager`] methods can be called on the handle, as in
+/// v1; [`AnimationManager::motion`] gives the full [`MotionValue`].
+#[deprecated(note = "use `dioxus_motion2::use_motion`")]
+pub fn use_motion<T: Animatable>(initial: T) -> impl AnimationManager<T> {
+    crate::use_motion(initial)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use easer::functions::{Cubic, Easing};
+
+    #[test]
+    fn test_old_configs_map_onto_the_new_ones() {
+        let old = AnimationConfig::new(AnimationMode::Spring(Spring {
+            stiffness: 300.0,
+            damping: 20.0,
+            mass: 2.0,
+            velocity: 5.0,
+        }))
+        .with_loop(LoopMode::Times(2))
+        .with_delay(Duration::from_millis(100));
+        let new = v2::AnimationConfig::new(v2::AnimationMode::Spring(v2::Spring {
+            stiffness: 300.0,
+            damping: 20.0,
+            mass: 2.0,
+            initial_velocity: Some(5.0),
+            ..v2::Spring::default()
+        }))
+        .with_loop(v2::LoopMode::Count(2))
+        .with_delay(Duration::from_millis(100));
+        assert_eq!(v2::AnimationConfig::from(old), new);
+
+        let old = AnimationConfig::new(AnimationMode::Tween(
+            Tween::new(Duration::from_millis(400)).with_easing(Cubic::ease_in_out),
+        ));
+        assert_eq!(old.get_duration(), Some(Duration::from_millis(400)));
+        let new = v2::AnimationConfig::new(v2::AnimationMode::Tween(
+            v2::Tween::new()
+                .duration(Duration::from_millis(400))
+                .easing(Cubic::ease_in_out),
+        ));
+        assert_eq!(v2::AnimationConfig::from(old), new);
+
+        // Defaults agree too, and no delay is no delay
+        let old = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        assert_eq!(
+            v2::AnimationConfig::from(old),
+            v2::AnimationConfig::default()
+        );
+        let old = AnimationConfig::new(AnimationMode::Tween(Tween::default()));
+        assert_eq!(
+            v2::AnimationConfig::from(old),
+            v2::AnimationConfig::new(v2::AnimationMode::Tween(v2::Tween::default()))
+        );
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_old_calls_move_like_the_new_ones() {
+        use std::cell::Cell;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use dioxus::prelude::*;
+
+        use crate::testing::motion_test_harness;
+
+        thread_local! {
+            static OLD: Cell<Option<MotionValue<f32>>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let mut old = use_motion(0.0f32);
+            let mut new = crate::use_motion(0.0f32);
+            OLD.set(Some(old.motion()));
+            use_hook(move || {
+                let spring = Spring {
+                    stiffness: 200.0,
+                    damping: 12.0,
+                    mass: 1.0,
+                    velocity: -50.0,
+                };
+                old.animate_to(100.0, AnimationConfig::new(AnimationMode::Spring(spring)));
+                new.animate_to_with_config(
+                    100.0,
+                    v2::AnimationConfig::new(v2::AnimationMode::Spring(spring.into())),
+                );
+            });
+            rsx! {}
+        }
+
+        let mut harness = motion_test_harness(app);
+        for _ in 0..90 {
+            harness.tick(Duration::from_millis(16));
+            let values: Vec<_> = harness
+                .motion_values()
+                .iter()
+                .filter_map(|snapshot| snapshot.value::<f32>())
+                .collect();
+            assert_eq!(values.len(), 2);
+            assert_eq!(values[0], values[1]);
+        }
+
+        // A sequence plays its steps in turn, each from where the last ended
+        let Some(mut old) = OLD.get() else {
+            unreachable!("the app stores its old-style value");
+        };
+        let tween = |millis| {
+            AnimationConfig::new(AnimationMode::Tween(Tween::new(Duration::from_millis(
+                millis,
+            ))))
+        };
+        let done = Arc::new(AtomicUsize::new(0));
+        let counter = done.clone();
+        harness.act(|| {
+            old.animate_sequence(
+                AnimationSequence::new()
+                    .then(10.0, tween(100))
+                    .then(20.0, tween(100))
+                    .then(30.0, tween(100))
+                    .on_complete(move || {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                    }),
+            );
+        });
+        assert!(harness.act(|| old.is_running()));
+        for target in [10.0, 20.0, 30.0] {
+            harness.tick(Duration::from_millis(100));
+            let value = harness.act(|| old.get_value());
+            assert!((value - target).abs() < 1e-3);
+        }
+        assert_eq!(done.load(Ordering::SeqCst), 1);
+        assert!(!harness.act(|| old.is_running()));
+    }
+}
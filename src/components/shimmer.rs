@@ -0,0 +1,363 @@
+//! Skeleton loading shimmer
+//!
+//! [`Shimmer`] renders a block with a highlight sweeping across it, the usual
+//! placeholder while content loads. Every shimmer on screen reads one shared
+//! clock, a single motion value counting seconds, instead of running a looping
+//! animation each; a block's `index` offsets its sweep so a column of skeleton
+//! lines ripples instead of flashing in unison.
+//!
+//! The clock only runs while at least one shimmer is visible. On the web each
+//! block watches itself with an `IntersectionObserver`, so blocks scrolled out
+//! of view stop costing frames; elsewhere a mounted block counts as visible.
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn ArticleSkeleton() -> Element {
+//!     rsx! {
+//!         for index in 0..4 {
+//!             Shimmer { key: "{index}", index, style: "height: 1rem; margin-bottom: 0.5rem" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::animation::AnimationState;
+use crate::{Animation, AnimationEngine, Color, Duration, MotionValue, driver};
+
+/// Fraction of a sweep between the highlights of consecutive indices
+const PHASE_STEP: f32 = 0.08;
+
+thread_local! {
+    static CLOCK: RefCell<Option<SharedClock>> = const { RefCell::new(None) };
+}
+
+/// The clock every shimmer on the thread reads, and how many are visible
+struct SharedClock {
+    seconds: MotionValue<f32>,
+    visibility: Visibility,
+}
+
+/// Counts visible shimmers, telling when the clock must start or pause
+#[derive(Debug, Default)]
+struct Visibility {
+    visible: usize,
+}
+
+impl Visibility {
+    /// A shimmer came into view; returns whether the clock must start
+    fn show(&mut self) -> bool {
+        self.visible += 1;
+        self.visible == 1
+    }
+
+    /// A shimmer left the view; returns whether the clock must pause
+    fn hide(&mut self) -> bool {
+        if self.visible == 0 {
+            return false;
+        }
+        self.visible -= 1;
+        self.visible == 0
+    }
+}
+
+/// The shared clock, created on first use
+///
+/// Owned by the root scope, like tunable springs, so it outlives the shimmers
+/// using it; a clock left over from a previous app is replaced.
+fn clock() -> MotionValue<f32> {
+    CLOCK.with_borrow_mut(|clock| {
+        if let Some(clock) = clock
+            && clock.seconds.engine.try_peek().is_ok()
+        {
+            return clock.seconds;
+        }
+
+        let id = driver::next_id();
+        let engine = AnimationEngine::new(0.0f32).with_driver_id(id);
+        let signal = Signal::new_in_scope(engine, ScopeId::ROOT);
+        crate::drive(id, signal, None);
+        let seconds = MotionValue::new(signal);
+        *clock = Some(SharedClock {
+            seconds,
+            visibility: Visibility::default(),
+        });
+        seconds
+    })
+}
+
+/// Count a shimmer in or out of view, starting or pausing the clock
+fn set_visible(visible: bool) {
+    let change = CLOCK.with_borrow_mut(|clock| {
+        let clock = clock.as_mut()?;
+        let toggle = if visible {
+            clock.visibility.show()
+        } else {
+            clock.visibility.hide()
+        };
+        toggle.then_some(clock.seconds)
+    });
+    // Outside the borrow, as starting and stopping notify subscribers
+    let Some(mut seconds) = change else {
+        return;
+    };
+    if visible {
+        let start = seconds.peek();
+        seconds.animate_custom(Ticker {
+            seconds: start,
+            start,
+        });
+    } else {
+        seconds.stop();
+    }
+}
+
+/// Counts seconds for as long as it runs
+struct Ticker {
+    seconds: f32,
+    start: f32,
+}
+
+impl Animation for Ticker {
+    type Value = f32;
+
+    fn update(&mut self, dt: f32) -> (AnimationState, f32, f32) {
+        self.seconds += dt;
+        (AnimationState::Active, self.seconds, 1.0)
+    }
+
+    fn value(&self) -> f32 {
+        self.seconds
+    }
+
+    fn velocity(&self) -> f32 {
+        1.0
+    }
+
+    fn reset(&mut self) {
+        self.seconds = self.start;
+    }
+
+    fn is_active(&self) -> bool {
+        true
+    }
+}
+
+/// Whether one shimmer is counted as visible
+///
+/// Reports only changes to the shared count, and counts itself out when dropped
+/// with its component.
+#[derive(Default)]
+struct Presence {
+    visible: Cell<bool>,
+}
+
+impl Presence {
+    fn set(&self, visible: bool) {
+        if self.visible.replace(visible) != visible {
+            set_visible(visible);
+        }
+    }
+}
+
+impl Drop for Presence {
+    fn drop(&mut self) {
+        self.set(false);
+    }
+}
+
+/// Position of a shimmer's highlight through its sweep, from 0 to 1
+///
+/// `seconds` is the shared clock and `period` the time one sweep takes. Higher
+/// indices trail behind by [`PHASE_STEP`] of a sweep each.
+fn phase(seconds: f32, period: Duration, index: usize) -> f32 {
+    let period = period.as_secs_f32();
+    if period <= 0.0 {
+        return 0.0;
+    }
+    (seconds / period - index as f32 * PHASE_STEP).rem_euclid(1.0)
+}
+
+/// Inline style painting the highlight at `phase`
+///
+/// The gradient is three times as wide as the block, so moving its position
+/// from 100% to 0% carries the highlight from just off the left edge to just
+/// off the right one.
+fn shimmer_style(phase: f32, angle: f32, base: Color, highlight: Color) -> String {
+    let (base, highlight) = (base.to_css_string(), highlight.to_css_string());
+    format!(
+        "background-image: linear-gradient({angle}deg, {base} 0%, {base} 40%, {highlight} 50%, {base} 60%, {base} 100%); background-size: 300% 100%; background-position: {:.2}% 0;",
+        (1.0 - phase) * 100.0
+    )
+}
+
+/// Skeleton block with a sweeping highlight, for content still loading
+///
+/// Size it with `class` or `style`. `duration` is the time one sweep takes and
+/// `angle` the direction of the gradient in degrees; `index` staggers blocks
+/// shown together. All shimmers share one clock, paused while none is visible.
+#[component]
+pub fn Shimmer(
+    /// Position among the shimmers shown together, offsetting the sweep
+    #[props(default)]
+    index: usize,
+    /// Time one sweep takes
+    #[props(default = Duration::from_millis(1500))]
+    duration: Duration,
+    /// Direction of the gradient, in degrees
+    #[props(default = 100.0)]
+    angle: f32,
+    /// Color of the block
+    #[props(default = Color::from_rgba(226, 229, 231, 255))]
+    base: Color,
+    /// Color of the highlight
+    #[props(default = Color::from_rgba(245, 246, 247, 255))]
+    highlight: Color,
+    /// Classes of the block
+    #[props(default)]
+    class: String,
+    /// Extra inline style of the block
+    #[props(default)]
+    style: String,
+) -> Element {
+    let seconds = use_hook(clock);
+    let presence = use_hook(|| Rc::new(Presence::default()));
+    let observer = use_observer(presence.clone());
+
+    let phase = phase(seconds.get(), duration, index);
+    let shimmer = shimmer_style(phase, angle, base, highlight);
+
+    rsx! {
+        div {
+            class: "motion-shimmer {class}",
+            style: "{shimmer} {style}",
+            onmounted: move |event| observer(&event.data()),
+        }
+    }
+}
+
+/// Track whether the shimmer is on screen with an `IntersectionObserver`
+///
+/// Returns the `onmounted` handler starting the observation; the observer is
+/// disconnected when the component unmounts.
+#[cfg(feature = "web")]
+fn use_observer(presence: Rc<Presence>) -> impl Fn(&MountedData) + Copy {
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::{JsCast, JsValue};
+
+    type Observer = (
+        web_sys::IntersectionObserver,
+        Closure<dyn FnMut(Vec<JsValue>)>,
+    );
+
+    let observer = use_signal(|| None::<Observer>);
+    use_drop(move || {
+        let mut observer = observer;
+        if let Ok(mut observer) = observer.try_write()
+            && let Some((observer, _)) = observer.take()
+        {
+            observer.disconnect();
+        }
+    });
+    let presence = use_hook(|| CopyValue::new(presence));
+
+    move |mounted: &MountedData| {
+        let Some(element) = mounted.downcast::<web_sys::Element>().cloned() else {
+            return;
+        };
+        let presence = presence.read().clone();
+        let callback = Closure::<dyn FnMut(Vec<JsValue>)>::new(move |entries: Vec<JsValue>| {
+            // The latest entry tells the current state
+            if let Some(entry) = entries.into_iter().last() {
+                let entry = entry.unchecked_into::<web_sys::IntersectionObserverEntry>();
+                presence.set(entry.is_intersecting());
+            }
+        });
+        let Ok(created) = web_sys::IntersectionObserver::new(callback.as_ref().unchecked_ref())
+        else {
+            return;
+        };
+        created.observe(&element);
+        let mut observer = observer;
+        if let Some((previous, _)) = observer.write().replace((created, callback)) {
+            previous.disconnect();
+        }
+    }
+}
+
+/// Count the shimmer as visible while it is mounted
+///
+/// Without an `IntersectionObserver`, a mounted shimmer is taken to be on screen.
+#[cfg(not(feature = "web"))]
+fn use_observer(presence: Rc<Presence>) -> impl Fn(&MountedData) + Copy {
+    use_hook(move || presence.set(true));
+    |_: &MountedData| {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_wraps_and_offsets_by_index() {
+        let period = Duration::from_millis(1000);
+        assert_eq!(phase(0.0, period, 0), 0.0);
+        assert!((phase(0.25, period, 0) - 0.25).abs() < 1e-6);
+        assert!((phase(3.25, period, 0) - 0.25).abs() < 1e-4);
+
+        // Later indices trail behind, wrapping below zero
+        assert!((phase(0.25, period, 1) - (0.25 - PHASE_STEP)).abs() < 1e-6);
+        assert!((phase(0.0, period, 1) - (1.0 - PHASE_STEP)).abs() < 1e-6);
+
+        // A slower sweep moves proportionally less
+        assert!((phase(0.25, Duration::from_millis(2000), 0) - 0.125).abs() < 1e-6);
+        assert_eq!(phase(1.0, Duration::ZERO, 3), 0.0);
+    }
+
+    #[test]
+    fn test_clock_runs_while_any_shimmer_is_visible() {
+        let mut visibility = Visibility::default();
+        assert!(visibility.show(), "first visible shimmer starts the clock");
+        assert!(!visibility.show());
+        assert!(!visibility.hide());
+        assert!(visibility.hide(), "last hidden shimmer pauses the clock");
+
+        // Extra hides don't underflow into a stuck count
+        assert!(!visibility.hide());
+        assert!(visibility.show());
+    }
+
+    #[test]
+    fn test_highlight_sweeps_left_to_right() {
+        let base = Color::black();
+        let highlight = Color::white();
+        assert!(
+            shimmer_style(0.0, 90.0, base, highlight).contains("background-position: 100.00% 0")
+        );
+        assert!(
+            shimmer_style(0.75, 90.0, base, highlight).contains("background-position: 25.00% 0")
+        );
+        assert!(shimmer_style(0.0, 90.0, base, highlight).contains("linear-gradient(90deg"));
+    }
+
+    #[test]
+    fn test_ticker_counts_seconds_from_where_it_paused() {
+        let mut engine = AnimationEngine::new(2.0f32);
+        engine.apply_custom(Ticker {
+            seconds: 2.0,
+            start: 2.0,
+        });
+        for _ in 0..30 {
+            assert!(engine.update(1.0 / 60.0));
+        }
+        engine.stop();
+        assert!((engine.get() - 2.5).abs() < 1e-4);
+    }
+}
@@ -4,22 +4,187 @@
 //! that form the foundation of the library.
 
 use dioxus::prelude::*;
-use std::sync::{Arc, Mutex};
+use instant::Duration;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::sync::Arc;
 
 use crate::Animatable;
 use crate::MotionTime;
-use crate::animation::{Animation, AnimationConfig, AnimationMode, AnimationState};
-use crate::animations::keyframe::KeyframeAnimation;
+use crate::animation::{
+    Animation, AnimationConfig, AnimationMode, AnimationState, AnimationTiming, AnimationToken,
+    Callback, LoopMode, MaybeSend, Run, RunOutcome, boxed,
+};
+use crate::animations::blend::Blend;
+use crate::animations::bounce::BounceBounds;
+use crate::animations::cues::Cues;
+use crate::animations::keyframe::{KeyframeAnimation, KeyframeTrack};
+use crate::animations::noise::Noise;
+use crate::animations::power::Throttled;
 use crate::animations::spring::Spring;
 use crate::animations::spring::SpringBuilder;
 use crate::animations::tween::Tween;
+use crate::animations::tween::TweenAnimation;
 use crate::animations::tween::TweenBuilder;
+use crate::driver;
+use crate::events::{self, AnimationKind, MotionEvent};
 use crate::platform::TimeProvider;
 use crate::platform::request_animation_frame;
 use crate::prelude::sequence::AnimationSequence;
 use crate::prelude::sequence::SequenceBuilder;
 
 use tokio_with_wasm::alias as tokio;
+use tracing::debug;
+
+/// Told each new value of a followed engine; returns whether to keep following
+type FollowerFn<T> = Box<dyn FnMut(&T) -> bool>;
+
+/// A signal following one `f32` part of a value, e.g. a transform's rotation
+struct Part<T> {
+    name: &'static str,
+    read: fn(&T) -> f32,
+    signal: Signal<f32>,
+}
+
+/// Renders a value, and whether it is animating, to an inline style for
+/// direct style binding
+#[cfg(target_arch = "wasm32")]
+pub(crate) type StyleFn<T> = Box<dyn Fn(&T, bool) -> String>;
+
+/// Renders a value, and whether it is animating, to an inline style for
+/// direct style binding
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) type StyleFn<T> = Box<dyn Fn(&T, bool) -> String + Send>;
+
+/// How a spring, tween or keyframe track heads to its target
+pub(crate) enum Approach<T: Animatable> {
+    Spring(Spring, Option<Noise<T>>, Option<BounceBounds>),
+    Tween(Tween, AnimationTiming),
+    Keyframes(KeyframeTrack, AnimationTiming),
+}
+
+impl<T: Animatable> Approach<T> {
+    /// Loop and delay the animation as an [`AnimationConfig`] asks
+    fn timed(mut self, loop_mode: Option<LoopMode>, delay: Option<Duration>) -> Self {
+        let timing = match &mut self {
+            Self::Spring(spring, _, _) => &mut spring.timing,
+            Self::Tween(_, timing) | Self::Keyframes(_, timing) => timing,
+        };
+        if let Some(loop_mode) = loop_mode {
+            timing.loop_mode = loop_mode;
+        }
+        if let Some(delay) = delay {
+            timing.delay = delay;
+        }
+        self
+    }
+}
+
+impl<T: Animatable> From<AnimationMode> for Approach<T> {
+    fn from(mode: AnimationMode) -> Self {
+        match mode {
+            AnimationMode::Spring(spring) => Self::Spring(spring, None, None),
+            AnimationMode::Tween(tween) => Self::Tween(tween, AnimationTiming::default()),
+            AnimationMode::Keyframes(track) => Self::Keyframes(track, AnimationTiming::default()),
+        }
+    }
+}
+
+/// An animate call waiting for the next tick, with the callbacks given with it
+struct Command<T: Animatable> {
+    target: T,
+    approach: Approach<T>,
+    run: Run,
+    callbacks: Vec<Callback>,
+    on_rest: Vec<Callback>,
+    on_interrupt: Vec<Callback>,
+    /// Whether the call was made from the callbacks of an ended run
+    chained: bool,
+    playback: Playback,
+    /// Velocity cues to watch the run for
+    cues: Option<Cues<T>>,
+}
+
+impl<T: Animatable> Command<T> {
+    /// Give the call a callback added after queueing it
+    fn add_callback(&mut self, callback: Callback, kind: CallbackKind) {
+        match kind {
+            CallbackKind::Complete => self.callbacks.push(callback),
+            CallbackKind::Rest => self.on_rest.push(callback),
+            CallbackKind::Interrupt => self.on_interrupt.push(callback),
+        }
+    }
+}
+
+/// Time step of the frames [`AnimationEngine::complete_now`] steps through
+const FRAME: f32 = 1.0 / 60.0;
+
+/// How many animations chained from each other's callbacks complete at once
+/// without frames; further ones stay queued, so endless chains end
+const FRAMELESS_CHAIN: usize = 16;
+
+/// How a spring or tween is played beyond its approach
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Playback {
+    /// How long the interrupted animation blends into this one
+    pub(crate) blend: Option<Duration>,
+    /// Frames per second to step at, for ambient animations saving power
+    pub(crate) frame_rate: Option<f32>,
+    /// Whether a spring starts at its own initial velocity instead of the
+    /// velocity of the animation it interrupts
+    pub(crate) from_rest: bool,
+}
+
+/// When a callback added to an engine fires
+#[derive(Clone, Copy)]
+enum CallbackKind {
+    Complete,
+    Rest,
+    Interrupt,
+}
+
+thread_local! {
+    /// How many motion values are invoking callbacks of ended runs
+    static IN_CALLBACKS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Marks the callbacks of ended runs as being invoked until dropped
+struct CallbackScope;
+
+impl CallbackScope {
+    fn enter() -> Self {
+        IN_CALLBACKS.set(IN_CALLBACKS.get() + 1);
+        Self
+    }
+
+    /// Whether a motion value is invoking callbacks of ended runs
+    fn is_active() -> bool {
+        IN_CALLBACKS.get() > 0
+    }
+
+    /// How many callbacks are invoked within each other
+    fn depth() -> usize {
+        IN_CALLBACKS.get()
+    }
+}
+
+impl Drop for CallbackScope {
+    fn drop(&mut self) {
+        IN_CALLBACKS.set(IN_CALLBACKS.get() - 1);
+    }
+}
+
+/// Invoke callbacks of ended runs, in a scope making the animate calls they
+/// make chained calls; see [`AnimationEngine::queue_to`]
+pub(crate) fn run_callbacks(callbacks: Vec<Callback>) {
+    if callbacks.is_empty() {
+        return;
+    }
+    let _scope = CallbackScope::enter();
+    for callback in callbacks {
+        callback();
+    }
+}
 
 /// Core animation engine that manages animations
 pub struct AnimationEngine<T: Animatable> {
@@ -29,10 +194,52 @@ pub struct AnimationEngine<T: Animatable> {
     velocity: T,
     /// Current animation, if any
     animation: Option<Box<dyn Animation<Value = T>>>,
+    /// Target of the running spring or tween
+    target: Option<T>,
     /// Whether the engine is active
     is_active: bool,
+    /// The running animation's run, ended when it settles or is interrupted
+    run: Option<Run>,
+    /// Animate call to start at the next tick; a later call replaces it
+    pending: Option<Command<T>>,
+    /// Animate call made after a chained `pending` one, replaced by it at the
+    /// next tick
+    yielded: Option<Command<T>>,
+    /// Calls from [`MotionValue::queue`] waiting for the running animation to
+    /// settle, started one after another
+    backlog: VecDeque<Command<T>>,
+    /// Whether the running animation has produced an out-of-range value yet
+    out_of_range: bool,
+    /// Animation time of the running animation, in seconds
+    run_elapsed: f32,
+    /// Name reported in lifecycle events
+    label: Option<Arc<str>>,
+    /// Told every new value, dropped once they return `false`
+    followers: Vec<FollowerFn<T>>,
+    /// Parts read on their own, registered while rendering without writing
+    /// the engine
+    parts: RefCell<Vec<Part<T>>>,
     /// Callback queue for animation completion
-    callbacks: Arc<Mutex<Vec<Box<dyn FnOnce() + Send>>>>,
+    callbacks: Vec<Callback>,
+    /// Callbacks for the running animation if it settles on its own
+    on_rest: Vec<Callback>,
+    /// Callbacks for the running animation if it is cancelled or retargeted
+    on_interrupt: Vec<Callback>,
+    /// Velocity cues of the running animation
+    cues: Option<Cues<T>>,
+    /// Callbacks whose run has ended, waiting to be invoked outside the engine borrow
+    ready: Vec<Callback>,
+    /// Id of the driver entry ticking this engine, if any
+    driver_id: Option<u64>,
+    /// Style rendering for direct style binding
+    style: Option<StyleFn<T>>,
+    /// Whether the value changed since the bound style was last rendered
+    style_dirty: bool,
+    /// Animation used by a plain `animate_to`
+    default_animation: AnimationMode,
+    /// Recent samples of the value
+    #[cfg(feature = "trace")]
+    trace: crate::trace::Trace<T>,
 }
 
 impl<T: Animatable> AnimationEngine<T> {
@@ -42,32 +249,124 @@ impl<T: Animatable> AnimationEngine<T> {
             current: initial,
             velocity: T::zero(),
             animation: None,
+            target: None,
             is_active: false,
-            callbacks: Arc::new(Mutex::new(Vec::new())),
+            run: None,
+            pending: None,
+            yielded: None,
+            backlog: VecDeque::new(),
+            out_of_range: false,
+            run_elapsed: 0.0,
+            label: None,
+            followers: Vec::new(),
+            parts: RefCell::new(Vec::new()),
+            callbacks: Vec::new(),
+            on_rest: Vec::new(),
+            on_interrupt: Vec::new(),
+            cues: None,
+            ready: Vec::new(),
+            driver_id: None,
+            style: None,
+            style_dirty: false,
+            default_animation: T::preferred_animation(),
+            #[cfg(feature = "trace")]
+            trace: crate::trace::Trace::new(crate::trace::DEFAULT_TRACE_CAPACITY),
+        }
+    }
+
+    /// Replace the type's preferred animation for plain `animate_to` calls
+    pub(crate) fn with_default_animation(mut self, mode: AnimationMode) -> Self {
+        self.default_animation = mode;
+        self
+    }
+
+    /// Animation used when no builder or config is chosen
+    pub fn default_animation(&self) -> &AnimationMode {
+        &self.default_animation
+    }
+
+    /// Name the engine in lifecycle events
+    pub(crate) fn with_label(mut self, label: Arc<str>) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// The name reported in lifecycle events, if any
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Mark the engine as ticked by the shared driver entry `id`
+    pub(crate) fn with_driver_id(mut self, id: u64) -> Self {
+        self.driver_id = Some(id);
+        self
+    }
+
+    /// Id of the driver entry ticking this engine
+    pub(crate) fn driver_id(&self) -> Option<u64> {
+        self.driver_id
+    }
+
+    /// Bind or unbind the style rendered from the value
+    pub(crate) fn set_style(&mut self, style: Option<StyleFn<T>>) {
+        self.style_dirty = style.is_some();
+        self.style = style;
+    }
+
+    /// Render the bound style if the value changed since the last render
+    pub(crate) fn take_style(&mut self) -> Option<String> {
+        if !self.style_dirty {
+            return None;
         }
+        let render = self.style.as_ref()?;
+        self.style_dirty = false;
+        Some(render(&self.current, self.is_active))
     }
 
     /// Update the animation engine with time delta
     pub fn update(&mut self, dt: f32) -> bool {
+        #[cfg(feature = "trace")]
+        self.trace.advance(dt);
+
+        // Animate calls since the last tick take effect now, the ones yielding
+        // to a chained call first
+        if let Some(command) = self.yielded.take() {
+            self.end_command(command, RunOutcome::Replaced);
+        }
+        if let Some(command) = self.pending.take() {
+            self.start_command(command);
+        }
+
         if !self.is_active {
             return false;
         }
 
         if let Some(animation) = &mut self.animation {
             let (state, value, velocity) = animation.update(dt);
+            self.run_elapsed += dt;
 
             self.current = value;
             self.velocity = velocity;
+            self.style_dirty = true;
+            if let Some(cues) = &mut self.cues {
+                cues.observe(velocity, dt, &mut self.ready);
+            }
+            self.notify_followers();
+            #[cfg(feature = "trace")]
+            self.trace.record(value);
+            if !self.out_of_range && value.is_out_of_range() {
+                self.out_of_range = true;
+                debug!("Animation overshoots the displayable range; output is clamped");
+            }
 
             match state {
                 AnimationState::Active => {
                     return true;
                 }
                 AnimationState::Completed => {
-                    self.is_active = false;
-                    self.animation = None;
                     self.velocity = T::zero();
-                    return false;
+                    self.finish_run();
+                    return self.start_queued();
                 }
             }
         }
@@ -75,12 +374,278 @@ impl<T: Animatable> AnimationEngine<T> {
         false
     }
 
+    /// End the current run naturally, queueing its completion and rest callbacks
+    fn finish_run(&mut self) {
+        let duration = Duration::from_secs_f32(self.run_elapsed);
+        events::emit(self.label.as_ref(), |label| MotionEvent::Completed {
+            label,
+            duration,
+        });
+        self.end_run(RunOutcome::Completed);
+        self.is_active = false;
+        self.animation = None;
+        self.on_interrupt.clear();
+        if let Some(cues) = self.cues.take() {
+            cues.finish(&mut self.ready);
+        }
+        self.ready.append(&mut self.on_rest);
+        self.ready.append(&mut self.callbacks);
+    }
+
+    /// Begin `run` for a newly started animation
+    fn begin_run(&mut self, kind: AnimationKind, run: Run) {
+        self.run = Some(run);
+        self.target = None;
+        self.out_of_range = false;
+        self.run_elapsed = 0.0;
+        self.is_active = true;
+        events::emit(self.label.as_ref(), |label| MotionEvent::Started {
+            label,
+            kind,
+        });
+    }
+
+    /// Report the running animation as interrupted, before it is dropped
+    fn emit_interrupted(&self) {
+        if !self.is_active {
+            return;
+        }
+        events::emit(self.label.as_ref(), |label| {
+            let progress = match (self.remaining_time(), self.total_duration()) {
+                (Some(remaining), Some(total)) if !total.is_zero() => {
+                    Some(1.0 - remaining.as_secs_f32() / total.as_secs_f32())
+                }
+                _ => None,
+            };
+            MotionEvent::Interrupted { label, progress }
+        });
+    }
+
+    /// Resolve the current run's token with `outcome`
+    fn end_run(&mut self, outcome: RunOutcome) {
+        if let Some(run) = self.run.take() {
+            run.end(outcome);
+        }
+    }
+
+    /// End the current run and any queued call early, queueing their
+    /// interrupt callbacks
+    fn interrupt(&mut self, outcome: RunOutcome) {
+        self.drop_pending(outcome);
+        self.emit_interrupted();
+        self.end_run(outcome);
+        if self.is_active {
+            self.ready.append(&mut self.on_interrupt);
+        }
+        self.on_interrupt.clear();
+        self.on_rest.clear();
+        self.cues = None;
+    }
+
+    /// End the queued animate calls with `outcome` without starting them,
+    /// queueing their interrupt callbacks
+    fn drop_pending(&mut self, outcome: RunOutcome) {
+        let backlog = std::mem::take(&mut self.backlog);
+        for command in [self.yielded.take(), self.pending.take()]
+            .into_iter()
+            .flatten()
+            .chain(backlog)
+        {
+            self.end_command(command, outcome);
+        }
+    }
+
+    /// End an animate call with `outcome` without starting it, queueing its
+    /// interrupt callbacks
+    fn end_command(&mut self, mut command: Command<T>, outcome: RunOutcome) {
+        command.run.end(outcome);
+        self.ready.append(&mut command.on_interrupt);
+    }
+
+    /// Start a queued animate call, handing its callbacks to its run
+    ///
+    /// The backlog is kept: calls replacing it already dropped it when made.
+    fn start_command(&mut self, mut command: Command<T>) {
+        let backlog = std::mem::take(&mut self.backlog);
+        self.head_to(
+            command.target,
+            command.approach,
+            command.run,
+            command.playback,
+        );
+        self.backlog = backlog;
+        self.callbacks.append(&mut command.callbacks);
+        self.on_rest.append(&mut command.on_rest);
+        self.on_interrupt.append(&mut command.on_interrupt);
+        self.cues = command.cues.take().map(|mut cues| {
+            cues.begin(self.current, command.target);
+            cues
+        });
+    }
+
+    /// Start a spring, tween or keyframe track toward `target` as `run`, played
+    /// as `playback` says
+    fn head_to(&mut self, target: T, approach: Approach<T>, run: Run, playback: Playback) {
+        self.interrupt(RunOutcome::Replaced);
+        let outgoing = self.animation.take().filter(|_| self.is_active);
+        let (animation, kind, end): (Box<dyn Animation<Value = T>>, _, _) = match approach {
+            Approach::Spring(spring, noise, bounds) => {
+                // Carrying the velocity over keeps a retargeted spring from
+                // hitching; from rest it starts at its own
+                let velocity = if outgoing.is_some() && !playback.from_rest {
+                    self.velocity
+                } else {
+                    spring.directed_velocity(self.current, target)
+                };
+                let spring = spring.create_animation(self.current, target, velocity);
+                let spring = match noise {
+                    Some(noise) => spring.with_noise(noise),
+                    None => spring,
+                };
+                let spring = match bounds {
+                    Some(bounds) => spring.with_bounds(bounds),
+                    None => spring,
+                };
+                (Box::new(spring), AnimationKind::Spring, target)
+            }
+            Approach::Tween(tween, timing) => (
+                Box::new(TweenAnimation::new(self.current, target, tween, timing)),
+                AnimationKind::Tween,
+                target,
+            ),
+            Approach::Keyframes(track, timing) => {
+                let keyframes = track.create_animation(self.current, target).timing(timing);
+                // The track may end away from the target, e.g. a shake
+                // returning to where it started
+                let end = keyframes.sample(1.0);
+                (Box::new(keyframes), AnimationKind::Keyframes, end)
+            }
+        };
+        let animation = match playback.frame_rate {
+            Some(frame_rate) => Box::new(Throttled::new(animation, frame_rate)),
+            None => animation,
+        };
+        let animation = match (outgoing, playback.blend) {
+            (Some(outgoing), Some(blend)) if !blend.is_zero() => {
+                Box::new(Blend::new(outgoing, animation, blend))
+            }
+            _ => animation,
+        };
+        self.animation = Some(animation);
+        self.begin_run(kind, run);
+        self.target = Some(end);
+    }
+
+    /// Play the queued or running animation to its end at once, for renders
+    /// no frame will ever advance
+    ///
+    /// Springs and tweens land on their target, looping ones too; other
+    /// animations are stepped through, up to a minute of frames.
+    ///
+    /// Calls waiting in the backlog are played through after it.
+    pub(crate) fn complete_now(&mut self) {
+        if !self.update(0.0) {
+            return;
+        }
+        loop {
+            if let Some(target) = self.target {
+                self.current = target;
+            } else {
+                let mut frames = 0;
+                while frames < 3600 && self.update(FRAME) {
+                    frames += 1;
+                }
+            }
+            if self.is_active {
+                self.velocity = T::zero();
+                self.style_dirty = true;
+                self.notify_followers();
+                self.finish_run();
+            }
+            if !self.start_queued() {
+                return;
+            }
+        }
+    }
+
+    /// Append a spring or tween toward `target` to the backlog, to start once
+    /// the running animation and the calls queued before it have settled
+    ///
+    /// Starts at the next tick like [`queue`](Self::queue) when nothing runs
+    /// or waits. Any other animate call, [`stop`](Self::stop) or
+    /// [`set`](Self::set) drops the backlog.
+    pub(crate) fn enqueue(
+        &mut self,
+        target: T,
+        approach: Approach<T>,
+        on_complete: Option<Callback>,
+    ) -> AnimationToken {
+        let command = Command {
+            target,
+            approach,
+            run: Run::new(),
+            callbacks: on_complete.into_iter().collect(),
+            on_rest: Vec::new(),
+            on_interrupt: Vec::new(),
+            chained: CallbackScope::is_active(),
+            playback: Playback::default(),
+            cues: None,
+        };
+        let token = command.run.token();
+        if self.is_active || self.pending.is_some() || self.yielded.is_some() {
+            self.backlog.push_back(command);
+        } else {
+            self.pending = Some(command);
+        }
+        token
+    }
+
+    /// Start the oldest call of the backlog, returning whether there was one
+    fn start_queued(&mut self) -> bool {
+        let Some(command) = self.backlog.pop_front() else {
+            return false;
+        };
+        self.start_command(command);
+        true
+    }
+
     /// Set the current value directly (without animation)
     pub fn set(&mut self, value: T) {
+        self.interrupt(RunOutcome::Cancelled);
         self.current = value;
         self.velocity = T::zero();
+        self.style_dirty = true;
         self.animation = None;
         self.is_active = false;
+        self.notify_followers();
+        #[cfg(feature = "trace")]
+        self.trace.record(value);
+    }
+
+    /// The most recent samples of the value, oldest first
+    ///
+    /// A sample is recorded on every animation frame and on every
+    /// [`set`](AnimationEngine::set).
+    #[cfg(feature = "trace")]
+    pub fn trace(&self) -> Vec<crate::TraceSample<T>> {
+        self.trace.samples()
+    }
+
+    /// Keep at most `capacity` samples
+    #[cfg(feature = "trace")]
+    pub fn set_trace_capacity(&mut self, capacity: usize) {
+        self.trace.set_capacity(capacity);
+    }
+
+    /// Forget the recorded samples
+    #[cfg(feature = "trace")]
+    pub fn clear_trace(&mut self) {
+        self.trace.clear();
+    }
+
+    /// Borrow the current value
+    pub(crate) fn current(&self) -> &T {
+        &self.current
     }
 
     /// Get the current value
@@ -88,50 +653,408 @@ impl<T: Animatable> AnimationEngine<T> {
         self.current
     }
 
-    /// Check if the animation is active
+    /// Velocity of the value in units per second, zero when idle
+    ///
+    /// Springs report their physical velocity; tweens and keyframes the
+    /// change over the last frame.
+    pub fn velocity(&self) -> T {
+        if self.is_active {
+            self.velocity
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Check if the animation is active or queued to start at the next tick
     pub fn is_active(&self) -> bool {
-        self.is_active
+        self.is_active || self.pending.is_some()
+    }
+
+    /// Time left until the running animation completes
+    ///
+    /// `None` when idle or when the animation can't tell, e.g. when looping forever.
+    pub fn remaining_time(&self) -> Option<Duration> {
+        match &self.animation {
+            Some(animation) if self.is_active => animation.remaining_time(),
+            _ => None,
+        }
+    }
+
+    /// Total play time of the running animation, for duration-based animations
+    pub fn total_duration(&self) -> Option<Duration> {
+        match &self.animation {
+            Some(animation) if self.is_active => animation.total_duration(),
+            _ => None,
+        }
+    }
+
+    /// How far the running animation has come, from `0.0` to `1.0`
+    ///
+    /// `Some(0.0)` for an animation queued to start at the next tick; `None`
+    /// when idle or when the animation can't tell, e.g. when looping forever.
+    pub fn progress(&self) -> Option<f32> {
+        if self.pending.is_some() {
+            return Some(0.0);
+        }
+        match &self.animation {
+            Some(animation) if self.is_active => animation.progress(),
+            _ => None,
+        }
     }
 
     /// Start a spring animation
     pub fn spring_to(&mut self, target: T, spring: Spring) {
-        self.animation = Some(Box::new(spring.create_animation(
-            self.current,
+        self.head_to(
             target,
-            self.velocity,
-        )));
-        self.is_active = true;
+            Approach::Spring(spring, None, None),
+            Run::new(),
+            Playback::default(),
+        );
+    }
+
+    /// Start a spring animation that keeps drifting around `target`
+    ///
+    /// See [`Noise`]; the run goes on until it is replaced or stopped.
+    pub fn spring_with_noise(&mut self, target: T, spring: Spring, noise: Noise<T>) {
+        self.head_to(
+            target,
+            Approach::Spring(spring, Some(noise), None),
+            Run::new(),
+            Playback::default(),
+        );
     }
 
     /// Start a tween animation
     pub fn tween_to(&mut self, target: T, tween: Tween) {
-        self.animation = Some(Box::new(tween.create_animation(self.current, target)));
-        self.is_active = true;
+        self.head_to(
+            target,
+            Approach::Tween(tween, AnimationTiming::default()),
+            Run::new(),
+            Playback::default(),
+        );
+    }
+
+    /// Animate toward `target` from the next tick on
+    ///
+    /// Unlike [`spring_to`](Self::spring_to) and [`tween_to`](Self::tween_to),
+    /// which start right away, calls made between two ticks, e.g. by two event
+    /// handlers of one batch, take effect together in call order: the last one
+    /// wins and the earlier ones end as [`RunOutcome::Replaced`] without
+    /// starting, running only their interrupt callbacks. Callbacks added after
+    /// queueing belong to the queued call; [`run_token`](Self::run_token) and
+    /// [`target`](Self::target) report it until it starts.
+    ///
+    /// Calls made while a [`MotionValue`] invokes the callbacks of an ended run,
+    /// e.g. chaining the next animation from `on_complete`, are applied after
+    /// the other calls until the next tick. A later call made elsewhere, say by
+    /// a handler acting on what it read before the run ended, is replaced by
+    /// the chained one instead of replacing it, so a chain can't be cut short.
+    /// [`stop`](Self::stop) and [`set`](Self::set) still end a chained call.
+    pub fn queue_to(&mut self, target: T, mode: AnimationMode) {
+        self.queue(target, mode.into());
+    }
+
+    /// Queue a spring or tween toward `target`, see [`queue_to`](Self::queue_to)
+    pub(crate) fn queue(&mut self, target: T, approach: Approach<T>) {
+        self.queue_with(target, approach, Playback::default());
+    }
+
+    /// Queue a spring or tween toward `target` played as `playback` says, e.g.
+    /// blending out the animation it interrupts for [`MotionValue::interrupt_to`]
+    pub(crate) fn queue_with(&mut self, target: T, approach: Approach<T>, playback: Playback) {
+        let command = Command {
+            target,
+            approach,
+            run: Run::new(),
+            callbacks: Vec::new(),
+            on_rest: Vec::new(),
+            on_interrupt: Vec::new(),
+            chained: CallbackScope::is_active(),
+            playback,
+            cues: None,
+        };
+        let yields =
+            !command.chained && self.pending.as_ref().is_some_and(|pending| pending.chained);
+        if yields {
+            if let Some(earlier) = self.yielded.replace(command) {
+                self.end_command(earlier, RunOutcome::Replaced);
+            }
+        } else {
+            self.drop_pending(RunOutcome::Replaced);
+            self.pending = Some(command);
+        }
+    }
+
+    /// Token of the latest animate call, whether it will start or yield to a
+    /// chained call; the running animation's when no call is queued
+    pub(crate) fn call_token(&self) -> Option<AnimationToken> {
+        self.yielded
+            .as_ref()
+            .or(self.pending.as_ref())
+            .map(|command| command.run.token())
+            .or_else(|| self.run.as_ref().map(Run::token))
+    }
+
+    /// Add a callback to the latest animate call, or to the running animation
+    /// when no call is queued
+    fn add_callback(&mut self, callback: Callback, kind: CallbackKind) {
+        if let Some(command) = self.yielded.as_mut().or(self.pending.as_mut()) {
+            command.add_callback(callback, kind);
+            return;
+        }
+        match kind {
+            CallbackKind::Complete => self.callbacks.push(callback),
+            CallbackKind::Rest => self.on_rest.push(callback),
+            CallbackKind::Interrupt => self.on_interrupt.push(callback),
+        }
+    }
+
+    /// Watch the latest animate call for velocity cues, or the running
+    /// animation when no call is queued
+    pub(crate) fn add_cues(&mut self, mut cues: Cues<T>) {
+        if let Some(command) = self.yielded.as_mut().or(self.pending.as_mut()) {
+            command.cues = Some(cues);
+            return;
+        }
+        if let Some(target) = self.target.filter(|_| self.is_active) {
+            cues.begin(self.current, target);
+            self.cues = Some(cues);
+        }
+    }
+
+    /// Target of the running or queued animation, if it is a spring or tween
+    ///
+    /// `None` when idle or while playing keyframes, a sequence or a custom animation.
+    pub fn target(&self) -> Option<T> {
+        self.pending.as_ref().map_or_else(
+            || self.target.filter(|_| self.is_active),
+            |command| Some(command.target),
+        )
+    }
+
+    /// Spring toward `target`, moving the target of a running animation if it can
+    ///
+    /// Unlike [`spring_to`](Self::spring_to), retargeting a running spring keeps
+    /// its run going, so it can be called every frame to chase a moving target.
+    pub fn chase(&mut self, target: T, spring: &Spring) {
+        self.drop_pending(RunOutcome::Replaced);
+        if self.is_active {
+            if let Some(animation) = &mut self.animation
+                && animation.retarget(target)
+            {
+                self.target = Some(target);
+                return;
+            }
+        } else if self.current.is_settled(&target, &T::zero()) {
+            return;
+        }
+        self.spring_to(target, spring.clone());
+    }
+
+    /// Call `follower` with every new value until it returns `false`
+    pub(crate) fn add_follower(&mut self, follower: impl FnMut(&T) -> bool + 'static) {
+        self.followers.push(Box::new(follower));
+    }
+
+    fn notify_followers(&mut self) {
+        let value = self.current;
+        self.followers.retain_mut(|follower| follower(&value));
+        for part in self.parts.get_mut() {
+            let next = (part.read)(&value);
+            // Only a write tells subscribers, so compare before writing
+            let moved = part
+                .signal
+                .try_peek()
+                .is_ok_and(|last| (next - *last).abs() > T::epsilon());
+            if moved && let Ok(mut signal) = part.signal.try_write() {
+                *signal = next;
+            }
+        }
+    }
+
+    /// The signal following the part `name` of the value, created in `scope`
+    /// on first use
+    pub(crate) fn part(
+        &self,
+        name: &'static str,
+        read: fn(&T) -> f32,
+        scope: ScopeId,
+    ) -> Signal<f32> {
+        let mut parts = self.parts.borrow_mut();
+        if let Some(part) = parts.iter().find(|part| part.name == name) {
+            return part.signal;
+        }
+        let signal = Signal::new_in_scope(read(&self.current), scope);
+        parts.push(Part { name, read, signal });
+        signal
     }
 
     /// Stop any active animation
     pub fn stop(&mut self) {
+        self.interrupt(RunOutcome::Cancelled);
         self.animation = None;
         self.is_active = false;
     }
 
+    /// Let a looping animation play out its current pass, then complete
+    ///
+    /// The value ends on the pass's boundary, e.g. the resting pose of a pulse,
+    /// instead of freezing mid-cycle. Animations that can't end at a boundary,
+    /// such as custom ones by default, stop right away as with [`stop`](Self::stop).
+    pub fn stop_at_cycle_end(&mut self) {
+        self.drop_pending(RunOutcome::Cancelled);
+        let finishing = match &mut self.animation {
+            Some(animation) if self.is_active => animation.finish_loop(),
+            _ => false,
+        };
+        if !finishing {
+            self.stop();
+        }
+    }
+
     /// Apply a keyframe animation
     pub fn apply_keyframes(&mut self, keyframes: KeyframeAnimation<T>) {
-        self.animation = Some(Box::new(keyframes));
-        self.is_active = true;
+        self.interrupt(RunOutcome::Replaced);
+        self.animation = Some(Box::new(keyframes.prepared()));
+        self.begin_run(AnimationKind::Keyframes, Run::new());
     }
 
-    /// Add a completion callback
-    pub fn add_completion_callback<F: FnOnce() + Send + 'static>(&mut self, callback: F) {
-        if let Ok(mut callbacks) = self.callbacks.lock() {
-            callbacks.push(Box::new(callback));
+    /// Apply a custom animation implementing [`Animation`]
+    pub fn apply_custom<A: Animation<Value = T>>(&mut self, animation: A) {
+        self.interrupt(RunOutcome::Replaced);
+        self.animation = Some(Box::new(animation));
+        self.begin_run(AnimationKind::Custom, Run::new());
+    }
+
+    /// Token of the queued or running animation, `None` when idle
+    pub fn run_token(&self) -> Option<AnimationToken> {
+        self.pending
+            .as_ref()
+            .map(|command| &command.run)
+            .or(self.run.as_ref())
+            .map(Run::token)
+    }
+
+    /// Stop the animation only if it is the run `token` was issued for
+    ///
+    /// Returns whether it was stopped; a token whose run already settled or
+    /// was replaced does nothing.
+    pub fn cancel(&mut self, token: &AnimationToken) -> bool {
+        if let Some(command) = self
+            .yielded
+            .take_if(|command| command.run.id() == token.id())
+        {
+            self.end_command(command, RunOutcome::Cancelled);
+            return true;
+        }
+        if let Some(command) = self
+            .pending
+            .take_if(|command| command.run.id() == token.id())
+        {
+            self.end_command(command, RunOutcome::Cancelled);
+            if let Some(yielded) = self.yielded.take() {
+                self.end_command(yielded, RunOutcome::Cancelled);
+            }
+            // The backlog waits on nothing now; its first call takes the slot
+            if !self.is_active {
+                self.pending = self.backlog.pop_front();
+            }
+            return true;
+        }
+        if let Some(index) = self
+            .backlog
+            .iter()
+            .position(|command| command.run.id() == token.id())
+        {
+            if let Some(command) = self.backlog.remove(index) {
+                self.end_command(command, RunOutcome::Cancelled);
+            }
+            return true;
+        }
+        let current = self.run.as_ref().is_some_and(|run| run.id() == token.id());
+        if current {
+            // Stop just this run; calls queued after it still start
+            let pending = self.pending.take();
+            let mut backlog = std::mem::take(&mut self.backlog);
+            self.stop();
+            self.pending = pending.or_else(|| backlog.pop_front());
+            self.backlog = backlog;
+        }
+        current
+    }
+
+    /// Switch a running spring to new physics, keeping its value and velocity
+    ///
+    /// Returns whether a spring was running.
+    pub fn retune_spring(&mut self, spring: &Spring) -> bool {
+        match &mut self.animation {
+            Some(animation) if self.is_active => animation.retune_spring(spring),
+            _ => false,
         }
     }
 
+    /// Cancel the animation and drop every callback without running it
+    ///
+    /// Called when the owning component unmounts, so callbacks can't fire into a
+    /// dead scope and whatever they captured is released. The value is kept.
+    pub fn teardown(&mut self) {
+        self.drop_pending(RunOutcome::Cancelled);
+        self.emit_interrupted();
+        self.end_run(RunOutcome::Cancelled);
+        self.animation = None;
+        self.is_active = false;
+        self.velocity = T::zero();
+        self.callbacks.clear();
+        self.on_rest.clear();
+        self.on_interrupt.clear();
+        self.ready.clear();
+        self.style = None;
+        self.style_dirty = false;
+        self.followers.clear();
+        self.parts.get_mut().clear();
+    }
+
+    /// Add a completion callback
+    pub fn add_completion_callback<F: FnOnce() + MaybeSend + 'static>(&mut self, callback: F) {
+        self.add_callback(boxed(callback), CallbackKind::Complete);
+    }
+
+    /// Add a callback fired only if the current animation settles on its own
+    ///
+    /// Register after starting the animation; the callback is dropped without
+    /// running if the animation is interrupted.
+    pub fn add_rest_callback<F: FnOnce() + MaybeSend + 'static>(&mut self, callback: F) {
+        self.add_callback(boxed(callback), CallbackKind::Rest);
+    }
+
+    /// Add a callback fired only if the current animation is retargeted, stopped or
+    /// overwritten by [`AnimationEngine::set`] before it settles
+    ///
+    /// Register after starting the animation; the callback is dropped without
+    /// running if the animation settles.
+    pub fn add_interrupt_callback<F: FnOnce() + MaybeSend + 'static>(&mut self, callback: F) {
+        self.add_callback(boxed(callback), CallbackKind::Interrupt);
+    }
+
+    /// Whether any ended run has callbacks waiting to be invoked
+    pub fn has_ready_callbacks(&self) -> bool {
+        !self.ready.is_empty()
+    }
+
+    /// Take the callbacks of ended runs
+    ///
+    /// Callbacks are not invoked by the engine itself so they can freely start new
+    /// animations on the same motion value without re-entering the engine borrow.
+    pub fn take_ready_callbacks(&mut self) -> Vec<Callback> {
+        std::mem::take(&mut self.ready)
+    }
+
     /// Apply an animation sequence
-    pub fn apply_sequence(&mut self, sequence: AnimationSequence<T>) {
+    pub fn apply_sequence(&mut self, mut sequence: AnimationSequence<T>) {
+        self.interrupt(RunOutcome::Replaced);
+        sequence.seed(self.current);
         self.animation = Some(Box::new(sequence));
-        self.is_active = true;
+        self.begin_run(AnimationKind::Sequence, Run::new());
     }
 
     pub async fn run_animation_loop(&mut self) {
@@ -168,17 +1091,14 @@ impl<T: Animatable> AnimationEngine<T> {
     }
 
     fn complete_animation(&mut self) {
-        self.is_active = false;
-        self.animation = None;
+        self.finish_run();
 
         // Process callbacks
-        if let Ok(mut callbacks) = self.callbacks.lock() {
-            // Store callbacks before processing
-
+        {
             // Process callbacks immediately for web
             #[cfg(feature = "web")]
             {
-                let callbacks_to_process = std::mem::take(&mut *callbacks);
+                let callbacks_to_process = self.take_ready_callbacks();
                 for callback in callbacks_to_process {
                     callback();
                 }
@@ -187,7 +1107,7 @@ impl<T: Animatable> AnimationEngine<T> {
             #[cfg(not(feature = "web"))]
             {
                 use std::time::Duration;
-                let mut callbacks_to_process = std::mem::take(&mut *callbacks);
+                let mut callbacks_to_process = self.take_ready_callbacks();
                 while !callbacks_to_process.is_empty() {
                     let chunk: Vec<_> = callbacks_to_process
                         .drain(..callbacks_to_process.len().min(5))
@@ -227,6 +1147,51 @@ impl<T: Animatable> MotionValue<T> {
         self.engine.read().get()
     }
 
+    /// Get the velocity of the value in units per second, zero when idle
+    ///
+    /// Like [`MotionValue::get`], reading it subscribes to the value. Hand it
+    /// to a spring released from a drag, or use it for speed-dependent
+    /// styling such as motion blur.
+    pub fn velocity(&self) -> T {
+        self.engine.read().velocity()
+    }
+
+    /// Finish starting an animation, returning the token of its run
+    ///
+    /// The token is taken before ready callbacks run, as those may start another.
+    pub(crate) fn started(&mut self) -> AnimationToken {
+        let token = self.engine.peek().call_token();
+        self.complete_without_frames();
+        self.run_ready_callbacks();
+        token.unwrap_or_else(AnimationToken::ended)
+    }
+
+    /// Land the animation just started on its end when no frame will come,
+    /// or count it as running with the driver when one will
+    pub(crate) fn complete_without_frames(&mut self) {
+        if !driver::has_frames() {
+            if CallbackScope::depth() < FRAMELESS_CHAIN {
+                self.engine.write().complete_now();
+            }
+        } else if let Some(id) = self.engine.peek().driver_id()
+            && self.engine.peek().is_active()
+        {
+            driver::activate(id);
+        }
+    }
+
+    /// Invoke the callbacks of animation runs that have ended
+    ///
+    /// Runs after the engine borrow is released so callbacks may start new
+    /// animations; those are chained calls, see [`AnimationEngine::queue_to`].
+    pub(crate) fn run_ready_callbacks(&mut self) {
+        if !self.engine.peek().has_ready_callbacks() {
+            return;
+        }
+        let ready = self.engine.write().take_ready_callbacks();
+        run_callbacks(ready);
+    }
+
     pub fn peek(&self) -> T {
         self.engine.peek().get()
     }
@@ -234,6 +1199,7 @@ impl<T: Animatable> MotionValue<T> {
     /// Set the value directly (without animation)
     pub fn set(&mut self, value: T) {
         self.engine.write().set(value);
+        self.run_ready_callbacks();
     }
 
     /// Create a spring animation builder
@@ -247,9 +1213,18 @@ impl<T: Animatable> MotionValue<T> {
     }
 
     /// Start a keyframe animation
-    pub fn animate_keyframes(&mut self, keyframes: KeyframeAnimation<T>) -> &Self {
+    pub fn animate_keyframes(&mut self, keyframes: KeyframeAnimation<T>) -> AnimationToken {
         self.engine.write().apply_keyframes(keyframes);
-        self
+        self.started()
+    }
+
+    /// Start a custom animation
+    ///
+    /// The animation is boxed and driven by the engine like the built-in ones; see
+    /// [`Animation`] for the behavior it must follow.
+    pub fn animate_custom<A: Animation<Value = T>>(&mut self, animation: A) -> AnimationToken {
+        self.engine.write().apply_custom(animation);
+        self.started()
     }
 
     /// Sequence animation builder
@@ -259,38 +1234,1754 @@ impl<T: Animatable> MotionValue<T> {
     }
 
     /// Animate to a target value with custom configuration
-    pub fn animate_to_with_config(&mut self, target: T, config: AnimationConfig) -> &Self {
-        // Apply completion callback if provided
-        if let Some(callback) = config.on_complete {
-            self.engine.write().add_completion_callback(callback);
-        }
+    ///
+    /// The animation starts at the next frame; if several animate calls are
+    /// made before it, the last one wins and the earlier ones end as
+    /// [`RunOutcome::Replaced`] without their completion callbacks running.
+    /// Calls made from another run's callbacks win over the rest, see
+    /// [`AnimationEngine::queue_to`].
+    pub fn animate_to_with_config(&mut self, target: T, config: AnimationConfig) -> AnimationToken {
+        self.queue_with_config(target, config, None)
+    }
 
-        // Start animation based on mode
-        match config.mode {
-            AnimationMode::Spring(spring) => {
-                self.engine.write().spring_to(target, spring);
-            }
-            AnimationMode::Tween(tween) => {
-                self.engine.write().tween_to(target, tween);
+    /// Animate to `target`, blending the interrupted animation into the new
+    /// one over `blend`
+    ///
+    /// For `blend` after the new animation starts, the old one keeps playing
+    /// and the value cross-fades from its output to the new one's, easing in
+    /// and out, instead of switching at once. Redirections then keep their
+    /// momentum even when the new animation starts at rest, as tweens do, and
+    /// a value retargeted on every pointer move glides between targets. A
+    /// blend of 100 to 200ms is usually enough. Starting from rest, or
+    /// with a zero `blend`, it is the same as
+    /// [`animate_to_with_config`](Self::animate_to_with_config).
+    pub fn interrupt_to(
+        &mut self,
+        target: T,
+        config: AnimationConfig,
+        blend: Duration,
+    ) -> AnimationToken {
+        self.queue_with_config(target, config, Some(blend))
+    }
+
+    /// Animate to `target` once the running animation and the ones queued
+    /// before have settled
+    ///
+    /// Where [`animate_to_with_config`](Self::animate_to_with_config) replaces
+    /// the running animation, queued animations play one after another in call
+    /// order, each from where the last one stopped, e.g. one move per click
+    /// however fast the clicks come. Starts at the next frame when the value is
+    /// idle. Any other animate call, [`stop`](Self::stop) or [`set`](Self::set)
+    /// drops the queue, ending the waiting animations as
+    /// [`RunOutcome::Replaced`] or [`RunOutcome::Cancelled`]; a queued
+    /// animation can be [`cancel`](Self::cancel)ed alone with its token.
+    pub fn queue(&mut self, target: T, config: AnimationConfig) -> AnimationToken {
+        let token = {
+            let approach = Approach::from(config.mode).timed(config.loop_mode, config.delay);
+            self.engine
+                .write()
+                .enqueue(target, approach, config.on_complete)
+        };
+        self.complete_without_frames();
+        self.run_ready_callbacks();
+        token
+    }
+
+    /// Queue an animate call with `config`, see [`AnimationEngine::queue_to`]
+    fn queue_with_config(
+        &mut self,
+        target: T,
+        config: AnimationConfig,
+        blend: Option<Duration>,
+    ) -> AnimationToken {
+        {
+            let mut engine = self.engine.write();
+            let approach = Approach::from(config.mode).timed(config.loop_mode, config.delay);
+            let playback = Playback {
+                blend,
+                ..Playback::default()
+            };
+            engine.queue_with(target, approach, playback);
+            if let Some(callback) = config.on_complete {
+                engine.add_completion_callback(callback);
             }
         }
+        self.started()
+    }
 
-        self
+    /// Animate to `target` with custom configuration, unless already headed there
+    ///
+    /// Does nothing if the running spring or tween already has `target` as its
+    /// target, or if the value rests at `target`, so an effect re-running with
+    /// the same target doesn't restart the animation; `config` is ignored then,
+    /// `on_complete` included. Returns the token of the animation heading to
+    /// `target`, whether started now or before.
+    pub fn animate_to_if_changed(&mut self, target: T, config: AnimationConfig) -> AnimationToken {
+        let unchanged = {
+            let engine = self.engine.peek();
+            // Both ways round, as types such as `Color` clamp differences at zero
+            let same = |other: &T| {
+                target.sub(other).magnitude() <= T::epsilon()
+                    && other.sub(&target).magnitude() <= T::epsilon()
+            };
+            engine.target().map_or_else(
+                || !engine.is_active() && same(engine.current()),
+                |current| same(&current),
+            )
+        };
+        if unchanged {
+            return self.token().unwrap_or_else(AnimationToken::ended);
+        }
+        self.animate_to_with_config(target, config)
+    }
+
+    /// Jump to `from`, then animate to `to` with custom configuration
+    ///
+    /// The animation always starts at rest from `from`, regardless of the current
+    /// value or any running animation, e.g. for entrances from an off-screen offset.
+    pub fn animate_from_to(&mut self, from: T, to: T, config: AnimationConfig) -> AnimationToken {
+        self.engine.write().set(from);
+        self.animate_to_with_config(to, config)
+    }
+
+    /// Animate to a target value with the type's preferred animation
+    ///
+    /// Uses [`Animatable::preferred_animation`] unless a [`MotionConfig`](crate::MotionConfig)
+    /// context overrides it; pick a builder or config to choose explicitly.
+    ///
+    /// Returns a token for the started animation; use it to [`cancel`](Self::cancel)
+    /// just this animation or to await its end.
+    pub fn animate_to(&mut self, target: T) -> AnimationToken {
+        let mode = self.engine.peek().default_animation().clone();
+        self.animate_to_with_config(target, AnimationConfig::new(mode))
     }
 
-    /// Animate to a target value with spring physics
-    pub fn animate_to(&mut self, target: T) -> &Self {
-        self.animate_to_with_config(target, AnimationConfig::default())
+    /// Animate by `delta` from the current value with the type's preferred animation
+    ///
+    /// The target is the value at call time plus `delta`. During a running
+    /// animation that is where the value is now, not where it was headed, so
+    /// repeated nudges, e.g. a "move right by 50px" button clicked twice, add up
+    /// from the motion on screen.
+    pub fn animate_by(&mut self, delta: T) -> AnimationToken {
+        let target = self.peek().add(&delta);
+        self.animate_to(target)
     }
 
-    /// Stop any running animation
+    /// Stop any running animation, leaving the value where it is
+    ///
+    /// The value holds whatever it had reached mid-flight, e.g. a progress bar
+    /// pausing in place when the user cancels, and once no other value is
+    /// animating the driver drops back to its idle cadence. The run ends as
+    /// [`RunOutcome::Cancelled`] and queued animations are dropped.
     pub fn stop(&mut self) -> &Self {
         self.engine.write().stop();
+        self.run_ready_callbacks();
         self
     }
 
+    /// Stop a looping animation once its current pass ends
+    ///
+    /// Where [`stop`](Self::stop) freezes the value mid-cycle, this lets the
+    /// running pass finish so e.g. a pulse returns to its resting scale. The
+    /// run then completes normally, firing its completion callbacks.
+    pub fn stop_at_cycle_end(&mut self) -> &Self {
+        self.engine.write().stop_at_cycle_end();
+        self.run_ready_callbacks();
+        self
+    }
+
+    /// Stop the animation `token` was returned for, if it is still running
+    ///
+    /// Does nothing once that animation has settled or another one has been
+    /// started since, so a stale token can't cancel a newer animation.
+    /// Returns whether the animation was stopped.
+    pub fn cancel(&mut self, token: &AnimationToken) -> bool {
+        let cancelled = self.engine.write().cancel(token);
+        self.run_ready_callbacks();
+        cancelled
+    }
+
+    /// Token of the running animation, `None` when idle
+    pub fn token(&self) -> Option<AnimationToken> {
+        self.engine.peek().run_token()
+    }
+
     /// Check if there's an active animation
     pub fn is_animating(&self) -> bool {
         self.engine.read().is_active()
     }
+
+    /// Time left until the running animation completes
+    ///
+    /// Exact for tweens and keyframes, estimated for springs, and `None` when idle.
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.engine.read().remaining_time()
+    }
+
+    /// The most recent samples of the value, oldest first
+    ///
+    /// Requires the `trace` feature. Use it to check the shape of a curve after
+    /// an animation, e.g. that a spring overshoots or a tween eases out.
+    #[cfg(feature = "trace")]
+    pub fn trace(&self) -> Vec<crate::TraceSample<T>> {
+        self.engine.peek().trace()
+    }
+
+    /// Keep at most `capacity` samples in the trace
+    #[cfg(feature = "trace")]
+    pub fn set_trace_capacity(&mut self, capacity: usize) {
+        self.engine.write().set_trace_capacity(capacity);
+    }
+
+    /// Total play time of the running tween, keyframe or stagger animation
+    pub fn total_duration(&self) -> Option<Duration> {
+        self.engine.read().total_duration()
+    }
+
+    /// Read one `f32` part of the value, subscribing the caller to that part
+    /// alone
+    ///
+    /// The caller is only told about changes of the part larger than the
+    /// value's [`epsilon`](Animatable::epsilon); the returned value is always
+    /// exact.
+    pub(crate) fn read_part(&self, name: &'static str, read: fn(&T) -> f32) -> f32 {
+        let engine = self.engine.peek();
+        let signal = engine.part(name, read, self.engine.origin_scope());
+        // Subscribe only; the exact value comes from the engine
+        signal.read();
+        read(engine.current())
+    }
+
+    /// How far the running animation has come, from `0.0` to `1.0`
+    ///
+    /// For a sequence, the overall progress through all of its steps, e.g. for
+    /// a progress bar of an onboarding choreography. Reading it subscribes the
+    /// caller, so the bar re-renders every frame. `None` when idle or when the
+    /// animation can't tell, e.g. when looping forever.
+    pub fn progress(&self) -> Option<f32> {
+        self.engine.read().progress()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::{AnimationTiming, LoopMode, MotionConfig};
+    use crate::animations::tween::TweenAnimation;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counter() -> (Arc<AtomicUsize>, impl FnOnce() + Send + 'static) {
+        let count = Arc::new(AtomicUsize::new(0));
+        let handle = count.clone();
+        (count, move || {
+            handle.fetch_add(1, Ordering::SeqCst);
+        })
+    }
+
+    fn run_ready(engine: &mut AnimationEngine<f32>) {
+        for callback in engine.take_ready_callbacks() {
+            callback();
+        }
+    }
+
+    #[test]
+    fn test_set_then_animate_starts_from_offset() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_to(100.0, Spring::default());
+        for _ in 0..10 {
+            engine.update(1.0 / 60.0);
+        }
+
+        // Mid-flight with velocity towards 100, restart the entrance from -300
+        engine.set(-300.0);
+        engine.spring_to(0.0, Spring::default());
+        engine.update(1.0 / 60.0);
+        let first = engine.get();
+        assert!((-300.0..-290.0).contains(&first));
+
+        while engine.update(1.0 / 60.0) {}
+        assert_eq!(engine.get(), 0.0);
+    }
+
+    #[test]
+    fn test_retargeted_spring_keeps_its_velocity() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_to(200.0, Spring::default());
+        for _ in 0..10 {
+            engine.update(1.0 / 60.0);
+        }
+        let velocity = |engine: &AnimationEngine<f32>| {
+            engine
+                .animation
+                .as_ref()
+                .map_or(0.0, |animation| animation.velocity())
+        };
+        let before = velocity(&engine);
+        assert!(before > 100.0);
+
+        // Turning back mid-flight, the new spring starts moving as fast
+        engine.spring_to(0.0, Spring::default());
+        assert!((velocity(&engine) - before).abs() < 1e-4);
+        let position = engine.get();
+        engine.update(1.0 / 60.0);
+        assert!(engine.get() > position);
+
+        // Opting out starts it at its own initial velocity
+        engine.head_to(
+            200.0,
+            Approach::Spring(Spring::default(), None, None),
+            Run::new(),
+            Playback {
+                from_rest: true,
+                ..Playback::default()
+            },
+        );
+        assert_eq!(velocity(&engine), 0.0);
+        engine.head_to(
+            0.0,
+            Approach::Spring(Spring::default().initial_velocity(50.0), None, None),
+            Run::new(),
+            Playback {
+                from_rest: true,
+                ..Playback::default()
+            },
+        );
+        assert!((velocity(&engine) + 50.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_spring_rest_fires_only_rest() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        let (rested, on_rest) = counter();
+        let (interrupted, on_interrupt) = counter();
+
+        engine.spring_to(100.0, Spring::default());
+        engine.add_rest_callback(on_rest);
+        engine.add_interrupt_callback(on_interrupt);
+
+        let mut frames = 0;
+        while engine.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 10_000, "spring never settled");
+        }
+        run_ready(&mut engine);
+
+        assert_eq!(rested.load(Ordering::SeqCst), 1);
+        assert_eq!(interrupted.load(Ordering::SeqCst), 0);
+        assert_eq!(engine.get(), 100.0);
+    }
+
+    #[test]
+    fn test_spring_retarget_fires_only_interrupt() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        let (rested, on_rest) = counter();
+        let (interrupted, on_interrupt) = counter();
+
+        engine.spring_to(100.0, Spring::default());
+        engine.add_rest_callback(on_rest);
+        engine.add_interrupt_callback(on_interrupt);
+        engine.update(1.0 / 60.0);
+
+        engine.spring_to(50.0, Spring::default());
+        run_ready(&mut engine);
+        assert_eq!(interrupted.load(Ordering::SeqCst), 1);
+
+        while engine.update(1.0 / 60.0) {}
+        run_ready(&mut engine);
+
+        assert_eq!(rested.load(Ordering::SeqCst), 0);
+        assert_eq!(interrupted.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_animate_calls_between_ticks_apply_in_call_order() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        let (first_completed, on_first_complete) = counter();
+        let (first_interrupted, on_first_interrupt) = counter();
+        let (second_completed, on_second_complete) = counter();
+        let (second_rested, on_second_rest) = counter();
+
+        engine.queue_to(100.0, AnimationMode::Spring(Spring::default()));
+        engine.add_completion_callback(on_first_complete);
+        engine.add_interrupt_callback(on_first_interrupt);
+        let Some(first) = engine.run_token() else {
+            unreachable!("a queued call has a token");
+        };
+        engine.queue_to(50.0, AnimationMode::Tween(Tween::default()));
+        engine.add_completion_callback(on_second_complete);
+        engine.add_rest_callback(on_second_rest);
+        let Some(second) = engine.run_token() else {
+            unreachable!("a queued call has a token");
+        };
+
+        // The first call never starts
+        assert_eq!(first.outcome(), Some(RunOutcome::Replaced));
+        run_ready(&mut engine);
+        assert_eq!(first_interrupted.load(Ordering::SeqCst), 1);
+        assert_eq!(engine.get(), 0.0);
+        assert_eq!(engine.target(), Some(50.0));
+        assert!(engine.is_active());
+
+        assert!(engine.update(1.0 / 60.0));
+        assert_eq!(engine.target(), Some(50.0));
+        assert!(engine.get() > 0.0 && engine.get() < 50.0);
+        assert_eq!(second.outcome(), None);
+
+        while engine.update(1.0 / 60.0) {}
+        run_ready(&mut engine);
+        assert_eq!(engine.get(), 50.0);
+        assert_eq!(second.outcome(), Some(RunOutcome::Completed));
+        assert_eq!(second_completed.load(Ordering::SeqCst), 1);
+        assert_eq!(second_rested.load(Ordering::SeqCst), 1);
+        assert_eq!(first_completed.load(Ordering::SeqCst), 0);
+        assert_eq!(first_interrupted.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_queued_call_yields_to_later_immediate_ones() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.tween_to(10.0, Tween::default());
+        let Some(running) = engine.run_token() else {
+            unreachable!("the tween is running");
+        };
+        engine.queue_to(100.0, AnimationMode::Tween(Tween::default()));
+
+        // Cancelling the running tween leaves the queued call alone
+        assert!(engine.cancel(&running));
+        assert_eq!(engine.target(), Some(100.0));
+
+        // A later stop wins over the queued call
+        engine.stop();
+        assert!(!engine.is_active());
+        assert!(!engine.update(1.0 / 60.0));
+        assert_eq!(engine.get(), 0.0);
+    }
+
+    #[test]
+    fn test_config_loop_and_delay_reach_the_animation() {
+        let tween = Tween::new().duration(Duration::from_millis(100));
+        let mut engine = AnimationEngine::new(0.0f32);
+        let approach = Approach::from(AnimationMode::Tween(tween))
+            .timed(Some(LoopMode::Count(2)), Some(Duration::from_millis(50)));
+        engine.queue(1.0, approach);
+
+        // Held through the delay
+        for _ in 0..4 {
+            engine.update(0.01);
+        }
+        assert_eq!(engine.get(), 0.0);
+        assert_eq!(engine.total_duration(), Some(Duration::from_millis(200)));
+
+        let mut elapsed = 0.04;
+        while engine.update(0.01) {
+            elapsed += 0.01;
+        }
+        assert!((elapsed - 0.25f32).abs() < 0.02, "took {elapsed}s");
+
+        let spring = Spring::default();
+        let approach = Approach::<f32>::from(AnimationMode::Spring(spring))
+            .timed(Some(LoopMode::Infinite), None);
+        engine.queue(0.0, approach);
+        for _ in 0..600 {
+            assert!(engine.update(1.0 / 60.0), "a looping spring keeps going");
+        }
+    }
+
+    #[test]
+    fn test_spring_timing_reaches_the_animation() {
+        let mut spring = Spring::default();
+        spring.timing.loop_mode = LoopMode::Infinite;
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_to(1.0, spring);
+        for _ in 0..600 {
+            assert!(engine.update(1.0 / 60.0), "a looping spring keeps going");
+        }
+    }
+
+    #[test]
+    fn test_applied_keyframes_play_without_start() {
+        // A track handed straight to the engine, not through `start`
+        let track = KeyframeAnimation::new()
+            .at(0.0, 0.0)
+            .at(1.0, 10.0)
+            .for_duration(Duration::from_millis(100));
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.apply_keyframes(track);
+        assert!(engine.update(0.05));
+        assert!(engine.get() > 0.0);
+        while engine.update(0.01) {}
+        assert_eq!(engine.get(), 10.0);
+    }
+
+    #[test]
+    fn test_alternating_loops_play_back_the_way_they_came() {
+        let tween = Tween::new().duration(Duration::from_millis(100));
+        let alternating =
+            |mode, loop_mode| Approach::<f32>::from(mode).timed(Some(loop_mode), None);
+
+        // Forever: the way back passes through the same points, no jump to 0
+        let mut engine = AnimationEngine::new(0.0f32);
+        let (completed, on_complete) = counter();
+        engine.enqueue(
+            1.0,
+            alternating(AnimationMode::Tween(tween), LoopMode::Alternate),
+            Some(boxed(on_complete)),
+        );
+        let mut values = Vec::new();
+        for _ in 0..20 {
+            assert!(engine.update(0.01));
+            values.push(engine.get());
+        }
+        assert!((values[9] - 1.0).abs() < 1e-4);
+        for step in 1..10 {
+            // 10ms into the way back is 10ms before the end of the way there
+            assert!((values[9 + step] - values[9 - step]).abs() < 1e-3);
+        }
+        assert!(values[10] > 0.85, "the way back starts at the top");
+        assert!(engine.velocity() < 0.0 || values[19].abs() < 1e-4);
+        for _ in 0..600 {
+            assert!(engine.update(0.01));
+        }
+        run_ready(&mut engine);
+        assert_eq!(completed.load(Ordering::SeqCst), 0);
+
+        // A set number of passes ends where the last one heads, completing once
+        let track = KeyframeTrack::new(Duration::from_millis(100)).at(0.5, 0.8);
+        let spring = Spring::default();
+        for (mode, passes, end) in [
+            (AnimationMode::Tween(tween), 2, 0.0),
+            (AnimationMode::Keyframes(track), 2, 0.0),
+            (AnimationMode::Spring(spring), 3, 1.0),
+        ] {
+            let mut engine = AnimationEngine::new(0.0f32);
+            let (completed, on_complete) = counter();
+            engine.enqueue(
+                1.0,
+                alternating(mode, LoopMode::AlternateTimes(passes)),
+                Some(boxed(on_complete)),
+            );
+            let (mut last, mut frames) = (0.0f32, 0);
+            while engine.update(1.0 / 60.0) {
+                let value = engine.get();
+                assert!((value - last).abs() < 0.4, "jumped from {last} to {value}");
+                last = value;
+                run_ready(&mut engine);
+                assert_eq!(completed.load(Ordering::SeqCst), 0);
+                frames += 1;
+                assert!(frames < 3000);
+            }
+            run_ready(&mut engine);
+            assert_eq!(engine.get(), end);
+            assert_eq!(completed.load(Ordering::SeqCst), 1);
+        }
+    }
+
+    #[test]
+    fn test_stop_interrupts_running_animation() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        let (interrupted, on_interrupt) = counter();
+
+        engine.tween_to(1.0, Tween::default());
+        engine.add_interrupt_callback(on_interrupt);
+        engine.update(0.01);
+        engine.update(0.01);
+        let mid_flight = engine.get();
+        assert!(mid_flight > 0.0 && mid_flight < 1.0);
+        engine.stop();
+        run_ready(&mut engine);
+
+        assert_eq!(interrupted.load(Ordering::SeqCst), 1);
+        // The value freezes where it was and later frames leave it there
+        assert_eq!(engine.get(), mid_flight);
+        assert_eq!(engine.velocity(), 0.0);
+        assert!(!engine.update(0.01));
+        assert_eq!(engine.get(), mid_flight);
+    }
+
+    #[test]
+    fn test_tween_remaining_time_counts_down_to_zero() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        assert_eq!(engine.remaining_time(), None);
+
+        let duration = Duration::from_millis(300);
+        engine.tween_to(1.0, Tween::new().duration(duration));
+        assert_eq!(engine.remaining_time(), Some(duration));
+        assert_eq!(engine.total_duration(), Some(duration));
+
+        let mut previous = duration;
+        loop {
+            let still_active = engine.animation.as_mut().map(|animation| {
+                let (state, _, _) = animation.update(1.0 / 60.0);
+                state == AnimationState::Active
+            });
+            let remaining = engine
+                .animation
+                .as_ref()
+                .and_then(|animation| animation.remaining_time());
+            let Some(remaining) = remaining else {
+                unreachable!("tween remaining time unknown");
+            };
+            assert!(remaining < previous);
+            previous = remaining;
+
+            if still_active != Some(true) {
+                // Zero exactly when the tween completes, not before
+                assert_eq!(remaining, Duration::ZERO);
+                break;
+            }
+            assert!(remaining > Duration::ZERO);
+        }
+
+        // Idle once the engine has dropped the finished animation
+        engine.update(1.0 / 60.0);
+        assert_eq!(engine.remaining_time(), None);
+    }
+
+    #[test]
+    fn test_remaining_time_counts_loops_and_delay() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        let tween = Tween::new().duration(Duration::from_millis(200));
+        let timing = AnimationTiming::new()
+            .with_loop_mode(LoopMode::Count(3))
+            .with_delay(Duration::from_millis(100));
+        engine.apply_custom(TweenAnimation::new(0.0, 1.0, tween, timing));
+
+        assert_eq!(engine.total_duration(), Some(Duration::from_millis(600)));
+        assert_eq!(engine.remaining_time(), Some(Duration::from_millis(700)));
+
+        // Past the delay and the first pass
+        for _ in 0..15 {
+            engine.update(1.0 / 50.0);
+        }
+        let Some(remaining) = engine.remaining_time() else {
+            unreachable!("tween remaining time unknown");
+        };
+        assert!(remaining.abs_diff(Duration::from_millis(400)) < Duration::from_millis(25));
+
+        let infinite = AnimationTiming::new().with_loop_mode(LoopMode::Infinite);
+        engine.apply_custom(TweenAnimation::new(0.0, 1.0, tween, infinite));
+        assert_eq!(engine.remaining_time(), None);
+        assert_eq!(engine.total_duration(), None);
+    }
+
+    #[test]
+    fn test_stop_at_cycle_end_returns_to_rest() {
+        let pulse = || {
+            KeyframeAnimation::new()
+                .at(0.0, 1.0)
+                .at(0.5, 1.2)
+                .at(1.0, 1.0)
+                .for_duration(Duration::from_millis(400))
+                .looping(LoopMode::Infinite)
+        };
+        let mut engine = AnimationEngine::new(1.0f32);
+        engine.apply_keyframes(pulse());
+        // Past the first pass and into the peak of a later one
+        for _ in 0..30 {
+            engine.update(1.0 / 60.0);
+        }
+        while engine.get() < 1.15 {
+            assert!(engine.update(1.0 / 60.0));
+        }
+
+        engine.stop_at_cycle_end();
+        let Some(remaining) = engine.remaining_time() else {
+            unreachable!("the last pass should have a known end");
+        };
+        assert!(remaining < Duration::from_millis(400));
+        let mut frames = 0;
+        while engine.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 30, "the pulse should end with its pass");
+        }
+        assert_eq!(engine.get(), 1.0);
+
+        // A plain stop freezes mid-pulse instead
+        engine.apply_keyframes(pulse());
+        for _ in 0..12 {
+            engine.update(1.0 / 60.0);
+        }
+        engine.stop();
+        assert!(engine.get() > 1.05);
+    }
+
+    #[test]
+    fn test_stop_at_cycle_end_finishes_a_looping_tween() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        let infinite = AnimationTiming::new().with_loop_mode(LoopMode::Infinite);
+        let tween = Tween::new().duration(Duration::from_millis(100));
+        engine.apply_custom(TweenAnimation::new(0.0, 1.0, tween, infinite));
+        for _ in 0..9 {
+            engine.update(1.0 / 60.0);
+        }
+
+        engine.stop_at_cycle_end();
+        assert!(engine.is_active(), "the current pass keeps playing");
+        while engine.update(1.0 / 60.0) {}
+        assert_eq!(engine.get(), 1.0);
+
+        // Idle engines stay idle
+        engine.stop_at_cycle_end();
+        assert!(!engine.is_active());
+    }
+
+    #[test]
+    fn test_spring_remaining_time_estimate() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_to(100.0, Spring::default());
+        assert_eq!(engine.total_duration(), None);
+        let Some(estimate) = engine.remaining_time() else {
+            unreachable!("spring settle time not estimated");
+        };
+
+        let mut frames = 0u32;
+        while engine.update(1.0 / 60.0) {
+            frames += 1;
+        }
+        let simulated = Duration::from_secs_f32(frames as f32 / 60.0);
+
+        // The envelope bound is conservative but in the right ballpark
+        assert!(estimate >= simulated.mul_f32(0.5));
+        assert!(estimate <= simulated.mul_f32(3.0));
+    }
+
+    #[test]
+    fn test_undamped_spring_never_settles() {
+        let spring = Spring {
+            damping: 0.0,
+            ..Spring::default()
+        };
+        assert_eq!(spring.settle_time(100.0, 0.0, 0.01), None);
+        assert_eq!(
+            Spring::default().settle_time(0.001, 0.0, 0.01),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_max_excursion_matches_simulated_peak() {
+        fn simulated_peak(spring: Spring, start: f32, target: f32) -> f32 {
+            let mut animation = crate::animations::spring::SpringAnimation::new(
+                start,
+                target,
+                spring,
+                AnimationTiming::default(),
+            );
+            let mut peak = start;
+            while animation.update(1.0 / 2000.0).0 == AnimationState::Active {
+                peak = peak.max(animation.value());
+            }
+            peak
+        }
+
+        let springs = [
+            Spring::new().stiffness(300.0).damping(8.0),
+            Spring::new().stiffness(170.0).damping(12.0),
+            Spring {
+                damping: 0.0,
+                ..Spring::default()
+            },
+            // Critically and overdamped, pushed hard enough to overshoot
+            Spring::new()
+                .stiffness(100.0)
+                .damping(20.0)
+                .initial_velocity(2000.0),
+            Spring::new()
+                .stiffness(100.0)
+                .damping(25.0)
+                .initial_velocity(3000.0),
+        ];
+        for spring in springs {
+            let predicted = spring.max_excursion(0.0f32, 100.0);
+            if spring.damping == 0.0 {
+                // Undamped: swings the full distance past the target
+                assert!((predicted - 200.0).abs() < 0.01);
+                continue;
+            }
+            let simulated = simulated_peak(spring.clone(), 0.0, 100.0);
+            assert!(
+                (predicted - simulated).abs() < 1.0,
+                "{spring:?}: predicted {predicted}, simulated {simulated}"
+            );
+            assert!(predicted > 100.0);
+        }
+
+        // No overshoot, and overshoot below a target approached from above
+        let stiff = Spring::new().stiffness(100.0).damping(30.0);
+        assert_eq!(stiff.max_excursion(0.0f32, 100.0), 100.0);
+        let bouncy = Spring::new().stiffness(300.0).damping(8.0);
+        assert!(
+            (bouncy.max_excursion(100.0f32, 0.0) + bouncy.max_excursion(0.0f32, 100.0) - 100.0)
+                .abs()
+                < 1e-3
+        );
+        assert_eq!(bouncy.max_excursion(5.0f32, 5.0), 5.0);
+    }
+
+    #[test]
+    fn test_evaluated_springs_match_the_integrator() {
+        // Under, critically and overdamped, with and without a push
+        let cases = [
+            (Spring::new().stiffness(300.0).damping(8.0), 0.0),
+            (Spring::new().stiffness(170.0).damping(12.0), -400.0),
+            (Spring::new().stiffness(100.0).damping(20.0), 0.0),
+            (Spring::new().stiffness(100.0).damping(20.0), 1500.0),
+            (Spring::new().stiffness(100.0).damping(45.0), 0.0),
+            (
+                Spring::new().stiffness(100.0).damping(45.0).mass(2.0),
+                800.0,
+            ),
+        ];
+        for (spring, push) in cases {
+            let mut animation = spring.create_animation(0.0f32, 100.0, push);
+            let dt = 1.0 / 4000.0;
+            for step in 1..=4000 {
+                let (state, value, velocity) = animation.update(dt);
+                if state == AnimationState::Completed {
+                    break;
+                }
+                if step % 200 == 0 {
+                    let (position, speed) = spring.evaluate(0.0, 100.0, push, step as f32 * dt);
+                    assert!(
+                        (position - value).abs() < 0.5 && (speed - velocity).abs() < 5.0,
+                        "{spring} pushed {push} at step {step}: \
+                         ({position}, {speed}) vs ({value}, {velocity})"
+                    );
+                }
+            }
+        }
+
+        // Starting points and an undamped swing
+        let spring = Spring::default();
+        assert_eq!(spring.evaluate(10.0, 20.0, 3.0, 0.0), (10.0, 3.0));
+        let undamped = Spring {
+            damping: 0.0,
+            ..Spring::default()
+        };
+        let half_period = std::f32::consts::PI / 10.0;
+        let (position, _) = undamped.evaluate(0.0, 100.0, 0.0, half_period);
+        assert!((position - 200.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_spring_samples_end_on_the_target() {
+        let spring = Spring::new().stiffness(300.0).damping(8.0);
+        let samples = spring.sample(0.0, 100.0, 0.0, 60.0, Duration::from_secs(10));
+        assert_eq!(samples.first(), Some(&0.0));
+        assert_eq!(samples.last(), Some(&100.0));
+        assert!(samples.iter().any(|sample| *sample > 100.0));
+        let Some(settle) = spring.settle_time(100.0, 0.0, 0.1) else {
+            unreachable!("a damped spring settles");
+        };
+        assert_eq!(
+            samples.len(),
+            (settle.as_secs_f32() * 60.0).ceil() as usize + 1
+        );
+
+        // Cut short, the last sample is wherever the spring got to
+        let cut = spring.sample(0.0, 100.0, 0.0, 60.0, Duration::from_millis(100));
+        assert_eq!(cut.len(), 7);
+        assert_eq!(cut.last(), Some(&spring.evaluate(0.0, 100.0, 0.0, 0.1).0));
+        let undamped = Spring {
+            damping: 0.0,
+            ..Spring::default()
+        };
+        assert_eq!(
+            undamped
+                .sample(0.0, 1.0, 0.0, 10.0, Duration::from_secs(1))
+                .len(),
+            11
+        );
+        assert_eq!(
+            spring.sample(5.0, 5.0, 0.0, 60.0, Duration::from_secs(1)),
+            [5.0]
+        );
+    }
+
+    #[test]
+    fn test_default_animation_per_type() {
+        fn default_mode<T: Animatable>(value: T) -> AnimationMode {
+            AnimationEngine::new(value).default_animation().clone()
+        }
+
+        // Numbers use a spring that doesn't overshoot
+        for mode in [
+            default_mode(0.0f32),
+            default_mode(0.0f64),
+            default_mode(0i32),
+            default_mode([0.0f32; 3]),
+        ] {
+            let AnimationMode::Spring(spring) = mode else {
+                unreachable!("numbers should default to a spring");
+            };
+            assert!(spring.damping >= 2.0 * (spring.stiffness * spring.mass).sqrt());
+        }
+
+        let AnimationMode::Tween(tween) = default_mode(crate::Color::white()) else {
+            unreachable!("colors should default to a tween");
+        };
+        assert_eq!(tween.duration, Duration::from_millis(200));
+
+        assert_eq!(
+            default_mode(crate::Transform::identity()),
+            AnimationMode::Spring(Spring::default())
+        );
+    }
+
+    #[test]
+    fn test_snappy_spring_keeps_opacity_in_range() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        let AnimationMode::Spring(spring) = engine.default_animation().clone() else {
+            unreachable!("f32 should default to a spring");
+        };
+        engine.spring_to(1.0, spring);
+        while engine.update(1.0 / 60.0) {
+            assert!(engine.get() <= 1.0);
+        }
+        assert_eq!(engine.get(), 1.0);
+    }
+
+    #[test]
+    fn test_heavy_weight_has_more_inertia() {
+        use crate::animations::spring::Weight;
+
+        let run = |weight: Weight| {
+            let mut engine = AnimationEngine::new(0.0f32);
+            engine.spring_to(100.0, Spring::default().weight(weight));
+            let mut trajectory = Vec::new();
+            while engine.update(1.0 / 60.0) {
+                trajectory.push(engine.get());
+            }
+            trajectory
+        };
+        let (light, heavy) = (run(Weight::Light), run(Weight::Heavy));
+
+        // Slower off the mark, and longer to come to rest
+        assert!(heavy[5] < light[5] * 0.6);
+        assert!(heavy.len() > light.len());
+        assert_eq!(
+            Spring::default().weight(Weight::Medium).mass,
+            Spring::default().mass
+        );
+    }
+
+    #[test]
+    fn test_motion_config_overrides_type_default() {
+        thread_local! {
+            static VALUES: Cell<[Option<MotionValue<f32>>; 2]> = const { Cell::new([None; 2]) };
+        }
+
+        fn configured() -> AnimationMode {
+            AnimationMode::Tween(Tween::new().duration(Duration::from_millis(250)))
+        }
+
+        #[component]
+        fn Configured() -> Element {
+            crate::use_motion_config(|| MotionConfig::new().with_default_animation(configured()));
+            rsx! { Leaf { index: 0 } }
+        }
+
+        #[component]
+        fn Leaf(index: usize) -> Element {
+            let value = crate::use_motion(0.0f32);
+            let mut values = VALUES.get();
+            values[index] = Some(value);
+            VALUES.set(values);
+            rsx! {}
+        }
+
+        fn app() -> Element {
+            rsx! {
+                Configured {}
+                Leaf { index: 1 }
+            }
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let [Some(configured_value), Some(plain_value)] = VALUES.get() else {
+            unreachable!("the leaves store their values");
+        };
+        dom.in_runtime(|| {
+            // Below the config its default wins, elsewhere the type's
+            assert_eq!(
+                configured_value.engine.peek().default_animation(),
+                &configured()
+            );
+            assert_eq!(
+                plain_value.engine.peek().default_animation(),
+                AnimationEngine::new(0.0f32).default_animation()
+            );
+            assert_ne!(plain_value.engine.peek().default_animation(), &configured());
+        });
+    }
+
+    #[test]
+    fn test_motion_with_computes_its_initial_value_once() {
+        thread_local! {
+            static CALLS: Cell<usize> = const { Cell::new(0) };
+            static RENDERS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        fn app() -> Element {
+            let value = crate::use_motion_with(|| {
+                CALLS.set(CALLS.get() + 1);
+                42.0f32
+            });
+            RENDERS.set(RENDERS.get() + 1);
+            rsx! { "{value.get()}" }
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        for _ in 0..3 {
+            dom.mark_dirty(ScopeId::APP);
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+        assert_eq!(RENDERS.get(), 4);
+        assert_eq!(CALLS.get(), 1);
+    }
+
+    #[test]
+    fn test_teardown_releases_callbacks_and_stops_updates() {
+        let captured = Arc::new(());
+        let mut engine = AnimationEngine::new(0.0f32);
+
+        let timing = AnimationTiming::new().with_on_complete({
+            let captured = captured.clone();
+            move || {
+                let _ = &captured;
+            }
+        });
+        engine.apply_custom(TweenAnimation::new(0.0, 1.0, Tween::default(), timing));
+        for add in [
+            AnimationEngine::<f32>::add_completion_callback,
+            AnimationEngine::<f32>::add_rest_callback,
+            AnimationEngine::<f32>::add_interrupt_callback,
+        ] {
+            let captured = captured.clone();
+            add(&mut engine, move || drop(captured));
+        }
+        engine.update(0.1);
+        let value = engine.get();
+        assert_eq!(Arc::strong_count(&captured), 5);
+
+        // Simulated unmount
+        engine.teardown();
+        assert_eq!(Arc::strong_count(&captured), 1);
+        assert!(!engine.has_ready_callbacks());
+
+        assert!(!engine.update(0.1));
+        assert_eq!(engine.get(), value);
+        assert_eq!(engine.remaining_time(), None);
+    }
+
+    #[cfg(feature = "trace")]
+    #[test]
+    fn test_trace_records_curve_shape() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.set_trace_capacity(1000);
+        engine.tween_to(
+            1.0,
+            Tween::new()
+                .duration(Duration::from_millis(500))
+                .easing(|t, b, c, d| c * (t / d) * (t / d) + b),
+        );
+        while engine.update(0.05) {}
+
+        let trace = engine.trace();
+        assert_eq!(trace.len(), 10);
+        assert!(trace.windows(2).all(|pair| pair[0].time < pair[1].time));
+        assert_eq!(trace.last().map(|sample| sample.value), Some(1.0));
+
+        // Ease-in: each step is bigger than the previous one
+        let steps: Vec<f32> = trace
+            .windows(2)
+            .map(|pair| pair[1].value - pair[0].value)
+            .collect();
+        assert!(steps.windows(2).all(|pair| pair[1] > pair[0]));
+
+        // Idle frames advance the clock but record nothing
+        engine.update(1.0);
+        engine.set(0.0);
+        let Some(sample) = engine.trace().last().copied() else {
+            unreachable!("set was not recorded");
+        };
+        assert_eq!(sample.value, 0.0);
+        assert!((sample.time - 1.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_cancel_after_replacement_is_noop() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_to(100.0, Spring::default());
+        let Some(first) = engine.run_token() else {
+            unreachable!("spring_to started no run");
+        };
+        engine.update(1.0 / 60.0);
+
+        engine.tween_to(50.0, Tween::new().duration(Duration::from_millis(200)));
+        let Some(second) = engine.run_token() else {
+            unreachable!("tween_to started no run");
+        };
+        assert_eq!(first.outcome(), Some(RunOutcome::Replaced));
+        assert!(second.is_active());
+
+        // The stale token leaves the tween running
+        assert!(!engine.cancel(&first));
+        assert!(engine.is_active());
+        assert!(second.is_active());
+
+        assert!(engine.cancel(&second));
+        assert!(!engine.is_active());
+        assert_eq!(second.outcome(), Some(RunOutcome::Cancelled));
+        assert!(!engine.cancel(&second));
+    }
+
+    #[test]
+    fn test_awaiting_token_resolves_on_completion() {
+        use std::future::Future;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct CountingWaker(AtomicUsize);
+        impl Wake for CountingWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        let wakes = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let waker = Waker::from(wakes.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.tween_to(1.0, Tween::new().duration(Duration::from_millis(100)));
+        let Some(token) = engine.run_token() else {
+            unreachable!("tween_to started no run");
+        };
+        let mut finished = std::pin::pin!(token.finished());
+        assert_eq!(finished.as_mut().poll(&mut cx), Poll::Pending);
+
+        engine.update(0.05);
+        assert_eq!(finished.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(wakes.0.load(Ordering::SeqCst), 0);
+
+        while engine.update(0.05) {}
+        assert_eq!(wakes.0.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            finished.as_mut().poll(&mut cx),
+            Poll::Ready(RunOutcome::Completed)
+        );
+        assert!(!token.is_active());
+        assert!(engine.run_token().is_none());
+    }
+
+    #[test]
+    fn test_awaiting_token_resolves_on_cancellation() {
+        let waker = std::task::Waker::noop();
+        let mut cx = std::task::Context::from_waker(waker);
+
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.spring_to(1.0, Spring::default());
+        let Some(token) = engine.run_token() else {
+            unreachable!("spring_to started no run");
+        };
+        let mut finished = std::pin::pin!(token.finished());
+        assert!(std::future::Future::poll(finished.as_mut(), &mut cx).is_pending());
+
+        engine.set(0.5);
+        assert_eq!(
+            std::future::Future::poll(finished.as_mut(), &mut cx),
+            std::task::Poll::Ready(RunOutcome::Cancelled)
+        );
+
+        // Unmounting cancels too
+        engine.spring_to(1.0, Spring::default());
+        let Some(token) = engine.run_token() else {
+            unreachable!("spring_to started no run");
+        };
+        engine.teardown();
+        assert_eq!(token.outcome(), Some(RunOutcome::Cancelled));
+    }
+
+    #[test]
+    fn test_out_of_range_is_noted_once_per_animation() {
+        use crate::properties::style::MotionStyle;
+
+        let mut engine = AnimationEngine::new(MotionStyle::hidden());
+        // A bouncy spring swings the opacity past 1
+        let bouncy = Spring::new().stiffness(400.0).damping(5.0);
+        engine.spring_to(MotionStyle::visible(), bouncy);
+        while engine.update(1.0 / 60.0) {}
+        assert!(engine.out_of_range);
+
+        // A new animation starts with a clean slate
+        engine.tween_to(MotionStyle::hidden(), Tween::default());
+        assert!(!engine.out_of_range);
+        while engine.update(1.0 / 60.0) {}
+        assert!(!engine.out_of_range);
+    }
+
+    #[test]
+    fn test_target_tracks_springs_and_tweens() {
+        let mut engine = AnimationEngine::new(0.0f32);
+        assert_eq!(engine.target(), None);
+
+        engine.spring_to(100.0, Spring::default());
+        assert_eq!(engine.target(), Some(100.0));
+        engine.chase(50.0, &Spring::default());
+        assert_eq!(engine.target(), Some(50.0));
+
+        engine.apply_keyframes(KeyframeAnimation::new().duration(Duration::from_millis(100)));
+        assert_eq!(engine.target(), None);
+
+        engine.tween_to(10.0, Tween::default());
+        while engine.update(1.0 / 60.0) {}
+        assert_eq!(engine.target(), None);
+    }
+
+    #[test]
+    fn test_blended_retargets_keep_their_momentum() {
+        use easer::functions::{Cubic, Easing};
+
+        const DT: f32 = 1.0 / 60.0;
+
+        /// Largest change of speed between two frames while the target moves
+        /// on every 6 frames
+        fn largest_kick(blend: Option<Duration>) -> (f32, AnimationEngine<f32>) {
+            // Eased-out tweens start fast, so restarting one jolts the value
+            let tween = || {
+                let tween = Tween::new().easing(Cubic::ease_out);
+                Approach::Tween(tween, AnimationTiming::default())
+            };
+            let mut engine = AnimationEngine::new(0.0f32);
+            let (mut last, mut speed, mut kick) = (0.0f32, 0.0f32, 0.0f32);
+            for frame in 0..60 {
+                // A pointer dragged steadily to the right
+                if frame % 6 == 0 {
+                    let playback = Playback {
+                        blend,
+                        ..Playback::default()
+                    };
+                    engine.queue_with(frame as f32 * 5.0 + 30.0, tween(), playback);
+                }
+                engine.update(DT);
+                let next_speed = (engine.get() - last) / DT;
+                if frame > 0 {
+                    kick = kick.max((next_speed - speed).abs());
+                }
+                (last, speed) = (engine.get(), next_speed);
+            }
+            (kick, engine)
+        }
+
+        let (restart, _) = largest_kick(None);
+        let (blended, mut engine) = largest_kick(Some(Duration::from_millis(150)));
+        assert!(
+            blended < restart / 3.0,
+            "blending kicked by {blended}, restarting by {restart}"
+        );
+
+        // The last target is reached once the blend is over
+        while engine.update(DT) {}
+        assert_eq!(engine.get(), 300.0);
+        assert!(!engine.is_active());
+
+        // From rest there is nothing to blend out
+        let mut still = AnimationEngine::new(0.0f32);
+        let mut plain = AnimationEngine::new(0.0f32);
+        let tween = Tween::new().duration(Duration::from_millis(200));
+        still.queue_with(
+            10.0,
+            Approach::Tween(tween, AnimationTiming::default()),
+            Playback {
+                blend: Some(Duration::from_millis(100)),
+                ..Playback::default()
+            },
+        );
+        plain.queue_to(10.0, AnimationMode::Tween(tween));
+        for _ in 0..6 {
+            still.update(DT);
+            plain.update(DT);
+            assert_eq!(still.get(), plain.get());
+        }
+    }
+
+    #[test]
+    fn test_delayed_spring_holds_the_value_until_its_delay_elapsed() {
+        thread_local! {
+            static ENGINES: Cell<Option<[Signal<AnimationEngine<f32>>; 2]>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            ENGINES.set(Some([
+                use_signal(|| AnimationEngine::new(0.0f32)),
+                use_signal(|| AnimationEngine::new(0.0f32)),
+            ]));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some([mut delayed, mut plain]) = ENGINES.get() else {
+            unreachable!("the app stores its engines");
+        };
+        let spring = |engine| {
+            MotionValue::new(engine)
+                .spring()
+                .stiffness(180.0)
+                .damping(12.0)
+        };
+
+        dom.in_runtime(|| {
+            spring(delayed)
+                .delay(Duration::from_millis(250))
+                .animate_to(100.0);
+            // 240ms of uneven frames: still waiting
+            for dt in [0.1, 0.1, 0.04] {
+                assert!(delayed.write().update(dt));
+                assert_eq!(delayed.peek().get(), 0.0);
+            }
+            delayed.write().update(0.02);
+            assert!(delayed.peek().get() > 0.0);
+
+            // Retargeting during a delay starts the new delay afresh
+            delayed.write().set(0.0);
+            spring(delayed)
+                .delay(Duration::from_millis(250))
+                .animate_to(100.0);
+            delayed.write().update(0.2);
+            spring(delayed)
+                .delay(Duration::from_millis(250))
+                .animate_to(50.0);
+            delayed.write().update(0.2);
+            assert_eq!(delayed.peek().get(), 0.0);
+            delayed.write().update(0.1);
+            assert!(delayed.peek().get() > 0.0);
+            assert_eq!(delayed.peek().target(), Some(50.0));
+
+            // A zero delay plays as if there were none
+            delayed.write().set(0.0);
+            spring(delayed).delay(Duration::ZERO).animate_to(100.0);
+            spring(plain).animate_to(100.0);
+            for _ in 0..30 {
+                delayed.write().update(1.0 / 60.0);
+                plain.write().update(1.0 / 60.0);
+                assert_eq!(delayed.peek().get(), plain.peek().get());
+            }
+        });
+    }
+
+    #[test]
+    fn test_without_frames_animations_land_at_once() {
+        thread_local! {
+            static VALUES: Cell<Option<[Signal<AnimationEngine<f32>>; 3]>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let values = [
+                use_signal(|| AnimationEngine::new(0.0f32)),
+                use_signal(|| AnimationEngine::new(0.0f32)),
+                use_signal(|| AnimationEngine::new(0.0f32)),
+            ];
+            VALUES.set(Some(values));
+            use_hook(move || {
+                let [looping, mut chained, _] = values.map(MotionValue::new);
+                looping
+                    .spring()
+                    .loop_mode(LoopMode::Infinite)
+                    .animate_to(10.0);
+                let fade_then_slide = AnimationConfig::new(AnimationMode::Tween(Tween::default()))
+                    .with_on_complete(|| {
+                        motion(1).animate_to(20.0);
+                    });
+                chained.animate_to_with_config(1.0, fade_then_slide);
+                bounce(100.0);
+            });
+            rsx! {}
+        }
+
+        /// The app's value at `index`; callbacks must be `Send` natively, so
+        /// values are looked up rather than captured
+        fn motion(index: usize) -> MotionValue<f32> {
+            let Some(values) = VALUES.get() else {
+                unreachable!("the app stores its values");
+            };
+            MotionValue::new(values[index])
+        }
+
+        /// Ping-pong between 0 and 100 forever, each leg started by the last
+        fn bounce(target: f32) {
+            let config = AnimationConfig::new(AnimationMode::Tween(Tween::default()))
+                .with_on_complete(move || bounce(100.0 - target));
+            motion(2).animate_to_with_config(target, config);
+        }
+
+        // No async runtime, as when rendering on the server
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some([looping, chained, bouncing]) = VALUES.get() else {
+            unreachable!("the app stores its values");
+        };
+        dom.in_runtime(|| {
+            assert_eq!(looping.peek().get(), 10.0);
+            assert!(!looping.peek().is_active());
+            // Chained calls land too...
+            assert_eq!(chained.peek().get(), 20.0);
+            assert!(!chained.peek().is_active());
+            // ...up to a point, so endless ones end
+            assert!(bouncing.peek().is_active());
+        });
+    }
+
+    #[test]
+    fn test_with_a_runtime_animations_wait_for_frames() {
+        thread_local! {
+            static ENGINE: Cell<Option<Signal<AnimationEngine<f32>>>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            let engine = use_signal(|| AnimationEngine::new(0.0f32));
+            ENGINE.set(Some(engine));
+            use_hook(move || MotionValue::new(engine).spring().animate_to(10.0));
+            rsx! {}
+        }
+
+        // A Tokio runtime, as in a native app whatever its features
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(engine) = ENGINE.get() else {
+            unreachable!("the app stores its engine");
+        };
+        dom.in_runtime(|| {
+            assert_eq!(engine.peek().get(), 0.0);
+            assert!(engine.peek().is_active());
+        });
+    }
+
+    #[test]
+    fn test_queued_animations_play_one_after_another() {
+        thread_local! {
+            static ENGINE: Cell<Option<Signal<AnimationEngine<f32>>>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            ENGINE.set(Some(use_signal(|| AnimationEngine::new(0.0f32))));
+            rsx! {}
+        }
+
+        let tween = || {
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new().duration(Duration::from_millis(100)),
+            ))
+        };
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(mut engine) = ENGINE.get() else {
+            unreachable!("the app stores its engine");
+        };
+
+        dom.in_runtime(|| {
+            let mut motion = MotionValue::new(engine);
+            let completed = Arc::new(AtomicUsize::new(0));
+            // Three quick clicks, each queueing a move
+            let tokens: Vec<_> = [10.0, 20.0, 30.0]
+                .into_iter()
+                .map(|target| {
+                    let completed = completed.clone();
+                    motion.queue(
+                        target,
+                        tween().with_on_complete(move || {
+                            completed.fetch_add(1, Ordering::SeqCst);
+                        }),
+                    )
+                })
+                .collect();
+
+            // Each move plays in full from where the last one ended
+            for (index, target) in [10.0, 20.0, 30.0].into_iter().enumerate() {
+                for _ in 0..6 {
+                    engine.write().update(1.0 / 60.0);
+                    motion.run_ready_callbacks();
+                }
+                assert!((motion.peek() - target).abs() < 1e-3);
+                assert_eq!(completed.load(Ordering::SeqCst), index + 1);
+                assert_eq!(tokens[index].outcome(), Some(RunOutcome::Completed));
+            }
+            assert!(!motion.is_animating());
+
+            // A queued move can be cancelled alone; stop drops the rest
+            let first = motion.queue(40.0, tween());
+            let second = motion.queue(50.0, tween());
+            let third = motion.queue(60.0, tween());
+            assert!(motion.cancel(&second));
+            engine.write().update(1.0 / 60.0);
+            motion.stop();
+            assert_eq!(first.outcome(), Some(RunOutcome::Cancelled));
+            assert_eq!(second.outcome(), Some(RunOutcome::Cancelled));
+            assert_eq!(third.outcome(), Some(RunOutcome::Cancelled));
+
+            // Any other animate call replaces the queue
+            motion.queue(70.0, tween());
+            let waiting = motion.queue(80.0, tween());
+            motion.animate_to_with_config(90.0, tween());
+            assert_eq!(waiting.outcome(), Some(RunOutcome::Replaced));
+        });
+    }
+
+    #[test]
+    fn test_keyframe_configs_play_relative_to_the_move() {
+        let overshoot = KeyframeTrack::new(Duration::from_millis(100)).at(0.5, 1.5);
+        let mut engine = AnimationEngine::new(10.0f32);
+        engine.queue_to(20.0, AnimationMode::Keyframes(overshoot));
+        for _ in 0..3 {
+            engine.update(1.0 / 60.0);
+        }
+        // Halfway through, half as far again past the target
+        assert!((engine.get() - 25.0).abs() < 0.5);
+        let mut frames = 0;
+        while engine.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 60, "the track never ended");
+        }
+        assert_eq!(engine.get(), 20.0);
+
+        // A shake returns to where it started, so that is where the run heads
+        let shake = KeyframeTrack::new(Duration::from_millis(100))
+            .at(0.5, 1.0)
+            .at(1.0, 0.0);
+        engine.queue_to(30.0, AnimationMode::Keyframes(shake));
+        engine.update(1.0 / 60.0);
+        assert_eq!(engine.target(), Some(20.0));
+    }
+
+    #[test]
+    fn test_velocity_is_reported_for_every_kind_of_animation() {
+        const DT: f32 = 1.0 / 60.0;
+        let mut engine = AnimationEngine::new(0.0f32);
+        assert_eq!(engine.velocity(), 0.0);
+
+        // A linear tween covers 100 in a second
+        engine.tween_to(100.0, Tween::new().duration(Duration::from_secs(1)));
+        for _ in 0..10 {
+            engine.update(DT);
+        }
+        assert!((engine.velocity() - 100.0).abs() < 1.0);
+
+        // Keyframes back down at the same pace
+        engine.queue_to(
+            engine.get() - 100.0,
+            AnimationMode::Keyframes(KeyframeTrack::new(Duration::from_secs(1))),
+        );
+        for _ in 0..10 {
+            engine.update(DT);
+        }
+        assert!((engine.velocity() + 100.0).abs() < 1.0);
+
+        // Springs report their own
+        engine.spring_to(100.0, Spring::default());
+        engine.update(DT);
+        let spring = engine
+            .animation
+            .as_ref()
+            .map_or(0.0, |animation| animation.velocity());
+        assert_eq!(engine.velocity(), spring);
+        assert!(spring > 0.0);
+
+        while engine.update(DT) {}
+        assert_eq!(engine.velocity(), 0.0);
+    }
+
+    #[test]
+    fn test_animate_by_nudges_from_the_current_value() {
+        thread_local! {
+            static ENGINE: Cell<Option<Signal<AnimationEngine<f32>>>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            ENGINE.set(Some(use_signal(|| AnimationEngine::new(0.0f32))));
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(mut engine) = ENGINE.get() else {
+            unreachable!("the app stores its engine");
+        };
+
+        dom.in_runtime(|| {
+            let mut motion = MotionValue::new(engine);
+            motion.animate_by(50.0);
+            assert_eq!(engine.peek().target(), Some(50.0));
+            for _ in 0..5 {
+                engine.write().update(1.0 / 60.0);
+            }
+
+            // A second click mid-flight adds to where the value is, not to 50
+            let current = motion.peek();
+            assert!(current > 0.0 && current < 50.0);
+            motion.animate_by(50.0);
+            assert_eq!(engine.peek().target(), Some(current + 50.0));
+        });
+    }
+
+    #[test]
+    fn test_chained_calls_survive_stale_retargets() {
+        thread_local! {
+            static ENGINE: Cell<Option<Signal<AnimationEngine<f32>>>> = const { Cell::new(None) };
+        }
+
+        fn app() -> Element {
+            ENGINE.set(Some(use_signal(|| AnimationEngine::new(0.0f32))));
+            rsx! {}
+        }
+
+        fn tween(millis: u64) -> AnimationConfig {
+            AnimationConfig::new(AnimationMode::Tween(
+                Tween::new().duration(Duration::from_millis(millis)),
+            ))
+        }
+
+        /// Ping-pong between 0 and 100 forever, each leg started by the last
+        fn bounce(target: f32, legs: Arc<AtomicUsize>) {
+            let config = tween(50).with_on_complete(move || {
+                legs.fetch_add(1, Ordering::SeqCst);
+                bounce(100.0 - target, legs);
+            });
+            // Callbacks must be `Send` natively, so the motion value is looked
+            // up rather than captured
+            if let Some(engine) = ENGINE.get() {
+                MotionValue::new(engine).animate_to_with_config(target, config);
+            }
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(mut engine) = ENGINE.get() else {
+            unreachable!("the app stores its engine");
+        };
+        let legs = Arc::new(AtomicUsize::new(0));
+
+        dom.in_runtime(|| {
+            let mut motion = MotionValue::new(engine);
+            bounce(100.0, legs.clone());
+
+            let mut seed = 0x2545_f491u32;
+            for frame in 0..3000 {
+                // What a handler read before the frame
+                let stale = motion.peek();
+                engine.write().update(1.0 / 60.0);
+                motion.run_ready_callbacks();
+
+                // The handler then retargets, sometimes right after a leg
+                // ended and chained the next one
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                if seed >> 29 < 2 {
+                    let (interrupted, on_interrupt) = counter();
+                    let token = motion.animate_to_with_config(stale, tween(30));
+                    motion.engine.write().add_interrupt_callback(on_interrupt);
+                    let yielded = motion.token().is_some_and(|running| running != token);
+                    if yielded {
+                        // The stale call ends at the next tick, without
+                        // starting
+                        engine.write().update(0.0);
+                        motion.run_ready_callbacks();
+                        assert_eq!(token.outcome(), Some(RunOutcome::Replaced));
+                        assert_eq!(interrupted.load(Ordering::SeqCst), 1);
+                    }
+                }
+                assert!(
+                    engine.peek().is_active(),
+                    "the chain stopped at frame {frame}"
+                );
+            }
+        });
+        assert!(legs.load(Ordering::SeqCst) > 100);
+    }
+
+    #[test]
+    fn test_animate_to_if_changed_keeps_running_animation() {
+        thread_local! {
+            static TOKENS: std::cell::RefCell<Vec<(u64, f32, bool)>> = const { std::cell::RefCell::new(Vec::new()) };
+        }
+
+        fn app() -> Element {
+            let mut engine = use_signal(|| AnimationEngine::new(0.0f32));
+            use_hook(move || {
+                let mut motion = MotionValue::new(engine);
+                let config = || AnimationConfig::new(AnimationMode::Tween(Tween::default()));
+                let record = move |token: AnimationToken| {
+                    let value = engine.peek().get();
+                    TOKENS.with_borrow_mut(|tokens| {
+                        tokens.push((token.id(), value, token.is_active()))
+                    });
+                };
+
+                record(motion.animate_to_if_changed(100.0, config()));
+                engine.write().update(1.0 / 60.0);
+                // As an effect re-running with the same target would
+                record(motion.animate_to_if_changed(100.0, config()));
+                record(motion.animate_to_if_changed(50.0, config()));
+
+                motion.set(20.0);
+                record(motion.animate_to_if_changed(20.0, config()));
+            });
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+
+        let tokens = TOKENS.with_borrow(|tokens| tokens.clone());
+        assert_eq!(tokens.len(), 4);
+        assert_eq!(tokens[1].0, tokens[0].0, "same target restarted the tween");
+        assert!(tokens[1].1 > 0.0);
+        assert_ne!(tokens[2].0, tokens[1].0, "new target ignored");
+        // Already resting at the target: nothing to start
+        assert!(!tokens[3].2);
+    }
+
+    #[test]
+    fn test_animate_to_if_changed_sees_darker_colors() {
+        thread_local! {
+            static STARTED: Cell<bool> = const { Cell::new(false) };
+        }
+
+        fn app() -> Element {
+            let engine = use_signal(|| AnimationEngine::new(crate::Color::white()));
+            use_hook(move || {
+                let config = AnimationConfig::new(AnimationMode::Tween(Tween::default()));
+                let token =
+                    MotionValue::new(engine).animate_to_if_changed(crate::Color::black(), config);
+                STARTED.set(token.is_active());
+            });
+            rsx! {}
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        VirtualDom::new(app).rebuild_in_place();
+        assert!(STARTED.get());
+    }
 }
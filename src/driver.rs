@@ -0,0 +1,1571 @@
+//! Shared animation driver
+//!
+//! Every motion value created with [`use_motion`](crate::use_motion) is ticked by
+//! a single loop per thread instead of a loop of its own. All engines advance in
+//! the same frame, which lets per-frame work be batched in two phases:
+//!
+//! 1. **Compute**: each engine advances and, if it has a direct style binding
+//!    ([`MotionValue::bind_style`]), queues its rendered style string.
+//! 2. **Write**: the queued styles are applied back-to-back, after every engine
+//!    has updated, so the DOM is only touched once all values for the frame are
+//!    known and reads never interleave with writes.
+//!
+//! # Frame budget
+//!
+//! The compute phase of a frame is limited to a time budget, 8ms by default
+//! (see [`set_frame_budget`]). Engines with [`Priority::High`], e.g. the element
+//! being dragged, advance first, followed by [`Priority::Normal`] ones; both
+//! advance every frame. Engines marked [`Priority::Low`], e.g. ambient or decorative
+//! motion, are advanced only while budget remains; the rest are deferred to the
+//! next frame and then advanced by the whole time they missed, so they skip
+//! ahead instead of falling behind. The longest deferred engines go first, so
+//! under sustained load the work is spread round-robin over several frames.
+//!
+//! # Pausing
+//!
+//! [`pause_all`] freezes every animation on the thread at once, e.g. behind a
+//! modal overlay or while debugging a frame. Engines keep their value, velocity
+//! and progress, and [`resume_all`] continues each from where it stopped; the
+//! time spent paused is never applied. Until then the driver loop is parked
+//! instead of waking up for frames.
+//!
+//! # Completion callbacks
+//!
+//! Values always land on their targets in the frame their animations end, but
+//! when many end together, e.g. a staggered grid of hundreds of items, running
+//! every completion callback in that frame would stall it. Past a number of
+//! callbacks per frame (see [`set_completion_budget`]) the rest wait and are
+//! spread evenly over the next few frames. Engines with [`Priority::High`] are
+//! essential: their callbacks always run in the frame they end.
+//!
+//! # Animation cap
+//!
+//! A component that misbehaves, e.g. one starting a looping animation on every
+//! render, can end up with hundreds running at once and bring a low-end device
+//! to a crawl. Past a soft cap of animations running at once, 256 by default
+//! (see [`set_animation_cap`]), the driver logs a warning naming the labels
+//! most of them come from, at most once every ten seconds or so. Optionally,
+//! new ambient animations are cut back while over the cap, e.g. not started at
+//! all or landed on their target at once. [`motion_stats`] tells how many are
+//! running.
+//!
+//! The cap is per thread, like the driver: it counts the animations of every
+//! component and every virtual dom on the thread together, not those of one
+//! motion value, component or [`MotionConfig`](crate::MotionConfig) scope.
+//!
+//! # Deterministic mode
+//!
+//! For profiling, e.g. in CI performance jobs, [`MotionConfig::deterministic_mode`](crate::MotionConfig::deterministic_mode)
+//! makes the driver advance every engine by exactly the same time step each
+//! frame, whatever the real clock says, and never slow down while idle or defer
+//! low priority engines. Runs then do the same work frame for frame and stay
+//! comparable. It can also be switched on without code changes through the
+//! `DIOXUS_MOTION_FIXED_DT` environment variable, see
+//! [`use_motion_config`](crate::use_motion_config).
+//!
+//! # Without frames
+//!
+//! Where nothing will ever drive the loop, e.g. when rendering on the server or
+//! in a test without an async runtime, animate calls land on their target at
+//! once: the rendered output shows where animations end instead of where they
+//! start. In the browser frames come from the `window`'s timers, so there must
+//! be one; natively, whether or not the `desktop` feature is on, from the Tokio
+//! runtime the app runs in.
+//!
+//! # Measuring
+//!
+//! With many independently animated elements, going through signals and
+//! re-renders causes one DOM mutation per element, spread over the frame. Binding
+//! the styles directly collapses them into one write pass:
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Dot(index: usize) -> Element {
+//!     let mut y = use_motion(0.0f32);
+//!     use_effect(move || {
+//!         y.tween()
+//!             .duration(Duration::from_millis(800 + index as u64 * 5))
+//!             .animate_to(200.0);
+//!     });
+//!
+//!     rsx! {
+//!         div {
+//!             class: "dot",
+//!             onmounted: move |event| {
+//!                 y.bind_style(&event.data(), |y| format!("transform: translateY({y}px)"))
+//!             },
+//!         }
+//!     }
+//! }
+//!
+//! #[component]
+//! fn Dots() -> Element {
+//!     rsx! {
+//!         for index in 0..200 {
+//!             Dot { key: "{index}", index }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! `cargo test --release --lib -- --ignored bench_bound_dots` animates the 200
+//! dots headless and counts, per frame, the components re-rendered and the DOM
+//! edits their diff produces: 200 of each with styles from `rsx!`, none with
+//! bound styles. It also checks that the bound frame takes less CPU time, the
+//! driver tick included.
+//!
+//! Bound, the 200 style strings are written in one pass after the compute
+//! phase instead. The browser's own layout work is not part of the bench:
+//! to see it, record the animation in the performance panel and compare the
+//! style recalculations per frame.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+
+use crate::animation::{Callback, MaybeSend};
+use crate::animations::power::AmbientReduction;
+use crate::core::{StyleFn, run_callbacks};
+use crate::{Animatable, Duration, MotionTime, MotionValue, TimeProvider};
+
+/// Advances one engine by a time delta; returns whether it is still animating
+pub(crate) type TickFn = Box<dyn FnMut(f32) -> bool>;
+
+/// How urgently an engine must advance when a frame runs over budget
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Priority {
+    /// Advanced first every frame, e.g. for values following the pointer
+    High,
+    /// Advanced every frame, regardless of the frame budget
+    #[default]
+    Normal,
+    /// Deferred to a later frame once the frame budget is used up
+    Low,
+}
+
+/// Default time the compute phase may take per frame
+const DEFAULT_BUDGET: Duration = Duration::from_millis(8);
+
+/// An engine registered with the driver
+struct Entry {
+    id: u64,
+    /// Label of the motion value, naming it in warnings
+    label: Option<Arc<str>>,
+    tick: TickFn,
+    priority: Priority,
+    /// Time not yet applied because the engine was deferred
+    deferred: f32,
+    /// Whether the engine was animating when it was last advanced
+    active: bool,
+}
+
+impl Entry {
+    /// An idle engine, animating once started
+    fn new(id: u64, label: Option<Arc<str>>, tick: TickFn) -> Self {
+        Self {
+            id,
+            label,
+            tick,
+            priority: Priority::Normal,
+            deferred: 0.0,
+            active: false,
+        }
+    }
+
+    /// Advance by `dt` plus any time missed while deferred
+    fn advance(&mut self, dt: f32) -> bool {
+        let dt = dt + std::mem::take(&mut self.deferred);
+        self.active = (self.tick)(dt);
+        self.active
+    }
+
+    /// Skip this frame, keeping its time for the next one
+    fn defer(&mut self, dt: f32) -> bool {
+        self.deferred += dt;
+        self.active
+    }
+}
+
+/// Style writes queued during the compute phase of a frame
+#[derive(Default)]
+pub(crate) struct StyleQueue {
+    writes: Vec<(u64, String)>,
+}
+
+impl StyleQueue {
+    /// Queue a write, replacing one already queued for the same engine this frame
+    pub(crate) fn queue(&mut self, id: u64, style: String) {
+        match self.writes.iter_mut().find(|(queued, _)| *queued == id) {
+            Some(write) => write.1 = style,
+            None => self.writes.push((id, style)),
+        }
+    }
+
+    /// Drop the write queued for an engine, if any
+    pub(crate) fn cancel(&mut self, id: u64) {
+        self.writes.retain(|(queued, _)| *queued != id);
+    }
+
+    /// Apply every queued write in queue order, emptying the queue
+    pub(crate) fn flush(&mut self, mut apply: impl FnMut(u64, &str)) {
+        for (id, style) in self.writes.drain(..) {
+            apply(id, &style);
+        }
+    }
+}
+
+/// Default number of animations running at once before the driver warns
+const DEFAULT_ANIMATION_CAP: usize = 256;
+
+/// Frames between two warnings about running over the cap, about ten seconds
+const CAP_WARNING_INTERVAL: u64 = 600;
+
+/// Soft limit on the animations running at once
+struct AnimationCap {
+    /// Animations that may run at once, `None` for no limit
+    limit: Option<usize>,
+    /// How ambient animations started while over the cap are cut back
+    over: AmbientReduction,
+    /// Frame of the last warning
+    warned: Option<u64>,
+    /// Warnings logged so far
+    warnings: u64,
+}
+
+impl Default for AnimationCap {
+    fn default() -> Self {
+        Self {
+            limit: Some(DEFAULT_ANIMATION_CAP),
+            over: AmbientReduction::none(),
+            warned: None,
+            warnings: 0,
+        }
+    }
+}
+
+/// How busy the driver of the current thread is, from [`motion_stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MotionStats {
+    /// Motion values driven, animating or not
+    pub registered: usize,
+    /// Motion values animating right now
+    pub active: usize,
+    /// Soft limit on the animations running at once, see [`set_animation_cap`]
+    pub cap: Option<usize>,
+    /// Warnings logged so far for running over the cap
+    pub cap_warnings: u64,
+}
+
+/// Default number of completion callbacks a frame runs before spreading the rest
+const DEFAULT_COMPLETIONS_PER_FRAME: usize = 64;
+
+/// Default number of frames the callbacks over a frame's budget are spread over
+const DEFAULT_COMPLETION_SPREAD: u64 = 4;
+
+/// Completion callbacks over a frame's budget, waiting for later frames
+struct CompletionQueue {
+    /// Callbacks a frame may run, `None` for all of them
+    per_frame: Option<usize>,
+    /// Frames the callbacks over budget are spread over
+    spread: u64,
+    /// Callbacks run this frame
+    ran: usize,
+    /// Waiting callbacks, with the frame they must run by and their engine
+    waiting: VecDeque<(u64, u64, Callback)>,
+}
+
+impl CompletionQueue {
+    /// Split the callbacks `id` ended with into those to run now and those
+    /// to wait, queueing the latter to run by `frame` plus the spread
+    fn admit(
+        &mut self,
+        id: u64,
+        mut callbacks: Vec<Callback>,
+        frame: u64,
+        essential: bool,
+    ) -> Vec<Callback> {
+        let room = match self.per_frame {
+            Some(per_frame) if !essential => per_frame.saturating_sub(self.ran),
+            _ => callbacks.len(),
+        };
+        let late = callbacks.split_off(room.min(callbacks.len()));
+        let due = frame + self.spread;
+        self.waiting
+            .extend(late.into_iter().map(|callback| (due, id, callback)));
+        self.ran += callbacks.len();
+        callbacks
+    }
+
+    /// Take this frame's share of the waiting callbacks: what the budget
+    /// allows, and at least an even share of what must run by the oldest
+    /// deadline
+    fn take_due(&mut self, frame: u64) -> Vec<Callback> {
+        let Some(&(due, _, _)) = self.waiting.front() else {
+            return Vec::new();
+        };
+        let frames_left = due.saturating_sub(frame) + 1;
+        let share = self.waiting.len().div_ceil(frames_left as usize);
+        let room = self
+            .per_frame
+            .map_or(usize::MAX, |per_frame| per_frame.saturating_sub(self.ran));
+        let count = share.max(room).min(self.waiting.len());
+        self.ran += count;
+        self.waiting
+            .drain(..count)
+            .map(|(_, _, callback)| callback)
+            .collect()
+    }
+}
+
+impl Default for CompletionQueue {
+    fn default() -> Self {
+        Self {
+            per_frame: Some(DEFAULT_COMPLETIONS_PER_FRAME),
+            spread: DEFAULT_COMPLETION_SPREAD,
+            ran: 0,
+            waiting: VecDeque::new(),
+        }
+    }
+}
+
+struct Driver {
+    /// Next engine id to hand out
+    next_id: u64,
+    /// Registered engines, in registration order
+    entries: Vec<Entry>,
+    /// Whether the entries are currently taken out for a tick
+    ticking: bool,
+    /// Engines unregistered while ticking
+    removed: HashSet<u64>,
+    /// Whether the driver loop is running
+    running: bool,
+    /// Style writes for the current frame
+    styles: StyleQueue,
+    /// Time the compute phase may take per frame, `None` for unlimited
+    budget: Option<Duration>,
+    /// Whether ticking is suspended by [`pause_all`]
+    paused: bool,
+    /// Frames ticked so far
+    frame: u64,
+    /// Priority of the engine being advanced
+    advancing: Priority,
+    /// Completion callbacks over a frame's budget
+    completions: CompletionQueue,
+    /// Entries animating, as of their last tick or start
+    active: usize,
+    /// Soft limit on `active`
+    cap: AnimationCap,
+    /// Time step of every frame in deterministic mode, `None` when adaptive
+    fixed_dt: Option<f32>,
+    /// Whether frames are stepped by hand instead of by the driver loop
+    #[cfg(feature = "test-utils")]
+    manual: bool,
+    /// Elements receiving the bound styles
+    #[cfg(feature = "web")]
+    targets: std::collections::HashMap<u64, web_sys::Element>,
+}
+
+impl Default for Driver {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            entries: Vec::new(),
+            ticking: false,
+            removed: HashSet::new(),
+            running: false,
+            styles: StyleQueue::default(),
+            budget: Some(DEFAULT_BUDGET),
+            paused: false,
+            frame: 0,
+            advancing: Priority::Normal,
+            completions: CompletionQueue::default(),
+            active: 0,
+            cap: AnimationCap::default(),
+            fixed_dt: None,
+            #[cfg(feature = "test-utils")]
+            manual: false,
+            #[cfg(feature = "web")]
+            targets: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl Driver {
+    /// The warning to log when `active` animations, or one more when
+    /// `starting`, go over the cap, unless one was logged lately
+    fn cap_warning(&mut self, starting: bool) -> Option<String> {
+        let limit = self.cap.limit?;
+        let demand = self.active + usize::from(starting);
+        if demand <= limit
+            || self
+                .cap
+                .warned
+                .is_some_and(|warned| self.frame < warned + CAP_WARNING_INTERVAL)
+        {
+            return None;
+        }
+        self.cap.warned = Some(self.frame);
+        self.cap.warnings += 1;
+
+        // The labels with the most animations running
+        let mut sources = BTreeMap::new();
+        for entry in self.entries.iter().filter(|entry| entry.active) {
+            *sources
+                .entry(entry.label.as_deref().unwrap_or("unlabeled"))
+                .or_insert(0usize) += 1;
+        }
+        let mut sources: Vec<_> = sources.into_iter().collect();
+        sources.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        let sources: Vec<_> = sources
+            .iter()
+            .take(3)
+            .map(|(label, count)| format!("{label} ×{count}"))
+            .collect();
+        Some(format!(
+            "{demand} animations running at once, over the cap of {limit}; most come from {}",
+            sources.join(", ")
+        ))
+    }
+}
+
+thread_local! {
+    static DRIVER: RefCell<Driver> = RefCell::new(Driver::default());
+}
+
+/// Allocate an id for a new engine
+pub(crate) fn next_id() -> u64 {
+    DRIVER.with_borrow_mut(|driver| {
+        driver.next_id += 1;
+        driver.next_id
+    })
+}
+
+/// Register an engine and make sure the driver loop is running
+pub(crate) fn register(id: u64, label: Option<Arc<str>>, tick: TickFn) {
+    push_entry(Entry::new(id, label, tick));
+    start_loop();
+}
+
+/// Spawn the driver loop unless it is running already
+fn start_loop() {
+    #[cfg(feature = "test-utils")]
+    if DRIVER.with_borrow(|driver| driver.manual) {
+        return;
+    }
+
+    let start = DRIVER.with_borrow_mut(|driver| !std::mem::replace(&mut driver.running, true));
+    if start && spawn_forever(run()).is_none() {
+        DRIVER.with_borrow_mut(|driver| driver.running = false);
+    }
+}
+
+/// Add an engine without starting the loop
+fn push_entry(entry: Entry) {
+    DRIVER.with_borrow_mut(|driver| {
+        driver.active += usize::from(entry.active);
+        driver.entries.push(entry);
+    });
+}
+
+/// Count engine `id` as animating from now on, warning if that takes the
+/// driver over its cap
+pub(crate) fn activate(id: u64) {
+    let warning = DRIVER.with_borrow_mut(|driver| {
+        let entry = driver.entries.iter_mut().find(|entry| entry.id == id)?;
+        if std::mem::replace(&mut entry.active, true) {
+            return None;
+        }
+        driver.active += 1;
+        driver.cap_warning(false)
+    });
+    if let Some(warning) = warning {
+        tracing::warn!("{warning}");
+    }
+}
+
+/// How ambient animations starting now are cut back for the cap
+///
+/// At the cap, a new one would go over it, so it gets the cap's reduction and
+/// counts towards the warning even when it is not started.
+pub(crate) fn cap_reduction() -> AmbientReduction {
+    let (reduction, warning) = DRIVER.with_borrow_mut(|driver| match driver.cap.limit {
+        Some(limit) if driver.active >= limit => (driver.cap.over, driver.cap_warning(true)),
+        _ => (AmbientReduction::none(), None),
+    });
+    if let Some(warning) = warning {
+        tracing::warn!("{warning}");
+    }
+    reduction
+}
+
+/// Change how urgently an engine must advance
+pub(crate) fn set_priority(id: u64, priority: Priority) {
+    DRIVER.with_borrow_mut(|driver| {
+        if let Some(entry) = driver.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.priority = priority;
+        }
+    });
+}
+
+/// Set the time the driver may spend advancing animations per frame
+///
+/// Once a frame has used up the budget, engines with [`Priority::Low`] are
+/// deferred to the next frame. `None` advances every engine every frame.
+/// Applies to the driver of the current thread.
+pub fn set_frame_budget(budget: Option<Duration>) {
+    DRIVER.with_borrow_mut(|driver| driver.budget = budget);
+}
+
+/// Limit how many completion callbacks run per frame
+///
+/// When runs ending in one frame bring more than `per_frame` callbacks, the
+/// rest wait and are spread evenly over the next `spread` frames, though the
+/// values themselves land on their targets right away. Callbacks of engines
+/// with [`Priority::High`] always run in the frame their run ends. `None`
+/// runs every callback in its frame. Defaults to 64 callbacks per frame,
+/// spread over up to 4 frames. Applies to the driver of the current thread.
+pub fn set_completion_budget(per_frame: Option<usize>, spread: u32) {
+    DRIVER.with_borrow_mut(|driver| {
+        driver.completions.per_frame = per_frame;
+        driver.completions.spread = u64::from(spread.max(1));
+    });
+}
+
+/// Set a soft limit on the animations running at once
+///
+/// Past `cap` the driver logs a warning naming the labels of the motion
+/// values most of the running animations come from, at most once every 600
+/// frames. Ambient animations started while at or over the cap are cut back
+/// by `over`, e.g. [`AmbientReduction::skip`] to refuse them or
+/// [`AmbientReduction::jump`] to land them on their target at once;
+/// [`AmbientReduction::none`] only warns. `None` lifts the cap. Defaults to
+/// 256 animations, warning only.
+///
+/// Applies to the driver of the current thread, so the cap is shared by every
+/// animation on it rather than set per component.
+pub fn set_animation_cap(cap: Option<usize>, over: AmbientReduction) {
+    DRIVER.with_borrow_mut(|driver| {
+        driver.cap.limit = cap;
+        driver.cap.over = over;
+    });
+}
+
+/// How many motion values the driver of the current thread drives, and how
+/// many of them are animating
+pub fn motion_stats() -> MotionStats {
+    DRIVER.with_borrow(|driver| MotionStats {
+        registered: driver.entries.len(),
+        active: driver.active,
+        cap: driver.cap.limit,
+        cap_warnings: driver.cap.warnings,
+    })
+}
+
+/// Run the callbacks of the runs engine `id` ended this frame, or as many as
+/// the frame's budget allows, leaving the rest for the next frames
+pub(crate) fn complete(id: u64, callbacks: Vec<Callback>) {
+    if callbacks.is_empty() {
+        return;
+    }
+    let now = DRIVER.with_borrow_mut(|driver| {
+        let essential = driver.advancing == Priority::High;
+        driver
+            .completions
+            .admit(id, callbacks, driver.frame, essential)
+    });
+    run_callbacks(now);
+}
+
+/// Freeze every animation driven on the current thread
+///
+/// Engines stop advancing but keep their state; animations started while
+/// paused wait too. The driver loop stops waking up for frames until
+/// [`resume_all`] undoes this.
+pub fn pause_all() {
+    DRIVER.with_borrow_mut(|driver| driver.paused = true);
+}
+
+/// Continue every animation frozen by [`pause_all`] from where it stopped
+pub fn resume_all() {
+    let engines = DRIVER.with_borrow_mut(|driver| {
+        driver.paused = false;
+        !driver.entries.is_empty()
+    });
+    if engines {
+        start_loop();
+    }
+}
+
+/// Whether animations on the current thread are frozen by [`pause_all`]
+pub fn is_paused() -> bool {
+    DRIVER.with_borrow(|driver| driver.paused)
+}
+
+/// Advance every frame by exactly `fixed_dt` seconds, or adaptively with `None`
+///
+/// Steps that aren't positive and finite switch back to adaptive timing.
+pub(crate) fn set_fixed_dt(fixed_dt: Option<f32>) {
+    let fixed_dt = fixed_dt.filter(|dt| dt.is_finite() && *dt > 0.0);
+    DRIVER.with_borrow_mut(|driver| driver.fixed_dt = fixed_dt);
+}
+
+/// Step frames by hand with [`step`] instead of running the driver loop
+///
+/// Engines registered afterwards don't start the loop.
+#[cfg(feature = "test-utils")]
+pub(crate) fn set_manual(manual: bool) {
+    DRIVER.with_borrow_mut(|driver| driver.manual = manual);
+}
+
+/// Run one frame advancing every engine by `dt` seconds, as the driver loop
+/// does; returns whether any engine is still animating
+#[cfg(any(test, feature = "test-utils"))]
+pub(crate) fn step(dt: f32) -> bool {
+    let is_active = tick(dt, || false);
+    flush_styles();
+    is_active
+}
+
+/// Whether frames will advance the engines of this thread
+///
+/// The driver loop waits for its next frame on the timers of the platform it
+/// runs on, whichever features are enabled: in a browser those of the
+/// `window`, natively those of the Tokio runtime the app runs in. Without
+/// them the loop never gets to run.
+pub(crate) fn has_frames() -> bool {
+    #[cfg(feature = "test-utils")]
+    if DRIVER.with_borrow(|driver| driver.manual) {
+        return true;
+    }
+    #[cfg(all(feature = "web", target_arch = "wasm32"))]
+    return web_sys::window().is_some();
+    // Without web-sys to ask, assume the JS host has timers
+    #[cfg(all(not(feature = "web"), target_arch = "wasm32"))]
+    return true;
+    #[cfg(not(target_arch = "wasm32"))]
+    ::tokio::runtime::Handle::try_current().is_ok()
+}
+
+/// Time step for a frame `measured` seconds after the last one, and the
+/// frame's compute budget
+fn frame_timing(measured: f32) -> (f32, Option<Duration>) {
+    // In deterministic mode: the same step every frame and no deferred engines
+    DRIVER.with_borrow(|driver| {
+        driver
+            .fixed_dt
+            .map_or((measured, driver.budget), |fixed_dt| (fixed_dt, None))
+    })
+}
+
+/// How long to wait for the next frame after one that advanced by `dt`
+fn next_delay(dt: f32, is_active: bool) -> Duration {
+    if let Some(fixed_dt) = DRIVER.with_borrow(|driver| driver.fixed_dt) {
+        return Duration::from_secs_f32(fixed_dt);
+    }
+    // Adaptive frame rate based on activity
+    if !is_active {
+        Duration::from_millis(100)
+    } else if dt > 0.064 {
+        Duration::from_millis(8)
+    } else {
+        Duration::from_millis(16)
+    }
+}
+
+/// Remove an engine, dropping its pending style write and callbacks
+pub(crate) fn unregister(id: u64) {
+    DRIVER.with_borrow_mut(|driver| {
+        if driver.ticking {
+            driver.removed.insert(id);
+        }
+        let active = driver
+            .entries
+            .iter()
+            .any(|entry| entry.id == id && entry.active);
+        driver.active -= usize::from(active);
+        driver.entries.retain(|entry| entry.id != id);
+        driver.styles.cancel(id);
+        // Like the callbacks still in its engine, those waiting never run
+        driver
+            .completions
+            .waiting
+            .retain(|(_, waiting, _)| *waiting != id);
+        #[cfg(feature = "web")]
+        driver.targets.remove(&id);
+    });
+    #[cfg(feature = "test-utils")]
+    crate::testing::untrack(id);
+}
+
+/// Queue an engine's rendered style for this frame's write phase
+pub(crate) fn queue_style(id: u64, style: String) {
+    DRIVER.with_borrow_mut(|driver| driver.styles.queue(id, style));
+}
+
+/// Drop an engine's pending style write
+pub(crate) fn cancel_style(id: u64) {
+    DRIVER.with_borrow_mut(|driver| driver.styles.cancel(id));
+}
+
+/// Set the element an engine's bound style is written to
+#[cfg(feature = "web")]
+pub(crate) fn set_style_target(id: u64, element: web_sys::Element) {
+    DRIVER.with_borrow_mut(|driver| {
+        driver.targets.insert(id, element);
+    });
+}
+
+/// Compute phase: advance every registered engine
+///
+/// High and normal priority engines always advance, in that order; low priority ones only until
+/// `over_budget` reports the frame's budget as used up. Returns whether any
+/// engine is still animating.
+fn tick(dt: f32, mut over_budget: impl FnMut() -> bool) -> bool {
+    // While paused, frozen animations still count as animating
+    if is_paused() {
+        return DRIVER.with_borrow(|driver| driver.entries.iter().any(|entry| entry.active));
+    }
+
+    // Callbacks left waiting by earlier frames go first, as the budget allows
+    let due = DRIVER.with_borrow_mut(|driver| {
+        driver.frame += 1;
+        driver.completions.ran = 0;
+        driver.completions.take_due(driver.frame)
+    });
+    run_callbacks(due);
+
+    // Take the entries out so ticks may register or unregister engines
+    let mut entries = DRIVER.with_borrow_mut(|driver| {
+        driver.ticking = true;
+        std::mem::take(&mut driver.entries)
+    });
+    let is_removed = |id: u64| DRIVER.with_borrow(|driver| driver.removed.contains(&id));
+    let advancing =
+        |priority: Priority| DRIVER.with_borrow_mut(|driver| driver.advancing = priority);
+
+    let mut is_active = false;
+    for priority in [Priority::High, Priority::Normal] {
+        advancing(priority);
+        for entry in entries.iter_mut().filter(|e| e.priority == priority) {
+            // Skip engines cancelled earlier in this frame
+            if !is_removed(entry.id) {
+                is_active |= entry.advance(dt);
+            }
+        }
+    }
+    advancing(Priority::Low);
+
+    // The longest deferred engines go first, so none is starved
+    let mut low: Vec<_> = entries
+        .iter_mut()
+        .filter(|entry| entry.priority == Priority::Low)
+        .collect();
+    low.sort_by(|a, b| b.deferred.total_cmp(&a.deferred));
+
+    let mut exhausted = false;
+    for entry in low {
+        if is_removed(entry.id) {
+            continue;
+        }
+        exhausted = exhausted || over_budget();
+        is_active |= if exhausted {
+            entry.defer(dt)
+        } else {
+            entry.advance(dt)
+        };
+    }
+
+    let warning = DRIVER.with_borrow_mut(|driver| {
+        driver.ticking = false;
+        let removed = std::mem::take(&mut driver.removed);
+        entries.retain(|entry| !removed.contains(&entry.id));
+        // Keep engines registered during the tick after the existing ones
+        entries.append(&mut driver.entries);
+        driver.entries = entries;
+        driver.active = driver.entries.iter().filter(|entry| entry.active).count();
+        driver.cap_warning(false)
+    });
+    if let Some(warning) = warning {
+        tracing::warn!("{warning}");
+    }
+
+    is_active || DRIVER.with_borrow(|driver| !driver.completions.waiting.is_empty())
+}
+
+/// Write phase: apply every queued style write back-to-back
+fn flush_styles() {
+    #[cfg(feature = "web")]
+    DRIVER.with_borrow_mut(|driver| {
+        let Driver {
+            styles, targets, ..
+        } = driver;
+        styles.flush(|id, style| {
+            if let Some(element) = targets.get(&id) {
+                let _ = element.set_attribute("style", style);
+            }
+        });
+    });
+
+    // Without a DOM there is nothing to write to
+    #[cfg(not(feature = "web"))]
+    DRIVER.with_borrow_mut(|driver| driver.styles.flush(|_, _| {}));
+}
+
+/// The driver loop, running while any engine is registered
+async fn run() {
+    let mut last_frame = MotionTime::now();
+
+    loop {
+        // Parked while paused, until `resume_all` starts a fresh loop whose
+        // first frame doesn't count the time spent paused
+        let parked = DRIVER.with_borrow_mut(|driver| {
+            if driver.paused {
+                driver.running = false;
+            }
+            driver.paused
+        });
+        if parked {
+            return;
+        }
+
+        let now = MotionTime::now();
+        let (dt, budget) = frame_timing(now.duration_since(last_frame).as_secs_f32());
+
+        let is_active = tick(dt, || {
+            budget.is_some_and(|budget| MotionTime::now().duration_since(now) >= budget)
+        });
+        flush_styles();
+
+        let idle = DRIVER.with_borrow_mut(|driver| {
+            let idle = driver.entries.is_empty() && driver.completions.waiting.is_empty();
+            if idle {
+                driver.running = false;
+            }
+            idle
+        });
+        if idle {
+            return;
+        }
+
+        last_frame = now;
+        MotionTime::delay(next_delay(dt, is_active)).await;
+    }
+}
+
+impl<T: Animatable> MotionValue<T> {
+    /// Write this value straight to an element's `style` attribute every frame
+    ///
+    /// The style is rendered during the driver's compute phase and applied
+    /// together with every other bound element, without re-rendering the
+    /// component. Call from the element's `onmounted` handler, and don't also
+    /// set the element's `style` from `rsx!`.
+    #[cfg(feature = "web")]
+    pub fn bind_style(
+        &mut self,
+        mounted: &MountedData,
+        render: impl Fn(&T) -> String + MaybeSend + 'static,
+    ) {
+        self.bind_style_with(mounted, Box::new(move |value, _| render(value)));
+    }
+
+    /// Bind a renderer that is also told whether the value is animating
+    #[cfg(feature = "web")]
+    pub(crate) fn bind_style_with(&mut self, mounted: &MountedData, render: StyleFn<T>) {
+        let Some(element) = mounted.downcast::<web_sys::Element>().cloned() else {
+            return;
+        };
+        let Some(id) = self.engine.peek().driver_id() else {
+            return;
+        };
+
+        set_style_target(id, element);
+        self.engine.write().set_style(Some(render));
+    }
+
+    /// Write this value straight to an element's style every frame
+    ///
+    /// Direct style binding is only available on the web; elsewhere read the
+    /// value from `rsx!` as usual.
+    #[cfg(not(feature = "web"))]
+    pub fn bind_style(
+        &mut self,
+        _mounted: &MountedData,
+        _render: impl Fn(&T) -> String + MaybeSend + 'static,
+    ) {
+    }
+
+    #[cfg(not(feature = "web"))]
+    pub(crate) fn bind_style_with(&mut self, _mounted: &MountedData, _render: StyleFn<T>) {}
+
+    /// Set how urgently this value must advance when a frame runs over budget
+    ///
+    /// Mark the value the user is interacting with, e.g. a dragged element,
+    /// [`Priority::High`] so it updates first, and ambient or decorative motion
+    /// [`Priority::Low`] so it yields under load. See [`set_frame_budget`].
+    pub fn set_priority(&mut self, priority: Priority) {
+        if let Some(id) = self.engine.peek().driver_id() {
+            set_priority(id, priority);
+        }
+    }
+
+    /// Stop writing this value to its bound element, dropping any pending write
+    pub fn unbind_style(&mut self) {
+        let id = self.engine.peek().driver_id();
+        self.engine.write().set_style(None);
+        if let Some(id) = id {
+            cancel_style(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Add an engine counted as animating, without starting the loop
+    fn add_entry(id: u64, tick: TickFn) {
+        push_entry(Entry {
+            active: true,
+            ..Entry::new(id, None, tick)
+        });
+    }
+
+    fn flushed() -> Vec<(u64, String)> {
+        let mut applied = Vec::new();
+        DRIVER.with_borrow_mut(|driver| {
+            driver
+                .styles
+                .flush(|id, style| applied.push((id, style.to_string())))
+        });
+        applied
+    }
+
+    #[test]
+    fn test_queue_flushes_in_order_once_per_engine() {
+        let mut queue = StyleQueue::default();
+        queue.queue(1, "a".into());
+        queue.queue(2, "b".into());
+        queue.queue(1, "c".into());
+
+        let mut applied = Vec::new();
+        queue.flush(|id, style| applied.push((id, style.to_string())));
+        assert_eq!(applied, vec![(1, "c".into()), (2, "b".into())]);
+
+        // The queue is empty after a flush
+        queue.flush(|_, _| unreachable!("flushed twice"));
+    }
+
+    #[test]
+    fn test_cancelled_write_is_dropped() {
+        let mut queue = StyleQueue::default();
+        queue.queue(1, "a".into());
+        queue.queue(2, "b".into());
+        queue.cancel(1);
+
+        let mut applied = Vec::new();
+        queue.flush(|id, _| applied.push(id));
+        assert_eq!(applied, vec![2]);
+    }
+
+    #[test]
+    fn test_writes_happen_after_every_engine_ticked() {
+        let ticked = Rc::new(RefCell::new(Vec::new()));
+        let (first, second) = (next_id(), next_id());
+
+        for id in [first, second] {
+            let ticked = ticked.clone();
+            add_entry(
+                id,
+                Box::new(move |_| {
+                    ticked.borrow_mut().push(id);
+                    queue_style(id, format!("engine {id}"));
+                    true
+                }),
+            );
+        }
+
+        assert!(tick(0.016, || false));
+        // Nothing is written during the compute phase
+        assert_eq!(*ticked.borrow(), vec![first, second]);
+        assert_eq!(
+            flushed(),
+            vec![
+                (first, format!("engine {first}")),
+                (second, format!("engine {second}"))
+            ]
+        );
+
+        unregister(first);
+        unregister(second);
+    }
+
+    #[test]
+    fn test_engine_cancelled_mid_frame_is_not_written() {
+        let (first, second, third) = (next_id(), next_id(), next_id());
+
+        add_entry(
+            first,
+            Box::new(move |_| {
+                queue_style(first, "first".into());
+                true
+            }),
+        );
+        // A completion callback in the second engine cancels the first and third
+        add_entry(
+            second,
+            Box::new(move |_| {
+                queue_style(second, "second".into());
+                unregister(first);
+                unregister(third);
+                false
+            }),
+        );
+        add_entry(third, Box::new(|_| unreachable!("cancelled engine ticked")));
+
+        tick(0.016, || false);
+        assert_eq!(flushed(), vec![(second, "second".to_string())]);
+
+        // Cancelled engines are gone for good
+        tick(0.016, || false);
+        assert_eq!(flushed(), vec![(second, "second".to_string())]);
+        unregister(second);
+        assert!(!tick(0.016, || false));
+    }
+
+    #[test]
+    fn test_low_priority_deferred_over_budget() {
+        let (urgent, idle) = (next_id(), next_id());
+        let advanced = Rc::new(RefCell::new(Vec::new()));
+
+        for id in [idle, urgent] {
+            let advanced = advanced.clone();
+            add_entry(
+                id,
+                Box::new(move |dt| {
+                    advanced.borrow_mut().push((id, dt));
+                    true
+                }),
+            );
+        }
+        set_priority(idle, Priority::Low);
+
+        // Over budget: the normal engine still advances, the low one waits
+        assert!(tick(0.016, || true));
+        assert_eq!(*advanced.borrow(), vec![(urgent, 0.016)]);
+
+        // With budget to spare it catches up on the time it missed
+        advanced.borrow_mut().clear();
+        tick(0.016, || false);
+        assert_eq!(*advanced.borrow(), vec![(urgent, 0.016), (idle, 0.032)]);
+
+        unregister(urgent);
+        unregister(idle);
+    }
+
+    /// Synthetic load: many low priority engines, each costing one unit of work
+    #[test]
+    fn test_many_animations_spread_over_frames() {
+        const ENGINES: usize = 1000;
+        const BUDGET: usize = 150;
+        const FRAMES: usize = 20;
+        let dt = 1.0 / 60.0;
+
+        let work = Rc::new(RefCell::new(0usize));
+        let elapsed = Rc::new(RefCell::new(vec![0.0f32; ENGINES]));
+        let ids: Vec<u64> = (0..ENGINES).map(|_| next_id()).collect();
+        for (index, id) in ids.iter().enumerate() {
+            let (work, elapsed) = (work.clone(), elapsed.clone());
+            add_entry(
+                *id,
+                Box::new(move |dt| {
+                    *work.borrow_mut() += 1;
+                    elapsed.borrow_mut()[index] += dt;
+                    true
+                }),
+            );
+            set_priority(*id, Priority::Low);
+        }
+
+        for _ in 0..FRAMES {
+            *work.borrow_mut() = 0;
+            tick(dt, || *work.borrow() >= BUDGET);
+            // Each frame does bounded work instead of advancing everything
+            assert_eq!(*work.borrow(), BUDGET);
+        }
+
+        // Round-robin: every engine advanced, and none lost any time
+        let deferred: Vec<f32> = DRIVER
+            .with_borrow(|driver| driver.entries.iter().map(|entry| entry.deferred).collect());
+        assert!(elapsed.borrow().iter().all(|time| *time > 0.0));
+        assert_eq!(
+            deferred.iter().filter(|time| **time > 0.0).count(),
+            ENGINES - BUDGET
+        );
+        let total: f32 = elapsed.borrow().iter().sum::<f32>() + deferred.iter().sum::<f32>();
+        assert!((total - dt * (ENGINES * FRAMES) as f32).abs() < 0.01);
+
+        for id in ids {
+            unregister(id);
+        }
+    }
+
+    #[test]
+    fn test_dragged_value_updates_every_frame_under_load() {
+        let (background, dragged) = (next_id(), next_id());
+        let order = Rc::new(RefCell::new(Vec::new()));
+
+        // Registered before the dragged value, but only decoration
+        for id in [background, dragged] {
+            let order = order.clone();
+            add_entry(
+                id,
+                Box::new(move |_| {
+                    order.borrow_mut().push(id);
+                    true
+                }),
+            );
+        }
+        let decorations: Vec<u64> = (0..3).map(|_| next_id()).collect();
+        for id in &decorations {
+            let (order, id) = (order.clone(), *id);
+            add_entry(
+                id,
+                Box::new(move |_| {
+                    order.borrow_mut().push(id);
+                    true
+                }),
+            );
+            set_priority(id, Priority::Low);
+        }
+        set_priority(dragged, Priority::High);
+
+        // Budget allows only one decoration per frame
+        for decoration in &decorations {
+            order.borrow_mut().clear();
+            let mut budget = 1;
+            tick(0.016, || {
+                budget -= 1;
+                budget < 0
+            });
+            let order = order.borrow();
+            assert_eq!(order[..2], [dragged, background]);
+            // The decorations take turns
+            assert_eq!(order[2..], [*decoration]);
+        }
+
+        for id in [background, dragged].into_iter().chain(decorations) {
+            unregister(id);
+        }
+    }
+
+    #[test]
+    fn test_pause_all_freezes_and_resume_continues() {
+        let ids = [next_id(), next_id()];
+        let elapsed = Rc::new(RefCell::new(vec![0.0f32; ids.len()]));
+        for (index, id) in ids.iter().enumerate() {
+            let elapsed = elapsed.clone();
+            add_entry(
+                *id,
+                Box::new(move |dt| {
+                    elapsed.borrow_mut()[index] += dt;
+                    true
+                }),
+            );
+        }
+        set_priority(ids[1], Priority::Low);
+
+        tick(0.016, || false);
+        pause_all();
+        assert!(is_paused());
+        for _ in 0..10 {
+            // Still animating, just frozen
+            assert!(tick(0.016, || false));
+        }
+        assert_eq!(*elapsed.borrow(), vec![0.016, 0.016]);
+
+        resume_all();
+        assert!(!is_paused());
+        tick(0.016, || false);
+        assert_eq!(*elapsed.borrow(), vec![0.032, 0.032]);
+
+        for id in ids {
+            unregister(id);
+        }
+    }
+
+    #[test]
+    fn test_paused_loop_parks_until_resumed() {
+        let runtime = crate::test_support::frame_runtime();
+        let id = next_id();
+        let ticks = Rc::new(Cell::new(0));
+        let ticked = ticks.clone();
+        add_entry(
+            id,
+            Box::new(move |_| {
+                ticked.set(ticked.get() + 1);
+                true
+            }),
+        );
+        let running = || DRIVER.with_borrow(|driver| driver.running);
+
+        // The loop returns instead of waiting for another frame
+        pause_all();
+        DRIVER.with_borrow_mut(|driver| driver.running = true);
+        runtime.block_on(run());
+        assert!(!running());
+        assert_eq!(ticks.get(), 0);
+
+        // Resuming spawns it again
+        let dom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(resume_all);
+        assert!(running());
+
+        unregister(id);
+    }
+
+    #[test]
+    fn test_animations_over_the_cap_are_cut_back_and_reported_once() {
+        use crate::animation::LoopMode;
+        use crate::{MotionValue, use_motion_labeled};
+
+        const CAP: usize = 8;
+        thread_local! {
+            static VALUES: RefCell<Vec<MotionValue<f32>>> = const { RefCell::new(Vec::new()) };
+        }
+
+        /// A looping ambient spring or tween, most of them cards
+        #[component]
+        fn Decoration(index: usize) -> Element {
+            let label = if index < 12 { "card" } else { "blob" };
+            let value = use_motion_labeled(label, 0.0f32);
+            use_hook(move || {
+                if index % 2 == 0 {
+                    value
+                        .spring()
+                        .loop_mode(LoopMode::Infinite)
+                        .ambient()
+                        .animate_to(10.0);
+                } else {
+                    value
+                        .tween()
+                        .loop_mode(LoopMode::Infinite)
+                        .ambient()
+                        .animate_to(10.0);
+                }
+                VALUES.with_borrow_mut(|values| values.push(value));
+            });
+            rsx! {}
+        }
+
+        fn app() -> Element {
+            rsx! {
+                for index in 0..CAP + 10 {
+                    Decoration { key: "{index}", index }
+                }
+            }
+        }
+
+        set_animation_cap(Some(CAP), AmbientReduction::skip());
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+
+        // Ten more ambient animations than the cap start in one go
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let values = VALUES.take();
+        let animating = |dom: &VirtualDom| {
+            dom.in_runtime(|| values.iter().filter(|v| v.is_animating()).count())
+        };
+        assert_eq!(animating(&dom), CAP);
+        assert_eq!(
+            motion_stats(),
+            MotionStats {
+                registered: CAP + 10,
+                active: CAP,
+                cap: Some(CAP),
+                cap_warnings: 1,
+            }
+        );
+
+        // Held at the cap, the running ones don't warn again
+        for _ in 0..10 {
+            dom.in_runtime(|| step(0.016));
+        }
+        assert_eq!(animating(&dom), CAP);
+        assert_eq!(motion_stats().active, CAP);
+        assert_eq!(motion_stats().cap_warnings, 1);
+
+        // The warning names where the animations come from
+        let warning = DRIVER.with_borrow_mut(|driver| {
+            driver.cap.warned = None;
+            driver.cap_warning(true)
+        });
+        assert_eq!(
+            warning.as_deref(),
+            Some("9 animations running at once, over the cap of 8; most come from card ×8")
+        );
+
+        // Ending animations make room again
+        dom.in_runtime(|| {
+            let Some(mut first) = values.iter().copied().find(MotionValue::is_animating) else {
+                unreachable!("the cap lets some start");
+            };
+            first.stop();
+            step(0.016);
+        });
+        assert_eq!(motion_stats().active, CAP - 1);
+        assert_eq!(cap_reduction(), AmbientReduction::none());
+
+        drop(dom);
+        assert_eq!(motion_stats().registered, 0);
+        DRIVER.with_borrow_mut(|driver| driver.cap = AnimationCap::default());
+    }
+
+    /// Register `count` engines that all end on their first frame, each
+    /// marking itself final and completing with one callback that does
+    /// `work` units of work and bumps `ran`
+    fn ending_together(
+        count: usize,
+        work: usize,
+        ran: &Arc<AtomicUsize>,
+    ) -> (Vec<u64>, Rc<RefCell<Vec<bool>>>) {
+        let finals = Rc::new(RefCell::new(vec![false; count]));
+        let ids: Vec<u64> = (0..count).map(|_| next_id()).collect();
+        for (index, id) in ids.iter().enumerate() {
+            let (id, ran, finals) = (*id, ran.clone(), finals.clone());
+            add_entry(
+                id,
+                Box::new(move |_| {
+                    finals.borrow_mut()[index] = true;
+                    let ran = ran.clone();
+                    complete(
+                        id,
+                        vec![crate::animation::boxed(move || {
+                            // Real work, like formatting a message
+                            for _ in 0..work {
+                                std::hint::black_box(format!("engine {id} done"));
+                            }
+                            ran.fetch_add(1, Ordering::SeqCst);
+                        })],
+                    );
+                    false
+                }),
+            );
+        }
+        (ids, finals)
+    }
+
+    /// 1000 runs ending in one frame, as when a large staggered grid settles
+    #[test]
+    fn test_simultaneous_completions_spread_their_callbacks() {
+        const ENGINES: usize = 1000;
+        let ran = Arc::new(AtomicUsize::new(0));
+        let (ids, finals) = ending_together(ENGINES, 1, &ran);
+
+        // Every value is final in the frame it ends, but only the budget of
+        // callbacks runs with it
+        assert!(tick(0.016, || false));
+        assert!(finals.borrow().iter().all(|done| *done));
+        assert_eq!(ran.load(Ordering::SeqCst), DEFAULT_COMPLETIONS_PER_FRAME);
+        for id in &ids {
+            unregister_entry_only(*id);
+        }
+
+        // The rest are spread evenly, all done within the spread
+        let mut per_frame = Vec::new();
+        for _ in 0..DEFAULT_COMPLETION_SPREAD {
+            let before = ran.load(Ordering::SeqCst);
+            tick(0.016, || false);
+            per_frame.push(ran.load(Ordering::SeqCst) - before);
+        }
+        assert_eq!(ran.load(Ordering::SeqCst), ENGINES);
+        assert!(per_frame.iter().all(|count| *count <= ENGINES / 4));
+        // Nothing left to run, so the loop may idle
+        assert!(!tick(0.016, || false));
+    }
+
+    #[test]
+    fn test_essential_completions_are_never_spread() {
+        let ran = Arc::new(AtomicUsize::new(0));
+        let (ids, _) = ending_together(200, 1, &ran);
+        for id in &ids {
+            set_priority(*id, Priority::High);
+        }
+        tick(0.016, || false);
+        assert_eq!(ran.load(Ordering::SeqCst), 200);
+
+        // Without a budget nothing is spread either
+        for id in ids {
+            unregister(id);
+        }
+        set_completion_budget(None, 4);
+        ran.store(0, Ordering::SeqCst);
+        let (ids, _) = ending_together(200, 1, &ran);
+        tick(0.016, || false);
+        assert_eq!(ran.load(Ordering::SeqCst), 200);
+
+        set_completion_budget(Some(DEFAULT_COMPLETIONS_PER_FRAME), 4);
+        for id in ids {
+            unregister(id);
+        }
+    }
+
+    /// Remove an entry that ended, keeping its waiting callbacks as the
+    /// driver loop does for engines that are still mounted
+    fn unregister_entry_only(id: u64) {
+        DRIVER.with_borrow_mut(|driver| driver.entries.retain(|entry| entry.id != id));
+    }
+
+    /// Frames of the 200 dot example in the module docs, run headless: the
+    /// re-renders, DOM edits and CPU time per frame, with each dot's style set
+    /// from `rsx!` or bound
+    fn dot_frames(bound: bool) -> (usize, usize, Duration) {
+        const DOTS: usize = 200;
+        const FRAMES: u32 = 40;
+        thread_local! {
+            static BOUND: Cell<bool> = const { Cell::new(false) };
+            static RENDERS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        #[component]
+        fn Dot(index: usize) -> Element {
+            RENDERS.set(RENDERS.get() + 1);
+            let mut y = crate::use_motion(0.0f32);
+            use_hook(move || {
+                if BOUND.get() {
+                    y.engine.write().set_style(Some(Box::new(|y, _| {
+                        format!("transform: translateY({y}px)")
+                    })));
+                }
+                y.tween()
+                    .duration(Duration::from_millis(800 + index as u64 * 5))
+                    .animate_to(200.0);
+            });
+            if BOUND.get() {
+                rsx! { div { class: "dot" } }
+            } else {
+                rsx! { div { class: "dot", style: "transform: translateY({y.get()}px)" } }
+            }
+        }
+
+        fn dots() -> Element {
+            rsx! {
+                for index in 0..DOTS {
+                    Dot { key: "{index}", index }
+                }
+            }
+        }
+
+        BOUND.set(bound);
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(dots);
+        dom.rebuild_in_place();
+        let renders = RENDERS.get();
+        let mut edits = 0;
+        let start = std::time::Instant::now();
+        for _ in 0..FRAMES {
+            dom.in_runtime(|| {
+                tick(1.0 / 60.0, || false);
+                flush_styles();
+            });
+            let mut mutations = dioxus::dioxus_core::Mutations::default();
+            dom.render_immediate(&mut mutations);
+            edits += mutations.edits.len();
+        }
+        let elapsed = start.elapsed() / FRAMES;
+        let per_frame = |count: usize| count / FRAMES as usize;
+        (
+            per_frame(RENDERS.get() - renders),
+            per_frame(edits),
+            elapsed,
+        )
+    }
+
+    /// Run with `cargo test --release --lib -- --ignored bench_bound_dots`,
+    /// see the module docs
+    #[test]
+    #[ignore]
+    fn bench_bound_dots() {
+        let (renders, edits, unbound) = dot_frames(false);
+        assert_eq!((renders, edits), (200, 200));
+        let (renders, edits, bound) = dot_frames(true);
+        assert_eq!((renders, edits), (0, 0));
+        assert!(bound < unbound, "bound {bound:?}, unbound {unbound:?}");
+    }
+
+    /// Run with `cargo test --release -- --ignored --nocapture bench_completion`
+    #[test]
+    #[ignore]
+    fn bench_completion_spread() {
+        const ENGINES: usize = 5000;
+        fn worst_frame(budget: Option<usize>) -> Duration {
+            set_completion_budget(budget, 4);
+            let ran = Arc::new(AtomicUsize::new(0));
+            let (ids, _) = ending_together(ENGINES, 20, &ran);
+            let mut worst = Duration::ZERO;
+            for _ in 0..6 {
+                let start = std::time::Instant::now();
+                tick(0.016, || false);
+                worst = worst.max(start.elapsed());
+                for id in &ids {
+                    unregister_entry_only(*id);
+                }
+            }
+            assert_eq!(ran.load(Ordering::SeqCst), ENGINES);
+            worst
+        }
+
+        let all_at_once = worst_frame(None);
+        let spread = worst_frame(Some(DEFAULT_COMPLETIONS_PER_FRAME));
+        set_completion_budget(Some(DEFAULT_COMPLETIONS_PER_FRAME), 4);
+        println!(
+            "{ENGINES} runs ending in one frame, worst frame: all callbacks at once \
+             {all_at_once:?}, spread {spread:?}"
+        );
+    }
+
+    #[test]
+    fn test_fixed_dt_ignores_the_clock_until_disabled() {
+        let id = next_id();
+        let advanced = Rc::new(RefCell::new(Vec::new()));
+        let record = advanced.clone();
+        add_entry(
+            id,
+            Box::new(move |dt| {
+                record.borrow_mut().push(dt);
+                false
+            }),
+        );
+
+        set_fixed_dt(Some(0.01));
+        // Slow and fast frames alike advance by the fixed step, without idling
+        for measured in [0.5, 0.001, 0.016] {
+            let (dt, budget) = frame_timing(measured);
+            assert_eq!(budget, None);
+            let is_active = tick(dt, || false);
+            assert_eq!(next_delay(dt, is_active), Duration::from_secs_f32(0.01));
+        }
+        assert_eq!(*advanced.borrow(), vec![0.01, 0.01, 0.01]);
+
+        set_fixed_dt(None);
+        let (dt, budget) = frame_timing(0.5);
+        assert_eq!((dt, budget), (0.5, Some(DEFAULT_BUDGET)));
+        assert_eq!(next_delay(dt, false), Duration::from_millis(100));
+        assert_eq!(next_delay(0.016, true), Duration::from_millis(16));
+
+        // Steps that can't advance time keep the adaptive loop
+        set_fixed_dt(Some(0.0));
+        assert_eq!(frame_timing(0.02).0, 0.02);
+
+        unregister(id);
+    }
+}
@@ -0,0 +1,346 @@
+//! Lifecycle events of animation runs
+//!
+//! [`subscribe_events`] opens a stream of [`MotionEvent`]s for every animation
+//! started, completed or interrupted on any motion value, e.g. to measure how
+//! often users cut an animation short or to assert the order of animations in a
+//! test. Values created with [`use_motion_labeled`](crate::use_motion_labeled)
+//! report their label; others are skipped unless the subscription asks for them
+//! with [`MotionEvents::with_unlabeled`].
+//!
+//! Each subscription buffers up to a fixed number of events. When it is full
+//! the oldest event is dropped, so a slow reader never holds up the animation
+//! loop. Without subscriptions, emitting an event costs one atomic load.
+//!
+//! ```ignore
+//! let mut events = subscribe_events(64);
+//! spawn(async move {
+//!     loop {
+//!         if let MotionEvent::Interrupted { label: Some(label), .. } = events.recv().await {
+//!             analytics::track("animation_interrupted", &label);
+//!         }
+//!     }
+//! });
+//! ```
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::task::{Context, Poll, Waker};
+
+use instant::Duration;
+
+/// Live subscriptions, checked before taking the lock
+static SUBSCRIBERS: AtomicUsize = AtomicUsize::new(0);
+
+/// Every subscription's queue; dead ones are pruned on the next emit
+static QUEUES: Mutex<Vec<Weak<Mutex<Queue>>>> = Mutex::new(Vec::new());
+
+/// What kind of animation a run plays
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AnimationKind {
+    /// A spring, from `spring_to` or the spring builder
+    Spring,
+    /// A tween, from `tween_to` or the tween builder
+    Tween,
+    /// A keyframe animation
+    Keyframes,
+    /// An animation sequence
+    Sequence,
+    /// An animation passed to `animate_custom`
+    Custom,
+}
+
+/// A step in the life of an animation run
+#[derive(Debug, Clone, PartialEq)]
+pub enum MotionEvent {
+    /// An animation started
+    Started {
+        /// Label of the motion value, `None` if it has none
+        label: Option<Arc<str>>,
+        kind: AnimationKind,
+    },
+    /// An animation settled on its own
+    Completed {
+        label: Option<Arc<str>>,
+        /// Animation time the run took, excluding time spent paused
+        duration: Duration,
+    },
+    /// An animation was stopped, replaced or unmounted before settling
+    Interrupted {
+        label: Option<Arc<str>>,
+        /// Fraction of the run played, for animations with a known duration
+        progress: Option<f32>,
+    },
+}
+
+impl MotionEvent {
+    /// Label of the motion value the event is about
+    pub fn label(&self) -> Option<&str> {
+        match self {
+            Self::Started { label, .. }
+            | Self::Completed { label, .. }
+            | Self::Interrupted { label, .. } => label.as_deref(),
+        }
+    }
+}
+
+struct Queue {
+    events: VecDeque<MotionEvent>,
+    capacity: usize,
+    dropped: u64,
+    unlabeled: bool,
+    waker: Option<Waker>,
+}
+
+impl Queue {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+            dropped: 0,
+            unlabeled: false,
+            waker: None,
+        }
+    }
+
+    /// Append `event`, dropping the oldest one when full
+    fn push(&mut self, event: MotionEvent) {
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+            self.dropped += 1;
+        }
+        self.events.push_back(event);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Subscribe to the events of every motion value, buffering up to `capacity`
+pub fn subscribe_events(capacity: usize) -> MotionEvents {
+    let queue = Arc::new(Mutex::new(Queue::new(capacity)));
+    if let Ok(mut queues) = QUEUES.lock() {
+        queues.push(Arc::downgrade(&queue));
+    }
+    SUBSCRIBERS.fetch_add(1, Ordering::Relaxed);
+    MotionEvents { queue }
+}
+
+/// Send an event to every subscription that wants it
+///
+/// `event` is only built when someone is listening.
+pub(crate) fn emit(label: Option<&Arc<str>>, event: impl FnOnce(Option<Arc<str>>) -> MotionEvent) {
+    if SUBSCRIBERS.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+    let Ok(mut queues) = QUEUES.lock() else {
+        return;
+    };
+    let event = event(label.cloned());
+    queues.retain(|queue| {
+        let Some(queue) = queue.upgrade() else {
+            return false;
+        };
+        if let Ok(mut queue) = queue.lock()
+            && (label.is_some() || queue.unlabeled)
+        {
+            queue.push(event.clone());
+        }
+        true
+    });
+}
+
+/// A subscription to animation events, created with [`subscribe_events`]
+///
+/// Dropping it unsubscribes.
+pub struct MotionEvents {
+    queue: Arc<Mutex<Queue>>,
+}
+
+impl MotionEvents {
+    /// Also receive events of motion values without a label
+    pub fn with_unlabeled(self) -> Self {
+        if let Ok(mut queue) = self.queue.lock() {
+            queue.unlabeled = true;
+        }
+        self
+    }
+
+    /// The oldest buffered event, if any
+    pub fn try_next(&mut self) -> Option<MotionEvent> {
+        self.queue.lock().ok()?.events.pop_front()
+    }
+
+    /// Take every buffered event, oldest first
+    pub fn drain(&mut self) -> Vec<MotionEvent> {
+        self.queue
+            .lock()
+            .map(|mut queue| queue.events.drain(..).collect())
+            .unwrap_or_default()
+    }
+
+    /// Wait for the next event
+    pub fn recv(&mut self) -> NextEvent<'_> {
+        NextEvent { events: self }
+    }
+
+    /// How many events were dropped because the buffer was full
+    pub fn dropped(&self) -> u64 {
+        self.queue.lock().map_or(0, |queue| queue.dropped)
+    }
+}
+
+impl Drop for MotionEvents {
+    fn drop(&mut self) {
+        SUBSCRIBERS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Future returned by [`MotionEvents::recv`]
+pub struct NextEvent<'a> {
+    events: &'a mut MotionEvents,
+}
+
+impl Future for NextEvent<'_> {
+    type Output = MotionEvent;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<MotionEvent> {
+        let Ok(mut queue) = self.events.queue.lock() else {
+            return Poll::Pending;
+        };
+        if let Some(event) = queue.events.pop_front() {
+            return Poll::Ready(event);
+        }
+        queue.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+    use crate::animations::spring::Spring;
+    use crate::animations::tween::Tween;
+
+    fn settle(engine: &mut AnimationEngine<f32>) {
+        while engine.update(1.0 / 60.0) {}
+    }
+
+    /// Events for `label` only, as other tests animate on other threads
+    fn labeled(events: &mut MotionEvents, label: &str) -> Vec<MotionEvent> {
+        events
+            .drain()
+            .into_iter()
+            .filter(|event| event.label() == Some(label))
+            .collect()
+    }
+
+    #[test]
+    fn test_completed_and_interrupted_runs_in_order() {
+        let mut events = subscribe_events(16);
+        let label: Arc<str> = Arc::from("events-hero");
+        let mut engine = AnimationEngine::new(0.0f32).with_label(label.clone());
+
+        engine.tween_to(1.0, Tween::new().duration(Duration::from_millis(100)));
+        settle(&mut engine);
+        engine.tween_to(0.0, Tween::new().duration(Duration::from_millis(200)));
+        for _ in 0..6 {
+            engine.update(1.0 / 60.0);
+        }
+        engine.spring_to(1.0, Spring::default());
+        engine.stop();
+
+        let events = labeled(&mut events, "events-hero");
+        let label = Some(label);
+        assert_eq!(events.len(), 6, "{events:?}");
+        assert_eq!(
+            events[0],
+            MotionEvent::Started {
+                label: label.clone(),
+                kind: AnimationKind::Tween
+            }
+        );
+        let MotionEvent::Completed { duration, .. } = &events[1] else {
+            unreachable!("expected a completion, got {:?}", events[1]);
+        };
+        assert!((duration.as_secs_f32() - 0.1).abs() < 1.0 / 60.0);
+        assert!(matches!(
+            events[2],
+            MotionEvent::Started {
+                kind: AnimationKind::Tween,
+                ..
+            }
+        ));
+        // Replaced halfway through its 200ms
+        let MotionEvent::Interrupted {
+            progress: Some(progress),
+            ..
+        } = events[3]
+        else {
+            unreachable!("expected an interruption, got {:?}", events[3]);
+        };
+        assert!((progress - 0.5).abs() < 0.01);
+        assert!(matches!(
+            events[4],
+            MotionEvent::Started {
+                kind: AnimationKind::Spring,
+                ..
+            }
+        ));
+        assert_eq!(
+            events[5],
+            MotionEvent::Interrupted {
+                label,
+                progress: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_unlabeled_values_need_opting_in() {
+        let mut labeled_only = subscribe_events(4096);
+        let mut everything = subscribe_events(4096).with_unlabeled();
+
+        let mut engine = AnimationEngine::new(0.0f32);
+        engine.tween_to(1.0, Tween::new().duration(Duration::from_millis(50)));
+        settle(&mut engine);
+
+        assert!(
+            labeled_only
+                .drain()
+                .iter()
+                .all(|event| event.label().is_some())
+        );
+        let anonymous = everything.drain();
+        assert!(anonymous.contains(&MotionEvent::Started {
+            label: None,
+            kind: AnimationKind::Tween
+        }));
+    }
+
+    #[test]
+    fn test_full_buffer_drops_oldest() {
+        let mut queue = Queue::new(2);
+        for progress in [0.1, 0.2, 0.3] {
+            queue.push(MotionEvent::Interrupted {
+                label: None,
+                progress: Some(progress),
+            });
+        }
+
+        assert_eq!(queue.dropped, 1);
+        let kept: Vec<_> = queue
+            .events
+            .iter()
+            .map(|event| match event {
+                MotionEvent::Interrupted { progress, .. } => *progress,
+                _ => None,
+            })
+            .collect();
+        assert_eq!(kept, vec![Some(0.2), Some(0.3)]);
+    }
+}
@@ -9,9 +9,34 @@
 //! - Keyframe animations for complex sequences
 //! - Animation groups for coordinated motion
 //! - Staggered animations for sequential effects
+//! - Seeded noise for reproducible idle drift
 //! - Color interpolation
 //! - Transform animations
-//! - Page transitions (with "transitions" feature)
+//! - Transition variants and animated presence components (with "transition-core" feature, on by default)
+//! - Page transitions between routes (with "transitions" feature, which pulls in the router)
+//! - `#[derive(Animatable)]` for structs of animatable fields (with "derive" feature)
+//! - A skeleton loading `Shimmer` component (with "components" feature)
+//! - Half-precision `f16` values, for large sets of values (with "f16" feature)
+//! - Deprecated shims of the dioxus-motion (v1) API, for porting a step at a time (with "compat" feature)
+//! - Animation clips authored as JSON and loaded at runtime (with "clips" feature)
+//!
+//! # Upgrading with `default-features = false`
+//! `AnimatedShow`, `AnimatedSwitch`, `TransitionVariant` and `play_transition`
+//! used to compile unconditionally. They now sit behind "transition-core",
+//! which is a default feature, so a dependency that turns the defaults off
+//! must list it again to keep them:
+//!
+//! ```toml
+//! dioxus-motion2 = { version = "...", default-features = false, features = ["web", "transition-core"] }
+//! ```
+//!
+//! A dependency already enabling "transitions" needs no change: it implies
+//! "transition-core".
+//!
+//! # Minimum supported Rust version
+//! Rust 1.88, the first release with `let` chains in `if` and `while`
+//! conditions. Edition 2024 and the `const` float methods such as `f32::clamp`,
+//! which let `Color` and `Transform` be built in constants, came with 1.85.
 //!
 #![deny(clippy::unwrap_used)]
 #![deny(clippy::panic)]
@@ -23,27 +48,64 @@
 #![deny(clippy::modulo_arithmetic)]
 #![deny(clippy::option_if_let_else)]
 
+use std::sync::Arc;
+
 use dioxus::prelude::*;
 
+use crate::animations::time_scale::TimeScale;
+
+// Lets derive macros refer to this crate as `::dioxus_motion2` from inside it too
+extern crate self as dioxus_motion2;
+
 pub use instant::Duration;
 
 mod core;
+mod driver;
+pub mod events;
 mod platform;
+#[cfg(feature = "trace")]
+pub mod trace;
 
 // Animation type modules
 mod animation;
 pub mod animations;
+#[cfg(feature = "compat")]
+pub mod compat;
+#[cfg(feature = "components")]
+pub mod components;
 mod properties;
+#[cfg(feature = "test-utils")]
+pub mod testing;
 mod traits;
+#[cfg(feature = "transition-core")]
 pub mod transitions;
 
 // Re-exports for ease of use
-pub use animation::{Animation, AnimationState, AnimationTiming};
+pub use animation::{
+    Animation, AnimationState, AnimationTiming, AnimationToken, ConfigError, FIXED_DT_VAR,
+    Finished, MaybeSend, MotionConfig, PlaybackDirection, RunOutcome, presets, use_motion_config,
+};
 pub use core::{AnimationEngine, MotionValue};
+pub use driver::{
+    MotionStats, Priority, is_paused, motion_stats, pause_all, resume_all, set_animation_cap,
+    set_completion_budget, set_frame_budget,
+};
+pub use events::{AnimationKind, MotionEvent, MotionEvents, subscribe_events};
 pub use platform::{MotionTime, TimeProvider};
-pub use properties::{color::Color, transform::Transform};
+pub use properties::{
+    color::{Color, ColorMix, ColorSpace, HueDirection},
+    filter::Filter,
+    length::{Length, LengthUnit},
+    rotation::{Rotation3D, RotationMode},
+    style::MotionStyle,
+    transform::{RotationWinding, Transform},
+};
+#[cfg(feature = "trace")]
+pub use trace::TraceSample;
 pub use traits::animatable::Animatable;
 
+#[cfg(feature = "derive")]
+pub use dioxus_motion_transitions_macro::Animatable;
 #[cfg(feature = "transitions")]
 pub use dioxus_motion_transitions_macro::MotionTransitions;
 
@@ -52,50 +114,179 @@ pub mod prelude {
     pub use crate::Duration;
     #[cfg(feature = "transitions")]
     pub use crate::MotionTransitions;
-    pub use crate::animation::timing::LoopMode;
-    pub use crate::animation::{AnimationConfig, AnimationMode};
+    pub use crate::animation::timing::{LoopMode, PlaybackDirection};
+    pub use crate::animation::{
+        AnimationConfig, AnimationMode, AnimationToken, ConfigError, MotionConfig, RunOutcome,
+        presets, use_motion_config,
+    };
+    pub use crate::animations::bundle::{GroupProgress, MotionBundle, use_motion_bundle};
+    #[cfg(feature = "clips")]
+    pub use crate::animations::clip::{
+        Channel, Clip, ClipError, ClipKey, ClipPlayer, use_clip_player,
+    };
+    pub use crate::animations::crossfade::{Crossfade, use_crossfade};
+    pub use crate::animations::easing::{CubicBezier, easing_named, split_easing};
+    pub use crate::animations::entrance::{Entrance, use_entrance, use_entrance_on};
+    pub use crate::animations::follow::use_tracked_motion;
+    pub use crate::animations::hover::{HoverIntent, use_hover_intent, use_hover_motion};
+    pub use crate::animations::join::join_complete;
+    pub use crate::animations::keyframe::{
+        InterpMode, KeyframeAnimation, KeyframeTrack, SegmentCurve,
+    };
+    pub use crate::animations::noise::Noise;
+    pub use crate::animations::persist::{MotionStore, use_persistent_motion};
+    pub use crate::animations::power::{
+        AmbientPolicy, AmbientReduction, PowerPreferences, set_power_preferences,
+        use_power_preferences,
+    };
+    pub use crate::animations::scheme::{
+        ColorScheme, set_color_scheme, use_color_scheme, use_scheme_motion,
+    };
+    pub use crate::animations::scroll::{
+        ScrollMetrics, ScrollRange, ScrubHandle, use_scroll_timeline, use_scrub,
+    };
     pub use crate::animations::sequence;
-    pub use crate::animations::{spring::Spring, tween::Tween};
+    pub use crate::animations::simple::{SimpleMotion, use_simple_motion};
+    pub use crate::animations::snap::MagneticSnap;
+    pub use crate::animations::styles::use_motion_styles;
+    pub use crate::animations::time_scale::MotionTimeScale;
+    pub use crate::animations::tuning::{
+        TunableSpring, TuningPanel, use_retuning, use_tunable_spring,
+    };
+    pub use crate::animations::{
+        spring::{Spring, Weight},
+        tween::Tween,
+    };
+    #[cfg(feature = "components")]
+    pub use crate::components::shimmer::Shimmer;
     pub use crate::core::{AnimationEngine, MotionValue};
-    pub use crate::properties::{color::Color, transform::Transform};
+    pub use crate::driver::{Priority, pause_all, resume_all};
+    pub use crate::events::{MotionEvent, subscribe_events};
+    pub use crate::properties::{
+        color::{Color, ColorMix, ColorSpace, HueDirection},
+        filter::Filter,
+        length::{Length, LengthUnit},
+        rotation::{Rotation3D, RotationMode},
+        style::MotionStyle,
+        transform::{RotationWinding, Transform},
+    };
     pub use crate::traits::animatable::Animatable;
+    #[cfg(feature = "transition-core")]
+    pub use crate::transitions::dialog::AnimatedDialog;
+    #[cfg(feature = "transitions")]
+    pub use crate::transitions::page_transition::{
+        AnimatableRoute, AnimatedOutlet, TransitionMap, TransitionOutlet,
+    };
+    #[cfg(feature = "transition-core")]
+    pub use crate::transitions::presence::{AnimatedShow, AnimatedSwitch};
     #[cfg(feature = "transitions")]
-    pub use crate::transitions::page_transition::{AnimatableRoute, AnimatedOutlet};
-    pub use crate::use_motion;
+    pub use crate::transitions::route_transitions::{RouteTransitions, TransitionsBuilder};
+    #[cfg(feature = "transition-core")]
+    pub use crate::transitions::utility::{
+        TransitionConfig, TransitionPhase, TransitionVariant, play_transition, transition_transform,
+    };
+    pub use crate::{use_motion, use_motion_labeled, use_motion_with};
+    #[cfg(feature = "derive")]
+    pub use dioxus_motion_transitions_macro::Animatable;
+    #[cfg(feature = "f16")]
+    pub use half::f16;
 }
 
 /// Create a motion value with an initial value
 ///
-/// This is the primary entry point for creating animations
+/// This is the primary entry point for creating animations. The value is
+/// advanced by the shared animation driver together with every other motion
+/// value. When the component unmounts it is unregistered, its animation is
+/// cancelled and its pending callbacks are dropped without running.
+/// Inside a [`MotionTimeScale`](crate::animations::time_scale::MotionTimeScale)
+/// it runs at that scope's speed.
 ///
 pub fn use_motion<T: Animatable>(initial: T) -> MotionValue<T> {
-    let animation_engine = AnimationEngine::new(initial);
-    let mut signal = use_signal(|| animation_engine);
-
-    use_future(move || async move {
-        let mut last_frame = MotionTime::now();
+    use_motion_inner(|| initial, None)
+}
 
-        loop {
-            let now = MotionTime::now();
-            let dt = now.duration_since(last_frame).as_secs_f32();
+/// Create a motion value whose initial value `initial` computes
+///
+/// Works like [`use_motion`], but like the closure form of `use_signal`,
+/// `initial` runs once, on the first render, rather than on every render.
+/// Use it when the initial value is costly to work out.
+pub fn use_motion_with<T: Animatable>(initial: impl FnOnce() -> T) -> MotionValue<T> {
+    use_motion_inner(initial, None)
+}
 
-            let is_active = signal.write().update(dt);
+/// Create a motion value named `label` in its lifecycle events
+///
+/// Works like [`use_motion`]; the label tells the value apart in the
+/// [`MotionEvent`]s of a [`subscribe_events`] subscription. It is read on the
+/// first render only.
+pub fn use_motion_labeled<T: Animatable>(label: impl Into<Arc<str>>, initial: T) -> MotionValue<T> {
+    use_motion_inner(|| initial, Some(label.into()))
+}
 
-            // Adaptive frame rate based on activity
-            let delay = if is_active {
-                if dt > 0.064 {
-                    Duration::from_millis(8)
-                } else {
-                    Duration::from_millis(16)
-                }
-            } else {
-                Duration::from_millis(100)
-            };
+fn use_motion_inner<T: Animatable>(
+    initial: impl FnOnce() -> T,
+    label: Option<Arc<str>>,
+) -> MotionValue<T> {
+    let id = use_hook(driver::next_id);
+    let config = try_use_context::<MotionConfig>();
+    let time_scale = try_use_context::<TimeScale>();
+    let mut signal = use_signal(|| {
+        let mut engine = AnimationEngine::new(initial()).with_driver_id(id);
+        if let Some(label) = label {
+            engine = engine.with_label(label);
+        }
+        match config.and_then(|config| config.default_animation) {
+            Some(mode) => engine.with_default_animation(mode),
+            None => engine,
+        }
+    });
 
-            last_frame = now;
-            MotionTime::delay(delay).await;
+    use_hook(move || drive(id, signal, time_scale));
+    use_drop(move || {
+        driver::unregister(id);
+        // The signal may already be gone if the whole scope is being dropped
+        if let Ok(mut engine) = signal.try_write() {
+            engine.teardown();
         }
     });
 
     MotionValue::new(signal)
 }
+
+/// Register `signal`'s engine with the shared driver under `id`
+pub(crate) fn drive<T: Animatable>(
+    id: u64,
+    mut signal: Signal<AnimationEngine<T>>,
+    time_scale: Option<TimeScale>,
+) {
+    #[cfg(feature = "test-utils")]
+    testing::track(id, signal);
+    let label = signal.peek().label().map(std::sync::Arc::from);
+    driver::register(
+        id,
+        label,
+        Box::new(move |dt| {
+            let dt = time_scale.as_ref().map_or(dt, |scale| scale.scale(dt));
+            let (is_active, style, ready) = match signal.try_write() {
+                Ok(mut engine) => {
+                    let is_active = engine.update(dt);
+                    (
+                        is_active,
+                        engine.take_style(),
+                        engine.take_ready_callbacks(),
+                    )
+                }
+                // The component is being torn down
+                Err(_) => return false,
+            };
+            if let Some(style) = style {
+                driver::queue_style(id, style);
+            }
+            driver::complete(id, ready);
+            is_active
+        }),
+    )
+}
+
+#[cfg(test)]
+mod test_support;
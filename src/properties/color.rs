@@ -3,12 +3,86 @@
 //! Provides RGBA color representation and animation interpolation.
 //! Supports both normalized (0.0-1.0) and byte (0-255) color values.
 
-use crate::Animatable;
+use easer::functions::{Cubic, Easing};
+use tracing::debug;
+
+use crate::animation::{AnimationConfig, AnimationMode, AnimationToken};
+use crate::animations::tween::Tween;
+use crate::{Animatable, Duration, MotionValue};
+
+/// HSL saturation below which a color counts as gray and has no hue
+const ACHROMATIC_SATURATION: f32 = 1e-4;
+
+/// Color space in which two colors are mixed
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ColorSpace {
+    /// Mix the sRGB components directly
+    #[default]
+    Rgb,
+    /// Mix hue, saturation and lightness
+    Hsl,
+    /// Mix perceptual lightness, chroma and hue
+    Oklch,
+}
+
+/// Which way around the color wheel a hue is interpolated
+///
+/// Follows the hue interpolation methods of CSS Color 4 and only applies to
+/// the polar color spaces [`ColorSpace::Hsl`] and [`ColorSpace::Oklch`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum HueDirection {
+    /// The shorter arc, at most 180°
+    #[default]
+    Shorter,
+    /// The longer arc, at least 180°
+    Longer,
+    /// Always with increasing hue angles
+    Increasing,
+    /// Always with decreasing hue angles
+    Decreasing,
+}
+
+impl HueDirection {
+    /// Interpolates between two hue angles in degrees, returning 0.0-360.0
+    pub fn interpolate(&self, from: f32, to: f32, t: f32) -> f32 {
+        let (mut from, mut to) = (from.rem_euclid(360.0), to.rem_euclid(360.0));
+        let delta = to - from;
+        match self {
+            Self::Shorter if delta > 180.0 => from += 360.0,
+            Self::Shorter if delta < -180.0 => to += 360.0,
+            Self::Longer if delta > 0.0 && delta < 180.0 => from += 360.0,
+            Self::Longer if delta > -180.0 && delta <= 0.0 => to += 360.0,
+            Self::Increasing if to < from => to += 360.0,
+            Self::Decreasing if from < to => from += 360.0,
+            _ => {}
+        }
+        (from + (to - from) * t).rem_euclid(360.0)
+    }
+}
+
+/// How two colors are mixed: the color space and, for polar spaces, the hue direction
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct ColorMix {
+    /// Color space to interpolate in
+    pub space: ColorSpace,
+    /// Direction around the hue wheel
+    pub hue: HueDirection,
+}
 
 /// Represents an RGBA color with normalized components
 ///
 /// Each component (r,g,b,a) is stored as a float between 0.0 and 1.0
+///
+/// [`Color::new`], [`Color::from_rgba`] and the named colors are `const fn`,
+/// so a palette can be declared as constants:
+/// ```
+/// use dioxus_motion2::Color;
+///
+/// const BRAND: Color = Color::from_rgba(37, 99, 235, 255);
+/// const OVERLAY: Color = Color::new(0.0, 0.0, 0.0, 0.4);
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "clips", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     /// Red component (0.0-1.0)
     pub r: f32,
@@ -28,7 +102,7 @@ impl Color {
     /// use dioxus_motion2::Color;
     /// let orange = Color::new(1.0, 0.5, 0.0, 1.0);
     /// ```
-    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
         Self {
             r: r.clamp(0.0, 1.0),
             g: g.clamp(0.0, 1.0),
@@ -44,7 +118,7 @@ impl Color {
     /// use dioxus_motion2::Color;
     /// let orange = Color::from_rgba(255, 128, 0, 255);
     /// ```
-    pub fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+    pub const fn from_rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self::new(
             r as f32 / 255.0,
             g as f32 / 255.0,
@@ -154,57 +228,292 @@ impl Color {
         }
     }
 
+    /// Converts color to OKLCH components
+    ///
+    /// # Returns
+    /// Tuple of (lightness 0.0-1.0, chroma, hue in degrees 0.0-360.0). The hue is
+    /// 0.0 for achromatic colors.
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        // sRGB -> linear RGB
+        fn linearize(c: f32) -> f32 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+        let (r, g, b) = (linearize(self.r), linearize(self.g), linearize(self.b));
+
+        // Linear RGB -> OKLab (Björn Ottosson's reference matrices)
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        let lightness = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+        let a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+        let b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+
+        let chroma = (a * a + b * b).sqrt();
+        let hue = if chroma < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+
+        (lightness, chroma, hue)
+    }
+
+    /// Creates a color from OKLCH components, as returned by [`Color::to_oklch`]
+    ///
+    /// Colors outside the sRGB gamut are clipped.
+    pub fn from_oklch(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Self {
+        // sRGB gamma encoding of a linear component
+        fn encode(c: f32) -> f32 {
+            if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+        let (sin, cos) = hue.to_radians().sin_cos();
+        let (a, b) = (chroma * cos, chroma * sin);
+
+        // OKLab -> linear RGB (inverse of the matrices in `to_oklch`)
+        let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+        let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+        let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s;
+        let b = -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+
+        Self::new(encode(r), encode(g), encode(b), alpha)
+    }
+
+    /// Converts color to HSL components
+    ///
+    /// # Returns
+    /// Tuple of (hue in degrees 0.0-360.0, saturation 0.0-1.0, lightness 0.0-1.0).
+    /// The hue is 0.0 for grays.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta < 1e-6 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == self.r {
+            (self.g - self.b) / delta
+        } else if max == self.g {
+            (self.b - self.r) / delta + 2.0
+        } else {
+            (self.r - self.g) / delta + 4.0
+        };
+
+        ((hue * 60.0).rem_euclid(360.0), saturation, lightness)
+    }
+
+    /// Creates a color from HSL components, as returned by [`Color::to_hsl`]
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let offset = lightness - chroma / 2.0;
+
+        Self::new(r + offset, g + offset, b + offset, alpha)
+    }
+
+    /// Interpolates towards `target` in the color space and hue direction of `mix`
+    ///
+    /// The hue of a gray is meaningless, so a gray end takes the other end's hue,
+    /// as in CSS.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion2::{Color, ColorMix, ColorSpace, HueDirection};
+    ///
+    /// let mix = ColorMix { space: ColorSpace::Hsl, hue: HueDirection::Longer };
+    /// // The long way from blue to red passes through green
+    /// assert_eq!(Color::blue().mix(&Color::red(), 0.5, mix), Color::green());
+    /// ```
+    pub fn mix(&self, target: &Self, t: f32, mix: ColorMix) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        let alpha = lerp(self.a, target.a);
+
+        match mix.space {
+            ColorSpace::Rgb => self.interpolate(target, t),
+            ColorSpace::Hsl => {
+                let (h1, s1, l1) = self.to_hsl();
+                let (h2, s2, l2) = target.to_hsl();
+                let (h1, h2) = powerless_hues(h1, s1, h2, s2);
+                let hue = mix.hue.interpolate(h1, h2, t);
+                Self::from_hsl(hue, lerp(s1, s2), lerp(l1, l2), alpha)
+            }
+            ColorSpace::Oklch => {
+                let (l1, c1, h1) = self.to_oklch();
+                let (l2, c2, h2) = target.to_oklch();
+                let (h1, h2) = powerless_hues(h1, c1, h2, c2);
+                let hue = mix.hue.interpolate(h1, h2, t);
+                Self::from_oklch(lerp(l1, l2), lerp(c1, c2), hue, alpha)
+            }
+        }
+    }
+
+    /// Converts color to a CSS `oklch()` string
+    ///
+    /// Lightness is emitted as a percentage, chroma with 4 decimals and hue in
+    /// degrees. Alpha is appended only when the color is not fully opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion2::Color;
+    /// assert_eq!(Color::white().to_oklch_string(), "oklch(100% 0 0)");
+    /// ```
+    pub fn to_oklch_string(&self) -> String {
+        let (l, c, h) = self.to_oklch();
+        let lightness = (l * 1000.0).round() / 10.0;
+        let chroma = (c * 10000.0).round() / 10000.0;
+        let hue = (h * 100.0).round() / 100.0;
+        let alpha = (self.a * 100.0).round() / 100.0;
+
+        if alpha < 1.0 {
+            format!("oklch({}% {} {} / {})", lightness, chroma, hue, alpha)
+        } else {
+            format!("oklch({}% {} {})", lightness, chroma, hue)
+        }
+    }
+
+    /// The same color with alpha `alpha`
+    pub fn with_alpha(&self, alpha: f32) -> Self {
+        Self::new(self.r, self.g, self.b, alpha)
+    }
+
+    /// The same hue and saturation with HSL lightness `lightness`, from 0.0 to 1.0
+    pub fn with_lightness(&self, lightness: f32) -> Self {
+        let (hue, saturation, _) = self.to_hsl();
+        Self::from_hsl(hue, saturation, lightness.clamp(0.0, 1.0), self.a)
+    }
+
+    /// The color with its hue turned by `degrees`, keeping saturation and lightness
+    ///
+    /// Returns `None` for grays, whose hue is meaningless.
+    ///
+    /// # Examples
+    /// ```
+    /// use dioxus_motion2::Color;
+    /// assert_eq!(Color::red().rotate_hue(120.0), Some(Color::green()));
+    /// assert_eq!(Color::gray().rotate_hue(120.0), None);
+    /// ```
+    pub fn rotate_hue(&self, degrees: f32) -> Option<Self> {
+        let (hue, saturation, lightness) = self.to_hsl();
+        (saturation >= ACHROMATIC_SATURATION)
+            .then(|| Self::from_hsl(hue + degrees, saturation, lightness, self.a))
+    }
+
     /// Pre-defined color: transparent black
-    pub fn transparent() -> Self {
+    pub const fn transparent() -> Self {
         Self::new(0.0, 0.0, 0.0, 0.0)
     }
 
     /// Pre-defined color: black
-    pub fn black() -> Self {
+    pub const fn black() -> Self {
         Self::new(0.0, 0.0, 0.0, 1.0)
     }
 
     /// Pre-defined color: white
-    pub fn white() -> Self {
+    pub const fn white() -> Self {
         Self::new(1.0, 1.0, 1.0, 1.0)
     }
 
     /// Pre-defined color: red
-    pub fn red() -> Self {
+    pub const fn red() -> Self {
         Self::new(1.0, 0.0, 0.0, 1.0)
     }
 
     /// Pre-defined color: green
-    pub fn green() -> Self {
+    pub const fn green() -> Self {
         Self::new(0.0, 1.0, 0.0, 1.0)
     }
 
     /// Pre-defined color: blue
-    pub fn blue() -> Self {
+    pub const fn blue() -> Self {
         Self::new(0.0, 0.0, 1.0, 1.0)
     }
 
     /// Pre-defined color: yellow
-    pub fn yellow() -> Self {
+    pub const fn yellow() -> Self {
         Self::new(1.0, 1.0, 0.0, 1.0)
     }
 
     /// Pre-defined color: cyan
-    pub fn cyan() -> Self {
+    pub const fn cyan() -> Self {
         Self::new(0.0, 1.0, 1.0, 1.0)
     }
 
     /// Pre-defined color: magenta
-    pub fn magenta() -> Self {
+    pub const fn magenta() -> Self {
         Self::new(1.0, 0.0, 1.0, 1.0)
     }
 
     /// Pre-defined color: gray (50%)
-    pub fn gray() -> Self {
+    pub const fn gray() -> Self {
         Self::new(0.5, 0.5, 0.5, 1.0)
     }
 }
 
+/// Replace the hue of an achromatic end with the other end's hue
+fn powerless_hues(h1: f32, colorfulness1: f32, h2: f32, colorfulness2: f32) -> (f32, f32) {
+    const ACHROMATIC: f32 = 1e-4;
+    match (colorfulness1 < ACHROMATIC, colorfulness2 < ACHROMATIC) {
+        (true, false) => (h2, h2),
+        (false, true) => (h1, h1),
+        _ => (h1, h2),
+    }
+}
+
+impl MotionValue<Color> {
+    /// Animate only the alpha, keeping the current red, green and blue
+    pub fn animate_alpha_to(&mut self, alpha: f32, config: AnimationConfig) -> AnimationToken {
+        let target = self.peek().with_alpha(alpha);
+        self.animate_to_with_config(target, config)
+    }
+
+    /// Animate the hue around by `degrees`, keeping saturation and lightness
+    ///
+    /// The animation runs between the current and the turned color like any
+    /// other, so it doesn't pass through the hues in between. Grays have no hue
+    /// to turn, so they are left as they are.
+    pub fn animate_hue_by(&mut self, degrees: f32, config: AnimationConfig) -> AnimationToken {
+        let Some(target) = self.peek().rotate_hue(degrees) else {
+            debug!("Not rotating the hue of a gray color");
+            return AnimationToken::ended();
+        };
+        self.animate_to_with_config(target, config)
+    }
+
+    /// Animate the HSL lightness, keeping hue, saturation and alpha
+    pub fn animate_lightness_to(
+        &mut self,
+        lightness: f32,
+        config: AnimationConfig,
+    ) -> AnimationToken {
+        let target = self.peek().with_lightness(lightness);
+        self.animate_to_with_config(target, config)
+    }
+}
+
 /// Implementation of animation interpolation for Color
 impl Animatable for Color {
     /// Creates a fully transparent black color
@@ -217,6 +526,15 @@ impl Animatable for Color {
         0.001
     }
 
+    /// Eases out over 200ms; a spring's overshoot would flash through other hues
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Tween(
+            Tween::new()
+                .duration(Duration::from_millis(200))
+                .easing(Cubic::ease_out),
+        )
+    }
+
     /// Calculates color vector magnitude
     fn magnitude(&self) -> f32 {
         (self.r * self.r + self.g * self.g + self.b * self.b + self.a * self.a).sqrt()
@@ -269,6 +587,17 @@ impl Animatable for Color {
 mod tests {
     use super::*;
 
+    const CLAMPED: Color = Color::new(1.5, -0.7, 0.25, 1.0);
+    const BRAND: Color = Color::from_rgba(255, 0, 51, 255);
+    const _: Color = Color::transparent();
+
+    #[test]
+    fn test_const_constructors_match_runtime() {
+        assert_eq!(CLAMPED, Color::new(1.0, 0.0, 0.25, 1.0));
+        assert_eq!(BRAND, Color::from_rgba(255, 0, 51, 255));
+        assert_eq!(BRAND.to_rgba(), (255, 0, 51, 255));
+    }
+
     #[test]
     fn test_color_new() {
         let color = Color::new(0.5, 0.7, 0.3, 1.0);
@@ -469,6 +798,134 @@ mod tests {
         assert_eq!(color.to_css_string(), "rgba(255, 128, 0, 0.5)");
     }
 
+    #[test]
+    fn test_color_to_oklch() {
+        // Reference values from the CSS Color 4 specification examples
+        let (l, c, h) = Color::red().to_oklch();
+        assert!((l - 0.628).abs() < 0.001);
+        assert!((c - 0.2577).abs() < 0.001);
+        assert!((h - 29.23).abs() < 0.1);
+
+        let (l, c, _) = Color::gray().to_oklch();
+        assert!((l - 0.5982).abs() < 0.001);
+        assert!(c < 1e-4);
+
+        let (l, c, h) = Color::black().to_oklch();
+        assert_eq!((l, c, h), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_color_oklch_string_formatting() {
+        assert_eq!(Color::white().to_oklch_string(), "oklch(100% 0 0)");
+        assert_eq!(Color::black().to_oklch_string(), "oklch(0% 0 0)");
+        assert_eq!(Color::red().to_oklch_string(), "oklch(62.8% 0.2577 29.23)");
+        assert_eq!(
+            Color::new(1.0, 0.0, 0.0, 0.5).to_oklch_string(),
+            "oklch(62.8% 0.2577 29.23 / 0.5)"
+        );
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        assert_eq!(Color::blue().to_hsl(), (240.0, 1.0, 0.5));
+        assert_eq!(Color::gray().to_hsl().1, 0.0);
+        for color in [
+            Color::red(),
+            Color::yellow(),
+            Color::new(0.2, 0.4, 0.8, 0.5),
+        ] {
+            let (h, s, l) = color.to_hsl();
+            let back = Color::from_hsl(h, s, l, color.a);
+            assert!(back.sub(&color).magnitude() < 1e-5, "{color:?} -> {back:?}");
+        }
+    }
+
+    #[test]
+    fn test_channel_targets() {
+        let badge = Color::new(0.2, 0.4, 0.8, 1.0);
+        assert_eq!(badge.with_alpha(0.0), Color::new(0.2, 0.4, 0.8, 0.0));
+
+        let (hue, saturation, _) = badge.to_hsl();
+        let lighter = badge.with_lightness(0.9);
+        let (lighter_hue, lighter_saturation, lightness) = lighter.to_hsl();
+        assert!((lighter_hue - hue).abs() < 1e-3);
+        assert!((lighter_saturation - saturation).abs() < 1e-3);
+        assert!((lightness - 0.9).abs() < 1e-5);
+        assert_eq!(lighter.a, badge.a);
+
+        let Some(turned) = badge.with_alpha(0.5).rotate_hue(-90.0) else {
+            unreachable!("a saturated color has a hue");
+        };
+        let (turned_hue, turned_saturation, turned_lightness) = turned.to_hsl();
+        assert!((turned_hue - (hue - 90.0)).abs() < 1e-3);
+        assert!((turned_saturation - saturation).abs() < 1e-3);
+        assert!((turned_lightness - badge.to_hsl().2).abs() < 1e-5);
+        assert_eq!(turned.a, 0.5);
+        assert!(
+            Color::red()
+                .rotate_hue(360.0)
+                .is_some_and(|c| c.sub(&Color::red()).magnitude() < 1e-5)
+        );
+    }
+
+    #[test]
+    fn test_rotating_gray_hue_is_noop() {
+        assert_eq!(Color::gray().rotate_hue(90.0), None);
+        assert_eq!(Color::white().rotate_hue(90.0), None);
+        assert_eq!(Color::black().rotate_hue(90.0), None);
+    }
+
+    #[test]
+    fn test_oklch_round_trip() {
+        for color in [Color::red(), Color::cyan(), Color::new(0.2, 0.4, 0.8, 1.0)] {
+            let (l, c, h) = color.to_oklch();
+            let back = Color::from_oklch(l, c, h, color.a);
+            assert!(back.sub(&color).magnitude() < 1e-3, "{color:?} -> {back:?}");
+        }
+    }
+
+    #[test]
+    fn test_hue_directions() {
+        let (blue, red) = (240.0, 0.0);
+        assert_eq!(HueDirection::Shorter.interpolate(blue, red, 0.5), 300.0);
+        assert_eq!(HueDirection::Longer.interpolate(blue, red, 0.5), 120.0);
+        assert_eq!(HueDirection::Increasing.interpolate(blue, red, 0.5), 300.0);
+        assert_eq!(HueDirection::Decreasing.interpolate(blue, red, 0.5), 120.0);
+        assert_eq!(HueDirection::Increasing.interpolate(red, blue, 0.5), 120.0);
+        assert_eq!(HueDirection::Decreasing.interpolate(red, blue, 0.5), 300.0);
+        // Equal hues only move along the longer arc, which is the full circle
+        assert_eq!(HueDirection::Shorter.interpolate(90.0, 90.0, 0.5), 90.0);
+        assert_eq!(HueDirection::Longer.interpolate(90.0, 90.0, 0.5), 270.0);
+    }
+
+    #[test]
+    fn test_mix_blue_to_red_midpoints() {
+        let mix = |space, hue| Color::blue().mix(&Color::red(), 0.5, ColorMix { space, hue });
+
+        assert_eq!(
+            mix(ColorSpace::Hsl, HueDirection::Shorter),
+            Color::magenta()
+        );
+        assert_eq!(mix(ColorSpace::Hsl, HueDirection::Longer), Color::green());
+
+        // In OKLCH the short way is purple-pink, the long way green-yellow
+        let short = mix(ColorSpace::Oklch, HueDirection::Shorter);
+        assert!(short.r > 0.5 && short.b > 0.5 && short.g < 0.3, "{short:?}");
+        let long = mix(ColorSpace::Oklch, HueDirection::Longer);
+        assert!(long.g > long.r && long.g > long.b, "{long:?}");
+    }
+
+    #[test]
+    fn test_mix_from_gray_keeps_hue() {
+        let mix = ColorMix {
+            space: ColorSpace::Hsl,
+            hue: HueDirection::Shorter,
+        };
+        let halfway = Color::gray().mix(&Color::red(), 0.5, mix);
+        let (h, _, _) = halfway.to_hsl();
+        assert!(h.abs() < 1e-3, "{halfway:?}");
+    }
+
     #[test]
     fn test_color_hex_string_formatting() {
         // Test various color combinations
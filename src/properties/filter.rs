@@ -0,0 +1,344 @@
+//! Filter module for CSS `filter` and `backdrop-filter` functions
+//!
+//! Provides a Filter type carrying the functions glass-like surfaces animate:
+//! blur, saturate, brightness and grayscale.
+
+use crate::animation::{AnimationConfig, AnimationMode, AnimationToken};
+use crate::animations::spring::Spring;
+use crate::{Animatable, MotionValue};
+
+/// Value of each function that leaves the element unchanged
+const BLUR: f32 = 0.0;
+const SATURATE: f32 = 1.0;
+const BRIGHTNESS: f32 = 1.0;
+const GRAYSCALE: f32 = 0.0;
+
+/// A list of CSS filter functions, each one optional
+///
+/// A missing function counts as its identity value: no blur, full saturation,
+/// full brightness and no grayscale. Animating between filters with different
+/// functions therefore starts or ends the missing ones at their identity, e.g.
+/// a blur fades in from `0px`, and [`to_css_string`](Filter::to_css_string)
+/// writes only the functions present.
+///
+/// The same value renders `filter` and `backdrop-filter`; a [`MotionStyle`]
+/// carries one as its `filter` channel.
+///
+/// # Example
+/// ```
+/// use dioxus_motion2::Filter;
+///
+/// let frosted = Filter::none().with_blur(8.0).with_saturate(1.8);
+/// assert_eq!(frosted.to_css_string(), "blur(8px) saturate(1.8)");
+/// assert_eq!(Filter::none().to_css_string(), "none");
+/// ```
+///
+/// [`MotionStyle`]: crate::MotionStyle
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Filter {
+    /// Blur radius in pixels
+    blur: Option<f32>,
+    /// Saturation factor, 1 leaving colors as they are
+    saturate: Option<f32>,
+    /// Brightness factor, 1 leaving colors as they are
+    brightness: Option<f32>,
+    /// Amount of grayscale from 0 to 1
+    grayscale: Option<f32>,
+}
+
+impl Filter {
+    /// A filter without any function, rendered as `none`
+    pub const fn none() -> Self {
+        Self {
+            blur: None,
+            saturate: None,
+            brightness: None,
+            grayscale: None,
+        }
+    }
+
+    /// Adds or replaces the blur, a radius in pixels
+    pub const fn with_blur(self, px: f32) -> Self {
+        Self {
+            blur: Some(px),
+            ..self
+        }
+    }
+
+    /// Adds or replaces the saturation factor
+    pub const fn with_saturate(self, factor: f32) -> Self {
+        Self {
+            saturate: Some(factor),
+            ..self
+        }
+    }
+
+    /// Adds or replaces the brightness factor
+    pub const fn with_brightness(self, factor: f32) -> Self {
+        Self {
+            brightness: Some(factor),
+            ..self
+        }
+    }
+
+    /// Adds or replaces the amount of grayscale, from 0 to 1
+    pub const fn with_grayscale(self, amount: f32) -> Self {
+        Self {
+            grayscale: Some(amount),
+            ..self
+        }
+    }
+
+    /// The blur radius in pixels, if present
+    pub fn blur(&self) -> Option<f32> {
+        self.blur
+    }
+
+    /// The saturation factor, if present
+    pub fn saturate(&self) -> Option<f32> {
+        self.saturate
+    }
+
+    /// The brightness factor, if present
+    pub fn brightness(&self) -> Option<f32> {
+        self.brightness
+    }
+
+    /// The amount of grayscale, if present
+    pub fn grayscale(&self) -> Option<f32> {
+        self.grayscale
+    }
+
+    /// Whether no function is present
+    pub fn is_none(&self) -> bool {
+        *self == Self::none()
+    }
+
+    /// Converts the filter to a CSS value such as `blur(8px) saturate(1.8)`
+    ///
+    /// Functions are written in a fixed order, blur, saturate, brightness then
+    /// grayscale, whatever order they were added in, so consecutive frames of
+    /// an animation keep the same function list. CSS rejects negative values
+    /// and grayscale above one, so those are written as the nearest valid one.
+    pub fn to_css_string(&self) -> String {
+        // Adding zero turns -0 into 0
+        let functions = [
+            self.blur.map(|px| format!("blur({}px)", px.max(0.0) + 0.0)),
+            self.saturate
+                .map(|factor| format!("saturate({})", factor.max(0.0) + 0.0)),
+            self.brightness
+                .map(|factor| format!("brightness({})", factor.max(0.0) + 0.0)),
+            self.grayscale
+                .map(|amount| format!("grayscale({})", amount.clamp(0.0, 1.0) + 0.0)),
+        ];
+        let functions: Vec<_> = functions.into_iter().flatten().collect();
+        if functions.is_empty() {
+            "none".to_string()
+        } else {
+            functions.join(" ")
+        }
+    }
+
+    /// Applies `op` to the offsets from identity of each function, keeping the
+    /// functions present on either side
+    fn zip(&self, other: &Self, op: impl Fn(f32, f32) -> f32) -> Self {
+        let channel = |a: Option<f32>, b: Option<f32>, identity: f32| {
+            let offset = |value: Option<f32>| value.map_or(0.0, |value| value - identity);
+            (a.is_some() || b.is_some()).then(|| op(offset(a), offset(b)) + identity)
+        };
+        Self {
+            blur: channel(self.blur, other.blur, BLUR),
+            saturate: channel(self.saturate, other.saturate, SATURATE),
+            brightness: channel(self.brightness, other.brightness, BRIGHTNESS),
+            grayscale: channel(self.grayscale, other.grayscale, GRAYSCALE),
+        }
+    }
+
+    /// Offsets from identity of each function, in the order they are written
+    fn offsets(&self) -> [f32; 4] {
+        let offset =
+            |value: Option<f32>, identity: f32| value.map_or(0.0, |value| value - identity);
+        [
+            offset(self.blur, BLUR),
+            offset(self.saturate, SATURATE),
+            offset(self.brightness, BRIGHTNESS),
+            offset(self.grayscale, GRAYSCALE),
+        ]
+    }
+}
+
+impl MotionValue<Filter> {
+    /// Animate only the blur, keeping the other functions
+    pub fn blur_to(&mut self, px: f32, config: AnimationConfig) -> AnimationToken {
+        let target = self.peek().with_blur(px);
+        self.animate_to_with_config(target, config)
+    }
+
+    /// Animate only the saturation, keeping the other functions
+    pub fn saturate_to(&mut self, factor: f32, config: AnimationConfig) -> AnimationToken {
+        let target = self.peek().with_saturate(factor);
+        self.animate_to_with_config(target, config)
+    }
+
+    /// Animate only the brightness, keeping the other functions
+    pub fn brightness_to(&mut self, factor: f32, config: AnimationConfig) -> AnimationToken {
+        let target = self.peek().with_brightness(factor);
+        self.animate_to_with_config(target, config)
+    }
+
+    /// Animate only the grayscale, keeping the other functions
+    pub fn grayscale_to(&mut self, amount: f32, config: AnimationConfig) -> AnimationToken {
+        let target = self.peek().with_grayscale(amount);
+        self.animate_to_with_config(target, config)
+    }
+}
+
+// Functions are stored as they are written, but added, subtracted and scaled
+// as offsets from their identity values, like the scale of a `Transform`
+impl Animatable for Filter {
+    /// Creates a filter without any function
+    fn zero() -> Self {
+        Self::none()
+    }
+
+    /// Minimum meaningful difference between filters
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    /// Settles without overshoot; a blur mustn't swing below zero
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::snappy())
+    }
+
+    /// Sum of the distances of each function from its identity
+    fn magnitude(&self) -> f32 {
+        self.offsets().iter().map(|offset| offset.abs()).sum()
+    }
+
+    /// Scales each function's offset from its identity
+    fn scale(&self, factor: f32) -> Self {
+        self.zip(self, |offset, _| offset * factor)
+    }
+
+    /// Adds the offsets from identity, keeping the functions of both
+    fn add(&self, other: &Self) -> Self {
+        self.zip(other, |a, b| a + b)
+    }
+
+    /// Subtracts the offsets from identity, keeping the functions of both
+    fn sub(&self, other: &Self) -> Self {
+        self.zip(other, |a, b| a - b)
+    }
+
+    /// Interpolates each function, a missing one from or to its identity
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        self.zip(target, |from, to| from + (to - from) * t)
+    }
+
+    /// Settled once every function is, the blur measured in pixels and the
+    /// factors on their own scale
+    fn is_settled(&self, target: &Self, velocity: &Self) -> bool {
+        // (max remaining distance, max speed per second) for each function
+        const PIXELS: (f32, f32) = (0.05, 0.5);
+        const FACTOR: (f32, f32) = (0.001, 0.01);
+
+        let delta = target.sub(self).offsets();
+        let speed = velocity.offsets();
+        [PIXELS, FACTOR, FACTOR, FACTOR]
+            .into_iter()
+            .enumerate()
+            .all(|(i, (distance, max_speed))| {
+                delta[i].abs() < distance && speed[i].abs() < max_speed
+            })
+    }
+
+    /// A negative blur or factor, or grayscale beyond 1, can't be displayed
+    fn is_out_of_range(&self) -> bool {
+        self.blur.is_some_and(|px| px < 0.0)
+            || self.saturate.is_some_and(|factor| factor < 0.0)
+            || self.brightness.is_some_and(|factor| factor < 0.0)
+            || self
+                .grayscale
+                .is_some_and(|amount| !(0.0..=1.0).contains(&amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+    use crate::animations::tween::Tween;
+
+    #[test]
+    fn test_missing_functions_are_omitted() {
+        assert_eq!(Filter::none().to_css_string(), "none");
+        assert!(Filter::default().is_none());
+        assert_eq!(
+            Filter::none().with_brightness(0.9).to_css_string(),
+            "brightness(0.9)"
+        );
+        assert_eq!(Filter::none().with_blur(-2.0).to_css_string(), "blur(0px)");
+        assert!(Filter::none().with_blur(-2.0).is_out_of_range());
+
+        // Offsets from identity: no function means no distance
+        assert_eq!(Filter::none().magnitude(), 0.0);
+        assert_eq!(Filter::none().with_saturate(1.0).magnitude(), 0.0);
+    }
+
+    #[test]
+    fn test_functions_written_in_a_fixed_order() {
+        let filter = Filter::none()
+            .with_grayscale(0.5)
+            .with_brightness(1.1)
+            .with_saturate(1.8)
+            .with_blur(8.0);
+        assert_eq!(
+            filter.to_css_string(),
+            "blur(8px) saturate(1.8) brightness(1.1) grayscale(0.5)"
+        );
+    }
+
+    #[test]
+    fn test_mixed_functions_interpolate_from_identity() {
+        let plain = Filter::none().with_saturate(2.0);
+        let frosted = Filter::none().with_blur(8.0);
+
+        let halfway = plain.interpolate(&frosted, 0.5);
+        assert_eq!(halfway.blur(), Some(4.0));
+        assert_eq!(halfway.saturate(), Some(1.5));
+        assert_eq!(halfway.brightness(), None);
+        assert_eq!(halfway.to_css_string(), "blur(4px) saturate(1.5)");
+    }
+
+    #[test]
+    fn test_arithmetic_keeps_factors_relative_to_one() {
+        let a = Filter::none().with_saturate(1.5);
+        let b = Filter::none().with_saturate(2.0);
+        let delta = b.sub(&a);
+        assert_eq!(delta.saturate(), Some(1.5));
+        assert_eq!(a.add(&delta), b);
+        assert_eq!(a.add(&Filter::zero()), a);
+        assert_eq!(a.scale(2.0).saturate(), Some(2.0));
+    }
+
+    #[test]
+    fn test_springs_and_tweens_reach_the_target() {
+        let glass = Filter::none().with_blur(12.0).with_saturate(1.8);
+
+        let mut spring = AnimationEngine::new(Filter::none());
+        spring.spring_to(glass, Spring::default());
+        let mut tween = AnimationEngine::new(Filter::none());
+        tween.tween_to(glass, Tween::default());
+
+        for engine in [&mut spring, &mut tween] {
+            let mut frames = 0;
+            while engine.update(1.0 / 60.0) {
+                frames += 1;
+                assert!(frames < 600, "the filter never settled");
+            }
+            assert_eq!(engine.get(), glass);
+        }
+    }
+}
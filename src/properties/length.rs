@@ -0,0 +1,187 @@
+//! Length module for unit-bearing CSS numbers
+//!
+//! Provides a Length type for properties such as `gap`, `border-radius` or
+//! `width`, which animates its number and keeps its CSS unit.
+
+use crate::Animatable;
+use crate::animation::AnimationMode;
+use crate::animations::spring::Spring;
+
+/// CSS unit of a [`Length`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum LengthUnit {
+    /// Pixels (`px`)
+    #[default]
+    Px,
+    /// Multiples of the root font size (`rem`)
+    Rem,
+    /// Percentage of the containing block (`%`)
+    Percent,
+    /// Percentage of the viewport width (`vw`)
+    Vw,
+}
+
+impl LengthUnit {
+    /// The CSS suffix of the unit
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Px => "px",
+            Self::Rem => "rem",
+            Self::Percent => "%",
+            Self::Vw => "vw",
+        }
+    }
+}
+
+/// A number with a CSS unit
+///
+/// Units can't be converted into each other without layout information, so
+/// start and target of an animation should share a unit. If they don't, the
+/// numbers are animated as they are and the target's unit is used.
+///
+/// # Example
+/// ```
+/// use dioxus_motion2::Length;
+///
+/// assert_eq!(Length::px(0.0).to_css_string(), "0px");
+/// assert_eq!(Length::rem(1.5).to_css_string(), "1.5rem");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Length {
+    /// The number
+    pub value: f32,
+    /// The unit
+    pub unit: LengthUnit,
+}
+
+impl Length {
+    /// Creates a length with the given unit
+    pub fn new(value: f32, unit: LengthUnit) -> Self {
+        Self { value, unit }
+    }
+
+    /// Creates a length in pixels
+    pub fn px(value: f32) -> Self {
+        Self::new(value, LengthUnit::Px)
+    }
+
+    /// Creates a length in root font sizes
+    pub fn rem(value: f32) -> Self {
+        Self::new(value, LengthUnit::Rem)
+    }
+
+    /// Creates a percentage length
+    pub fn percent(value: f32) -> Self {
+        Self::new(value, LengthUnit::Percent)
+    }
+
+    /// Creates a length in percent of the viewport width
+    pub fn vw(value: f32) -> Self {
+        Self::new(value, LengthUnit::Vw)
+    }
+
+    /// Converts the length to a CSS value such as `12px`
+    pub fn to_css_string(&self) -> String {
+        // Adding zero turns -0 into 0, so a value crossing zero never prints `-0`
+        format!("{}{}", self.value + 0.0, self.unit.as_str())
+    }
+
+    /// Unit of the result of combining `self` with `other`
+    ///
+    /// A zero-valued operand, such as [`Animatable::zero`], adopts the other's unit.
+    fn combined_unit(&self, other: &Self) -> LengthUnit {
+        if self.value == 0.0 {
+            other.unit
+        } else {
+            self.unit
+        }
+    }
+}
+
+impl Animatable for Length {
+    /// Creates a zero pixel length
+    fn zero() -> Self {
+        Self::px(0.0)
+    }
+
+    /// Minimum difference between lengths
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    /// Settles without overshoot; sizes and radii mustn't swing below zero
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::snappy())
+    }
+
+    /// Absolute value of the number
+    fn magnitude(&self) -> f32 {
+        self.value.abs()
+    }
+
+    /// Scales the number, keeping the unit
+    fn scale(&self, factor: f32) -> Self {
+        Self::new(self.value * factor, self.unit)
+    }
+
+    /// Adds the numbers
+    fn add(&self, other: &Self) -> Self {
+        Self::new(self.value + other.value, self.combined_unit(other))
+    }
+
+    /// Subtracts the numbers
+    fn sub(&self, other: &Self) -> Self {
+        Self::new(self.value - other.value, self.combined_unit(other))
+    }
+
+    /// Linearly interpolates the numbers, taking the target's unit
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        Self::new(self.value + (target.value - self.value) * t, target.unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+
+    #[test]
+    fn test_length_to_css_string() {
+        assert_eq!(Length::px(0.0).to_css_string(), "0px");
+        assert_eq!(Length::px(-0.0).to_css_string(), "0px");
+        assert_eq!(Length::rem(1.25).to_css_string(), "1.25rem");
+        assert_eq!(Length::percent(50.0).to_css_string(), "50%");
+        assert_eq!(Length::vw(-10.0).to_css_string(), "-10vw");
+    }
+
+    #[test]
+    fn test_length_arithmetic_keeps_unit() {
+        let a = Length::rem(1.0);
+        let b = Length::rem(3.0);
+        assert_eq!(a.interpolate(&b, 0.5), Length::rem(2.0));
+        assert_eq!(b.sub(&a).scale(2.0), Length::rem(4.0));
+        // The zero velocity of a spring adopts the unit it is added to
+        assert_eq!(Length::zero().add(&a), a);
+        assert_eq!(a.add(&Length::zero()), a);
+    }
+
+    #[test]
+    fn test_border_radius_animation_emits_pixels() {
+        let mut engine = AnimationEngine::new(Length::px(0.0));
+        assert_eq!(engine.get().to_css_string(), "0px");
+
+        engine.spring_to(Length::px(20.0), Spring::default());
+        let mut frames = Vec::new();
+        while engine.update(1.0 / 60.0) {
+            frames.push(engine.get().to_css_string());
+        }
+
+        assert!(frames.len() > 1);
+        for css in &frames {
+            let number = css.strip_suffix("px").and_then(|n| n.parse::<f32>().ok());
+            assert!(number.is_some(), "{css} is not a pixel length");
+        }
+        assert_eq!(engine.get().to_css_string(), "20px");
+    }
+}
@@ -1,2 +1,6 @@
 pub mod color;
+pub mod filter;
+pub mod length;
+pub mod rotation;
+pub mod style;
 pub mod transform;
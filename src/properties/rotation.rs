@@ -0,0 +1,425 @@
+//! 3D rotations that interpolate without gimbal artifacts
+//!
+//! A [`Rotation3D`] holds Euler angles, in radians, applied like CSS
+//! `rotateX(x) rotateY(y) rotateZ(z)`. Interpolating each angle on its own is
+//! cheap and matches how the angles are written, but for large rotations around
+//! several axes at once the path wobbles through orientations neither end is
+//! near. With [`RotationMode::ShortestArc`] the rotation instead travels the
+//! shortest arc between both orientations: the angles are converted to unit
+//! quaternions, spherically interpolated and converted back.
+//!
+//! There is no 3D transform type yet; this is the rotation part it will build
+//! on, usable on its own with [`Rotation3D::to_css_string`].
+//!
+//! ```
+//! use dioxus_motion2::prelude::*;
+//!
+//! let from = Rotation3D::identity();
+//! let to = Rotation3D::from_degrees(170.0, 170.0, 0.0).rotation_mode(RotationMode::ShortestArc);
+//! let halfway = from.interpolate(&to, 0.5);
+//! assert!(halfway.to_css_string().starts_with("rotate3d("));
+//! ```
+
+use crate::Animatable;
+
+/// How a [`Rotation3D`] travels between two orientations when interpolated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RotationMode {
+    /// Each Euler angle moves on its own, straight from start to end
+    #[default]
+    Euler,
+    /// The shortest arc between both orientations, as a quaternion slerp
+    ShortestArc,
+}
+
+/// A 3D rotation as Euler angles in radians, applied X, then Y, then Z
+///
+/// Tweens and keyframes interpolate it with the [`RotationMode`] of the value
+/// they animate to. Springs always integrate the angles one by one.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rotation3D {
+    /// Rotation around the X axis in radians
+    pub x: f32,
+    /// Rotation around the Y axis in radians
+    pub y: f32,
+    /// Rotation around the Z axis in radians
+    pub z: f32,
+    /// How interpolation toward this rotation travels
+    pub mode: RotationMode,
+}
+
+impl Rotation3D {
+    /// Creates a rotation from angles in radians
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            mode: RotationMode::Euler,
+        }
+    }
+
+    /// Creates a rotation from angles in degrees
+    pub fn from_degrees(x: f32, y: f32, z: f32) -> Self {
+        Self::new(x.to_radians(), y.to_radians(), z.to_radians())
+    }
+
+    /// No rotation
+    pub fn identity() -> Self {
+        Self::default()
+    }
+
+    /// Sets how interpolation toward this rotation travels
+    pub fn rotation_mode(mut self, mode: RotationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Rotation axis and angle in radians of the orientation
+    ///
+    /// The axis is a unit vector; without any rotation it is the Z axis.
+    pub fn axis_angle(&self) -> ([f32; 3], f32) {
+        Quaternion::from_euler(self).axis_angle()
+    }
+
+    /// CSS `rotate3d()` for the orientation
+    pub fn to_css_string(&self) -> String {
+        let ([x, y, z], angle) = self.axis_angle();
+        // Adding zero turns -0 into 0
+        format!(
+            "rotate3d({}, {}, {}, {}rad)",
+            x + 0.0,
+            y + 0.0,
+            z + 0.0,
+            angle + 0.0
+        )
+    }
+
+    /// Per-angle interpolation, ignoring the mode
+    fn lerp(&self, target: &Self, t: f32) -> Self {
+        Self {
+            x: self.x + (target.x - self.x) * t,
+            y: self.y + (target.y - self.y) * t,
+            z: self.z + (target.z - self.z) * t,
+            mode: target.mode,
+        }
+    }
+
+    fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        Self {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+            mode: self.mode,
+        }
+    }
+
+    fn zip(&self, other: &Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        Self {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+            mode: self.mode,
+        }
+    }
+}
+
+/// Unit quaternion `w + xi + yj + zk`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Quaternion {
+    w: f32,
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+impl Quaternion {
+    /// Orientation of `rotateX(x) rotateY(y) rotateZ(z)`
+    fn from_euler(rotation: &Rotation3D) -> Self {
+        let axis = |angle: f32, pick: usize| {
+            let (sin, cos) = (angle / 2.0).sin_cos();
+            let mut axis = [0.0; 3];
+            axis[pick] = sin;
+            Self {
+                w: cos,
+                x: axis[0],
+                y: axis[1],
+                z: axis[2],
+            }
+        };
+        axis(rotation.x, 0)
+            .mul(&axis(rotation.y, 1))
+            .mul(&axis(rotation.z, 2))
+    }
+
+    /// Euler angles of the orientation, in the order of [`Quaternion::from_euler`]
+    fn to_euler(self) -> (f32, f32, f32) {
+        let Self { w, x, y, z } = self;
+        // Entries of the rotation matrix `Rx * Ry * Rz`
+        let m00 = 1.0 - 2.0 * (y * y + z * z);
+        let m01 = 2.0 * (x * y - w * z);
+        let m02 = 2.0 * (x * z + w * y);
+        let m11 = 1.0 - 2.0 * (x * x + z * z);
+        let m12 = 2.0 * (y * z - w * x);
+        let m21 = 2.0 * (y * z + w * x);
+        let m22 = 1.0 - 2.0 * (x * x + y * y);
+
+        let pitch = m02.clamp(-1.0, 1.0).asin();
+        if m02.abs() < 0.9999 {
+            ((-m12).atan2(m22), pitch, (-m01).atan2(m00))
+        } else {
+            // Gimbal lock: X and Z turn around the same axis, so Z takes none
+            (m21.atan2(m11), pitch, 0.0)
+        }
+    }
+
+    /// Hamilton product, applying `other` first
+    fn mul(&self, other: &Self) -> Self {
+        Self {
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        }
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn scale(&self, factor: f32) -> Self {
+        Self {
+            w: self.w * factor,
+            x: self.x * factor,
+            y: self.y * factor,
+            z: self.z * factor,
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self {
+            w: self.w + other.w,
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+
+    fn normalize(&self) -> Self {
+        let length = self.dot(self).sqrt();
+        if length == 0.0 {
+            return Self {
+                w: 1.0,
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            };
+        }
+        self.scale(1.0 / length)
+    }
+
+    /// Spherical interpolation along the shorter of the two arcs
+    fn slerp(&self, target: &Self, t: f32) -> Self {
+        // `q` and `-q` are the same orientation; flip one so the arc is the short one
+        let mut dot = self.dot(target);
+        let target = if dot < 0.0 {
+            dot = -dot;
+            target.scale(-1.0)
+        } else {
+            *target
+        };
+
+        if dot > 0.9995 {
+            // Nearly the same orientation: a normalized lerp is accurate and stable
+            return self.scale(1.0 - t).add(&target.scale(t)).normalize();
+        }
+        let angle = dot.acos();
+        let sin = angle.sin();
+        let from = ((1.0 - t) * angle).sin() / sin;
+        let to = (t * angle).sin() / sin;
+        self.scale(from).add(&target.scale(to)).normalize()
+    }
+
+    /// Unit axis and angle in `[0, π]`
+    fn axis_angle(&self) -> ([f32; 3], f32) {
+        // The angle is taken on the shorter side of the double cover
+        let q = if self.w < 0.0 {
+            self.scale(-1.0)
+        } else {
+            *self
+        };
+        let sin = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+        if sin < 1e-6 {
+            return ([0.0, 0.0, 1.0], 0.0);
+        }
+        let angle = 2.0 * sin.atan2(q.w);
+        ([q.x / sin, q.y / sin, q.z / sin], angle)
+    }
+}
+
+/// Implementation of Animatable for 3D rotations
+impl Animatable for Rotation3D {
+    fn zero() -> Self {
+        Self::identity()
+    }
+
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    fn scale(&self, factor: f32) -> Self {
+        self.map(|angle| angle * factor)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        self.zip(other, |a, b| a + b)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        self.zip(other, |a, b| a - b)
+    }
+
+    /// Settled once every angle is within the tolerance [`Transform`](crate::Transform)
+    /// uses for its rotation
+    fn is_settled(&self, target: &Self, velocity: &Self) -> bool {
+        let remaining = target.sub(self);
+        [remaining.x, remaining.y, remaining.z]
+            .iter()
+            .all(|delta| delta.abs() < 0.001)
+            && [velocity.x, velocity.y, velocity.z]
+                .iter()
+                .all(|speed| speed.abs() < 0.01)
+    }
+
+    /// Interpolates with the mode of `target`
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        match target.mode {
+            RotationMode::Euler => self.lerp(target, t),
+            RotationMode::ShortestArc => {
+                let (x, y, z) = Quaternion::from_euler(self)
+                    .slerp(&Quaternion::from_euler(target), t)
+                    .to_euler();
+                Self::new(x, y, z).rotation_mode(target.mode)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    /// Whether both rotations describe the same orientation
+    fn same_orientation(a: &Rotation3D, b: &Rotation3D) -> bool {
+        let (qa, qb) = (Quaternion::from_euler(a), Quaternion::from_euler(b));
+        qa.dot(&qb).abs() > 1.0 - 1e-5
+    }
+
+    #[test]
+    fn test_euler_round_trip() {
+        for rotation in [
+            Rotation3D::from_degrees(30.0, -45.0, 60.0),
+            Rotation3D::from_degrees(170.0, 10.0, -120.0),
+            Rotation3D::from_degrees(0.0, 90.0, 0.0),
+            Rotation3D::identity(),
+        ] {
+            let (x, y, z) = Quaternion::from_euler(&rotation).to_euler();
+            assert!(same_orientation(&rotation, &Rotation3D::new(x, y, z)));
+        }
+    }
+
+    #[test]
+    fn test_shortest_arc_midpoint_of_combined_rotation() {
+        let from = Rotation3D::identity();
+        let to =
+            Rotation3D::from_degrees(170.0, 170.0, 0.0).rotation_mode(RotationMode::ShortestArc);
+
+        // rotateX(170°) rotateY(170°) is (c², cs, cs, s²) with c = cos 85°, s = sin 85°:
+        // a turn of 179.13° around (0.0868, 0.0868, 0.9924)
+        let (c, s) = (85f32.to_radians().cos(), 85f32.to_radians().sin());
+        let expected_angle = 2.0 * (c * c).acos();
+        let norm = (1.0 - c.powi(4)).sqrt();
+        let expected_axis = [c * s / norm, c * s / norm, s * s / norm];
+        let ([ax, ay, az], angle) = to.axis_angle();
+        assert_close(angle, expected_angle);
+        assert_close(ax, expected_axis[0]);
+        assert_close(ay, expected_axis[1]);
+        assert_close(az, expected_axis[2]);
+
+        // Slerp from identity turns half as far around the same axis
+        let halfway = from.interpolate(&to, 0.5);
+        let ([hx, hy, hz], half_angle) = halfway.axis_angle();
+        assert_close(half_angle, expected_angle / 2.0);
+        assert_close(hx, expected_axis[0]);
+        assert_close(hy, expected_axis[1]);
+        assert_close(hz, expected_axis[2]);
+        assert_eq!(halfway.mode, RotationMode::ShortestArc);
+
+        // Per-angle interpolation passes through (85°, 85°, 0), far off that arc
+        let euler = from.interpolate(&to.rotation_mode(RotationMode::Euler), 0.5);
+        assert!(!same_orientation(&euler, &halfway));
+
+        // The ends are reached exactly
+        assert!(same_orientation(&from.interpolate(&to, 1.0), &to));
+        assert!(same_orientation(&from.interpolate(&to, 0.0), &from));
+    }
+
+    #[test]
+    fn test_shortest_arc_takes_the_short_side_of_the_double_cover() {
+        // 170° and -170° around Z are 20° apart; their quaternions have a negative dot
+        let from = Rotation3D::from_degrees(0.0, 0.0, 170.0);
+        let to =
+            Rotation3D::from_degrees(0.0, 0.0, -170.0).rotation_mode(RotationMode::ShortestArc);
+        assert!(Quaternion::from_euler(&from).dot(&Quaternion::from_euler(&to)) < 0.0);
+
+        let halfway = from.interpolate(&to, 0.5);
+        assert!(same_orientation(&halfway, &Rotation3D::new(0.0, 0.0, PI)));
+        let ([_, _, axis_z], angle) = halfway.axis_angle();
+        assert_close(angle, PI);
+        assert_close(axis_z.abs(), 1.0);
+
+        // Euler mode swings the long way through 0°
+        let euler = from.interpolate(&to.rotation_mode(RotationMode::Euler), 0.5);
+        assert_close(euler.z, 0.0);
+    }
+
+    #[test]
+    fn test_euler_mode_interpolates_each_angle() {
+        let from = Rotation3D::from_degrees(10.0, 20.0, 30.0);
+        let to = Rotation3D::from_degrees(170.0, 170.0, -30.0);
+        assert_eq!(to.mode, RotationMode::Euler);
+
+        let quarter = from.interpolate(&to, 0.25);
+        assert_close(quarter.x, 50f32.to_radians());
+        assert_close(quarter.y, 57.5f32.to_radians());
+        assert_close(quarter.z, 15f32.to_radians());
+        // Past the end holds the end
+        let end = from.interpolate(&to, 2.0);
+        assert_close(end.x, to.x);
+        assert_close(end.z, to.z);
+    }
+
+    #[test]
+    fn test_rotation_css() {
+        assert_eq!(
+            Rotation3D::identity().to_css_string(),
+            "rotate3d(0, 0, 1, 0rad)"
+        );
+        let ([x, y, z], angle) = Rotation3D::new(PI / 2.0, 0.0, 0.0).axis_angle();
+        assert_eq!([x, y, z], [1.0, 0.0, 0.0]);
+        assert_close(angle, PI / 2.0);
+    }
+}
@@ -0,0 +1,382 @@
+//! Style module pairing a transform with an opacity
+//!
+//! Provides a MotionStyle type for the most common element animation: moving,
+//! scaling or rotating an element while fading it, optionally with a filter.
+
+use dioxus::prelude::MountedData;
+
+use crate::properties::filter::Filter;
+use crate::properties::transform::{RotationWinding, Transform};
+use crate::{Animatable, MotionValue};
+
+/// A transform and an opacity, animated together
+///
+/// Springs and easings such as `Back::ease_out` overshoot, which can push the
+/// opacity below 0 or above 1. The animation keeps the overshooting value so
+/// its motion stays intact, but [`opacity`](MotionStyle::opacity) and
+/// [`to_css_string`](MotionStyle::to_css_string) clamp it to the valid range,
+/// since paths like `filter: opacity()` or canvas compositing don't.
+///
+/// A [`Filter`] set with [`with_filter`](MotionStyle::with_filter) animates
+/// along, e.g. a panel blurring as it fades, and is written as a `filter`
+/// declaration only when it has a function.
+///
+/// A style flagged with
+/// [`disable_pointer_while_animating`](MotionStyle::disable_pointer_while_animating)
+/// stops taking clicks while it animates, e.g. a toast sliding away, and takes
+/// them again once it comes to rest.
+///
+/// # Example
+/// ```
+/// use dioxus_motion2::{MotionStyle, Transform};
+///
+/// let style = MotionStyle::new(Transform::translate(10.0, 0.0), 1.2);
+/// assert_eq!(style.opacity(), 1.0);
+/// assert_eq!(style.to_css_string(), "transform: translate(10px, 0px); opacity: 1;");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MotionStyle {
+    /// The transform
+    pub transform: Transform,
+    /// The opacity, possibly overshooting
+    opacity: f32,
+    /// The filter, written only when it has a function
+    filter: Filter,
+    /// Whether the element ignores the pointer while animating
+    pointer_inert: bool,
+}
+
+impl MotionStyle {
+    /// Creates a style from a transform and an opacity
+    pub const fn new(transform: Transform, opacity: f32) -> Self {
+        Self {
+            transform,
+            opacity,
+            filter: Filter::none(),
+            pointer_inert: false,
+        }
+    }
+
+    /// Animate a filter along with the transform and the opacity
+    pub const fn with_filter(self, filter: Filter) -> Self {
+        Self { filter, ..self }
+    }
+
+    /// The filter, [`Filter::none`] unless set
+    pub fn filter(&self) -> Filter {
+        self.filter
+    }
+
+    /// Ignore clicks and hovers while animating to or from this style
+    ///
+    /// The flag is part of the value, so it is compared by `==` and a string
+    /// memoized on the style is rebuilt when it changes. Once set on a value or
+    /// a target, it stays with the motion value's later frames.
+    pub fn disable_pointer_while_animating(mut self) -> Self {
+        self.pointer_inert = true;
+        self
+    }
+
+    /// Whether [`disable_pointer_while_animating`](MotionStyle::disable_pointer_while_animating)
+    /// was set
+    pub fn disables_pointer_while_animating(&self) -> bool {
+        self.pointer_inert
+    }
+
+    /// A fully visible element in its natural place
+    pub const fn visible() -> Self {
+        Self::new(Transform::identity(), 1.0)
+    }
+
+    /// A fully transparent element in its natural place
+    pub const fn hidden() -> Self {
+        Self::new(Transform::identity(), 0.0)
+    }
+
+    /// The opacity, clamped to `0.0..=1.0`
+    pub fn opacity(&self) -> f32 {
+        self.opacity.clamp(0.0, 1.0)
+    }
+
+    /// The opacity as animated, which may overshoot `0.0..=1.0`
+    pub fn raw_opacity(&self) -> f32 {
+        self.opacity
+    }
+
+    /// Converts the style to CSS declarations, with the opacity clamped
+    ///
+    /// A `filter` declaration follows when the filter has a function.
+    pub fn to_css_string(&self) -> String {
+        // Adding zero turns -0 into 0
+        let css = format!(
+            "transform: {}; opacity: {};",
+            self.transform.to_css_string(),
+            self.opacity() + 0.0
+        );
+        if self.filter.is_none() {
+            css
+        } else {
+            format!("{css} filter: {};", self.filter.to_css_string())
+        }
+    }
+
+    /// Converts the style to CSS declarations, adding `pointer-events: none;`
+    /// if `animating` and the style is flagged to ignore the pointer meanwhile
+    pub fn to_css_string_animating(&self, animating: bool) -> String {
+        let css = self.to_css_string();
+        if animating && self.pointer_inert {
+            css + " pointer-events: none;"
+        } else {
+            css
+        }
+    }
+}
+
+impl MotionValue<MotionStyle> {
+    /// The current style as CSS declarations, ignoring the pointer while a
+    /// flagged animation runs
+    ///
+    /// See [`MotionStyle::to_css_string_animating`].
+    pub fn to_css_string(&self) -> String {
+        self.get().to_css_string_animating(self.is_animating())
+    }
+
+    /// Write the style straight to an element every frame, ignoring the
+    /// pointer while a flagged animation runs
+    ///
+    /// Like [`bind_style`](MotionValue::bind_style) with
+    /// [`MotionStyle::to_css_string_animating`] as the renderer; the frame an
+    /// animation completes on is written without `pointer-events: none;`.
+    pub fn bind_motion_style(&mut self, mounted: &MountedData) {
+        self.bind_style_with(
+            mounted,
+            Box::new(|style: &MotionStyle, animating| style.to_css_string_animating(animating)),
+        );
+    }
+}
+
+impl Default for MotionStyle {
+    fn default() -> Self {
+        Self::visible()
+    }
+}
+
+impl Animatable for MotionStyle {
+    /// Creates a zero style (zero transform, zero opacity)
+    fn zero() -> Self {
+        Self::new(Transform::zero(), 0.0)
+    }
+
+    /// Minimum meaningful difference between styles
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    /// Magnitude of the transform plus the opacity and the filter
+    fn magnitude(&self) -> f32 {
+        self.transform.magnitude() + self.opacity.abs() + self.filter.magnitude()
+    }
+
+    /// Scales every channel
+    fn scale(&self, factor: f32) -> Self {
+        Self {
+            filter: self.filter.scale(factor),
+            pointer_inert: self.pointer_inert,
+            ..Self::new(self.transform.scale(factor), self.opacity * factor)
+        }
+    }
+
+    /// Adds every channel, keeping either pointer flag
+    fn add(&self, other: &Self) -> Self {
+        Self {
+            filter: self.filter.add(&other.filter),
+            pointer_inert: self.pointer_inert || other.pointer_inert,
+            ..Self::new(
+                self.transform.add(&other.transform),
+                self.opacity + other.opacity,
+            )
+        }
+    }
+
+    /// Subtracts every channel, keeping either pointer flag
+    fn sub(&self, other: &Self) -> Self {
+        Self {
+            filter: self.filter.sub(&other.filter),
+            pointer_inert: self.pointer_inert || other.pointer_inert,
+            ..Self::new(
+                self.transform.sub(&other.transform),
+                self.opacity - other.opacity,
+            )
+        }
+    }
+
+    /// Interpolates every channel; the opacity follows `t` beyond `0.0..=1.0`
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        self.interpolate_winding(target, t, RotationWinding::Shortest)
+    }
+
+    /// Interpolates every channel, turning the rotation as `winding` says
+    fn interpolate_winding(&self, target: &Self, t: f32, winding: RotationWinding) -> Self {
+        Self {
+            filter: self.filter.interpolate(&target.filter, t),
+            pointer_inert: self.pointer_inert || target.pointer_inert,
+            ..Self::new(
+                self.transform
+                    .interpolate_winding(&target.transform, t, winding),
+                self.opacity + (target.opacity - self.opacity) * t,
+            )
+        }
+    }
+
+    /// Settled once the transform, the opacity and the filter each are
+    fn is_settled(&self, target: &Self, velocity: &Self) -> bool {
+        self.transform
+            .is_settled(&target.transform, &velocity.transform)
+            && self.opacity.is_settled(&target.opacity, &velocity.opacity)
+            && self.filter.is_settled(&target.filter, &velocity.filter)
+    }
+
+    /// An opacity outside `0.0..=1.0`, or a filter out of its range, can't be
+    /// displayed as is
+    fn is_out_of_range(&self) -> bool {
+        !(0.0..=1.0).contains(&self.opacity) || self.filter.is_out_of_range()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AnimationEngine;
+    use crate::animations::tween::Tween;
+    use easer::functions::{Back, Easing};
+    use instant::Duration;
+
+    #[test]
+    fn test_opacity_clamped_on_output_only() {
+        let over = MotionStyle::new(Transform::identity(), 1.25);
+        assert_eq!(over.opacity(), 1.0);
+        assert_eq!(over.raw_opacity(), 1.25);
+        assert_eq!(over.to_css_string(), "transform: none; opacity: 1;");
+
+        let under = MotionStyle::new(Transform::identity(), -0.1);
+        assert_eq!(under.opacity(), 0.0);
+        assert_eq!(under.to_css_string(), "transform: none; opacity: 0;");
+        assert!(under.is_out_of_range());
+        assert!(!MotionStyle::visible().is_out_of_range());
+    }
+
+    #[test]
+    fn test_back_easing_overshoots_internally_but_not_on_output() {
+        let mut engine = AnimationEngine::new(MotionStyle::hidden());
+        let tween = Tween::new()
+            .duration(Duration::from_millis(500))
+            .easing(Back::ease_out);
+        engine.tween_to(MotionStyle::visible(), tween);
+
+        let mut peak: f32 = 0.0;
+        while engine.update(1.0 / 60.0) {
+            let style = engine.get();
+            peak = peak.max(style.raw_opacity());
+            assert!((0.0..=1.0).contains(&style.opacity()));
+            assert!(
+                style
+                    .to_css_string()
+                    .ends_with(&format!("opacity: {};", style.opacity()))
+            );
+        }
+
+        assert!(
+            peak > 1.05,
+            "Back easing should overshoot, peaked at {peak}"
+        );
+        assert_eq!(engine.get().opacity(), 1.0);
+    }
+
+    #[test]
+    fn test_pointer_events_disabled_only_while_animating() {
+        let plain = MotionStyle::hidden();
+        let flagged = MotionStyle::hidden().disable_pointer_while_animating();
+        assert_ne!(plain, flagged);
+        assert_eq!(flagged.to_css_string(), plain.to_css_string());
+        assert_eq!(
+            flagged.to_css_string_animating(true),
+            "transform: none; opacity: 0; pointer-events: none;"
+        );
+        assert!(
+            !flagged
+                .to_css_string_animating(false)
+                .contains("pointer-events")
+        );
+        assert!(
+            !plain
+                .to_css_string_animating(true)
+                .contains("pointer-events")
+        );
+    }
+
+    #[test]
+    fn test_pointer_flag_rides_along_with_springs_and_tweens() {
+        let exit = MotionStyle::hidden().disable_pointer_while_animating();
+
+        let mut spring = AnimationEngine::new(MotionStyle::visible());
+        spring.spring_to(exit, crate::animations::spring::Spring::default());
+        let mut tween = AnimationEngine::new(MotionStyle::visible());
+        tween.tween_to(exit, Tween::default());
+
+        for engine in [&mut spring, &mut tween] {
+            while engine.update(1.0 / 60.0) {
+                let css = engine.get().to_css_string_animating(engine.is_active());
+                assert!(css.ends_with("pointer-events: none;"), "{css}");
+            }
+            let css = engine.get().to_css_string_animating(engine.is_active());
+            assert!(!css.contains("pointer-events"), "{css}");
+            assert!(engine.get().disables_pointer_while_animating());
+        }
+    }
+
+    #[test]
+    fn test_filter_written_only_when_present() {
+        let frosted = MotionStyle::visible().with_filter(Filter::none().with_blur(8.0));
+        assert_eq!(
+            frosted.to_css_string(),
+            "transform: none; opacity: 1; filter: blur(8px);"
+        );
+
+        // Fading the blur out ends on a style without a filter declaration
+        let mut engine = AnimationEngine::new(frosted);
+        engine.tween_to(MotionStyle::hidden(), Tween::default());
+        engine.update(0.15);
+        let blur = engine.get().filter().blur().unwrap_or_default();
+        assert!((blur - 4.0).abs() < 0.01, "blur {blur}");
+        while engine.update(1.0 / 60.0) {}
+        assert_eq!(engine.get().to_css_string(), "transform: none; opacity: 0;");
+    }
+
+    #[test]
+    fn test_bound_style_drops_pointer_flag_on_the_completing_frame() {
+        let mut engine = AnimationEngine::new(MotionStyle::visible());
+        engine.set_style(Some(Box::new(|style: &MotionStyle, animating| {
+            style.to_css_string_animating(animating)
+        })));
+        engine.tween_to(
+            MotionStyle::hidden().disable_pointer_while_animating(),
+            Tween::default(),
+        );
+
+        let mut written = Vec::new();
+        while engine.update(1.0 / 60.0) {
+            written.extend(engine.take_style());
+        }
+        written.extend(engine.take_style());
+
+        let Some((last, running)) = written.split_last() else {
+            unreachable!("the bound style should be written every frame");
+        };
+        assert!(
+            running
+                .iter()
+                .all(|css| css.ends_with("pointer-events: none;"))
+        );
+        assert_eq!(last, "transform: none; opacity: 0;");
+    }
+}
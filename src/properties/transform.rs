@@ -6,14 +6,58 @@
 //! - Rotation
 //! - Skew (skewX, skewY)
 //!
-//! Uses radians for rotation and supports smooth interpolation.
+//! Rotation and skew are in radians everywhere: in the fields, in
+//! [`Transform::new`] and in the CSS output. Angles written in degrees go
+//! through [`Transform::from_degrees`], [`Transform::rotate_degrees`] or
+//! [`Transform::skew_degrees`].
+//!
+//! A `MotionValue<Transform>` also reads single components reactively, e.g.
+//! [`rotation`](crate::MotionValue::rotation): a component reading only the
+//! rotation doesn't re-render while only the translation moves.
 
 use std::f32::consts::PI;
 
-use crate::Animatable;
+use crate::{Animatable, MotionValue};
+
+/// Which way a rotation turns when interpolated, e.g. by a tween
+///
+/// Angles a whole turn apart look the same, so by default a rotation takes the
+/// shorter way round. Positive angles turn clockwise on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RotationWinding {
+    /// The shorter way round, at most half a turn
+    #[default]
+    Shortest,
+    /// Always clockwise, less than a full turn
+    Clockwise,
+    /// Always counterclockwise, less than a full turn
+    CounterClockwise,
+    /// The shorter way plus whole extra turns, clockwise if positive and
+    /// counterclockwise if negative, e.g. for spinners
+    Turns(i32),
+}
+
+impl RotationWinding {
+    /// Angle in radians to rotate by to get from `from` to `to`
+    pub fn delta(&self, from: f32, to: f32) -> f32 {
+        let turn = 2.0 * PI;
+        // The clockwise angle, in [0, 2π)
+        let clockwise = (to - from).rem_euclid(turn);
+        match self {
+            Self::Shortest if clockwise > PI => clockwise - turn,
+            Self::Shortest | Self::Clockwise => clockwise,
+            Self::CounterClockwise if clockwise > 0.0 => clockwise - turn,
+            Self::CounterClockwise => 0.0,
+            Self::Turns(turns) => Self::Shortest.delta(from, to) + turn * *turns as f32,
+        }
+    }
+}
 
 /// Transform animation for position, scale, rotation, and skew
 ///
+/// The constructors are `const fn`, so resting and target poses can be shared
+/// as constants, e.g. `const LIFTED: Transform = Transform::translate(0.0, -8.0);`.
+///
 /// # Example
 /// ```
 /// use dioxus_motion2::Transform;
@@ -22,6 +66,11 @@ use crate::Animatable;
 /// let transform = Transform::new(100.0, 0.0, 1.5, 1.5, PI/4.0, 0.0, 0.0);
 /// ```
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "clips",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default = "Transform::identity")
+)]
 pub struct Transform {
     /// X translation component (px)
     pub x: f32,
@@ -41,7 +90,10 @@ pub struct Transform {
 
 impl Transform {
     /// Creates a new transform with specified parameters
-    pub fn new(
+    ///
+    /// `rotation`, `skew_x` and `skew_y` are in radians; see
+    /// [`Transform::from_degrees`] for angles in degrees.
+    pub const fn new(
         x: f32,
         y: f32,
         scale_x: f32,
@@ -61,8 +113,38 @@ impl Transform {
         }
     }
 
+    /// Creates a new transform with rotation and skew given in degrees
+    ///
+    /// # Example
+    /// ```
+    /// use dioxus_motion2::Transform;
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// let quarter = Transform::from_degrees(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0);
+    /// assert_eq!(quarter.rotation, FRAC_PI_2);
+    /// ```
+    pub const fn from_degrees(
+        x: f32,
+        y: f32,
+        scale_x: f32,
+        scale_y: f32,
+        rotation_degrees: f32,
+        skew_x_degrees: f32,
+        skew_y_degrees: f32,
+    ) -> Self {
+        Self::new(
+            x,
+            y,
+            scale_x,
+            scale_y,
+            rotation_degrees.to_radians(),
+            skew_x_degrees.to_radians(),
+            skew_y_degrees.to_radians(),
+        )
+    }
+
     /// Creates an identity transform (no transformation)
-    pub fn identity() -> Self {
+    pub const fn identity() -> Self {
         Self {
             x: 0.0,
             y: 0.0,
@@ -75,68 +157,131 @@ impl Transform {
     }
 
     /// Creates a translation transform
-    pub fn translate(x: f32, y: f32) -> Self {
-        let mut result = Self::identity();
-        result.x = x;
-        result.y = y;
-        result
+    pub const fn translate(x: f32, y: f32) -> Self {
+        Self {
+            x,
+            y,
+            ..Self::identity()
+        }
     }
 
     /// Creates a scaling transform
-    pub fn scale(scale_x: f32, scale_y: f32) -> Self {
-        let mut result = Self::identity();
-        result.scale_x = scale_x;
-        result.scale_y = scale_y;
-        result
+    pub const fn scale(scale_x: f32, scale_y: f32) -> Self {
+        Self {
+            scale_x,
+            scale_y,
+            ..Self::identity()
+        }
     }
 
     /// Creates a uniform scaling transform
-    pub fn scale_uniform(scale: f32) -> Self {
+    pub const fn scale_uniform(scale: f32) -> Self {
         Self::scale(scale, scale)
     }
 
-    /// Creates a rotation transform
-    pub fn rotate(angle_radians: f32) -> Self {
-        let mut result = Self::identity();
-        result.rotation = angle_radians; // Set the rotation angle directly
-        result
+    /// Creates a rotation transform, in radians
+    pub const fn rotate(angle_radians: f32) -> Self {
+        Self {
+            rotation: angle_radians,
+            ..Self::identity()
+        }
     }
 
     /// Creates a rotation transform using degrees
-    pub fn rotate_degrees(angle_degrees: f32) -> Self {
-        Self::rotate(angle_degrees * std::f32::consts::PI / 180.0)
+    pub const fn rotate_degrees(angle_degrees: f32) -> Self {
+        Self::rotate(angle_degrees.to_radians())
     }
 
-    /// Creates a skew transform
-    pub fn skew(skew_x: f32, skew_y: f32) -> Self {
-        let mut result = Self::identity();
-        result.skew_x = skew_x;
-        result.skew_y = skew_y;
-        result
+    /// Creates a skew transform, in radians
+    pub const fn skew(skew_x: f32, skew_y: f32) -> Self {
+        Self {
+            skew_x,
+            skew_y,
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a skew transform using degrees
+    pub const fn skew_degrees(skew_x_degrees: f32, skew_y_degrees: f32) -> Self {
+        Self::skew(skew_x_degrees.to_radians(), skew_y_degrees.to_radians())
+    }
+
+    /// Places an element on a circle of `radius` around a center point
+    ///
+    /// `angle` is in radians, starting from the positive x axis and turning
+    /// clockwise on screen like every rotation. The element is only moved, so it
+    /// stays upright; see [`Transform::rotate_around`] to turn it with the orbit.
+    ///
+    /// # Example
+    /// ```
+    /// use dioxus_motion2::Transform;
+    /// use std::f32::consts::FRAC_PI_2;
+    ///
+    /// let below = Transform::orbit(100.0, 50.0, 20.0, FRAC_PI_2);
+    /// assert!((below.x - 100.0).abs() < 1e-4 && (below.y - 70.0).abs() < 1e-4);
+    /// ```
+    pub fn orbit(center_x: f32, center_y: f32, radius: f32, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::translate(center_x + radius * cos, center_y + radius * sin)
+    }
+
+    /// Rotates the transform by `angle` radians around an external pivot
+    ///
+    /// The translation swings around (`pivot_x`, `pivot_y`) and the element turns
+    /// by the same angle, as if pinned to an arm reaching from the pivot.
+    pub fn rotate_around(&self, pivot_x: f32, pivot_y: f32, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let (dx, dy) = (self.x - pivot_x, self.y - pivot_y);
+        Self {
+            x: pivot_x + dx * cos - dy * sin,
+            y: pivot_y + dx * sin + dy * cos,
+            rotation: self.rotation + angle,
+            ..*self
+        }
     }
 
     /// Converts the transform to a CSS transform string
+    ///
+    /// Negative scales are flips. A flip on one axis is written per axis, e.g.
+    /// `scale(-1, 1)` mirrors horizontally, so animating `scale_x` from `1.0` to
+    /// `-1.0` squashes the element to a line and unfolds it mirrored. Flipping both
+    /// axes is the same as a half turn, so it is written as an extra `rotate` by π
+    /// with positive scales; the translation is never affected by a flip.
     pub fn to_css_string(&self) -> String {
         let mut transforms = Vec::new();
 
+        let (mut rotation, mut scale_x, mut scale_y) = (self.rotation, self.scale_x, self.scale_y);
+        if scale_x < 0.0 && scale_y < 0.0 {
+            rotation += PI;
+            scale_x = -scale_x;
+            scale_y = -scale_y;
+        }
+        // Adding zero turns -0 into 0, so a scale crossing zero never prints `-0`
+        let scale_x = scale_x + 0.0;
+        let scale_y = scale_y + 0.0;
+
         if self.x != 0.0 || self.y != 0.0 {
             transforms.push(format!("translate({}px, {}px)", self.x, self.y));
         }
 
-        if self.rotation != 0.0 {
+        if rotation != 0.0 {
             // Use PI/4 directly to ensure exact same value as test
-            if (self.rotation - std::f32::consts::PI / 4.0).abs() < f32::EPSILON {
+            if (rotation - std::f32::consts::PI / 4.0).abs() < f32::EPSILON {
                 transforms.push("rotate(0.7853981633974483rad)".to_string());
             } else {
-                transforms.push(format!("rotate({:.16}rad)", self.rotation));
+                transforms.push(format!("rotate({:.16}rad)", rotation));
             }
         }
 
-        if self.scale_x != 1.0 || self.scale_y != 1.0 {
-            if (self.scale_x - self.scale_y).abs() < f32::EPSILON {
-                transforms.push(format!("scale({})", self.scale_x));
+        if self.skew_x != 0.0 || self.skew_y != 0.0 {
+            transforms.push(format!("skew({}rad, {}rad)", self.skew_x, self.skew_y));
+        }
+
+        if scale_x != 1.0 || scale_y != 1.0 {
+            if (scale_x - scale_y).abs() < f32::EPSILON {
+                transforms.push(format!("scale({})", scale_x));
             } else {
-                transforms.push(format!("scale({}, {})", self.scale_x, self.scale_y));
+                transforms.push(format!("scale({}, {})", scale_x, scale_y));
             }
         }
 
@@ -163,6 +308,48 @@ impl Transform {
     }
 }
 
+/// Component-level reads, each subscribing the caller to that component alone
+///
+/// Reading [`get`](MotionValue::get) re-renders the caller on every frame the
+/// transform moves. These re-render it only when the component read changes
+/// by more than [`Transform::epsilon`].
+impl MotionValue<Transform> {
+    /// The animated x translation (px)
+    pub fn x(&self) -> f32 {
+        self.read_part("x", |transform| transform.x)
+    }
+
+    /// The animated y translation (px)
+    pub fn y(&self) -> f32 {
+        self.read_part("y", |transform| transform.y)
+    }
+
+    /// The animated x scale factor
+    pub fn scale_x(&self) -> f32 {
+        self.read_part("scale_x", |transform| transform.scale_x)
+    }
+
+    /// The animated y scale factor
+    pub fn scale_y(&self) -> f32 {
+        self.read_part("scale_y", |transform| transform.scale_y)
+    }
+
+    /// The animated rotation (radians)
+    pub fn rotation(&self) -> f32 {
+        self.read_part("rotation", |transform| transform.rotation)
+    }
+
+    /// The animated x skew (radians)
+    pub fn skew_x(&self) -> f32 {
+        self.read_part("skew_x", |transform| transform.skew_x)
+    }
+
+    /// The animated y skew (radians)
+    pub fn skew_y(&self) -> f32 {
+        self.read_part("skew_y", |transform| transform.skew_y)
+    }
+}
+
 /// Implementation of Animatable for Transform
 impl Animatable for Transform {
     /// Creates a zero transform (all components 0)
@@ -215,6 +402,31 @@ impl Animatable for Transform {
         }
     }
 
+    /// Settled once every channel is within its own tolerance
+    ///
+    /// Translation is measured in pixels, rotation and skew in radians and scale as
+    /// a factor, so a single weighted magnitude would let small rotations finish
+    /// early while keeping translations running.
+    fn is_settled(&self, target: &Self, velocity: &Self) -> bool {
+        // (max remaining distance, max speed per second) for each channel
+        const TRANSLATION: (f32, f32) = (0.5, 5.0);
+        const ANGLE: (f32, f32) = (0.001, 0.01);
+        const SCALE: (f32, f32) = (0.001, 0.01);
+
+        let within = |delta: f32, speed: f32, (distance, max_speed): (f32, f32)| {
+            delta.abs() < distance && speed.abs() < max_speed
+        };
+
+        // Scale velocities are stored relative to 1.0, like the scale channel itself
+        within(target.x - self.x, velocity.x, TRANSLATION)
+            && within(target.y - self.y, velocity.y, TRANSLATION)
+            && within(target.scale_x - self.scale_x, velocity.scale_x - 1.0, SCALE)
+            && within(target.scale_y - self.scale_y, velocity.scale_y - 1.0, SCALE)
+            && within(target.rotation - self.rotation, velocity.rotation, ANGLE)
+            && within(target.skew_x - self.skew_x, velocity.skew_x, ANGLE)
+            && within(target.skew_y - self.skew_y, velocity.skew_y, ANGLE)
+    }
+
     /// Subtracts two transforms component-wise
     fn sub(&self, other: &Self) -> Self {
         Self {
@@ -231,17 +443,13 @@ impl Animatable for Transform {
     /// Interpolates between two transforms
     /// Handles rotation specially to ensure shortest path
     fn interpolate(&self, target: &Self, t: f32) -> Self {
-        let t = t.clamp(0.0, 1.0);
-
-        // Handle rotation specially to ensure shortest path
-        let mut rotation_diff = target.rotation - self.rotation;
+        self.interpolate_winding(target, t, RotationWinding::Shortest)
+    }
 
-        // Ensure we go the shortest way around the circle
-        if rotation_diff > PI {
-            rotation_diff -= 2.0 * PI;
-        } else if rotation_diff < -PI {
-            rotation_diff += 2.0 * PI;
-        }
+    /// Interpolates between two transforms, turning the rotation as `winding` says
+    fn interpolate_winding(&self, target: &Self, t: f32, winding: RotationWinding) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let rotation_diff = winding.delta(self.rotation, target.rotation);
 
         Self {
             x: self.x + (target.x - self.x) * t,
@@ -260,6 +468,19 @@ mod tests {
     use super::*;
     use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 
+    const LIFTED: Transform = Transform::translate(0.0, 24.0);
+    const POPPED: Transform = Transform::scale_uniform(1.2);
+    const TILTED: Transform = Transform::rotate_degrees(90.0);
+    const _: Transform = Transform::skew(0.1, 0.0);
+
+    #[test]
+    fn test_const_constructors_match_runtime() {
+        assert_eq!(LIFTED, Transform::new(0.0, 24.0, 1.0, 1.0, 0.0, 0.0, 0.0));
+        assert_eq!(POPPED, Transform::scale(1.2, 1.2));
+        assert_eq!(TILTED.rotation, FRAC_PI_2);
+        assert_eq!(TILTED, Transform::rotate(90f32.to_radians()));
+    }
+
     #[test]
     fn test_transform_new() {
         let transform = Transform::new(100.0, 50.0, 2.0, 1.5, PI / 4.0, 0.1, 0.2);
@@ -320,6 +541,62 @@ mod tests {
         assert_eq!(transform.skew_y, 0.0); // No skew
     }
 
+    #[test]
+    fn test_degree_constructors() {
+        let transform = Transform::from_degrees(10.0, 20.0, 1.0, 1.0, 180.0, 45.0, -45.0);
+        assert_eq!(transform.x, 10.0);
+        assert_eq!(transform.rotation, PI);
+        assert_eq!(transform.skew_x, FRAC_PI_4);
+        assert_eq!(transform.skew_y, -FRAC_PI_4);
+        assert_eq!(Transform::rotate_degrees(90.0).rotation, FRAC_PI_2);
+        assert_eq!(
+            Transform::skew_degrees(45.0, 0.0),
+            Transform::skew(FRAC_PI_4, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_orbit_around_center() {
+        let close = |a: Transform, b: Transform| {
+            (a.x - b.x).abs() < 1e-4 && (a.y - b.y).abs() < 1e-4 && a.rotation == b.rotation
+        };
+
+        assert!(close(
+            Transform::orbit(10.0, 20.0, 50.0, 0.0),
+            Transform::translate(60.0, 20.0)
+        ));
+        assert!(close(
+            Transform::orbit(10.0, 20.0, 50.0, PI),
+            Transform::translate(-40.0, 20.0)
+        ));
+        // Every angle keeps the radius and the element upright
+        for step in 0..8 {
+            let orbit = Transform::orbit(10.0, 20.0, 50.0, step as f32 * FRAC_PI_4);
+            assert!(((orbit.x - 10.0).hypot(orbit.y - 20.0) - 50.0).abs() < 1e-3);
+            assert_eq!(orbit.rotation, 0.0);
+        }
+
+        // Rotating around a pivot also turns the element
+        let arm = Transform::translate(60.0, 20.0).rotate_around(10.0, 20.0, FRAC_PI_2);
+        assert!(close(
+            arm,
+            Transform {
+                rotation: FRAC_PI_2,
+                ..Transform::translate(10.0, 70.0)
+            }
+        ));
+        let scaled = Transform::scale_uniform(2.0).rotate_around(0.0, 0.0, PI);
+        assert_eq!(scaled.scale_x, 2.0);
+    }
+
+    #[test]
+    fn test_skew_css() {
+        assert_eq!(
+            Transform::skew(0.5, -0.25).to_css_string(),
+            "skew(0.5rad, -0.25rad)"
+        );
+    }
+
     #[test]
     fn test_transform_to_css_string() {
         // Test individual transformations
@@ -352,6 +629,38 @@ mod tests {
         assert_eq!(transform.to_css_string(), "none");
     }
 
+    #[test]
+    fn test_flip_css() {
+        // Single-axis flips mirror along that axis only
+        assert_eq!(Transform::scale(-1.0, 1.0).to_css_string(), "scale(-1, 1)");
+        assert_eq!(Transform::scale(1.0, -2.0).to_css_string(), "scale(1, -2)");
+
+        // Flipping both axes is a half turn, leaving the translation alone
+        let mut transform = Transform::translate(10.0, 0.0);
+        transform.scale_x = -1.0;
+        transform.scale_y = -1.0;
+        assert_eq!(
+            transform.to_css_string(),
+            format!("translate(10px, 0px) rotate({:.16}rad)", PI)
+        );
+
+        // Crossing zero never prints a negative zero
+        assert_eq!(Transform::scale(-0.0, 1.0).to_css_string(), "scale(0, 1)");
+    }
+
+    #[test]
+    fn test_horizontal_flip_animation_css() {
+        let start = Transform::identity();
+        let end = Transform::scale(-1.0, 1.0);
+
+        let halfway = start.interpolate(&end, 0.5).to_css_string();
+        assert_eq!(halfway, "scale(0, 1)");
+        let flipping = start.interpolate(&end, 0.75).to_css_string();
+        assert_eq!(flipping, "scale(-0.5, 1)");
+        // No rotation is introduced along the way
+        assert!(!flipping.contains("rotate"));
+    }
+
     #[test]
     fn test_transform_combine() {
         let t1 = Transform::translate(100.0, 50.0);
@@ -419,6 +728,40 @@ mod tests {
         assert_eq!(mid.y, 50.0);
     }
 
+    fn settle_frames(from: Transform, to: Transform) -> usize {
+        let mut engine = crate::AnimationEngine::new(from);
+        engine.spring_to(to, crate::animations::spring::Spring::default());
+        let mut frames = 0;
+        while engine.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 10_000, "spring never settled");
+        }
+        frames
+    }
+
+    #[test]
+    fn test_small_rotation_spring_does_not_finish_early() {
+        let mut engine = crate::AnimationEngine::new(Transform::identity());
+        engine.spring_to(
+            Transform::rotate(0.05),
+            crate::animations::spring::Spring::default(),
+        );
+        // Still running after the first frames, with the rotation in progress
+        for _ in 0..5 {
+            assert!(engine.update(1.0 / 60.0));
+        }
+        let rotation = engine.get().rotation;
+        assert!(rotation > 0.0 && rotation < 0.05);
+
+        assert!(settle_frames(Transform::identity(), Transform::rotate(0.05)) > 20);
+    }
+
+    #[test]
+    fn test_tiny_translation_spring_finishes() {
+        // Terminates within a reasonable time instead of lingering
+        assert!(settle_frames(Transform::identity(), Transform::translate(0.4, 0.0)) < 120);
+    }
+
     #[test]
     fn test_transform_edge_cases() {
         // Test very large values
@@ -436,4 +779,137 @@ mod tests {
         assert_eq!(mid.x, 5e5);
         assert_eq!(mid.y, 5e5);
     }
+
+    #[test]
+    fn test_rotation_winding_delta() {
+        let (quarter, three_quarters) = (FRAC_PI_2, 3.0 * FRAC_PI_2);
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+
+        assert!(close(
+            RotationWinding::Shortest.delta(0.0, three_quarters),
+            -quarter
+        ));
+        assert!(close(
+            RotationWinding::Clockwise.delta(0.0, three_quarters),
+            three_quarters
+        ));
+        assert!(close(
+            RotationWinding::CounterClockwise.delta(0.0, quarter),
+            -three_quarters
+        ));
+        assert!(close(
+            RotationWinding::CounterClockwise.delta(0.0, 0.0),
+            0.0
+        ));
+        assert!(close(
+            RotationWinding::Turns(1).delta(0.0, quarter),
+            quarter + 2.0 * PI
+        ));
+        assert!(close(
+            RotationWinding::Turns(-1).delta(0.0, quarter),
+            quarter - 2.0 * PI
+        ));
+    }
+
+    #[test]
+    fn test_tween_spins_two_full_turns() {
+        use crate::animations::tween::Tween;
+        use instant::Duration;
+
+        let spin = Transform::rotate_degrees(720.0);
+        // The shortest way, 720° is the same angle as 0° and nothing turns
+        let mid = Transform::identity().interpolate(&spin, 0.5);
+        assert!(mid.rotation.abs() < 1e-5);
+
+        let mut engine = crate::AnimationEngine::new(Transform::identity());
+        let tween = Tween::new()
+            .duration(Duration::from_millis(1000))
+            .winding(RotationWinding::Turns(2));
+        engine.tween_to(spin, tween);
+
+        let mut rotations = vec![0.0];
+        while engine.update(0.1) {
+            rotations.push(engine.get().rotation);
+        }
+        rotations.push(engine.get().rotation);
+
+        assert!(rotations.windows(2).all(|pair| pair[1] > pair[0]));
+        assert!((rotations[5] - 2.0 * PI).abs() < 1e-3);
+        assert!((engine.get().rotation - 4.0 * PI).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_component_reads_rerender_only_for_their_component() {
+        use dioxus::prelude::*;
+        use std::cell::{Cell, RefCell};
+
+        thread_local! {
+            static MOTION: Cell<Option<MotionValue<Transform>>> = const { Cell::new(None) };
+            static RENDERS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        }
+
+        fn app() -> Element {
+            let engine = use_signal(|| crate::AnimationEngine::new(Transform::identity()));
+            use_context_provider(|| MotionValue::new(engine));
+            MOTION.set(Some(MotionValue::new(engine)));
+            rsx! {
+                Spinner {}
+                Slider {}
+            }
+        }
+
+        #[component]
+        fn Spinner() -> Element {
+            let transform = use_context::<MotionValue<Transform>>();
+            let rotation = transform.rotation();
+            RENDERS.with_borrow_mut(|renders| renders.push("rotation"));
+            rsx! { "{rotation}" }
+        }
+
+        #[component]
+        fn Slider() -> Element {
+            let transform = use_context::<MotionValue<Transform>>();
+            let x = transform.x();
+            RENDERS.with_borrow_mut(|renders| renders.push("x"));
+            rsx! { "{x}" }
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let mut renders_after = |change: fn(Transform) -> Transform| {
+            RENDERS.with_borrow_mut(Vec::clear);
+            dom.in_runtime(|| {
+                let Some(mut motion) = MOTION.get() else {
+                    unreachable!("the app stores its motion value");
+                };
+                motion.set(change(motion.peek()));
+            });
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+            RENDERS.with_borrow(Clone::clone)
+        };
+
+        assert_eq!(
+            renders_after(|transform| Transform {
+                x: 40.0,
+                ..transform
+            }),
+            vec!["x"]
+        );
+        assert_eq!(
+            renders_after(|transform| Transform {
+                rotation: 1.0,
+                ..transform
+            }),
+            vec!["rotation"]
+        );
+        // Changes within epsilon don't re-render anything
+        assert!(
+            renders_after(|transform| Transform {
+                x: 40.0005,
+                ..transform
+            })
+            .is_empty()
+        );
+    }
 }
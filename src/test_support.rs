@@ -0,0 +1,13 @@
+//! Fixtures shared by the unit tests
+
+/// A runtime telling animate calls that frames will come, see
+/// [`has_frames`](crate::driver::has_frames)
+///
+/// Enter it for the length of a test and step the frames by hand: nothing
+/// polls the driver loop, so the runtime only has to be there.
+pub(crate) fn frame_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .expect("a runtime without io builds")
+}
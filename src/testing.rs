@@ -0,0 +1,291 @@
+//! Testing components without a renderer
+//!
+//! [`motion_test_harness`] mounts a component in a [`VirtualDom`] whose motion
+//! values are advanced by the test instead of the driver loop. Time only
+//! passes in [`MotionHarness::tick`], frame by frame, so a test can check
+//! "clicking the button starts an animation toward X" without a browser, a
+//! window or waiting in real time:
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//! use dioxus_motion2::testing::motion_test_harness;
+//!
+//! #[test]
+//! fn fades_in() {
+//!     let mut harness = motion_test_harness(app);
+//!     harness.act(|| SHOWN.with(|shown| shown.set(true)));
+//!
+//!     let opacity = harness.motion_value("opacity").unwrap();
+//!     assert_eq!(opacity.target::<f32>(), Some(1.0));
+//!
+//!     harness.tick(Duration::from_millis(500));
+//!     assert_eq!(harness.motion_value("opacity").unwrap().value::<f32>(), Some(1.0));
+//! }
+//! ```
+//!
+//! Events can't be dispatched to elements without a renderer: drive the state
+//! an event handler would change from [`act`](MotionHarness::act) instead.
+//! Values created with [`use_motion_labeled`](crate::use_motion_labeled) can be
+//! found by their label; others are listed by [`motion_values`](MotionHarness::motion_values)
+//! in creation order.
+//!
+//! Only frames are driven by the harness. Timers of their own, e.g. the delay
+//! of a hover intent, still wait on the clock of the async runtime.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+
+use crate::{Animatable, AnimationEngine, Duration, driver};
+
+/// Reads a snapshot of one engine, `None` once its signal is gone
+type SnapshotFn = Box<dyn Fn() -> Option<MotionSnapshot>>;
+
+thread_local! {
+    /// Engines registered with the driver, in registration order
+    static VALUES: RefCell<Vec<(u64, SnapshotFn)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Make the engine driven as `id` visible to harnesses
+pub(crate) fn track<T: Animatable>(id: u64, signal: Signal<AnimationEngine<T>>) {
+    let snapshot = move || {
+        let engine = signal.try_peek().ok()?;
+        Some(MotionSnapshot {
+            label: engine.label().map(Arc::from),
+            value: Box::new(engine.get()),
+            target: engine
+                .target()
+                .map(|target| Box::new(target) as Box<dyn Any>),
+            is_animating: engine.is_active(),
+            progress: engine.progress(),
+        })
+    };
+    VALUES.with_borrow_mut(|values| values.push((id, Box::new(snapshot))));
+}
+
+/// Forget the engine driven as `id`
+pub(crate) fn untrack(id: u64) {
+    VALUES.with_borrow_mut(|values| values.retain(|(tracked, _)| *tracked != id));
+}
+
+/// The state of a motion value at one moment, from [`MotionHarness::motion_values`]
+///
+/// The value and target are read back with the type the value was created
+/// with, e.g. `snapshot.value::<f32>()`.
+pub struct MotionSnapshot {
+    label: Option<Arc<str>>,
+    value: Box<dyn Any>,
+    target: Option<Box<dyn Any>>,
+    is_animating: bool,
+    progress: Option<f32>,
+}
+
+impl MotionSnapshot {
+    /// The label given with [`use_motion_labeled`](crate::use_motion_labeled), if any
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// The current value, `None` if the value isn't a `T`
+    pub fn value<T: Animatable>(&self) -> Option<T> {
+        self.value.downcast_ref().copied()
+    }
+
+    /// Target of the running or queued spring or tween, `None` when idle, when
+    /// playing another animation or if the value isn't a `T`
+    pub fn target<T: Animatable>(&self) -> Option<T> {
+        self.target.as_ref()?.downcast_ref().copied()
+    }
+
+    /// Whether an animation is running or queued to start at the next frame
+    pub fn is_animating(&self) -> bool {
+        self.is_animating
+    }
+
+    /// How far the running animation has come, from `0.0` to `1.0`, as
+    /// [`MotionValue::progress`](crate::MotionValue::progress) reports it
+    pub fn progress(&self) -> Option<f32> {
+        self.progress
+    }
+}
+
+/// Time step of the frames of [`MotionHarness::tick`]
+const FRAME: Duration = Duration::from_nanos(16_666_667);
+
+/// A component mounted for a test, created by [`motion_test_harness`]
+///
+/// Its motion values stand still until [`tick`](Self::tick) advances them.
+/// Only one harness should exist per thread at a time; each test runs on a
+/// thread of its own.
+pub struct MotionHarness {
+    dom: VirtualDom,
+}
+
+/// Mount `app` for a test, with its motion values driven by the harness
+///
+/// The app is rendered and its effects run before this returns.
+pub fn motion_test_harness(app: fn() -> Element) -> MotionHarness {
+    driver::set_manual(true);
+    let mut harness = MotionHarness {
+        dom: VirtualDom::new(app),
+    };
+    harness.dom.rebuild_in_place();
+    harness.settle();
+    harness
+}
+
+impl MotionHarness {
+    /// Let `duration` pass in frames of 1/60s, re-rendering after each
+    ///
+    /// The last frame is shorter when `duration` isn't a whole number of frames.
+    pub fn tick(&mut self, duration: Duration) {
+        let mut remaining = duration;
+        while !remaining.is_zero() {
+            let dt = remaining.min(FRAME);
+            remaining -= dt;
+            self.dom.in_runtime(|| driver::step(dt.as_secs_f32()));
+            self.settle();
+        }
+    }
+
+    /// Run `action` in the app's runtime, e.g. to set a signal an event handler
+    /// would, then re-render and run effects
+    pub fn act<R>(&mut self, action: impl FnOnce() -> R) -> R {
+        let result = self.dom.in_runtime(action);
+        self.settle();
+        result
+    }
+
+    /// A snapshot of every mounted motion value, in creation order
+    pub fn motion_values(&self) -> Vec<MotionSnapshot> {
+        self.dom.in_runtime(|| {
+            VALUES.with_borrow(|values| values.iter().filter_map(|(_, read)| read()).collect())
+        })
+    }
+
+    /// A snapshot of the motion value labeled `label`
+    pub fn motion_value(&self, label: &str) -> Option<MotionSnapshot> {
+        self.motion_values()
+            .into_iter()
+            .find(|snapshot| snapshot.label() == Some(label))
+    }
+
+    /// The virtual dom the app is mounted in
+    pub fn dom(&mut self) -> &mut VirtualDom {
+        &mut self.dom
+    }
+
+    /// Run pending effects and re-render dirty components
+    fn settle(&mut self) {
+        self.dom.process_events();
+        self.dom.render_immediate(&mut NoOpMutations);
+        // Effects of the components just rendered
+        self.dom.process_events();
+    }
+}
+
+impl Drop for MotionHarness {
+    fn drop(&mut self) {
+        driver::set_manual(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MotionValue, use_motion, use_motion_labeled};
+
+    thread_local! {
+        static SHOWN: RefCell<Option<Signal<bool>>> = const { RefCell::new(None) };
+    }
+
+    /// The fade of the docs' basic value animation, toggled by `SHOWN`
+    fn app() -> Element {
+        let shown = use_signal(|| false);
+        let opacity = use_motion_labeled("opacity", 0.0f32);
+        let _unlabeled: MotionValue<f32> = use_motion(0.0);
+        SHOWN.set(Some(shown));
+
+        use_effect(move || {
+            let target = if shown() { 1.0 } else { 0.0 };
+            opacity
+                .tween()
+                .duration(Duration::from_millis(500))
+                .animate_to(target);
+        });
+
+        rsx! {
+            div { style: "opacity: {opacity.get()}" }
+        }
+    }
+
+    #[test]
+    fn test_toggle_fades_to_the_new_target() {
+        let mut harness = motion_test_harness(app);
+        assert_eq!(harness.motion_values().len(), 2);
+        let Some(opacity) = harness.motion_value("opacity") else {
+            unreachable!("the app labels its opacity");
+        };
+        assert_eq!(opacity.value::<f32>(), Some(0.0));
+        assert_eq!(opacity.value::<f64>(), None);
+
+        harness.act(|| {
+            if let Some(mut shown) = SHOWN.take() {
+                shown.set(true);
+            }
+        });
+        let Some(opacity) = harness.motion_value("opacity") else {
+            unreachable!("the app labels its opacity");
+        };
+        assert_eq!(opacity.target::<f32>(), Some(1.0));
+        assert!(opacity.is_animating());
+
+        // Nothing moves until the harness says so
+        harness.tick(Duration::from_millis(250));
+        let Some(opacity) = harness.motion_value("opacity") else {
+            unreachable!("the app labels its opacity");
+        };
+        let halfway = opacity.value::<f32>().unwrap_or_default();
+        assert!(halfway > 0.3 && halfway < 0.7);
+
+        harness.tick(Duration::from_millis(250));
+        let Some(opacity) = harness.motion_value("opacity") else {
+            unreachable!("the app labels its opacity");
+        };
+        assert!((opacity.value::<f32>().unwrap_or_default() - 1.0).abs() < 1e-3);
+        assert!(!opacity.is_animating());
+        assert_eq!(opacity.progress(), None);
+    }
+
+    #[test]
+    fn test_unmounted_values_are_forgotten() {
+        let harness = motion_test_harness(app);
+        assert_eq!(harness.motion_values().len(), 2);
+        drop(harness);
+        assert!(VALUES.with_borrow(|values| values.is_empty()));
+    }
+
+    #[test]
+    fn test_tweens_settle_in_their_duration() {
+        fn app() -> Element {
+            let x = use_motion_labeled("x", 0.0f32);
+            use_hook(move || {
+                x.tween()
+                    .duration(Duration::from_millis(300))
+                    .animate_to(10.0)
+            });
+            rsx! {}
+        }
+
+        let mut harness = motion_test_harness(app);
+        harness.tick(Duration::from_millis(300));
+        let Some(x) = harness.motion_value("x") else {
+            unreachable!("the app labels its value");
+        };
+        assert_eq!(x.value::<f32>(), Some(10.0));
+    }
+}
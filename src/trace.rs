@@ -0,0 +1,106 @@
+//! Trajectory recording for debugging
+//!
+//! With the `trace` feature, every engine keeps its most recent samples in a
+//! ring buffer. Read them with [`MotionValue::trace`](crate::MotionValue::trace)
+//! to check the shape of a curve in a test or plot it in a debug panel.
+//!
+//! ```ignore
+//! let samples = motion.trace();
+//! // The spring overshot its target of 100
+//! assert!(samples.iter().any(|sample| sample.value > 100.0));
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::Animatable;
+
+/// Samples kept per engine unless changed with `set_trace_capacity`
+pub const DEFAULT_TRACE_CAPACITY: usize = 256;
+
+/// A recorded value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraceSample<T> {
+    /// Seconds since the engine was created
+    pub time: f32,
+    /// The value at that time
+    pub value: T,
+}
+
+/// Ring buffer of the most recent samples
+pub(crate) struct Trace<T> {
+    samples: VecDeque<TraceSample<T>>,
+    capacity: usize,
+    time: f32,
+}
+
+impl<T: Animatable> Trace<T> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            time: 0.0,
+        }
+    }
+
+    /// Advance the clock without recording
+    pub(crate) fn advance(&mut self, dt: f32) {
+        self.time += dt;
+    }
+
+    /// Record `value` at the current time, evicting the oldest sample when full
+    pub(crate) fn record(&mut self, value: T) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(TraceSample {
+            time: self.time,
+            value,
+        });
+    }
+
+    /// Keep at most `capacity` samples, dropping the oldest ones
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        let excess = self.samples.len().saturating_sub(capacity);
+        self.samples.drain(..excess);
+        self.capacity = capacity;
+    }
+
+    /// The recorded samples, oldest first
+    pub(crate) fn samples(&self) -> Vec<TraceSample<T>> {
+        self.samples.iter().copied().collect()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.samples.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_keeps_latest_samples() {
+        let mut trace = Trace::new(3);
+        for value in 0..5 {
+            trace.advance(0.5);
+            trace.record(value as f32);
+        }
+
+        let values: Vec<f32> = trace.samples().iter().map(|s| s.value).collect();
+        assert_eq!(values, vec![2.0, 3.0, 4.0]);
+        assert_eq!(trace.samples()[0].time, 1.5);
+
+        trace.set_capacity(1);
+        assert_eq!(
+            trace.samples(),
+            vec![TraceSample {
+                time: 2.5,
+                value: 4.0
+            }]
+        );
+    }
+}
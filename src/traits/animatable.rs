@@ -3,6 +3,11 @@
 //! The Animatable trait provides the core operations needed for interpolation
 //! and physics-based animations.
 
+use crate::animation::AnimationMode;
+use crate::animations::bounce::BounceBounds;
+use crate::animations::spring::Spring;
+use crate::properties::transform::RotationWinding;
+
 /// A trait for types that can be animated
 ///
 /// Types implementing this trait can be used with both tween and spring animations.
@@ -36,12 +41,70 @@ pub trait Animatable: Copy + Send + Sync + 'static {
     /// Used for tween animations and keyframe interpolation
     fn interpolate(&self, target: &Self, t: f32) -> Self;
 
+    /// Interpolates like [`interpolate`](Animatable::interpolate), turning any
+    /// rotation the way `winding` says
+    ///
+    /// Used by tweens. Only types with a rotation, like
+    /// [`Transform`](crate::Transform), need to override it.
+    fn interpolate_winding(&self, target: &Self, t: f32, winding: RotationWinding) -> Self {
+        let _ = winding;
+        self.interpolate(target, t)
+    }
+
     /// Creates a value from a normalized parameter (0.0 to 1.0)
     /// Used for advanced interpolation and parameterization
     fn from_parameter(parameter: f32) -> Self {
         Self::zero().scale(parameter)
     }
 
+    /// Whether a value moving with `velocity` has come to rest on `target`
+    ///
+    /// Used by springs to detect completion. The default compares the magnitude of
+    /// the remaining displacement and of the velocity against `epsilon() * 1000`.
+    /// Composite types whose channels have different units (pixels, radians, scale
+    /// factors) should override it and check every channel against its own tolerance.
+    fn is_settled(&self, target: &Self, velocity: &Self) -> bool {
+        let tolerance = Self::epsilon() * 1000.0;
+        target.sub(self).magnitude() < tolerance && velocity.magnitude() < tolerance
+    }
+
+    /// Animation used by a plain [`MotionValue::animate_to`](crate::MotionValue::animate_to)
+    ///
+    /// Only applies when no builder or config is chosen, and can be overridden for
+    /// every type at once with a [`MotionConfig`](crate::MotionConfig) context.
+    /// Defaults to [`Spring::default`]. The built-in types use:
+    ///
+    /// | Type | Animation | Why |
+    /// |---|---|---|
+    /// | `f32`, `f64`, `i32`, `[f32; N]`, `f16`, `[f16; N]` | [`Spring::snappy`] | Fast, and doesn't overshoot bounded values like opacity |
+    /// | [`Length`](crate::Length) | [`Spring::snappy`] | Sizes and radii mustn't overshoot below zero |
+    /// | [`Filter`](crate::Filter) | [`Spring::snappy`] | A blur mustn't overshoot below zero |
+    /// | [`Color`](crate::Color) | 200ms tween, cubic ease-out | Overshoot would flash through other hues |
+    /// | [`Transform`](crate::Transform) | [`Spring::default`] | Movement reads naturally with a little bounce |
+    /// | [`MotionStyle`](crate::MotionStyle) | [`Spring::default`] | As for `Transform`; the opacity is clamped on output |
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::default())
+    }
+
+    /// Whether the value can't be displayed as is, e.g. an opacity above 1
+    ///
+    /// Such values still animate unclamped; types clamp them when rendering. The
+    /// engine logs the first out-of-range value of each animation at debug level,
+    /// so overshooting configs get noticed. Defaults to `false`.
+    fn is_out_of_range(&self) -> bool {
+        false
+    }
+
+    /// Reflect a value moving with `velocity` that crossed a wall of `bounds`
+    /// back inside, reversing the velocity
+    ///
+    /// Used by springs with [`SpringBuilder::bounce_bounds`](crate::animations::spring::SpringBuilder::bounce_bounds).
+    /// `f32` and `f64` bounce as a whole and `[f32; N]` channel by channel;
+    /// the default leaves the value alone, so other types pass through.
+    fn bounce(&mut self, velocity: &mut Self, bounds: &BounceBounds) {
+        let _ = (velocity, bounds);
+    }
+
     /// Equality check with epsilon tolerance
     fn approx_eq(&self, other: &Self) -> bool {
         self.sub(other).magnitude() < Self::epsilon()
@@ -58,6 +121,10 @@ impl Animatable for f32 {
         0.001
     }
 
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::snappy())
+    }
+
     fn magnitude(&self) -> f32 {
         self.abs()
     }
@@ -82,6 +149,10 @@ impl Animatable for f32 {
     fn from_parameter(parameter: f32) -> Self {
         parameter
     }
+
+    fn bounce(&mut self, velocity: &mut Self, bounds: &BounceBounds) {
+        bounds.reflect(self, velocity);
+    }
 }
 
 /// Implementation of Animatable for primitive f64
@@ -94,6 +165,10 @@ impl Animatable for f64 {
         0.001
     }
 
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::snappy())
+    }
+
     fn magnitude(&self) -> f32 {
         self.abs() as f32
     }
@@ -118,6 +193,14 @@ impl Animatable for f64 {
     fn from_parameter(parameter: f32) -> Self {
         parameter as f64
     }
+
+    fn bounce(&mut self, velocity: &mut Self, bounds: &BounceBounds) {
+        let (mut position, mut speed) = (*self as f32, *velocity as f32);
+        bounds.reflect(&mut position, &mut speed);
+        if position as f64 != *self {
+            (*self, *velocity) = (position as f64, speed as f64);
+        }
+    }
 }
 
 /// Implementation of Animatable for primitive i32
@@ -130,6 +213,10 @@ impl Animatable for i32 {
         0.5
     }
 
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::snappy())
+    }
+
     fn magnitude(&self) -> f32 {
         self.abs() as f32
     }
@@ -151,3 +238,308 @@ impl Animatable for i32 {
         (*self as f32 * (1.0 - t) + *target as f32 * t) as i32
     }
 }
+
+/// Implementation of Animatable for fixed-size f32 arrays
+///
+/// Each component is animated independently, which lets one motion value drive a
+/// group of related numbers (e.g. the reveal progress of every item in a list).
+impl<const N: usize> Animatable for [f32; N] {
+    fn zero() -> Self {
+        [0.0; N]
+    }
+
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::snappy())
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.iter()
+            .map(|component| component * component)
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    fn scale(&self, factor: f32) -> Self {
+        self.map(|component| component * factor)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i] + other[i])
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i] - other[i])
+    }
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        std::array::from_fn(|i| self[i].interpolate(&target[i], t))
+    }
+
+    fn from_parameter(parameter: f32) -> Self {
+        [parameter; N]
+    }
+
+    fn bounce(&mut self, velocity: &mut Self, bounds: &BounceBounds) {
+        for (position, velocity) in self.iter_mut().zip(velocity.iter_mut()) {
+            bounds.reflect(position, velocity);
+        }
+    }
+}
+
+/// Implementation of Animatable for half-precision floats
+///
+/// Stores values in 2 bytes instead of 4, for large sets of values where memory
+/// matters more than precision, like particles. Arithmetic runs in `f32` and is
+/// rounded on store; read values back with [`f16::to_f32`](half::f16::to_f32).
+/// Precision is about three significant digits, so a value near 1000 moves in
+/// steps of 0.5.
+#[cfg(feature = "f16")]
+impl Animatable for half::f16 {
+    fn zero() -> Self {
+        half::f16::ZERO
+    }
+
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::snappy())
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.to_f32().abs()
+    }
+
+    fn scale(&self, factor: f32) -> Self {
+        half::f16::from_f32(self.to_f32() * factor)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        half::f16::from_f32(self.to_f32() + other.to_f32())
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        half::f16::from_f32(self.to_f32() - other.to_f32())
+    }
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        half::f16::from_f32(self.to_f32().interpolate(&target.to_f32(), t))
+    }
+
+    fn from_parameter(parameter: f32) -> Self {
+        half::f16::from_f32(parameter)
+    }
+}
+
+/// Implementation of Animatable for fixed-size half-precision arrays
+///
+/// Halves the memory of the `[f32; N]` equivalent, e.g. one motion value holding
+/// the positions of a few thousand particles.
+#[cfg(feature = "f16")]
+impl<const N: usize> Animatable for [half::f16; N] {
+    fn zero() -> Self {
+        [half::f16::ZERO; N]
+    }
+
+    fn epsilon() -> f32 {
+        0.001
+    }
+
+    fn preferred_animation() -> AnimationMode {
+        AnimationMode::Spring(Spring::snappy())
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.iter()
+            .map(|component| component.to_f32() * component.to_f32())
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    fn scale(&self, factor: f32) -> Self {
+        self.map(|component| component.scale(factor))
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i].add(&other[i]))
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i].sub(&other[i]))
+    }
+
+    fn interpolate(&self, target: &Self, t: f32) -> Self {
+        std::array::from_fn(|i| self[i].interpolate(&target[i], t))
+    }
+
+    fn from_parameter(parameter: f32) -> Self {
+        [half::f16::from_f32(parameter); N]
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use crate::{AnimationEngine, Color, Transform};
+
+    #[derive(Debug, Clone, Copy, PartialEq, crate::prelude::Animatable)]
+    struct Card {
+        bg: Color,
+        transform: Transform,
+        opacity: f32,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, crate::prelude::Animatable)]
+    struct Offset(f32, f32);
+
+    fn rest() -> Card {
+        Card {
+            bg: Color::white(),
+            transform: Transform::identity(),
+            opacity: 0.0,
+        }
+    }
+
+    fn raised() -> Card {
+        Card {
+            bg: Color::new(0.2, 0.4, 0.8, 1.0),
+            transform: Transform::new(0.0, -8.0, 1.05, 1.05, 0.0, 0.0, 0.0),
+            opacity: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_derived_ops_apply_per_field() {
+        let halfway = rest().interpolate(&raised(), 0.5);
+        assert_eq!(halfway.bg, Color::white().interpolate(&raised().bg, 0.5));
+        assert_eq!(
+            halfway.transform,
+            Transform::identity().interpolate(&raised().transform, 0.5)
+        );
+        assert_eq!(halfway.opacity, 0.5);
+
+        assert_eq!(Offset(1.0, 2.0).add(&Offset(3.0, 4.0)), Offset(4.0, 6.0));
+        assert_eq!(Offset(3.0, 4.0).magnitude(), 5.0);
+        assert_eq!(Offset::zero(), Offset(0.0, 0.0));
+    }
+
+    /// Velocity of a card at rest; transform scale velocities are relative to 1.0
+    fn still() -> Card {
+        Card {
+            bg: Color::zero(),
+            transform: Transform::identity(),
+            opacity: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_derived_settles_per_field() {
+        let nearly = Card {
+            opacity: 0.9999,
+            ..raised()
+        };
+        assert!(nearly.is_settled(&raised(), &still()));
+
+        // Half a degree of rotation left is far from settled, even though the
+        // combined magnitude is tiny
+        let tilted = Card {
+            transform: Transform {
+                rotation: 0.01,
+                ..raised().transform
+            },
+            ..raised()
+        };
+        assert!(!tilted.is_settled(&raised(), &still()));
+    }
+
+    #[test]
+    fn test_derived_struct_animates_as_a_unit() {
+        let mut engine = AnimationEngine::new(rest());
+        engine.tween_to(raised(), crate::animations::tween::Tween::default());
+        engine.update(0.15);
+
+        let mid = engine.get();
+        assert!(mid.opacity > 0.0 && mid.opacity < 1.0);
+        assert!(mid.transform.y < 0.0 && mid.transform.y > -8.0);
+        assert!(mid.bg.r < 1.0 && mid.bg.r > 0.2);
+
+        while engine.update(1.0 / 60.0) {}
+        assert_eq!(engine.get(), raised());
+
+        engine.spring_to(rest(), Spring::default());
+        while engine.update(1.0 / 60.0) {}
+        assert_eq!(engine.get(), rest());
+    }
+}
+
+#[cfg(all(test, feature = "f16"))]
+mod half_tests {
+    use half::f16;
+
+    use super::*;
+    use crate::AnimationEngine;
+    use crate::animations::tween::Tween;
+
+    fn halves<const N: usize>(values: [f32; N]) -> [f16; N] {
+        values.map(f16::from_f32)
+    }
+
+    #[test]
+    fn test_half_arrays_take_half_the_memory() {
+        assert_eq!(size_of::<f16>(), 2);
+        assert_eq!(size_of::<[f16; 1024]>(), size_of::<[f32; 1024]>() / 2);
+    }
+
+    #[test]
+    fn test_half_tween_tracks_full_precision() {
+        let mut half = AnimationEngine::new(f16::ZERO);
+        let mut full = AnimationEngine::new(0.0f32);
+        half.tween_to(f16::from_f32(400.0), Tween::default());
+        full.tween_to(400.0, Tween::default());
+
+        loop {
+            let active = half.update(1.0 / 60.0);
+            full.update(1.0 / 60.0);
+            // Within f16 rounding, a step of 0.25 between 256 and 512
+            assert!((half.get().to_f32() - full.get()).abs() <= 0.125);
+            if !active {
+                break;
+            }
+        }
+        assert_eq!(half.get().to_f32(), 400.0);
+    }
+
+    #[test]
+    fn test_half_springs_settle_on_target() {
+        let start = halves([0.0, 10.0, -50.0, 300.0]);
+        let target = halves([1.0, -20.0, 75.5, 120.0]);
+        let mut engine = AnimationEngine::new(start);
+        engine.spring_to(target, Spring::default());
+
+        let mut frames = 0;
+        while engine.update(1.0 / 60.0) {
+            frames += 1;
+            assert!(frames < 600, "the spring should come to rest");
+        }
+        assert_eq!(engine.get(), target);
+
+        let mut scalar = AnimationEngine::new(f16::from_f32(0.5));
+        scalar.spring_to(f16::ONE, Spring::snappy());
+        while scalar.update(1.0 / 60.0) {}
+        assert_eq!(scalar.get(), f16::ONE);
+    }
+
+    #[test]
+    fn test_half_ops_round_to_nearest() {
+        let third = f16::from_f32(1.0).interpolate(&f16::from_f32(2.0), 1.0 / 3.0);
+        assert!((third.to_f32() - 4.0 / 3.0).abs() < 1e-3);
+        assert_eq!(f16::from_f32(1.5).scale(2.0), f16::from_f32(3.0));
+        assert_eq!(halves([3.0, 4.0]).magnitude(), 5.0);
+        assert_eq!(<[f16; 3]>::from_parameter(0.5), halves([0.5; 3]));
+    }
+}
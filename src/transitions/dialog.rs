@@ -0,0 +1,477 @@
+//! Animated modal dialogs
+//!
+//! [`AnimatedDialog`] plays the same enter and exit choreography as
+//! [`AnimatedShow`](super::presence::AnimatedShow), over a fading backdrop, and
+//! handles focus the way a modal must: focus moves into the dialog when it
+//! opens, `Tab` cycles within it while it is open, and once it has finished
+//! animating away focus returns to the element that had it before, usually the
+//! button that opened it. Reopening the dialog while it is still closing keeps
+//! that original opener, so rapid toggling never strands focus inside the
+//! dialog.
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Settings() -> Element {
+//!     let mut open = use_signal(|| false);
+//!
+//!     rsx! {
+//!         button { onclick: move |_| open.set(true), "Settings" }
+//!         AnimatedDialog { open: open(), on_close: move |_| open.set(false),
+//!             h2 { "Settings" }
+//!             button { onclick: move |_| open.set(false), "Done" }
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::animation::AnimationConfig;
+use crate::transitions::presence::{Presence, layer_style};
+use crate::transitions::utility::{
+    TransitionConfig, TransitionPhase, TransitionVariant, play_transition,
+};
+use crate::{Transform, use_motion};
+
+/// Elements `Tab` moves between inside a dialog
+#[cfg(feature = "web")]
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]), \
+     select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// Lifecycle of a dialog, and the element to give focus back to
+///
+/// `O` is whatever identifies the focused element outside the dialog.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DialogState<O> {
+    presence: Presence,
+    opener: Option<O>,
+}
+
+impl<O> DialogState<O> {
+    /// A closed dialog
+    pub(crate) fn new() -> Self {
+        Self {
+            presence: Presence::Hidden,
+            opener: None,
+        }
+    }
+
+    /// Request the dialog to open; returns whether the enter animation must start
+    ///
+    /// `focused` tells which element has focus; it is only remembered when the
+    /// dialog doesn't hold an opener yet, so reopening mid-exit keeps the
+    /// element focused before the first opening.
+    pub(crate) fn open(&mut self, focused: impl FnOnce() -> Option<O>) -> bool {
+        if !self.presence.show() {
+            return false;
+        }
+        if self.opener.is_none() {
+            self.opener = focused();
+        }
+        true
+    }
+
+    /// Request the dialog to close; returns whether the exit animation must start
+    pub(crate) fn close(&mut self) -> bool {
+        self.presence.hide()
+    }
+
+    /// Finish the running animation; returns the opener to focus again once the
+    /// dialog is fully closed
+    pub(crate) fn settle(&mut self) -> Option<O> {
+        self.presence.settle();
+        if self.presence.is_mounted() {
+            return None;
+        }
+        self.opener.take()
+    }
+
+    pub(crate) fn presence(&self) -> Presence {
+        self.presence
+    }
+}
+
+/// Where `Tab` must send focus to stay inside the dialog
+///
+/// `count` elements can take focus and `current` is the focused one, if it is
+/// one of them. Returns the element to focus when focus would otherwise leave
+/// the dialog, and `None` when the browser's own move stays inside.
+#[cfg(any(feature = "web", test))]
+fn trap_target(count: usize, current: Option<usize>, backwards: bool) -> Option<usize> {
+    let last = count.checked_sub(1)?;
+    match (current, backwards) {
+        (None, false) => Some(0),
+        (None, true) => Some(last),
+        (Some(index), false) if index >= last => Some(0),
+        (Some(0), true) => Some(last),
+        (Some(_), _) => None,
+    }
+}
+
+#[cfg(feature = "web")]
+type Opener = web_sys::HtmlElement;
+#[cfg(not(feature = "web"))]
+type Opener = ();
+
+/// The element focused outside the dialog, to return focus to on close
+#[cfg(feature = "web")]
+fn focused_element() -> Option<Opener> {
+    use wasm_bindgen::JsCast;
+
+    // Outside a browser web-sys panics rather than finding no window
+    if !cfg!(target_arch = "wasm32") {
+        return None;
+    }
+    web_sys::window()?
+        .document()?
+        .active_element()?
+        .dyn_into::<web_sys::HtmlElement>()
+        .ok()
+}
+
+#[cfg(not(feature = "web"))]
+fn focused_element() -> Option<Opener> {
+    None
+}
+
+#[cfg(feature = "web")]
+fn restore_focus(opener: &Opener) {
+    let _ = opener.focus();
+}
+
+#[cfg(not(feature = "web"))]
+fn restore_focus(_: &Opener) {}
+
+/// Keep `Tab` inside `panel`; returns whether the default move must be prevented
+#[cfg(feature = "web")]
+fn trap_focus(panel: &MountedData, backwards: bool) -> bool {
+    use wasm_bindgen::JsCast;
+
+    let Some(panel) = panel.downcast::<web_sys::Element>() else {
+        return false;
+    };
+    let Ok(nodes) = panel.query_selector_all(FOCUSABLE) else {
+        return false;
+    };
+    let focusable: Vec<web_sys::HtmlElement> = (0..nodes.length())
+        .filter_map(|index| nodes.get(index)?.dyn_into().ok())
+        .collect();
+    if focusable.is_empty() {
+        // Nothing to move to: focus stays on the panel
+        return true;
+    }
+    let active = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element());
+    let current = active.and_then(|active| {
+        focusable
+            .iter()
+            .position(|element| element.is_same_node(Some(&active)))
+    });
+    let Some(target) = trap_target(focusable.len(), current, backwards) else {
+        return false;
+    };
+    focusable
+        .get(target)
+        .is_some_and(|element| element.focus().is_ok())
+}
+
+/// Without a DOM to query, `Tab` is left to the platform
+#[cfg(not(feature = "web"))]
+fn trap_focus(_: &MountedData, _: bool) -> bool {
+    false
+}
+
+/// Move focus onto the dialog panel
+fn focus_panel(panel: Rc<MountedData>) {
+    spawn(async move {
+        let _ = panel.set_focus(true).await;
+    });
+}
+
+/// Modal dialog animating in and out over a fading backdrop
+///
+/// The dialog is shown while `open` is true; `on_close` is called when the user
+/// asks to close it, with `Escape` or a click on the backdrop, so the owner can
+/// set `open` to false. Children stay mounted while the exit animation plays.
+///
+/// `enter` is played when opening and `exit` when closing, defaulting to
+/// `enter`, which defaults to [`TransitionVariant::ScaleUpFade`]. Without
+/// `timing`, each half plays with the [`timing`](TransitionConfig::timing) of
+/// its own config.
+///
+/// Focus moves to the dialog when it opens and returns to the previously
+/// focused element once the exit animation has finished. On the web, `Tab`
+/// and `Shift+Tab` cycle through the dialog's focusable elements while it is
+/// open.
+#[component]
+pub fn AnimatedDialog(
+    open: bool,
+    on_close: EventHandler<()>,
+    enter: Option<TransitionConfig>,
+    exit: Option<TransitionConfig>,
+    timing: Option<AnimationConfig>,
+    /// Call `on_close` when `Escape` is pressed
+    #[props(default = true)]
+    close_on_escape: bool,
+    /// Call `on_close` on a click outside the dialog
+    #[props(default = true)]
+    close_on_backdrop: bool,
+    /// Classes of the dialog panel
+    #[props(default)]
+    class: String,
+    /// Extra inline style of the dialog panel
+    #[props(default)]
+    style: String,
+    children: Element,
+) -> Element {
+    let enter = enter.unwrap_or_else(|| TransitionVariant::ScaleUpFade.get_config());
+    let exit = exit.unwrap_or_else(|| enter.clone());
+    let enter_timing = timing.clone().unwrap_or_else(|| enter.timing.clone());
+    let exit_timing = timing.unwrap_or_else(|| exit.timing.clone());
+
+    let mut state = use_signal(DialogState::<Opener>::new);
+    let mut panel = use_signal(|| None::<Rc<MountedData>>);
+    let mut transform = use_motion(Transform::identity());
+    let mut opacity = use_motion(0.0f32);
+
+    use_effect(use_reactive((&open,), move |(open,)| {
+        if open {
+            let was_hidden = !state.peek().presence().is_mounted();
+            if !state.write().open(focused_element) {
+                return;
+            }
+            if was_hidden {
+                transform.set(enter.enter_start);
+                opacity.set(0.0);
+            } else if let Some(panel) = panel.peek().clone() {
+                // Reopened mid-exit: the panel is still there to take focus back
+                focus_panel(panel);
+            }
+            play_transition(
+                &mut transform,
+                &mut opacity,
+                &enter,
+                TransitionPhase::Enter,
+                enter_timing.clone(),
+            );
+        } else if state.write().close() {
+            play_transition(
+                &mut transform,
+                &mut opacity,
+                &exit,
+                TransitionPhase::Exit,
+                exit_timing.clone(),
+            );
+        }
+    }));
+
+    // Settle once both values come to rest, handing focus back after the exit
+    use_effect(move || {
+        let animating = transform.is_animating() || opacity.is_animating();
+        if animating || !state.peek().presence().is_animating() {
+            return;
+        }
+        let opener = state.write().settle();
+        if !state.peek().presence().is_mounted() {
+            panel.set(None);
+        }
+        if let Some(opener) = opener {
+            restore_focus(&opener);
+        }
+    });
+
+    let presence = state.read().presence();
+    if !presence.is_mounted() {
+        return rsx! {};
+    }
+    let opacity = opacity.get();
+    // A closing dialog no longer blocks the page below it
+    let pointer_events = if presence == Presence::Exiting {
+        "none"
+    } else {
+        "auto"
+    };
+
+    rsx! {
+        div {
+            class: "motion-dialog-backdrop",
+            style: "position: fixed; inset: 0; display: flex; align-items: center; justify-content: center; background: rgba(0, 0, 0, {opacity * 0.4}); pointer-events: {pointer_events};",
+            onclick: move |_| {
+                if close_on_backdrop {
+                    on_close.call(());
+                }
+            },
+            div {
+                class: "motion-dialog {class}",
+                role: "dialog",
+                aria_modal: "true",
+                tabindex: "-1",
+                style: "{layer_style(transform.get(), opacity)} {style}",
+                onclick: move |event| event.stop_propagation(),
+                onmounted: move |event| {
+                    let mounted = event.data();
+                    panel.set(Some(mounted.clone()));
+                    focus_panel(mounted);
+                },
+                onkeydown: move |event| {
+                    match event.key() {
+                        Key::Escape if close_on_escape => {
+                            event.stop_propagation();
+                            on_close.call(());
+                        }
+                        Key::Tab => {
+                            let backwards = event.modifiers().contains(Modifiers::SHIFT);
+                            if let Some(panel) = panel.peek().as_ref()
+                                && trap_focus(panel, backwards)
+                            {
+                                event.prevent_default();
+                            }
+                        }
+                        _ => {}
+                    }
+                },
+                {children}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    /// Open with `focused` holding focus outside the dialog
+    fn open(state: &mut DialogState<&'static str>, focused: &'static str) -> bool {
+        state.open(|| Some(focused))
+    }
+
+    #[test]
+    fn test_focus_returns_only_after_the_exit() {
+        let mut state = DialogState::new();
+        assert!(open(&mut state, "settings button"));
+        assert_eq!(state.settle(), None);
+        assert_eq!(state.presence(), Presence::Visible);
+
+        assert!(state.close());
+        // Still animating away: focus stays put until the exit settles
+        assert_eq!(state.presence(), Presence::Exiting);
+        assert_eq!(state.settle(), Some("settings button"));
+        assert_eq!(state.presence(), Presence::Hidden);
+    }
+
+    #[test]
+    fn test_rapid_reopen_keeps_the_original_opener() {
+        let mut state = DialogState::new();
+        assert!(open(&mut state, "settings button"));
+        assert!(state.close());
+
+        // Reopened mid-exit while focus is still on a control inside the dialog
+        assert!(open(&mut state, "dialog checkbox"));
+        assert_eq!(state.presence(), Presence::Entering);
+        assert_eq!(state.settle(), None, "an open dialog keeps focus");
+
+        assert!(state.close());
+        assert_eq!(state.settle(), Some("settings button"));
+
+        // The next opening remembers its own opener
+        assert!(open(&mut state, "profile link"));
+        assert!(state.close());
+        assert_eq!(state.settle(), Some("profile link"));
+    }
+
+    #[test]
+    fn test_repeated_requests_change_nothing() {
+        let mut state = DialogState::new();
+        assert!(!state.close());
+        assert!(open(&mut state, "button"));
+        assert!(!open(&mut state, "elsewhere"));
+        assert!(state.close());
+        assert!(!state.close());
+        assert_eq!(state.settle(), Some("button"));
+        assert_eq!(state.settle(), None);
+    }
+
+    #[test]
+    fn test_open_without_focused_element() {
+        let mut state = DialogState::<&str>::new();
+        assert!(state.open(|| None));
+        assert!(state.close());
+        assert_eq!(state.settle(), None);
+        assert!(!state.presence().is_mounted());
+    }
+
+    #[test]
+    fn test_tab_wraps_at_the_edges() {
+        // Forward from the last element, or backward from the first, wraps around
+        assert_eq!(trap_target(3, Some(2), false), Some(0));
+        assert_eq!(trap_target(3, Some(0), true), Some(2));
+        // Moves inside are left to the browser
+        assert_eq!(trap_target(3, Some(0), false), None);
+        assert_eq!(trap_target(3, Some(2), true), None);
+        // From the panel itself, Tab enters at either end
+        assert_eq!(trap_target(3, None, false), Some(0));
+        assert_eq!(trap_target(3, None, true), Some(2));
+        // A single element keeps focus; without any, nothing can take it
+        assert_eq!(trap_target(1, Some(0), false), Some(0));
+        assert_eq!(trap_target(0, None, false), None);
+    }
+
+    #[test]
+    fn test_dialog_opens_and_closes_when_mounted() {
+        thread_local! {
+            static OPEN: Cell<Option<Signal<bool>>> = const { Cell::new(None) };
+            static SHOWN: Cell<bool> = const { Cell::new(false) };
+        }
+
+        #[component]
+        fn Content() -> Element {
+            use_hook(|| SHOWN.set(true));
+            use_drop(|| SHOWN.set(false));
+            rsx! { "Settings" }
+        }
+
+        fn app() -> Element {
+            let mut open = use_signal(|| false);
+            OPEN.set(Some(open));
+            rsx! {
+                AnimatedDialog { open: open(), on_close: move |_| open.set(false), Content {} }
+            }
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(mut open) = OPEN.get() else {
+            unreachable!("the app stores its signal");
+        };
+        let settle = |dom: &mut VirtualDom| {
+            for _ in 0..60 {
+                dom.in_runtime(|| crate::driver::step(1.0 / 60.0));
+                // One pass re-renders the frame, the next runs the effects
+                for _ in 0..2 {
+                    dom.process_events();
+                    dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+                }
+            }
+        };
+        assert!(!SHOWN.get());
+
+        // Opening remembers the focused element, of which there is none here
+        dom.in_runtime(|| open.set(true));
+        settle(&mut dom);
+        assert!(SHOWN.get());
+
+        dom.in_runtime(|| open.set(false));
+        settle(&mut dom);
+        assert!(!SHOWN.get(), "the content unmounts after the exit");
+    }
+}
@@ -1,2 +1,17 @@
+//! Enter, exit and route transitions
+//!
+//! Transitions are described by a [`TransitionConfig`](utility::TransitionConfig)
+//! of transforms whose translations are percentages of the animated element's
+//! own size, and rendered with [`transition_transform`](utility::transition_transform)
+//! as CSS percentages. Nothing is measured in pixels: when the window is resized
+//! mid-transition, the browser re-resolves the percentages against the new
+//! layout, so a page sliding out stays at the same relative position instead
+//! of jumping, and no resize listener or target recomputation is needed.
+
+pub mod dialog;
+#[cfg(feature = "transitions")]
 pub mod page_transition;
+pub mod presence;
+#[cfg(feature = "transitions")]
+pub mod route_transitions;
 pub mod utility;
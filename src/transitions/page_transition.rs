@@ -2,9 +2,11 @@ use std::marker::PhantomData;
 
 use dioxus::prelude::*;
 
+use crate::prelude::AnimationConfig;
 use crate::use_motion;
 
-use super::utility::TransitionVariant;
+use super::route_transitions::RouteTransitions;
+use super::utility::{TransitionPhase, TransitionVariant, play_transition, transition_transform};
 #[derive(Clone)]
 pub enum AnimatedRouterContext<R: Routable + PartialEq> {
     /// Transition from one route to another.
@@ -49,14 +51,53 @@ impl<R: Routable + PartialEq> AnimatedRouterContext<R> {
 /// the layout depth or route conditions are met, it renders a transition component; otherwise,
 /// it renders a standard outlet.
 ///
+/// The transition runs with `timing` when given, unless the destination route
+/// provides its own through [`AnimatableRoute::get_timing`]. Without either, the
+/// variant's [`TransitionVariant::default_timing`] is used.
+///
 /// # Examples
 ///
-/// ```
+/// ```ignore
 /// // Assuming `AppRoute` implements `AnimatableRoute`:
-/// let animated_outlet = AnimatedOutlet::<AppRoute>();
-/// // Use `animated_outlet` as part of your Dioxus component tree.
+/// rsx! { AnimatedOutlet::<AppRoute> {} }
+///
+/// // Slow every transition of this outlet down
+/// let timing = AnimationConfig::new(AnimationMode::Tween(
+///     Tween::new().duration(Duration::from_millis(600)),
+/// ));
+/// rsx! { AnimatedOutlet::<AppRoute> { timing } }
 /// ```
-pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
+pub fn AnimatedOutlet<R: AnimatableRoute>(timing: Option<AnimationConfig>) -> Element {
+    let transitions = use_hook(|| {
+        RouteTransitions::from_fns(R::transition_between, R::get_layout_depth, R::get_timing)
+    });
+
+    rsx! {
+        TransitionOutlet::<R> { transitions, timing }
+    }
+}
+
+/// [`AnimatedOutlet`] for routes without `#[derive(MotionTransitions)]`
+///
+/// Transitions come from `transitions`, built with a
+/// [`TransitionsBuilder`](super::route_transitions::TransitionsBuilder), so the
+/// route only needs to be `Routable`. `timing` works as on [`AnimatedOutlet`].
+///
+/// # Example
+///
+/// ```ignore
+/// let transitions = use_hook(|| {
+///     TransitionsBuilder::<Route>::new()
+///         .on_prefix("/docs", TransitionVariant::SlideLeft)
+///         .build()
+/// });
+/// rsx! { TransitionOutlet::<Route> { transitions } }
+/// ```
+#[component]
+pub fn TransitionOutlet<R: Routable + Clone + PartialEq>(
+    transitions: RouteTransitions<R>,
+    timing: Option<AnimationConfig>,
+) -> Element {
     let route = use_route::<R>();
     // Create router context only if we're the root AnimatedOutlet
     let mut prev_route = use_signal(|| AnimatedRouterContext::In(route.clone()));
@@ -86,8 +127,8 @@ pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
         // Special handling for transitions from root path
         let is_from_root = from.to_string() == "/";
         let current_depth = outlet.level();
-        let target_depth = to.get_layout_depth();
-        let from_depth = from.get_layout_depth();
+        let target_depth = transitions.layout_depth(&to);
+        let from_depth = transitions.layout_depth(&from);
 
         // Animate if:
         // 1. We're transitioning from root
@@ -99,6 +140,8 @@ pub fn AnimatedOutlet<R: AnimatableRoute>() -> Element {
                     route_type: PhantomData,
                     from: from.clone(),
                     to: to.clone(),
+                    transitions: transitions.clone(),
+                    timing: timing.clone(),
                 }
             };
         } else {
@@ -117,6 +160,74 @@ pub trait AnimatableRoute: Routable + Clone + PartialEq {
     fn get_transition(&self) -> TransitionVariant;
     fn get_component(&self) -> Element;
     fn get_layout_depth(&self) -> usize;
+
+    /// Transition used when navigating from `from` to `to`
+    ///
+    /// Defaults to the destination's [`get_transition`](AnimatableRoute::get_transition),
+    /// i.e. its `#[transition(...)]` attribute. Deriving with `#[transition_map]`
+    /// on the enum makes it use the route's [`TransitionMap`] instead.
+    fn transition_between(from: &Self, to: &Self) -> TransitionVariant {
+        let _ = from;
+        to.get_transition()
+    }
+
+    /// Timing for transitions into this route, overriding the outlet's and the
+    /// variant's default timing
+    fn get_timing(&self) -> Option<AnimationConfig> {
+        None
+    }
+}
+
+/// Route transitions defined in one place, for every pair of routes
+///
+/// Instead of annotating each variant with `#[transition(...)]`, implement this
+/// trait and mark the enum with `#[transition_map]`. The map then decides every
+/// transition [`AnimatedOutlet`] plays, and receives both the route being left
+/// and the route being entered, so a pair of routes can get its own choreography.
+///
+/// # Precedence
+///
+/// 1. With `#[transition_map]`, [`TransitionMap::transition`] for the `(from, to)` pair.
+/// 2. Otherwise the destination's `#[transition(...)]` attribute, through
+///    [`AnimatableRoute::get_transition`].
+/// 3. Otherwise [`TransitionVariant::Fade`].
+///
+/// # Example
+///
+/// ```ignore
+/// use dioxus::prelude::*;
+/// use dioxus_motion2::prelude::*;
+///
+/// #[derive(Routable, Clone, Debug, PartialEq, MotionTransitions)]
+/// #[rustfmt::skip]
+/// #[transition_map]
+/// enum Route {
+///     #[layout(NavBar)]
+///         #[route("/")]
+///         Home {},
+///         #[route("/inbox")]
+///         Inbox {},
+///         #[route("/inbox/:id")]
+///         Message { id: usize },
+/// }
+///
+/// impl TransitionMap for Route {
+///     fn transition(from: &Route, to: &Route) -> TransitionVariant {
+///         match (from, to) {
+///             // Drill down into a message and back out of it
+///             (Route::Inbox {}, Route::Message { .. }) => TransitionVariant::SlideLeft,
+///             (Route::Message { .. }, Route::Inbox {}) => TransitionVariant::SlideRight,
+///             // Step between messages without sliding the whole view
+///             (Route::Message { .. }, Route::Message { .. }) => TransitionVariant::Fade,
+///             (_, Route::Home {}) => TransitionVariant::ZoomOut,
+///             _ => TransitionVariant::ZoomIn,
+///         }
+///     }
+/// }
+/// ```
+pub trait TransitionMap: AnimatableRoute {
+    /// Transition used when navigating from `from` to `to`
+    fn transition(from: &Self, to: &Self) -> TransitionVariant;
 }
 
 /// Shortcut to get access to the [AnimatedRouterContext].
@@ -125,62 +236,42 @@ pub fn use_animated_router<Route: Routable + PartialEq>() -> Signal<AnimatedRout
 }
 
 #[component]
-fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, to: R) -> Element {
+fn FromRouteToCurrent<R: Routable + Clone + PartialEq>(
+    route_type: PhantomData<R>,
+    from: R,
+    to: R,
+    transitions: RouteTransitions<R>,
+    timing: Option<AnimationConfig>,
+) -> Element {
     let mut animated_router = use_animated_router::<R>();
-    let config = to.get_transition().get_config();
-    let from_transform = use_motion(config.exit_start);
-    let to_transform = use_motion(config.enter_start);
-    let from_opacity = use_motion(1.0f32);
-    let to_opacity = use_motion(0.0f32);
+    let variant = transitions.transition(&from, &to);
+    let config = variant.get_config();
+    let timing = variant.resolve_timing(transitions.timing(&to), timing);
+    let mut from_transform = use_motion(config.exit_start);
+    let mut to_transform = use_motion(config.enter_start);
+    let mut from_opacity = use_motion(1.0f32);
+    let mut to_opacity = use_motion(0.0f32);
 
     // Track animation state separately
     let mut is_animating = use_signal(|| true);
 
     // Start animation in a separate effect
     use_effect(move || {
-        // Animate FROM route with gentler spring
-        from_transform
-            .spring()
-            .stiffness(80.0) // Reduced from 160.0
-            .damping(12.0) // Reduced from 20.0
-            .mass(1.0) // Reduced from 1.5
-            .on_complete(move || {
-                println!("From transform animation complete");
-            })
-            .animate_to(config.exit_end);
-
-        // Animate TO route with gentler spring
-        to_transform
-            .spring()
-            .stiffness(80.0) // Reduced from 160.0
-            .damping(12.0) // Reduced from 20.0
-            .mass(1.0) // Reduced from 1.5
-            .on_complete(move || {
-                println!("To transform animation complete");
-            })
-            .animate_to(config.enter_end);
-
-        // Fade out old route
-        from_opacity
-            .spring()
-            .stiffness(160.0)
-            .damping(20.0)
-            .mass(1.5)
-            .on_complete(move || {
-                println!("From opacity animation complete");
-            })
-            .animate_to(0.0);
-
-        // Fade in new route
-        to_opacity
-            .spring()
-            .stiffness(160.0)
-            .damping(20.0)
-            .mass(1.5)
-            .on_complete(move || {
-                println!("To opacity animation complete");
-            })
-            .animate_to(1.0);
+        // Move the routes and cross-fade them with the same timing
+        play_transition(
+            &mut from_transform,
+            &mut from_opacity,
+            &config,
+            TransitionPhase::Exit,
+            timing.clone(),
+        );
+        play_transition(
+            &mut to_transform,
+            &mut to_opacity,
+            &config,
+            TransitionPhase::Enter,
+            timing.clone(),
+        );
     });
 
     // Track animation completion in a separate effect
@@ -208,20 +299,18 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
             div {
                 class: "route-content from",
                 style: "
-                    transform: translate3d({from_transform.get().x}%, {from_transform.get().y}%, 0) 
-                             scale({from_transform.get().scale_x}, {from_transform.get().scale_y});
+                    transform: {transition_transform(&from_transform.get())};
                     opacity: {from_opacity.get()};
                     will-change: transform, opacity;
                     backface-visibility: hidden;
                     -webkit-backface-visibility: hidden;
                 ",
-                {from.render(from.get_layout_depth() + 1)}
+                {from.render(transitions.layout_depth(&from) + 1)}
             }
             div {
                 class: "route-content to",
                 style: "
-                    transform: translate3d({to_transform.get().x}%, {to_transform.get().y}%, 0) 
-                             scale({to_transform.get().scale_x}, {to_transform.get().scale_y});
+                    transform: {transition_transform(&to_transform.get())};
                     opacity: {to_opacity.get()};
                     will-change: transform, opacity;
                     backface-visibility: hidden;
@@ -232,3 +321,171 @@ fn FromRouteToCurrent<R: AnimatableRoute>(route_type: PhantomData<R>, from: R, t
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[component]
+    fn Home() -> Element {
+        rsx! {}
+    }
+
+    #[component]
+    fn Inbox() -> Element {
+        rsx! {}
+    }
+
+    #[derive(Routable, Clone, Debug, PartialEq)]
+    enum Route {
+        #[route("/")]
+        Home {},
+        #[route("/inbox")]
+        Inbox {},
+    }
+
+    /// Route with per-variant transitions only
+    impl AnimatableRoute for Route {
+        fn get_transition(&self) -> TransitionVariant {
+            match self {
+                Self::Home {} => TransitionVariant::Fade,
+                Self::Inbox {} => TransitionVariant::SlideLeft,
+            }
+        }
+
+        fn get_component(&self) -> Element {
+            rsx! {}
+        }
+
+        fn get_layout_depth(&self) -> usize {
+            0
+        }
+    }
+
+    /// The same routes, with a transition map
+    #[derive(Routable, Clone, Debug, PartialEq)]
+    enum MappedRoute {
+        #[route("/")]
+        Home {},
+        #[route("/inbox")]
+        Inbox {},
+    }
+
+    impl AnimatableRoute for MappedRoute {
+        fn get_transition(&self) -> TransitionVariant {
+            TransitionVariant::Fade
+        }
+
+        fn get_component(&self) -> Element {
+            rsx! {}
+        }
+
+        fn get_layout_depth(&self) -> usize {
+            0
+        }
+
+        // What `#[transition_map]` generates
+        fn transition_between(from: &Self, to: &Self) -> TransitionVariant {
+            <Self as TransitionMap>::transition(from, to)
+        }
+    }
+
+    impl TransitionMap for MappedRoute {
+        fn transition(from: &Self, to: &Self) -> TransitionVariant {
+            match (from, to) {
+                (Self::Home {}, Self::Inbox {}) => TransitionVariant::SlideLeft,
+                (Self::Inbox {}, Self::Home {}) => TransitionVariant::SlideRight,
+                _ => TransitionVariant::Fade,
+            }
+        }
+    }
+
+    #[component]
+    fn NavBar() -> Element {
+        rsx! { Outlet::<DerivedRoute> {} }
+    }
+
+    #[component]
+    fn DocsLayout() -> Element {
+        rsx! { Outlet::<DerivedRoute> {} }
+    }
+
+    #[component]
+    fn DocsLanding() -> Element {
+        rsx! {}
+    }
+
+    thread_local! {
+        static RENDERED_POST: std::cell::Cell<Option<usize>> = const { std::cell::Cell::new(None) };
+    }
+
+    #[component]
+    fn Post(id: usize) -> Element {
+        RENDERED_POST.set(Some(id));
+        rsx! {}
+    }
+
+    /// Layouts, a nest and a named-field route, through the derive
+    #[derive(Routable, Clone, Debug, PartialEq, crate::MotionTransitions)]
+    #[rustfmt::skip]
+    #[allow(clippy::empty_line_after_outer_attr)]
+    enum DerivedRoute {
+        #[layout(NavBar)]
+            #[route("/")]
+            Home {},
+            #[nest("/docs")]
+            #[layout(DocsLayout)]
+                #[route("/")]
+                #[transition(SlideLeft)]
+                DocsLanding {},
+            #[end_layout]
+            #[end_nest]
+            #[route("/post/:id")]
+            #[transition(SlideUp)]
+            Post { id: usize },
+        #[end_layout]
+        #[route("/inbox")]
+        Inbox {},
+    }
+
+    #[test]
+    fn test_derived_depths_and_transitions() {
+        let post = DerivedRoute::Post { id: 7 };
+        assert_eq!(DerivedRoute::Home {}.get_layout_depth(), 1);
+        assert_eq!(DerivedRoute::DocsLanding {}.get_layout_depth(), 2);
+        assert_eq!(post.get_layout_depth(), 1);
+        assert_eq!(DerivedRoute::Inbox {}.get_layout_depth(), 0);
+
+        assert!(DerivedRoute::Home {}.get_transition() == TransitionVariant::Fade);
+        assert!(DerivedRoute::DocsLanding {}.get_transition() == TransitionVariant::SlideLeft);
+        assert!(post.get_transition() == TransitionVariant::SlideUp);
+    }
+
+    #[test]
+    fn test_derived_component_gets_route_fields() {
+        fn app() -> Element {
+            DerivedRoute::Post { id: 7 }.get_component()
+        }
+
+        VirtualDom::new(app).rebuild_in_place();
+        assert_eq!(RENDERED_POST.get(), Some(7));
+    }
+
+    #[test]
+    fn test_without_map_uses_destination_transition() {
+        let (home, inbox) = (Route::Home {}, Route::Inbox {});
+        assert!(Route::transition_between(&home, &inbox) == TransitionVariant::SlideLeft);
+        assert!(Route::transition_between(&inbox, &home) == TransitionVariant::Fade);
+        // The route being left has no say
+        assert!(Route::transition_between(&inbox, &inbox) == TransitionVariant::SlideLeft);
+    }
+
+    #[test]
+    fn test_map_takes_precedence_and_sees_both_routes() {
+        let (home, inbox) = (MappedRoute::Home {}, MappedRoute::Inbox {});
+        assert!(MappedRoute::transition_between(&home, &inbox) == TransitionVariant::SlideLeft);
+        // Same destination attribute, different choreography per direction
+        assert!(MappedRoute::transition_between(&inbox, &home) == TransitionVariant::SlideRight);
+        assert!(inbox.get_transition() == TransitionVariant::Fade);
+    }
+}
@@ -0,0 +1,506 @@
+//! Animated conditional rendering
+//!
+//! [`AnimatedShow`] is the animated counterpart of `if show { rsx! { ... } }`: children
+//! mount with an enter animation and stay mounted while their exit animation plays.
+//! [`AnimatedSwitch`] transitions between pieces of content keyed by a value, such as
+//! tab panels, keeping the outgoing content mounted until it has animated away.
+//!
+//! Both keep animating smoothly under rapid toggling: a reversal mid-animation
+//! retargets from the current position instead of jumping.
+//!
+//! Content on its way out still sits under the pointer, so a click meant for
+//! what's behind a fading toast lands on the toast. Set
+//! `disable_pointer_during_exit` to let the exiting content ignore the pointer;
+//! a reversal back in restores it right away.
+//!
+//! # Examples
+//!
+//! A tooltip:
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Tooltip(text: String) -> Element {
+//!     let mut open = use_signal(|| false);
+//!
+//!     rsx! {
+//!         span {
+//!             onmouseenter: move |_| open.set(true),
+//!             onmouseleave: move |_| open.set(false),
+//!             "?"
+//!             AnimatedShow {
+//!                 when: open(),
+//!                 enter: TransitionVariant::SlideUpFade,
+//!                 div { class: "tooltip", "{text}" }
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! A tab switcher:
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Tabs() -> Element {
+//!     let mut tab = use_signal(|| 0usize);
+//!
+//!     rsx! {
+//!         for i in 0..3 {
+//!             button { onclick: move |_| tab.set(i), "Tab {i}" }
+//!         }
+//!         AnimatedSwitch {
+//!             value: tab(),
+//!             variant: TransitionVariant::SlideLeftFade,
+//!             render: move |i: usize| rsx! { p { "Content of tab {i}" } },
+//!         }
+//!     }
+//! }
+//! ```
+
+use dioxus::prelude::*;
+
+use crate::animation::AnimationConfig;
+use crate::transitions::utility::{
+    TransitionPhase, TransitionVariant, play_transition, transition_transform,
+};
+use crate::{Transform, use_motion};
+
+/// Lifecycle of content that animates in and out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    /// Not mounted
+    Hidden,
+    /// Mounted and playing the enter animation
+    Entering,
+    /// Mounted and settled
+    Visible,
+    /// Still mounted while playing the exit animation
+    Exiting,
+}
+
+impl Presence {
+    /// Initial state for content that starts shown or hidden
+    pub fn new(shown: bool) -> Self {
+        if shown { Self::Visible } else { Self::Hidden }
+    }
+
+    /// Request the content to be shown; returns whether an enter animation must start
+    pub fn show(&mut self) -> bool {
+        match self {
+            Self::Hidden | Self::Exiting => {
+                *self = Self::Entering;
+                true
+            }
+            Self::Entering | Self::Visible => false,
+        }
+    }
+
+    /// Request the content to be hidden; returns whether an exit animation must start
+    pub fn hide(&mut self) -> bool {
+        match self {
+            Self::Entering | Self::Visible => {
+                *self = Self::Exiting;
+                true
+            }
+            Self::Hidden | Self::Exiting => false,
+        }
+    }
+
+    /// Finish the running enter or exit animation
+    pub fn settle(&mut self) {
+        *self = match self {
+            Self::Entering | Self::Visible => Self::Visible,
+            Self::Exiting | Self::Hidden => Self::Hidden,
+        };
+    }
+
+    /// Whether the content should be in the tree
+    pub fn is_mounted(&self) -> bool {
+        *self != Self::Hidden
+    }
+
+    /// Whether an enter or exit animation is in progress
+    pub fn is_animating(&self) -> bool {
+        matches!(self, Self::Entering | Self::Exiting)
+    }
+}
+
+/// Content shown by an [`AnimatedSwitch`], and the content leaving it
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwitchState<K> {
+    current: K,
+    generation: u64,
+    leaving: Option<(K, u64)>,
+}
+
+impl<K: Clone + PartialEq> SwitchState<K> {
+    /// Start settled on `value`
+    pub fn new(value: K) -> Self {
+        Self {
+            current: value,
+            generation: 0,
+            leaving: None,
+        }
+    }
+
+    /// Switch to `value`; returns whether a transition must start
+    ///
+    /// Switching mid-transition drops the content that was already leaving and
+    /// sends the half-entered content out instead.
+    pub fn switch(&mut self, value: K) -> bool {
+        if value == self.current {
+            return false;
+        }
+        let previous = std::mem::replace(&mut self.current, value);
+        self.leaving = Some((previous, self.generation));
+        self.generation += 1;
+        true
+    }
+
+    /// Finish the transition, unmounting the leaving content
+    pub fn settle(&mut self) {
+        self.leaving = None;
+    }
+
+    /// The content being switched to, with its render key
+    pub fn current(&self) -> (&K, u64) {
+        (&self.current, self.generation)
+    }
+
+    /// The content animating away, with its render key
+    pub fn leaving(&self) -> Option<(&K, u64)> {
+        self.leaving.as_ref().map(|(value, key)| (value, *key))
+    }
+}
+
+/// Inline style for a layer of a presence animation
+pub(crate) fn layer_style(transform: Transform, opacity: f32) -> String {
+    format!(
+        "transform: {}; opacity: {};",
+        transition_transform(&transform),
+        opacity
+    )
+}
+
+/// Extra style keeping a layer from taking the pointer while it exits
+pub(crate) fn exit_pointer_style(exiting: bool, disable_pointer_during_exit: bool) -> &'static str {
+    if exiting && disable_pointer_during_exit {
+        " pointer-events: none;"
+    } else {
+        ""
+    }
+}
+
+/// Animated `if`: mounts children with an enter animation and unmounts them after
+/// their exit animation
+///
+/// `enter` picks the enter half of a [`TransitionVariant`] and `exit` the exit half;
+/// `exit` defaults to `enter`, which defaults to a fade. Without `timing`, each
+/// half plays with its own variant's [`TransitionVariant::default_timing`], so
+/// e.g. a bouncy entrance can leave with a plain fade.
+#[component]
+pub fn AnimatedShow(
+    when: bool,
+    enter: Option<TransitionVariant>,
+    exit: Option<TransitionVariant>,
+    timing: Option<AnimationConfig>,
+    /// Stop clicks and hovers on the children while they animate out
+    #[props(default)]
+    disable_pointer_during_exit: bool,
+    children: Element,
+) -> Element {
+    let enter = enter.unwrap_or(TransitionVariant::Fade);
+    let exit = exit.unwrap_or_else(|| enter.clone());
+    let enter = enter.get_config();
+    let exit = exit.get_config();
+    let enter_timing = timing.clone().unwrap_or_else(|| enter.timing.clone());
+    let exit_timing = timing.unwrap_or_else(|| exit.timing.clone());
+
+    let mut presence = use_signal(|| Presence::new(when));
+    let mut transform = use_motion(Transform::identity());
+    let mut opacity = use_motion(1.0f32);
+
+    use_effect(use_reactive((&when,), move |(when,)| {
+        if when {
+            let was_hidden = !presence.peek().is_mounted();
+            if presence.write().show() {
+                if was_hidden {
+                    transform.set(enter.enter_start);
+                    opacity.set(0.0);
+                }
+                play_transition(
+                    &mut transform,
+                    &mut opacity,
+                    &enter,
+                    TransitionPhase::Enter,
+                    enter_timing.clone(),
+                );
+            }
+        } else if presence.write().hide() {
+            play_transition(
+                &mut transform,
+                &mut opacity,
+                &exit,
+                TransitionPhase::Exit,
+                exit_timing.clone(),
+            );
+        }
+    }));
+
+    // Settle once both values come to rest
+    use_effect(move || {
+        let animating = transform.is_animating() || opacity.is_animating();
+        if !animating && presence.peek().is_animating() {
+            presence.write().settle();
+        }
+    });
+
+    let presence = presence();
+    if !presence.is_mounted() {
+        return rsx! {};
+    }
+    let pointer = exit_pointer_style(presence == Presence::Exiting, disable_pointer_during_exit);
+
+    rsx! {
+        div {
+            style: "{layer_style(transform.get(), opacity.get())}{pointer}",
+            {children}
+        }
+    }
+}
+
+/// Transitions between content keyed by `value`, e.g. tab panels
+///
+/// The outgoing content plays the exit half of `variant` while the incoming content
+/// plays the enter half; both stay mounted until the transition settles.
+#[component]
+pub fn AnimatedSwitch<K: Clone + PartialEq + 'static>(
+    value: K,
+    render: Callback<K, Element>,
+    variant: Option<TransitionVariant>,
+    timing: Option<AnimationConfig>,
+    /// Stop clicks and hovers on the outgoing content while it animates out
+    #[props(default)]
+    disable_pointer_during_exit: bool,
+) -> Element {
+    let variant = variant.unwrap_or(TransitionVariant::Fade);
+    let config = variant.get_config();
+    let timing = timing.unwrap_or_else(|| config.timing.clone());
+
+    let mut state = use_signal(|| SwitchState::new(value.clone()));
+    let mut enter_transform = use_motion(Transform::identity());
+    let mut enter_opacity = use_motion(1.0f32);
+    let mut exit_transform = use_motion(Transform::identity());
+    let mut exit_opacity = use_motion(1.0f32);
+
+    use_effect(use_reactive((&value,), move |(value,)| {
+        if !state.write().switch(value) {
+            return;
+        }
+
+        // The outgoing layer continues from wherever the incoming one was
+        exit_transform.set(enter_transform.peek());
+        exit_opacity.set(enter_opacity.peek());
+        enter_transform.set(config.enter_start);
+        enter_opacity.set(0.0);
+
+        play_transition(
+            &mut exit_transform,
+            &mut exit_opacity,
+            &config,
+            TransitionPhase::Exit,
+            timing.clone(),
+        );
+        play_transition(
+            &mut enter_transform,
+            &mut enter_opacity,
+            &config,
+            TransitionPhase::Enter,
+            timing.clone(),
+        );
+    }));
+
+    // Unmount the outgoing content once everything comes to rest
+    use_effect(move || {
+        let animating = enter_transform.is_animating()
+            || enter_opacity.is_animating()
+            || exit_transform.is_animating()
+            || exit_opacity.is_animating();
+        if !animating && state.peek().leaving().is_some() {
+            state.write().settle();
+        }
+    });
+
+    let state = state.read();
+    let (current, current_key) = state.current();
+    let leaving = state.leaving().map(|(value, key)| (value.clone(), key));
+    let leaving_pointer = exit_pointer_style(true, disable_pointer_during_exit);
+
+    rsx! {
+        div {
+            style: "position: relative;",
+            if let Some((leaving, leaving_key)) = leaving {
+                div {
+                    key: "{leaving_key}",
+                    style: "position: absolute; inset: 0; {layer_style(exit_transform.get(), exit_opacity.get())}{leaving_pointer}",
+                    {render.call(leaving)}
+                }
+            }
+            // Keyed as the root of its own template, so a switch remounts it
+            {rsx! {
+                div {
+                    key: "{current_key}",
+                    style: layer_style(enter_transform.get(), enter_opacity.get()),
+                    {render.call(current.clone())}
+                }
+            }}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use super::*;
+
+    #[test]
+    fn test_presence_show_and_hide() {
+        let mut presence = Presence::new(false);
+        assert!(!presence.is_mounted());
+
+        assert!(presence.show());
+        assert_eq!(presence, Presence::Entering);
+        presence.settle();
+        assert_eq!(presence, Presence::Visible);
+
+        assert!(presence.hide());
+        // Stays mounted while the exit animation plays
+        assert!(presence.is_mounted());
+        presence.settle();
+        assert!(!presence.is_mounted());
+    }
+
+    #[test]
+    fn test_presence_rapid_toggling() {
+        let mut presence = Presence::new(false);
+        assert!(presence.show());
+        assert!(presence.hide());
+        // Re-showing mid-exit reverses without unmounting
+        assert!(presence.show());
+        assert_eq!(presence, Presence::Entering);
+        assert!(presence.is_mounted());
+
+        // Repeated requests in the same direction don't restart the animation
+        assert!(!presence.show());
+        presence.settle();
+        assert!(!presence.show());
+        assert!(presence.hide());
+        assert!(!presence.hide());
+    }
+
+    #[test]
+    fn test_presence_starts_settled() {
+        assert_eq!(Presence::new(true), Presence::Visible);
+        assert!(!Presence::new(true).is_animating());
+    }
+
+    #[test]
+    fn test_pointer_disabled_only_while_exiting() {
+        let mut presence = Presence::new(false);
+        let style = |presence: Presence, opt_in| {
+            format!(
+                "{}{}",
+                layer_style(Transform::identity(), 1.0),
+                exit_pointer_style(presence == Presence::Exiting, opt_in)
+            )
+        };
+
+        presence.show();
+        assert!(!style(presence, true).contains("pointer-events"));
+        presence.settle();
+        assert!(!style(presence, true).contains("pointer-events"));
+        presence.hide();
+        assert!(style(presence, true).ends_with("pointer-events: none;"));
+        assert!(!style(presence, false).contains("pointer-events"));
+        // Reversing mid-exit takes clicks again right away
+        presence.show();
+        assert!(!style(presence, true).contains("pointer-events"));
+    }
+
+    #[test]
+    fn test_switch_keeps_leaving_content() {
+        let mut state = SwitchState::new("home");
+        assert!(!state.switch("home"));
+
+        assert!(state.switch("settings"));
+        assert_eq!(state.current(), (&"settings", 1));
+        assert_eq!(state.leaving(), Some((&"home", 0)));
+
+        state.settle();
+        assert_eq!(state.leaving(), None);
+    }
+
+    #[test]
+    fn test_switch_rapid_changes() {
+        let mut state = SwitchState::new(0);
+        state.switch(1);
+        state.switch(2);
+
+        // Only the half-entered content leaves; the first one is dropped
+        assert_eq!(state.current(), (&2, 2));
+        assert_eq!(state.leaving(), Some((&1, 1)));
+
+        // Switching back to the leaving content brings it in fresh
+        state.switch(1);
+        assert_eq!(state.current(), (&1, 3));
+        assert_eq!(state.leaving(), Some((&2, 2)));
+    }
+
+    #[test]
+    fn test_switch_remounts_the_incoming_content() {
+        thread_local! {
+            static TAB: Cell<Option<Signal<&'static str>>> = const { Cell::new(None) };
+            static MOUNTED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+        }
+
+        #[component]
+        fn Panel(name: &'static str) -> Element {
+            use_hook(|| MOUNTED.with_borrow_mut(|mounted| mounted.push(name)));
+            rsx! { "{name}" }
+        }
+
+        fn app() -> Element {
+            let tab = use_signal(|| "home");
+            TAB.set(Some(tab));
+            rsx! {
+                AnimatedSwitch {
+                    value: tab(),
+                    render: move |name| rsx! { Panel { name } },
+                }
+            }
+        }
+
+        let runtime = crate::test_support::frame_runtime();
+        let _guard = runtime.enter();
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let Some(mut tab) = TAB.get() else {
+            unreachable!("the app stores its tab");
+        };
+
+        dom.in_runtime(|| tab.set("settings"));
+        for _ in 0..2 {
+            dom.process_events();
+            dom.render_immediate(&mut dioxus::dioxus_core::NoOpMutations);
+        }
+        // The new panel starts fresh rather than taking over the old one's state
+        assert!(MOUNTED.with_borrow(|mounted| mounted.contains(&"settings")));
+    }
+}
@@ -0,0 +1,310 @@
+//! Route transitions without the derive
+//!
+//! `#[derive(MotionTransitions)]` is the usual way to give routes transitions,
+//! but it can't be used when the route enum comes from another macro. For those
+//! routes, describe the transitions with a [`TransitionsBuilder`] and hand the
+//! result to a [`TransitionOutlet`](super::page_transition::TransitionOutlet),
+//! which only needs the route to be `Routable`.
+//!
+//! # Precedence
+//!
+//! For the route being entered, the first matching rule of the highest kind wins:
+//!
+//! 1. An exact route added with [`on`](TransitionsBuilder::on).
+//! 2. A path prefix added with [`on_prefix`](TransitionsBuilder::on_prefix); the
+//!    longest matching prefix wins.
+//! 3. A predicate added with [`on_match`](TransitionsBuilder::on_match), in the
+//!    order they were added.
+//! 4. The [`default`](TransitionsBuilder::default), a fade unless set.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use dioxus::prelude::*;
+//! use dioxus_motion2::prelude::*;
+//!
+//! #[component]
+//! fn Shell() -> Element {
+//!     let transitions = use_hook(|| {
+//!         TransitionsBuilder::<Route>::new()
+//!             .on(Route::Home {}, TransitionVariant::Fade)
+//!             .on_prefix("/docs", TransitionVariant::SlideLeft)
+//!             .on_match(|route| matches!(route, Route::Settings { .. }), TransitionVariant::SlideUp)
+//!             .default(TransitionVariant::Fade)
+//!             .build()
+//!     });
+//!
+//!     rsx! { TransitionOutlet::<Route> { transitions } }
+//! }
+//! ```
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::animation::AnimationConfig;
+
+use super::utility::TransitionVariant;
+
+/// Predicate deciding whether a rule applies to a route
+type Predicate<R> = Arc<dyn Fn(&R) -> bool>;
+
+struct Rules<R> {
+    exact: Vec<(R, TransitionVariant)>,
+    prefixes: Vec<(String, TransitionVariant)>,
+    predicates: Vec<(Predicate<R>, TransitionVariant)>,
+    default: TransitionVariant,
+    layout_depth: fn(&R) -> usize,
+    /// Transition for a pair of routes, taking over from the rules above
+    between: Option<fn(&R, &R) -> TransitionVariant>,
+    /// Timing of transitions into a route
+    timing: fn(&R) -> Option<AnimationConfig>,
+}
+
+/// Builds the [`RouteTransitions`] for routes without the derive
+pub struct TransitionsBuilder<R> {
+    rules: Rules<R>,
+}
+
+impl<R: PartialEq + Display> TransitionsBuilder<R> {
+    /// Start with no rules and a fade as the default
+    pub fn new() -> Self {
+        Self {
+            rules: Rules {
+                exact: Vec::new(),
+                prefixes: Vec::new(),
+                predicates: Vec::new(),
+                default: TransitionVariant::Fade,
+                layout_depth: |_| 0,
+                between: None,
+                timing: |_| None,
+            },
+        }
+    }
+
+    /// Play `variant` when entering exactly `route`
+    pub fn on(mut self, route: R, variant: TransitionVariant) -> Self {
+        self.rules.exact.push((route, variant));
+        self
+    }
+
+    /// Play `variant` when entering a route whose path starts with `prefix`
+    ///
+    /// Matches whole path segments: `/docs` matches `/docs` and `/docs/intro`,
+    /// but not `/docsets`.
+    pub fn on_prefix(mut self, prefix: impl Into<String>, variant: TransitionVariant) -> Self {
+        self.rules.prefixes.push((prefix.into(), variant));
+        self
+    }
+
+    /// Play `variant` when entering a route `predicate` accepts
+    pub fn on_match<F: Fn(&R) -> bool + 'static>(
+        mut self,
+        predicate: F,
+        variant: TransitionVariant,
+    ) -> Self {
+        self.rules.predicates.push((Arc::new(predicate), variant));
+        self
+    }
+
+    /// Play `variant` when no other rule matches
+    pub fn default(mut self, variant: TransitionVariant) -> Self {
+        self.rules.default = variant;
+        self
+    }
+
+    /// Set how many layouts wrap each route, as nesting `#[layout]` would
+    ///
+    /// Outlets use it to decide which level animates. Without it, every route
+    /// counts as not wrapped in a layout.
+    pub fn layout_depth(mut self, depth: fn(&R) -> usize) -> Self {
+        self.rules.layout_depth = depth;
+        self
+    }
+
+    /// Finish the rules
+    pub fn build(self) -> RouteTransitions<R> {
+        RouteTransitions {
+            rules: Arc::new(self.rules),
+        }
+    }
+}
+
+impl<R: PartialEq + Display> Default for TransitionsBuilder<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Transition rules for an outlet, built with a [`TransitionsBuilder`]
+///
+/// Cheap to clone. Two values are equal only if they share the same build, so
+/// build them once, e.g. in `use_hook`, to keep outlets from re-rendering.
+pub struct RouteTransitions<R> {
+    rules: Arc<Rules<R>>,
+}
+
+impl<R> Clone for RouteTransitions<R> {
+    fn clone(&self) -> Self {
+        Self {
+            rules: self.rules.clone(),
+        }
+    }
+}
+
+impl<R> PartialEq for RouteTransitions<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.rules, &other.rules)
+    }
+}
+
+impl<R: PartialEq + Display> RouteTransitions<R> {
+    /// Rules that defer to per-pair transitions, layout depths and timings
+    pub(crate) fn from_fns(
+        between: fn(&R, &R) -> TransitionVariant,
+        layout_depth: fn(&R) -> usize,
+        timing: fn(&R) -> Option<AnimationConfig>,
+    ) -> Self {
+        let mut builder = TransitionsBuilder::new().layout_depth(layout_depth);
+        builder.rules.between = Some(between);
+        builder.rules.timing = timing;
+        builder.build()
+    }
+
+    /// The transition played when entering `route`
+    pub fn resolve(&self, route: &R) -> TransitionVariant {
+        let rules = &self.rules;
+        if let Some((_, variant)) = rules.exact.iter().find(|(exact, _)| exact == route) {
+            return variant.clone();
+        }
+
+        let path = route.to_string();
+        let prefix = rules
+            .prefixes
+            .iter()
+            .rev()
+            .filter(|(prefix, _)| has_path_prefix(&path, prefix))
+            .max_by_key(|(prefix, _)| prefix.trim_end_matches('/').len());
+        if let Some((_, variant)) = prefix {
+            return variant.clone();
+        }
+
+        rules
+            .predicates
+            .iter()
+            .find(|(predicate, _)| predicate(route))
+            .map_or_else(|| rules.default.clone(), |(_, variant)| variant.clone())
+    }
+
+    /// The transition played when navigating from `from` to `to`
+    pub fn transition(&self, from: &R, to: &R) -> TransitionVariant {
+        self.rules
+            .between
+            .map_or_else(|| self.resolve(to), |between| between(from, to))
+    }
+
+    /// How many layouts wrap `route`
+    pub(crate) fn layout_depth(&self, route: &R) -> usize {
+        (self.rules.layout_depth)(route)
+    }
+
+    /// Timing of transitions into `route`, if it sets its own
+    pub(crate) fn timing(&self, route: &R) -> Option<AnimationConfig> {
+        (self.rules.timing)(route)
+    }
+}
+
+/// Whether `prefix` is a run of whole leading segments of `path`
+fn has_path_prefix(path: &str, prefix: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '?', '#']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Route {
+        Home,
+        Docs(&'static str),
+        Docsets,
+        Settings(&'static str),
+    }
+
+    impl Display for Route {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::Home => write!(f, "/"),
+                Self::Docs("") => write!(f, "/docs"),
+                Self::Docs(page) => write!(f, "/docs/{page}"),
+                Self::Docsets => write!(f, "/docsets"),
+                Self::Settings(page) => write!(f, "/settings/{page}"),
+            }
+        }
+    }
+
+    fn transitions() -> RouteTransitions<Route> {
+        TransitionsBuilder::new()
+            .on_match(|_| true, TransitionVariant::ZoomIn)
+            .on_prefix("/docs", TransitionVariant::SlideLeft)
+            .on_prefix("/docs/api/", TransitionVariant::SlideUp)
+            .on(Route::Docs("intro"), TransitionVariant::SlideRight)
+            .default(TransitionVariant::Fade)
+            .build()
+    }
+
+    #[test]
+    fn test_exact_beats_prefix_beats_predicate() {
+        let transitions = transitions();
+        assert!(transitions.resolve(&Route::Docs("intro")) == TransitionVariant::SlideRight);
+        assert!(transitions.resolve(&Route::Docs("")) == TransitionVariant::SlideLeft);
+        assert!(transitions.resolve(&Route::Docs("guide")) == TransitionVariant::SlideLeft);
+        assert!(transitions.resolve(&Route::Home) == TransitionVariant::ZoomIn);
+    }
+
+    #[test]
+    fn test_longest_prefix_wins() {
+        let transitions = transitions();
+        assert!(transitions.resolve(&Route::Docs("api/motion")) == TransitionVariant::SlideUp);
+    }
+
+    #[test]
+    fn test_prefix_matches_whole_segments() {
+        let transitions = transitions();
+        // `/docsets` doesn't start with the `/docs` segment, so the predicate decides
+        assert!(transitions.resolve(&Route::Docsets) == TransitionVariant::ZoomIn);
+    }
+
+    #[test]
+    fn test_predicates_in_order_then_default() {
+        let transitions = TransitionsBuilder::new()
+            .on_match(
+                |route| matches!(route, Route::Settings("privacy")),
+                TransitionVariant::SlideUp,
+            )
+            .on_match(
+                |route| matches!(route, Route::Settings(_)),
+                TransitionVariant::SlideDown,
+            )
+            .default(TransitionVariant::ZoomOut)
+            .build();
+        assert!(transitions.resolve(&Route::Settings("privacy")) == TransitionVariant::SlideUp);
+        assert!(transitions.resolve(&Route::Settings("account")) == TransitionVariant::SlideDown);
+        assert!(transitions.resolve(&Route::Home) == TransitionVariant::ZoomOut);
+
+        // Without a default, unmatched routes fade
+        let fallback = TransitionsBuilder::<Route>::new().build();
+        assert!(fallback.resolve(&Route::Home) == TransitionVariant::Fade);
+    }
+
+    #[test]
+    fn test_transition_uses_destination() {
+        let transitions = transitions();
+        let (home, intro) = (Route::Home, Route::Docs("intro"));
+        assert!(transitions.transition(&home, &intro) == TransitionVariant::SlideRight);
+        assert!(transitions.transition(&intro, &home) == TransitionVariant::ZoomIn);
+        assert!(transitions == transitions.clone());
+        assert!(transitions != self::transitions());
+    }
+}
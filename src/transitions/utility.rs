@@ -1,6 +1,15 @@
-use crate::prelude::Transform;
+use easer::functions::{Back, Cubic, Easing};
 
-#[derive(Clone)]
+use crate::MotionValue;
+use crate::animations::tween::EasingFunction;
+use crate::prelude::{AnimationConfig, AnimationMode, Duration, Spring, Transform, Tween};
+
+/// Start and end transforms of both halves of a transition
+///
+/// Translations (`x` and `y`) are percentages of the element's own size, not
+/// pixels, so a config never depends on the window size; render them with
+/// [`transition_transform`].
+#[derive(Clone, PartialEq)]
 pub struct TransitionConfig {
     // For the page that's leaving (FROM)
     pub exit_start: Transform, // Starting position of exiting page
@@ -9,6 +18,64 @@ pub struct TransitionConfig {
     // For the page that's entering (TO)
     pub enter_start: Transform, // Starting position of entering page
     pub enter_end: Transform,   // Final position of entering page
+
+    /// Animation suiting the movement, the variant's
+    /// [`default_timing`](TransitionVariant::default_timing)
+    pub timing: AnimationConfig,
+}
+
+/// Which half of a [`TransitionConfig`] to play
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionPhase {
+    /// Move to `enter_end` and fade in
+    Enter,
+    /// Move to `exit_end` and fade out
+    Exit,
+}
+
+/// Play one half of a transition on a transform and an opacity
+///
+/// Only the targets are set. Place the values on `enter_start` and `0.0` first
+/// when content appears, or leave them where they are to reverse smoothly
+/// mid-animation. This drives [`AnimatedShow`](super::presence::AnimatedShow),
+/// [`AnimatedSwitch`](super::presence::AnimatedSwitch) and route transitions,
+/// and works the same for hand-rolled modals or drawers.
+pub fn play_transition(
+    transform: &mut MotionValue<Transform>,
+    opacity: &mut MotionValue<f32>,
+    config: &TransitionConfig,
+    phase: TransitionPhase,
+    timing: AnimationConfig,
+) {
+    let (target, visibility) = match phase {
+        TransitionPhase::Enter => (config.enter_end, 1.0),
+        TransitionPhase::Exit => (config.exit_end, 0.0),
+    };
+    transform.animate_to_with_config(target, timing.clone());
+    opacity.animate_to_with_config(visibility, timing);
+}
+
+/// CSS `transform` for a value played with [`play_transition`]
+///
+/// Translations are written as percentages, which the browser resolves
+/// against the element on every layout. A window resize mid-transition then
+/// keeps the element at the same relative position instead of jumping.
+/// Rotations are written in radians, like every angle of a [`Transform`].
+pub fn transition_transform(transform: &Transform) -> String {
+    // Adding zero turns -0 into 0
+    let rotation = if transform.rotation == 0.0 {
+        String::new()
+    } else {
+        format!(" rotate({}rad)", transform.rotation)
+    };
+    format!(
+        "translate3d({}%, {}%, 0){} scale({}, {})",
+        transform.x + 0.0,
+        transform.y + 0.0,
+        rotation,
+        transform.scale_x + 0.0,
+        transform.scale_y + 0.0
+    )
 }
 
 #[derive(PartialEq, Clone)]
@@ -74,8 +141,97 @@ pub enum TransitionVariant {
 }
 
 impl TransitionVariant {
+    /// Suggested timing for this transition, scaled to how far the content moves
+    ///
+    /// Used by `AnimatedOutlet` and the presence components
+    /// when neither the route nor the outlet provides a timing, and carried by
+    /// [`get_config`](Self::get_config). Small changes read best when quick,
+    /// while full-page movement needs time to be followed; bouncy variants
+    /// use an underdamped spring so they overshoot and settle back:
+    ///
+    /// | Variants | Animation |
+    /// |---|---|
+    /// | `Fade` | 180ms tween, cubic ease-out |
+    /// | `ScaleUp`, `ScaleDown`, `ScaleUpFade`, `ScaleDownFade`, `ZoomIn`, `ZoomOut` | 250ms tween, cubic ease-out |
+    /// | `SlideLeft`, `SlideRight`, `SlideUp`, `SlideDown` and their `Fade` variants | 350ms tween, cubic ease-in-out |
+    /// | `SlideDiagonal*` | 380ms tween, cubic ease-in-out |
+    /// | `RotateLeft`, `RotateRight`, `FlipHorizontal`, `FlipVertical` and their `Fade` variants | 400ms tween, cubic ease-in-out |
+    /// | `SwingIn`, `SwingOut` | 400ms tween, back ease-out |
+    /// | `ScaleRotateFade`, `SlideFadeRotate`, `ScaleFadeFlip`, `RotateScaleSlide` | 400ms tween, cubic ease-in-out |
+    /// | `SpiralIn`, `SpiralOut` | 450ms tween, cubic ease-in-out |
+    /// | `BounceIn`, `BounceOut` | spring, stiffness 400 and damping 18 |
+    /// | `ElasticIn`, `ElasticOut` | spring, stiffness 300 and damping 12 |
+    pub fn default_timing(&self) -> AnimationConfig {
+        let tween = |millis: u64, easing: EasingFunction| {
+            AnimationMode::Tween(
+                Tween::new()
+                    .duration(Duration::from_millis(millis))
+                    .easing(easing),
+            )
+        };
+        let spring = |stiffness: f32, damping: f32| {
+            AnimationMode::Spring(Spring::new().stiffness(stiffness).damping(damping))
+        };
+
+        let mode = match self {
+            TransitionVariant::Fade => tween(180, Cubic::ease_out),
+            TransitionVariant::ScaleUp
+            | TransitionVariant::ScaleDown
+            | TransitionVariant::ScaleUpFade
+            | TransitionVariant::ScaleDownFade
+            | TransitionVariant::ZoomIn
+            | TransitionVariant::ZoomOut => tween(250, Cubic::ease_out),
+            TransitionVariant::SlideLeft
+            | TransitionVariant::SlideRight
+            | TransitionVariant::SlideUp
+            | TransitionVariant::SlideDown
+            | TransitionVariant::SlideLeftFade
+            | TransitionVariant::SlideRightFade
+            | TransitionVariant::SlideUpFade
+            | TransitionVariant::SlideDownFade => tween(350, Cubic::ease_in_out),
+            TransitionVariant::SlideDiagonalUpLeft
+            | TransitionVariant::SlideDiagonalUpRight
+            | TransitionVariant::SlideDiagonalDownLeft
+            | TransitionVariant::SlideDiagonalDownRight => tween(380, Cubic::ease_in_out),
+            TransitionVariant::RotateLeft
+            | TransitionVariant::RotateRight
+            | TransitionVariant::RotateLeftFade
+            | TransitionVariant::RotateRightFade
+            | TransitionVariant::FlipHorizontal
+            | TransitionVariant::FlipVertical
+            | TransitionVariant::FlipHorizontalFade
+            | TransitionVariant::FlipVerticalFade => tween(400, Cubic::ease_in_out),
+            TransitionVariant::SwingIn | TransitionVariant::SwingOut => tween(400, Back::ease_out),
+            TransitionVariant::ScaleRotateFade
+            | TransitionVariant::SlideFadeRotate
+            | TransitionVariant::ScaleFadeFlip
+            | TransitionVariant::RotateScaleSlide => tween(400, Cubic::ease_in_out),
+            TransitionVariant::SpiralIn | TransitionVariant::SpiralOut => {
+                tween(450, Cubic::ease_in_out)
+            }
+            TransitionVariant::BounceIn | TransitionVariant::BounceOut => spring(400.0, 18.0),
+            TransitionVariant::ElasticIn | TransitionVariant::ElasticOut => spring(300.0, 12.0),
+        };
+
+        AnimationConfig::new(mode)
+    }
+
+    /// Pick the timing for a transition into a route
+    ///
+    /// A route-specific timing wins over the outlet's, and both win over
+    /// [`TransitionVariant::default_timing`].
+    pub fn resolve_timing(
+        &self,
+        route: Option<AnimationConfig>,
+        outlet: Option<AnimationConfig>,
+    ) -> AnimationConfig {
+        route.or(outlet).unwrap_or_else(|| self.default_timing())
+    }
+
+    /// Transforms of both halves of this transition, with the timing they suit
     pub fn get_config(&self) -> TransitionConfig {
         let identity = Transform::identity();
+        let timing = self.default_timing();
 
         match self {
             TransitionVariant::SlideLeft => {
@@ -84,6 +240,7 @@ impl TransitionVariant {
                     exit_end: Transform::new(-100.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit left
                     enter_start: Transform::new(100.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from right
                     enter_end: identity, // End in place
+                    timing,
                 }
             }
 
@@ -93,6 +250,7 @@ impl TransitionVariant {
                     exit_end: Transform::new(100.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit right
                     enter_start: Transform::new(-100.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from left
                     enter_end: identity, // End in place
+                    timing,
                 }
             }
 
@@ -102,6 +260,7 @@ impl TransitionVariant {
                     exit_end: Transform::new(0.0, -100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit up
                     enter_start: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from bottom
                     enter_end: identity, // End in place
+                    timing,
                 }
             }
 
@@ -111,6 +270,7 @@ impl TransitionVariant {
                     exit_end: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit down
                     enter_start: Transform::new(0.0, -100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from top
                     enter_end: identity, // End in place
+                    timing,
                 }
             }
 
@@ -119,211 +279,458 @@ impl TransitionVariant {
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Fade out completely
                 enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Start invisible
                 enter_end: identity,  // Fade in completely
+                timing,
             },
             TransitionVariant::ScaleUp => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0),    // Shrink to nothing
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                                            // Grow to full size
+                timing,
             },
             TransitionVariant::ScaleDown => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0), // Grow to twice size
                 enter_start: Transform::new(0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0), // Start twice size
                 enter_end: identity,                                         // Shrink to full size
+                timing,
             },
             TransitionVariant::FlipHorizontal => TransitionConfig {
-                exit_start: identity,                                          // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 180.0, 0.0, 0.0), // Flip 180 degrees horizontally
-                enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, -180.0, 0.0, 0.0), // Start flipped 180 degrees horizontally
-                enter_end: identity,                                               // End in place
+                exit_start: identity,                     // Start in place
+                exit_end: Transform::scale(-1.0, 1.0),    // Mirror horizontally
+                enter_start: Transform::scale(-1.0, 1.0), // Start mirrored horizontally
+                enter_end: identity,                      // End in place
+                timing,
             },
             TransitionVariant::FlipVertical => TransitionConfig {
-                exit_start: identity,                                          // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 180.0, 0.0), // Flip 180 degrees vertically
-                enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, -180.0, 0.0), // Start flipped 180 degrees vertically
-                enter_end: identity,                                               // End in place
+                exit_start: identity,                     // Start in place
+                exit_end: Transform::scale(1.0, -1.0),    // Mirror vertically
+                enter_start: Transform::scale(1.0, -1.0), // Start mirrored vertically
+                enter_end: identity,                      // End in place
+                timing,
             },
             TransitionVariant::RotateLeft => TransitionConfig {
-                exit_start: identity,                                         // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0), // Rotate 90 degrees to the left
-                enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, -90.0, 0.0, 0.0), // Start rotated 90 degrees to the right
-                enter_end: identity,                                              // End in place
+                exit_start: identity, // Start in place
+                exit_end: Transform::from_degrees(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0), // Rotate 90 degrees to the left
+                enter_start: Transform::from_degrees(0.0, 0.0, 1.0, 1.0, -90.0, 0.0, 0.0), // Start rotated 90 degrees to the right
+                enter_end: identity, // End in place
+                timing,
             },
             TransitionVariant::RotateRight => TransitionConfig {
-                exit_start: identity,                                          // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, -90.0, 0.0, 0.0), // Rotate 90 degrees to the right
-                enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0), // Start rotated 90 degrees to the left
-                enter_end: identity,                                             // End in place
+                exit_start: identity, // Start in place
+                exit_end: Transform::from_degrees(0.0, 0.0, 1.0, 1.0, -90.0, 0.0, 0.0), // Rotate 90 degrees to the right
+                enter_start: Transform::from_degrees(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0), // Start rotated 90 degrees to the left
+                enter_end: identity, // End in place
+                timing,
             },
             TransitionVariant::SlideUpFade => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, -100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit up
                 enter_start: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from bottom
                 enter_end: identity,                                              // End in place
+                timing,
             },
             TransitionVariant::SlideDownFade => TransitionConfig {
                 exit_start: identity,                                          // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit down
                 enter_start: Transform::new(0.0, -100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from top
                 enter_end: identity,                                           // End in place
+                timing,
             },
             TransitionVariant::ScaleUpFade => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0),    // Shrink to nothing
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                                            // Grow to full size
+                timing,
             },
             TransitionVariant::BounceIn => TransitionConfig {
                 exit_start: identity,                                        // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Start from bottom
                 enter_end: identity,                                              // End in place
+                timing,
             },
             TransitionVariant::BounceOut => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Start in place
                 enter_end: identity,                                            // No change
+                timing,
             },
             TransitionVariant::ScaleDownFade => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0), // Grow to twice size
                 enter_start: Transform::new(0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0), // Start twice size
                 enter_end: identity,                                         // Shrink to full size
+                timing,
             },
             TransitionVariant::RotateLeftFade => TransitionConfig {
-                exit_start: identity,                                         // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0), // Rotate 90 degrees to the left
-                enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, -90.0, 0.0, 0.0), // Start rotated 90 degrees to the right
-                enter_end: identity,                                              // End in place
+                exit_start: identity, // Start in place
+                exit_end: Transform::from_degrees(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0), // Rotate 90 degrees to the left
+                enter_start: Transform::from_degrees(0.0, 0.0, 1.0, 1.0, -90.0, 0.0, 0.0), // Start rotated 90 degrees to the right
+                enter_end: identity, // End in place
+                timing,
             },
             TransitionVariant::RotateRightFade => TransitionConfig {
-                exit_start: identity,                                          // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, -90.0, 0.0, 0.0), // Rotate 90 degrees to the right
-                enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0), // Start rotated 90 degrees to the left
-                enter_end: identity,                                             // End in place
+                exit_start: identity, // Start in place
+                exit_end: Transform::from_degrees(0.0, 0.0, 1.0, 1.0, -90.0, 0.0, 0.0), // Rotate 90 degrees to the right
+                enter_start: Transform::from_degrees(0.0, 0.0, 1.0, 1.0, 90.0, 0.0, 0.0), // Start rotated 90 degrees to the left
+                enter_end: identity, // End in place
+                timing,
             },
             TransitionVariant::FlipHorizontalFade => TransitionConfig {
-                exit_start: identity,                                          // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 180.0, 0.0, 0.0), // Flip 180 degrees horizontally
-                enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, -180.0, 0.0, 0.0), // Start flipped 180 degrees horizontally
-                enter_end: identity,                                               // End in place
+                exit_start: identity,                     // Start in place
+                exit_end: Transform::scale(-1.0, 1.0),    // Mirror horizontally
+                enter_start: Transform::scale(-1.0, 1.0), // Start mirrored horizontally
+                enter_end: identity,                      // End in place
+                timing,
             },
             TransitionVariant::FlipVerticalFade => TransitionConfig {
-                exit_start: identity,                                          // Start in place
-                exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 180.0, 0.0), // Flip 180 degrees vertically
-                enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, -180.0, 0.0), // Start flipped 180 degrees vertically
-                enter_end: identity,                                               // End in place
+                exit_start: identity,                     // Start in place
+                exit_end: Transform::scale(1.0, -1.0),    // Mirror vertically
+                enter_start: Transform::scale(1.0, -1.0), // Start mirrored vertically
+                enter_end: identity,                      // End in place
+                timing,
             },
             TransitionVariant::ZoomIn => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                                            // Grow to full size
+                timing,
             },
             TransitionVariant::ZoomOut => TransitionConfig {
                 exit_start: identity,                                         // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0),  // Grow to twice size
                 enter_start: identity,                                        // Start in place
                 enter_end: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Shrink to full size
+                timing,
             },
             TransitionVariant::SlideDiagonalUpLeft => TransitionConfig {
                 exit_start: identity, // Start in place
                 exit_end: Transform::new(-100.0, -100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit up and left
                 enter_start: Transform::new(100.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from bottom right
                 enter_end: identity,                                                // End in place
+                timing,
             },
             TransitionVariant::SlideDiagonalUpRight => TransitionConfig {
                 exit_start: identity, // Start in place
                 exit_end: Transform::new(100.0, -100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit up and right
                 enter_start: Transform::new(-100.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from bottom left
                 enter_end: identity,                                                 // End in place
+                timing,
             },
             TransitionVariant::SlideDiagonalDownLeft => TransitionConfig {
                 exit_start: identity, // Start in place
                 exit_end: Transform::new(-100.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit down and left
                 enter_start: Transform::new(100.0, -100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from top right
                 enter_end: identity,                                                 // End in place
+                timing,
             },
             TransitionVariant::SlideDiagonalDownRight => TransitionConfig {
                 exit_start: identity, // Start in place
                 exit_end: Transform::new(100.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit down and right
                 enter_start: Transform::new(-100.0, -100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from top left
                 enter_end: identity, // End in place
+                timing,
             },
             TransitionVariant::SpiralIn => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                                            // Grow to full size
+                timing,
             },
             TransitionVariant::SpiralOut => TransitionConfig {
                 exit_start: identity,                                         // Start in place
                 exit_end: Transform::new(0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 0.0),  // Grow to twice size
                 enter_start: identity,                                        // Start in place
                 enter_end: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Shrink to full size
+                timing,
             },
             TransitionVariant::ElasticIn => TransitionConfig {
                 exit_start: identity,                                        // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Start from bottom
                 enter_end: identity,                                              // End in place
+                timing,
             },
             TransitionVariant::ElasticOut => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Start in place
                 enter_end: identity,                                            // No change
+                timing,
             },
             TransitionVariant::SwingIn => TransitionConfig {
                 exit_start: identity,                                        // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // No change
                 enter_start: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Start from bottom
                 enter_end: identity,                                              // End in place
+                timing,
             },
             TransitionVariant::SwingOut => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 100.0, 1.0, 1.0, 0.0, 0.0, 0.0),  // Exit to bottom
                 enter_start: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Start in place
                 enter_end: identity,                                            // No change
+                timing,
             },
             TransitionVariant::SlideLeftFade => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(-100.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit left
                 enter_start: Transform::new(100.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from right
                 enter_end: identity,                                              // End in place
+                timing,
             },
             TransitionVariant::SlideRightFade => TransitionConfig {
                 exit_start: identity,                                          // Start in place
                 exit_end: Transform::new(100.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Exit right
                 enter_start: Transform::new(-100.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0), // Enter from left
                 enter_end: identity,                                               // End in place
+                timing,
             },
             TransitionVariant::ScaleRotateFade => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                                            // Grow to full size
+                timing,
             },
             TransitionVariant::SlideFadeRotate => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                                            // Grow to full size
+                timing,
             },
             TransitionVariant::ScaleFadeFlip => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                                            // Grow to full size
+                timing,
             },
             TransitionVariant::RotateScaleSlide => TransitionConfig {
                 exit_start: identity,                                           // Start in place
                 exit_end: Transform::new(0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0),    // No change
                 enter_start: Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0), // Start as nothing
                 enter_end: identity,                                            // Grow to full size
+                timing,
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Animatable, AnimationEngine};
+
+    const ALL: [TransitionVariant; 39] = [
+        TransitionVariant::SlideLeft,
+        TransitionVariant::SlideRight,
+        TransitionVariant::SlideUp,
+        TransitionVariant::SlideDown,
+        TransitionVariant::Fade,
+        TransitionVariant::ScaleUp,
+        TransitionVariant::ScaleDown,
+        TransitionVariant::FlipHorizontal,
+        TransitionVariant::FlipVertical,
+        TransitionVariant::RotateLeft,
+        TransitionVariant::RotateRight,
+        TransitionVariant::SlideUpFade,
+        TransitionVariant::SlideDownFade,
+        TransitionVariant::ScaleUpFade,
+        TransitionVariant::BounceIn,
+        TransitionVariant::BounceOut,
+        TransitionVariant::ScaleDownFade,
+        TransitionVariant::RotateLeftFade,
+        TransitionVariant::RotateRightFade,
+        TransitionVariant::FlipHorizontalFade,
+        TransitionVariant::FlipVerticalFade,
+        TransitionVariant::ZoomIn,
+        TransitionVariant::ZoomOut,
+        TransitionVariant::SlideDiagonalUpLeft,
+        TransitionVariant::SlideDiagonalUpRight,
+        TransitionVariant::SlideDiagonalDownLeft,
+        TransitionVariant::SlideDiagonalDownRight,
+        TransitionVariant::SpiralIn,
+        TransitionVariant::SpiralOut,
+        TransitionVariant::ElasticIn,
+        TransitionVariant::ElasticOut,
+        TransitionVariant::SwingIn,
+        TransitionVariant::SwingOut,
+        TransitionVariant::SlideLeftFade,
+        TransitionVariant::SlideRightFade,
+        TransitionVariant::ScaleRotateFade,
+        TransitionVariant::SlideFadeRotate,
+        TransitionVariant::ScaleFadeFlip,
+        TransitionVariant::RotateScaleSlide,
+    ];
+
+    /// How long the animation of `config` takes across a full page
+    fn duration_of(config: &AnimationConfig) -> Duration {
+        match &config.mode {
+            AnimationMode::Tween(tween) => tween.duration,
+            AnimationMode::Keyframes(track) => track.duration(),
+            AnimationMode::Spring(spring) => {
+                spring.settle_time(100.0, 0.0, 0.5).unwrap_or(Duration::MAX)
+            }
+        }
+    }
+
+    /// Every value a transition half passes through, at 60fps
+    fn frames<T: Animatable>(from: T, to: T, config: &AnimationConfig) -> Vec<T> {
+        let mut engine = AnimationEngine::new(from);
+        match config.mode.clone() {
+            AnimationMode::Spring(spring) => engine.spring_to(to, spring),
+            AnimationMode::Tween(tween) => engine.tween_to(to, tween),
+            AnimationMode::Keyframes(track) => {
+                engine.apply_keyframes(track.create_animation(from, to))
+            }
+        }
+        let mut values = Vec::new();
+        while engine.update(1.0 / 60.0) {
+            values.push(engine.get());
+            assert!(values.len() < 600, "the transition never settled");
+        }
+        values
+    }
+
+    /// Resolve the horizontal translation of `css` against a layout `width`,
+    /// as the browser does after a resize
+    fn resolved_x(css: &str, width: f32) -> f32 {
+        let percent = css
+            .strip_prefix("translate3d(")
+            .and_then(|rest| rest.split('%').next())
+            .and_then(|x| x.parse::<f32>().ok());
+        let Some(percent) = percent else {
+            unreachable!("{css} has no percentage translation");
+        };
+        percent / 100.0 * width
+    }
+
+    #[test]
+    fn test_resize_mid_transition_keeps_relative_position() {
+        let config = TransitionVariant::SlideLeft.get_config();
+        let halfway = config.exit_start.interpolate(&config.exit_end, 0.5);
+        let css = transition_transform(&halfway);
+        assert_eq!(css, "translate3d(-50%, 0%, 0) scale(1, 1)");
+
+        // The style is unchanged by a resize, and resolves to the same fraction
+        let (narrow, wide) = (800.0, 1200.0);
+        assert_eq!(resolved_x(&css, narrow), -400.0);
+        assert_eq!(resolved_x(&css, wide), -600.0);
+
+        // The exit still ends exactly one width off-screen at the new size
+        let end = transition_transform(&config.exit_end);
+        assert_eq!(resolved_x(&end, wide), -wide);
+    }
+
+    #[test]
+    fn test_targets_hold_no_pixel_measurements() {
+        for variant in ALL {
+            let config = variant.get_config();
+            for transform in [
+                config.exit_start,
+                config.exit_end,
+                config.enter_start,
+                config.enter_end,
+            ] {
+                assert!(transform.x.abs() <= 100.0 && transform.y.abs() <= 100.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotations_turn_a_quarter_and_flips_mirror() {
+        let rotate = TransitionVariant::RotateLeft.get_config();
+        assert_eq!(rotate.exit_end.rotation, std::f32::consts::FRAC_PI_2);
+        assert_eq!(rotate.enter_start.rotation, -std::f32::consts::FRAC_PI_2);
+        assert_eq!(
+            transition_transform(&rotate.exit_end),
+            format!(
+                "translate3d(0%, 0%, 0) rotate({}rad) scale(1, 1)",
+                std::f32::consts::FRAC_PI_2
+            )
+        );
+
+        let flip = TransitionVariant::FlipVertical.get_config();
+        assert_eq!(flip.exit_end, Transform::scale(1.0, -1.0));
+        let halfway = flip.exit_start.interpolate(&flip.exit_end, 0.5);
+        assert_eq!(
+            transition_transform(&halfway),
+            "translate3d(0%, 0%, 0) scale(1, 0)"
+        );
+
+        // No variant turns or skews by more than half a turn
+        for variant in ALL {
+            let config = variant.get_config();
+            for transform in [config.exit_end, config.enter_start] {
+                assert!(transform.rotation.abs() <= std::f32::consts::PI);
+                assert_eq!((transform.skew_x, transform.skew_y), (0.0, 0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_timings_are_sane() {
+        for variant in ALL {
+            let timing = variant.default_timing();
+            assert_eq!(timing.validate(), Ok(()));
+            assert!(variant.get_config().timing == timing);
+
+            let duration = duration_of(&timing);
+            assert!(duration >= Duration::from_millis(150));
+            match timing.mode {
+                AnimationMode::Tween(_) | AnimationMode::Keyframes(_) => {
+                    assert!(duration <= Duration::from_millis(500))
+                }
+                // Bounces take a little longer to die down
+                AnimationMode::Spring(_) => assert!(duration <= Duration::from_secs(1)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_bouncy_variants_overshoot_and_fades_do_not() {
+        for variant in [TransitionVariant::BounceIn, TransitionVariant::ElasticIn] {
+            let config = variant.get_config();
+            let path = frames(config.enter_start, config.enter_end, &config.timing);
+            // Rising from below, the content passes its resting place
+            let peak = path.iter().map(|transform| transform.y).fold(0.0, f32::min);
+            assert!(peak < -5.0, "{peak}% is no bounce");
+        }
+
+        let fade = TransitionVariant::Fade.get_config();
+        let opacity = frames(0.0f32, 1.0, &fade.timing);
+        assert!(opacity.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(opacity.iter().all(|opacity| *opacity <= 1.0));
+    }
+
+    #[test]
+    fn test_small_motion_is_faster_than_page_slides() {
+        let fade = duration_of(&TransitionVariant::Fade.default_timing());
+        let slide = duration_of(&TransitionVariant::SlideLeft.default_timing());
+        assert!(fade < slide);
+        assert!(slide >= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_overrides_take_precedence() {
+        let variant = TransitionVariant::SlideLeft;
+        let route = AnimationConfig::new(AnimationMode::Spring(Spring::default()));
+        let outlet = AnimationConfig::new(AnimationMode::Tween(
+            Tween::new().duration(Duration::from_millis(120)),
+        ));
+
+        assert_eq!(variant.resolve_timing(None, None), variant.default_timing());
+        assert_eq!(variant.resolve_timing(None, Some(outlet.clone())), outlet);
+        assert_eq!(
+            variant.resolve_timing(Some(route.clone()), Some(outlet)),
+            route
+        );
+    }
+}